@@ -62,6 +62,13 @@ export const A320Failure = Object.freeze({
   Sec3Failure: 27004,
   Fcdc1Failure: 27005,
   Fcdc2Failure: 27006,
+  ThsJam: 27100,
+  ThsGreenHydraulicMotor: 27101,
+  ThsYellowHydraulicMotor: 27102,
+  ThsElectricMotor1: 27103,
+  ThsElectricMotor2: 27104,
+  ThsElectricMotor3: 27105,
+  ThsTrimRunaway: 27106,
 
   GreenReservoirLeak: 29000,
   BlueReservoirLeak: 29001,
@@ -111,10 +118,34 @@ export const A320Failure = Object.freeze({
   GearActuatorJammedGearDoorLeft: 32024,
   GearActuatorJammedGearDoorRight: 32025,
 
+  GearStrutUnderServicedNose: 32030,
+  GearStrutUnderServicedLeft: 32031,
+  GearStrutUnderServicedRight: 32032,
+
   GreenBrakeHydraulicLeak: 32100,
   YellowBrakeHydraulicLeak: 32101,
   YellowBrakeAccumulatorGasLeak: 32150,
 
+  NoseWheelSteeringActuatorJammed: 32200,
+  NoseWheelSteeringServoValve: 32201,
+  NoseWheelShimmyDamper: 32202,
+  BscuSteeringChannel1: 32210,
+  BscuSteeringChannel2: 32211,
+
+  TyreSlowLeakWheel1: 32301,
+  TyreSlowLeakWheel2: 32302,
+  TyreSlowLeakWheel3: 32303,
+  TyreSlowLeakWheel4: 32304,
+  TyreSlowLeakWheel5: 32305,
+  TyreSlowLeakWheel6: 32306,
+  TyreBurstWheel1: 32351,
+  TyreBurstWheel2: 32352,
+  TyreBurstWheel3: 32353,
+  TyreBurstWheel4: 32354,
+  TyreBurstWheel5: 32355,
+  TyreBurstWheel6: 32356,
+  TyrePressureIndicatingSystem: 32400,
+
   RadioAltimeter1: 34000,
   RadioAltimeter2: 34001,
   RadioAntennaInterrupted1: 34010,
@@ -122,6 +153,72 @@ export const A320Failure = Object.freeze({
   RadioAntennaDirectCoupling1: 34020,
   RadioAntennaDirectCoupling2: 34021,
   Egpwc: 34030,
+
+  BleedHpValveStuckOpen1: 36000,
+  BleedHpValveStuckOpen2: 36001,
+  BleedHpValveStuckClosed1: 36010,
+  BleedHpValveStuckClosed2: 36011,
+  BleedPrValveStuckOpen1: 36020,
+  BleedPrValveStuckOpen2: 36021,
+  BleedPrValveStuckClosed1: 36030,
+  BleedPrValveStuckClosed2: 36031,
+  BleedFanAirValveStuckClosed1: 36040,
+  BleedFanAirValveStuckClosed2: 36041,
+  CrossBleedValveJammed: 36050,
+  BleedPylonDuctLeak1: 36060,
+  BleedPylonDuctLeak2: 36061,
+  BleedWingDuctLeak1: 36070,
+  BleedWingDuctLeak2: 36071,
+  BleedApuDuctLeak: 36080,
+  Bmc1Channel1: 36090,
+  Bmc1Channel2: 36091,
+  Bmc2Channel1: 36092,
+  Bmc2Channel2: 36093,
+  BleedLeakLoopPylon1: 36100,
+  BleedLeakLoopPylon2: 36102,
+  BleedLeakLoopWing1A: 36110,
+  BleedLeakLoopWing1B: 36111,
+  BleedLeakLoopWing2A: 36112,
+  BleedLeakLoopWing2B: 36113,
+  BleedLeakLoopApu: 36120,
+
+  ApuOverspeed: 49000,
+  ApuEgtOvertemperature: 49001,
+  ApuLowOilPressure: 49002,
+  ApuHighOilTemperature: 49003,
+  ApuAirIntakeFlapStuck: 49004,
+  ApuStartMotor: 49005,
+  ApuElectronicControlBox: 49006,
+  ApuGeneratorBearing: 49007,
+
+  CargoDoorFwdLatchStuck: 52000,
+  CargoDoorAftLatchStuck: 52001,
+  CargoDoorFwdHydraulicLock: 52010,
+  CargoDoorAftHydraulicLock: 52011,
+  EngineFanDamage1: 72000,
+  EngineFanDamage2: 72001,
+  EngineBirdStrike1: 72010,
+  EngineBirdStrike2: 72011,
+  EngineBearingDamage1: 72020,
+  EngineBearingDamage2: 72021,
+  ReverserUnlocked1: 78000,
+  ReverserUnlocked2: 78001,
+  ReverserFailToDeploy1: 78010,
+  ReverserFailToDeploy2: 78011,
+  ReverserFailToStow1: 78020,
+  ReverserFailToStow2: 78021,
+  ReverserSleeveJammed1: 78030,
+  ReverserSleeveJammed2: 78031,
+  ReverserActuationSupplyLoss1: 78040,
+  ReverserActuationSupplyLoss2: 78041,
+  EngineOilFilterClog1: 79000,
+  EngineOilFilterClog2: 79001,
+  EngineOilLowPressure1: 79010,
+  EngineOilLowPressure2: 79011,
+  EngineOilHighTemperature1: 79020,
+  EngineOilHighTemperature2: 79021,
+  EngineStarterValveStuckOpen1: 80000,
+  EngineStarterValveStuckOpen2: 80001,
 });
 
 export const A320FailureDefinitions: FailureDefinition[] = [
@@ -182,6 +279,13 @@ export const A320FailureDefinitions: FailureDefinition[] = [
   [27, A320Failure.Sec3Failure, 'SEC 3'],
   [27, A320Failure.Fcdc1Failure, 'FCDC 1'],
   [27, A320Failure.Fcdc2Failure, 'FCDC 2'],
+  [27, A320Failure.ThsJam, 'THS jam'],
+  [27, A320Failure.ThsGreenHydraulicMotor, 'THS green hydraulic motor'],
+  [27, A320Failure.ThsYellowHydraulicMotor, 'THS yellow hydraulic motor'],
+  [27, A320Failure.ThsElectricMotor1, 'THS electric trim motor 1'],
+  [27, A320Failure.ThsElectricMotor2, 'THS electric trim motor 2'],
+  [27, A320Failure.ThsElectricMotor3, 'THS electric trim motor 3'],
+  [27, A320Failure.ThsTrimRunaway, 'THS trim runaway'],
 
   [29, A320Failure.GreenReservoirLeak, 'Green reservoir leak'],
   [29, A320Failure.BlueReservoirLeak, 'Blue reservoir leak'],
@@ -226,9 +330,31 @@ export const A320FailureDefinitions: FailureDefinition[] = [
   [32, A320Failure.GearActuatorJammedGearDoorLeft, 'Main left gear door jammed actuator'],
   [32, A320Failure.GearActuatorJammedGearDoorRight, 'Main right gear door jammed actuator'],
 
+  [32, A320Failure.GearStrutUnderServicedNose, 'Nose gear shock absorber under-serviced'],
+  [32, A320Failure.GearStrutUnderServicedLeft, 'Main left gear shock absorber under-serviced'],
+  [32, A320Failure.GearStrutUnderServicedRight, 'Main right gear shock absorber under-serviced'],
+
   [32, A320Failure.GreenBrakeHydraulicLeak, 'Green brakes circuit leak'],
   [32, A320Failure.YellowBrakeHydraulicLeak, 'Yellow brakes circuit leak'],
   [32, A320Failure.YellowBrakeAccumulatorGasLeak, 'Yellow brake accumulator gas leak'],
+  [32, A320Failure.NoseWheelSteeringActuatorJammed, 'Nose wheel steering jammed actuator'],
+  [32, A320Failure.NoseWheelSteeringServoValve, 'Nose wheel steering servo valve'],
+  [32, A320Failure.NoseWheelShimmyDamper, 'Nose wheel shimmy damper'],
+  [32, A320Failure.BscuSteeringChannel1, 'BSCU 1 steering channel'],
+  [32, A320Failure.BscuSteeringChannel2, 'BSCU 2 steering channel'],
+  [32, A320Failure.TyreSlowLeakWheel1, 'Wheel 1 tyre slow leak'],
+  [32, A320Failure.TyreSlowLeakWheel2, 'Wheel 2 tyre slow leak'],
+  [32, A320Failure.TyreSlowLeakWheel3, 'Wheel 3 tyre slow leak'],
+  [32, A320Failure.TyreSlowLeakWheel4, 'Wheel 4 tyre slow leak'],
+  [32, A320Failure.TyreSlowLeakWheel5, 'Wheel 5 tyre slow leak'],
+  [32, A320Failure.TyreSlowLeakWheel6, 'Wheel 6 tyre slow leak'],
+  [32, A320Failure.TyreBurstWheel1, 'Wheel 1 tyre burst'],
+  [32, A320Failure.TyreBurstWheel2, 'Wheel 2 tyre burst'],
+  [32, A320Failure.TyreBurstWheel3, 'Wheel 3 tyre burst'],
+  [32, A320Failure.TyreBurstWheel4, 'Wheel 4 tyre burst'],
+  [32, A320Failure.TyreBurstWheel5, 'Wheel 5 tyre burst'],
+  [32, A320Failure.TyreBurstWheel6, 'Wheel 6 tyre burst'],
+  [32, A320Failure.TyrePressureIndicatingSystem, 'TPIS'],
 
   [34, A320Failure.RadioAltimeter1, 'RA 1'],
   [34, A320Failure.RadioAltimeter2, 'RA 2'],
@@ -238,4 +364,72 @@ export const A320FailureDefinitions: FailureDefinition[] = [
   [34, A320Failure.RadioAntennaDirectCoupling2, 'RA 2 Direct Coupling'],
 
   [34, A320Failure.Egpwc, 'EGPWC'],
+
+  [36, A320Failure.BleedHpValveStuckOpen1, 'ENG 1 HP valve stuck open'],
+  [36, A320Failure.BleedHpValveStuckOpen2, 'ENG 2 HP valve stuck open'],
+  [36, A320Failure.BleedHpValveStuckClosed1, 'ENG 1 HP valve stuck closed'],
+  [36, A320Failure.BleedHpValveStuckClosed2, 'ENG 2 HP valve stuck closed'],
+  [36, A320Failure.BleedPrValveStuckOpen1, 'ENG 1 bleed valve stuck open'],
+  [36, A320Failure.BleedPrValveStuckOpen2, 'ENG 2 bleed valve stuck open'],
+  [36, A320Failure.BleedPrValveStuckClosed1, 'ENG 1 bleed valve stuck closed'],
+  [36, A320Failure.BleedPrValveStuckClosed2, 'ENG 2 bleed valve stuck closed'],
+  [36, A320Failure.BleedFanAirValveStuckClosed1, 'ENG 1 fan air valve stuck closed'],
+  [36, A320Failure.BleedFanAirValveStuckClosed2, 'ENG 2 fan air valve stuck closed'],
+  [36, A320Failure.CrossBleedValveJammed, 'X-bleed valve jammed'],
+  [36, A320Failure.BleedPylonDuctLeak1, 'ENG 1 pylon bleed duct leak'],
+  [36, A320Failure.BleedPylonDuctLeak2, 'ENG 2 pylon bleed duct leak'],
+  [36, A320Failure.BleedWingDuctLeak1, 'ENG 1 wing bleed duct leak'],
+  [36, A320Failure.BleedWingDuctLeak2, 'ENG 2 wing bleed duct leak'],
+  [36, A320Failure.BleedApuDuctLeak, 'APU bleed duct leak'],
+  [36, A320Failure.Bmc1Channel1, 'BMC 1 channel 1'],
+  [36, A320Failure.Bmc1Channel2, 'BMC 1 channel 2'],
+  [36, A320Failure.Bmc2Channel1, 'BMC 2 channel 1'],
+  [36, A320Failure.Bmc2Channel2, 'BMC 2 channel 2'],
+  [36, A320Failure.BleedLeakLoopPylon1, 'ENG 1 pylon leak detection loop'],
+  [36, A320Failure.BleedLeakLoopPylon2, 'ENG 2 pylon leak detection loop'],
+  [36, A320Failure.BleedLeakLoopWing1A, 'ENG 1 wing leak detection loop A'],
+  [36, A320Failure.BleedLeakLoopWing1B, 'ENG 1 wing leak detection loop B'],
+  [36, A320Failure.BleedLeakLoopWing2A, 'ENG 2 wing leak detection loop A'],
+  [36, A320Failure.BleedLeakLoopWing2B, 'ENG 2 wing leak detection loop B'],
+  [36, A320Failure.BleedLeakLoopApu, 'APU leak detection loop'],
+
+  [49, A320Failure.ApuOverspeed, 'APU overspeed'],
+  [49, A320Failure.ApuEgtOvertemperature, 'APU EGT overtemperature'],
+  [49, A320Failure.ApuLowOilPressure, 'APU low oil pressure'],
+  [49, A320Failure.ApuHighOilTemperature, 'APU high oil temperature'],
+  [49, A320Failure.ApuAirIntakeFlapStuck, 'APU air intake flap stuck'],
+  [49, A320Failure.ApuStartMotor, 'APU start motor'],
+  [49, A320Failure.ApuElectronicControlBox, 'APU ECB'],
+  [49, A320Failure.ApuGeneratorBearing, 'APU generator bearing'],
+
+  [52, A320Failure.CargoDoorFwdLatchStuck, 'Forward cargo door latch stuck'],
+  [52, A320Failure.CargoDoorAftLatchStuck, 'Aft cargo door latch stuck'],
+  [52, A320Failure.CargoDoorFwdHydraulicLock, 'Forward cargo door hydraulic lock'],
+  [52, A320Failure.CargoDoorAftHydraulicLock, 'Aft cargo door hydraulic lock'],
+
+  [72, A320Failure.EngineFanDamage1, 'ENG 1 fan damage'],
+  [72, A320Failure.EngineFanDamage2, 'ENG 2 fan damage'],
+  [72, A320Failure.EngineBirdStrike1, 'ENG 1 bird strike'],
+  [72, A320Failure.EngineBirdStrike2, 'ENG 2 bird strike'],
+  [72, A320Failure.EngineBearingDamage1, 'ENG 1 bearing damage'],
+  [72, A320Failure.EngineBearingDamage2, 'ENG 2 bearing damage'],
+  [78, A320Failure.ReverserUnlocked1, 'ENG 1 reverser unlocked'],
+  [78, A320Failure.ReverserUnlocked2, 'ENG 2 reverser unlocked'],
+  [78, A320Failure.ReverserFailToDeploy1, 'ENG 1 reverser fail to deploy'],
+  [78, A320Failure.ReverserFailToDeploy2, 'ENG 2 reverser fail to deploy'],
+  [78, A320Failure.ReverserFailToStow1, 'ENG 1 reverser fail to stow'],
+  [78, A320Failure.ReverserFailToStow2, 'ENG 2 reverser fail to stow'],
+  [78, A320Failure.ReverserSleeveJammed1, 'ENG 1 reverser sleeve jammed'],
+  [78, A320Failure.ReverserSleeveJammed2, 'ENG 2 reverser sleeve jammed'],
+  [78, A320Failure.ReverserActuationSupplyLoss1, 'ENG 1 reverser actuation supply loss'],
+  [78, A320Failure.ReverserActuationSupplyLoss2, 'ENG 2 reverser actuation supply loss'],
+  [79, A320Failure.EngineOilFilterClog1, 'ENG 1 oil filter clog'],
+  [79, A320Failure.EngineOilFilterClog2, 'ENG 2 oil filter clog'],
+  [79, A320Failure.EngineOilLowPressure1, 'ENG 1 oil low pressure'],
+  [79, A320Failure.EngineOilLowPressure2, 'ENG 2 oil low pressure'],
+  [79, A320Failure.EngineOilHighTemperature1, 'ENG 1 oil high temperature'],
+  [79, A320Failure.EngineOilHighTemperature2, 'ENG 2 oil high temperature'],
+
+  [80, A320Failure.EngineStarterValveStuckOpen1, 'ENG 1 starter valve stuck open'],
+  [80, A320Failure.EngineStarterValveStuckOpen2, 'ENG 2 starter valve stuck open'],
 ];
@@ -575,6 +575,9 @@ function getA1A2CellText(
       case 3:
         text = '<text class="FontMedium MiddleAlign Green" x="16.782249" y="7.1280665">BRK MAX</text>';
         break;
+      case 4:
+        text = '<text class="FontMedium MiddleAlign Green" x="16.782249" y="7.1280665">BRK BTV</text>';
+        break;
       default:
         text = '';
         isShown = false;
@@ -69,17 +69,21 @@ export const FuelComponent = () => {
 };
 
 export const OilComponent = () => {
-  const [oilQuantLeft] = useSimVar('ENG OIL QUANTITY:1', 'percent', 1000);
-  const [oilQuantRight] = useSimVar('ENG OIL QUANTITY:2', 'percent', 1000);
+  const [oilQuantLeft] = useSimVar('L:A32NX_ENGINE_1_OIL_QUANTITY', 'number', 1000);
+  const [oilQuantRight] = useSimVar('L:A32NX_ENGINE_2_OIL_QUANTITY', 'number', 1000);
 
-  const oilLeft = splitDecimals(oilQuantLeft * 0.01 * 25);
-  const oilRight = splitDecimals(oilQuantRight * 0.01 * 25);
+  const oilLeft = splitDecimals(oilQuantLeft);
+  const oilRight = splitDecimals(oilQuantRight);
 
-  const [leftVIBN1] = useSimVar('TURB ENG VIBRATION:1', 'Number', 1000);
-  const [rightVIBN1] = useSimVar('TURB ENG VIBRATION:2', 'Number', 1000);
+  const [leftVIBN1] = useSimVar('L:A32NX_ENGINE_1_N1_VIBRATION', 'number', 1000);
+  const [rightVIBN1] = useSimVar('L:A32NX_ENGINE_2_N1_VIBRATION', 'number', 1000);
+  const [leftVIBN2] = useSimVar('L:A32NX_ENGINE_1_N2_VIBRATION', 'number', 1000);
+  const [rightVIBN2] = useSimVar('L:A32NX_ENGINE_2_N2_VIBRATION', 'number', 1000);
 
   const leftVN1 = splitDecimals(leftVIBN1 < 0 ? 0.0 : leftVIBN1);
   const rightVN1 = splitDecimals(rightVIBN1 < 0 ? 0.0 : rightVIBN1);
+  const leftVN2 = splitDecimals(leftVIBN2 < 0 ? 0.0 : leftVIBN2);
+  const rightVN2 = splitDecimals(rightVIBN2 < 0 ? 0.0 : rightVIBN2);
 
   return (
     <>
@@ -127,10 +131,10 @@ export const OilComponent = () => {
       </text>
 
       <text id="VibN2Left" className="Large Green" x="195" y="265" textAnchor="end">
-        {leftVN1[0]}.
+        {leftVN2[0]}.
       </text>
       <text id="VibN2LeftDecimal" className="Standard Green" x="197" y="265" textAnchor="start">
-        {leftVN1[1]}
+        {leftVN2[1]}
       </text>
 
       <text id="VibN1Right" className="Large Green" x="440" y="235" textAnchor="end">
@@ -141,10 +145,10 @@ export const OilComponent = () => {
       </text>
 
       <text id="VibN2Right" className="Large Green" x="440" y="265" textAnchor="end">
-        {rightVN1[0]}.
+        {rightVN2[0]}.
       </text>
       <text id="VibN2RightDecimal" className="Standard Green" x="440" y="265" textAnchor="start">
-        {rightVN1[1]}
+        {rightVN2[1]}
       </text>
     </>
   );
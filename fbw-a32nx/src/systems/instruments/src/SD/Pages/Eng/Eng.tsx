@@ -93,7 +93,7 @@ interface ComponentPositionProps {
 }
 
 const PressureGauge = ({ x, y, engineNumber, fadecOn }: ComponentPositionProps) => {
-  const [engineOilPressure] = useSimVar(`ENG OIL PRESSURE:${engineNumber}`, 'psi', 100);
+  const [engineOilPressure] = useSimVar(`L:A32NX_ENGINE_${engineNumber}_OIL_PRESSURE`, 'number', 100);
   const displayedEngineOilPressure = Math.round(engineOilPressure / 2) * 2; // Engine oil pressure has a step of 2
   const OIL_PSI_MAX = 130;
   const OIL_PSI_HIGH_LIMIT = 130;
@@ -191,11 +191,10 @@ const PressureGauge = ({ x, y, engineNumber, fadecOn }: ComponentPositionProps)
 };
 
 const QuantityGauge = ({ x, y, engineNumber, fadecOn }: ComponentPositionProps) => {
-  const [engineOilQuantity] = useSimVar(`ENG OIL QUANTITY:${engineNumber}`, 'percent', 100);
+  const [engineOilQuantity] = useSimVar(`L:A32NX_ENGINE_${engineNumber}_OIL_QUANTITY`, 'number', 100);
   const OIL_QTY_MAX = 24.25;
   const OIL_QTY_LOW_ADVISORY = 1.35;
-  const displayedEngineOilQuantity =
-    engineOilQuantity === 100 ? OIL_QTY_MAX : Math.round(((engineOilQuantity / 100) * OIL_QTY_MAX) / 0.5) * 0.5; // Engine oil quantity has a step of 0.2
+  const displayedEngineOilQuantity = Math.round(engineOilQuantity / 0.5) * 0.5; // Engine oil quantity has a step of 0.5
   const [quantityAtOrBelowLow, setQuantityAtOrBelowLow] = useState(false);
   const [shouldQuantityPulse, setShouldQuantityPulse] = useState(false);
 
@@ -324,7 +323,7 @@ const EngineColumn = ({ x, y, engineNumber, fadecOn }: ComponentPositionProps) =
   const [fuelUsed] = useSimVar(`L:A32NX_FUEL_USED:${engineNumber}`, 'number', 500);
   const displayedFuelUsed = useMetric ? Math.round(fuelUsed / 10) * 10 : Math.round(fuelUsed / 0.4535934 / 20) * 20;
 
-  const [engineOilTemperature] = useSimVar(`GENERAL ENG OIL TEMPERATURE:${engineNumber}`, 'celsius', 250);
+  const [engineOilTemperature] = useSimVar(`L:A32NX_ENGINE_${engineNumber}_OIL_TEMPERATURE`, 'number', 250);
   const OIL_TEMP_LOW_TAKEOFF = 38;
   const OIL_TEMP_HIGH_ADVISORY = 140;
   const OIL_TEMP_VHIGH_LIMIT = 155;
@@ -333,9 +332,13 @@ const EngineColumn = ({ x, y, engineNumber, fadecOn }: ComponentPositionProps) =
   const [shouldTemperaturePulse, setShouldTemperaturePulse] = useState(false);
   const [tempBeenAboveAdvisory, setTempBeenAboveAdvisory] = useState(false);
 
-  const [n1Vibration] = useSimVar(`TURB ENG VIBRATION:${engineNumber}`, 'Number');
+  const [n1Vibration] = useSimVar(`L:A32NX_ENGINE_${engineNumber}_N1_VIBRATION`, 'number');
 
-  const [n2Vibration] = useSimVar(`TURB ENG VIBRATION:${engineNumber}`, 'Number'); // FIXME TODO: should have a different value than N1, currently API limited
+  const [n2Vibration] = useSimVar(`L:A32NX_ENGINE_${engineNumber}_N2_VIBRATION`, 'number');
+
+  const [n1VibrationAdvisory] = useSimVar(`L:A32NX_ENGINE_${engineNumber}_N1_VIBRATION_ADVISORY`, 'bool');
+
+  const [n2VibrationAdvisory] = useSimVar(`L:A32NX_ENGINE_${engineNumber}_N2_VIBRATION_ADVISORY`, 'bool');
 
   const activeVisibility = fadecOn ? 'visible' : 'hidden';
   const inactiveVisibility = fadecOn ? 'hidden' : 'visible';
@@ -397,13 +400,13 @@ const EngineColumn = ({ x, y, engineNumber, fadecOn }: ComponentPositionProps) =
           {displayedEngineOilTemperature}
         </text>
 
-        <text x={x} y={y + 270} className="FillGreen TextCenter">
+        <text x={x} y={y + 270} className={`TextCenter ${n1VibrationAdvisory ? 'FillPulse' : 'FillGreen'}`}>
           <tspan className="FontLarge">{n1Vibration.toFixed(1).toString().split('.')[0]}</tspan>
           <tspan className="FontSmall">.</tspan>
           <tspan className="FontSmall">{n1Vibration.toFixed(1).toString().split('.')[1]}</tspan>
         </text>
 
-        <text x={x} y={y + 300} className="FillGreen TextCenter">
+        <text x={x} y={y + 300} className={`TextCenter ${n2VibrationAdvisory ? 'FillPulse' : 'FillGreen'}`}>
           <tspan className="FontLarge">{n2Vibration.toFixed(1).toString().split('.')[0]}</tspan>
           <tspan className="FontSmall">.</tspan>
           <tspan className="FontSmall">{n2Vibration.toFixed(1).toString().split('.')[1]}</tspan>
@@ -258,6 +258,7 @@ const AutoBrake = ({ x, y }: ComponentPositionProps) => {
           {autoBrakeLevel === 1 ? <AutoBrakeLevel text="LO" available={available} /> : null}
           {autoBrakeLevel === 2 ? <AutoBrakeLevel text="MED" available={available} /> : null}
           {autoBrakeLevel === 3 ? <AutoBrakeLevel text="MAX" available={available} /> : null}
+          {autoBrakeLevel === 4 ? <AutoBrakeLevel text="BTV" available={available} /> : null}
         </SvgGroup>
       </SvgGroup>
     );
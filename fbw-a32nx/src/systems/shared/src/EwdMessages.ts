@@ -580,6 +580,7 @@ const EwdMessages = new Map<string, { group?: string; text: string }>([
   ['000002202', { text: '\x1b<3mAUTO BRK MED' }],
   ['000002203', { text: '\x1b<3mAUTO BRK MAX' }],
   ['000002204', { text: '\x1b<3mAUTO BRK OFF' }],
+  ['000002205', { text: '\x1b<3mAUTO BRK BTV' }],
   ['000023001', { text: '\x1b<3mMAN LDG ELEV' }],
   ['000025001', { text: '\x1b<3mFUEL X FEED' }],
   ['000025002', { text: '\x1b<4mFUEL X FEED' }],
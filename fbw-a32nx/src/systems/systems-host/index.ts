@@ -12,6 +12,7 @@ import {
   MathUtils,
   StallWarningPublisher,
 } from '@microsoft/msfs-sdk';
+import { BrakeWearPersistence } from '@flybywiresim/fbw-sdk';
 import { AtsuSystem } from './systems/atsu';
 import { PowerSupplyBusses } from './systems/powersupply';
 import { FuelSystemPublisher } from '../instruments/src/MsfsAvionicsCommon/providers/FuelSystemPublisher';
@@ -66,6 +67,7 @@ class SystemsHost extends BaseInstrument {
     this.backplane.addInstrument('AtsuSystem', this.atsu);
     this.backplane.addInstrument('Ecp', new Ecp(this.bus));
     this.backplane.addInstrument('FakeDmc', new FakeDmc(this.bus));
+    this.backplane.addInstrument('BrakeWearPersistence', new BrakeWearPersistence(4));
 
     this.backplane.addPublisher('HEvent', this.hEventPublisher);
     this.backplane.addPublisher('FuelSystem', this.fuelSystemPublisher);
@@ -7425,8 +7425,8 @@ export class PseudoFWC {
       // AUTOBRAKE
       flightPhaseInhib: [],
       simVarIsActive: this.fwcFlightPhase.map((v) => v === 7 || v === 8),
-      whichCodeToReturn: () => [this.autoBrake.get() - 1],
-      codesToReturn: ['000002201', '000002202', '000002203', '000002204'],
+      whichCodeToReturn: () => [this.autoBrake.get() === 4 ? 4 : this.autoBrake.get() - 1],
+      codesToReturn: ['000002201', '000002202', '000002203', '000002204', '000002205'],
       memoInhibit: () => false,
       side: 'RIGHT',
     },
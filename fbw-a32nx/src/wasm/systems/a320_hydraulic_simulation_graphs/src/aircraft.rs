@@ -0,0 +1,437 @@
+use std::time::Duration;
+
+use systems::hydraulic::{linear_actuator::Actuator, *};
+
+use systems::{
+    electrical::{test::TestElectricitySource, ElectricalBus, Electricity},
+    shared::{update_iterator::FixedStepLoop, ElectricalBusType, PotentialOrigin, SectionPressure},
+    simulation::{
+        Aircraft, InitContext, SimulationElement, SimulationElementVisitor, UpdateContext,
+    },
+};
+use uom::si::{
+    angular_velocity::revolution_per_minute,
+    electric_current::ampere,
+    electric_potential::volt,
+    f64::*,
+    pressure::psi,
+    volume::{cubic_inch, gallon},
+    volume_rate::{gallon_per_minute, gallon_per_second},
+};
+
+use a320_systems::hydraulic::A320HydraulicCircuitFactory;
+use a380_systems::hydraulic::A380HydraulicCircuitFactory;
+
+use crate::scenario::{
+    AircraftType, CircuitColor, ConsumerSection, PumpDefinition, PumpType, Scenario,
+};
+
+struct ScenarioCircuitController {
+    should_open_fire_shutoff_valve: Vec<bool>,
+}
+impl ScenarioCircuitController {
+    fn commanding_open_fire_shutoff_valves(number_of_pumps: usize) -> Self {
+        Self {
+            should_open_fire_shutoff_valve: vec![true; number_of_pumps],
+        }
+    }
+
+    fn set_fire_shutoff_valve(&mut self, pump_section: usize, is_open: bool) {
+        self.should_open_fire_shutoff_valve[pump_section] = is_open;
+    }
+}
+impl HydraulicCircuitController for ScenarioCircuitController {
+    fn should_open_fire_shutoff_valve(&self, pump_index: usize) -> bool {
+        // Pump index is one based, so we do - 1
+        self.should_open_fire_shutoff_valve[pump_index - 1]
+    }
+
+    fn should_open_leak_measurement_valve(&self) -> bool {
+        true
+    }
+}
+
+struct ScenarioPumpController {
+    should_pressurise: bool,
+}
+impl ScenarioPumpController {
+    fn new(should_pressurise: bool) -> Self {
+        Self { should_pressurise }
+    }
+
+    fn set_pressurise(&mut self, should_pressurise: bool) {
+        self.should_pressurise = should_pressurise;
+    }
+}
+impl PumpController for ScenarioPumpController {
+    fn should_pressurise(&self) -> bool {
+        self.should_pressurise
+    }
+}
+
+/// Placeholder for pump sections the scenario leaves without any pump
+struct NoPump {}
+impl PressureSource for NoPump {
+    fn delta_vol_max(&self) -> Volume {
+        Volume::default()
+    }
+
+    fn update_after_pressure_regulation(
+        &mut self,
+        _: &UpdateContext,
+        _: Volume,
+        _: &mut Reservoir,
+        _: bool,
+    ) {
+    }
+
+    fn flow(&self) -> VolumeRate {
+        VolumeRate::default()
+    }
+
+    fn displacement(&self) -> Volume {
+        Volume::default()
+    }
+}
+impl HeatingElement for NoPump {}
+impl HeatingPressureSource for NoPump {}
+
+enum SectionPump {
+    Electric(Box<ElectricPump>),
+    EngineDriven(Box<EngineDrivenPump>),
+    Empty(NoPump),
+}
+impl SectionPump {
+    fn new(context: &mut InitContext, definition: &PumpDefinition) -> Self {
+        match definition.pump_type {
+            PumpType::Electric => SectionPump::Electric(Box::new(ElectricPump::new(
+                context,
+                definition
+                    .electric_pump_id()
+                    .expect("Scenario pump ids are validated when loading"),
+                ElectricalBusType::AlternatingCurrentGndFltService,
+                ElectricCurrent::new::<ampere>(ScenarioAircraft::ELECTRIC_PUMP_MAX_CURRENT_AMPERE),
+                definition.pump_characteristics(),
+            ))),
+            PumpType::EngineDriven => SectionPump::EngineDriven(Box::new(EngineDrivenPump::new(
+                context,
+                definition
+                    .engine_driven_pump_id()
+                    .expect("Scenario pump ids are validated when loading"),
+                definition.pump_characteristics(),
+            ))),
+        }
+    }
+
+    fn as_pressure_source(&mut self) -> &mut dyn HeatingPressureSource {
+        match self {
+            SectionPump::Electric(pump) => pump.as_mut(),
+            SectionPump::EngineDriven(pump) => pump.as_mut(),
+            SectionPump::Empty(pump) => pump,
+        }
+    }
+
+    fn pressure_source(&self) -> &dyn HeatingPressureSource {
+        match self {
+            SectionPump::Electric(pump) => pump.as_ref(),
+            SectionPump::EngineDriven(pump) => pump.as_ref(),
+            SectionPump::Empty(pump) => pump,
+        }
+    }
+}
+impl SimulationElement for SectionPump {
+    fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
+        match self {
+            SectionPump::Electric(pump) => pump.accept(visitor),
+            SectionPump::EngineDriven(pump) => pump.accept(visitor),
+            SectionPump::Empty(_) => {}
+        }
+    }
+}
+
+/// Consumer drawing the flow of a scenario profile and returning it to reservoir
+struct FlowConsumer {
+    section: ConsumerSection,
+    demand: VolumeRate,
+    volume_to_actuator: Volume,
+    volume_to_reservoir: Volume,
+}
+impl FlowConsumer {
+    // Under this section pressure consumers are considered unable to draw any fluid
+    const MIN_PRESSURE_TO_CONSUME_PSI: f64 = 100.;
+
+    fn new(section: ConsumerSection) -> Self {
+        Self {
+            section,
+            demand: VolumeRate::default(),
+            volume_to_actuator: Volume::default(),
+            volume_to_reservoir: Volume::default(),
+        }
+    }
+
+    fn set_demand(&mut self, demand: VolumeRate) {
+        self.demand = demand.max(VolumeRate::default());
+    }
+
+    fn update(&mut self, delta: Duration, section_pressure: Pressure) {
+        if section_pressure.get::<psi>() > Self::MIN_PRESSURE_TO_CONSUME_PSI {
+            let volume =
+                Volume::new::<gallon>(self.demand.get::<gallon_per_second>() * delta.as_secs_f64());
+            self.volume_to_actuator += volume;
+            self.volume_to_reservoir += volume;
+        }
+    }
+}
+impl Actuator for FlowConsumer {
+    fn used_volume(&self) -> Volume {
+        self.volume_to_actuator
+    }
+
+    fn reservoir_return(&self) -> Volume {
+        self.volume_to_reservoir
+    }
+
+    fn reset_volumes(&mut self) {
+        self.volume_to_actuator = Volume::default();
+        self.volume_to_reservoir = Volume::default();
+    }
+}
+
+/// Minimal aircraft made of a single hydraulic circuit driven by a scenario
+pub struct ScenarioAircraft {
+    updater: FixedStepLoop,
+    reservoir_air_pressure: Pressure,
+
+    hydraulic_circuit: HydraulicCircuit,
+    circuit_controller: ScenarioCircuitController,
+
+    section_pumps: Vec<SectionPump>,
+    pump_controllers: Vec<ScenarioPumpController>,
+    engine_pump_speeds: Vec<AngularVelocity>,
+
+    consumers: Vec<FlowConsumer>,
+
+    powered_source_ac: TestElectricitySource,
+    ac_ground_service_bus: ElectricalBus,
+}
+impl ScenarioAircraft {
+    const ELECTRIC_PUMP_MAX_CURRENT_AMPERE: f64 = 45.;
+
+    pub fn new(context: &mut InitContext, scenario: &Scenario) -> Self {
+        let pump_sections_number = scenario
+            .circuit
+            .pump_sections_number()
+            .expect("Scenario circuit is validated when loading");
+
+        let mut section_pumps: Vec<SectionPump> = (0..pump_sections_number)
+            .map(|_| SectionPump::Empty(NoPump {}))
+            .collect();
+        let mut pump_controllers: Vec<ScenarioPumpController> = (0..pump_sections_number)
+            .map(|_| ScenarioPumpController::new(false))
+            .collect();
+        for pump in &scenario.pumps {
+            section_pumps[pump.section] = SectionPump::new(context, pump);
+            pump_controllers[pump.section] = ScenarioPumpController::new(pump.pressurised);
+        }
+
+        Self {
+            updater: FixedStepLoop::new(scenario.time_step()),
+            reservoir_air_pressure: Pressure::new::<psi>(scenario.reservoir_air_pressure_psi),
+            hydraulic_circuit: Self::hydraulic_circuit(context, scenario),
+            circuit_controller: ScenarioCircuitController::commanding_open_fire_shutoff_valves(
+                pump_sections_number,
+            ),
+            section_pumps,
+            pump_controllers,
+            engine_pump_speeds: vec![AngularVelocity::default(); pump_sections_number],
+            consumers: scenario
+                .consumers
+                .iter()
+                .map(|c| FlowConsumer::new(c.section))
+                .collect(),
+            powered_source_ac: TestElectricitySource::powered(
+                context,
+                PotentialOrigin::EngineGenerator(1),
+            ),
+            ac_ground_service_bus: ElectricalBus::new(
+                context,
+                ElectricalBusType::AlternatingCurrentGndFltService,
+            ),
+        }
+    }
+
+    fn hydraulic_circuit(context: &mut InitContext, scenario: &Scenario) -> HydraulicCircuit {
+        match (scenario.circuit.aircraft, scenario.circuit.color) {
+            (AircraftType::A320, CircuitColor::Green) => {
+                A320HydraulicCircuitFactory::new_green_circuit(context)
+            }
+            (AircraftType::A320, CircuitColor::Blue) => {
+                A320HydraulicCircuitFactory::new_blue_circuit(context)
+            }
+            (AircraftType::A320, CircuitColor::Yellow) => {
+                A320HydraulicCircuitFactory::new_yellow_circuit(
+                    context,
+                    Volume::new::<gallon>(scenario.circuit.brake_accumulator_fluid_gallon),
+                )
+            }
+            (AircraftType::A380, CircuitColor::Green) => {
+                A380HydraulicCircuitFactory::new_green_circuit(context)
+            }
+            (AircraftType::A380, CircuitColor::Yellow) => {
+                A380HydraulicCircuitFactory::new_yellow_circuit(context)
+            }
+            (AircraftType::A380, CircuitColor::Blue) => {
+                panic!("Scenario circuit is validated when loading")
+            }
+        }
+    }
+
+    pub fn set_pump_pressurise(&mut self, pump_section: usize, should_pressurise: bool) {
+        self.pump_controllers[pump_section].set_pressurise(should_pressurise);
+    }
+
+    pub fn set_fire_shutoff_valve(&mut self, pump_section: usize, is_open: bool) {
+        self.circuit_controller
+            .set_fire_shutoff_valve(pump_section, is_open);
+    }
+
+    pub fn set_engine_pump_speed(&mut self, pump_section: usize, speed: AngularVelocity) {
+        self.engine_pump_speeds[pump_section] = speed;
+    }
+
+    pub fn set_consumer_demand(&mut self, consumer_index: usize, demand: VolumeRate) {
+        self.consumers[consumer_index].set_demand(demand);
+    }
+
+    pub fn circuit(&self) -> &HydraulicCircuit {
+        &self.hydraulic_circuit
+    }
+
+    pub fn pump_speed_rpm(&self, pump_section: usize) -> f64 {
+        match &self.section_pumps[pump_section] {
+            SectionPump::Electric(pump) => pump.speed().get::<revolution_per_minute>(),
+            SectionPump::EngineDriven(_) => {
+                self.engine_pump_speeds[pump_section].get::<revolution_per_minute>()
+            }
+            SectionPump::Empty(_) => 0.,
+        }
+    }
+
+    pub fn pump_displacement_cubic_inch(&self, pump_section: usize) -> f64 {
+        self.section_pumps[pump_section]
+            .pressure_source()
+            .displacement()
+            .get::<cubic_inch>()
+    }
+
+    pub fn pump_flow_gpm(&self, pump_section: usize) -> f64 {
+        self.section_pumps[pump_section]
+            .pressure_source()
+            .flow()
+            .get::<gallon_per_minute>()
+    }
+
+    pub fn pump_is_overheating(&self, pump_section: usize) -> bool {
+        self.section_pumps[pump_section]
+            .pressure_source()
+            .is_overheating()
+    }
+
+    pub fn consumer_demand_gpm(&self, consumer_index: usize) -> f64 {
+        self.consumers[consumer_index]
+            .demand
+            .get::<gallon_per_minute>()
+    }
+
+    fn update_pumps(&mut self, context: &UpdateContext) {
+        for (section_index, pump) in self.section_pumps.iter_mut().enumerate() {
+            let section = self.hydraulic_circuit.pump_section(section_index);
+            let reservoir = self.hydraulic_circuit.reservoir();
+            let controller = &self.pump_controllers[section_index];
+
+            match pump {
+                SectionPump::Electric(pump) => pump.update(context, section, reservoir, controller),
+                SectionPump::EngineDriven(pump) => pump.update(
+                    context,
+                    section,
+                    reservoir,
+                    self.engine_pump_speeds[section_index],
+                    controller,
+                ),
+                SectionPump::Empty(_) => {}
+            }
+        }
+    }
+
+    fn update_consumers(&mut self, delta: Duration) {
+        for consumer in &mut self.consumers {
+            let pressure = match consumer.section {
+                ConsumerSection::System => self.hydraulic_circuit.system_section().pressure(),
+                ConsumerSection::Auxiliary => self.hydraulic_circuit.auxiliary_section().pressure(),
+            };
+            consumer.update(delta, pressure);
+
+            match consumer.section {
+                ConsumerSection::System => {
+                    self.hydraulic_circuit
+                        .update_system_actuator_volumes(consumer);
+                }
+                ConsumerSection::Auxiliary => {
+                    self.hydraulic_circuit
+                        .update_auxiliary_actuator_volumes(consumer);
+                }
+            }
+        }
+    }
+}
+impl Aircraft for ScenarioAircraft {
+    fn update_before_power_distribution(
+        &mut self,
+        _: &UpdateContext,
+        electricity: &mut Electricity,
+    ) {
+        self.powered_source_ac
+            .power_with_potential(ElectricPotential::new::<volt>(115.));
+        electricity.supplied_by(&self.powered_source_ac);
+
+        electricity.flow(&self.powered_source_ac, &self.ac_ground_service_bus);
+    }
+
+    fn update_after_power_distribution(&mut self, context: &UpdateContext) {
+        self.updater.update(context);
+
+        for cur_time_step in self.updater {
+            let step_context = context.with_delta(cur_time_step);
+
+            self.update_consumers(cur_time_step);
+            self.update_pumps(&step_context);
+
+            let mut pumps: Vec<&mut dyn HeatingPressureSource> = self
+                .section_pumps
+                .iter_mut()
+                .map(|pump| pump.as_pressure_source())
+                .collect();
+
+            self.hydraulic_circuit.update(
+                &step_context,
+                &mut pumps,
+                None::<&mut ElectricPump>,
+                None::<&mut ElectricPump>,
+                None,
+                &self.circuit_controller,
+                self.reservoir_air_pressure,
+            );
+        }
+    }
+}
+impl SimulationElement for ScenarioAircraft {
+    fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
+        self.hydraulic_circuit.accept(visitor);
+        for pump in &mut self.section_pumps {
+            pump.accept(visitor);
+        }
+        self.ac_ground_service_bus.accept(visitor);
+
+        visitor.visit(self);
+    }
+}
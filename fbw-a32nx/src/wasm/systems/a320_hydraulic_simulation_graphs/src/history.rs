@@ -0,0 +1,244 @@
+use std::{fmt::Write as _, fs, io, path::Path};
+
+/// History class to record a simulation
+pub struct History {
+    /// Simulation time starting from 0
+    time_vector: Vec<f64>,
+    /// Name of each var saved
+    name_vector: Vec<String>,
+    /// Vector data for each var saved
+    data_vector: Vec<Vec<f64>>,
+}
+impl History {
+    const PLOT_WIDTH: f64 = 1000.;
+    const PLOT_HEIGHT: f64 = 220.;
+    const PLOT_MARGIN_LEFT: f64 = 80.;
+    const PLOT_MARGIN_RIGHT: f64 = 20.;
+    const PLOT_MARGIN_TOP: f64 = 30.;
+    const PLOT_MARGIN_BOTTOM: f64 = 40.;
+    const PLOT_TICKS_NUMBER: usize = 5;
+
+    pub fn new(names: Vec<String>) -> History {
+        History {
+            time_vector: Vec::new(),
+            data_vector: vec![Vec::new(); names.len()],
+            name_vector: names,
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.name_vector.is_empty()
+    }
+
+    /// Sets initialisation values of each data before first step
+    pub fn init(&mut self, start_time: f64, values: Vec<f64>) {
+        self.time_vector.push(start_time);
+        self.push_data(values);
+    }
+
+    /// Updates all values and time vector
+    pub fn update(&mut self, delta_time: f64, values: Vec<f64>) {
+        self.time_vector
+            .push(self.time_vector.last().unwrap() + delta_time);
+        self.push_data(values);
+    }
+
+    fn push_data(&mut self, values: Vec<f64>) {
+        debug_assert!(values.len() == self.data_vector.len());
+
+        for (idx, v) in values.iter().enumerate() {
+            self.data_vector[idx].push(*v);
+        }
+    }
+
+    /// Writes every recorded sample as a CSV table, one column per variable
+    pub fn to_csv(&self) -> String {
+        let mut csv = String::from("Time [sec]");
+        for name in &self.name_vector {
+            csv.push(',');
+            csv.push_str(&name.replace(',', ";"));
+        }
+        csv.push('\n');
+
+        for (sample_idx, time) in self.time_vector.iter().enumerate() {
+            let _ = write!(csv, "{}", time);
+            for data in &self.data_vector {
+                let _ = write!(csv, ",{}", data[sample_idx]);
+            }
+            csv.push('\n');
+        }
+
+        csv
+    }
+
+    /// Builds a static SVG figure with one subplot per variable sharing the time axis
+    pub fn to_svg(&self, figure_title: &str) -> String {
+        let total_height = Self::PLOT_HEIGHT * self.name_vector.len() as f64;
+        let max_time = self.time_vector.last().copied().unwrap_or_default();
+
+        let mut svg = String::new();
+        let _ = writeln!(
+            svg,
+            r#"<svg xmlns="http://www.w3.org/2000/svg" width="{w}" height="{h}" viewBox="0 0 {w} {h}" font-family="sans-serif" font-size="12">"#,
+            w = Self::PLOT_WIDTH,
+            h = total_height + Self::PLOT_MARGIN_TOP,
+        );
+        let _ = writeln!(
+            svg,
+            r#"<rect width="100%" height="100%" fill="white"/><text x="{}" y="18" font-size="16" text-anchor="middle">{}</text>"#,
+            Self::PLOT_WIDTH / 2.,
+            Self::escape(figure_title),
+        );
+
+        for (idx, data) in self.data_vector.iter().enumerate() {
+            self.write_subplot(
+                &mut svg,
+                &self.name_vector[idx],
+                data,
+                max_time,
+                Self::PLOT_MARGIN_TOP + idx as f64 * Self::PLOT_HEIGHT,
+            );
+        }
+
+        svg.push_str("</svg>\n");
+        svg
+    }
+
+    fn write_subplot(&self, svg: &mut String, name: &str, data: &[f64], max_time: f64, top: f64) {
+        let left = Self::PLOT_MARGIN_LEFT;
+        let right = Self::PLOT_WIDTH - Self::PLOT_MARGIN_RIGHT;
+        let plot_top = top + Self::PLOT_MARGIN_TOP;
+        let bottom = top + Self::PLOT_HEIGHT - Self::PLOT_MARGIN_BOTTOM;
+
+        let (mut min_value, mut max_value) = data
+            .iter()
+            .fold((f64::INFINITY, f64::NEG_INFINITY), |(min, max), v| {
+                (min.min(*v), max.max(*v))
+            });
+        if !min_value.is_finite() || !max_value.is_finite() {
+            min_value = 0.;
+            max_value = 1.;
+        }
+        if (max_value - min_value).abs() < f64::EPSILON {
+            min_value -= 1.;
+            max_value += 1.;
+        }
+
+        let x = |time: f64| {
+            if max_time > 0. {
+                left + time / max_time * (right - left)
+            } else {
+                left
+            }
+        };
+        let y = |value: f64| {
+            bottom - (value - min_value) / (max_value - min_value) * (bottom - plot_top)
+        };
+
+        let _ = writeln!(
+            svg,
+            r#"<text x="{}" y="{}" font-weight="bold">{}</text>"#,
+            left,
+            plot_top - 8.,
+            Self::escape(name)
+        );
+
+        for tick in 0..=Self::PLOT_TICKS_NUMBER {
+            let ratio = tick as f64 / Self::PLOT_TICKS_NUMBER as f64;
+
+            let value = min_value + ratio * (max_value - min_value);
+            let _ = writeln!(
+                svg,
+                r##"<line x1="{l}" y1="{y}" x2="{r}" y2="{y}" stroke="#dddddd"/><text x="{tx}" y="{ty}" text-anchor="end">{v:.2}</text>"##,
+                l = left,
+                r = right,
+                y = y(value),
+                tx = left - 6.,
+                ty = y(value) + 4.,
+                v = value,
+            );
+
+            let time = ratio * max_time;
+            let _ = writeln!(
+                svg,
+                r##"<line x1="{x}" y1="{t}" x2="{x}" y2="{b}" stroke="#dddddd"/><text x="{x}" y="{ty}" text-anchor="middle">{v:.1}</text>"##,
+                x = x(time),
+                t = plot_top,
+                b = bottom,
+                ty = bottom + 16.,
+                v = time,
+            );
+        }
+
+        let _ = writeln!(
+            svg,
+            r#"<rect x="{}" y="{}" width="{}" height="{}" fill="none" stroke="black"/>"#,
+            left,
+            plot_top,
+            right - left,
+            bottom - plot_top
+        );
+        let _ = writeln!(
+            svg,
+            r#"<text x="{}" y="{}" text-anchor="middle">Time [sec]</text>"#,
+            (left + right) / 2.,
+            bottom + 32.
+        );
+
+        svg.push_str(r#"<polyline fill="none" stroke="blue" stroke-width="1" points=""#);
+        for (time, value) in self.time_vector.iter().zip(data) {
+            let _ = write!(svg, "{:.2},{:.2} ", x(*time), y(*value));
+        }
+        svg.push_str("\"/>\n");
+    }
+
+    fn escape(text: &str) -> String {
+        text.replace('&', "&amp;")
+            .replace('<', "&lt;")
+            .replace('>', "&gt;")
+    }
+
+    /// Saves both CSV data and SVG plots as `<output_dir>/<figure_title>.csv|svg`
+    pub fn save(&self, figure_title: &str, output_dir: &Path) -> io::Result<()> {
+        fs::write(
+            output_dir.join(format!("{}.csv", figure_title)),
+            self.to_csv(),
+        )?;
+        fs::write(
+            output_dir.join(format!("{}.svg", figure_title)),
+            self.to_svg(figure_title),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn history() -> History {
+        let mut history = History::new(vec!["Pressure".to_string(), "Volume".to_string()]);
+        history.init(0., vec![0., 1.]);
+        history.update(0.5, vec![3000., 2.]);
+
+        history
+    }
+
+    #[test]
+    fn csv_has_header_and_one_line_per_sample() {
+        let csv = history().to_csv();
+        let lines: Vec<&str> = csv.lines().collect();
+
+        assert_eq!(lines[0], "Time [sec],Pressure,Volume");
+        assert_eq!(lines[1], "0,0,1");
+        assert_eq!(lines[2], "0.5,3000,2");
+        assert_eq!(lines.len(), 3);
+    }
+
+    #[test]
+    fn svg_has_one_curve_per_variable() {
+        let svg = history().to_svg("test");
+
+        assert!(svg.starts_with("<svg"));
+        assert_eq!(svg.matches("<polyline").count(), 2);
+    }
+}
@@ -0,0 +1,577 @@
+use serde::Deserialize;
+use std::{fmt, time::Duration};
+
+use systems::{
+    failures::FailureType,
+    hydraulic::pumps::PumpCharacteristics,
+    shared::{
+        interpolation, AirbusElectricPumpId, AirbusEngineDrivenPumpId, ElectricalBusType,
+        HydraulicColor,
+    },
+};
+use uom::si::{angular_velocity::revolution_per_minute, f64::*};
+
+#[derive(Debug)]
+pub struct ScenarioError(String);
+impl fmt::Display for ScenarioError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Invalid scenario: {}", self.0)
+    }
+}
+impl std::error::Error for ScenarioError {}
+
+/// Full description of a hydraulic simulation run, loaded from a TOML file
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Scenario {
+    pub name: String,
+    pub duration_s: f64,
+    #[serde(default = "Scenario::default_time_step_ms")]
+    pub time_step_ms: u64,
+    #[serde(default = "Scenario::default_reservoir_air_pressure_psi")]
+    pub reservoir_air_pressure_psi: f64,
+    pub circuit: CircuitDefinition,
+    #[serde(default)]
+    pub pumps: Vec<PumpDefinition>,
+    #[serde(default)]
+    pub consumers: Vec<ConsumerDefinition>,
+    #[serde(default)]
+    pub events: Vec<ScenarioEvent>,
+}
+impl Scenario {
+    fn default_time_step_ms() -> u64 {
+        33
+    }
+
+    fn default_reservoir_air_pressure_psi() -> f64 {
+        50.
+    }
+
+    pub fn from_toml(content: &str) -> Result<Self, Box<dyn std::error::Error>> {
+        let mut scenario: Scenario = toml::from_str(content)?;
+        scenario.validate()?;
+        scenario
+            .events
+            .sort_by(|a, b| a.time_s.total_cmp(&b.time_s));
+
+        Ok(scenario)
+    }
+
+    pub fn time_step(&self) -> Duration {
+        Duration::from_millis(self.time_step_ms)
+    }
+
+    pub fn steps_number(&self) -> usize {
+        (self.duration_s / self.time_step().as_secs_f64()).ceil() as usize
+    }
+
+    pub fn pump_index(&self, pump_id: &str) -> Option<usize> {
+        self.pumps.iter().position(|p| p.id == pump_id)
+    }
+
+    fn validate(&self) -> Result<(), ScenarioError> {
+        if self.duration_s <= 0. {
+            return Err(ScenarioError("duration_s must be positive".to_owned()));
+        }
+        if self.time_step_ms == 0 {
+            return Err(ScenarioError("time_step_ms must be positive".to_owned()));
+        }
+
+        let pump_sections = self.circuit.pump_sections_number()?;
+
+        for (idx, pump) in self.pumps.iter().enumerate() {
+            if pump.section >= pump_sections {
+                return Err(ScenarioError(format!(
+                    "pump {} uses section {} but circuit only has {} pump section(s)",
+                    pump.id, pump.section, pump_sections
+                )));
+            }
+            if self.pumps[..idx].iter().any(|p| p.section == pump.section) {
+                return Err(ScenarioError(format!(
+                    "pump {} shares section {} with another pump",
+                    pump.id, pump.section
+                )));
+            }
+            if self.pumps[..idx].iter().any(|p| p.id == pump.id) {
+                return Err(ScenarioError(format!("pump {} is defined twice", pump.id)));
+            }
+
+            pump.validate()?;
+        }
+
+        for consumer in &self.consumers {
+            if consumer.section == ConsumerSection::Auxiliary
+                && !self.circuit.has_auxiliary_section()
+            {
+                return Err(ScenarioError(format!(
+                    "consumer {} uses an auxiliary section the circuit does not have",
+                    consumer.name
+                )));
+            }
+            Profile::validate(&consumer.flow_gpm, &consumer.name)?;
+        }
+
+        for event in &self.events {
+            event.validate(self)?;
+        }
+
+        Ok(())
+    }
+}
+
+#[derive(Clone, Copy, Debug, Deserialize, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum AircraftType {
+    A320,
+    A380,
+}
+
+#[derive(Clone, Copy, Debug, Deserialize, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum CircuitColor {
+    Green,
+    Blue,
+    Yellow,
+}
+impl From<CircuitColor> for HydraulicColor {
+    fn from(value: CircuitColor) -> Self {
+        match value {
+            CircuitColor::Green => HydraulicColor::Green,
+            CircuitColor::Blue => HydraulicColor::Blue,
+            CircuitColor::Yellow => HydraulicColor::Yellow,
+        }
+    }
+}
+
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct CircuitDefinition {
+    pub aircraft: AircraftType,
+    pub color: CircuitColor,
+
+    /// A320 yellow circuit only: fluid initially stored in the brake accumulator
+    #[serde(default)]
+    pub brake_accumulator_fluid_gallon: f64,
+}
+impl CircuitDefinition {
+    const A320_PUMP_SECTIONS_NUMBER: usize = 1;
+    const A380_PUMP_SECTIONS_NUMBER: usize = 6;
+
+    pub fn pump_sections_number(&self) -> Result<usize, ScenarioError> {
+        match (self.aircraft, self.color) {
+            (AircraftType::A320, _) => Ok(Self::A320_PUMP_SECTIONS_NUMBER),
+            (AircraftType::A380, CircuitColor::Green | CircuitColor::Yellow) => {
+                Ok(Self::A380_PUMP_SECTIONS_NUMBER)
+            }
+            (AircraftType::A380, CircuitColor::Blue) => Err(ScenarioError(
+                "the A380 has no blue hydraulic circuit".to_owned(),
+            )),
+        }
+    }
+
+    pub fn has_auxiliary_section(&self) -> bool {
+        self.aircraft == AircraftType::A380 && self.color == CircuitColor::Green
+    }
+}
+
+#[derive(Clone, Copy, Debug, Deserialize, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum PumpType {
+    Electric,
+    EngineDriven,
+}
+
+#[derive(Clone, Copy, Debug, Deserialize, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum PumpCharacteristicsPreset {
+    A320Edp,
+    A320ElectricPump,
+    A380Edp,
+    A380ElectricPump,
+}
+
+/// Optional override of the displacement map of the selected characteristics preset
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct DisplacementMap {
+    pub pressure_psi: [f64; 9],
+    pub displacement_cubic_inch: [f64; 9],
+}
+
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct PumpDefinition {
+    /// Identifier of the pump, as used for simulation variables and failures (ex: "blue", "edp1a")
+    pub id: String,
+    #[serde(rename = "type")]
+    pub pump_type: PumpType,
+    pub section: usize,
+    pub characteristics: PumpCharacteristicsPreset,
+    pub displacement_map: Option<DisplacementMap>,
+    pub regulated_speed_rpm: Option<f64>,
+
+    /// Pump is commanded to pressurise at simulation start
+    #[serde(default = "PumpDefinition::default_pressurised")]
+    pub pressurised: bool,
+
+    /// Engine driven pumps only: input shaft speed over time as [time_s, rpm] points
+    #[serde(default)]
+    pub speed_rpm: Vec<[f64; 2]>,
+}
+impl PumpDefinition {
+    fn default_pressurised() -> bool {
+        true
+    }
+
+    fn validate(&self) -> Result<(), ScenarioError> {
+        match self.pump_type {
+            PumpType::Electric => {
+                self.electric_pump_id()?;
+                if !self.speed_rpm.is_empty() {
+                    return Err(ScenarioError(format!(
+                        "electric pump {} cannot have a speed profile",
+                        self.id
+                    )));
+                }
+            }
+            PumpType::EngineDriven => {
+                self.engine_driven_pump_id()?;
+                Profile::validate(&self.speed_rpm, &self.id)?;
+            }
+        }
+
+        Ok(())
+    }
+
+    pub fn electric_pump_id(&self) -> Result<AirbusElectricPumpId, ScenarioError> {
+        match self.id.as_str() {
+            "green_a" => Ok(AirbusElectricPumpId::GreenA),
+            "green_b" => Ok(AirbusElectricPumpId::GreenB),
+            "yellow_a" => Ok(AirbusElectricPumpId::YellowA),
+            "yellow_b" => Ok(AirbusElectricPumpId::YellowB),
+            "green" => Ok(AirbusElectricPumpId::Green),
+            "blue" => Ok(AirbusElectricPumpId::Blue),
+            "yellow" => Ok(AirbusElectricPumpId::Yellow),
+            "green_aux" => Ok(AirbusElectricPumpId::GreenAux),
+            _ => Err(ScenarioError(format!(
+                "unknown electric pump id {}",
+                self.id
+            ))),
+        }
+    }
+
+    pub fn engine_driven_pump_id(&self) -> Result<AirbusEngineDrivenPumpId, ScenarioError> {
+        match self.id.as_str() {
+            "edp1a" => Ok(AirbusEngineDrivenPumpId::Edp1a),
+            "edp1b" => Ok(AirbusEngineDrivenPumpId::Edp1b),
+            "edp2a" => Ok(AirbusEngineDrivenPumpId::Edp2a),
+            "edp2b" => Ok(AirbusEngineDrivenPumpId::Edp2b),
+            "edp3a" => Ok(AirbusEngineDrivenPumpId::Edp3a),
+            "edp3b" => Ok(AirbusEngineDrivenPumpId::Edp3b),
+            "edp4a" => Ok(AirbusEngineDrivenPumpId::Edp4a),
+            "edp4b" => Ok(AirbusEngineDrivenPumpId::Edp4b),
+            "green" => Ok(AirbusEngineDrivenPumpId::Green),
+            "yellow" => Ok(AirbusEngineDrivenPumpId::Yellow),
+            _ => Err(ScenarioError(format!(
+                "unknown engine driven pump id {}",
+                self.id
+            ))),
+        }
+    }
+
+    pub fn overheat_failure(&self) -> Result<FailureType, ScenarioError> {
+        match self.pump_type {
+            PumpType::Electric => Ok(FailureType::ElecPumpOverheat(self.electric_pump_id()?)),
+            PumpType::EngineDriven => Ok(FailureType::EnginePumpOverheat(
+                self.engine_driven_pump_id()?,
+            )),
+        }
+    }
+
+    pub fn pump_characteristics(&self) -> PumpCharacteristics {
+        let mut characteristics = match self.characteristics {
+            PumpCharacteristicsPreset::A320Edp => PumpCharacteristics::a320_edp(),
+            PumpCharacteristicsPreset::A320ElectricPump => {
+                PumpCharacteristics::a320_electric_pump()
+            }
+            PumpCharacteristicsPreset::A380Edp => PumpCharacteristics::a380_edp(),
+            PumpCharacteristicsPreset::A380ElectricPump => {
+                PumpCharacteristics::a380_electric_pump()
+            }
+        };
+
+        if let Some(map) = &self.displacement_map {
+            characteristics = characteristics
+                .with_displacement_map(map.pressure_psi, map.displacement_cubic_inch);
+        }
+
+        if let Some(speed) = self.regulated_speed_rpm {
+            characteristics = characteristics
+                .with_regulated_speed(AngularVelocity::new::<revolution_per_minute>(speed));
+        }
+
+        characteristics
+    }
+
+    pub fn speed_at(&self, time_s: f64) -> AngularVelocity {
+        AngularVelocity::new::<revolution_per_minute>(Profile::value_at(&self.speed_rpm, time_s))
+    }
+}
+
+#[derive(Clone, Copy, Debug, Deserialize, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum ConsumerSection {
+    System,
+    Auxiliary,
+}
+
+/// A fluid consumer drawing a flow from a circuit section and returning it to the reservoir
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct ConsumerDefinition {
+    pub name: String,
+    #[serde(default = "ConsumerDefinition::default_section")]
+    pub section: ConsumerSection,
+
+    /// Consumed flow over time as [time_s, gallon_per_minute] points
+    pub flow_gpm: Vec<[f64; 2]>,
+}
+impl ConsumerDefinition {
+    fn default_section() -> ConsumerSection {
+        ConsumerSection::System
+    }
+
+    pub fn flow_at(&self, time_s: f64) -> f64 {
+        Profile::value_at(&self.flow_gpm, time_s)
+    }
+}
+
+#[derive(Clone, Copy, Debug, Deserialize, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum FailureKind {
+    ReservoirLeak,
+    ReservoirAirLeak,
+    ReservoirReturnLeak,
+    PumpOverheat,
+    ElectricPowerLoss,
+}
+
+#[derive(Deserialize)]
+#[serde(tag = "action", rename_all = "snake_case", deny_unknown_fields)]
+pub enum EventAction {
+    PumpOn {
+        pump: String,
+    },
+    PumpOff {
+        pump: String,
+    },
+    OpenFireValve {
+        pump: String,
+    },
+    CloseFireValve {
+        pump: String,
+    },
+    Fail {
+        failure: FailureKind,
+        pump: Option<String>,
+    },
+    Unfail {
+        failure: FailureKind,
+        pump: Option<String>,
+    },
+}
+
+#[derive(Deserialize)]
+pub struct ScenarioEvent {
+    pub time_s: f64,
+    #[serde(flatten)]
+    pub action: EventAction,
+}
+impl ScenarioEvent {
+    fn validate(&self, scenario: &Scenario) -> Result<(), ScenarioError> {
+        match &self.action {
+            EventAction::PumpOn { pump }
+            | EventAction::PumpOff { pump }
+            | EventAction::OpenFireValve { pump }
+            | EventAction::CloseFireValve { pump } => {
+                Self::check_pump_exists(scenario, pump)?;
+            }
+            EventAction::Fail { failure, pump } | EventAction::Unfail { failure, pump } => {
+                self.failure_type(scenario, *failure, pump.as_deref())?;
+            }
+        }
+
+        Ok(())
+    }
+
+    fn check_pump_exists(scenario: &Scenario, pump: &str) -> Result<usize, ScenarioError> {
+        scenario
+            .pump_index(pump)
+            .ok_or_else(|| ScenarioError(format!("event references unknown pump {}", pump)))
+    }
+
+    pub fn failure_type(
+        &self,
+        scenario: &Scenario,
+        failure: FailureKind,
+        pump: Option<&str>,
+    ) -> Result<FailureType, ScenarioError> {
+        let color = scenario.circuit.color.into();
+        match failure {
+            FailureKind::ReservoirLeak => Ok(FailureType::ReservoirLeak(color)),
+            FailureKind::ReservoirAirLeak => Ok(FailureType::ReservoirAirLeak(color)),
+            FailureKind::ReservoirReturnLeak => Ok(FailureType::ReservoirReturnLeak(color)),
+            FailureKind::ElectricPowerLoss => Ok(FailureType::ElectricalBus(
+                ElectricalBusType::AlternatingCurrentGndFltService,
+            )),
+            FailureKind::PumpOverheat => {
+                let pump = pump.ok_or_else(|| {
+                    ScenarioError("pump_overheat failure requires a pump".to_owned())
+                })?;
+                scenario.pumps[Self::check_pump_exists(scenario, pump)?].overheat_failure()
+            }
+        }
+    }
+}
+
+/// Piecewise linear profile given as [time_s, value] points, held constant outside its range
+struct Profile {}
+impl Profile {
+    fn validate(points: &[[f64; 2]], owner: &str) -> Result<(), ScenarioError> {
+        if points.is_empty() {
+            return Err(ScenarioError(format!("{} profile has no points", owner)));
+        }
+        if points.windows(2).any(|w| w[1][0] <= w[0][0]) {
+            return Err(ScenarioError(format!(
+                "{} profile times must be strictly increasing",
+                owner
+            )));
+        }
+
+        Ok(())
+    }
+
+    fn value_at(points: &[[f64; 2]], time_s: f64) -> f64 {
+        match points {
+            [] => 0.,
+            [single] => single[1],
+            _ => {
+                let times: Vec<f64> = points.iter().map(|p| p[0]).collect();
+                let values: Vec<f64> = points.iter().map(|p| p[1]).collect();
+                interpolation(&times, &values, time_s)
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const DEFAULT_SCENARIO: &str = include_str!("../scenarios/blue_circuit_epump.toml");
+
+    fn scenario_with_pumps(pumps: &str) -> String {
+        format!(
+            r#"
+            name = "test"
+            duration_s = 1.0
+
+            [circuit]
+            aircraft = "a380"
+            color = "green"
+
+            {}
+            "#,
+            pumps
+        )
+    }
+
+    #[test]
+    fn default_scenario_is_valid() {
+        let scenario = Scenario::from_toml(DEFAULT_SCENARIO).unwrap();
+
+        assert_eq!(scenario.pumps.len(), 1);
+        assert_eq!(scenario.time_step(), Duration::from_millis(33));
+        assert!(scenario.steps_number() > 0);
+    }
+
+    #[test]
+    fn events_are_sorted_by_time() {
+        let scenario = Scenario::from_toml(&scenario_with_pumps(
+            r#"
+            [[pumps]]
+            id = "edp1a"
+            type = "engine_driven"
+            section = 0
+            characteristics = "a380_edp"
+            speed_rpm = [[0.0, 3500.0]]
+
+            [[events]]
+            time_s = 0.8
+            action = "pump_on"
+            pump = "edp1a"
+
+            [[events]]
+            time_s = 0.2
+            action = "fail"
+            failure = "pump_overheat"
+            pump = "edp1a"
+            "#,
+        ))
+        .unwrap();
+
+        assert_eq!(scenario.events[0].time_s, 0.2);
+        assert_eq!(scenario.events[1].time_s, 0.8);
+    }
+
+    #[test]
+    fn a380_blue_circuit_is_rejected() {
+        assert!(Scenario::from_toml(
+            r#"
+            name = "test"
+            duration_s = 1.0
+
+            [circuit]
+            aircraft = "a380"
+            color = "blue"
+            "#
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn pump_outside_circuit_sections_is_rejected() {
+        assert!(Scenario::from_toml(&scenario_with_pumps(
+            r#"
+            [[pumps]]
+            id = "green_a"
+            type = "electric"
+            section = 6
+            characteristics = "a380_electric_pump"
+            "#,
+        ))
+        .is_err());
+    }
+
+    #[test]
+    fn event_on_unknown_pump_is_rejected() {
+        assert!(Scenario::from_toml(&scenario_with_pumps(
+            r#"
+            [[events]]
+            time_s = 0.5
+            action = "pump_off"
+            pump = "green_a"
+            "#,
+        ))
+        .is_err());
+    }
+
+    #[test]
+    fn profile_interpolates_between_points() {
+        let points = [[0., 0.], [10., 100.]];
+
+        assert_eq!(Profile::value_at(&points, -1.), 0.);
+        assert_eq!(Profile::value_at(&points, 5.), 50.);
+        assert_eq!(Profile::value_at(&points, 20.), 100.);
+    }
+}
@@ -4,6 +4,7 @@ use systems::{
         acs_controller::{AcscId, AirConditioningSystemController, Pack},
         cabin_air::CabinAirSimulation,
         cabin_pressure_controller::{CabinPressureController, CpcId},
+        preconditioned_air_unit::PreconditionedAirUnit,
         pressure_valve::{OutflowValve, SafetyValve, SafetyValveSignal},
         AdirsToAirCondInterface, Air, AirConditioningOverheadShared, AirConditioningPack, CabinFan,
         Channel, DuctTemperature, MixerUnit, OutflowValveSignal, OutletAir, OverheadFlowSelector,
@@ -33,6 +34,7 @@ use systems::{
 use std::time::Duration;
 use uom::si::{
     f64::*,
+    mass_rate::kilogram_per_second,
     pressure::{hectopascal, psi},
     ratio::percent,
     thermodynamic_temperature::degree_celsius,
@@ -249,6 +251,7 @@ pub struct A320AirConditioningSystem {
     mixer_unit: MixerUnit<3>,
     // Temporary structure until packs are simulated
     packs: [AirConditioningPack; 2],
+    preconditioned_air_unit: PreconditionedAirUnit,
     trim_air_system: TrimAirSystem<3, 2>,
 
     air_conditioning_overhead: A320AirConditioningSystemOverhead<3>,
@@ -256,6 +259,7 @@ pub struct A320AirConditioningSystem {
 
 impl A320AirConditioningSystem {
     const CAB_FAN_DESIGN_FLOW_RATE_L_S: f64 = 325.; // litres/sec
+    const PCA_RATED_FLOW_KG_S: f64 = 1.2; // kg/sec
 
     pub(crate) fn new(context: &mut InitContext, cabin_zones: &[ZoneType; 3]) -> Self {
         Self {
@@ -320,6 +324,10 @@ impl A320AirConditioningSystem {
                 AirConditioningPack::new(context, Pack(1)),
                 AirConditioningPack::new(context, Pack(2)),
             ],
+            preconditioned_air_unit: PreconditionedAirUnit::new(
+                context,
+                MassRate::new::<kilogram_per_second>(Self::PCA_RATED_FLOW_KG_S),
+            ),
             trim_air_system: TrimAirSystem::new(
                 context,
                 cabin_zones,
@@ -360,6 +368,9 @@ impl A320AirConditioningSystem {
 
         self.update_packs(context);
 
+        self.preconditioned_air_unit
+            .update(context, cabin_simulation.cabin_pressure());
+
         self.update_mixer_unit();
 
         self.update_trim_air_system(context);
@@ -432,7 +443,11 @@ impl A320AirConditioningSystem {
     }
 
     fn update_mixer_unit(&mut self) {
-        let mut mixer_intakes: Vec<&dyn OutletAir> = vec![&self.packs[0], &self.packs[1]];
+        let mut mixer_intakes: Vec<&dyn OutletAir> = vec![
+            &self.packs[0],
+            &self.packs[1],
+            &self.preconditioned_air_unit,
+        ];
         for fan in self.cabin_fans.iter() {
             mixer_intakes.push(fan)
         }
@@ -494,7 +509,9 @@ impl OutletAir for A320AirConditioningSystem {
     fn outlet_air(&self) -> Air {
         let mut outlet_air = Air::new();
         outlet_air.set_flow_rate(
-            self.acsc[0].individual_pack_flow() + self.acsc[1].individual_pack_flow(),
+            self.acsc[0].individual_pack_flow()
+                + self.acsc[1].individual_pack_flow()
+                + self.preconditioned_air_unit.outlet_air().flow_rate(),
         );
         outlet_air.set_pressure(self.trim_air_system.trim_air_outlet_pressure());
         outlet_air.set_temperature(self.duct_temperature().iter().average());
@@ -515,6 +532,7 @@ impl SimulationElement for A320AirConditioningSystem {
         self.trim_air_system.accept(visitor);
         accept_iterable!(self.cabin_fans, visitor);
         accept_iterable!(self.packs, visitor);
+        self.preconditioned_air_unit.accept(visitor);
 
         self.air_conditioning_overhead.accept(visitor);
 
@@ -1911,6 +1929,11 @@ mod tests {
             self
         }
 
+        fn command_pca_on(mut self) -> Self {
+            self.write_by_name("PCA_TURNED_ON", true);
+            self
+        }
+
         fn command_man_vs_switch_position(mut self, position: usize) -> Self {
             if position == 0 {
                 self.write_by_name("OVHD_PRESS_MAN_VS_CTL_SWITCH", 0);
@@ -2820,6 +2843,41 @@ mod tests {
             );
         }
 
+        #[test]
+        fn preconditioned_air_supplies_cabin_with_packs_off() {
+            let test_bed = test_bed()
+                .on_ground()
+                .with()
+                .command_packs_on_off(false)
+                .command_pca_on()
+                .iterate(20);
+
+            assert_gt!(test_bed.cabin_air_in(), MassRate::default());
+        }
+
+        #[test]
+        fn preconditioned_air_cools_cabin_on_hot_day_with_packs_off() {
+            let without_pca = test_bed()
+                .on_ground()
+                .with()
+                .command_packs_on_off(false)
+                .ambient_temperature_of(ThermodynamicTemperature::new::<degree_celsius>(35.))
+                .iterate(600);
+
+            let with_pca = test_bed()
+                .on_ground()
+                .with()
+                .command_packs_on_off(false)
+                .ambient_temperature_of(ThermodynamicTemperature::new::<degree_celsius>(35.))
+                .command_pca_on()
+                .iterate(600);
+
+            assert_lt!(
+                with_pca.cabin_temperature(),
+                without_pca.cabin_temperature()
+            );
+        }
+
         #[test]
         fn when_on_ground_pressure_diff_is_less_than_excessive() {
             let test_bed = test_bed()
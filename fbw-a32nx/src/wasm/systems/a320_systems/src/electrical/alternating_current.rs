@@ -297,6 +297,11 @@ impl A320AlternatingCurrentElectrical {
         self.main_power_sources.gen_contactor_open(number)
     }
 
+    pub fn engine_generator_shaft_power_extraction(&self, number: usize) -> Power {
+        self.main_power_sources
+            .engine_generator_shaft_power_extraction(number)
+    }
+
     pub fn apu_gen_contactor_open(&self) -> bool {
         self.main_power_sources.apu_gen_contactor_open()
     }
@@ -509,6 +514,14 @@ impl A320MainPowerSources {
         self.engine_generator_contactors[number - 1].is_open()
     }
 
+    fn engine_generator_shaft_power_extraction(&self, number: usize) -> Power {
+        if number == 1 {
+            self.engine_1_gen.shaft_power_extraction()
+        } else {
+            self.engine_2_gen.shaft_power_extraction()
+        }
+    }
+
     pub fn apu_gen_contactor_open(&self) -> bool {
         self.apu_gen_contactor.is_open()
     }
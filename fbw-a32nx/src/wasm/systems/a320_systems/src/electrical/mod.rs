@@ -200,6 +200,12 @@ impl A320Electrical {
         self.alternating_current.gen_contactor_open(number)
     }
 
+    /// Mechanical power drawn by the engine generator from the engine accessory gearbox.
+    pub fn engine_generator_shaft_power_extraction(&self, number: usize) -> Power {
+        self.alternating_current
+            .engine_generator_shaft_power_extraction(number)
+    }
+
     fn apu_gen_contactor_open(&self) -> bool {
         self.alternating_current.apu_gen_contactor_open()
     }
@@ -316,10 +322,6 @@ impl A320ElectricalOverheadPanel {
         self.generators[number - 1].is_on()
     }
 
-    pub fn external_power_is_available(&self) -> bool {
-        self.ext_pwr.is_available()
-    }
-
     pub fn external_power_is_on(&self) -> bool {
         self.ext_pwr.is_on()
     }
@@ -459,7 +461,7 @@ mod a320_electrical_circuit_tests {
         electrical::{
             ElectricalElement, ElectricalElementIdentifier, ElectricalElementIdentifierProvider,
             Electricity, ElectricitySource, ExternalPowerSource, Potential, ProvideFrequency,
-            ProvidePotential, INTEGRATED_DRIVE_GENERATOR_STABILIZATION_TIME,
+            ProvideLoad, ProvidePotential, INTEGRATED_DRIVE_GENERATOR_STABILIZATION_TIME,
         },
         failures::FailureType,
         shared::{
@@ -2244,6 +2246,15 @@ mod a320_electrical_circuit_tests {
             self.is_available && !self.has_fault
         }
     }
+    impl ProvideLoad for TestApuGenerator {
+        fn load(&self) -> Ratio {
+            Ratio::default()
+        }
+
+        fn load_normal(&self) -> bool {
+            true
+        }
+    }
     impl ProvidePotential for TestApuGenerator {
         fn potential(&self) -> ElectricPotential {
             if self.output_within_normal_parameters() {
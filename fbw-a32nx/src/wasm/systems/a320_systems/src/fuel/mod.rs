@@ -2,9 +2,11 @@
 
 use nalgebra::Vector3;
 use systems::{
-    fuel::{FuelCG, FuelInfo, FuelPayload, FuelPump, FuelPumpProperties, FuelSystem},
+    fuel::{
+        FuelCG, FuelInfo, FuelPayload, FuelPump, FuelPumpProperties, FuelSystem, FuelTemperature,
+    },
     shared::ElectricalBusType,
-    simulation::{InitContext, SimulationElement, SimulationElementVisitor},
+    simulation::{InitContext, SimulationElement, SimulationElementVisitor, UpdateContext},
 };
 use uom::si::f64::*;
 
@@ -131,6 +133,10 @@ impl A320Fuel {
         }
     }
 
+    pub fn update(&mut self, context: &UpdateContext, engines_heat_rejection: Power) {
+        self.fuel_system.update(context, engines_heat_rejection);
+    }
+
     pub fn left_inner_tank_has_fuel_remaining(&self) -> bool {
         self.fuel_system
             .tank_has_fuel(A320FuelTankType::LeftInner.into())
@@ -208,6 +214,11 @@ impl FuelCG for A320Fuel {
         self.center_of_gravity()
     }
 }
+impl FuelTemperature for A320Fuel {
+    fn fuel_temperature(&self) -> ThermodynamicTemperature {
+        self.fuel_system.fuel_temperature()
+    }
+}
 impl SimulationElement for A320Fuel {
     fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
         self.fuel_system.accept(visitor);
@@ -6,13 +6,16 @@ use uom::si::{
     acceleration::meter_per_second_squared,
     angle::degree,
     angular_velocity::{radian_per_second, revolution_per_minute},
+    area::square_meter,
     electric_current::ampere,
     f64::*,
+    force::{kilonewton, newton},
     length::{inch, meter},
     mass::kilogram,
     pressure::psi,
     ratio::{percent, ratio},
     thermodynamic_temperature::degree_celsius,
+    torque::newton_meter,
     velocity::knot,
     volume::{cubic_inch, gallon, liter},
     volume_rate::gallon_per_second,
@@ -21,30 +24,38 @@ use uom::si::{
 use systems::{
     accept_iterable,
     engine::Engine,
+    failures::{Failure, FailureType},
     hydraulic::{
         aerodynamic_model::AerodynamicModel,
-        brake::{BrakeAssembly, BrakeFanPanel, BrakeProperties},
+        anti_skid::{
+            braking_force_transmission_ratio, AntiSkidController, BrakedWheel, TyreRunwayFriction,
+        },
+        brake::{BrakeAssembly, BrakeFanPanel, BrakeProperties, UnbrakedWheels},
         brake_circuit::{
             AutobrakeDecelerationGovernor, AutobrakeMode, AutobrakePanel,
-            BrakeAccumulatorCharacteristics, BrakeCircuit, BrakeCircuitController,
+            AutobrakeRunwayOverrunProtection, BrakeAccumulatorCharacteristics, BrakeCircuit,
+            BrakeCircuitController, BrakingDistanceCalculator, BtvDecelScheduler,
         },
         bypass_pin::BypassPin,
-        cargo_doors::{CargoDoor, HydraulicDoorController},
+        cargo_doors::{CargoDoor, CargoDoorHandPumpController, HydraulicDoorController},
         electrical_generator::{GeneratorControlUnit, HydraulicGeneratorMotor},
         flap_slat::{
             FlapSlatAssembly, SecondarySurface, SecondarySurfaceSide, SecondarySurfaceType,
         },
-        landing_gear::{GearGravityExtension, GearSystemController, HydraulicGearSystem},
+        landing_gear::{
+            GearGravityExtension, GearStructuralLimits, GearSystemController, HydraulicGearSystem,
+        },
         linear_actuator::{
             Actuator, BoundedLinearLength, ElectroHydrostaticPowered, HydraulicAssemblyController,
             HydraulicLinearActuatorAssembly, HydraulicLocking, LinearActuatedRigidBodyOnHingeAxis,
             LinearActuator, LinearActuatorCharacteristics, LinearActuatorMode,
         },
         nose_steering::{
-            SteeringActuator, SteeringAngleLimiter, SteeringController, SteeringRatioToAngle,
+            NoseWheelSteeringControl, PedalSteeringDynamicLimiter, SteeringActuator,
+            SteeringAngleLimiter, SteeringAngleLimits, SteeringController, SteeringRatioToAngle,
         },
         pumps::PumpCharacteristics,
-        pushback::PushbackTug,
+        pushback::{PushbackTug, TowingLimits},
         reverser::{ReverserAssembly, ReverserFeedback, ReverserInterface},
         rudder_control::{
             AngularPositioningController, RudderMechanicalControl, YawDamperActuatorController,
@@ -54,23 +65,27 @@ use systems::{
             TrimmableHorizontalStabilizerAssembly,
         },
         Accumulator, ElectricPump, EngineDrivenPump, HeatingElement, HydraulicCircuit,
-        HydraulicCircuitController, HydraulicPressureSensors, PowerTransferUnit,
+        HydraulicCircuitController, HydraulicPressureSensors, ManualPump, PowerTransferUnit,
         PowerTransferUnitCharacteristics, PowerTransferUnitController, PressureSwitch,
         PressureSwitchType, PriorityValve, PumpController, RamAirTurbine, Reservoir,
     },
-    landing_gear::{GearSystemSensors, LandingGearControlInterfaceUnitSet},
+    landing_gear::{
+        tpis::TyrePressureIndicatingSystem, GearSystemSensors, LandingGear,
+        LandingGearControlInterfaceUnitSet, ShockAbsorber,
+    },
     overhead::{
         AutoOffFaultPushButton, AutoOnFaultPushButton, MomentaryOnPushButton, MomentaryPushButton,
     },
     shared::{
         arinc429::SignStatus, interpolation, random_from_normal_distribution, random_from_range,
         update_iterator::MaxStepLoop, AdirsDiscreteOutputs, AdirsMeasurementOutputs,
-        AirbusElectricPumpId, AirbusEngineDrivenPumpId, ControllerSignal, DelayedFalseLogicGate,
-        DelayedPulseTrueLogicGate, DelayedTrueLogicGate, ElectricalBusType, ElectricalBuses,
-        EmergencyElectricalRatPushButton, EmergencyElectricalState, EmergencyGeneratorControlUnit,
-        EmergencyGeneratorPower, EngineFirePushButtons, GearWheel, HydraulicColor,
-        LandingGearHandle, LgciuInterface, LgciuWeightOnWheels, RamAirTurbineController,
-        ReservoirAirPressure, ReverserPosition, SectionPressure, TrimmableHorizontalStabilizer,
+        AirbusElectricPumpId, AirbusEngineDrivenPumpId, CargoDoorId, ControllerSignal,
+        DelayedFalseLogicGate, DelayedPulseTrueLogicGate, DelayedTrueLogicGate, ElectricalBusType,
+        ElectricalBuses, EmergencyElectricalRatPushButton, EmergencyElectricalState,
+        EmergencyGeneratorControlUnit, EmergencyGeneratorPower, EngineFirePushButtons, GearWheel,
+        HydraulicColor, LandingGearHandle, LgciuInterface, LgciuWeightOnWheels,
+        RamAirTurbineController, ReservoirAirPressure, ReverserPosition, SectionPressure,
+        SteeringActuatorId, TrimmableHorizontalStabilizer,
     },
     simulation::{
         InitContext, Read, Reader, SimulationElement, SimulationElementVisitor, SimulatorReader,
@@ -237,7 +252,7 @@ impl A320HydraulicCircuitFactory {
             Pressure::new::<psi>(Self::MIN_PRESS_EDP_SECTION_HI_HYST),
             false,
             true,
-            false,
+            true,
             Pressure::new::<psi>(Self::HYDRAULIC_TARGET_PRESSURE_PSI),
             PriorityValve::new(
                 Pressure::new::<psi>(Self::PRIORITY_VALVE_PRESSURE_CUTOFF_PSI),
@@ -318,11 +333,12 @@ impl A320CargoDoorFactory {
         HydraulicLinearActuatorAssembly::new([cargo_door_actuator], cargo_door_body)
     }
 
-    fn new_a320_cargo_door(context: &mut InitContext, id: &str) -> CargoDoor {
+    fn new_a320_cargo_door(context: &mut InitContext, id: &str, door_id: CargoDoorId) -> CargoDoor {
         let assembly = Self::a320_cargo_door_assembly(context);
         CargoDoor::new(
             context,
             id,
+            door_id,
             assembly,
             Self::new_a320_cargo_door_aero_model(),
         )
@@ -1295,8 +1311,36 @@ impl A320GearFactory {
     }
 }
 
+struct A320BrakedWheelFactory {}
+impl A320BrakedWheelFactory {
+    const TYRE_RADIUS_METER: f64 = 0.57;
+    const WHEEL_INERTIA_KG_M2: f64 = 40.;
+    const MAX_BRAKE_TORQUE_NEWTON_METER: f64 = 40000.;
+    const MAX_BRAKE_TORQUE_REFERENCE_PRESSURE_PSI: f64 = 2538.;
+
+    // Each of the four main wheels carries a quarter of the weight not supported by the nose gear
+    const MAIN_WHEEL_STATIC_LOAD_RATIO: f64 = 0.23;
+
+    fn new_braked_wheel(context: &mut InitContext, index: usize) -> BrakedWheel {
+        BrakedWheel::new(
+            context,
+            index,
+            Length::new::<meter>(Self::TYRE_RADIUS_METER),
+            Self::WHEEL_INERTIA_KG_M2,
+            Torque::new::<newton_meter>(Self::MAX_BRAKE_TORQUE_NEWTON_METER),
+            Pressure::new::<psi>(Self::MAX_BRAKE_TORQUE_REFERENCE_PRESSURE_PSI),
+            Ratio::new::<ratio>(Self::MAIN_WHEEL_STATIC_LOAD_RATIO),
+        )
+    }
+}
+
 struct A320GearSystemFactory {}
 impl A320GearSystemFactory {
+    const MAX_GEAR_OPERATING_SPEED_KNOTS: f64 = 250.0;
+    const MAX_GEAR_EXTENDED_SPEED_KNOTS: f64 = 280.0;
+    const MAX_LANDING_WEIGHT_KG: f64 = 67400.0;
+    const MAX_TAKEOFF_WEIGHT_KG: f64 = 79000.0;
+
     fn a320_gear_system(context: &mut InitContext) -> HydraulicGearSystem {
         let init_downlocked = context.start_gear_down();
 
@@ -1325,9 +1369,66 @@ impl A320GearSystemFactory {
             A320GearFactory::a320_left_gear_aerodynamics(),
             A320GearFactory::a320_right_gear_aerodynamics(),
             A320GearFactory::a320_nose_gear_aerodynamics(),
+            GearStructuralLimits::new(
+                Velocity::new::<knot>(Self::MAX_GEAR_OPERATING_SPEED_KNOTS),
+                Velocity::new::<knot>(Self::MAX_GEAR_EXTENDED_SPEED_KNOTS),
+                Mass::new::<kilogram>(Self::MAX_LANDING_WEIGHT_KG),
+                Mass::new::<kilogram>(Self::MAX_TAKEOFF_WEIGHT_KG),
+            ),
+        )
+    }
+}
+
+pub(super) struct A320LandingGearFactory {}
+impl A320LandingGearFactory {
+    // Contact point loads are scaled so that static load at max landing weight is half the max contact load
+    const MAIN_STRUT_MAX_STROKE_METERS: f64 = 0.47;
+    const MAIN_STRUT_PISTON_AREA_SQUARE_METERS: f64 = 0.03;
+    const MAIN_STRUT_GAS_VOLUME_LITERS: f64 = 20.7;
+    const MAIN_STRUT_PRECHARGE_PRESSURE_PSI: f64 = 740.;
+    const MAIN_STRUT_MAX_CONTACT_LOAD_NEWTONS: f64 = 600000.;
+
+    const NOSE_STRUT_MAX_STROKE_METERS: f64 = 0.4;
+    const NOSE_STRUT_PISTON_AREA_SQUARE_METERS: f64 = 0.012;
+    const NOSE_STRUT_GAS_VOLUME_LITERS: f64 = 7.06;
+    const NOSE_STRUT_PRECHARGE_PRESSURE_PSI: f64 = 338.;
+    const NOSE_STRUT_MAX_CONTACT_LOAD_NEWTONS: f64 = 110000.;
+
+    pub(super) fn a320_landing_gear(context: &mut InitContext) -> LandingGear {
+        let nose_strut = Self::a320_nose_strut(context);
+        let main_struts = [
+            Self::a320_main_strut(context, GearWheel::LEFT),
+            Self::a320_main_strut(context, GearWheel::RIGHT),
+        ];
+
+        LandingGear::new(context, nose_strut, main_struts, None)
+    }
+
+    fn a320_main_strut(context: &mut InitContext, wheel_id: GearWheel) -> ShockAbsorber {
+        ShockAbsorber::new(
+            context,
+            wheel_id,
+            Length::new::<meter>(Self::MAIN_STRUT_MAX_STROKE_METERS),
+            Area::new::<square_meter>(Self::MAIN_STRUT_PISTON_AREA_SQUARE_METERS),
+            Volume::new::<liter>(Self::MAIN_STRUT_GAS_VOLUME_LITERS),
+            Pressure::new::<psi>(Self::MAIN_STRUT_PRECHARGE_PRESSURE_PSI),
+            Force::new::<newton>(Self::MAIN_STRUT_MAX_CONTACT_LOAD_NEWTONS),
+        )
+    }
+
+    fn a320_nose_strut(context: &mut InitContext) -> ShockAbsorber {
+        ShockAbsorber::new(
+            context,
+            GearWheel::NOSE,
+            Length::new::<meter>(Self::NOSE_STRUT_MAX_STROKE_METERS),
+            Area::new::<square_meter>(Self::NOSE_STRUT_PISTON_AREA_SQUARE_METERS),
+            Volume::new::<liter>(Self::NOSE_STRUT_GAS_VOLUME_LITERS),
+            Pressure::new::<psi>(Self::NOSE_STRUT_PRECHARGE_PRESSURE_PSI),
+            Force::new::<newton>(Self::NOSE_STRUT_MAX_CONTACT_LOAD_NEWTONS),
         )
     }
 }
+
 struct A320PowerTransferUnitCharacteristics {
     efficiency: Ratio,
 
@@ -1507,6 +1608,9 @@ pub(super) struct A320Hydraulic {
     yellow_electric_pump: ElectricPump,
     yellow_electric_pump_controller: A320YellowElectricPumpController,
 
+    yellow_hand_pump: ManualPump,
+    yellow_hand_pump_controller: CargoDoorHandPumpController,
+
     pushback_tug: PushbackTug,
 
     bypass_pin: BypassPin,
@@ -1553,6 +1657,11 @@ pub(super) struct A320Hydraulic {
     brake_properties: BrakeProperties,
     left_brake_assembly: BrakeAssembly<2>,
     right_brake_assembly: BrakeAssembly<2>,
+    nose_wheels: UnbrakedWheels<2>,
+    tyre_pressure_indicating_system: TyrePressureIndicatingSystem<6>,
+    runway_friction: TyreRunwayFriction,
+    left_braked_wheels: [BrakedWheel; 2],
+    right_braked_wheels: [BrakedWheel; 2],
     // The relays for enabling the brake fans are powered by DC2
     dc2_powered: bool,
 
@@ -1569,6 +1678,13 @@ impl A320Hydraulic {
     const HIGH_PITCH_PTU_SOUND_DELTA_PRESS_THRESHOLD_PSI: f64 = 2400.;
     const HIGH_PITCH_PTU_SOUND_DURATION: Duration = Duration::from_millis(3000);
 
+    const MAIN_TYRE_NOMINAL_PRESSURE_PSI: f64 = 200.;
+    const NOSE_TYRE_NOMINAL_PRESSURE_PSI: f64 = 180.;
+
+    const TOWBAR_SHEAR_PIN_FORCE_KILONEWTON: f64 = 60.;
+    const NOSE_GEAR_TOWING_FORCE_KILONEWTON: f64 = 90.;
+    const TOWBAR_STEERING_ANGLE_DEGREES: f64 = 95.;
+
     const FLAP_FPPU_TO_SURFACE_ANGLE_BREAKPTS: [f64; 12] = [
         0., 35.66, 69.32, 89.7, 105.29, 120.22, 145.51, 168.35, 189.87, 210.69, 231.25, 251.97,
     ];
@@ -1641,6 +1757,7 @@ impl A320Hydraulic {
             nose_steering: SteeringActuator::new(
                 context,
                 "NOSE_WHEEL",
+                SteeringActuatorId::Nose,
                 Angle::new::<degree>(75.),
                 AngularVelocity::new::<radian_per_second>(0.35),
                 Length::new::<meter>(0.075),
@@ -1722,7 +1839,17 @@ impl A320Hydraulic {
                 Self::YELLOW_ELEC_PUMP_CONTROL_FROM_CARGO_DOOR_OPERATION_POWER_BUS,
             ),
 
-            pushback_tug: PushbackTug::new(context),
+            yellow_hand_pump: ManualPump::new(PumpCharacteristics::a320_hand_pump()),
+            yellow_hand_pump_controller: CargoDoorHandPumpController::new(context),
+
+            pushback_tug: PushbackTug::new(
+                context,
+                TowingLimits::new(
+                    Force::new::<kilonewton>(Self::TOWBAR_SHEAR_PIN_FORCE_KILONEWTON),
+                    Force::new::<kilonewton>(Self::NOSE_GEAR_TOWING_FORCE_KILONEWTON),
+                    Angle::new::<degree>(Self::TOWBAR_STEERING_ANGLE_DEGREES),
+                ),
+            ),
             bypass_pin: BypassPin::new(context),
 
             ram_air_turbine: RamAirTurbine::new(context, PumpCharacteristics::a320_rat()),
@@ -1798,6 +1925,7 @@ impl A320Hydraulic {
             forward_cargo_door: A320CargoDoorFactory::new_a320_cargo_door(
                 context,
                 Self::FORWARD_CARGO_DOOR_ID,
+                CargoDoorId::Forward,
             ),
             forward_cargo_door_controller: HydraulicDoorController::new(
                 context,
@@ -1807,6 +1935,7 @@ impl A320Hydraulic {
             aft_cargo_door: A320CargoDoorFactory::new_a320_cargo_door(
                 context,
                 Self::AFT_CARGO_DOOR_ID,
+                CargoDoorId::Aft,
             ),
             aft_cargo_door_controller: HydraulicDoorController::new(
                 context,
@@ -1844,6 +1973,7 @@ impl A320Hydraulic {
                 [1, 2],
                 [ElectricalBusType::DirectCurrent(1); 2],
                 Some(ElectricalBusType::AlternatingCurrent(2)),
+                Pressure::new::<psi>(Self::MAIN_TYRE_NOMINAL_PRESSURE_PSI),
             ),
             right_brake_assembly: BrakeAssembly::new(
                 context,
@@ -1851,7 +1981,31 @@ impl A320Hydraulic {
                 [3, 4],
                 [ElectricalBusType::DirectCurrent(1); 2],
                 Some(ElectricalBusType::AlternatingCurrent(2)),
+                Pressure::new::<psi>(Self::MAIN_TYRE_NOMINAL_PRESSURE_PSI),
+            ),
+            nose_wheels: UnbrakedWheels::new(
+                context,
+                [5, 6],
+                Pressure::new::<psi>(Self::NOSE_TYRE_NOMINAL_PRESSURE_PSI),
+            ),
+            tyre_pressure_indicating_system: TyrePressureIndicatingSystem::new(
+                context,
+                [
+                    (1, Self::MAIN_TYRE_NOMINAL_PRESSURE_PSI),
+                    (2, Self::MAIN_TYRE_NOMINAL_PRESSURE_PSI),
+                    (3, Self::MAIN_TYRE_NOMINAL_PRESSURE_PSI),
+                    (4, Self::MAIN_TYRE_NOMINAL_PRESSURE_PSI),
+                    (5, Self::NOSE_TYRE_NOMINAL_PRESSURE_PSI),
+                    (6, Self::NOSE_TYRE_NOMINAL_PRESSURE_PSI),
+                ]
+                .map(|(index, pressure)| (index, Pressure::new::<psi>(pressure))),
+                ElectricalBusType::DirectCurrent(1),
             ),
+            runway_friction: TyreRunwayFriction::new(context),
+            left_braked_wheels: [1, 2]
+                .map(|index| A320BrakedWheelFactory::new_braked_wheel(context, index)),
+            right_braked_wheels: [3, 4]
+                .map(|index| A320BrakedWheelFactory::new_braked_wheel(context, index)),
             dc2_powered: false,
 
             ptu_high_pitch_sound_active: DelayedFalseLogicGate::new(
@@ -1889,6 +2043,7 @@ impl A320Hydraulic {
         brake_fan_panel: &BrakeFanPanel,
         engine_fire_push_buttons: &impl EngineFirePushButtons,
         lgcius: &LandingGearControlInterfaceUnitSet,
+        landing_gear: &LandingGear,
         rat_and_emer_gen_man_on: &impl EmergencyElectricalRatPushButton,
         emergency_elec: &(impl EmergencyElectricalState + EmergencyGeneratorPower),
         reservoir_pneumatics: &impl ReservoirAirPressure,
@@ -1916,6 +2071,7 @@ impl A320Hydraulic {
                 rat_and_emer_gen_man_on,
                 emergency_elec,
                 lgcius,
+                landing_gear,
                 adirs,
             );
 
@@ -2030,18 +2186,20 @@ impl A320Hydraulic {
         rat_and_emer_gen_man_on: &impl EmergencyElectricalRatPushButton,
         emergency_elec: &(impl EmergencyElectricalState + EmergencyGeneratorPower),
         lgcius: &LandingGearControlInterfaceUnitSet,
+        landing_gear: &LandingGear,
         adirs: &impl AdirsDiscreteOutputs,
     ) {
+        // The cargo door manifold is fed through a shuttle valve from the yellow system or from the
+        // hand pump line, whichever has the highest pressure
         self.forward_cargo_door.update(
             context,
             &self.forward_cargo_door_controller,
-            self.yellow_circuit.system_section(),
+            self.yellow_circuit.shuttle_valve_section(),
         );
-
         self.aft_cargo_door.update(
             context,
             &self.aft_cargo_door_controller,
-            self.yellow_circuit.system_section(),
+            self.yellow_circuit.shuttle_valve_section(),
         );
 
         self.ram_air_turbine
@@ -2141,6 +2299,7 @@ impl A320Hydraulic {
             &self.gear_system_hydraulic_controller,
             lgcius.active_lgciu(),
             self.green_circuit.system_section(),
+            landing_gear,
         );
     }
 
@@ -2179,6 +2338,9 @@ impl A320Hydraulic {
             engine2,
             self.left_brake_assembly.brake_temperature_sensors(),
             self.right_brake_assembly.brake_temperature_sensors(),
+            &self.left_braked_wheels,
+            &self.right_braked_wheels,
+            self.nose_steering.position_feedback(),
         );
 
         // Updating rat stowed pos on all frames in case it's used for graphics
@@ -2192,7 +2354,14 @@ impl A320Hydraulic {
             emergency_elec_state,
         );
 
-        self.pushback_tug.update(context);
+        self.pushback_tug.update(
+            context,
+            self.braking_circuit_norm
+                .left_brake_pressure()
+                .max(self.braking_circuit_norm.right_brake_pressure())
+                .max(self.braking_circuit_altn.left_brake_pressure())
+                .max(self.braking_circuit_altn.right_brake_pressure()),
+        );
         self.bypass_pin.update(&self.pushback_tug);
 
         // The relay turning on the brake fans is grounded via LGCIU 2 signal and powered by DC2 (206PP via 3GS)
@@ -2222,10 +2391,45 @@ impl A320Hydraulic {
             );
         }
 
+        self.nose_wheels.update(context);
+        self.tyre_pressure_indicating_system.update(
+            context,
+            self.left_brake_assembly
+                .tyres()
+                .chain(self.right_brake_assembly.tyres())
+                .chain(self.nose_wheels.tyres()),
+        );
+
+        self.runway_friction.update(context);
+        for (braked_wheels, braking_pressure_norm, braking_pressure_altn) in [
+            (
+                &mut self.left_braked_wheels,
+                self.braking_circuit_norm.left_brake_pressure(),
+                self.braking_circuit_altn.left_brake_pressure(),
+            ),
+            (
+                &mut self.right_braked_wheels,
+                self.braking_circuit_norm.right_brake_pressure(),
+                self.braking_circuit_altn.right_brake_pressure(),
+            ),
+        ] {
+            for braked_wheel in braked_wheels {
+                braked_wheel.update(
+                    context,
+                    braking_pressure_norm.max(braking_pressure_altn),
+                    &self.runway_friction,
+                );
+            }
+        }
+
         self.braking_force.update_forces(
             context,
             &self.braking_circuit_norm,
             &self.braking_circuit_altn,
+            &self.left_brake_assembly,
+            &self.right_brake_assembly,
+            &self.left_braked_wheels,
+            &self.right_braked_wheels,
             engine1,
             engine2,
         );
@@ -2258,13 +2462,12 @@ impl A320Hydraulic {
         self.forward_cargo_door_controller.update(
             context,
             &self.forward_cargo_door,
-            self.yellow_circuit.system_section(),
+            self.yellow_circuit.shuttle_valve_section(),
         );
-
         self.aft_cargo_door_controller.update(
             context,
             &self.aft_cargo_door,
-            self.yellow_circuit.system_section(),
+            self.yellow_circuit.shuttle_valve_section(),
         );
 
         self.slats_flaps_complex.update(
@@ -2347,10 +2550,10 @@ impl A320Hydraulic {
             .update_system_actuator_volumes(self.flap_system.right_motor());
 
         self.yellow_circuit
-            .update_system_actuator_volumes(self.forward_cargo_door.actuator());
+            .update_shuttle_valve_actuator_volumes(self.forward_cargo_door.actuator());
 
         self.yellow_circuit
-            .update_system_actuator_volumes(self.aft_cargo_door.actuator());
+            .update_shuttle_valve_actuator_volumes(self.aft_cargo_door.actuator());
 
         self.yellow_circuit
             .update_system_actuator_volumes(&mut self.nose_steering);
@@ -2518,6 +2721,13 @@ impl A320Hydraulic {
             &self.yellow_electric_pump_controller,
         );
 
+        self.yellow_hand_pump.update(
+            context,
+            self.yellow_circuit.auxiliary_section(),
+            self.yellow_circuit.reservoir(),
+            &self.yellow_hand_pump_controller,
+        );
+
         self.ram_air_turbine.update(
             context,
             self.blue_circuit.system_section(),
@@ -2551,7 +2761,7 @@ impl A320Hydraulic {
             context,
             &mut [&mut self.engine_driven_pump_2],
             Some(&mut self.yellow_electric_pump),
-            None::<&mut ElectricPump>,
+            Some(&mut self.yellow_hand_pump),
             Some(&self.power_transfer_unit),
             &self.yellow_circuit_controller,
             reservoir_pneumatics.yellow_reservoir_pressure(),
@@ -2677,6 +2887,7 @@ impl SimulationElement for A320Hydraulic {
 
         self.yellow_electric_pump.accept(visitor);
         self.yellow_electric_pump_controller.accept(visitor);
+        self.yellow_hand_pump_controller.accept(visitor);
 
         self.forward_cargo_door_controller.accept(visitor);
         self.forward_cargo_door.accept(visitor);
@@ -2728,6 +2939,11 @@ impl SimulationElement for A320Hydraulic {
         self.gear_system.accept(visitor);
         self.left_brake_assembly.accept(visitor);
         self.right_brake_assembly.accept(visitor);
+        self.nose_wheels.accept(visitor);
+        self.tyre_pressure_indicating_system.accept(visitor);
+        self.runway_friction.accept(visitor);
+        accept_iterable!(self.left_braked_wheels, visitor);
+        accept_iterable!(self.right_braked_wheels, visitor);
 
         self.trim_controller.accept(visitor);
         self.trim_assembly.accept(visitor);
@@ -3677,11 +3893,6 @@ struct A320HydraulicBrakeSteerComputerUnit {
     left_brake_pedal_input_id: VariableIdentifier,
     right_brake_pedal_input_id: VariableIdentifier,
 
-    rudder_pedal_input_id: VariableIdentifier,
-    tiller_handle_input_id: VariableIdentifier,
-    tiller_pedal_disconnect_id: VariableIdentifier,
-    autopilot_nosewheel_demand_id: VariableIdentifier,
-
     brake_fan_running_identifier: VariableIdentifier,
     brake_fan_running: bool,
 
@@ -3701,16 +3912,10 @@ struct A320HydraulicBrakeSteerComputerUnit {
     should_disable_auto_brake_when_retracting: DelayedTrueLogicGate,
     anti_skid_activated: bool,
 
-    tiller_pedal_disconnect: bool,
-    tiller_handle_position: Ratio,
-    rudder_pedal_position: Ratio,
-    autopilot_nosewheel_demand: Ratio,
+    steering_control: NoseWheelSteeringControl,
+    steering_channel_failures: [Failure; 2],
 
-    pedal_steering_limiter: SteeringAngleLimiter<5>,
-    pedal_input_map: SteeringRatioToAngle<6>,
-    tiller_steering_limiter: SteeringAngleLimiter<5>,
-    tiller_input_map: SteeringRatioToAngle<6>,
-    final_steering_position_request: Angle,
+    anti_skid_controllers: [AntiSkidController; 2],
 }
 impl A320HydraulicBrakeSteerComputerUnit {
     const RUDDER_PEDAL_INPUT_GAIN: f64 = 32.;
@@ -3725,12 +3930,11 @@ impl A320HydraulicBrakeSteerComputerUnit {
     const TILLER_INPUT_MAP: [f64; 6] = [0., 1., 20., 40., 66., 75.];
     const TILLER_INPUT_CURVE_MAP: [f64; 6] = [0., 0., 4., 15., 45., 74.];
 
-    const AUTOPILOT_STEERING_INPUT_GAIN: f64 = 6.;
-
     const SPEED_MAP_FOR_TILLER_ACTION_KNOT: [f64; 5] = [0., 20., 70., 1500.0, 2800.0];
     const STEERING_ANGLE_FOR_TILLER_ACTION_DEGREE: [f64; 5] = [1., 1., 0., 0., 0.];
 
     const MAX_STEERING_ANGLE_DEMAND_DEGREES: f64 = 74.;
+    const OVERSTEER_ANGLE_DEGREES: f64 = 95.;
 
     // Minimum pressure hysteresis on green until main switched on ALTN brakes
     // Feedback by Cpt. Chaos — 25/04/2021 #pilot-feedback
@@ -3755,13 +3959,6 @@ impl A320HydraulicBrakeSteerComputerUnit {
             right_brake_pedal_input_id: context
                 .get_identifier("RIGHT_BRAKE_PEDAL_INPUT".to_owned()),
 
-            rudder_pedal_input_id: context.get_identifier("RUDDER_PEDAL_POSITION_RATIO".to_owned()),
-            tiller_handle_input_id: context.get_identifier("TILLER_HANDLE_POSITION".to_owned()),
-            tiller_pedal_disconnect_id: context
-                .get_identifier("TILLER_PEDAL_DISCONNECT".to_owned()),
-            autopilot_nosewheel_demand_id: context
-                .get_identifier("AUTOPILOT_NOSEWHEEL_DEMAND".to_owned()),
-
             brake_fan_running_identifier: context.get_identifier("BRAKE_FAN_RUNNING".to_owned()),
             brake_fan_running: false,
 
@@ -3781,30 +3978,45 @@ impl A320HydraulicBrakeSteerComputerUnit {
             ),
             anti_skid_activated: true,
 
-            tiller_pedal_disconnect: false,
-            tiller_handle_position: Ratio::new::<ratio>(0.),
-            rudder_pedal_position: Ratio::new::<ratio>(0.),
-            autopilot_nosewheel_demand: Ratio::new::<ratio>(0.),
-
-            pedal_steering_limiter: SteeringAngleLimiter::new(
-                Self::SPEED_MAP_FOR_PEDAL_ACTION_KNOT,
-                Self::STEERING_ANGLE_FOR_PEDAL_ACTION_DEGREE,
-            ),
-            pedal_input_map: SteeringRatioToAngle::new(
-                Ratio::new::<ratio>(Self::RUDDER_PEDAL_INPUT_GAIN),
-                Self::RUDDER_PEDAL_INPUT_MAP,
-                Self::RUDDER_PEDAL_INPUT_CURVE_MAP,
-            ),
-            tiller_steering_limiter: SteeringAngleLimiter::new(
-                Self::SPEED_MAP_FOR_TILLER_ACTION_KNOT,
-                Self::STEERING_ANGLE_FOR_TILLER_ACTION_DEGREE,
-            ),
-            tiller_input_map: SteeringRatioToAngle::new(
-                Ratio::new::<ratio>(Self::TILLER_INPUT_GAIN),
-                Self::TILLER_INPUT_MAP,
-                Self::TILLER_INPUT_CURVE_MAP,
+            steering_control: NoseWheelSteeringControl::new(
+                context,
+                ElectricalBusType::DirectCurrent(2),
+                SteeringRatioToAngle::new(
+                    Ratio::new::<ratio>(Self::RUDDER_PEDAL_INPUT_GAIN),
+                    Self::RUDDER_PEDAL_INPUT_MAP,
+                    Self::RUDDER_PEDAL_INPUT_CURVE_MAP,
+                ),
+                // Same pedal authority for takeoff and landing
+                PedalSteeringDynamicLimiter::new(
+                    SteeringAngleLimiter::new(
+                        Self::SPEED_MAP_FOR_PEDAL_ACTION_KNOT,
+                        Self::STEERING_ANGLE_FOR_PEDAL_ACTION_DEGREE,
+                    ),
+                    SteeringAngleLimiter::new(
+                        Self::SPEED_MAP_FOR_PEDAL_ACTION_KNOT,
+                        Self::STEERING_ANGLE_FOR_PEDAL_ACTION_DEGREE,
+                    ),
+                    Velocity::default(),
+                ),
+                SteeringRatioToAngle::new(
+                    Ratio::new::<ratio>(Self::TILLER_INPUT_GAIN),
+                    Self::TILLER_INPUT_MAP,
+                    Self::TILLER_INPUT_CURVE_MAP,
+                ),
+                SteeringAngleLimiter::new(
+                    Self::SPEED_MAP_FOR_TILLER_ACTION_KNOT,
+                    Self::STEERING_ANGLE_FOR_TILLER_ACTION_DEGREE,
+                ),
+                SteeringAngleLimits::new(
+                    Angle::new::<degree>(Self::MAX_RUDDER_INPUT_INCLUDING_AUTOPILOT_DEGREE),
+                    Angle::new::<degree>(Self::MAX_STEERING_ANGLE_DEMAND_DEGREES),
+                    Angle::new::<degree>(Self::OVERSTEER_ANGLE_DEGREES),
+                ),
             ),
-            final_steering_position_request: Angle::new::<degree>(0.),
+            steering_channel_failures: [1, 2]
+                .map(|channel| Failure::new(FailureType::SteeringControlChannel(channel))),
+
+            anti_skid_controllers: [AntiSkidController::new(), AntiSkidController::new()],
         }
     }
 
@@ -3869,6 +4081,9 @@ impl A320HydraulicBrakeSteerComputerUnit {
         engine2: &impl Engine,
         left_brake_temperature_sensors: &[TemperatureSensor; 2],
         right_brake_temperature_sensors: &[TemperatureSensor; 2],
+        left_braked_wheels: &[BrakedWheel; 2],
+        right_braked_wheels: &[BrakedWheel; 2],
+        nose_steering_feedback: Angle,
     ) {
         for (brake_temperature, sensor) in self.brake_temperatures.iter_mut().zip(
             left_brake_temperature_sensors
@@ -3880,7 +4095,7 @@ impl A320HydraulicBrakeSteerComputerUnit {
 
         self.brake_fan_running = brake_fan_panel.brake_fan_pb_is_pressed();
 
-        self.update_steering_demands(context, lgciu1, engine1, engine2);
+        self.update_steering_demands(context, lgciu1, engine1, engine2, nose_steering_feedback);
 
         self.update_normal_braking_availability(current_pressure.pressure());
         self.update_brake_pressure_limitation();
@@ -3954,6 +4169,37 @@ impl A320HydraulicBrakeSteerComputerUnit {
                 }
             }
         }
+
+        self.update_anti_skid(context, left_braked_wheels, right_braked_wheels);
+    }
+
+    /// Anti-skid modulates normal and alternate brake demands of each side from the speed of its wheels
+    fn update_anti_skid(
+        &mut self,
+        context: &UpdateContext,
+        left_braked_wheels: &[BrakedWheel; 2],
+        right_braked_wheels: &[BrakedWheel; 2],
+    ) {
+        let anti_skid_active = self.anti_skid_activated && !self.parking_brake_demand;
+
+        for (controller, braked_wheels) in self
+            .anti_skid_controllers
+            .iter_mut()
+            .zip([left_braked_wheels, right_braked_wheels])
+        {
+            controller.update(context, anti_skid_active, braked_wheels);
+        }
+
+        let [left_anti_skid, right_anti_skid] = &self.anti_skid_controllers;
+        for brake_outputs in [
+            &mut self.norm_brake_outputs,
+            &mut self.alternate_brake_outputs,
+        ] {
+            brake_outputs.set_brake_demands(
+                left_anti_skid.modulated_demand(brake_outputs.left_demand()),
+                right_anti_skid.modulated_demand(brake_outputs.right_demand()),
+            );
+        }
     }
 
     fn update_steering_demands(
@@ -3962,53 +4208,20 @@ impl A320HydraulicBrakeSteerComputerUnit {
         lgciu1: &impl LgciuInterface,
         engine1: &impl Engine,
         engine2: &impl Engine,
+        nose_steering_feedback: Angle,
     ) {
-        let steer_angle_from_autopilot = Angle::new::<degree>(
-            self.autopilot_nosewheel_demand.get::<ratio>() * Self::AUTOPILOT_STEERING_INPUT_GAIN,
-        );
-
-        let steer_angle_from_pedals = if self.tiller_pedal_disconnect {
-            Angle::new::<degree>(0.)
-        } else {
-            self.pedal_input_map
-                .angle_demand_from_input_demand(self.rudder_pedal_position)
-        };
-
-        // TODO Here ground speed would be probably computed from wheel sensor logic
-        let final_steer_rudder_plus_autopilot = self.pedal_steering_limiter.angle_from_speed(
-            context.ground_speed(),
-            (steer_angle_from_pedals + steer_angle_from_autopilot)
-                .min(Angle::new::<degree>(
-                    Self::MAX_RUDDER_INPUT_INCLUDING_AUTOPILOT_DEGREE,
-                ))
-                .max(Angle::new::<degree>(
-                    -Self::MAX_RUDDER_INPUT_INCLUDING_AUTOPILOT_DEGREE,
-                )),
-        );
-
-        let steer_angle_from_tiller = self.tiller_steering_limiter.angle_from_speed(
-            context.ground_speed(),
-            self.tiller_input_map
-                .angle_demand_from_input_demand(self.tiller_handle_position),
-        );
-
         let is_both_engine_low_oil_pressure =
             engine1.oil_pressure_is_low() && engine2.oil_pressure_is_low();
 
-        self.final_steering_position_request = if !is_both_engine_low_oil_pressure
-            && self.anti_skid_activated
-            && lgciu1.nose_gear_compressed(false)
-        {
-            (final_steer_rudder_plus_autopilot + steer_angle_from_tiller)
-                .min(Angle::new::<degree>(
-                    Self::MAX_STEERING_ANGLE_DEMAND_DEGREES,
-                ))
-                .max(Angle::new::<degree>(
-                    -Self::MAX_STEERING_ANGLE_DEMAND_DEGREES,
-                ))
-        } else {
-            Angle::new::<degree>(0.)
-        };
+        self.steering_control.update(
+            context,
+            lgciu1,
+            nose_steering_feedback,
+            !is_both_engine_low_oil_pressure
+                && self.anti_skid_activated
+                && self.is_steering_channel_available(),
+            Angle::default(),
+        );
     }
 
     fn norm_controller(&self) -> &impl BrakeCircuitController {
@@ -4019,6 +4232,13 @@ impl A320HydraulicBrakeSteerComputerUnit {
         &self.alternate_brake_outputs
     }
 
+    /// Steering stays available as long as one of the two BSCU steering channels is healthy
+    fn is_steering_channel_available(&self) -> bool {
+        self.steering_channel_failures
+            .iter()
+            .any(|failure| !failure.is_active())
+    }
+
     fn brakes_hot(&self) -> bool {
         self.brake_temperatures.iter().any(|t| {
             t.is_some_and(|t| t.get::<degree_celsius>() >= Self::BRAKES_HOT_THRESHOLD_CELSIUS)
@@ -4028,6 +4248,8 @@ impl A320HydraulicBrakeSteerComputerUnit {
 impl SimulationElement for A320HydraulicBrakeSteerComputerUnit {
     fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
         self.autobrake_controller.accept(visitor);
+        self.steering_control.accept(visitor);
+        accept_iterable!(self.steering_channel_failures, visitor);
         visitor.visit(self);
     }
 
@@ -4039,14 +4261,6 @@ impl SimulationElement for A320HydraulicBrakeSteerComputerUnit {
             Ratio::new::<percent>(reader.read(&self.left_brake_pedal_input_id));
         self.right_brake_pilot_input =
             Ratio::new::<percent>(reader.read(&self.right_brake_pedal_input_id));
-
-        self.tiller_handle_position =
-            Ratio::new::<ratio>(reader.read(&self.tiller_handle_input_id));
-        self.rudder_pedal_position = Ratio::new::<ratio>(reader.read(&self.rudder_pedal_input_id));
-        self.tiller_pedal_disconnect = reader.read(&self.tiller_pedal_disconnect_id);
-
-        self.autopilot_nosewheel_demand =
-            Ratio::new::<ratio>(reader.read(&self.autopilot_nosewheel_demand_id));
     }
 
     fn write(&self, writer: &mut SimulatorWriter) {
@@ -4075,7 +4289,11 @@ impl SimulationElement for A320HydraulicBrakeSteerComputerUnit {
 }
 impl SteeringController for A320HydraulicBrakeSteerComputerUnit {
     fn requested_position(&self) -> Angle {
-        self.final_steering_position_request
+        self.steering_control.requested_position()
+    }
+
+    fn is_steering_available(&self) -> bool {
+        self.is_steering_channel_available() && self.steering_control.is_steering_available()
     }
 }
 
@@ -4131,6 +4349,10 @@ impl A320BrakingForce {
         context: &UpdateContext,
         norm_brakes: &BrakeCircuit,
         altn_brakes: &BrakeCircuit,
+        left_brakes: &BrakeAssembly<2>,
+        right_brakes: &BrakeAssembly<2>,
+        left_braked_wheels: &[BrakedWheel; 2],
+        right_braked_wheels: &[BrakedWheel; 2],
         engine1: &impl Engine,
         engine2: &impl Engine,
     ) {
@@ -4152,6 +4374,14 @@ impl A320BrakingForce {
         self.right_braking_force = right_force_norm + right_force_altn;
         self.right_braking_force = self.right_braking_force.clamp(0., 1.);
 
+        // A skidding tyre can't transmit all the force its brake is asking for
+        self.left_braking_force *= braking_force_transmission_ratio(left_braked_wheels);
+        self.right_braking_force *= braking_force_transmission_ratio(right_braked_wheels);
+
+        self.left_braking_force = left_brakes.tyre_corrected_force_factor(self.left_braking_force);
+        self.right_braking_force =
+            right_brakes.tyre_corrected_force_factor(self.right_braking_force);
+
         self.correct_with_flaps_state(context);
 
         self.update_chocks_braking(context, engine1, engine2);
@@ -4228,6 +4458,10 @@ pub struct A320AutobrakeController {
 
     deceleration_governor: AutobrakeDecelerationGovernor,
 
+    btv_scheduler: BtvDecelScheduler,
+    braking_distance_calculator: BrakingDistanceCalculator,
+    runway_overrun_protection: AutobrakeRunwayOverrunProtection<2>,
+
     target: Acceleration,
     mode: AutobrakeMode,
 
@@ -4259,6 +4493,9 @@ impl A320AutobrakeController {
     const MARGIN_PERCENT_TO_TARGET_TO_SHOW_DECEL_IN_LO_MED: f64 = 80.;
     const TARGET_TO_SHOW_DECEL_IN_MAX_MS2: f64 = -2.7;
 
+    const OFFSET_PLANE_REF_POINT_TO_FRONT_METERS: f64 = 17.;
+    const REVERSING_ENGINES: [usize; 2] = [1, 2];
+
     fn new(context: &mut InitContext) -> A320AutobrakeController {
         A320AutobrakeController {
             armed_mode_id: context.get_identifier("AUTOBRAKES_ARMED_MODE".to_owned()),
@@ -4274,6 +4511,15 @@ impl A320AutobrakeController {
             external_disarm_event_id: context.get_identifier("AUTOBRAKE_DISARM".to_owned()),
 
             deceleration_governor: AutobrakeDecelerationGovernor::new(),
+            btv_scheduler: BtvDecelScheduler::new(context),
+            braking_distance_calculator: BrakingDistanceCalculator::new(
+                context,
+                Length::new::<meter>(Self::OFFSET_PLANE_REF_POINT_TO_FRONT_METERS),
+            ),
+            runway_overrun_protection: AutobrakeRunwayOverrunProtection::new(
+                context,
+                Self::REVERSING_ENGINES,
+            ),
             target: Acceleration::new::<meter_per_second_squared>(0.),
             mode: AutobrakeMode::NONE,
             arming_is_allowed_by_bcu: context.is_in_flight(),
@@ -4298,7 +4544,11 @@ impl A320AutobrakeController {
     }
 
     fn brake_output(&self) -> Ratio {
-        Ratio::new::<ratio>(self.deceleration_governor.output())
+        if self.runway_overrun_protection.rop_max_braking_requested() {
+            Ratio::new::<ratio>(1.)
+        } else {
+            Ratio::new::<ratio>(self.deceleration_governor.output())
+        }
     }
 
     fn determine_mode(
@@ -4311,6 +4561,10 @@ impl A320AutobrakeController {
         } else {
             match autobrake_panel.pressed_mode() {
                 Some(mode) if self.mode == mode => AutobrakeMode::NONE,
+                Some(AutobrakeMode::BTV) => {
+                    self.btv_scheduler.enable();
+                    AutobrakeMode::BTV
+                }
                 Some(mode)
                     if mode != AutobrakeMode::MAX
                         || !self.should_reject_max_mode_after_time_in_flight.output() =>
@@ -4333,7 +4587,7 @@ impl A320AutobrakeController {
     fn is_decelerating(&self) -> bool {
         match self.mode {
             AutobrakeMode::NONE => false,
-            AutobrakeMode::LOW | AutobrakeMode::MED => {
+            AutobrakeMode::LOW | AutobrakeMode::MED | AutobrakeMode::BTV => {
                 self.deceleration_demanded()
                     && self
                         .deceleration_governor
@@ -4360,7 +4614,7 @@ impl A320AutobrakeController {
     fn should_disarm_due_to_pedal_input(&self) -> bool {
         match self.mode {
             AutobrakeMode::NONE => false,
-            AutobrakeMode::LOW | AutobrakeMode::MED => {
+            AutobrakeMode::LOW | AutobrakeMode::MED | AutobrakeMode::BTV => {
                 self.left_brake_pedal_input > Ratio::new::<percent>(53.)
                     || self.right_brake_pedal_input > Ratio::new::<percent>(53.)
                     || (self.left_brake_pedal_input > Ratio::new::<percent>(11.)
@@ -4385,6 +4639,11 @@ impl A320AutobrakeController {
             || self.external_disarm_event
             || (self.mode == AutobrakeMode::MAX
                 && self.should_reject_max_mode_after_time_in_flight.output())
+            || (self.mode == AutobrakeMode::BTV && !self.btv_scheduler.is_armed())
+    }
+
+    fn btv_should_revert_to_med(&self) -> bool {
+        self.mode == AutobrakeMode::BTV && self.btv_scheduler.is_btv_lost()
     }
 
     fn calculate_target(&mut self) -> Acceleration {
@@ -4401,6 +4660,7 @@ impl A320AutobrakeController {
                 self.deceleration_governor.time_engaged().as_secs_f64(),
             ),
             AutobrakeMode::MAX => Self::MAX_MODE_DECEL_TARGET_MS2,
+            AutobrakeMode::BTV => self.btv_scheduler.decel().get::<meter_per_second_squared>(),
         })
     }
 
@@ -4446,16 +4706,59 @@ impl A320AutobrakeController {
             lgciu1,
             lgciu2,
         );
+
+        self.braking_distance_calculator.update_braking_estimations(
+            context,
+            if self.mode == AutobrakeMode::BTV {
+                self.btv_scheduler.predicted_decel()
+            } else if self.mode != AutobrakeMode::NONE {
+                context.long_accel()
+            } else {
+                Acceleration::default()
+            },
+        );
+
         self.mode = self.determine_mode(context, autobrake_panel);
 
+        if self.btv_should_revert_to_med() {
+            self.mode = AutobrakeMode::MED;
+        }
+
+        if self.mode != AutobrakeMode::BTV {
+            self.btv_scheduler.disarm();
+        }
+
         self.deceleration_governor
             .engage_when(self.should_engage_deceleration_governor(context));
 
         self.target = self.calculate_target();
         self.deceleration_governor.update(context, self.target);
+
+        self.btv_scheduler.update(
+            context,
+            self.ground_spoilers_are_deployed,
+            &self.braking_distance_calculator,
+            &self.runway_overrun_protection,
+        );
+
+        self.runway_overrun_protection.update(
+            context,
+            self.deceleration_governor.is_engaged(),
+            &self.braking_distance_calculator,
+            lgciu1.left_and_right_gear_compressed(false)
+                || lgciu2.left_and_right_gear_compressed(false),
+        );
     }
 }
 impl SimulationElement for A320AutobrakeController {
+    fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
+        self.braking_distance_calculator.accept(visitor);
+        self.btv_scheduler.accept(visitor);
+        self.runway_overrun_protection.accept(visitor);
+
+        visitor.visit(self);
+    }
+
     fn write(&self, writer: &mut SimulatorWriter) {
         writer.write(&self.armed_mode_id, self.mode as u8 as f64);
         writer.write(&self.armed_mode_id_set, -1.);
@@ -4477,6 +4780,9 @@ impl SimulationElement for A320AutobrakeController {
         let readed_mode = reader.read_f64(&self.armed_mode_id_set);
         if readed_mode >= 0.0 {
             self.mode = readed_mode.into();
+            if self.mode == AutobrakeMode::BTV {
+                self.btv_scheduler.enable();
+            }
         }
     }
 }
@@ -6047,6 +6353,8 @@ impl A320Reversers {
 
             reversers: [
                 ReverserAssembly::new(
+                    context,
+                    1,
                     Pressure::new::<psi>(
                         A320HydraulicCircuitFactory::HYDRAULIC_TARGET_PRESSURE_PSI,
                     ),
@@ -6061,6 +6369,8 @@ impl A320Reversers {
                     Self::REVERSER_1_SECONDARY_VALVES_SUPPLY_POWER_BUS,
                 ),
                 ReverserAssembly::new(
+                    context,
+                    2,
                     Pressure::new::<psi>(
                         A320HydraulicCircuitFactory::HYDRAULIC_TARGET_PRESSURE_PSI,
                     ),
@@ -6174,13 +6484,14 @@ mod tests {
             engine::{leap_engine::LeapEngine, EngineFireOverheadPanel},
             failures::FailureType,
             hydraulic::{
+                anti_skid::RunwayContamination,
                 cargo_doors::{DoorControlState, HydraulicDoorController},
                 electrical_generator::TestGenerator,
             },
             landing_gear::{GearSystemState, LandingGear, LandingGearControlInterfaceUnitSet},
             shared::{
-                arinc429::Arinc429Word, EmergencyElectricalState, EmergencyGeneratorControlUnit,
-                LgciuId, PotentialOrigin,
+                arinc429::{Arinc429Word, SignStatus},
+                EmergencyElectricalState, EmergencyGeneratorControlUnit, LgciuId, PotentialOrigin,
             },
             simulation::{
                 test::{ReadByName, SimulationTestBed, TestBed, WriteByName},
@@ -6196,6 +6507,7 @@ mod tests {
                 length::foot,
                 mass_density::kilogram_per_cubic_meter,
                 ratio::{percent, ratio},
+                velocity::foot_per_second,
                 volume::liter,
             },
             ConstZero,
@@ -6428,7 +6740,7 @@ mod tests {
                         context,
                     ),
                     engine_fire_overhead: EngineFireOverheadPanel::new(context),
-                    landing_gear: LandingGear::new(context, false),
+                    landing_gear: A320LandingGearFactory::a320_landing_gear(context),
                     lgcius: LandingGearControlInterfaceUnitSet::new(
                         context,
                         ElectricalBusType::DirectCurrentEssential,
@@ -6670,6 +6982,8 @@ mod tests {
 
                 self.adirus.update(context);
 
+                self.landing_gear.update(context);
+
                 self.lgcius.update(
                     context,
                     &self.landing_gear,
@@ -6686,6 +7000,7 @@ mod tests {
                     &self.brake_fan_panel,
                     &self.engine_fire_overhead,
                     &self.lgcius,
+                    &self.landing_gear,
                     &self.emergency_electrical_overhead,
                     &self.electrical,
                     &self.pneumatics,
@@ -6777,6 +7092,14 @@ mod tests {
                 self.read_by_name("FWD_DOOR_CARGO_LOCKED")
             }
 
+            fn is_cargo_fwd_door_closed(&mut self) -> bool {
+                self.read_by_name("FWD_DOOR_CARGO_CLOSED")
+            }
+
+            fn is_cargo_fwd_door_not_locked(&mut self) -> bool {
+                self.read_by_name("FWD_DOOR_CARGO_NOT_LOCKED")
+            }
+
             fn is_cargo_fwd_door_locked_up(&self) -> bool {
                 self.query(|a| a.is_cargo_fwd_door_locked_up())
             }
@@ -6801,6 +7124,10 @@ mod tests {
                 self.read_by_name("HYD_YELLOW_SYSTEM_1_SECTION_PRESSURE")
             }
 
+            fn yellow_pressure_auxiliary(&mut self) -> Pressure {
+                self.read_by_name("HYD_YELLOW_AUXILIARY_1_SECTION_PRESSURE")
+            }
+
             fn get_yellow_reservoir_volume(&mut self) -> Volume {
                 self.read_by_name("HYD_YELLOW_RESERVOIR_LEVEL")
             }
@@ -7034,6 +7361,12 @@ mod tests {
                 self
             }
 
+            fn touches_down_with_sink_rate(mut self, sink_rate: Velocity) -> Self {
+                self.write_by_name("VELOCITY BODY Y", -sink_rate.get::<foot_per_second>());
+                self = self.run_one_tick();
+                self.on_the_ground_after_touchdown().run_one_tick()
+            }
+
             fn on_the_ground_after_touchdown(mut self) -> Self {
                 self.set_pressure_altitude(Length::new::<foot>(0.));
                 self.set_on_ground(true);
@@ -7100,6 +7433,16 @@ mod tests {
                 self
             }
 
+            fn set_rudder_pedal_steering_demand(mut self, pedal_ratio: Ratio) -> Self {
+                self.write_by_name("RUDDER_PEDAL_POSITION_RATIO", pedal_ratio.get::<ratio>());
+                self
+            }
+
+            fn set_tiller_pedal_disconnect(mut self, is_pressed: bool) -> Self {
+                self.write_by_name("TILLER_PEDAL_DISCONNECT", is_pressed);
+                self
+            }
+
             fn set_eng1_fire_button(mut self, is_active: bool) -> Self {
                 self.write_by_name("FIRE_BUTTON_ENG1", is_active);
                 self
@@ -7120,6 +7463,11 @@ mod tests {
                 self
             }
 
+            fn operate_cargo_door_hand_pump(mut self, is_operated: bool) -> Self {
+                self.write_by_name("HYD_CARGO_DOOR_HAND_PUMP_OPERATED", is_operated);
+                self
+            }
+
             fn set_pushback_state(mut self, is_pushed_back: bool) -> Self {
                 if is_pushed_back {
                     self.write_by_name("PUSHBACK STATE", 0.);
@@ -7245,6 +7593,21 @@ mod tests {
                 self
             }
 
+            fn rolling_on_runway(
+                mut self,
+                ground_speed: Velocity,
+                contamination: RunwayContamination,
+            ) -> Self {
+                self.write_by_name("TOTAL WEIGHT", Mass::new::<kilogram>(64000.));
+                self.write_by_name("GPS GROUND SPEED", ground_speed.get::<knot>());
+                self.write_by_name("SURFACE CONDITION", f64::from(contamination));
+                self
+            }
+
+            fn wheel_slip_ratio(&mut self, wheel_index: usize) -> Ratio {
+                self.read_by_name(&format!("WHEEL_SLIP_RATIO_{wheel_index}"))
+            }
+
             fn set_yellow_e_pump(mut self, is_auto: bool) -> Self {
                 self.write_by_name("OVHD_HYD_EPUMPY_PB_IS_AUTO", is_auto);
                 self
@@ -7482,6 +7845,46 @@ mod tests {
                 self
             }
 
+            fn set_autobrake_btv(mut self) -> Self {
+                self.write_by_name("OVHD_AUTOBRK_BTV_ON_IS_PRESSED", true);
+                self = self.run_one_tick();
+                self.write_by_name("OVHD_AUTOBRK_BTV_ON_IS_PRESSED", false);
+                self
+            }
+
+            fn set_btv_runway_data(mut self, runway_length: Length, exit_distance: Length) -> Self {
+                self.write_arinc429_by_name(
+                    "OANS_RWY_LENGTH",
+                    runway_length.get::<meter>(),
+                    SignStatus::NormalOperation,
+                );
+                self.write_arinc429_by_name(
+                    "OANS_BTV_REQ_STOPPING_DISTANCE",
+                    exit_distance.get::<meter>(),
+                    SignStatus::NormalOperation,
+                );
+                self.write_arinc429_by_name(
+                    "OANS_BTV_REMAINING_DIST_TO_EXIT",
+                    exit_distance.get::<meter>(),
+                    SignStatus::NormalOperation,
+                );
+                self
+            }
+
+            fn decelerating_at(mut self, acceleration: Acceleration) -> Self {
+                self.test_bed.set_long_acceleration(acceleration);
+                self
+            }
+
+            fn set_distance_to_runway_end(mut self, distance: Length) -> Self {
+                self.write_arinc429_by_name(
+                    "OANS_BTV_REMAINING_DIST_TO_RWY_END",
+                    distance.get::<meter>(),
+                    SignStatus::NormalOperation,
+                );
+                self
+            }
+
             fn set_deploy_ground_spoilers(mut self) -> Self {
                 self.write_by_name("SEC_1_GROUND_SPOILER_OUT", true);
                 self.write_by_name("SEC_2_GROUND_SPOILER_OUT", true);
@@ -7868,7 +8271,10 @@ mod tests {
 
             assert!(!test_bed.is_ptu_enabled());
 
-            test_bed = test_bed.rotates_on_runway().run_one_tick();
+            // Nose strut has to extend before weight on wheels is lost
+            test_bed = test_bed
+                .rotates_on_runway()
+                .run_waiting_for(Duration::from_secs(1));
             assert!(test_bed.is_ptu_enabled());
         }
 
@@ -9789,82 +10195,174 @@ mod tests {
         }
 
         #[test]
-        fn autobrakes_arming_according_to_set_variable() {
+        fn autobrakes_btv_does_not_arm_without_runway_data() {
             let mut test_bed = test_bed_on_ground_with()
                 .set_cold_dark_inputs()
-                .on_the_ground()
-                .set_park_brake(false)
-                .start_eng1(Ratio::new::<percent>(100.))
-                .start_eng2(Ratio::new::<percent>(100.))
-                .run_waiting_for(Duration::from_secs(10));
-
-            assert_eq!(test_bed.autobrake_mode(), AutobrakeMode::NONE);
-
-            // set autobrake to LOW
-            test_bed = test_bed
-                .set_autobrake_low_with_set_variable()
-                .run_waiting_for(Duration::from_secs(1));
-            assert_eq!(test_bed.autobrake_mode(), AutobrakeMode::LOW);
-
-            // using the set variable again is still resulting in LOW
-            // and not disarming
-            test_bed = test_bed
-                .set_autobrake_low_with_set_variable()
-                .run_waiting_for(Duration::from_secs(1));
-            assert_eq!(test_bed.autobrake_mode(), AutobrakeMode::LOW);
-
-            // set autobrake to MED
-            test_bed = test_bed
-                .set_autobrake_med_with_set_variable()
-                .run_waiting_for(Duration::from_secs(1));
-            assert_eq!(test_bed.autobrake_mode(), AutobrakeMode::MED);
+                .in_flight()
+                .set_gear_lever_up()
+                .run_waiting_for(Duration::from_secs(12));
 
-            // set autobrake to MAX
             test_bed = test_bed
-                .set_autobrake_max_with_set_variable()
+                .set_autobrake_btv()
                 .run_waiting_for(Duration::from_secs(1));
-            assert_eq!(test_bed.autobrake_mode(), AutobrakeMode::MAX);
 
-            // set autobrake to DISARMED
-            test_bed = test_bed
-                .set_autobrake_disarmed_with_set_variable()
-                .run_waiting_for(Duration::from_secs(1));
             assert_eq!(test_bed.autobrake_mode(), AutobrakeMode::NONE);
         }
 
         #[test]
-        fn autobrakes_disarms_if_green_pressure_low() {
+        fn autobrakes_btv_arms_in_flight_with_runway_data() {
             let mut test_bed = test_bed_on_ground_with()
                 .set_cold_dark_inputs()
                 .in_flight()
                 .set_gear_lever_up()
+                .set_btv_runway_data(Length::new::<meter>(3000.), Length::new::<meter>(2000.))
                 .run_waiting_for(Duration::from_secs(12));
 
-            assert_eq!(test_bed.autobrake_mode(), AutobrakeMode::NONE);
-
             test_bed = test_bed
-                .set_autobrake_low()
+                .set_autobrake_btv()
                 .run_waiting_for(Duration::from_secs(1));
 
-            assert_eq!(test_bed.autobrake_mode(), AutobrakeMode::LOW);
+            assert_eq!(test_bed.autobrake_mode(), AutobrakeMode::BTV);
 
             test_bed = test_bed
-                .set_ptu_state(false)
-                .stop_eng1()
-                .run_waiting_for(Duration::from_secs(20));
+                .set_autobrake_btv()
+                .run_waiting_for(Duration::from_secs(1));
 
             assert_eq!(test_bed.autobrake_mode(), AutobrakeMode::NONE);
         }
 
         #[test]
-        fn autobrakes_does_not_disarm_if_askid_off_but_sim_not_ready() {
+        fn autobrakes_rop_applies_max_braking_when_runway_overrun_predicted() {
             let mut test_bed = test_bed_on_ground_with()
                 .set_cold_dark_inputs()
-                .in_flight()
-                .sim_not_ready()
-                .set_gear_lever_up()
-                .run_waiting_for(Duration::from_secs(12));
-
+                .on_the_ground_after_touchdown()
+                .set_park_brake(false)
+                .start_eng1(Ratio::new::<percent>(100.))
+                .start_eng2(Ratio::new::<percent>(100.))
+                .rolling_on_runway(Velocity::new::<knot>(120.), RunwayContamination::Dry)
+                .decelerating_at(Acceleration::new::<meter_per_second_squared>(-1.))
+                .set_distance_to_runway_end(Length::new::<meter>(400.))
+                .run_waiting_for(Duration::from_secs(3));
+
+            test_bed = test_bed
+                .set_autobrake_low()
+                .set_deploy_ground_spoilers()
+                .run_waiting_for(Duration::from_secs(1));
+
+            assert_eq!(test_bed.autobrake_mode(), AutobrakeMode::LOW);
+            assert_gt!(
+                test_bed.get_brake_left_green_pressure(),
+                Pressure::new::<psi>(1000.)
+            );
+            assert_gt!(
+                test_bed.get_brake_right_green_pressure(),
+                Pressure::new::<psi>(1000.)
+            );
+        }
+
+        #[test]
+        fn autobrakes_rop_does_not_brake_with_enough_runway_left() {
+            let mut test_bed = test_bed_on_ground_with()
+                .set_cold_dark_inputs()
+                .on_the_ground_after_touchdown()
+                .set_park_brake(false)
+                .start_eng1(Ratio::new::<percent>(100.))
+                .start_eng2(Ratio::new::<percent>(100.))
+                .rolling_on_runway(Velocity::new::<knot>(120.), RunwayContamination::Dry)
+                .decelerating_at(Acceleration::new::<meter_per_second_squared>(-1.))
+                .set_distance_to_runway_end(Length::new::<meter>(3000.))
+                .run_waiting_for(Duration::from_secs(3));
+
+            test_bed = test_bed
+                .set_autobrake_low()
+                .set_deploy_ground_spoilers()
+                .run_waiting_for(Duration::from_secs(1));
+
+            // LOW mode delays its deceleration target, so no braking is expected yet
+            assert_eq!(test_bed.autobrake_mode(), AutobrakeMode::LOW);
+            assert_lt!(
+                test_bed.get_brake_left_green_pressure(),
+                Pressure::new::<psi>(50.)
+            );
+        }
+
+        #[test]
+        fn autobrakes_arming_according_to_set_variable() {
+            let mut test_bed = test_bed_on_ground_with()
+                .set_cold_dark_inputs()
+                .on_the_ground()
+                .set_park_brake(false)
+                .start_eng1(Ratio::new::<percent>(100.))
+                .start_eng2(Ratio::new::<percent>(100.))
+                .run_waiting_for(Duration::from_secs(10));
+
+            assert_eq!(test_bed.autobrake_mode(), AutobrakeMode::NONE);
+
+            // set autobrake to LOW
+            test_bed = test_bed
+                .set_autobrake_low_with_set_variable()
+                .run_waiting_for(Duration::from_secs(1));
+            assert_eq!(test_bed.autobrake_mode(), AutobrakeMode::LOW);
+
+            // using the set variable again is still resulting in LOW
+            // and not disarming
+            test_bed = test_bed
+                .set_autobrake_low_with_set_variable()
+                .run_waiting_for(Duration::from_secs(1));
+            assert_eq!(test_bed.autobrake_mode(), AutobrakeMode::LOW);
+
+            // set autobrake to MED
+            test_bed = test_bed
+                .set_autobrake_med_with_set_variable()
+                .run_waiting_for(Duration::from_secs(1));
+            assert_eq!(test_bed.autobrake_mode(), AutobrakeMode::MED);
+
+            // set autobrake to MAX
+            test_bed = test_bed
+                .set_autobrake_max_with_set_variable()
+                .run_waiting_for(Duration::from_secs(1));
+            assert_eq!(test_bed.autobrake_mode(), AutobrakeMode::MAX);
+
+            // set autobrake to DISARMED
+            test_bed = test_bed
+                .set_autobrake_disarmed_with_set_variable()
+                .run_waiting_for(Duration::from_secs(1));
+            assert_eq!(test_bed.autobrake_mode(), AutobrakeMode::NONE);
+        }
+
+        #[test]
+        fn autobrakes_disarms_if_green_pressure_low() {
+            let mut test_bed = test_bed_on_ground_with()
+                .set_cold_dark_inputs()
+                .in_flight()
+                .set_gear_lever_up()
+                .run_waiting_for(Duration::from_secs(12));
+
+            assert_eq!(test_bed.autobrake_mode(), AutobrakeMode::NONE);
+
+            test_bed = test_bed
+                .set_autobrake_low()
+                .run_waiting_for(Duration::from_secs(1));
+
+            assert_eq!(test_bed.autobrake_mode(), AutobrakeMode::LOW);
+
+            test_bed = test_bed
+                .set_ptu_state(false)
+                .stop_eng1()
+                .run_waiting_for(Duration::from_secs(20));
+
+            assert_eq!(test_bed.autobrake_mode(), AutobrakeMode::NONE);
+        }
+
+        #[test]
+        fn autobrakes_does_not_disarm_if_askid_off_but_sim_not_ready() {
+            let mut test_bed = test_bed_on_ground_with()
+                .set_cold_dark_inputs()
+                .in_flight()
+                .sim_not_ready()
+                .set_gear_lever_up()
+                .run_waiting_for(Duration::from_secs(12));
+
             assert_eq!(test_bed.autobrake_mode(), AutobrakeMode::NONE);
 
             test_bed = test_bed
@@ -10313,7 +10811,10 @@ mod tests {
 
             assert!(!test_bed.query(|a| a.is_blue_epump_controller_pressurising()));
 
-            test_bed = test_bed.rotates_on_runway().run_one_tick();
+            // Nose strut has to extend before weight on wheels is lost
+            test_bed = test_bed
+                .rotates_on_runway()
+                .run_waiting_for(Duration::from_secs(1));
 
             assert!(test_bed.query(|a| a.is_blue_epump_controller_pressurising()));
         }
@@ -11417,6 +11918,178 @@ mod tests {
             assert_le!(test_bed.cargo_fwd_door_position(), 0.);
         }
 
+        #[test]
+        fn cargo_door_hand_pump_opens_the_door_without_electrical_power() {
+            let mut test_bed = test_bed_on_ground_with()
+                .engines_off()
+                .on_the_ground()
+                .set_cold_dark_inputs()
+                .ac_ground_service_lost()
+                .dc_ground_service_lost()
+                .run_one_tick();
+
+            test_bed = test_bed.open_fwd_cargo_door().run_waiting_for(
+                HydraulicDoorController::DELAY_UNLOCK_TO_HYDRAULIC_CONTROL + Duration::from_secs(5),
+            );
+
+            assert!(!test_bed.is_cargo_fwd_door_locked_down());
+            assert!(!test_bed.is_cargo_fwd_door_locked_up());
+
+            test_bed = test_bed
+                .operate_cargo_door_hand_pump(true)
+                .run_waiting_for(Duration::from_secs(160));
+
+            assert!(test_bed.is_cargo_fwd_door_locked_up());
+            assert_gt!(test_bed.cargo_fwd_door_position(), 0.85);
+            assert_gt!(
+                test_bed.yellow_pressure_auxiliary(),
+                Pressure::new::<psi>(500.)
+            );
+            assert_le!(test_bed.yellow_pressure(), Pressure::new::<psi>(50.));
+        }
+
+        #[test]
+        fn cargo_door_hand_pump_closes_the_door_without_electrical_power() {
+            let mut test_bed = test_bed_on_ground_with()
+                .engines_off()
+                .on_the_ground()
+                .set_cold_dark_inputs()
+                .run_one_tick();
+
+            test_bed = test_bed
+                .open_fwd_cargo_door()
+                .run_waiting_for(Duration::from_secs_f64(40.));
+            assert!(test_bed.is_cargo_fwd_door_locked_up());
+
+            test_bed = test_bed
+                .ac_ground_service_lost()
+                .dc_ground_service_lost()
+                .run_waiting_for(Duration::from_secs_f64(30.));
+
+            test_bed = test_bed
+                .close_fwd_cargo_door()
+                .operate_cargo_door_hand_pump(true)
+                .run_waiting_for(Duration::from_secs_f64(120.));
+
+            assert!(test_bed.is_cargo_fwd_door_locked_down());
+            assert!(test_bed.is_cargo_fwd_door_closed());
+            assert!(!test_bed.is_cargo_fwd_door_not_locked());
+        }
+
+        #[test]
+        fn cargo_door_partially_open_is_not_locked() {
+            let mut test_bed = test_bed_on_ground_with()
+                .engines_off()
+                .on_the_ground()
+                .set_cold_dark_inputs()
+                .run_one_tick();
+
+            assert!(test_bed.is_cargo_fwd_door_closed());
+            assert!(!test_bed.is_cargo_fwd_door_not_locked());
+
+            test_bed = test_bed.open_fwd_cargo_door().run_waiting_for(
+                HydraulicDoorController::DELAY_UNLOCK_TO_HYDRAULIC_CONTROL + Duration::from_secs(1),
+            );
+
+            assert!(!test_bed.is_cargo_fwd_door_locked_down());
+            assert!(test_bed.is_cargo_fwd_door_not_locked());
+
+            test_bed = test_bed.run_waiting_for(Duration::from_secs_f64(35.));
+
+            assert!(test_bed.is_cargo_fwd_door_locked_up());
+            assert!(!test_bed.is_cargo_fwd_door_closed());
+            assert!(!test_bed.is_cargo_fwd_door_not_locked());
+        }
+
+        #[test]
+        fn cargo_door_with_stuck_latch_cannot_be_opened() {
+            let mut test_bed = test_bed_on_ground_with()
+                .engines_off()
+                .on_the_ground()
+                .set_cold_dark_inputs()
+                .run_one_tick();
+
+            test_bed.fail(FailureType::CargoDoorLatchStuck(CargoDoorId::Forward));
+
+            test_bed = test_bed
+                .open_fwd_cargo_door()
+                .run_waiting_for(Duration::from_secs_f64(30.));
+
+            assert!(test_bed.is_cargo_fwd_door_locked_down());
+            assert_eq!(test_bed.cargo_fwd_door_position(), 0.);
+            assert!(!test_bed.query(|a| a.is_cargo_powering_yellow_epump()));
+        }
+
+        #[test]
+        fn cargo_door_closed_with_stuck_latch_is_not_locked() {
+            let mut test_bed = test_bed_on_ground_with()
+                .engines_off()
+                .on_the_ground()
+                .set_cold_dark_inputs()
+                .run_one_tick();
+
+            test_bed = test_bed
+                .open_fwd_cargo_door()
+                .run_waiting_for(Duration::from_secs_f64(40.));
+            assert!(test_bed.is_cargo_fwd_door_locked_up());
+
+            test_bed.fail(FailureType::CargoDoorLatchStuck(CargoDoorId::Forward));
+
+            test_bed = test_bed
+                .close_fwd_cargo_door()
+                .run_waiting_for(Duration::from_secs_f64(30.));
+
+            assert!(test_bed.is_cargo_fwd_door_closed());
+            assert!(!test_bed.is_cargo_fwd_door_locked_down());
+            assert!(test_bed.is_cargo_fwd_door_not_locked());
+        }
+
+        #[test]
+        fn cargo_door_with_hydraulic_lock_does_not_move() {
+            let mut test_bed = test_bed_on_ground_with()
+                .engines_off()
+                .on_the_ground()
+                .set_cold_dark_inputs()
+                .run_one_tick();
+
+            test_bed.fail(FailureType::CargoDoorHydraulicLock(CargoDoorId::Forward));
+
+            test_bed = test_bed
+                .open_fwd_cargo_door()
+                .run_waiting_for(Duration::from_secs_f64(30.));
+
+            assert!(!test_bed.is_cargo_fwd_door_locked_up());
+            assert_lt!(test_bed.cargo_fwd_door_position(), 0.1);
+        }
+
+        #[test]
+        fn open_cargo_door_with_hydraulic_lock_stays_open_until_released() {
+            let mut test_bed = test_bed_on_ground_with()
+                .engines_off()
+                .on_the_ground()
+                .set_cold_dark_inputs()
+                .run_one_tick();
+
+            test_bed = test_bed
+                .open_fwd_cargo_door()
+                .run_waiting_for(Duration::from_secs_f64(40.));
+            assert!(test_bed.is_cargo_fwd_door_locked_up());
+
+            test_bed.fail(FailureType::CargoDoorHydraulicLock(CargoDoorId::Forward));
+
+            test_bed = test_bed
+                .close_fwd_cargo_door()
+                .run_waiting_for(Duration::from_secs_f64(30.));
+
+            assert_gt!(test_bed.cargo_fwd_door_position(), 0.85);
+
+            test_bed.unfail(FailureType::CargoDoorHydraulicLock(CargoDoorId::Forward));
+            test_bed = test_bed.run_waiting_for(Duration::from_secs_f64(30.));
+
+            assert!(test_bed.is_cargo_fwd_door_locked_down());
+            assert!(test_bed.is_cargo_fwd_door_closed());
+        }
+
         #[test]
         fn nose_steering_responds_to_tiller_demand_if_yellow_pressure_and_engines() {
             let mut test_bed = test_bed_on_ground_with()
@@ -11539,6 +12212,148 @@ mod tests {
             assert_ge!(test_bed.nose_steering_position().get::<degree>(), -6.5);
         }
 
+        #[test]
+        fn nose_steering_ignores_rudder_pedals_when_pedal_disconnect_pressed() {
+            let mut test_bed = test_bed_on_ground_with()
+                .engines_off()
+                .on_the_ground()
+                .set_cold_dark_inputs()
+                .start_eng1(Ratio::new::<percent>(80.))
+                .start_eng2(Ratio::new::<percent>(80.))
+                .run_waiting_for(Duration::from_secs_f64(1.));
+
+            test_bed = test_bed
+                .set_rudder_pedal_steering_demand(Ratio::new::<ratio>(1.))
+                .run_waiting_for(Duration::from_secs_f64(2.));
+
+            assert_ge!(test_bed.nose_steering_position().get::<degree>(), 5.5);
+
+            test_bed = test_bed
+                .set_tiller_pedal_disconnect(true)
+                .run_waiting_for(Duration::from_secs_f64(4.));
+
+            assert_le!(test_bed.nose_steering_position().get::<degree>().abs(), 0.5);
+        }
+
+        #[test]
+        fn nose_steering_still_available_with_one_bscu_steering_channel_failed() {
+            let mut test_bed = test_bed_on_ground_with()
+                .engines_off()
+                .on_the_ground()
+                .set_cold_dark_inputs()
+                .start_eng1(Ratio::new::<percent>(80.))
+                .start_eng2(Ratio::new::<percent>(80.))
+                .run_waiting_for(Duration::from_secs_f64(1.));
+
+            test_bed.fail(FailureType::SteeringControlChannel(1));
+
+            test_bed = test_bed
+                .set_tiller_demand(Ratio::new::<ratio>(1.))
+                .run_waiting_for(Duration::from_secs_f64(5.));
+
+            assert_ge!(test_bed.nose_steering_position().get::<degree>(), 73.5);
+        }
+
+        #[test]
+        fn nose_steering_lost_with_both_bscu_steering_channels_failed() {
+            let mut test_bed = test_bed_on_ground_with()
+                .engines_off()
+                .on_the_ground()
+                .set_cold_dark_inputs()
+                .start_eng1(Ratio::new::<percent>(80.))
+                .start_eng2(Ratio::new::<percent>(80.))
+                .run_waiting_for(Duration::from_secs_f64(1.));
+
+            test_bed.fail(FailureType::SteeringControlChannel(1));
+            test_bed.fail(FailureType::SteeringControlChannel(2));
+
+            test_bed = test_bed
+                .set_tiller_demand(Ratio::new::<ratio>(1.))
+                .run_waiting_for(Duration::from_secs_f64(5.));
+
+            assert_le!(test_bed.nose_steering_position().get::<degree>().abs(), 0.1);
+        }
+
+        fn min_max_left_brake_pressure_while_braking(
+            mut test_bed: A320HydraulicsTestBed,
+        ) -> (Pressure, Pressure) {
+            test_bed = test_bed
+                .set_left_brake(Ratio::new::<ratio>(1.))
+                .set_right_brake(Ratio::new::<ratio>(1.))
+                .run_waiting_for(Duration::from_secs_f64(1.));
+
+            let mut min_pressure = Pressure::new::<psi>(3000.);
+            let mut max_pressure = Pressure::default();
+            for _ in 0..50 {
+                test_bed = test_bed.run_waiting_for(Duration::from_millis(100));
+
+                let pressure = test_bed.get_brake_left_green_pressure();
+                min_pressure = min_pressure.min(pressure);
+                max_pressure = max_pressure.max(pressure);
+            }
+
+            (min_pressure, max_pressure)
+        }
+
+        fn test_bed_rolling_with_brakes_released(
+            ground_speed: Velocity,
+            contamination: RunwayContamination,
+        ) -> A320HydraulicsTestBed {
+            test_bed_on_ground_with()
+                .engines_off()
+                .on_the_ground_after_touchdown()
+                .set_cold_dark_inputs()
+                .start_eng1(Ratio::new::<percent>(80.))
+                .start_eng2(Ratio::new::<percent>(80.))
+                .set_park_brake(false)
+                .set_anti_skid(true)
+                .rolling_on_runway(ground_speed, contamination)
+                .run_waiting_for(Duration::from_secs_f64(5.))
+        }
+
+        #[test]
+        fn max_manual_braking_on_dry_runway_keeps_full_brake_pressure() {
+            let test_bed = test_bed_rolling_with_brakes_released(
+                Velocity::new::<knot>(100.),
+                RunwayContamination::Dry,
+            );
+
+            let (min_pressure, _) = min_max_left_brake_pressure_while_braking(test_bed);
+
+            assert_gt!(min_pressure, Pressure::new::<psi>(2000.));
+        }
+
+        #[test]
+        fn max_manual_braking_on_wet_runway_cycles_brake_pressure_with_anti_skid() {
+            let test_bed = test_bed_rolling_with_brakes_released(
+                Velocity::new::<knot>(100.),
+                RunwayContamination::Wet,
+            );
+
+            let (min_pressure, max_pressure) = min_max_left_brake_pressure_while_braking(test_bed);
+
+            assert_gt!(max_pressure, Pressure::new::<psi>(1000.));
+            assert_lt!(min_pressure, Pressure::new::<psi>(500.));
+        }
+
+        #[test]
+        fn max_manual_braking_on_icy_runway_locks_wheels_without_anti_skid() {
+            let mut test_bed = test_bed_rolling_with_brakes_released(
+                Velocity::new::<knot>(100.),
+                RunwayContamination::Ice,
+            )
+            .set_anti_skid(false)
+            .set_left_brake(Ratio::new::<ratio>(1.))
+            .set_right_brake(Ratio::new::<ratio>(1.))
+            .run_waiting_for(Duration::from_secs_f64(3.));
+
+            assert_gt!(
+                test_bed.get_brake_left_yellow_pressure(),
+                Pressure::new::<psi>(900.)
+            );
+            assert_gt!(test_bed.wheel_slip_ratio(1), Ratio::new::<ratio>(0.99));
+        }
+
         #[test]
         fn ptu_pressurise_green_from_yellow_edp() {
             let mut test_bed = test_bed_on_ground_with()
@@ -11984,10 +12799,12 @@ mod tests {
 
         #[test]
         fn nose_wheel_steers_with_pushback_tug() {
+            // Parking brake released, as braking while pushed would break the towbar shear pin
             let mut test_bed = test_bed_on_ground_with()
                 .engines_off()
                 .on_the_ground()
                 .set_cold_dark_inputs()
+                .set_park_brake(false)
                 .run_one_tick();
 
             test_bed = test_bed
@@ -12662,6 +13479,108 @@ mod tests {
             assert!(test_bed.is_all_gears_really_down());
         }
 
+        #[test]
+        fn gear_cycles_normally_with_one_lgciu_channel_sensor_failed() {
+            let mut test_bed = test_bed_on_ground_with().set_cold_dark_inputs().in_flight();
+
+            test_bed.fail(FailureType::GearProxSensorDamage(
+                systems::shared::ProximityDetectorId::UplockGearLeft1,
+            ));
+            test_bed.fail(FailureType::GearProxSensorDamage(
+                systems::shared::ProximityDetectorId::DownlockGearLeft1,
+            ));
+
+            // Isolated once channel 1 still sees the retracted gear in transit after a transition timeout
+            test_bed = test_bed.run_waiting_for(Duration::from_secs_f64(25.));
+            let lgciu1_sensor_fault: bool = test_bed.read_by_name("LGCIU_1_PROX_SENSOR_FAULT");
+            let lgciu2_sensor_fault: bool = test_bed.read_by_name("LGCIU_2_PROX_SENSOR_FAULT");
+            assert!(lgciu1_sensor_fault);
+            assert!(!lgciu2_sensor_fault);
+
+            for _ in 0..2 {
+                test_bed = test_bed
+                    .set_gear_lever_up()
+                    .run_waiting_for(Duration::from_secs_f64(25.));
+                assert_eq!(test_bed.gear_system_state(), GearSystemState::AllUpLocked);
+
+                test_bed = test_bed
+                    .set_gear_lever_down()
+                    .run_waiting_for(Duration::from_secs_f64(25.));
+                assert_eq!(test_bed.gear_system_state(), GearSystemState::AllDownLocked);
+            }
+        }
+
+        #[test]
+        fn gear_not_damaged_by_normal_touchdown() {
+            let mut test_bed = test_bed_on_ground_with()
+                .set_cold_dark_inputs()
+                .in_flight()
+                .set_gear_lever_down()
+                .run_waiting_for(Duration::from_secs_f64(5.))
+                .touches_down_with_sink_rate(Velocity::new::<foot_per_second>(5.));
+
+            let left_damage: f64 = test_bed.read_by_name("GEAR_LEFT_DAMAGE");
+            let right_damage: f64 = test_bed.read_by_name("GEAR_RIGHT_DAMAGE");
+            assert!(left_damage == 0.);
+            assert!(right_damage == 0.);
+            assert_eq!(test_bed.gear_system_state(), GearSystemState::AllDownLocked);
+        }
+
+        #[test]
+        fn gear_damaged_by_hard_landing_stays_downlocked() {
+            let mut test_bed = test_bed_on_ground_with()
+                .set_cold_dark_inputs()
+                .in_flight()
+                .set_gear_lever_down()
+                .run_waiting_for(Duration::from_secs_f64(5.))
+                .touches_down_with_sink_rate(Velocity::new::<foot_per_second>(12.));
+
+            let left_damage: f64 = test_bed.read_by_name("GEAR_LEFT_DAMAGE");
+            let nose_damage: f64 = test_bed.read_by_name("GEAR_CENTER_DAMAGE");
+            assert!(left_damage == 1.);
+            assert!(nose_damage == 1.);
+
+            test_bed = test_bed.run_waiting_for(Duration::from_secs_f64(2.));
+            assert!(test_bed.is_all_gears_really_down());
+        }
+
+        #[test]
+        fn gear_collapses_on_very_hard_landing() {
+            let mut test_bed = test_bed_on_ground_with()
+                .set_cold_dark_inputs()
+                .in_flight()
+                .set_gear_lever_down()
+                .run_waiting_for(Duration::from_secs_f64(5.))
+                .touches_down_with_sink_rate(Velocity::new::<foot_per_second>(20.));
+
+            let left_damage: f64 = test_bed.read_by_name("GEAR_LEFT_DAMAGE");
+            assert!(left_damage == 2.);
+
+            test_bed = test_bed.run_waiting_for(Duration::from_secs_f64(2.));
+            assert!(!test_bed.query(|a| a
+                .hydraulics
+                .gear_system()
+                .is_wheel_id_down_and_locked(GearWheel::LEFT, LgciuId::Lgciu1)));
+        }
+
+        #[test]
+        fn gear_doors_damaged_when_extending_above_vlo() {
+            let mut test_bed = test_bed_in_flight_with()
+                .set_cold_dark_inputs()
+                .in_flight()
+                .run_one_tick();
+
+            test_bed.set_indicated_airspeed(Velocity::new::<knot>(270.));
+            test_bed = test_bed
+                .set_gear_lever_down()
+                .run_waiting_for(Duration::from_secs_f64(5.));
+
+            let left_door_damage: f64 = test_bed.read_by_name("GEAR_DOOR_LEFT_DAMAGE");
+            let left_gear_damage: f64 = test_bed.read_by_name("GEAR_LEFT_DAMAGE");
+            assert!(left_door_damage == 1.);
+            assert!(left_gear_damage == 0.);
+        }
+
         #[test]
         fn gear_stays_downlocked_when_door_sensors_fails() {
             let mut test_bed = test_bed_on_ground_with()
@@ -23,7 +23,7 @@ use electrical::{
     A320Electrical, A320ElectricalOverheadPanel, A320EmergencyElectricalOverheadPanel,
     APU_START_MOTOR_BUS_TYPE,
 };
-use hydraulic::{A320Hydraulic, A320HydraulicOverheadPanel};
+use hydraulic::{A320Hydraulic, A320HydraulicOverheadPanel, A320LandingGearFactory};
 use navigation::{A320AirDataInertialReferenceSystemBuilder, A320RadioAltimeters};
 use power_consumption::A320PowerConsumption;
 use systems::navigation::ils::MultiModeReceiverShim;
@@ -32,7 +32,10 @@ use systems::{
     surveillance::egpws::EnhancedGroundProximityWarningComputer as EnhancedGroundProximityWarningComputer2,
 };
 use systems::{hydraulic::brake::BrakeFanPanel, simulation::InitContext};
-use uom::si::{f64::Length, length::nautical_mile};
+use uom::si::{
+    f64::Length,
+    length::{meter, nautical_mile},
+};
 
 use systems::{
     air_starter_unit::AirStarterUnit,
@@ -42,7 +45,10 @@ use systems::{
         AuxiliaryPowerUnitOverheadPanel,
     },
     electrical::{Electricity, ElectricitySource, ExternalPowerSource},
-    engine::{leap_engine::LeapEngine, reverser_thrust::ReverserForce, EngineFireOverheadPanel},
+    engine::{
+        leap_engine::LeapEngine, native_engine::NativeEngineInputs, reverser_thrust::ReverserForce,
+        EngineFireOverheadPanel,
+    },
     hydraulic::brake_circuit::AutobrakePanel,
     landing_gear::{LandingGear, LandingGearControlInterfaceUnitSet},
     navigation::adirs::{
@@ -123,7 +129,7 @@ impl A320 {
             hydraulic_overhead: A320HydraulicOverheadPanel::new(context),
             autobrake_panel: AutobrakePanel::new(context),
             brake_fan_panel: BrakeFanPanel::new(context),
-            landing_gear: LandingGear::new(context, false),
+            landing_gear: A320LandingGearFactory::a320_landing_gear(context),
             pneumatic: A320Pneumatic::new(context),
             radio_altimeters: A320RadioAltimeters::new(context),
             egpwc: EnhancedGroundProximityWarningComputer::new(
@@ -145,9 +151,21 @@ impl A320 {
             ),
             egpws_electrical_harness: A320EgpwsElectricalHarness::new(context),
             mmr: MultiModeReceiverShim::new(context),
-            reverse_thrust: ReverserForce::new(context),
+            reverse_thrust: ReverserForce::new(context, Length::new::<meter>(5.)),
         }
     }
+
+    /// The engines run before the pneumatic system and the generators have been updated,
+    /// thus the loads and commands of the previous update are used.
+    fn native_engine_inputs(&self, number: usize) -> NativeEngineInputs {
+        NativeEngineInputs::new(
+            self.pneumatic.engine_fuel_is_on(number),
+            self.pneumatic.engine_starter_torque(number),
+            self.pneumatic.engine_bleed_air_extraction(number),
+            self.electrical
+                .engine_generator_shaft_power_extraction(number),
+        )
+    }
 }
 impl Aircraft for A320 {
     fn update_before_power_distribution(
@@ -160,13 +178,8 @@ impl Aircraft for A320 {
             &self.apu_overhead,
             false, // Todo: fire detection system
             &self.apu_fire_overhead,
-            self.pneumatic_overhead.apu_bleed_is_on(),
-            // This will be replaced when integrating the whole electrical system.
-            // For now we use the same logic as found in the JavaScript code; ignoring whether or not
-            // the engine generators are supplying electricity.
-            self.electrical_overhead.apu_generator_is_on()
-                && !(self.electrical_overhead.external_power_is_on()
-                    && self.electrical_overhead.external_power_is_available()),
+            self.pneumatic_overhead.apu_bleed_is_on() && !self.pneumatic.apu_bleed_is_isolated(),
+            self.pneumatic.apu_bleed_air_flow(),
             self.pneumatic.apu_bleed_air_valve(),
             self.fuel.left_inner_tank_has_fuel_remaining(),
         );
@@ -190,6 +203,15 @@ impl Aircraft for A320 {
             .update_after_electrical(&self.electrical, electricity, &self.apu);
         self.emergency_electrical_overhead
             .update_after_electrical(context, &self.electrical);
+        self.fuel.update(
+            context,
+            self.engine_1.heat_rejected_to_fuel() + self.engine_2.heat_rejected_to_fuel(),
+        );
+        self.engine_1
+            .update(context, &self.fuel, self.native_engine_inputs(1));
+        self.engine_2
+            .update(context, &self.fuel, self.native_engine_inputs(2));
+
         self.payload.update(context);
         self.airframe
             .update(&self.fuel, &self.payload, &self.payload);
@@ -202,7 +224,10 @@ impl Aircraft for A320 {
         );
         self.apu_overhead.update_after_apu(&self.apu);
 
-        self.asu.update();
+        self.asu
+            .update(context, self.pneumatic.asu_bleed_air_flow());
+
+        self.landing_gear.update(context);
 
         self.lgcius.update(
             context,
@@ -222,6 +247,7 @@ impl Aircraft for A320 {
             &self.brake_fan_panel,
             &self.engine_fire_overhead,
             &self.lgcius,
+            &self.landing_gear,
             &self.emergency_electrical_overhead,
             &self.electrical,
             &self.pneumatic,
@@ -4,7 +4,7 @@ use uom::si::{
     f64::*,
     length::foot,
     pressure::psi,
-    ratio::{percent, ratio},
+    ratio::ratio,
     thermodynamic_temperature::degree_celsius,
     velocity::foot_per_minute,
     volume::{cubic_meter, gallon},
@@ -12,27 +12,31 @@ use uom::si::{
 
 use systems::{
     accept_iterable,
-    air_conditioning::PackFlowControllers,
+    air_conditioning::{Channel, PackFlowControllers},
+    engine::start_controller::{EngineStartCharacteristics, EngineStartController},
+    failures::{Failure, FailureType},
     overhead::{AutoOffFaultPushButton, OnOffFaultPushButton},
     pneumatic::{
-        valve::*, BleedMonitoringComputerChannelOperationMode,
-        BleedMonitoringComputerIsAliveSignal, BleedTemperatureSensor, CompressionChamber,
-        ControllablePneumaticValve, CrossBleedValveSelectorKnob, CrossBleedValveSelectorMode,
-        DifferentialPressureTransducer, EngineCompressionChamberController, EngineModeSelector,
-        EngineState, PneumaticContainer, PneumaticPipe, PneumaticValveSignal, Precooler,
-        PressureTransducer, PressurisedReservoirWithExhaustValve, PressurizeableReservoir,
-        SolenoidSignal, TargetPressureTemperatureSignal, VariableVolumeContainer,
-        WingAntiIcePushButton, WingAntiIceSelected,
+        leak_detection::BleedLeakDetection, valve::*, BleedDuctLeak,
+        BleedMonitoringComputerChannelOperationMode, BleedMonitoringComputerIsAliveSignal,
+        BleedTemperatureSensor, CompressionChamber, ControllablePneumaticValve,
+        CrossBleedValveSelectorKnob, CrossBleedValveSelectorMode, DifferentialPressureTransducer,
+        EngineCompressionChamberController, EngineModeSelector, EngineState, PneumaticContainer,
+        PneumaticPipe, PneumaticValveSignal, Precooler, PressureTransducer,
+        PressurisedReservoirWithExhaustValve, PressurizeableReservoir, SolenoidSignal,
+        TargetPressureTemperatureSignal, VariableVolumeContainer, WingAntiIcePushButton,
+        WingAntiIceSelected,
     },
     shared::{
-        pid::PidController, update_iterator::MaxStepLoop, ControllerSignal, DelayedTrueLogicGate,
-        ElectricalBusType, ElectricalBuses, EngineBleedPushbutton, EngineCorrectedN1,
-        EngineCorrectedN2, EngineFirePushButtons, EngineStartState, HydraulicColor,
+        pid::PidController, update_iterator::MaxStepLoop, BleedDuctZone, ControllerSignal,
+        DelayedTrueLogicGate, ElectricalBusType, ElectricalBuses, EngineBleedPushbutton,
+        EngineCorrectedN1, EngineCorrectedN2, EngineExhaustGasTemperature, EngineFirePushButtons,
+        EngineStartState, EngineUncorrectedN2, FireDetectionLoopID, HydraulicColor,
         LgciuWeightOnWheels, PackFlowValveState, PneumaticBleed, PneumaticValve,
         ReservoirAirPressure,
     },
     simulation::{
-        InitContext, Read, Reader, SimulationElement, SimulationElementVisitor, SimulatorReader,
+        InitContext, Reader, SimulationElement, SimulationElementVisitor, SimulatorReader,
         SimulatorWriter, UpdateContext, VariableIdentifier, Write,
     },
     valve_signal_implementation,
@@ -101,10 +105,14 @@ pub struct A320Pneumatic {
     cross_bleed_valve: CrossBleedValve,
 
     fadec: FullAuthorityDigitalEngineControl,
+    engine_start_controllers: [EngineStartController; 2],
     engine_starter_valve_controllers: [EngineStarterValveController; 2],
 
     apu_compression_chamber: CompressionChamber,
     apu_bleed_air_valve: DefaultValve,
+    apu_bleed_duct_leak: BleedDuctLeak,
+
+    leak_detection: A320BleedLeakDetection,
 
     air_starter_unit_compression_chamber: CompressionChamber,
     air_starter_unit_bleed_air_valve: PurelyPneumaticValve,
@@ -125,6 +133,7 @@ pub struct A320Pneumatic {
 }
 impl A320Pneumatic {
     const PNEUMATIC_SIM_MAX_TIME_STEP: Duration = Duration::from_millis(10);
+    const APU_BLEED_DUCT_LEAK_SPEED: f64 = 1.;
 
     pub fn new(context: &mut InitContext) -> Self {
         Self {
@@ -154,14 +163,22 @@ impl A320Pneumatic {
                 ),
                 EngineBleedAirSystem::new(context, 2, ElectricalBusType::DirectCurrent(2)),
             ],
-            cross_bleed_valve: CrossBleedValve::new(Ratio::new::<ratio>(0.4)),
+            cross_bleed_valve: CrossBleedValve::new(1, Ratio::new::<ratio>(0.4)),
             fadec: FullAuthorityDigitalEngineControl::new(context),
+            engine_start_controllers: [1, 2].map(|number| {
+                EngineStartController::new(context, number, EngineStartCharacteristics::leap_1a26())
+            }),
             engine_starter_valve_controllers: [
-                EngineStarterValveController::new(1),
-                EngineStarterValveController::new(2),
+                EngineStarterValveController::new(),
+                EngineStarterValveController::new(),
             ],
             apu_compression_chamber: CompressionChamber::new(Volume::new::<cubic_meter>(5.)),
             apu_bleed_air_valve: DefaultValve::new_closed(),
+            apu_bleed_duct_leak: BleedDuctLeak::new(
+                BleedDuctZone::Apu,
+                Self::APU_BLEED_DUCT_LEAK_SPEED,
+            ),
+            leak_detection: A320BleedLeakDetection::new(context),
             air_starter_unit_compression_chamber: CompressionChamber::new(
                 Volume::new::<cubic_meter>(5.),
             ),
@@ -220,7 +237,10 @@ impl A320Pneumatic {
     pub(crate) fn update(
         &mut self,
         context: &UpdateContext,
-        engines: [&(impl EngineCorrectedN1 + EngineCorrectedN2); 2],
+        engines: [&(impl EngineCorrectedN1
+              + EngineCorrectedN2
+              + EngineUncorrectedN2
+              + EngineExhaustGasTemperature); 2],
         overhead_panel: &A320PneumaticOverheadPanel,
         engine_fire_push_buttons: &impl EngineFirePushButtons,
         apu: &impl ControllerSignal<TargetPressureTemperatureSignal>,
@@ -247,7 +267,10 @@ impl A320Pneumatic {
     pub(crate) fn update_physics(
         &mut self,
         context: &UpdateContext,
-        engines: [&(impl EngineCorrectedN1 + EngineCorrectedN2); 2],
+        engines: [&(impl EngineCorrectedN1
+              + EngineCorrectedN2
+              + EngineUncorrectedN2
+              + EngineExhaustGasTemperature); 2],
         overhead_panel: &A320PneumaticOverheadPanel,
         engine_fire_push_buttons: &impl EngineFirePushButtons,
         apu: &impl ControllerSignal<TargetPressureTemperatureSignal>,
@@ -268,6 +291,7 @@ impl A320Pneumatic {
                 &self.cross_bleed_valve,
                 &self.fadec,
                 &self.wing_anti_ice,
+                &self.leak_detection,
             );
 
             // I am not exactly sure if both BMCs should actually control this valve all the time.
@@ -280,8 +304,27 @@ impl A320Pneumatic {
         bmc_one.check_for_failure(bmc_two);
         bmc_two.check_for_failure(bmc_one);
 
-        for controller in self.engine_starter_valve_controllers.iter_mut() {
-            controller.update(&self.fadec);
+        for (start_controller, engine_system) in self
+            .engine_start_controllers
+            .iter_mut()
+            .zip(&self.engine_systems)
+        {
+            start_controller.update(
+                context,
+                self.fadec.engine_state(engine_system.number),
+                self.fadec.engine_mode_selector(),
+                engines[engine_system.number - 1],
+                engine_system.engine_starter_container_pressure(),
+                engine_system.engine_starter_valve_is_open(),
+            );
+        }
+
+        for (controller, start_controller) in self
+            .engine_starter_valve_controllers
+            .iter_mut()
+            .zip(&self.engine_start_controllers)
+        {
+            controller.update(start_controller);
         }
 
         for (engine_system, hydraulic_valve) in self
@@ -326,6 +369,9 @@ impl A320Pneumatic {
             &mut self.apu_compression_chamber,
             left_system,
         );
+        // The APU bleed duct joins the crossbleed duct on the left side
+        self.apu_bleed_duct_leak
+            .update_move_fluid(context, left_system);
         self.air_starter_unit_bleed_air_valve.update_move_fluid(
             context,
             &mut self.air_starter_unit_compression_chamber,
@@ -335,6 +381,9 @@ impl A320Pneumatic {
         self.cross_bleed_valve
             .update_move_fluid(context, left_system, right_system);
 
+        self.leak_detection
+            .update(context, &self.engine_systems, &self.apu_bleed_duct_leak);
+
         self.green_hydraulic_reservoir_with_valve
             .update_flow_through_valve(context, &mut self.hydraulic_reservoir_bleed_air_pipe);
         self.blue_hydraulic_reservoir_with_valve
@@ -359,6 +408,30 @@ impl A320Pneumatic {
         &mut self.apu_bleed_air_valve
     }
 
+    pub fn apu_bleed_air_flow(&self) -> MassRate {
+        self.apu_bleed_air_valve.fluid_flow()
+    }
+
+    pub fn asu_bleed_air_flow(&self) -> MassRate {
+        self.air_starter_unit_bleed_air_valve.fluid_flow()
+    }
+
+    /// Air taken from the engine compressor through the pressure regulating valve.
+    pub fn engine_bleed_air_extraction(&self, number: usize) -> MassRate {
+        self.engine_systems[number - 1].bleed_air_extraction()
+    }
+
+    /// Fuel is supplied to a running engine and during a start once the start controller
+    /// has introduced it.
+    pub fn engine_fuel_is_on(&self, number: usize) -> bool {
+        self.fadec.engine_state(number) == EngineState::On
+            || self.engine_start_controllers[number - 1].start_fuel_is_on()
+    }
+
+    pub fn engine_starter_torque(&self, number: usize) -> Torque {
+        self.engine_start_controllers[number - 1].starter_torque()
+    }
+
     pub fn update_hydraulic_reservoir_spatial_volumes(
         &mut self,
         green_hydraulic_reservoir: &impl PressurizeableReservoir,
@@ -376,6 +449,13 @@ impl A320Pneumatic {
     pub fn packs(&mut self) -> &mut [PackComplex; 2] {
         &mut self.packs
     }
+
+    /// Whether a BMC requests the APU bleed valve to close because of a bleed leak.
+    pub fn apu_bleed_is_isolated(&self) -> bool {
+        self.bleed_monitoring_computers
+            .iter()
+            .any(|bmc| bmc.should_isolate_apu_bleed())
+    }
 }
 impl PneumaticBleed for A320Pneumatic {
     fn apu_bleed_is_on(&self) -> bool {
@@ -410,7 +490,10 @@ impl SimulationElement for A320Pneumatic {
         self.cross_bleed_valve.accept(visitor);
         self.fadec.accept(visitor);
         self.wing_anti_ice.accept(visitor);
+        self.apu_bleed_duct_leak.accept(visitor);
+        self.leak_detection.accept(visitor);
 
+        accept_iterable!(self.engine_start_controllers, visitor);
         accept_iterable!(self.bleed_monitoring_computers, visitor);
         accept_iterable!(self.engine_systems, visitor);
         accept_iterable!(self.packs, visitor);
@@ -456,64 +539,75 @@ impl ReservoirAirPressure for A320Pneumatic {
 }
 
 struct EngineStarterValveController {
-    number: usize,
-    engine_state: EngineState,
-    engine_n2_percent: f64,
+    should_open: bool,
 }
 impl ControllerSignal<EngineStarterValveSignal> for EngineStarterValveController {
     fn signal(&self) -> Option<EngineStarterValveSignal> {
-        match self.engine_state {
-            //FIXME should start at around 60% N2 and complete at 65% N2 because of traveltime of valve
-            EngineState::Starting | EngineState::Restarting if self.engine_n2_percent < 65. => {
-                Some(EngineStarterValveSignal::new_open())
-            }
-            _ => Some(EngineStarterValveSignal::new_closed()),
+        if self.should_open {
+            Some(EngineStarterValveSignal::new_open())
+        } else {
+            Some(EngineStarterValveSignal::new_closed())
         }
     }
 }
 impl EngineStarterValveController {
-    fn new(number: usize) -> Self {
-        Self {
-            number,
-            engine_state: EngineState::Off,
-            engine_n2_percent: 0.,
-        }
+    fn new() -> Self {
+        Self { should_open: false }
     }
 
-    fn update(&mut self, fadec: &FullAuthorityDigitalEngineControl) {
-        self.engine_state = fadec.engine_state(self.number);
-        self.engine_n2_percent = fadec.engine_n2_percent(self.number);
+    fn update(&mut self, start_controller: &EngineStartController) {
+        self.should_open = start_controller.starter_valve_should_open();
     }
 }
 
 struct BleedMonitoringComputer {
+    fault_id: VariableIdentifier,
+    cross_bleed_valve_fault_id: VariableIdentifier,
+
     main_channel_engine_number: usize,
     backup_channel_engine_number: usize,
     main_channel: BleedMonitoringComputerChannel,
     backup_channel: BleedMonitoringComputerChannel,
+    cross_bleed_valve_disagree: DelayedTrueLogicGate,
     powered_by: ElectricalBusType,
     is_powered: bool,
 }
 impl BleedMonitoringComputer {
+    // The crossbleed valve takes 2.5 seconds to travel, so this leaves a comfortable margin
+    const CROSS_BLEED_VALVE_DISAGREE_CONFIRMATION_TIME: Duration = Duration::from_secs(10);
+
     fn new(
         context: &mut InitContext,
         main_channel_engine_number: usize,
         backup_channel_engine_number: usize,
         powered_by: ElectricalBusType,
     ) -> Self {
+        // BMC 1 primarily monitors engine 1, BMC 2 engine 2.
+        let number = main_channel_engine_number;
+
         Self {
+            fault_id: context.get_identifier(format!("PNEU_BMC_{}_FAULT", number)),
+            cross_bleed_valve_fault_id: context
+                .get_identifier(format!("PNEU_BMC_{}_XBLEED_VALVE_FAULT", number)),
             main_channel_engine_number,
             backup_channel_engine_number,
             main_channel: BleedMonitoringComputerChannel::new(
                 context,
+                number,
+                Channel::ChannelOne,
                 main_channel_engine_number,
                 BleedMonitoringComputerChannelOperationMode::Master,
             ),
             backup_channel: BleedMonitoringComputerChannel::new(
                 context,
+                number,
+                Channel::ChannelTwo,
                 backup_channel_engine_number,
                 BleedMonitoringComputerChannelOperationMode::Slave,
             ),
+            cross_bleed_valve_disagree: DelayedTrueLogicGate::new(
+                Self::CROSS_BLEED_VALVE_DISAGREE_CONFIRMATION_TIME,
+            ),
             powered_by,
             is_powered: true,
         }
@@ -529,7 +623,14 @@ impl BleedMonitoringComputer {
         cross_bleed_valve: &impl PneumaticValve,
         fadec: &FullAuthorityDigitalEngineControl,
         wing_anti_ice: &impl WingAntiIceSelected,
+        leak_detection: &A320BleedLeakDetection,
     ) {
+        // The leak isolation is latched until the computer is reset by a power interruption
+        if !self.is_powered() {
+            self.main_channel.reset_leak_isolation();
+            self.backup_channel.reset_leak_isolation();
+        }
+
         self.main_channel.update(
             context,
             &sensors[self.main_channel_engine_number - 1],
@@ -539,6 +640,7 @@ impl BleedMonitoringComputer {
             overhead_panel,
             fadec,
             wing_anti_ice,
+            leak_detection,
         );
 
         self.backup_channel.update(
@@ -550,11 +652,20 @@ impl BleedMonitoringComputer {
             overhead_panel,
             fadec,
             wing_anti_ice,
+            leak_detection,
+        );
+
+        let cross_bleed_valve_signal: Option<CrossBleedValveSignal> = self.main_channel.signal();
+        self.cross_bleed_valve_disagree.update(
+            context,
+            cross_bleed_valve_signal.is_some_and(|signal| {
+                (signal.target_open_amount() > Ratio::default()) != cross_bleed_valve.is_open()
+            }),
         );
     }
 
     fn check_for_failure(&mut self, other: &mut BleedMonitoringComputer) {
-        if other.signal().is_none() {
+        if other.signal().is_none() || other.main_channel.is_failed() {
             self.change_backup_channel_operation_mode(
                 BleedMonitoringComputerChannelOperationMode::Master,
             );
@@ -594,6 +705,25 @@ impl BleedMonitoringComputer {
     fn is_powered(&self) -> bool {
         self.is_powered
     }
+
+    fn has_fault(&self) -> bool {
+        !self.is_powered() || self.main_channel.is_failed() || self.backup_channel.is_failed()
+    }
+
+    fn has_cross_bleed_valve_fault(&self) -> bool {
+        self.is_powered() && self.cross_bleed_valve_disagree.output()
+    }
+
+    fn should_isolate_apu_bleed(&self) -> bool {
+        self.is_powered()
+            && [&self.main_channel, &self.backup_channel]
+                .iter()
+                .any(|channel| {
+                    channel
+                        .or_none_if_slave()
+                        .is_some_and(|channel| channel.should_isolate_apu_bleed)
+                })
+    }
 }
 impl SimulationElement for BleedMonitoringComputer {
     fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
@@ -603,6 +733,14 @@ impl SimulationElement for BleedMonitoringComputer {
         visitor.visit(self);
     }
 
+    fn write(&self, writer: &mut SimulatorWriter) {
+        writer.write(&self.fault_id, self.has_fault());
+        writer.write(
+            &self.cross_bleed_valve_fault_id,
+            self.has_cross_bleed_valve_fault(),
+        );
+    }
+
     fn receive_power(&mut self, buses: &impl ElectricalBuses) {
         self.is_powered = buses.is_powered(self.powered_by)
     }
@@ -643,10 +781,19 @@ struct BleedMonitoringComputerChannel {
     flight_phase_loop: FlightPhaseLoop,
     low_temperature_regulation_active: DelayedTrueLogicGate,
     should_command_onside_prv_closed: bool,
+    pressure_regulating_valve_not_closed: DelayedTrueLogicGate,
+    abnormal_regulated_pressure: DelayedTrueLogicGate,
+    should_isolate_engine_bleed: bool,
+    should_isolate_cross_bleed: bool,
+    should_isolate_apu_bleed: bool,
 
     low_temperature_id: VariableIdentifier,
     overheat_id: VariableIdentifier,
     overpressure_id: VariableIdentifier,
+    pressure_regulating_valve_not_closed_id: VariableIdentifier,
+    abnormal_pressure_id: VariableIdentifier,
+
+    failure: Failure,
 }
 impl BleedMonitoringComputerChannel {
     const PRESSURE_REGULATING_VALVE_SINGLE_BLEED_CONFIG_TARGET_PSI: f64 = 50.;
@@ -665,8 +812,14 @@ impl BleedMonitoringComputerChannel {
     const HIGH_TEMPERATURE_REGULATION_SETPOINT: f64 = 200.;
     const LOW_TEMPERATURE_REGULATION_THRESHOLD: f64 = 160.;
 
+    const PRESSURE_REGULATING_VALVE_NOT_CLOSED_CONFIRMATION_TIME: Duration = Duration::from_secs(5);
+    const ABNORMAL_PRESSURE_CONFIRMATION_TIME: Duration = Duration::from_secs(10);
+    const ABNORMAL_PRESSURE_THRESHOLD_PSI: f64 = 15.;
+
     fn new(
         context: &mut InitContext,
+        bmc_number: usize,
+        channel: Channel,
         engine_number: usize,
         operation_mode: BleedMonitoringComputerChannelOperationMode,
     ) -> Self {
@@ -695,12 +848,28 @@ impl BleedMonitoringComputerChannel {
             flight_phase_loop: FlightPhaseLoop::new(),
             low_temperature_regulation_active: DelayedTrueLogicGate::new(Duration::from_secs(20)),
             should_command_onside_prv_closed: false,
+            pressure_regulating_valve_not_closed: DelayedTrueLogicGate::new(
+                Self::PRESSURE_REGULATING_VALVE_NOT_CLOSED_CONFIRMATION_TIME,
+            ),
+            abnormal_regulated_pressure: DelayedTrueLogicGate::new(
+                Self::ABNORMAL_PRESSURE_CONFIRMATION_TIME,
+            ),
+            should_isolate_engine_bleed: false,
+            should_isolate_cross_bleed: false,
+            should_isolate_apu_bleed: false,
             is_in_dual_bleed_config: false,
             low_temperature_id: context
                 .get_identifier(format!("PNEU_ENG_{}_LOW_TEMPERATURE", engine_number)),
             overheat_id: context.get_identifier(format!("PNEU_ENG_{}_OVERHEAT", engine_number)),
             overpressure_id: context
                 .get_identifier(format!("PNEU_ENG_{}_OVERPRESSURE", engine_number)),
+            pressure_regulating_valve_not_closed_id: context
+                .get_identifier(format!("PNEU_ENG_{}_PR_VALVE_NOT_CLOSED", engine_number)),
+            abnormal_pressure_id: context
+                .get_identifier(format!("PNEU_ENG_{}_ABNORMAL_PRESSURE", engine_number)),
+            failure: Failure::new(FailureType::BleedMonitoringComputerChannel(
+                bmc_number, channel,
+            )),
         }
     }
 
@@ -714,6 +883,7 @@ impl BleedMonitoringComputerChannel {
         overhead_panel: &A320PneumaticOverheadPanel,
         fadec: &FullAuthorityDigitalEngineControl,
         wing_anti_ice: &impl WingAntiIceSelected,
+        leak_detection: &A320BleedLeakDetection,
     ) {
         // READ IN SENSORS
 
@@ -730,6 +900,7 @@ impl BleedMonitoringComputerChannel {
         // UPDATE STATE
 
         self.flight_phase_loop.update(context);
+        self.update_leak_isolation(leak_detection);
         self.update_dual_vs_single_bleed_operation(
             sensors,
             overhead_panel,
@@ -738,6 +909,7 @@ impl BleedMonitoringComputerChannel {
             fadec,
         );
         self.update_low_temperature_regulation(context, wing_anti_ice);
+        self.update_valve_monitoring(context, sensors, fadec);
 
         // UPDATE SETPOINTS
 
@@ -825,15 +997,61 @@ impl BleedMonitoringComputerChannel {
         }
     }
 
+    fn update_valve_monitoring(
+        &mut self,
+        context: &UpdateContext,
+        sensors: &EngineBleedAirSystem,
+        fadec: &FullAuthorityDigitalEngineControl,
+    ) {
+        self.pressure_regulating_valve_not_closed.update(
+            context,
+            self.should_command_onside_prv_closed && !self.pressure_regulating_valve_is_closed,
+        );
+
+        // With the engine running and the PRV allowed to regulate, the regulated pressure should never be this low.
+        self.abnormal_regulated_pressure.update(
+            context,
+            fadec.engine_state(self.engine_number) == EngineState::On
+                && !self.should_command_onside_prv_closed
+                && sensors
+                    .regulated_pressure_transducer_pressure()
+                    .is_some_and(|pressure| {
+                        pressure.get::<psi>() < Self::ABNORMAL_PRESSURE_THRESHOLD_PSI
+                    }),
+        );
+    }
+
+    fn update_leak_isolation(&mut self, leak_detection: &A320BleedLeakDetection) {
+        self.should_isolate_engine_bleed |= leak_detection.engine_leak_detected(self.engine_number);
+        self.should_isolate_cross_bleed |= leak_detection.any_leak_detected();
+        // The APU bleed duct runs through the left wing
+        self.should_isolate_apu_bleed |=
+            leak_detection.apu_leak_detected() || leak_detection.wing_leak_detected(1);
+    }
+
+    fn reset_leak_isolation(&mut self) {
+        self.should_isolate_engine_bleed = false;
+        self.should_isolate_cross_bleed = false;
+        self.should_isolate_apu_bleed = false;
+    }
+
     fn operation_mode(&self) -> BleedMonitoringComputerChannelOperationMode {
         self.operation_mode
     }
 
+    fn is_failed(&self) -> bool {
+        self.failure.is_active()
+    }
+
     fn set_operation_mode(&mut self, mode: BleedMonitoringComputerChannelOperationMode) {
         self.operation_mode = mode;
     }
 
     fn or_none_if_slave(&self) -> Option<&BleedMonitoringComputerChannel> {
+        if self.is_failed() {
+            return None;
+        }
+
         match self.operation_mode() {
             BleedMonitoringComputerChannelOperationMode::Master => Some(self),
             BleedMonitoringComputerChannelOperationMode::Slave => None,
@@ -888,6 +1106,14 @@ impl BleedMonitoringComputerChannel {
         self.overheat_monitor.has_overheat()
     }
 
+    fn has_pressure_regulating_valve_not_closed(&self) -> bool {
+        self.pressure_regulating_valve_not_closed.output()
+    }
+
+    fn has_abnormal_pressure(&self) -> bool {
+        self.abnormal_regulated_pressure.output()
+    }
+
     fn determine_temperature_setpoint(&self) -> f64 {
         if self.low_temperature_regulation_active.output() {
             Self::LOW_TEMPERATURE_REGULATION_THRESHOLD
@@ -911,7 +1137,8 @@ impl BleedMonitoringComputerChannel {
             cross_bleed_valve,
         ) || sensors.engine_starter_valve_is_open()
             || self.overpressure_monitor.has_overpressure()
-            || self.overheat_monitor.has_overheat();
+            || self.overheat_monitor.has_overheat()
+            || self.should_isolate_engine_bleed;
 
         let should_command_offside_prv_closed = self.should_command_prv_closed(
             self.engine_number % 2 + 1,
@@ -985,6 +1212,10 @@ impl ControllerSignal<FanAirValveSignal> for BleedMonitoringComputerChannel {
 }
 impl ControllerSignal<CrossBleedValveSignal> for BleedMonitoringComputerChannel {
     fn signal(&self) -> Option<CrossBleedValveSignal> {
+        if self.is_failed() {
+            return None;
+        }
+
         match self.cross_bleed_valve_selector {
             CrossBleedValveSelectorMode::Shut => Some(CrossBleedValveSignal::new_closed(
                 CrossBleedValveSignalType::Manual,
@@ -993,7 +1224,11 @@ impl ControllerSignal<CrossBleedValveSignal> for BleedMonitoringComputerChannel
                 CrossBleedValveSignalType::Manual,
             )),
             CrossBleedValveSelectorMode::Auto => {
-                if self.is_apu_bleed_valve_open {
+                if self.should_isolate_cross_bleed {
+                    Some(CrossBleedValveSignal::new_closed(
+                        CrossBleedValveSignalType::Automatic,
+                    ))
+                } else if self.is_apu_bleed_valve_open {
                     Some(CrossBleedValveSignal::new_open(
                         CrossBleedValveSignalType::Automatic,
                     ))
@@ -1008,15 +1243,144 @@ impl ControllerSignal<CrossBleedValveSignal> for BleedMonitoringComputerChannel
 }
 impl SimulationElement for BleedMonitoringComputerChannel {
     fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
+        self.failure.accept(visitor);
+
         visitor.visit(self);
     }
 
     fn write(&self, writer: &mut SimulatorWriter) {
-        if self.operation_mode() == BleedMonitoringComputerChannelOperationMode::Master {
+        if self.operation_mode() == BleedMonitoringComputerChannelOperationMode::Master
+            && !self.is_failed()
+        {
             writer.write(&self.low_temperature_id, self.has_low_temperature());
             writer.write(&self.overheat_id, self.has_overheat());
             writer.write(&self.overpressure_id, self.has_overpressure());
+            writer.write(
+                &self.pressure_regulating_valve_not_closed_id,
+                self.has_pressure_regulating_valve_not_closed(),
+            );
+            writer.write(&self.abnormal_pressure_id, self.has_abnormal_pressure());
+        }
+    }
+}
+
+/// The overheat detection loops along the bleed ducts. They are connected to both BMCs, which
+/// isolate the leaking zone.
+struct A320BleedLeakDetection {
+    pylons: [BleedLeakDetection; 2],
+    wings: [BleedLeakDetection; 2],
+    apu: BleedLeakDetection,
+}
+impl A320BleedLeakDetection {
+    const PYLON_ALARM_TEMPERATURE_C: f64 = 204.;
+    const WING_ALARM_TEMPERATURE_C: f64 = 124.;
+    const APU_ALARM_TEMPERATURE_C: f64 = 124.;
+
+    fn new(context: &mut InitContext) -> Self {
+        let pylon_alarm_temperature =
+            ThermodynamicTemperature::new::<degree_celsius>(Self::PYLON_ALARM_TEMPERATURE_C);
+        let wing_alarm_temperature =
+            ThermodynamicTemperature::new::<degree_celsius>(Self::WING_ALARM_TEMPERATURE_C);
+
+        // Pylons and APU duct have a single loop, the wings a double loop
+        Self {
+            pylons: [
+                BleedLeakDetection::new(
+                    context,
+                    BleedDuctZone::Pylon(1),
+                    pylon_alarm_temperature,
+                    &[(
+                        FireDetectionLoopID::A,
+                        ElectricalBusType::DirectCurrentEssentialShed,
+                    )],
+                ),
+                BleedLeakDetection::new(
+                    context,
+                    BleedDuctZone::Pylon(2),
+                    pylon_alarm_temperature,
+                    &[(FireDetectionLoopID::A, ElectricalBusType::DirectCurrent(2))],
+                ),
+            ],
+            wings: [
+                BleedLeakDetection::new(
+                    context,
+                    BleedDuctZone::Wing(1),
+                    wing_alarm_temperature,
+                    &[
+                        (
+                            FireDetectionLoopID::A,
+                            ElectricalBusType::DirectCurrentEssentialShed,
+                        ),
+                        (FireDetectionLoopID::B, ElectricalBusType::DirectCurrent(2)),
+                    ],
+                ),
+                BleedLeakDetection::new(
+                    context,
+                    BleedDuctZone::Wing(2),
+                    wing_alarm_temperature,
+                    &[
+                        (FireDetectionLoopID::A, ElectricalBusType::DirectCurrent(2)),
+                        (
+                            FireDetectionLoopID::B,
+                            ElectricalBusType::DirectCurrentEssentialShed,
+                        ),
+                    ],
+                ),
+            ],
+            apu: BleedLeakDetection::new(
+                context,
+                BleedDuctZone::Apu,
+                ThermodynamicTemperature::new::<degree_celsius>(Self::APU_ALARM_TEMPERATURE_C),
+                &[(
+                    FireDetectionLoopID::A,
+                    ElectricalBusType::DirectCurrentEssentialShed,
+                )],
+            ),
+        }
+    }
+
+    fn update(
+        &mut self,
+        context: &UpdateContext,
+        engine_systems: &[EngineBleedAirSystem; 2],
+        apu_bleed_duct_leak: &BleedDuctLeak,
+    ) {
+        for ((pylon, wing), engine_system) in self
+            .pylons
+            .iter_mut()
+            .zip(self.wings.iter_mut())
+            .zip(engine_systems)
+        {
+            pylon.update(context, &engine_system.pylon_duct_leak);
+            wing.update(context, &engine_system.wing_duct_leak);
         }
+
+        self.apu.update(context, apu_bleed_duct_leak);
+    }
+
+    fn engine_leak_detected(&self, engine_number: usize) -> bool {
+        self.pylons[engine_number - 1].leak_detected() || self.wing_leak_detected(engine_number)
+    }
+
+    fn wing_leak_detected(&self, engine_number: usize) -> bool {
+        self.wings[engine_number - 1].leak_detected()
+    }
+
+    fn apu_leak_detected(&self) -> bool {
+        self.apu.leak_detected()
+    }
+
+    fn any_leak_detected(&self) -> bool {
+        (1..=2).any(|number| self.engine_leak_detected(number)) || self.apu_leak_detected()
+    }
+}
+impl SimulationElement for A320BleedLeakDetection {
+    fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
+        accept_iterable!(self.pylons, visitor);
+        accept_iterable!(self.wings, visitor);
+        self.apu.accept(visitor);
+
+        visitor.visit(self);
     }
 }
 
@@ -1066,11 +1430,24 @@ struct EngineBleedAirSystem {
     regulated_pressure_transducer: PressureTransducer,
     differential_pressure_transducer: DifferentialPressureTransducer,
     bleed_temperature_sensor: BleedTemperatureSensor,
+
+    pylon_duct_leak: BleedDuctLeak,
+    wing_duct_leak: BleedDuctLeak,
+
+    high_pressure_valve_stuck_open_failure: Failure,
+    high_pressure_valve_stuck_closed_failure: Failure,
+    pressure_regulating_valve_stuck_open_failure: Failure,
+    pressure_regulating_valve_stuck_closed_failure: Failure,
+    fan_air_valve_stuck_closed_failure: Failure,
+    engine_starter_valve_stuck_open_failure: Failure,
 }
 impl EngineBleedAirSystem {
     const MIN_ENGINE_START_CONTAINER_PRESSURE_PSIG_HIGH: f64 = 10.;
     const MIN_ENGINE_START_CONTAINER_PRESSURE_PSIG_LOW: f64 = 5.;
 
+    const PYLON_DUCT_LEAK_SPEED: f64 = 1.;
+    const WING_DUCT_LEAK_SPEED: f64 = 1.;
+
     fn new(context: &mut InitContext, number: usize, powered_by: ElectricalBusType) -> Self {
         Self {
             number,
@@ -1186,9 +1563,71 @@ impl EngineBleedAirSystem {
             regulated_pressure_transducer: PressureTransducer::new(powered_by),
             differential_pressure_transducer: DifferentialPressureTransducer::new(powered_by),
             bleed_temperature_sensor: BleedTemperatureSensor::new(powered_by),
+            pylon_duct_leak: BleedDuctLeak::new(
+                BleedDuctZone::Pylon(number),
+                Self::PYLON_DUCT_LEAK_SPEED,
+            ),
+            wing_duct_leak: BleedDuctLeak::new(
+                BleedDuctZone::Wing(number),
+                Self::WING_DUCT_LEAK_SPEED,
+            ),
+            high_pressure_valve_stuck_open_failure: Failure::new(
+                FailureType::BleedHighPressureValveStuckOpen(number),
+            ),
+            high_pressure_valve_stuck_closed_failure: Failure::new(
+                FailureType::BleedHighPressureValveStuckClosed(number),
+            ),
+            pressure_regulating_valve_stuck_open_failure: Failure::new(
+                FailureType::BleedPressureRegulatingValveStuckOpen(number),
+            ),
+            pressure_regulating_valve_stuck_closed_failure: Failure::new(
+                FailureType::BleedPressureRegulatingValveStuckClosed(number),
+            ),
+            fan_air_valve_stuck_closed_failure: Failure::new(
+                FailureType::BleedFanAirValveStuckClosed(number),
+            ),
+            engine_starter_valve_stuck_open_failure: Failure::new(
+                FailureType::EngineStarterValveStuckOpen(number),
+            ),
         }
     }
 
+    fn stuck_open_amount(
+        stuck_open_failure: &Failure,
+        stuck_closed_failure: &Failure,
+    ) -> Option<Ratio> {
+        if stuck_open_failure.is_active() {
+            Some(Ratio::new::<ratio>(1.))
+        } else if stuck_closed_failure.is_active() {
+            Some(Ratio::new::<ratio>(0.))
+        } else {
+            None
+        }
+    }
+
+    fn update_valve_failures(&mut self) {
+        self.high_pressure_valve
+            .set_stuck_open_amount(Self::stuck_open_amount(
+                &self.high_pressure_valve_stuck_open_failure,
+                &self.high_pressure_valve_stuck_closed_failure,
+            ));
+        self.pressure_regulating_valve
+            .set_stuck_open_amount(Self::stuck_open_amount(
+                &self.pressure_regulating_valve_stuck_open_failure,
+                &self.pressure_regulating_valve_stuck_closed_failure,
+            ));
+        self.fan_air_valve.set_stuck_open_amount(
+            self.fan_air_valve_stuck_closed_failure
+                .is_active()
+                .then(|| Ratio::new::<ratio>(0.)),
+        );
+        self.engine_starter_valve.set_stuck_open_amount(
+            self.engine_starter_valve_stuck_open_failure
+                .is_active()
+                .then(|| Ratio::new::<ratio>(1.)),
+        );
+    }
+
     fn update(
         &mut self,
         context: &UpdateContext,
@@ -1213,6 +1652,8 @@ impl EngineBleedAirSystem {
         self.high_pressure_compression_chamber
             .update(&self.high_pressure_compression_chamber_controller);
 
+        self.update_valve_failures();
+
         self.high_pressure_valve
             .update_solenoid(high_pressure_valve_controller);
         self.pressure_regulating_valve
@@ -1253,6 +1694,10 @@ impl EngineBleedAirSystem {
             &mut self.precooler_supply_pipe,
             &mut self.precooler_outlet_pipe,
         );
+        self.pylon_duct_leak
+            .update_move_fluid(context, &mut self.precooler_inlet_pipe);
+        self.wing_duct_leak
+            .update_move_fluid(context, &mut self.precooler_outlet_pipe);
         self.engine_starter_valve
             .update_move_fluid_with_transfer_speed(
                 context,
@@ -1344,6 +1789,16 @@ impl EngineBleedAirSystem {
         self.engine_starter_valve.is_open()
     }
 
+    fn engine_starter_container_pressure(&self) -> Pressure {
+        self.engine_starter_container.pressure()
+    }
+
+    fn bleed_air_extraction(&self) -> MassRate {
+        self.pressure_regulating_valve
+            .fluid_flow()
+            .max(MassRate::default())
+    }
+
     fn pressure_regulating_valve_is_open(&self) -> bool {
         self.pressure_regulating_valve.is_open()
     }
@@ -1375,6 +1830,19 @@ impl SimulationElement for EngineBleedAirSystem {
         self.differential_pressure_transducer.accept(visitor);
         self.bleed_temperature_sensor.accept(visitor);
 
+        self.pylon_duct_leak.accept(visitor);
+        self.wing_duct_leak.accept(visitor);
+
+        self.high_pressure_valve_stuck_open_failure.accept(visitor);
+        self.high_pressure_valve_stuck_closed_failure
+            .accept(visitor);
+        self.pressure_regulating_valve_stuck_open_failure
+            .accept(visitor);
+        self.pressure_regulating_valve_stuck_closed_failure
+            .accept(visitor);
+        self.fan_air_valve_stuck_closed_failure.accept(visitor);
+        self.engine_starter_valve_stuck_open_failure.accept(visitor);
+
         visitor.visit(self);
     }
 
@@ -1592,11 +2060,6 @@ pub struct FullAuthorityDigitalEngineControl {
 
     engine_mode_selector1_id: VariableIdentifier,
     engine_mode_selector1_position: EngineModeSelector,
-
-    engine_1_n2_percent_id: VariableIdentifier,
-    engine_2_n2_percent_id: VariableIdentifier,
-    engine_1_n2_percent: Ratio,
-    engine_2_n2_percent: Ratio,
 }
 impl FullAuthorityDigitalEngineControl {
     fn new(context: &mut InitContext) -> Self {
@@ -1608,10 +2071,6 @@ impl FullAuthorityDigitalEngineControl {
             engine_mode_selector1_id: context
                 .get_identifier("TURB ENG IGNITION SWITCH EX1:1".to_owned()),
             engine_mode_selector1_position: EngineModeSelector::Norm,
-            engine_1_n2_percent_id: context.get_identifier("ENGINE_N2:1".to_owned()),
-            engine_2_n2_percent_id: context.get_identifier("ENGINE_N2:2".to_owned()),
-            engine_1_n2_percent: Ratio::new::<percent>(0.),
-            engine_2_n2_percent: Ratio::new::<percent>(0.),
         }
     }
 
@@ -1623,14 +2082,6 @@ impl FullAuthorityDigitalEngineControl {
         }
     }
 
-    fn engine_n2_percent(&self, number: usize) -> f64 {
-        match number {
-            1 => self.engine_1_n2_percent.get::<percent>(),
-            2 => self.engine_2_n2_percent.get::<percent>(),
-            _ => panic!("Invalid engine number"),
-        }
-    }
-
     fn is_single_vs_dual_bleed_config(&self) -> bool {
         (self.engine_1_state == EngineState::On) ^ (self.engine_2_state == EngineState::On)
     }
@@ -1656,8 +2107,6 @@ impl SimulationElement for FullAuthorityDigitalEngineControl {
             "EngineModeSelector",
             EngineModeSelector::Norm,
         );
-        self.engine_1_n2_percent = Ratio::new::<percent>(reader.read(&self.engine_1_n2_percent_id));
-        self.engine_2_n2_percent = Ratio::new::<percent>(reader.read(&self.engine_2_n2_percent_id));
     }
 }
 
@@ -1782,9 +2231,11 @@ pub struct CrossBleedValve {
     is_powered_for_automatic_control: bool,
     target_open_amount: Ratio,
     valve_speed: Ratio,
+
+    jammed_failure: Failure,
 }
 impl CrossBleedValve {
-    pub fn new(valve_speed: Ratio) -> Self {
+    pub fn new(number: usize, valve_speed: Ratio) -> Self {
         Self {
             open_amount: Ratio::default(),
             connector: PneumaticContainerConnector::new(),
@@ -1792,6 +2243,7 @@ impl CrossBleedValve {
             is_powered_for_automatic_control: false,
             target_open_amount: Ratio::default(),
             valve_speed,
+            jammed_failure: Failure::new(FailureType::CrossBleedValveJammed(number)),
         }
     }
 
@@ -1801,15 +2253,18 @@ impl CrossBleedValve {
         container_one: &mut impl PneumaticContainer,
         container_two: &mut impl PneumaticContainer,
     ) {
-        let open_amount_change = context.delta_as_secs_f64() * self.valve_speed;
+        // A jammed valve stays wherever it was when it jammed
+        if !self.jammed_failure.is_active() {
+            let open_amount_change = context.delta_as_secs_f64() * self.valve_speed;
 
-        self.open_amount = if self.target_open_amount > self.open_amount {
-            self.target_open_amount
-                .min(self.open_amount + open_amount_change)
-        } else {
-            self.target_open_amount
-                .max(self.open_amount - open_amount_change)
-        };
+            self.open_amount = if self.target_open_amount > self.open_amount {
+                self.target_open_amount
+                    .min(self.open_amount + open_amount_change)
+            } else {
+                self.target_open_amount
+                    .max(self.open_amount - open_amount_change)
+            };
+        }
 
         self.connector
             .with_transfer_speed_factor(self.open_amount)
@@ -1847,6 +2302,12 @@ impl FullyClosed for CrossBleedValve {
     }
 }
 impl SimulationElement for CrossBleedValve {
+    fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
+        self.jammed_failure.accept(visitor);
+
+        visitor.visit(self);
+    }
+
     fn receive_power(&mut self, buses: &impl ElectricalBuses) {
         self.is_powered_for_manual_control =
             buses.is_powered(ElectricalBusType::DirectCurrentEssentialShed);
@@ -1915,10 +2376,10 @@ pub mod tests {
     use more_asserts::*;
     use ntest::assert_about_eq;
     use systems::{
-        air_conditioning::{AdirsToAirCondInterface, PackFlowControllers, ZoneType},
+        air_conditioning::{AdirsToAirCondInterface, Channel, PackFlowControllers, ZoneType},
         air_starter_unit::AirStarterUnit,
         electrical::{test::TestElectricitySource, ElectricalBus, Electricity},
-        engine::leap_engine::LeapEngine,
+        engine::{leap_engine::LeapEngine, start_controller::EngineStartFault},
         failures::FailureType,
         pneumatic::{
             BleedMonitoringComputerChannelOperationMode, ControllablePneumaticValve,
@@ -1927,7 +2388,7 @@ pub mod tests {
         },
         shared::{
             arinc429::{Arinc429Word, SignStatus},
-            interpolation, ApuBleedAirValveSignal, CabinAltitude, CabinSimulation,
+            interpolation, ApuBleedAirValveSignal, BleedDuctZone, CabinAltitude, CabinSimulation,
             ControllerSignal, ElectricalBusType, ElectricalBuses, EmergencyElectricalState,
             EngineCorrectedN1, EngineFirePushButtons, EngineStartState, HydraulicColor,
             InternationalStandardAtmosphere, LgciuWeightOnWheels, MachNumber, PackFlowValveState,
@@ -1946,7 +2407,7 @@ pub mod tests {
         length::foot,
         mass_rate::kilogram_per_second,
         pressure::psi,
-        ratio::ratio,
+        ratio::{percent, ratio},
         thermodynamic_temperature::degree_celsius,
         velocity::{foot_per_minute, knot},
     };
@@ -2297,7 +2758,8 @@ pub mod tests {
             self.electrical.update(context);
 
             self.apu.update(self.pneumatic.apu_bleed_air_valve());
-            self.asu.update();
+            self.asu
+                .update(context, self.pneumatic.asu_bleed_air_flow());
             self.pneumatic.update(
                 context,
                 [&self.engine_1, &self.engine_2],
@@ -2665,6 +3127,10 @@ pub mod tests {
             self.query(|a| a.pneumatic.engine_systems[number - 1].engine_starter_valve_is_open())
         }
 
+        fn start_fault(&self, number: usize) -> EngineStartFault {
+            self.query(|a| a.pneumatic.engine_start_controllers[number - 1].start_fault())
+        }
+
         fn apu_bleed_valve_is_open(&self) -> bool {
             self.query(|a| a.pneumatic.apu_bleed_air_valve.is_open())
         }
@@ -2836,6 +3302,48 @@ pub mod tests {
             })
         }
 
+        fn bmc_has_fault(&mut self, bmc_number: usize) -> bool {
+            self.read_by_name(&format!("PNEU_BMC_{}_FAULT", bmc_number))
+        }
+
+        fn bmc_has_cross_bleed_valve_fault(&mut self, bmc_number: usize) -> bool {
+            self.read_by_name(&format!("PNEU_BMC_{}_XBLEED_VALVE_FAULT", bmc_number))
+        }
+
+        fn pr_valve_not_closed(&mut self, number: usize) -> bool {
+            self.read_by_name(&format!("PNEU_ENG_{}_PR_VALVE_NOT_CLOSED", number))
+        }
+
+        fn has_abnormal_bleed_pressure(&mut self, number: usize) -> bool {
+            self.read_by_name(&format!("PNEU_ENG_{}_ABNORMAL_PRESSURE", number))
+        }
+
+        fn leak_detected(&mut self, zone: BleedDuctZone) -> bool {
+            self.read_by_name(&format!("PNEU_{}_LEAK_DETECTED", zone))
+        }
+
+        fn run_until_leak_detected(&mut self, zone: BleedDuctZone) {
+            for _ in 0..20 {
+                if self.leak_detected(zone) {
+                    break;
+                }
+
+                self.run_multiple_frames(Duration::from_secs(5));
+            }
+        }
+
+        fn apu_bleed_is_isolated(&self) -> bool {
+            self.query(|a| a.pneumatic.apu_bleed_is_isolated())
+        }
+
+        fn pylon_duct_leak_flow(&self, number: usize) -> MassRate {
+            self.query(|a| {
+                a.pneumatic.engine_systems[number - 1]
+                    .pylon_duct_leak
+                    .fluid_flow()
+            })
+        }
+
         fn pack_flow_valve_flow(&self, engine_number: usize) -> MassRate {
             self.query(|a| {
                 a.pneumatic.packs[engine_number - 1]
@@ -3525,6 +4033,20 @@ pub mod tests {
         assert!(test_bed.es_valve_is_open(2));
     }
 
+    #[test]
+    fn starter_valve_closes_at_starter_cut_out_n2() {
+        let mut test_bed = test_bed_with().stop_eng1().stop_eng2().and_run();
+
+        test_bed = test_bed.start_eng1().and_run();
+
+        assert!(test_bed.es_valve_is_open(1));
+
+        test_bed.write_by_name("ENGINE_N2:1", Ratio::new::<percent>(66.));
+        test_bed = test_bed.and_run();
+
+        assert!(!test_bed.es_valve_is_open(1));
+    }
+
     #[test]
     fn apu_bleed_engine_start() {
         let mut test_bed = test_bed_with()
@@ -4210,6 +4732,296 @@ pub mod tests {
         );
     }
 
+    #[test]
+    fn bleed_monitoring_computer_one_takes_over_for_failed_bmc_two_main_channel() {
+        let mut test_bed = test_bed()
+            .set_dc_2_bus_power(true)
+            .set_dc_ess_shed_bus_power(true)
+            .and_run();
+
+        assert!(!test_bed.bmc_has_fault(2));
+
+        test_bed.fail(FailureType::BleedMonitoringComputerChannel(
+            2,
+            Channel::ChannelOne,
+        ));
+        test_bed = test_bed.and_run();
+
+        assert!(test_bed.bmc_has_fault(2));
+
+        assert_eq!(
+            test_bed.bmc_operation_mode_for_engine(1, 2),
+            BleedMonitoringComputerChannelOperationMode::Master
+        );
+        assert_eq!(
+            test_bed.bmc_operation_mode_for_engine(2, 2),
+            BleedMonitoringComputerChannelOperationMode::Slave
+        );
+    }
+
+    #[test]
+    fn hp_valve_stuck_open_does_not_close_when_commanded() {
+        let mut test_bed = test_bed_with()
+            .idle_eng2()
+            .set_engine_bleed_push_button_off(2)
+            .and_run();
+
+        assert!(!test_bed.hp_valve_is_open(2));
+
+        test_bed.fail(FailureType::BleedHighPressureValveStuckOpen(2));
+        test_bed = test_bed.and_run();
+
+        assert!(test_bed.hp_valve_is_open(2));
+    }
+
+    #[test]
+    fn hp_valve_stuck_closed_does_not_open_at_idle() {
+        let mut test_bed = test_bed_with().idle_eng2();
+        test_bed.fail(FailureType::BleedHighPressureValveStuckClosed(2));
+        test_bed = test_bed.and_stabilize();
+
+        assert!(!test_bed.hp_valve_is_open(2));
+    }
+
+    #[test]
+    fn pr_valve_stuck_open_is_detected_when_commanded_closed() {
+        let mut test_bed = test_bed_with()
+            .idle_eng2()
+            .set_engine_bleed_push_button_off(2)
+            .and_run();
+
+        assert!(!test_bed.pr_valve_is_open(2));
+
+        test_bed.fail(FailureType::BleedPressureRegulatingValveStuckOpen(2));
+        test_bed.run_with_delta(Duration::from_secs(6));
+
+        assert!(test_bed.pr_valve_is_open(2));
+        assert!(test_bed.pr_valve_not_closed(2));
+    }
+
+    #[test]
+    fn pr_valve_stuck_closed_is_detected_as_abnormal_pressure() {
+        let mut test_bed = test_bed_with()
+            .idle_eng1()
+            .idle_eng2()
+            .cross_bleed_valve_selector_knob(CrossBleedValveSelectorMode::Shut)
+            .both_packs_auto();
+        test_bed.fail(FailureType::BleedPressureRegulatingValveStuckClosed(2));
+        test_bed = test_bed.and_stabilize();
+
+        assert!(!test_bed.pr_valve_is_open(2));
+        assert!(!test_bed.has_abnormal_bleed_pressure(1));
+        assert!(test_bed.has_abnormal_bleed_pressure(2));
+    }
+
+    #[test]
+    fn fan_air_valve_stuck_closed_increases_bleed_temperature() {
+        let healthy_test_bed = test_bed_with()
+            .stop_eng1()
+            .toga_eng2()
+            .cross_bleed_valve_selector_knob(CrossBleedValveSelectorMode::Shut)
+            .both_packs_auto()
+            .and_stabilize();
+
+        let mut test_bed = test_bed_with()
+            .stop_eng1()
+            .toga_eng2()
+            .cross_bleed_valve_selector_knob(CrossBleedValveSelectorMode::Shut)
+            .both_packs_auto();
+        test_bed.fail(FailureType::BleedFanAirValveStuckClosed(2));
+        test_bed = test_bed.and_stabilize();
+
+        assert_gt!(
+            test_bed.precooler_outlet_temperature(2),
+            healthy_test_bed.precooler_outlet_temperature(2)
+        );
+    }
+
+    #[test]
+    fn cross_bleed_valve_jam_is_detected_by_bmc() {
+        let mut test_bed = test_bed_with()
+            .cross_bleed_valve_selector_knob(CrossBleedValveSelectorMode::Auto)
+            .and_run();
+        test_bed.fail(FailureType::CrossBleedValveJammed(1));
+
+        test_bed = test_bed
+            .set_apu_bleed_valve_signal(ApuBleedAirValveSignal::new_open())
+            .and_run();
+
+        assert!(!test_bed.cross_bleed_valve_is_open());
+        assert!(!test_bed.bmc_has_cross_bleed_valve_fault(2));
+
+        test_bed.run_with_delta(Duration::from_secs(11));
+
+        assert!(!test_bed.cross_bleed_valve_is_open());
+        assert!(test_bed.bmc_has_cross_bleed_valve_fault(2));
+    }
+
+    #[test]
+    fn pylon_duct_leak_reduces_bleed_pressure() {
+        let healthy_test_bed = test_bed_with()
+            .stop_eng1()
+            .idle_eng2()
+            .cross_bleed_valve_selector_knob(CrossBleedValveSelectorMode::Shut)
+            .both_packs_auto()
+            .and_stabilize();
+
+        let mut test_bed = test_bed_with()
+            .stop_eng1()
+            .idle_eng2()
+            .cross_bleed_valve_selector_knob(CrossBleedValveSelectorMode::Shut)
+            .both_packs_auto();
+        test_bed.fail(FailureType::BleedDuctLeak(BleedDuctZone::Pylon(2)));
+        test_bed = test_bed.and_stabilize();
+
+        assert_gt!(test_bed.pylon_duct_leak_flow(2), MassRate::default());
+        assert_eq!(test_bed.pylon_duct_leak_flow(1), MassRate::default());
+        assert_lt!(
+            test_bed.precooler_outlet_pressure(2),
+            healthy_test_bed.precooler_outlet_pressure(2)
+        );
+    }
+
+    #[test]
+    fn apu_duct_leak_reduces_left_bleed_pressure() {
+        let healthy_test_bed = test_bed_with()
+            .idle_eng1()
+            .stop_eng2()
+            .cross_bleed_valve_selector_knob(CrossBleedValveSelectorMode::Shut)
+            .both_packs_auto()
+            .and_stabilize();
+
+        let mut test_bed = test_bed_with()
+            .idle_eng1()
+            .stop_eng2()
+            .cross_bleed_valve_selector_knob(CrossBleedValveSelectorMode::Shut)
+            .both_packs_auto();
+        test_bed.fail(FailureType::BleedDuctLeak(BleedDuctZone::Apu));
+        test_bed = test_bed.and_stabilize();
+
+        assert_lt!(
+            test_bed.precooler_outlet_pressure(1),
+            healthy_test_bed.precooler_outlet_pressure(1)
+        );
+    }
+
+    #[test]
+    fn no_bleed_leak_detected_without_duct_leak() {
+        let mut test_bed = test_bed_with()
+            .toga_eng1()
+            .toga_eng2()
+            .both_packs_auto()
+            .and_stabilize();
+        test_bed.run_multiple_frames(Duration::from_secs(60));
+
+        for zone in [
+            BleedDuctZone::Pylon(1),
+            BleedDuctZone::Pylon(2),
+            BleedDuctZone::Wing(1),
+            BleedDuctZone::Wing(2),
+            BleedDuctZone::Apu,
+        ] {
+            assert!(!test_bed.leak_detected(zone));
+        }
+        assert!(!test_bed.apu_bleed_is_isolated());
+    }
+
+    #[test]
+    fn wing_duct_leak_is_detected_and_engine_bleed_is_isolated() {
+        let mut test_bed = test_bed_with()
+            .toga_eng1()
+            .stop_eng2()
+            .cross_bleed_valve_selector_knob(CrossBleedValveSelectorMode::Auto)
+            .both_packs_auto()
+            .and_stabilize();
+
+        assert!(test_bed.pr_valve_is_open(1));
+
+        test_bed.fail(FailureType::BleedDuctLeak(BleedDuctZone::Wing(1)));
+        test_bed.run_until_leak_detected(BleedDuctZone::Wing(1));
+
+        assert!(test_bed.leak_detected(BleedDuctZone::Wing(1)));
+        assert!(!test_bed.leak_detected(BleedDuctZone::Wing(2)));
+
+        test_bed = test_bed.and_stabilize();
+
+        assert!(!test_bed.pr_valve_is_open(1));
+        assert!(!test_bed.cross_bleed_valve_is_open());
+        assert!(test_bed.apu_bleed_is_isolated());
+    }
+
+    #[test]
+    fn wing_duct_leak_on_right_side_does_not_isolate_apu_bleed() {
+        let mut test_bed = test_bed_with()
+            .stop_eng1()
+            .toga_eng2()
+            .both_packs_auto()
+            .and_stabilize();
+
+        test_bed.fail(FailureType::BleedDuctLeak(BleedDuctZone::Wing(2)));
+        test_bed.run_until_leak_detected(BleedDuctZone::Wing(2));
+
+        assert!(test_bed.leak_detected(BleedDuctZone::Wing(2)));
+
+        test_bed = test_bed.and_stabilize();
+
+        assert!(!test_bed.pr_valve_is_open(2));
+        assert!(!test_bed.apu_bleed_is_isolated());
+    }
+
+    #[test]
+    fn apu_duct_leak_closes_cross_bleed_valve_in_auto() {
+        let mut test_bed = test_bed_with()
+            .stop_eng1()
+            .stop_eng2()
+            .cross_bleed_valve_selector_knob(CrossBleedValveSelectorMode::Auto)
+            .set_bleed_air_running()
+            .set_pack_flow_pb_is_auto(1, false)
+            .set_pack_flow_pb_is_auto(2, false)
+            .and_stabilize();
+
+        assert!(test_bed.cross_bleed_valve_is_open());
+
+        test_bed.fail(FailureType::BleedDuctLeak(BleedDuctZone::Apu));
+        test_bed.run_until_leak_detected(BleedDuctZone::Apu);
+
+        assert!(test_bed.leak_detected(BleedDuctZone::Apu));
+
+        test_bed = test_bed.and_stabilize();
+
+        assert!(!test_bed.cross_bleed_valve_is_open());
+        assert!(test_bed.apu_bleed_is_isolated());
+    }
+
+    #[test]
+    fn leak_isolation_is_reset_by_bmc_power_interruption() {
+        let mut test_bed = test_bed_with()
+            .stop_eng1()
+            .toga_eng2()
+            .both_packs_auto()
+            .and_stabilize();
+
+        test_bed.fail(FailureType::BleedDuctLeak(BleedDuctZone::Wing(2)));
+        test_bed.run_until_leak_detected(BleedDuctZone::Wing(2));
+        test_bed.unfail(FailureType::BleedDuctLeak(BleedDuctZone::Wing(2)));
+        test_bed = test_bed.and_stabilize();
+        test_bed.run_multiple_frames(Duration::from_secs(60));
+
+        // The leak is gone, but the engine bleed stays isolated
+        assert!(!test_bed.leak_detected(BleedDuctZone::Wing(2)));
+        assert!(!test_bed.pr_valve_is_open(2));
+
+        test_bed = test_bed
+            .set_dc_ess_shed_bus_power(false)
+            .set_dc_2_bus_power(false)
+            .and_run()
+            .set_dc_ess_shed_bus_power(true)
+            .set_dc_2_bus_power(true)
+            .and_stabilize();
+
+        assert!(test_bed.pr_valve_is_open(2));
+    }
+
     #[test]
     fn valves_powered_by_correct_busses() {
         let mut test_bed = test_bed()
@@ -4775,7 +5587,8 @@ pub mod tests {
         );
 
         // This will open the starter valve
-        test_bed = test_bed.start_eng2().and_stabilize();
+        test_bed = test_bed.start_eng2();
+        test_bed.run_multiple_frames(Duration::from_secs(8));
 
         // Check starter valve has opened
         assert!(test_bed.es_valve_is_open(2));
@@ -4791,6 +5604,12 @@ pub mod tests {
         assert!(
             test_bed.regulated_pressure_transducer_signal(2).unwrap() < Pressure::new::<psi>(10.)
         );
+
+        // The trapped air cannot drive the starter up to the fuel on N2
+        test_bed = test_bed.and_stabilize();
+
+        assert_eq!(test_bed.start_fault(2), EngineStartFault::HungStart);
+        assert!(!test_bed.es_valve_is_open(2));
     }
 
     #[ignore = "Needs fixed as it's not possible to directly set V/S anymore."]
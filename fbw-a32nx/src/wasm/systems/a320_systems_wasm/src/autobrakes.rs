@@ -71,6 +71,12 @@ pub(super) fn autobrakes(builder: &mut MsfsAspectBuilder) -> Result<(), Box<dyn
         Variable::named("AUTOBRAKES_ARMED_MODE_SET"),
         options_set,
     )?;
+    builder.event_to_variable(
+        "A32NX.AUTOBRAKE_SET_BTV",
+        EventToVariableMapping::Value(4.),
+        Variable::named("AUTOBRAKES_ARMED_MODE_SET"),
+        options_set,
+    )?;
 
     let options_buttons = |options: EventToVariableOptions| {
         options
@@ -96,6 +102,12 @@ pub(super) fn autobrakes(builder: &mut MsfsAspectBuilder) -> Result<(), Box<dyn
         Variable::named("OVHD_AUTOBRK_MAX_ON_IS_PRESSED"),
         options_buttons,
     )?;
+    builder.event_to_variable(
+        "A32NX.AUTOBRAKE_BUTTON_BTV",
+        EventToVariableMapping::Value(1.),
+        Variable::named("OVHD_AUTOBRK_BTV_ON_IS_PRESSED"),
+        options_buttons,
+    )?;
 
     Ok(())
 }
@@ -29,8 +29,9 @@ use systems::air_conditioning::{
 };
 use systems::failures::FailureType;
 use systems::shared::{
-    report_diagnostic, AirbusElectricPumpId, AirbusEngineDrivenPumpId, ElectricalBusType,
-    GearActuatorId, HydraulicColor, LgciuId, ProximityDetectorId,
+    report_diagnostic, AirbusElectricPumpId, AirbusEngineDrivenPumpId, BleedDuctZone, CargoDoorId,
+    ElectricalBusType, FireDetectionLoopID, GearActuatorId, GearWheel, HydraulicColor, LgciuId,
+    ProximityDetectorId, SteeringActuatorId,
 };
 use systems_wasm::aspects::ExecuteOn;
 use systems_wasm::{MsfsSimulationBuilder, Variable};
@@ -168,6 +169,19 @@ async fn systems(mut gauge: msfs::Gauge) -> Result<(), Box<dyn Error>> {
             24_113,
             FailureType::ElectricalBus(ElectricalBusType::DirectCurrentGndFltService),
         ),
+        (27_100, FailureType::ThsJam),
+        (
+            27_101,
+            FailureType::ThsHydraulicMotor(HydraulicColor::Green),
+        ),
+        (
+            27_102,
+            FailureType::ThsHydraulicMotor(HydraulicColor::Yellow),
+        ),
+        (27_103, FailureType::ThsElectricMotor(1)),
+        (27_104, FailureType::ThsElectricMotor(2)),
+        (27_105, FailureType::ThsElectricMotor(3)),
+        (27_106, FailureType::ThsTrimRunaway),
         (29_000, FailureType::ReservoirLeak(HydraulicColor::Green)),
         (29_001, FailureType::ReservoirLeak(HydraulicColor::Blue)),
         (29_002, FailureType::ReservoirLeak(HydraulicColor::Yellow)),
@@ -281,6 +295,12 @@ async fn systems(mut gauge: msfs::Gauge) -> Result<(), Box<dyn Error>> {
             32_025,
             FailureType::GearActuatorJammed(GearActuatorId::GearDoorRight),
         ),
+        (32_030, FailureType::GearStrutUnderServiced(GearWheel::NOSE)),
+        (32_031, FailureType::GearStrutUnderServiced(GearWheel::LEFT)),
+        (
+            32_032,
+            FailureType::GearStrutUnderServiced(GearWheel::RIGHT),
+        ),
         (
             32_100,
             FailureType::BrakeHydraulicLeak(HydraulicColor::Green),
@@ -290,6 +310,33 @@ async fn systems(mut gauge: msfs::Gauge) -> Result<(), Box<dyn Error>> {
             FailureType::BrakeHydraulicLeak(HydraulicColor::Yellow),
         ),
         (32_150, FailureType::BrakeAccumulatorGasLeak),
+        (
+            32_200,
+            FailureType::SteeringActuatorJammed(SteeringActuatorId::Nose),
+        ),
+        (
+            32_201,
+            FailureType::SteeringServoValve(SteeringActuatorId::Nose),
+        ),
+        (
+            32_202,
+            FailureType::SteeringShimmyDamper(SteeringActuatorId::Nose),
+        ),
+        (32_210, FailureType::SteeringControlChannel(1)),
+        (32_211, FailureType::SteeringControlChannel(2)),
+        (32_301, FailureType::TyreSlowLeak(1)),
+        (32_302, FailureType::TyreSlowLeak(2)),
+        (32_303, FailureType::TyreSlowLeak(3)),
+        (32_304, FailureType::TyreSlowLeak(4)),
+        (32_305, FailureType::TyreSlowLeak(5)),
+        (32_306, FailureType::TyreSlowLeak(6)),
+        (32_351, FailureType::TyreBurst(1)),
+        (32_352, FailureType::TyreBurst(2)),
+        (32_353, FailureType::TyreBurst(3)),
+        (32_354, FailureType::TyreBurst(4)),
+        (32_355, FailureType::TyreBurst(5)),
+        (32_356, FailureType::TyreBurst(6)),
+        (32_400, FailureType::TyrePressureIndicatingSystem),
         (34_000, FailureType::RadioAltimeter(1)),
         (34_001, FailureType::RadioAltimeter(2)),
         (34_010, FailureType::RadioAntennaInterrupted(1)),
@@ -300,6 +347,123 @@ async fn systems(mut gauge: msfs::Gauge) -> Result<(), Box<dyn Error>> {
             34_030,
             FailureType::EnhancedGroundProximityWarningSystemComputer,
         ),
+        (36_000, FailureType::BleedHighPressureValveStuckOpen(1)),
+        (36_001, FailureType::BleedHighPressureValveStuckOpen(2)),
+        (36_010, FailureType::BleedHighPressureValveStuckClosed(1)),
+        (36_011, FailureType::BleedHighPressureValveStuckClosed(2)),
+        (
+            36_020,
+            FailureType::BleedPressureRegulatingValveStuckOpen(1),
+        ),
+        (
+            36_021,
+            FailureType::BleedPressureRegulatingValveStuckOpen(2),
+        ),
+        (
+            36_030,
+            FailureType::BleedPressureRegulatingValveStuckClosed(1),
+        ),
+        (
+            36_031,
+            FailureType::BleedPressureRegulatingValveStuckClosed(2),
+        ),
+        (36_040, FailureType::BleedFanAirValveStuckClosed(1)),
+        (36_041, FailureType::BleedFanAirValveStuckClosed(2)),
+        (36_050, FailureType::CrossBleedValveJammed(1)),
+        (36_060, FailureType::BleedDuctLeak(BleedDuctZone::Pylon(1))),
+        (36_061, FailureType::BleedDuctLeak(BleedDuctZone::Pylon(2))),
+        (36_070, FailureType::BleedDuctLeak(BleedDuctZone::Wing(1))),
+        (36_071, FailureType::BleedDuctLeak(BleedDuctZone::Wing(2))),
+        (36_080, FailureType::BleedDuctLeak(BleedDuctZone::Apu)),
+        (
+            36_090,
+            FailureType::BleedMonitoringComputerChannel(1, Channel::ChannelOne),
+        ),
+        (
+            36_091,
+            FailureType::BleedMonitoringComputerChannel(1, Channel::ChannelTwo),
+        ),
+        (
+            36_092,
+            FailureType::BleedMonitoringComputerChannel(2, Channel::ChannelOne),
+        ),
+        (
+            36_093,
+            FailureType::BleedMonitoringComputerChannel(2, Channel::ChannelTwo),
+        ),
+        (
+            36_100,
+            FailureType::BleedLeakDetectionLoop(FireDetectionLoopID::A, BleedDuctZone::Pylon(1)),
+        ),
+        (
+            36_102,
+            FailureType::BleedLeakDetectionLoop(FireDetectionLoopID::A, BleedDuctZone::Pylon(2)),
+        ),
+        (
+            36_110,
+            FailureType::BleedLeakDetectionLoop(FireDetectionLoopID::A, BleedDuctZone::Wing(1)),
+        ),
+        (
+            36_111,
+            FailureType::BleedLeakDetectionLoop(FireDetectionLoopID::B, BleedDuctZone::Wing(1)),
+        ),
+        (
+            36_112,
+            FailureType::BleedLeakDetectionLoop(FireDetectionLoopID::A, BleedDuctZone::Wing(2)),
+        ),
+        (
+            36_113,
+            FailureType::BleedLeakDetectionLoop(FireDetectionLoopID::B, BleedDuctZone::Wing(2)),
+        ),
+        (
+            36_120,
+            FailureType::BleedLeakDetectionLoop(FireDetectionLoopID::A, BleedDuctZone::Apu),
+        ),
+        (49_000, FailureType::ApuOverspeed),
+        (49_001, FailureType::ApuEgtOvertemperature),
+        (49_002, FailureType::ApuLowOilPressure),
+        (49_003, FailureType::ApuHighOilTemperature),
+        (49_004, FailureType::ApuAirIntakeFlapStuck),
+        (49_005, FailureType::ApuStartMotor),
+        (49_006, FailureType::ApuElectronicControlBox),
+        (49_007, FailureType::ApuGeneratorBearing),
+        (
+            52_000,
+            FailureType::CargoDoorLatchStuck(CargoDoorId::Forward),
+        ),
+        (52_001, FailureType::CargoDoorLatchStuck(CargoDoorId::Aft)),
+        (
+            52_010,
+            FailureType::CargoDoorHydraulicLock(CargoDoorId::Forward),
+        ),
+        (
+            52_011,
+            FailureType::CargoDoorHydraulicLock(CargoDoorId::Aft),
+        ),
+        (72_000, FailureType::EngineFanDamage(1)),
+        (72_001, FailureType::EngineFanDamage(2)),
+        (72_010, FailureType::EngineBirdStrike(1)),
+        (72_011, FailureType::EngineBirdStrike(2)),
+        (72_020, FailureType::EngineBearingDamage(1)),
+        (72_021, FailureType::EngineBearingDamage(2)),
+        (78_000, FailureType::ReverserUnlocked(1)),
+        (78_001, FailureType::ReverserUnlocked(2)),
+        (78_010, FailureType::ReverserFailToDeploy(1)),
+        (78_011, FailureType::ReverserFailToDeploy(2)),
+        (78_020, FailureType::ReverserFailToStow(1)),
+        (78_021, FailureType::ReverserFailToStow(2)),
+        (78_030, FailureType::ReverserSleeveJammed(1)),
+        (78_031, FailureType::ReverserSleeveJammed(2)),
+        (78_040, FailureType::ReverserActuationSupplyLoss(1)),
+        (78_041, FailureType::ReverserActuationSupplyLoss(2)),
+        (79_000, FailureType::EngineOilFilterClog(1)),
+        (79_001, FailureType::EngineOilFilterClog(2)),
+        (79_010, FailureType::EngineOilLowPressure(1)),
+        (79_011, FailureType::EngineOilLowPressure(2)),
+        (79_020, FailureType::EngineOilHighTemperature(1)),
+        (79_021, FailureType::EngineOilHighTemperature(2)),
+        (80_000, FailureType::EngineStarterValveStuckOpen(1)),
+        (80_001, FailureType::EngineStarterValveStuckOpen(2)),
     ])
     .provides_aircraft_variable("ACCELERATION BODY X", "feet per second squared", 0)?
     .provides_aircraft_variable("ACCELERATION BODY Y", "feet per second squared", 0)?
@@ -359,6 +523,8 @@ async fn systems(mut gauge: msfs::Gauge) -> Result<(), Box<dyn Error>> {
     .provides_aircraft_variable("PUSHBACK ANGLE", "Radians", 0)?
     .provides_aircraft_variable("SEA LEVEL PRESSURE", "Millibars", 0)?
     .provides_aircraft_variable("SIM ON GROUND", "Bool", 0)?
+    .provides_aircraft_variable("SURFACE CONDITION", "Enum", 0)?
+    .provides_aircraft_variable("SURFACE TYPE", "Enum", 0)?
     .provides_aircraft_variable("TOTAL AIR TEMPERATURE", "celsius", 0)?
     .provides_aircraft_variable("TRAILING EDGE FLAPS LEFT PERCENT", "Percent", 0)?
     .provides_aircraft_variable("TRAILING EDGE FLAPS RIGHT PERCENT", "Percent", 0)?
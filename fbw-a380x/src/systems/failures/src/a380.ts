@@ -126,6 +126,23 @@ export const A380Failure = Object.freeze({
   Sec3: 27005,
   Fcdc1: 27006,
   Fcdc2: 27007,
+  LeftMidAileronEha: 27101,
+  LeftInboardAileronEha: 27102,
+  RightMidAileronEha: 27103,
+  RightInboardAileronEha: 27104,
+  LeftOutboardElevatorEha: 27105,
+  LeftInboardElevatorEha: 27106,
+  RightOutboardElevatorEha: 27107,
+  RightInboardElevatorEha: 27108,
+  UpperRudderUpperEbha: 27109,
+  UpperRudderLowerEbha: 27110,
+  LowerRudderUpperEbha: 27111,
+  LowerRudderLowerEbha: 27112,
+  LeftSpoiler6Ebha: 27113,
+  RightSpoiler6Ebha: 27114,
+  ThsJam: 27200,
+  ThsGreenHydraulicMotor: 27201,
+  ThsYellowHydraulicMotor: 27202,
 
   GreenReservoirLeak: 29000,
   YellowReservoirLeak: 29001,
@@ -162,6 +179,70 @@ export const A380Failure = Object.freeze({
   GearProxSensorDamageGearUplockNose1: 32006,
   GearProxSensorDamageDoorUplockLeft2: 32007,
 
+  GearStrutUnderServicedNose: 32030,
+  GearStrutUnderServicedLeftBody: 32031,
+  GearStrutUnderServicedRightBody: 32032,
+  GearStrutUnderServicedLeftWing: 32033,
+  GearStrutUnderServicedRightWing: 32034,
+
+  NoseWheelSteeringActuatorJammed: 32200,
+  NoseWheelSteeringServoValve: 32201,
+  NoseWheelShimmyDamper: 32202,
+  LeftBodyWheelSteeringActuatorJammed: 32203,
+  RightBodyWheelSteeringActuatorJammed: 32204,
+  LeftBodyWheelSteeringServoValve: 32205,
+  RightBodyWheelSteeringServoValve: 32206,
+  LeftBodyWheelShimmyDamper: 32207,
+  RightBodyWheelShimmyDamper: 32208,
+  BodyWheelSteeringChannel1: 32210,
+  BodyWheelSteeringChannel2: 32211,
+
+  TyreSlowLeakWheel1: 32301,
+  TyreSlowLeakWheel2: 32302,
+  TyreSlowLeakWheel3: 32303,
+  TyreSlowLeakWheel4: 32304,
+  TyreSlowLeakWheel5: 32305,
+  TyreSlowLeakWheel6: 32306,
+  TyreSlowLeakWheel7: 32307,
+  TyreSlowLeakWheel8: 32308,
+  TyreSlowLeakWheel9: 32309,
+  TyreSlowLeakWheel10: 32310,
+  TyreSlowLeakWheel11: 32311,
+  TyreSlowLeakWheel12: 32312,
+  TyreSlowLeakWheel13: 32313,
+  TyreSlowLeakWheel14: 32314,
+  TyreSlowLeakWheel15: 32315,
+  TyreSlowLeakWheel16: 32316,
+  TyreSlowLeakWheel17: 32317,
+  TyreSlowLeakWheel18: 32318,
+  TyreSlowLeakWheel19: 32319,
+  TyreSlowLeakWheel20: 32320,
+  TyreSlowLeakWheel21: 32321,
+  TyreSlowLeakWheel22: 32322,
+  TyreBurstWheel1: 32351,
+  TyreBurstWheel2: 32352,
+  TyreBurstWheel3: 32353,
+  TyreBurstWheel4: 32354,
+  TyreBurstWheel5: 32355,
+  TyreBurstWheel6: 32356,
+  TyreBurstWheel7: 32357,
+  TyreBurstWheel8: 32358,
+  TyreBurstWheel9: 32359,
+  TyreBurstWheel10: 32360,
+  TyreBurstWheel11: 32361,
+  TyreBurstWheel12: 32362,
+  TyreBurstWheel13: 32363,
+  TyreBurstWheel14: 32364,
+  TyreBurstWheel15: 32365,
+  TyreBurstWheel16: 32366,
+  TyreBurstWheel17: 32367,
+  TyreBurstWheel18: 32368,
+  TyreBurstWheel19: 32369,
+  TyreBurstWheel20: 32370,
+  TyreBurstWheel21: 32371,
+  TyreBurstWheel22: 32372,
+  TyrePressureIndicatingSystem: 32400,
+
   RadioAltimeter1: 34000,
   RadioAltimeter2: 34001,
   RadioAltimeter3: 34002,
@@ -180,6 +261,57 @@ export const A380Failure = Object.freeze({
   Gpws1: 34007,
   Gpws2: 34008,
 
+  BleedHpValveStuckOpen1: 36000,
+  BleedHpValveStuckOpen2: 36001,
+  BleedHpValveStuckOpen3: 36002,
+  BleedHpValveStuckOpen4: 36003,
+  BleedHpValveStuckClosed1: 36010,
+  BleedHpValveStuckClosed2: 36011,
+  BleedHpValveStuckClosed3: 36012,
+  BleedHpValveStuckClosed4: 36013,
+  BleedPrValveStuckOpen1: 36020,
+  BleedPrValveStuckOpen2: 36021,
+  BleedPrValveStuckOpen3: 36022,
+  BleedPrValveStuckOpen4: 36023,
+  BleedPrValveStuckClosed1: 36030,
+  BleedPrValveStuckClosed2: 36031,
+  BleedPrValveStuckClosed3: 36032,
+  BleedPrValveStuckClosed4: 36033,
+  BleedFanAirValveStuckClosed1: 36040,
+  BleedFanAirValveStuckClosed2: 36041,
+  BleedFanAirValveStuckClosed3: 36042,
+  BleedFanAirValveStuckClosed4: 36043,
+  CrossBleedValveJammed1: 36050,
+  CrossBleedValveJammed2: 36051,
+  CrossBleedValveJammed3: 36052,
+  BleedPylonDuctLeak1: 36060,
+  BleedPylonDuctLeak2: 36061,
+  BleedPylonDuctLeak3: 36062,
+  BleedPylonDuctLeak4: 36063,
+  BleedWingDuctLeak1: 36070,
+  BleedWingDuctLeak2: 36071,
+  BleedWingDuctLeak3: 36072,
+  BleedWingDuctLeak4: 36073,
+  BleedApuDuctLeak: 36080,
+  BleedLeakLoopPylon1A: 36100,
+  BleedLeakLoopPylon1B: 36101,
+  BleedLeakLoopPylon2A: 36102,
+  BleedLeakLoopPylon2B: 36103,
+  BleedLeakLoopPylon3A: 36104,
+  BleedLeakLoopPylon3B: 36105,
+  BleedLeakLoopPylon4A: 36106,
+  BleedLeakLoopPylon4B: 36107,
+  BleedLeakLoopWing1A: 36110,
+  BleedLeakLoopWing1B: 36111,
+  BleedLeakLoopWing2A: 36112,
+  BleedLeakLoopWing2B: 36113,
+  BleedLeakLoopWing3A: 36114,
+  BleedLeakLoopWing3B: 36115,
+  BleedLeakLoopWing4A: 36116,
+  BleedLeakLoopWing4B: 36117,
+  BleedLeakLoopApuA: 36120,
+  BleedLeakLoopApuB: 36121,
+
   NssAnsu1: 46001,
   NssAnsu2: 46002,
   FltOpsAnsu: 46003,
@@ -187,6 +319,58 @@ export const A380Failure = Object.freeze({
   FirstOfficerLaptop: 46005,
   CaptainOit: 46006,
   FirstOfficerOit: 46007,
+
+  ApuOverspeed: 49000,
+  ApuEgtOvertemperature: 49001,
+  ApuLowOilPressure: 49002,
+  ApuHighOilTemperature: 49003,
+  ApuAirIntakeFlapStuck: 49004,
+  ApuStartMotor: 49005,
+  ApuElectronicControlBox: 49006,
+  ApuGeneratorBearing: 49007,
+
+  CargoDoorFwdLatchStuck: 52000,
+  CargoDoorAftLatchStuck: 52001,
+  CargoDoorFwdHydraulicLock: 52010,
+  CargoDoorAftHydraulicLock: 52011,
+  EngineFanDamage1: 72000,
+  EngineFanDamage2: 72001,
+  EngineFanDamage3: 72002,
+  EngineFanDamage4: 72003,
+  EngineBirdStrike1: 72010,
+  EngineBirdStrike2: 72011,
+  EngineBirdStrike3: 72012,
+  EngineBirdStrike4: 72013,
+  EngineBearingDamage1: 72020,
+  EngineBearingDamage2: 72021,
+  EngineBearingDamage3: 72022,
+  EngineBearingDamage4: 72023,
+  ReverserUnlocked2: 78001,
+  ReverserUnlocked3: 78002,
+  ReverserFailToDeploy2: 78011,
+  ReverserFailToDeploy3: 78012,
+  ReverserFailToStow2: 78021,
+  ReverserFailToStow3: 78022,
+  ReverserSleeveJammed2: 78031,
+  ReverserSleeveJammed3: 78032,
+  ReverserActuationSupplyLoss2: 78041,
+  ReverserActuationSupplyLoss3: 78042,
+  EngineOilFilterClog1: 79000,
+  EngineOilFilterClog2: 79001,
+  EngineOilFilterClog3: 79002,
+  EngineOilFilterClog4: 79003,
+  EngineOilLowPressure1: 79010,
+  EngineOilLowPressure2: 79011,
+  EngineOilLowPressure3: 79012,
+  EngineOilLowPressure4: 79013,
+  EngineOilHighTemperature1: 79020,
+  EngineOilHighTemperature2: 79021,
+  EngineOilHighTemperature3: 79022,
+  EngineOilHighTemperature4: 79023,
+  EngineStarterValveStuckOpen1: 80000,
+  EngineStarterValveStuckOpen2: 80001,
+  EngineStarterValveStuckOpen3: 80002,
+  EngineStarterValveStuckOpen4: 80003,
 });
 
 export const A380FailureDefinitions: FailureDefinition[] = [
@@ -312,6 +496,23 @@ export const A380FailureDefinitions: FailureDefinition[] = [
   [27, A380Failure.Sec3, 'SEC 3'],
   [27, A380Failure.Fcdc1, 'FCDC 1'],
   [27, A380Failure.Fcdc2, 'FCDC 2'],
+  [27, A380Failure.LeftMidAileronEha, 'Left mid aileron EHA'],
+  [27, A380Failure.LeftInboardAileronEha, 'Left inboard aileron EHA'],
+  [27, A380Failure.RightMidAileronEha, 'Right mid aileron EHA'],
+  [27, A380Failure.RightInboardAileronEha, 'Right inboard aileron EHA'],
+  [27, A380Failure.LeftOutboardElevatorEha, 'Left outboard elevator EHA'],
+  [27, A380Failure.LeftInboardElevatorEha, 'Left inboard elevator EHA'],
+  [27, A380Failure.RightOutboardElevatorEha, 'Right outboard elevator EHA'],
+  [27, A380Failure.RightInboardElevatorEha, 'Right inboard elevator EHA'],
+  [27, A380Failure.UpperRudderUpperEbha, 'Upper rudder upper EBHA'],
+  [27, A380Failure.UpperRudderLowerEbha, 'Upper rudder lower EBHA'],
+  [27, A380Failure.LowerRudderUpperEbha, 'Lower rudder upper EBHA'],
+  [27, A380Failure.LowerRudderLowerEbha, 'Lower rudder lower EBHA'],
+  [27, A380Failure.LeftSpoiler6Ebha, 'Left spoiler 6 EBHA'],
+  [27, A380Failure.RightSpoiler6Ebha, 'Right spoiler 6 EBHA'],
+  [27, A380Failure.ThsJam, 'THS jam'],
+  [27, A380Failure.ThsGreenHydraulicMotor, 'THS green hydraulic motor'],
+  [27, A380Failure.ThsYellowHydraulicMotor, 'THS yellow hydraulic motor'],
 
   [29, A380Failure.GreenReservoirLeak, 'Green reservoir leak'],
   [29, A380Failure.YellowReservoirLeak, 'Yellow reservoir leak'],
@@ -344,6 +545,67 @@ export const A380FailureDefinitions: FailureDefinition[] = [
   [32, A380Failure.LgciuInternalError2, 'LGCIU 2 Internal error'],
 
   [32, A380Failure.GearProxSensorDamageGearUplockNose1, 'Proximity sensor damage uplock nose gear #1'],
+  [32, A380Failure.GearStrutUnderServicedNose, 'Nose gear shock absorber under-serviced'],
+  [32, A380Failure.GearStrutUnderServicedLeftBody, 'Left body gear shock absorber under-serviced'],
+  [32, A380Failure.GearStrutUnderServicedRightBody, 'Right body gear shock absorber under-serviced'],
+  [32, A380Failure.GearStrutUnderServicedLeftWing, 'Left wing gear shock absorber under-serviced'],
+  [32, A380Failure.GearStrutUnderServicedRightWing, 'Right wing gear shock absorber under-serviced'],
+  [32, A380Failure.NoseWheelSteeringActuatorJammed, 'Nose wheel steering jammed actuator'],
+  [32, A380Failure.NoseWheelSteeringServoValve, 'Nose wheel steering servo valve'],
+  [32, A380Failure.NoseWheelShimmyDamper, 'Nose wheel shimmy damper'],
+  [32, A380Failure.LeftBodyWheelSteeringActuatorJammed, 'Left body wheel steering jammed actuator'],
+  [32, A380Failure.RightBodyWheelSteeringActuatorJammed, 'Right body wheel steering jammed actuator'],
+  [32, A380Failure.LeftBodyWheelSteeringServoValve, 'Left body wheel steering servo valve'],
+  [32, A380Failure.RightBodyWheelSteeringServoValve, 'Right body wheel steering servo valve'],
+  [32, A380Failure.LeftBodyWheelShimmyDamper, 'Left body wheel shimmy damper'],
+  [32, A380Failure.RightBodyWheelShimmyDamper, 'Right body wheel shimmy damper'],
+  [32, A380Failure.BodyWheelSteeringChannel1, 'Body wheel steering channel 1'],
+  [32, A380Failure.BodyWheelSteeringChannel2, 'Body wheel steering channel 2'],
+  [32, A380Failure.TyreSlowLeakWheel1, 'Wheel 1 tyre slow leak'],
+  [32, A380Failure.TyreSlowLeakWheel2, 'Wheel 2 tyre slow leak'],
+  [32, A380Failure.TyreSlowLeakWheel3, 'Wheel 3 tyre slow leak'],
+  [32, A380Failure.TyreSlowLeakWheel4, 'Wheel 4 tyre slow leak'],
+  [32, A380Failure.TyreSlowLeakWheel5, 'Wheel 5 tyre slow leak'],
+  [32, A380Failure.TyreSlowLeakWheel6, 'Wheel 6 tyre slow leak'],
+  [32, A380Failure.TyreSlowLeakWheel7, 'Wheel 7 tyre slow leak'],
+  [32, A380Failure.TyreSlowLeakWheel8, 'Wheel 8 tyre slow leak'],
+  [32, A380Failure.TyreSlowLeakWheel9, 'Wheel 9 tyre slow leak'],
+  [32, A380Failure.TyreSlowLeakWheel10, 'Wheel 10 tyre slow leak'],
+  [32, A380Failure.TyreSlowLeakWheel11, 'Wheel 11 tyre slow leak'],
+  [32, A380Failure.TyreSlowLeakWheel12, 'Wheel 12 tyre slow leak'],
+  [32, A380Failure.TyreSlowLeakWheel13, 'Wheel 13 tyre slow leak'],
+  [32, A380Failure.TyreSlowLeakWheel14, 'Wheel 14 tyre slow leak'],
+  [32, A380Failure.TyreSlowLeakWheel15, 'Wheel 15 tyre slow leak'],
+  [32, A380Failure.TyreSlowLeakWheel16, 'Wheel 16 tyre slow leak'],
+  [32, A380Failure.TyreSlowLeakWheel17, 'Wheel 17 tyre slow leak'],
+  [32, A380Failure.TyreSlowLeakWheel18, 'Wheel 18 tyre slow leak'],
+  [32, A380Failure.TyreSlowLeakWheel19, 'Wheel 19 tyre slow leak'],
+  [32, A380Failure.TyreSlowLeakWheel20, 'Wheel 20 tyre slow leak'],
+  [32, A380Failure.TyreSlowLeakWheel21, 'Wheel 21 tyre slow leak'],
+  [32, A380Failure.TyreSlowLeakWheel22, 'Wheel 22 tyre slow leak'],
+  [32, A380Failure.TyreBurstWheel1, 'Wheel 1 tyre burst'],
+  [32, A380Failure.TyreBurstWheel2, 'Wheel 2 tyre burst'],
+  [32, A380Failure.TyreBurstWheel3, 'Wheel 3 tyre burst'],
+  [32, A380Failure.TyreBurstWheel4, 'Wheel 4 tyre burst'],
+  [32, A380Failure.TyreBurstWheel5, 'Wheel 5 tyre burst'],
+  [32, A380Failure.TyreBurstWheel6, 'Wheel 6 tyre burst'],
+  [32, A380Failure.TyreBurstWheel7, 'Wheel 7 tyre burst'],
+  [32, A380Failure.TyreBurstWheel8, 'Wheel 8 tyre burst'],
+  [32, A380Failure.TyreBurstWheel9, 'Wheel 9 tyre burst'],
+  [32, A380Failure.TyreBurstWheel10, 'Wheel 10 tyre burst'],
+  [32, A380Failure.TyreBurstWheel11, 'Wheel 11 tyre burst'],
+  [32, A380Failure.TyreBurstWheel12, 'Wheel 12 tyre burst'],
+  [32, A380Failure.TyreBurstWheel13, 'Wheel 13 tyre burst'],
+  [32, A380Failure.TyreBurstWheel14, 'Wheel 14 tyre burst'],
+  [32, A380Failure.TyreBurstWheel15, 'Wheel 15 tyre burst'],
+  [32, A380Failure.TyreBurstWheel16, 'Wheel 16 tyre burst'],
+  [32, A380Failure.TyreBurstWheel17, 'Wheel 17 tyre burst'],
+  [32, A380Failure.TyreBurstWheel18, 'Wheel 18 tyre burst'],
+  [32, A380Failure.TyreBurstWheel19, 'Wheel 19 tyre burst'],
+  [32, A380Failure.TyreBurstWheel20, 'Wheel 20 tyre burst'],
+  [32, A380Failure.TyreBurstWheel21, 'Wheel 21 tyre burst'],
+  [32, A380Failure.TyreBurstWheel22, 'Wheel 22 tyre burst'],
+  [32, A380Failure.TyrePressureIndicatingSystem, 'TPIS'],
 
   [34, A380Failure.RadioAltimeter1, 'RA SYS A'],
   [34, A380Failure.RadioAltimeter2, 'RA SYS B'],
@@ -361,6 +623,57 @@ export const A380FailureDefinitions: FailureDefinition[] = [
   [34, A380Failure.Transponder1, 'XPDR 1'],
   [34, A380Failure.Transponder2, 'XPDR 2'],
 
+  [36, A380Failure.BleedHpValveStuckOpen1, 'ENG 1 HP valve stuck open'],
+  [36, A380Failure.BleedHpValveStuckOpen2, 'ENG 2 HP valve stuck open'],
+  [36, A380Failure.BleedHpValveStuckOpen3, 'ENG 3 HP valve stuck open'],
+  [36, A380Failure.BleedHpValveStuckOpen4, 'ENG 4 HP valve stuck open'],
+  [36, A380Failure.BleedHpValveStuckClosed1, 'ENG 1 HP valve stuck closed'],
+  [36, A380Failure.BleedHpValveStuckClosed2, 'ENG 2 HP valve stuck closed'],
+  [36, A380Failure.BleedHpValveStuckClosed3, 'ENG 3 HP valve stuck closed'],
+  [36, A380Failure.BleedHpValveStuckClosed4, 'ENG 4 HP valve stuck closed'],
+  [36, A380Failure.BleedPrValveStuckOpen1, 'ENG 1 bleed valve stuck open'],
+  [36, A380Failure.BleedPrValveStuckOpen2, 'ENG 2 bleed valve stuck open'],
+  [36, A380Failure.BleedPrValveStuckOpen3, 'ENG 3 bleed valve stuck open'],
+  [36, A380Failure.BleedPrValveStuckOpen4, 'ENG 4 bleed valve stuck open'],
+  [36, A380Failure.BleedPrValveStuckClosed1, 'ENG 1 bleed valve stuck closed'],
+  [36, A380Failure.BleedPrValveStuckClosed2, 'ENG 2 bleed valve stuck closed'],
+  [36, A380Failure.BleedPrValveStuckClosed3, 'ENG 3 bleed valve stuck closed'],
+  [36, A380Failure.BleedPrValveStuckClosed4, 'ENG 4 bleed valve stuck closed'],
+  [36, A380Failure.BleedFanAirValveStuckClosed1, 'ENG 1 fan air valve stuck closed'],
+  [36, A380Failure.BleedFanAirValveStuckClosed2, 'ENG 2 fan air valve stuck closed'],
+  [36, A380Failure.BleedFanAirValveStuckClosed3, 'ENG 3 fan air valve stuck closed'],
+  [36, A380Failure.BleedFanAirValveStuckClosed4, 'ENG 4 fan air valve stuck closed'],
+  [36, A380Failure.CrossBleedValveJammed1, 'X-bleed valve L jammed'],
+  [36, A380Failure.CrossBleedValveJammed2, 'X-bleed valve C jammed'],
+  [36, A380Failure.CrossBleedValveJammed3, 'X-bleed valve R jammed'],
+  [36, A380Failure.BleedPylonDuctLeak1, 'ENG 1 pylon bleed duct leak'],
+  [36, A380Failure.BleedPylonDuctLeak2, 'ENG 2 pylon bleed duct leak'],
+  [36, A380Failure.BleedPylonDuctLeak3, 'ENG 3 pylon bleed duct leak'],
+  [36, A380Failure.BleedPylonDuctLeak4, 'ENG 4 pylon bleed duct leak'],
+  [36, A380Failure.BleedWingDuctLeak1, 'ENG 1 wing bleed duct leak'],
+  [36, A380Failure.BleedWingDuctLeak2, 'ENG 2 wing bleed duct leak'],
+  [36, A380Failure.BleedWingDuctLeak3, 'ENG 3 wing bleed duct leak'],
+  [36, A380Failure.BleedWingDuctLeak4, 'ENG 4 wing bleed duct leak'],
+  [36, A380Failure.BleedApuDuctLeak, 'APU bleed duct leak'],
+  [36, A380Failure.BleedLeakLoopPylon1A, 'ENG 1 pylon leak detection loop A'],
+  [36, A380Failure.BleedLeakLoopPylon1B, 'ENG 1 pylon leak detection loop B'],
+  [36, A380Failure.BleedLeakLoopPylon2A, 'ENG 2 pylon leak detection loop A'],
+  [36, A380Failure.BleedLeakLoopPylon2B, 'ENG 2 pylon leak detection loop B'],
+  [36, A380Failure.BleedLeakLoopPylon3A, 'ENG 3 pylon leak detection loop A'],
+  [36, A380Failure.BleedLeakLoopPylon3B, 'ENG 3 pylon leak detection loop B'],
+  [36, A380Failure.BleedLeakLoopPylon4A, 'ENG 4 pylon leak detection loop A'],
+  [36, A380Failure.BleedLeakLoopPylon4B, 'ENG 4 pylon leak detection loop B'],
+  [36, A380Failure.BleedLeakLoopWing1A, 'ENG 1 wing leak detection loop A'],
+  [36, A380Failure.BleedLeakLoopWing1B, 'ENG 1 wing leak detection loop B'],
+  [36, A380Failure.BleedLeakLoopWing2A, 'ENG 2 wing leak detection loop A'],
+  [36, A380Failure.BleedLeakLoopWing2B, 'ENG 2 wing leak detection loop B'],
+  [36, A380Failure.BleedLeakLoopWing3A, 'ENG 3 wing leak detection loop A'],
+  [36, A380Failure.BleedLeakLoopWing3B, 'ENG 3 wing leak detection loop B'],
+  [36, A380Failure.BleedLeakLoopWing4A, 'ENG 4 wing leak detection loop A'],
+  [36, A380Failure.BleedLeakLoopWing4B, 'ENG 4 wing leak detection loop B'],
+  [36, A380Failure.BleedLeakLoopApuA, 'APU leak detection loop A'],
+  [36, A380Failure.BleedLeakLoopApuB, 'APU leak detection loop B'],
+
   [46, A380Failure.NssAnsu1, 'NSS AVNCS ANSU 1'],
   [46, A380Failure.NssAnsu2, 'NSS AVNCS ANSU 2'],
   [46, A380Failure.FltOpsAnsu, 'FLT OPS ANSU'],
@@ -368,4 +681,58 @@ export const A380FailureDefinitions: FailureDefinition[] = [
   [46, A380Failure.FirstOfficerLaptop, 'F/O Laptop'],
   [46, A380Failure.CaptainOit, 'Captain OIT'],
   [46, A380Failure.FirstOfficerOit, 'F/O OIT'],
+
+  [49, A380Failure.ApuOverspeed, 'APU overspeed'],
+  [49, A380Failure.ApuEgtOvertemperature, 'APU EGT overtemperature'],
+  [49, A380Failure.ApuLowOilPressure, 'APU low oil pressure'],
+  [49, A380Failure.ApuHighOilTemperature, 'APU high oil temperature'],
+  [49, A380Failure.ApuAirIntakeFlapStuck, 'APU air intake flap stuck'],
+  [49, A380Failure.ApuStartMotor, 'APU start motor'],
+  [49, A380Failure.ApuElectronicControlBox, 'APU ECB'],
+  [49, A380Failure.ApuGeneratorBearing, 'APU generator bearing'],
+
+  [52, A380Failure.CargoDoorFwdLatchStuck, 'Forward cargo door latch stuck'],
+  [52, A380Failure.CargoDoorAftLatchStuck, 'Aft cargo door latch stuck'],
+  [52, A380Failure.CargoDoorFwdHydraulicLock, 'Forward cargo door hydraulic lock'],
+  [52, A380Failure.CargoDoorAftHydraulicLock, 'Aft cargo door hydraulic lock'],
+
+  [72, A380Failure.EngineFanDamage1, 'ENG 1 fan damage'],
+  [72, A380Failure.EngineFanDamage2, 'ENG 2 fan damage'],
+  [72, A380Failure.EngineFanDamage3, 'ENG 3 fan damage'],
+  [72, A380Failure.EngineFanDamage4, 'ENG 4 fan damage'],
+  [72, A380Failure.EngineBirdStrike1, 'ENG 1 bird strike'],
+  [72, A380Failure.EngineBirdStrike2, 'ENG 2 bird strike'],
+  [72, A380Failure.EngineBirdStrike3, 'ENG 3 bird strike'],
+  [72, A380Failure.EngineBirdStrike4, 'ENG 4 bird strike'],
+  [72, A380Failure.EngineBearingDamage1, 'ENG 1 bearing damage'],
+  [72, A380Failure.EngineBearingDamage2, 'ENG 2 bearing damage'],
+  [72, A380Failure.EngineBearingDamage3, 'ENG 3 bearing damage'],
+  [72, A380Failure.EngineBearingDamage4, 'ENG 4 bearing damage'],
+  [78, A380Failure.ReverserUnlocked2, 'ENG 2 reverser unlocked'],
+  [78, A380Failure.ReverserUnlocked3, 'ENG 3 reverser unlocked'],
+  [78, A380Failure.ReverserFailToDeploy2, 'ENG 2 reverser fail to deploy'],
+  [78, A380Failure.ReverserFailToDeploy3, 'ENG 3 reverser fail to deploy'],
+  [78, A380Failure.ReverserFailToStow2, 'ENG 2 reverser fail to stow'],
+  [78, A380Failure.ReverserFailToStow3, 'ENG 3 reverser fail to stow'],
+  [78, A380Failure.ReverserSleeveJammed2, 'ENG 2 reverser sleeve jammed'],
+  [78, A380Failure.ReverserSleeveJammed3, 'ENG 3 reverser sleeve jammed'],
+  [78, A380Failure.ReverserActuationSupplyLoss2, 'ENG 2 reverser actuation supply loss'],
+  [78, A380Failure.ReverserActuationSupplyLoss3, 'ENG 3 reverser actuation supply loss'],
+  [79, A380Failure.EngineOilFilterClog1, 'ENG 1 oil filter clog'],
+  [79, A380Failure.EngineOilFilterClog2, 'ENG 2 oil filter clog'],
+  [79, A380Failure.EngineOilFilterClog3, 'ENG 3 oil filter clog'],
+  [79, A380Failure.EngineOilFilterClog4, 'ENG 4 oil filter clog'],
+  [79, A380Failure.EngineOilLowPressure1, 'ENG 1 oil low pressure'],
+  [79, A380Failure.EngineOilLowPressure2, 'ENG 2 oil low pressure'],
+  [79, A380Failure.EngineOilLowPressure3, 'ENG 3 oil low pressure'],
+  [79, A380Failure.EngineOilLowPressure4, 'ENG 4 oil low pressure'],
+  [79, A380Failure.EngineOilHighTemperature1, 'ENG 1 oil high temperature'],
+  [79, A380Failure.EngineOilHighTemperature2, 'ENG 2 oil high temperature'],
+  [79, A380Failure.EngineOilHighTemperature3, 'ENG 3 oil high temperature'],
+  [79, A380Failure.EngineOilHighTemperature4, 'ENG 4 oil high temperature'],
+
+  [80, A380Failure.EngineStarterValveStuckOpen1, 'ENG 1 starter valve stuck open'],
+  [80, A380Failure.EngineStarterValveStuckOpen2, 'ENG 2 starter valve stuck open'],
+  [80, A380Failure.EngineStarterValveStuckOpen3, 'ENG 3 starter valve stuck open'],
+  [80, A380Failure.EngineStarterValveStuckOpen4, 'ENG 4 starter valve stuck open'],
 ];
@@ -32,14 +32,12 @@ const EngineColumn: FC<Position & EngineNumber & IgnitionActive & EngineColumnPr
 
   const [fuelFlow] = useSimVar(`L:A32NX_ENGINE_FF:${engine}`, 'number', 100);
 
-  const [n1Vibration] = useSimVar(`TURB ENG VIBRATION:${engine}`, 'number', 100);
-  const n2Vibration = n1Vibration;
-  const n3Vibration = n1Vibration;
+  const [n1Vibration] = useSimVar(`L:A32NX_ENGINE_${engine}_N1_VIBRATION`, 'number', 100);
+  const [n2Vibration] = useSimVar(`L:A32NX_ENGINE_${engine}_N2_VIBRATION`, 'number', 100);
+  const [n3Vibration] = useSimVar(`L:A32NX_ENGINE_${engine}_N3_VIBRATION`, 'number', 100);
 
-  const [oilQuantity] = useSimVar(`L:A32NX_ENGINE_OIL_QTY:${engine}`, 'number', 500); // TODO: Update with correct SimVars
-  const [engineOilTemperature] = useSimVar(`GENERAL ENG OIL TEMPERATURE:${engine}`, 'celsius', 100); // TODO: Update with correct SimVars
-  // fbw-a380x\src\wasm\fadec_a380x\src\Fadec\EngineControl_A380X.cpp
-  // has all the engine oil simvars in a large section commented out
+  const [oilQuantity] = useSimVar(`L:A32NX_ENGINE_${engine}_OIL_QUANTITY`, 'number', 500);
+  const [engineOilTemperature] = useSimVar(`L:A32NX_ENGINE_${engine}_OIL_TEMPERATURE`, 'number', 100);
 
   return (
     <>
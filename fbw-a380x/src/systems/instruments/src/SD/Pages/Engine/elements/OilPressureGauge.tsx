@@ -10,7 +10,7 @@ interface OilPressureGaugeProps {
 }
 
 const OilPressureGauge: FC<OilPressureGaugeProps> = ({ x, y, engine, active }) => {
-  const [engineOilPressure] = useSimVar(`ENG OIL PRESSURE:${engine}`, 'psi', 100);
+  const [engineOilPressure] = useSimVar(`L:A32NX_ENGINE_${engine}_OIL_PRESSURE`, 'number', 100);
   const radius = 45;
   const startAngle = -90;
   const endAngle = 90;
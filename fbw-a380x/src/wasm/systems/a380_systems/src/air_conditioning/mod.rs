@@ -4,6 +4,7 @@ use systems::{
     air_conditioning::{
         acs_controller::Pack,
         cabin_air::CabinAirSimulation,
+        preconditioned_air_unit::PreconditionedAirUnit,
         pressure_valve::{NegativeRelieveValveSignal, SafetyValve},
         AdirsToAirCondInterface, Air, AirConditioningOverheadShared, AirConditioningPack,
         AirHeater, CabinFan, DuctTemperature, FdacId, MixerUnit, OcsmId, OutletAir,
@@ -32,8 +33,9 @@ use systems::{
 
 use std::time::Duration;
 use uom::si::{
-    f64::*, length::foot, thermodynamic_temperature::degree_celsius, velocity::foot_per_minute,
-    volume::cubic_meter, volume_rate::liter_per_second,
+    f64::*, length::foot, mass_rate::kilogram_per_second,
+    thermodynamic_temperature::degree_celsius, velocity::foot_per_minute, volume::cubic_meter,
+    volume_rate::liter_per_second,
 };
 
 use crate::{
@@ -379,6 +381,7 @@ pub(super) struct A380AirConditioningSystem {
     mixer_unit: MixerUnit<18>,
     // Temporary structure until packs are simulated
     packs: [AirConditioningPack; 2],
+    preconditioned_air_unit: PreconditionedAirUnit,
     trim_air_system: TrimAirSystem<18, 4>,
 
     air_conditioning_overhead: A380AirConditioningSystemOverhead,
@@ -386,6 +389,7 @@ pub(super) struct A380AirConditioningSystem {
 
 impl A380AirConditioningSystem {
     const CAB_FAN_DESIGN_FLOW_RATE_L_S: f64 = 1250.; // litres/sec
+    const PCA_RATED_FLOW_KG_S: f64 = 5.; // kg/sec
 
     fn new(context: &mut InitContext, cabin_zones: &[ZoneType; 18]) -> Self {
         Self {
@@ -453,6 +457,10 @@ impl A380AirConditioningSystem {
                 AirConditioningPack::new(context, Pack(1)),
                 AirConditioningPack::new(context, Pack(2)),
             ],
+            preconditioned_air_unit: PreconditionedAirUnit::new(
+                context,
+                MassRate::new::<kilogram_per_second>(Self::PCA_RATED_FLOW_KG_S),
+            ),
             trim_air_system: TrimAirSystem::new(
                 context,
                 cabin_zones,
@@ -494,6 +502,9 @@ impl A380AirConditioningSystem {
 
         self.update_packs(context, cpiom_b);
 
+        self.preconditioned_air_unit
+            .update(context, cabin_simulation.cabin_pressure());
+
         self.update_mixer_unit();
 
         self.update_trim_air_system(context);
@@ -589,7 +600,11 @@ impl A380AirConditioningSystem {
     }
 
     fn update_mixer_unit(&mut self) {
-        let mut mixer_intakes: Vec<&dyn OutletAir> = vec![&self.packs[0], &self.packs[1]];
+        let mut mixer_intakes: Vec<&dyn OutletAir> = vec![
+            &self.packs[0],
+            &self.packs[1],
+            &self.preconditioned_air_unit,
+        ];
         for fan in self.cabin_fans.iter() {
             mixer_intakes.push(fan)
         }
@@ -787,6 +802,7 @@ impl SimulationElement for A380AirConditioningSystem {
         self.trim_air_system.accept(visitor);
         accept_iterable!(self.cabin_fans, visitor);
         accept_iterable!(self.packs, visitor);
+        self.preconditioned_air_unit.accept(visitor);
         self.cargo_air_heater.accept(visitor);
 
         self.air_conditioning_overhead.accept(visitor);
@@ -2509,6 +2525,11 @@ mod tests {
             self
         }
 
+        fn command_pca_on(mut self) -> Self {
+            self.write_by_name("PCA_TURNED_ON", true);
+            self
+        }
+
         fn command_hot_air_pb_on(mut self, on_off: bool, pb_id: usize) -> Self {
             self.write_by_name(
                 format!("OVHD_COND_HOT_AIR_{}_PB_IS_ON", pb_id).as_str(),
@@ -4822,6 +4843,24 @@ mod tests {
                 );
             }
 
+            #[test]
+            fn mixer_unit_outlet_is_preconditioned_air_without_packs_or_fans() {
+                let test_bed = test_bed()
+                    .on_ground()
+                    .with()
+                    .command_cab_fans_pb_on(false)
+                    .and()
+                    .command_packs_on_off(false)
+                    .and()
+                    .command_pca_on()
+                    .iterate(10);
+
+                assert_gt!(
+                    test_bed.mixer_unit_outlet_air().flow_rate(),
+                    MassRate::default()
+                );
+            }
+
             #[test]
             fn mixer_unit_outlet_is_same_as_packs_without_cab_fans() {
                 let test_bed = test_bed()
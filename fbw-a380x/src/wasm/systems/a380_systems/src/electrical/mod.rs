@@ -296,6 +296,12 @@ impl A380Electrical {
         self.alternating_current.gen_drive_connected(number)
     }
 
+    /// Mechanical power drawn by the engine generator from the engine accessory gearbox.
+    pub fn engine_generator_shaft_power_extraction(&self, number: usize) -> Power {
+        self.alternating_current
+            .engine_generator_shaft_power_extraction(number)
+    }
+
     pub fn in_emergency_elec(&self) -> bool {
         self.emergency_elec.is_in_emergency_elec()
     }
@@ -387,10 +393,6 @@ impl A380ElectricalOverheadPanel {
         });
     }
 
-    pub fn external_power_is_available(&self, number: usize) -> bool {
-        self.ext_pwrs[number - 1].is_available()
-    }
-
     pub fn external_power_is_on(&self, number: usize) -> bool {
         self.ext_pwrs[number - 1].is_on()
     }
@@ -574,7 +576,7 @@ mod a380_electrical_circuit_tests {
         electrical::{
             ElectricalElement, ElectricalElementIdentifier, ElectricalElementIdentifierProvider,
             Electricity, ElectricitySource, ExternalPowerSource, Potential, ProvideFrequency,
-            ProvidePotential, INTEGRATED_DRIVE_GENERATOR_STABILIZATION_TIME,
+            ProvideLoad, ProvidePotential, INTEGRATED_DRIVE_GENERATOR_STABILIZATION_TIME,
         },
         failures::FailureType,
         shared::{
@@ -3219,6 +3221,15 @@ mod a380_electrical_circuit_tests {
             self.is_available
         }
     }
+    impl ProvideLoad for TestApuGenerator {
+        fn load(&self) -> Ratio {
+            Ratio::default()
+        }
+
+        fn load_normal(&self) -> bool {
+            true
+        }
+    }
     impl ProvidePotential for TestApuGenerator {
         fn potential(&self) -> ElectricPotential {
             if self.is_available {
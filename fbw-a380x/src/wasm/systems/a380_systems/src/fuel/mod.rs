@@ -11,7 +11,9 @@ use fuel_quantity_data_concentrator::FuelQuantityDataConcentrator;
 use nalgebra::Vector3;
 use systems::{
     accept_iterable,
-    fuel::{FuelCG, FuelInfo, FuelPayload, FuelPump, FuelPumpProperties, FuelSystem},
+    fuel::{
+        FuelCG, FuelInfo, FuelPayload, FuelPump, FuelPumpProperties, FuelSystem, FuelTemperature,
+    },
     integrated_modular_avionics::AvionicsDataCommunicationNetwork,
     payload::LoadsheetInfo,
     shared::{arinc429::Arinc429Word, ElectricalBusType},
@@ -153,7 +155,11 @@ impl A380Fuel {
         context: &UpdateContext,
         acdn: &A380AvionicsDataCommunicationNetwork,
         loadsheet: &LoadsheetInfo,
+        engines_heat_rejection: Power,
     ) {
+        self.fuel_system
+            .update_fuel_temperature(context, engines_heat_rejection);
+
         let cpioms = ["F1", "F2", "F3", "F4"].map(|id| acdn.get_cpiom(id));
         for fqdc in &mut self.fuel_quantity_data_concentrators {
             fqdc.update(&self.fuel_system);
@@ -188,6 +194,11 @@ impl FuelCG for A380Fuel {
         self.fuel_system.center_of_gravity()
     }
 }
+impl FuelTemperature for A380Fuel {
+    fn fuel_temperature(&self) -> ThermodynamicTemperature {
+        self.fuel_system.fuel_system().fuel_temperature()
+    }
+}
 impl SimulationElement for A380Fuel {
     fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
         self.fuel_system.accept(visitor);
@@ -450,6 +461,10 @@ impl A380FuelSystem {
         &self.fuel_system
     }
 
+    fn update_fuel_temperature(&mut self, context: &UpdateContext, heat_input: Power) {
+        self.fuel_system.update(context, heat_input);
+    }
+
     fn tank_has_fuel(&self, tank: A380FuelTankType) -> bool {
         self.fuel_system().tank_has_fuel(tank as usize)
     }
@@ -41,8 +41,12 @@ impl Aircraft for FuelTestAircraft {
         _electricity: &mut Electricity,
     ) {
         self.acdn.update();
-        self.fuel
-            .update(context, &self.acdn, A380Airframe::get_loadsheet());
+        self.fuel.update(
+            context,
+            &self.acdn,
+            A380Airframe::get_loadsheet(),
+            Power::default(),
+        );
     }
 }
 impl SimulationElement for FuelTestAircraft {
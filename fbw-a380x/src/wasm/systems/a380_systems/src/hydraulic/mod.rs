@@ -4,13 +4,16 @@ use std::time::Duration;
 use uom::si::{
     angle::degree,
     angular_velocity::{degree_per_second, radian_per_second, revolution_per_minute},
+    area::square_meter,
     electric_current::ampere,
     f64::*,
+    force::{kilonewton, newton},
     length::{inch, meter},
     mass::kilogram,
     pressure::psi,
     ratio::{percent, ratio},
     thermodynamic_temperature::degree_celsius,
+    torque::newton_meter,
     velocity::knot,
     volume::{cubic_inch, gallon, liter},
     volume_rate::gallon_per_second,
@@ -19,16 +22,22 @@ use uom::si::{
 use systems::{
     accept_iterable,
     engine::Engine,
+    failures::{Failure, FailureType},
     hydraulic::{
         aerodynamic_model::AerodynamicModel,
-        brake::{BrakeAssembly, BrakeProperties},
+        anti_skid::{
+            braking_force_transmission_ratio, AntiSkidController, BrakedWheel, TyreRunwayFriction,
+        },
+        brake::{BrakeAssembly, BrakeProperties, UnbrakedWheels},
         brake_circuit::{BrakeAccumulatorCharacteristics, BrakeCircuit, BrakeCircuitController},
         bypass_pin::BypassPin,
         cargo_doors::{CargoDoor, HydraulicDoorController},
         flap_slat::{
             FlapSlatAssembly, SecondarySurface, SecondarySurfaceSide, SecondarySurfaceType,
         },
-        landing_gear::{GearGravityExtension, GearSystemController, HydraulicGearSystem},
+        landing_gear::{
+            GearGravityExtension, GearStructuralLimits, GearSystemController, HydraulicGearSystem,
+        },
         linear_actuator::{
             Actuator, BoundedLinearLength, ElectroHydrostaticActuatorType,
             ElectroHydrostaticBackup, ElectroHydrostaticPowered, HydraulicAssemblyController,
@@ -36,10 +45,11 @@ use systems::{
             LinearActuator, LinearActuatorCharacteristics, LinearActuatorMode,
         },
         nose_steering::{
-            SteeringActuator, SteeringAngleLimiter, SteeringController, SteeringRatioToAngle,
+            NoseWheelSteeringControl, PedalSteeringDynamicLimiter, SteeringActuator,
+            SteeringAngleLimiter, SteeringAngleLimits, SteeringController, SteeringRatioToAngle,
         },
         pumps::PumpCharacteristics,
-        pushback::PushbackTug,
+        pushback::{PushbackTug, TowingLimits},
         trimmable_horizontal_stabilizer::{
             TrimmableHorizontalStabilizerActuator, TrimmableHorizontalStabilizerMotorController,
         },
@@ -47,15 +57,18 @@ use systems::{
         HydraulicCircuitController, HydraulicPressureSensors, ManualPump, PressureSwitch,
         PressureSwitchType, PriorityValve, PumpController, Reservoir,
     },
-    landing_gear::{GearSystemSensors, LandingGearControlInterfaceUnitSet, TiltingGear},
+    landing_gear::{
+        tpis::TyrePressureIndicatingSystem, GearSystemSensors, LandingGear,
+        LandingGearControlInterfaceUnitSet, ShockAbsorber, TiltingGear,
+    },
     overhead::{AutoOffFaultPushButton, AutoOnFaultPushButton},
     shared::{
         interpolation, random_from_range, update_iterator::MaxStepLoop, AdirsDiscreteOutputs,
-        AdirsMeasurementOutputs, AirbusElectricPumpId, AirbusEngineDrivenPumpId, CargoDoorLocked,
-        ControllerSignal, DelayedFalseLogicGate, DelayedTrueLogicGate, ElectricalBusType,
-        ElectricalBuses, EngineFirePushButtons, GearWheel, HydraulicColor, LandingGearHandle,
-        LgciuInterface, LgciuWeightOnWheels, ReservoirAirPressure, SectionPressure,
-        SurfacesPositions,
+        AdirsMeasurementOutputs, AirbusElectricPumpId, AirbusEngineDrivenPumpId, CargoDoorId,
+        CargoDoorLocked, ControllerSignal, DelayedFalseLogicGate, DelayedTrueLogicGate,
+        ElectricalBusType, ElectricalBuses, EngineFirePushButtons, GearWheel, HydraulicColor,
+        LandingGearHandle, LgciuInterface, LgciuWeightOnWheels, ReservoirAirPressure,
+        SectionPressure, SteeringActuatorId, SurfacesPositions,
     },
     simulation::{
         InitContext, Read, SimulationElement, SimulationElementVisitor, SimulatorReader,
@@ -80,6 +93,74 @@ use systems::hydraulic::PressureSwitchState;
 
 const AC_EHA_BUS: ElectricalBusType = ElectricalBusType::AlternatingCurrentNamed("247XP");
 
+pub(super) struct A380LandingGearFactory {}
+impl A380LandingGearFactory {
+    // Contact point loads are scaled so that static load at max landing weight is half the max contact load
+    const BODY_STRUT_MAX_STROKE_METERS: f64 = 0.6;
+    const BODY_STRUT_PISTON_AREA_SQUARE_METERS: f64 = 0.072;
+    const BODY_STRUT_GAS_VOLUME_LITERS: f64 = 63.5;
+    const BODY_STRUT_PRECHARGE_PRESSURE_PSI: f64 = 1126.;
+    const BODY_STRUT_MAX_CONTACT_LOAD_NEWTONS: f64 = 2200000.;
+
+    const WING_STRUT_MAX_STROKE_METERS: f64 = 0.55;
+    const WING_STRUT_PISTON_AREA_SQUARE_METERS: f64 = 0.052;
+    const WING_STRUT_GAS_VOLUME_LITERS: f64 = 42.1;
+    const WING_STRUT_PRECHARGE_PRESSURE_PSI: f64 = 1098.;
+    const WING_STRUT_MAX_CONTACT_LOAD_NEWTONS: f64 = 1550000.;
+
+    const NOSE_STRUT_MAX_STROKE_METERS: f64 = 0.5;
+    const NOSE_STRUT_PISTON_AREA_SQUARE_METERS: f64 = 0.02;
+    const NOSE_STRUT_GAS_VOLUME_LITERS: f64 = 14.7;
+    const NOSE_STRUT_PRECHARGE_PRESSURE_PSI: f64 = 718.;
+    const NOSE_STRUT_MAX_CONTACT_LOAD_NEWTONS: f64 = 390000.;
+
+    pub(super) fn a380_landing_gear(context: &mut InitContext) -> LandingGear {
+        let nose_strut = ShockAbsorber::new(
+            context,
+            GearWheel::NOSE,
+            Length::new::<meter>(Self::NOSE_STRUT_MAX_STROKE_METERS),
+            Area::new::<square_meter>(Self::NOSE_STRUT_PISTON_AREA_SQUARE_METERS),
+            Volume::new::<liter>(Self::NOSE_STRUT_GAS_VOLUME_LITERS),
+            Pressure::new::<psi>(Self::NOSE_STRUT_PRECHARGE_PRESSURE_PSI),
+            Force::new::<newton>(Self::NOSE_STRUT_MAX_CONTACT_LOAD_NEWTONS),
+        );
+        let body_struts = [
+            Self::a380_body_strut(context, GearWheel::LEFT),
+            Self::a380_body_strut(context, GearWheel::RIGHT),
+        ];
+        let wing_struts = [
+            Self::a380_wing_strut(context, GearWheel::WINGLEFT),
+            Self::a380_wing_strut(context, GearWheel::WINGRIGHT),
+        ];
+
+        LandingGear::new(context, nose_strut, body_struts, Some(wing_struts))
+    }
+
+    fn a380_body_strut(context: &mut InitContext, wheel_id: GearWheel) -> ShockAbsorber {
+        ShockAbsorber::new(
+            context,
+            wheel_id,
+            Length::new::<meter>(Self::BODY_STRUT_MAX_STROKE_METERS),
+            Area::new::<square_meter>(Self::BODY_STRUT_PISTON_AREA_SQUARE_METERS),
+            Volume::new::<liter>(Self::BODY_STRUT_GAS_VOLUME_LITERS),
+            Pressure::new::<psi>(Self::BODY_STRUT_PRECHARGE_PRESSURE_PSI),
+            Force::new::<newton>(Self::BODY_STRUT_MAX_CONTACT_LOAD_NEWTONS),
+        )
+    }
+
+    fn a380_wing_strut(context: &mut InitContext, wheel_id: GearWheel) -> ShockAbsorber {
+        ShockAbsorber::new(
+            context,
+            wheel_id,
+            Length::new::<meter>(Self::WING_STRUT_MAX_STROKE_METERS),
+            Area::new::<square_meter>(Self::WING_STRUT_PISTON_AREA_SQUARE_METERS),
+            Volume::new::<liter>(Self::WING_STRUT_GAS_VOLUME_LITERS),
+            Pressure::new::<psi>(Self::WING_STRUT_PRECHARGE_PRESSURE_PSI),
+            Force::new::<newton>(Self::WING_STRUT_MAX_CONTACT_LOAD_NEWTONS),
+        )
+    }
+}
+
 struct A380TiltingGearsFactory {}
 impl A380TiltingGearsFactory {
     fn new_a380_body_gear(context: &mut InitContext, is_left: bool) -> TiltingGear {
@@ -350,11 +431,12 @@ impl A380CargoDoorFactory {
         HydraulicLinearActuatorAssembly::new([cargo_door_actuator], cargo_door_body)
     }
 
-    fn new_a380_cargo_door(context: &mut InitContext, id: &str) -> CargoDoor {
+    fn new_a380_cargo_door(context: &mut InitContext, id: &str, door_id: CargoDoorId) -> CargoDoor {
         let assembly = Self::a380_cargo_door_assembly(context);
         CargoDoor::new(
             context,
             id,
+            door_id,
             assembly,
             Self::new_a380_cargo_door_aero_model(),
         )
@@ -386,10 +468,14 @@ impl A380AileronFactory {
     // 247XP - AC EHA
     const INWARD_PANEL_EHA_BUS: ElectricalBusType = AC_EHA_BUS;
 
+    // EHA failure ids for [left, right] sides
+    const MIDDLE_PANEL_EHA_FAILURE_ID: [usize; 2] = [1, 3];
+    const INWARD_PANEL_EHA_FAILURE_ID: [usize; 2] = [2, 4];
+
     fn a380_aileron_actuator(
         context: &mut InitContext,
         bounded_linear_length: &impl BoundedLinearLength,
-        powered_by: Option<ElectricalBusType>,
+        electro_hydrostatic_unit: Option<(ElectricalBusType, usize)>,
     ) -> LinearActuator {
         let actuator_characteristics = LinearActuatorCharacteristics::new(
             Self::MAX_DAMPING_CONSTANT_FOR_SLOW_DAMPING / 3.,
@@ -425,10 +511,11 @@ impl A380AileronFactory {
             true,
             false,
             None,
-            powered_by.map(|bus| {
+            electro_hydrostatic_unit.map(|(bus, failure_id)| {
                 ElectroHydrostaticBackup::new(
                     bus,
                     ElectroHydrostaticActuatorType::ElectroHydrostaticActuator,
+                    FailureType::ElectroHydrostaticActuator(failure_id),
                 )
             }),
             Pressure::new::<psi>(A380HydraulicCircuitFactory::HYDRAULIC_TARGET_PRESSURE_PSI),
@@ -486,14 +573,14 @@ impl A380AileronFactory {
     fn a380_aileron_assembly(
         context: &mut InitContext,
         init_drooped_down: bool,
-        powered_by: Option<ElectricalBusType>,
+        electro_hydrostatic_unit: Option<(ElectricalBusType, usize)>,
         panel: AileronPanelPosition,
     ) -> HydraulicLinearActuatorAssembly<2> {
         let aileron_body = Self::a380_aileron_body(init_drooped_down, panel);
 
         let aileron_actuator_outward = Self::a380_aileron_actuator(context, &aileron_body, None);
         let aileron_actuator_inward =
-            Self::a380_aileron_actuator(context, &aileron_body, powered_by);
+            Self::a380_aileron_actuator(context, &aileron_body, electro_hydrostatic_unit);
 
         HydraulicLinearActuatorAssembly::new(
             [aileron_actuator_outward, aileron_actuator_inward],
@@ -503,6 +590,7 @@ impl A380AileronFactory {
 
     fn new_aileron(context: &mut InitContext, id: ActuatorSide) -> AileronAssembly {
         let init_drooped_down = !context.is_in_flight();
+        let side_index = id as usize;
         let assembly_outward = Self::a380_aileron_assembly(
             context,
             init_drooped_down,
@@ -512,13 +600,19 @@ impl A380AileronFactory {
         let assembly_middle = Self::a380_aileron_assembly(
             context,
             init_drooped_down,
-            Some(Self::MIDDLE_PANEL_EHA_BUS),
+            Some((
+                Self::MIDDLE_PANEL_EHA_BUS,
+                Self::MIDDLE_PANEL_EHA_FAILURE_ID[side_index],
+            )),
             AileronPanelPosition::Middle,
         );
         let assembly_inward = Self::a380_aileron_assembly(
             context,
             init_drooped_down,
-            Some(Self::INWARD_PANEL_EHA_BUS),
+            Some((
+                Self::INWARD_PANEL_EHA_BUS,
+                Self::INWARD_PANEL_EHA_FAILURE_ID[side_index],
+            )),
             AileronPanelPosition::Inward,
         );
         AileronAssembly::new(
@@ -567,10 +661,13 @@ impl A380SpoilerFactory {
     // 427XP - AC ESS
     const SPOILER_6_EBHA_BUS: ElectricalBusType = ElectricalBusType::AlternatingCurrentEssential;
 
+    // EBHA failure ids for [left, right] sides
+    const SPOILER_6_EBHA_FAILURE_ID: [usize; 2] = [13, 14];
+
     fn a380_spoiler_actuator(
         context: &mut InitContext,
         bounded_linear_length: &impl BoundedLinearLength,
-        powered_by: Option<ElectricalBusType>,
+        electro_hydrostatic_unit: Option<(ElectricalBusType, usize)>,
     ) -> LinearActuator {
         let actuator_characteristics = LinearActuatorCharacteristics::new(
             Self::MAX_DAMPING_CONSTANT_FOR_SLOW_DAMPING / 5.,
@@ -603,10 +700,11 @@ impl A380SpoilerFactory {
                 AngularVelocity::new::<radian_per_second>(-10000.),
                 AngularVelocity::new::<radian_per_second>(0.),
             )),
-            powered_by.map(|bus| {
+            electro_hydrostatic_unit.map(|(bus, failure_id)| {
                 ElectroHydrostaticBackup::new(
                     bus,
                     ElectroHydrostaticActuatorType::ElectricalBackupHydraulicActuator,
+                    FailureType::ElectroHydrostaticActuator(failure_id),
                 )
             }),
             Pressure::new::<psi>(A380HydraulicCircuitFactory::HYDRAULIC_TARGET_PRESSURE_PSI),
@@ -641,11 +739,12 @@ impl A380SpoilerFactory {
     /// Builds a spoiler assembly consisting of the spoiler physical rigid body and one hydraulic actuator
     fn a380_spoiler_assembly(
         context: &mut InitContext,
-        powered_by: Option<ElectricalBusType>,
+        electro_hydrostatic_unit: Option<(ElectricalBusType, usize)>,
     ) -> HydraulicLinearActuatorAssembly<1> {
         let spoiler_body = Self::a380_spoiler_body();
 
-        let spoiler_actuator = Self::a380_spoiler_actuator(context, &spoiler_body, powered_by);
+        let spoiler_actuator =
+            Self::a380_spoiler_actuator(context, &spoiler_body, electro_hydrostatic_unit);
 
         HydraulicLinearActuatorAssembly::new([spoiler_actuator], spoiler_body)
     }
@@ -656,8 +755,15 @@ impl A380SpoilerFactory {
         let spoiler_3 = Self::new_a380_spoiler_element(context, id, 3, None);
         let spoiler_4 = Self::new_a380_spoiler_element(context, id, 4, None);
         let spoiler_5 = Self::new_a380_spoiler_element(context, id, 5, None);
-        let spoiler_6 =
-            Self::new_a380_spoiler_element(context, id, 6, Some(Self::SPOILER_6_EBHA_BUS));
+        let spoiler_6 = Self::new_a380_spoiler_element(
+            context,
+            id,
+            6,
+            Some((
+                Self::SPOILER_6_EBHA_BUS,
+                Self::SPOILER_6_EBHA_FAILURE_ID[id as usize],
+            )),
+        );
         let spoiler_7 = Self::new_a380_spoiler_element(context, id, 7, None);
         let spoiler_8 = Self::new_a380_spoiler_element(context, id, 8, None);
 
@@ -678,9 +784,9 @@ impl A380SpoilerFactory {
         context: &mut InitContext,
         id: ActuatorSide,
         id_number: usize,
-        powered_by: Option<ElectricalBusType>,
+        electro_hydrostatic_unit: Option<(ElectricalBusType, usize)>,
     ) -> SpoilerElement {
-        let assembly = Self::a380_spoiler_assembly(context, powered_by);
+        let assembly = Self::a380_spoiler_assembly(context, electro_hydrostatic_unit);
         SpoilerElement::new(
             context,
             id,
@@ -726,10 +832,14 @@ impl A380ElevatorFactory {
     // 247XP - AC EHA
     const RIGHT_INWARD_PANEL_EHA_BUS: ElectricalBusType = AC_EHA_BUS;
 
+    // EHA failure ids for [left, right] sides
+    const OUTWARD_PANEL_EHA_FAILURE_ID: [usize; 2] = [5, 7];
+    const INWARD_PANEL_EHA_FAILURE_ID: [usize; 2] = [6, 8];
+
     fn a380_elevator_actuator(
         context: &mut InitContext,
         bounded_linear_length: &impl BoundedLinearLength,
-        powered_by: Option<ElectricalBusType>,
+        electro_hydrostatic_unit: Option<(ElectricalBusType, usize)>,
     ) -> LinearActuator {
         let actuator_characteristics = LinearActuatorCharacteristics::new(
             Self::MAX_DAMPING_CONSTANT_FOR_SLOW_DAMPING / 5.,
@@ -759,10 +869,11 @@ impl A380ElevatorFactory {
             true,
             false,
             None,
-            powered_by.map(|bus| {
+            electro_hydrostatic_unit.map(|(bus, failure_id)| {
                 ElectroHydrostaticBackup::new(
                     bus,
                     ElectroHydrostaticActuatorType::ElectroHydrostaticActuator,
+                    FailureType::ElectroHydrostaticActuator(failure_id),
                 )
             }),
             Pressure::new::<psi>(A380HydraulicCircuitFactory::HYDRAULIC_TARGET_PRESSURE_PSI),
@@ -819,7 +930,7 @@ impl A380ElevatorFactory {
     fn a380_elevator_assembly(
         context: &mut InitContext,
         init_drooped_down: bool,
-        powered_by: Option<ElectricalBusType>,
+        electro_hydrostatic_unit: Option<(ElectricalBusType, usize)>,
         is_outer: bool,
     ) -> HydraulicLinearActuatorAssembly<2> {
         let elevator_body = Self::a380_elevator_body(init_drooped_down, is_outer);
@@ -827,7 +938,7 @@ impl A380ElevatorFactory {
         let elevator_actuator_outboard =
             Self::a380_elevator_actuator(context, &elevator_body, None);
         let elevator_actuator_inbord =
-            Self::a380_elevator_actuator(context, &elevator_body, powered_by);
+            Self::a380_elevator_actuator(context, &elevator_body, electro_hydrostatic_unit);
 
         HydraulicLinearActuatorAssembly::new(
             [elevator_actuator_outboard, elevator_actuator_inbord],
@@ -841,21 +952,27 @@ impl A380ElevatorFactory {
         let assembly_outward = Self::a380_elevator_assembly(
             context,
             init_drooped_down,
-            if id == ActuatorSide::Left {
-                Some(Self::LEFT_OUTWARD_PANEL_EHA_BUS)
-            } else {
-                Some(Self::RIGHT_OUTWARD_PANEL_EHA_BUS)
-            },
+            Some((
+                if id == ActuatorSide::Left {
+                    Self::LEFT_OUTWARD_PANEL_EHA_BUS
+                } else {
+                    Self::RIGHT_OUTWARD_PANEL_EHA_BUS
+                },
+                Self::OUTWARD_PANEL_EHA_FAILURE_ID[id as usize],
+            )),
             true,
         );
         let assembly_inward = Self::a380_elevator_assembly(
             context,
             init_drooped_down,
-            if id == ActuatorSide::Left {
-                Some(Self::LEFT_INWARD_PANEL_EHA_BUS)
-            } else {
-                Some(Self::RIGHT_INWARD_PANEL_EHA_BUS)
-            },
+            Some((
+                if id == ActuatorSide::Left {
+                    Self::LEFT_INWARD_PANEL_EHA_BUS
+                } else {
+                    Self::RIGHT_INWARD_PANEL_EHA_BUS
+                },
+                Self::INWARD_PANEL_EHA_FAILURE_ID[id as usize],
+            )),
             false,
         );
         ElevatorAssembly::new(
@@ -904,10 +1021,15 @@ impl A380RudderFactory {
     // 100XP1 - AC 1
     const LOWER_PANEL_LOWER_EBHA_BUS: ElectricalBusType = ElectricalBusType::AlternatingCurrent(1);
 
+    // EBHA failure ids for [upper, lower] actuators
+    const UPPER_PANEL_EBHA_FAILURE_ID: [usize; 2] = [9, 10];
+    const LOWER_PANEL_EBHA_FAILURE_ID: [usize; 2] = [11, 12];
+
     fn a380_rudder_actuator(
         context: &mut InitContext,
         bounded_linear_length: &impl BoundedLinearLength,
         powered_by: ElectricalBusType,
+        failure_id: usize,
     ) -> LinearActuator {
         let actuator_characteristics = LinearActuatorCharacteristics::new(
             Self::MAX_DAMPING_CONSTANT_FOR_SLOW_DAMPING / 4.,
@@ -944,6 +1066,7 @@ impl A380RudderFactory {
             Some(ElectroHydrostaticBackup::new(
                 powered_by,
                 ElectroHydrostaticActuatorType::ElectricalBackupHydraulicActuator,
+                FailureType::ElectroHydrostaticActuator(failure_id),
             )),
             Pressure::new::<psi>(A380HydraulicCircuitFactory::HYDRAULIC_TARGET_PRESSURE_PSI),
         )
@@ -1003,11 +1126,16 @@ impl A380RudderFactory {
         lower_powered_by: ElectricalBusType,
     ) -> HydraulicLinearActuatorAssembly<2> {
         let rudder_body = Self::a380_rudder_body(init_at_center, is_upper_body);
+        let failure_ids = if is_upper_body {
+            Self::UPPER_PANEL_EBHA_FAILURE_ID
+        } else {
+            Self::LOWER_PANEL_EBHA_FAILURE_ID
+        };
 
         let rudder_actuator_upper =
-            Self::a380_rudder_actuator(context, &rudder_body, upper_powered_by);
+            Self::a380_rudder_actuator(context, &rudder_body, upper_powered_by, failure_ids[0]);
         let rudder_actuator_lower =
-            Self::a380_rudder_actuator(context, &rudder_body, lower_powered_by);
+            Self::a380_rudder_actuator(context, &rudder_body, lower_powered_by, failure_ids[1]);
 
         HydraulicLinearActuatorAssembly::new(
             [rudder_actuator_upper, rudder_actuator_lower],
@@ -1301,6 +1429,29 @@ impl A380GearDoorFactory {
     }
 }
 
+struct A380BrakedWheelFactory {}
+impl A380BrakedWheelFactory {
+    const TYRE_RADIUS_METER: f64 = 0.71;
+    const WHEEL_INERTIA_KG_M2: f64 = 70.;
+    const MAX_BRAKE_TORQUE_NEWTON_METER: f64 = 60000.;
+    const MAX_BRAKE_TORQUE_REFERENCE_PRESSURE_PSI: f64 = 2538.;
+
+    // Weight not supported by the nose gear is shared by the twenty wing and body gear wheels
+    const MAIN_WHEEL_STATIC_LOAD_RATIO: f64 = 0.047;
+
+    fn new_braked_wheel(context: &mut InitContext, index: usize) -> BrakedWheel {
+        BrakedWheel::new(
+            context,
+            index,
+            Length::new::<meter>(Self::TYRE_RADIUS_METER),
+            Self::WHEEL_INERTIA_KG_M2,
+            Torque::new::<newton_meter>(Self::MAX_BRAKE_TORQUE_NEWTON_METER),
+            Pressure::new::<psi>(Self::MAX_BRAKE_TORQUE_REFERENCE_PRESSURE_PSI),
+            Ratio::new::<ratio>(Self::MAIN_WHEEL_STATIC_LOAD_RATIO),
+        )
+    }
+}
+
 struct A380GearFactory {}
 impl A380GearFactory {
     fn a380_nose_gear_aerodynamics() -> AerodynamicModel {
@@ -1537,6 +1688,11 @@ impl A380GearFactory {
 
 struct A380GearSystemFactory {}
 impl A380GearSystemFactory {
+    const MAX_GEAR_OPERATING_SPEED_KNOTS: f64 = 250.0;
+    const MAX_GEAR_EXTENDED_SPEED_KNOTS: f64 = 250.0;
+    const MAX_LANDING_WEIGHT_KG: f64 = 394000.0;
+    const MAX_TAKEOFF_WEIGHT_KG: f64 = 575000.0;
+
     fn a380_gear_system(context: &mut InitContext) -> HydraulicGearSystem {
         let init_downlocked = context.start_gear_down();
 
@@ -1565,10 +1721,22 @@ impl A380GearSystemFactory {
             A380GearFactory::a380_left_gear_aerodynamics(),
             A380GearFactory::a380_right_gear_aerodynamics(),
             A380GearFactory::a380_nose_gear_aerodynamics(),
+            GearStructuralLimits::new(
+                Velocity::new::<knot>(Self::MAX_GEAR_OPERATING_SPEED_KNOTS),
+                Velocity::new::<knot>(Self::MAX_GEAR_EXTENDED_SPEED_KNOTS),
+                Mass::new::<kilogram>(Self::MAX_LANDING_WEIGHT_KG),
+                Mass::new::<kilogram>(Self::MAX_TAKEOFF_WEIGHT_KG),
+            ),
         )
     }
 }
 
+/// Ground inhibition of the electrical modes of the EHA and EBHA units.
+///
+/// Switching each unit between hydraulic and electrical mode is commanded by the PRIM and SEC through
+/// the servo solenoid and EBHA electronic enable variables. The PRIM and SEC do not compute this
+/// inhibition yet, so electrical modes are inhibited here once the aircraft has been on ground for a
+/// while with no engine master on.
 // FIXME Placeholder logic to remove when eha inhibit is handled on flight computer side
 struct A380EhaInhibitPlaceholder {
     general_eng_1_starter_active_id: VariableIdentifier,
@@ -1719,6 +1887,13 @@ pub(super) struct A380Hydraulic {
     right_wing_brake_assembly: BrakeAssembly<4>,
     left_body_brake_assembly: BrakeAssembly<4>,
     right_body_brake_assembly: BrakeAssembly<4>,
+    nose_wheels: UnbrakedWheels<2>,
+    body_centre_axle_wheels: UnbrakedWheels<4>,
+    tyre_pressure_indicating_system: TyrePressureIndicatingSystem<22>,
+    runway_friction: TyreRunwayFriction,
+    // One representative wheel for the wing and body gears of each side
+    left_braked_wheels: [BrakedWheel; 2],
+    right_braked_wheels: [BrakedWheel; 2],
 
     // FIXME: remove when CPIOM G is implemented
     brake_temperature_ids: [VariableIdentifier; 16],
@@ -1732,6 +1907,13 @@ pub(super) struct A380Hydraulic {
     aux_gear_doors: A380AuxiliaryGearDoorSet,
 }
 impl A380Hydraulic {
+    const MAIN_TYRE_NOMINAL_PRESSURE_PSI: f64 = 218.;
+    const NOSE_TYRE_NOMINAL_PRESSURE_PSI: f64 = 200.;
+
+    const TOWBAR_SHEAR_PIN_FORCE_KILONEWTON: f64 = 210.;
+    const NOSE_GEAR_TOWING_FORCE_KILONEWTON: f64 = 330.;
+    const TOWBAR_STEERING_ANGLE_DEGREES: f64 = 75.;
+
     const FLAP_FPPU_TO_SURFACE_ANGLE_BREAKPTS: [f64; 12] = [
         0., 122.2, 215.68, 259.28, 279.84, 297.59, 338.99, 338.99, 338.99, 338.99, 338.99, 338.99,
     ];
@@ -1789,6 +1971,7 @@ impl A380Hydraulic {
             nose_steering: SteeringActuator::new(
                 context,
                 "NOSE_WHEEL",
+                SteeringActuatorId::Nose,
                 Angle::new::<degree>(75.),
                 AngularVelocity::new::<degree_per_second>(15.), // Reference is 15 deg/s
                 Length::new::<meter>(0.11), // Diameter of 0.11 gives correct A380 flow of around 35 lpm at full speed
@@ -1800,6 +1983,7 @@ impl A380Hydraulic {
             body_wheel_steering_left: SteeringActuator::new(
                 context,
                 "LEFT_BODY_STEERING",
+                SteeringActuatorId::BodyLeft,
                 Angle::new::<degree>(15.),
                 AngularVelocity::new::<degree_per_second>(4.5), // Reference is 4.5 deg/s
                 Length::new::<meter>(0.11),                     // TODO
@@ -1811,6 +1995,7 @@ impl A380Hydraulic {
             body_wheel_steering_right: SteeringActuator::new(
                 context,
                 "RIGHT_BODY_STEERING",
+                SteeringActuatorId::BodyRight,
                 Angle::new::<degree>(15.),
                 AngularVelocity::new::<degree_per_second>(4.5), // Reference is 4.5 deg/s
                 Length::new::<meter>(0.11),                     // TODO
@@ -1973,7 +2158,14 @@ impl A380Hydraulic {
                 A380ElectricPumpId::GreenAuxiliary,
             ),
 
-            pushback_tug: PushbackTug::new(context),
+            pushback_tug: PushbackTug::new(
+                context,
+                TowingLimits::new(
+                    Force::new::<kilonewton>(Self::TOWBAR_SHEAR_PIN_FORCE_KILONEWTON),
+                    Force::new::<kilonewton>(Self::NOSE_GEAR_TOWING_FORCE_KILONEWTON),
+                    Angle::new::<degree>(Self::TOWBAR_STEERING_ANGLE_DEGREES),
+                ),
+            ),
             bypass_pin: BypassPin::new(context),
 
             braking_circuit_norm: BrakeCircuit::new(
@@ -2030,6 +2222,7 @@ impl A380Hydraulic {
             forward_cargo_door: A380CargoDoorFactory::new_a380_cargo_door(
                 context,
                 Self::FORWARD_CARGO_DOOR_ID,
+                CargoDoorId::Forward,
             ),
             forward_cargo_door_controller: HydraulicDoorController::new(
                 context,
@@ -2039,6 +2232,7 @@ impl A380Hydraulic {
             aft_cargo_door: A380CargoDoorFactory::new_a380_cargo_door(
                 context,
                 Self::AFT_CARGO_DOOR_ID,
+                CargoDoorId::Aft,
             ),
             aft_cargo_door_controller: HydraulicDoorController::new(
                 context,
@@ -2077,6 +2271,7 @@ impl A380Hydraulic {
                 [1, 2, 5, 6],
                 [ElectricalBusType::DirectCurrent(1); 4], // TODO
                 None,
+                Pressure::new::<psi>(Self::MAIN_TYRE_NOMINAL_PRESSURE_PSI),
             ),
             right_wing_brake_assembly: BrakeAssembly::new(
                 context,
@@ -2084,6 +2279,7 @@ impl A380Hydraulic {
                 [3, 4, 7, 8],
                 [ElectricalBusType::DirectCurrent(1); 4], // TODO
                 None,
+                Pressure::new::<psi>(Self::MAIN_TYRE_NOMINAL_PRESSURE_PSI),
             ),
             left_body_brake_assembly: BrakeAssembly::new(
                 context,
@@ -2091,6 +2287,7 @@ impl A380Hydraulic {
                 [9, 10, 13, 14],
                 [ElectricalBusType::DirectCurrent(1); 4], // TODO
                 None,
+                Pressure::new::<psi>(Self::MAIN_TYRE_NOMINAL_PRESSURE_PSI),
             ),
             right_body_brake_assembly: BrakeAssembly::new(
                 context,
@@ -2098,7 +2295,38 @@ impl A380Hydraulic {
                 [11, 12, 15, 16],
                 [ElectricalBusType::DirectCurrent(1); 4], // TODO
                 None,
+                Pressure::new::<psi>(Self::MAIN_TYRE_NOMINAL_PRESSURE_PSI),
+            ),
+            nose_wheels: UnbrakedWheels::new(
+                context,
+                [17, 18],
+                Pressure::new::<psi>(Self::NOSE_TYRE_NOMINAL_PRESSURE_PSI),
+            ),
+            body_centre_axle_wheels: UnbrakedWheels::new(
+                context,
+                [19, 20, 21, 22],
+                Pressure::new::<psi>(Self::MAIN_TYRE_NOMINAL_PRESSURE_PSI),
+            ),
+            tyre_pressure_indicating_system: TyrePressureIndicatingSystem::new(
+                context,
+                [
+                    1, 2, 5, 6, 3, 4, 7, 8, 9, 10, 13, 14, 11, 12, 15, 16, 17, 18, 19, 20, 21, 22,
+                ]
+                .map(|index| {
+                    let nominal_pressure = if (17..=18).contains(&index) {
+                        Self::NOSE_TYRE_NOMINAL_PRESSURE_PSI
+                    } else {
+                        Self::MAIN_TYRE_NOMINAL_PRESSURE_PSI
+                    };
+                    (index, Pressure::new::<psi>(nominal_pressure))
+                }),
+                ElectricalBusType::DirectCurrent(1),
             ),
+            runway_friction: TyreRunwayFriction::new(context),
+            left_braked_wheels: [1, 2]
+                .map(|index| A380BrakedWheelFactory::new_braked_wheel(context, index)),
+            right_braked_wheels: [3, 4]
+                .map(|index| A380BrakedWheelFactory::new_braked_wheel(context, index)),
 
             brake_temperature_ids: (1..=16)
                 .map(|index| context.get_identifier(format!("REPORTED_BRAKE_TEMPERATURE_{index}")))
@@ -2139,6 +2367,7 @@ impl A380Hydraulic {
         autobrake_panel: &A380AutobrakePanel,
         engine_fire_push_buttons: &impl EngineFirePushButtons,
         lgcius: &LandingGearControlInterfaceUnitSet,
+        landing_gear: &LandingGear,
         reservoir_pneumatics: &impl ReservoirAirPressure,
         adirs: &(impl AdirsDiscreteOutputs + AdirsMeasurementOutputs),
     ) {
@@ -2154,10 +2383,16 @@ impl A380Hydraulic {
             engines[1],
             adirs,
             lgcius,
+            landing_gear,
         );
 
         for cur_time_step in self.core_hydraulic_updater {
-            self.update_physics(&context.with_delta(cur_time_step), lgcius, adirs);
+            self.update_physics(
+                &context.with_delta(cur_time_step),
+                lgcius,
+                landing_gear,
+                adirs,
+            );
 
             self.update_core_hydraulics(
                 &context.with_delta(cur_time_step),
@@ -2257,6 +2492,7 @@ impl A380Hydraulic {
         &mut self,
         context: &UpdateContext,
         lgcius: &LandingGearControlInterfaceUnitSet,
+        landing_gear: &LandingGear,
         adirs: &impl AdirsDiscreteOutputs,
     ) {
         self.forward_cargo_door.update(
@@ -2408,6 +2644,7 @@ impl A380Hydraulic {
             &self.gear_system_hydraulic_controller,
             lgcius.active_lgciu(),
             self.green_circuit.system_section(),
+            landing_gear,
         );
     }
 
@@ -2422,6 +2659,7 @@ impl A380Hydraulic {
         engine2: &impl Engine,
         adirs: &impl AdirsMeasurementOutputs,
         lgcius: &LandingGearControlInterfaceUnitSet,
+        landing_gear: &LandingGear,
     ) {
         self.eha_backup_inhibit_logic
             .update(context, lgciu1, lgciu2);
@@ -2440,7 +2678,7 @@ impl A380Hydraulic {
         self.rudder_system_controller
             .update(&self.eha_backup_inhibit_logic);
 
-        self.tilting_gears.update(context);
+        self.tilting_gears.update(context, landing_gear);
 
         self.nose_steering.update(
             context,
@@ -2479,9 +2717,18 @@ impl A380Hydraulic {
             self.left_spoilers.ground_spoilers_are_requested()
                 && self.right_spoilers.ground_spoilers_are_requested(),
             self.nose_steering.position_feedback(),
+            &self.left_braked_wheels,
+            &self.right_braked_wheels,
         );
 
-        self.pushback_tug.update(context);
+        self.pushback_tug.update(
+            context,
+            self.braking_circuit_norm
+                .left_brake_pressure()
+                .max(self.braking_circuit_norm.right_brake_pressure())
+                .max(self.braking_circuit_altn.left_brake_pressure())
+                .max(self.braking_circuit_altn.right_brake_pressure()),
+        );
         self.bypass_pin.update(&self.pushback_tug);
 
         for (brake_assembly, braking_pressure_norm, braking_pressure_altn, gear_position) in [
@@ -2519,10 +2766,55 @@ impl A380Hydraulic {
             );
         }
 
+        self.nose_wheels.update(context);
+        self.body_centre_axle_wheels.update(context);
+        self.tyre_pressure_indicating_system.update(
+            context,
+            self.left_wing_brake_assembly
+                .tyres()
+                .chain(self.right_wing_brake_assembly.tyres())
+                .chain(self.left_body_brake_assembly.tyres())
+                .chain(self.right_body_brake_assembly.tyres())
+                .chain(self.nose_wheels.tyres())
+                .chain(self.body_centre_axle_wheels.tyres()),
+        );
+
+        self.runway_friction.update(context);
+        for (braked_wheels, braking_pressure_norm, braking_pressure_altn) in [
+            (
+                &mut self.left_braked_wheels,
+                self.braking_circuit_norm.left_brake_pressure(),
+                self.braking_circuit_altn.left_brake_pressure(),
+            ),
+            (
+                &mut self.right_braked_wheels,
+                self.braking_circuit_norm.right_brake_pressure(),
+                self.braking_circuit_altn.right_brake_pressure(),
+            ),
+        ] {
+            for braked_wheel in braked_wheels {
+                braked_wheel.update(
+                    context,
+                    braking_pressure_norm.max(braking_pressure_altn),
+                    &self.runway_friction,
+                );
+            }
+        }
+
         self.braking_force.update_forces(
             context,
             &self.braking_circuit_norm,
             &self.braking_circuit_altn,
+            [
+                &self.left_wing_brake_assembly,
+                &self.left_body_brake_assembly,
+            ],
+            [
+                &self.right_wing_brake_assembly,
+                &self.right_body_brake_assembly,
+            ],
+            &self.left_braked_wheels,
+            &self.right_braked_wheels,
             engine1,
             engine2,
             &self.bypass_pin,
@@ -3244,6 +3536,12 @@ impl SimulationElement for A380Hydraulic {
         self.right_wing_brake_assembly.accept(visitor);
         self.left_body_brake_assembly.accept(visitor);
         self.right_body_brake_assembly.accept(visitor);
+        self.nose_wheels.accept(visitor);
+        self.body_centre_axle_wheels.accept(visitor);
+        self.tyre_pressure_indicating_system.accept(visitor);
+        self.runway_friction.accept(visitor);
+        accept_iterable!(self.left_braked_wheels, visitor);
+        accept_iterable!(self.right_braked_wheels, visitor);
 
         self.ths_system_controller.accept(visitor);
         self.ths.accept(visitor);
@@ -4143,9 +4441,21 @@ impl BrakeCircuitController for A380BrakeSystemOutputs {
 struct BodyWheelSteeringController {
     requested_position: Angle,
     is_left_side: bool,
+    is_available: bool,
+    is_engaged: bool,
 }
 impl BodyWheelSteeringController {
-    const NOSE_ANGLE_INPUT_DEGREES: [f64; 4] = [-70., -20., 20., 70.];
+    // Body steering engages above the upper nose angle threshold and only disengages once back
+    // below the lower one, so small nose wheel corrections around the threshold don't cycle it
+    const NOSE_ANGLE_ENGAGE_DEGREES: f64 = 20.;
+    const NOSE_ANGLE_DISENGAGE_DEGREES: f64 = 15.;
+
+    const NOSE_ANGLE_INPUT_DEGREES: [f64; 4] = [
+        -70.,
+        -Self::NOSE_ANGLE_ENGAGE_DEGREES,
+        Self::NOSE_ANGLE_ENGAGE_DEGREES,
+        70.,
+    ];
     const BODY_STEERING_DEMAND_DEGREES: [f64; 4] = [15., 0., 0., -11.];
 
     const MAX_BODY_STEERING_SPEED_ENABLING_KNOT: f64 = 30.;
@@ -4154,11 +4464,21 @@ impl BodyWheelSteeringController {
         Self {
             requested_position: Angle::default(),
             is_left_side,
+            is_available: true,
+            is_engaged: false,
         }
     }
 
-    fn update(&mut self, context: &UpdateContext, nose_steering_angle: Angle) {
-        if context.ground_speed().get::<knot>() < Self::MAX_BODY_STEERING_SPEED_ENABLING_KNOT {
+    fn update(&mut self, context: &UpdateContext, nose_steering_angle: Angle, is_available: bool) {
+        self.is_available = is_available;
+
+        let nose_angle_magnitude = nose_steering_angle.get::<degree>().abs();
+        self.is_engaged = is_available
+            && context.ground_speed().get::<knot>() < Self::MAX_BODY_STEERING_SPEED_ENABLING_KNOT
+            && (nose_angle_magnitude > Self::NOSE_ANGLE_ENGAGE_DEGREES
+                || self.is_engaged && nose_angle_magnitude > Self::NOSE_ANGLE_DISENGAGE_DEGREES);
+
+        if self.is_engaged {
             self.requested_position = if self.is_left_side {
                 Angle::new::<degree>(interpolation(
                     &Self::NOSE_ANGLE_INPUT_DEGREES,
@@ -4176,29 +4496,49 @@ impl BodyWheelSteeringController {
             self.requested_position = Angle::default();
         }
     }
+
+    fn is_engaged(&self) -> bool {
+        self.is_engaged
+    }
 }
 impl SteeringController for BodyWheelSteeringController {
     fn requested_position(&self) -> Angle {
         self.requested_position
     }
+
+    fn is_steering_available(&self) -> bool {
+        self.is_available
+    }
 }
 
 struct BodyWheelSteeringControl {
     left_controller: BodyWheelSteeringController,
     right_controller: BodyWheelSteeringController,
+
+    channel_failures: [Failure; 2],
 }
 impl BodyWheelSteeringControl {
     fn default() -> Self {
         Self {
             left_controller: BodyWheelSteeringController::new(true),
             right_controller: BodyWheelSteeringController::new(false),
+
+            channel_failures: [1, 2]
+                .map(|channel| Failure::new(FailureType::SteeringControlChannel(channel))),
         }
     }
 
     fn update(&mut self, context: &UpdateContext, nose_steering_feedback: Angle) {
-        self.left_controller.update(context, nose_steering_feedback);
+        // Body wheel steering is lost only once both control channels are failed
+        let is_available = self
+            .channel_failures
+            .iter()
+            .any(|failure| !failure.is_active());
+
+        self.left_controller
+            .update(context, nose_steering_feedback, is_available);
         self.right_controller
-            .update(context, nose_steering_feedback);
+            .update(context, nose_steering_feedback, is_available);
     }
 
     fn left_controller(&self) -> &impl SteeringController {
@@ -4210,64 +4550,13 @@ impl BodyWheelSteeringControl {
     }
 
     fn is_requesting_steering(&self) -> bool {
-        self.left_controller
-            .requested_position()
-            .get::<degree>()
-            .abs()
-            > 0.1
-            || self
-                .right_controller
-                .requested_position()
-                .get::<degree>()
-                .abs()
-                > 0.1
+        self.left_controller.is_engaged() || self.right_controller.is_engaged()
     }
 }
-
-struct PedalSteeringDynamicLimiter {
-    pedal_steering_limiter_landing: SteeringAngleLimiter<5>,
-    pedal_steering_limiter_takeoff: SteeringAngleLimiter<5>,
-    is_landing_mode: bool,
-}
-impl PedalSteeringDynamicLimiter {
-    const SPEED_MAP_FOR_PEDAL_ACTION_KNOT: [f64; 5] = [0., 100., 150., 1500.0, 2800.0];
-    const STEERING_ANGLE_FOR_PEDAL_ACTION_TAKEOFF_RATIO: [f64; 5] = [1., 1., 0.333, 0.333, 0.333]; // Used in takeoff mode only
-    const STEERING_ANGLE_FOR_PEDAL_ACTION_LANDING_RATIO: [f64; 5] = [1., 1., 0., 0., 0.]; // Used in landing mode only
-    const SPEED_TO_SWITCH_TAKEOFF_MODE_KNOT: f64 = 100.;
-
-    fn default() -> Self {
-        Self {
-            pedal_steering_limiter_landing: SteeringAngleLimiter::new(
-                Self::SPEED_MAP_FOR_PEDAL_ACTION_KNOT,
-                Self::STEERING_ANGLE_FOR_PEDAL_ACTION_LANDING_RATIO,
-            ),
-            pedal_steering_limiter_takeoff: SteeringAngleLimiter::new(
-                Self::SPEED_MAP_FOR_PEDAL_ACTION_KNOT,
-                Self::STEERING_ANGLE_FOR_PEDAL_ACTION_TAKEOFF_RATIO,
-            ),
-            is_landing_mode: false,
-        }
-    }
-
-    fn update(&mut self, ground_speed: Velocity, lgciu: &impl LgciuWeightOnWheels) {
-        if !lgciu.left_and_right_gear_compressed(false) {
-            self.is_landing_mode = true;
-        }
-        if ground_speed.get::<knot>() < Self::SPEED_TO_SWITCH_TAKEOFF_MODE_KNOT
-            && lgciu.left_and_right_gear_compressed(false)
-        {
-            self.is_landing_mode = false;
-        }
-    }
-
-    fn angle_from_speed(&self, speed: Velocity, angle_demand: Angle) -> Angle {
-        if self.is_landing_mode {
-            self.pedal_steering_limiter_landing
-                .angle_from_speed(speed, angle_demand)
-        } else {
-            self.pedal_steering_limiter_takeoff
-                .angle_from_speed(speed, angle_demand)
-        }
+impl SimulationElement for BodyWheelSteeringControl {
+    fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
+        accept_iterable!(self.channel_failures, visitor);
+        visitor.visit(self);
     }
 }
 
@@ -4278,11 +4567,6 @@ struct A380HydraulicBrakeSteerComputerUnit {
     left_brake_pedal_input_id: VariableIdentifier,
     right_brake_pedal_input_id: VariableIdentifier,
 
-    rudder_pedal_input_id: VariableIdentifier,
-    tiller_handle_input_id: VariableIdentifier,
-    tiller_pedal_disconnect_id: VariableIdentifier,
-    autopilot_nosewheel_demand_id: VariableIdentifier,
-
     autobrake_controller: A380AutobrakeController,
     parking_brake_demand: bool,
 
@@ -4296,20 +4580,13 @@ struct A380HydraulicBrakeSteerComputerUnit {
     should_disable_auto_brake_when_retracting: DelayedTrueLogicGate,
     anti_skid_activated: bool,
 
-    tiller_pedal_disconnect: bool,
-    tiller_handle_position: Ratio,
-    rudder_pedal_position: Ratio,
-    autopilot_nosewheel_demand: Ratio,
-
-    pedal_steering_limiter: PedalSteeringDynamicLimiter,
-    pedal_input_map: SteeringRatioToAngle<6>,
-    tiller_steering_limiter: SteeringAngleLimiter<5>,
-    tiller_input_map: SteeringRatioToAngle<6>,
-    final_steering_position_request: Angle,
+    steering_control: NoseWheelSteeringControl,
 
     body_wheel_steering_control: BodyWheelSteeringControl,
 
     heading_control_function: HeadingControlFunction,
+
+    anti_skid_controllers: [AntiSkidController; 2],
 }
 impl A380HydraulicBrakeSteerComputerUnit {
     const RUDDER_PEDAL_INPUT_GAIN: f64 = 32.;
@@ -4321,12 +4598,16 @@ impl A380HydraulicBrakeSteerComputerUnit {
     const TILLER_INPUT_MAP: [f64; 6] = [0., 1., 20., 40., 66., 75.];
     const TILLER_INPUT_CURVE_MAP: [f64; 6] = [0., 0., 4., 15., 45., 74.];
 
-    const AUTOPILOT_STEERING_INPUT_GAIN: f64 = 6.;
+    const SPEED_MAP_FOR_PEDAL_ACTION_KNOT: [f64; 5] = [0., 100., 150., 1500.0, 2800.0];
+    const STEERING_ANGLE_FOR_PEDAL_ACTION_TAKEOFF_RATIO: [f64; 5] = [1., 1., 0.333, 0.333, 0.333]; // Used in takeoff mode only
+    const STEERING_ANGLE_FOR_PEDAL_ACTION_LANDING_RATIO: [f64; 5] = [1., 1., 0., 0., 0.]; // Used in landing mode only
+    const SPEED_TO_SWITCH_TAKEOFF_MODE_KNOT: f64 = 100.;
 
     const SPEED_MAP_FOR_TILLER_ACTION_KNOT: [f64; 5] = [0., 40., 100., 1500.0, 2800.0];
     const STEERING_ANGLE_FOR_TILLER_ACTION_RATIO: [f64; 5] = [1., 1., 0., 0., 0.];
 
     const MAX_STEERING_ANGLE_DEMAND_DEGREES: f64 = 70.;
+    const OVERSTEER_ANGLE_DEGREES: f64 = 75.;
 
     // Minimum pressure hysteresis on green until main switched on ALTN brakes
     // Feedback by Cpt. Chaos — 25/04/2021 #pilot-feedback
@@ -4349,13 +4630,6 @@ impl A380HydraulicBrakeSteerComputerUnit {
             right_brake_pedal_input_id: context
                 .get_identifier("RIGHT_BRAKE_PEDAL_INPUT".to_owned()),
 
-            rudder_pedal_input_id: context.get_identifier("RUDDER_PEDAL_POSITION_RATIO".to_owned()),
-            tiller_handle_input_id: context.get_identifier("TILLER_HANDLE_POSITION".to_owned()),
-            tiller_pedal_disconnect_id: context
-                .get_identifier("TILLER_PEDAL_DISCONNECT".to_owned()),
-            autopilot_nosewheel_demand_id: context
-                .get_identifier("AUTOPILOT_NOSEWHEEL_DEMAND".to_owned()),
-
             autobrake_controller: A380AutobrakeController::new(context),
 
             parking_brake_demand: true,
@@ -4369,31 +4643,46 @@ impl A380HydraulicBrakeSteerComputerUnit {
             ),
             anti_skid_activated: true,
 
-            tiller_pedal_disconnect: false,
-            tiller_handle_position: Ratio::new::<ratio>(0.),
-            rudder_pedal_position: Ratio::new::<ratio>(0.),
-            autopilot_nosewheel_demand: Ratio::new::<ratio>(0.),
-
-            pedal_steering_limiter: PedalSteeringDynamicLimiter::default(),
-            pedal_input_map: SteeringRatioToAngle::new(
-                Ratio::new::<ratio>(Self::RUDDER_PEDAL_INPUT_GAIN),
-                Self::RUDDER_PEDAL_INPUT_MAP,
-                Self::RUDDER_PEDAL_INPUT_CURVE_MAP,
-            ),
-            tiller_steering_limiter: SteeringAngleLimiter::new(
-                Self::SPEED_MAP_FOR_TILLER_ACTION_KNOT,
-                Self::STEERING_ANGLE_FOR_TILLER_ACTION_RATIO,
-            ),
-            tiller_input_map: SteeringRatioToAngle::new(
-                Ratio::new::<ratio>(Self::TILLER_INPUT_GAIN),
-                Self::TILLER_INPUT_MAP,
-                Self::TILLER_INPUT_CURVE_MAP,
+            steering_control: NoseWheelSteeringControl::new(
+                context,
+                ElectricalBusType::DirectCurrentEssential,
+                SteeringRatioToAngle::new(
+                    Ratio::new::<ratio>(Self::RUDDER_PEDAL_INPUT_GAIN),
+                    Self::RUDDER_PEDAL_INPUT_MAP,
+                    Self::RUDDER_PEDAL_INPUT_CURVE_MAP,
+                ),
+                PedalSteeringDynamicLimiter::new(
+                    SteeringAngleLimiter::new(
+                        Self::SPEED_MAP_FOR_PEDAL_ACTION_KNOT,
+                        Self::STEERING_ANGLE_FOR_PEDAL_ACTION_TAKEOFF_RATIO,
+                    ),
+                    SteeringAngleLimiter::new(
+                        Self::SPEED_MAP_FOR_PEDAL_ACTION_KNOT,
+                        Self::STEERING_ANGLE_FOR_PEDAL_ACTION_LANDING_RATIO,
+                    ),
+                    Velocity::new::<knot>(Self::SPEED_TO_SWITCH_TAKEOFF_MODE_KNOT),
+                ),
+                SteeringRatioToAngle::new(
+                    Ratio::new::<ratio>(Self::TILLER_INPUT_GAIN),
+                    Self::TILLER_INPUT_MAP,
+                    Self::TILLER_INPUT_CURVE_MAP,
+                ),
+                SteeringAngleLimiter::new(
+                    Self::SPEED_MAP_FOR_TILLER_ACTION_KNOT,
+                    Self::STEERING_ANGLE_FOR_TILLER_ACTION_RATIO,
+                ),
+                SteeringAngleLimits::new(
+                    Angle::new::<degree>(Self::MAX_RUDDER_INPUT_INCLUDING_AUTOPILOT_DEGREE),
+                    Angle::new::<degree>(Self::MAX_STEERING_ANGLE_DEMAND_DEGREES),
+                    Angle::new::<degree>(Self::OVERSTEER_ANGLE_DEGREES),
+                ),
             ),
-            final_steering_position_request: Angle::new::<degree>(0.),
 
             body_wheel_steering_control: BodyWheelSteeringControl::default(),
 
             heading_control_function: HeadingControlFunction::default(),
+
+            anti_skid_controllers: [AntiSkidController::new(), AntiSkidController::new()],
         }
     }
 
@@ -4460,6 +4749,8 @@ impl A380HydraulicBrakeSteerComputerUnit {
         adirs: &impl AdirsMeasurementOutputs,
         placeholder_ground_spoilers_out: bool,
         nose_steering_feedback: Angle,
+        left_braked_wheels: &[BrakedWheel; 2],
+        right_braked_wheels: &[BrakedWheel; 2],
     ) {
         // TODO split steering part from braking part in two different computers instances
         self.update_steering_demands(
@@ -4546,6 +4837,37 @@ impl A380HydraulicBrakeSteerComputerUnit {
                 }
             }
         }
+
+        self.update_anti_skid(context, left_braked_wheels, right_braked_wheels);
+    }
+
+    /// Anti-skid modulates normal and alternate brake demands of each side from the speed of its wheels
+    fn update_anti_skid(
+        &mut self,
+        context: &UpdateContext,
+        left_braked_wheels: &[BrakedWheel; 2],
+        right_braked_wheels: &[BrakedWheel; 2],
+    ) {
+        let anti_skid_active = self.anti_skid_activated && !self.parking_brake_demand;
+
+        for (controller, braked_wheels) in self
+            .anti_skid_controllers
+            .iter_mut()
+            .zip([left_braked_wheels, right_braked_wheels])
+        {
+            controller.update(context, anti_skid_active, braked_wheels);
+        }
+
+        let [left_anti_skid, right_anti_skid] = &self.anti_skid_controllers;
+        for brake_outputs in [
+            &mut self.norm_brake_outputs,
+            &mut self.alternate_brake_outputs,
+        ] {
+            brake_outputs.set_brake_demands(
+                left_anti_skid.modulated_demand(brake_outputs.left_demand()),
+                right_anti_skid.modulated_demand(brake_outputs.right_demand()),
+            );
+        }
     }
 
     fn update_steering_demands(
@@ -4557,70 +4879,25 @@ impl A380HydraulicBrakeSteerComputerUnit {
         engine2: &impl Engine,
         nose_steering_feedback: Angle,
     ) {
-        self.pedal_steering_limiter
-            .update(context.ground_speed(), lgciu1); // TODO check which lgciu input is used for mode determination
-
-        let steer_angle_from_autopilot = Angle::new::<degree>(
-            self.autopilot_nosewheel_demand.get::<ratio>() * Self::AUTOPILOT_STEERING_INPUT_GAIN,
-        );
-
-        let steer_angle_from_pedals = if self.tiller_pedal_disconnect {
-            Angle::new::<degree>(0.)
-        } else {
-            self.pedal_input_map
-                .angle_demand_from_input_demand(self.rudder_pedal_position)
-        };
-
-        // TODO Here ground speed would be probably computed from wheel sensor logic
-        let final_steer_rudder_plus_autopilot = self.pedal_steering_limiter.angle_from_speed(
-            context.ground_speed(),
-            (steer_angle_from_pedals + steer_angle_from_autopilot)
-                .min(Angle::new::<degree>(
-                    Self::MAX_RUDDER_INPUT_INCLUDING_AUTOPILOT_DEGREE,
-                ))
-                .max(Angle::new::<degree>(
-                    -Self::MAX_RUDDER_INPUT_INCLUDING_AUTOPILOT_DEGREE,
-                )),
-        );
-
-        let steer_angle_from_tiller = self.tiller_steering_limiter.angle_from_speed(
-            context.ground_speed(),
-            self.tiller_input_map
-                .angle_demand_from_input_demand(self.tiller_handle_position),
-        );
-
-        let any_steering_demand_active = steer_angle_from_autopilot.abs()
-            > Angle::new::<degree>(0.)
-            || steer_angle_from_pedals.abs() > Angle::new::<degree>(0.1)
-            || steer_angle_from_tiller.abs() > Angle::new::<degree>(0.1);
-
         self.heading_control_function.update(
             context,
             1,
             adirs,
-            any_steering_demand_active,
+            self.steering_control.is_pilot_demand_active(context),
             nose_steering_feedback,
         );
 
         let is_both_engine_low_oil_pressure =
             engine1.oil_pressure_is_low() && engine2.oil_pressure_is_low();
 
-        self.final_steering_position_request = if !is_both_engine_low_oil_pressure
-            && self.anti_skid_activated
-            && lgciu1.nose_gear_compressed(false)
-        {
-            (final_steer_rudder_plus_autopilot
-                + steer_angle_from_tiller
-                + self.heading_control_function.steering_output())
-            .min(Angle::new::<degree>(
-                Self::MAX_STEERING_ANGLE_DEMAND_DEGREES,
-            ))
-            .max(Angle::new::<degree>(
-                -Self::MAX_STEERING_ANGLE_DEMAND_DEGREES,
-            ))
-        } else {
-            Angle::new::<degree>(0.)
-        };
+        // TODO check which lgciu input is used for pedal steering mode determination
+        self.steering_control.update(
+            context,
+            lgciu1,
+            nose_steering_feedback,
+            !is_both_engine_low_oil_pressure && self.anti_skid_activated,
+            self.heading_control_function.steering_output(),
+        );
     }
 
     fn norm_controller(&self) -> &impl BrakeCircuitController {
@@ -4646,6 +4923,8 @@ impl A380HydraulicBrakeSteerComputerUnit {
 impl SimulationElement for A380HydraulicBrakeSteerComputerUnit {
     fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
         self.autobrake_controller.accept(visitor);
+        self.steering_control.accept(visitor);
+        self.body_wheel_steering_control.accept(visitor);
         visitor.visit(self);
     }
 
@@ -4657,19 +4936,15 @@ impl SimulationElement for A380HydraulicBrakeSteerComputerUnit {
             Ratio::new::<percent>(reader.read(&self.left_brake_pedal_input_id));
         self.right_brake_pilot_input =
             Ratio::new::<percent>(reader.read(&self.right_brake_pedal_input_id));
-
-        self.tiller_handle_position =
-            Ratio::new::<ratio>(reader.read(&self.tiller_handle_input_id));
-        self.rudder_pedal_position = Ratio::new::<ratio>(reader.read(&self.rudder_pedal_input_id));
-        self.tiller_pedal_disconnect = reader.read(&self.tiller_pedal_disconnect_id);
-
-        self.autopilot_nosewheel_demand =
-            Ratio::new::<ratio>(reader.read(&self.autopilot_nosewheel_demand_id));
     }
 }
 impl SteeringController for A380HydraulicBrakeSteerComputerUnit {
     fn requested_position(&self) -> Angle {
-        self.final_steering_position_request
+        self.steering_control.requested_position()
+    }
+
+    fn is_steering_available(&self) -> bool {
+        self.steering_control.is_steering_available()
     }
 }
 
@@ -4725,6 +5000,10 @@ impl A380BrakingForce {
         context: &UpdateContext,
         norm_brakes: &BrakeCircuit,
         altn_brakes: &BrakeCircuit,
+        left_brakes: [&BrakeAssembly<4>; 2],
+        right_brakes: [&BrakeAssembly<4>; 2],
+        left_braked_wheels: &[BrakedWheel; 2],
+        right_braked_wheels: &[BrakedWheel; 2],
         engine1: &impl Engine,
         engine2: &impl Engine,
         bypass_pin: &BypassPin,
@@ -4747,11 +5026,29 @@ impl A380BrakingForce {
         self.right_braking_force = right_force_norm + right_force_altn;
         self.right_braking_force = self.right_braking_force.clamp(0., 1.);
 
+        // A skidding tyre can't transmit all the force its brake is asking for
+        self.left_braking_force *= braking_force_transmission_ratio(left_braked_wheels);
+        self.right_braking_force *= braking_force_transmission_ratio(right_braked_wheels);
+
+        self.left_braking_force =
+            Self::tyre_corrected_force_factor(self.left_braking_force, left_brakes);
+        self.right_braking_force =
+            Self::tyre_corrected_force_factor(self.right_braking_force, right_brakes);
+
         self.correct_with_flaps_state(context);
 
         self.update_chocks_braking(context, engine1, engine2, bypass_pin);
     }
 
+    /// Averages the tyre correction over the wing and body gear brakes of one side
+    fn tyre_corrected_force_factor(force_factor: f64, brakes: [&BrakeAssembly<4>; 2]) -> f64 {
+        brakes
+            .iter()
+            .map(|brake_assembly| brake_assembly.tyre_corrected_force_factor(force_factor))
+            .sum::<f64>()
+            / brakes.len() as f64
+    }
+
     fn correct_with_flaps_state(&mut self, context: &UpdateContext) {
         let flap_correction = Ratio::new::<percent>(interpolation(
             &Self::FLAPS_BREAKPOINTS,
@@ -7075,11 +7372,15 @@ impl A380TiltingGears {
         }
     }
 
-    fn update(&mut self, context: &UpdateContext) {
-        self.left_body_gear.update(context);
-        self.right_body_gear.update(context);
-        self.left_wing_gear.update(context);
-        self.right_wing_gear.update(context);
+    fn update(&mut self, context: &UpdateContext, landing_gear: &LandingGear) {
+        self.left_body_gear
+            .update(context, landing_gear.strut_stroke(GearWheel::LEFT));
+        self.right_body_gear
+            .update(context, landing_gear.strut_stroke(GearWheel::RIGHT));
+        self.left_wing_gear
+            .update(context, landing_gear.strut_stroke(GearWheel::WINGLEFT));
+        self.right_wing_gear
+            .update(context, landing_gear.strut_stroke(GearWheel::WINGRIGHT));
     }
 }
 impl SimulationElement for A380TiltingGears {
@@ -7110,7 +7411,10 @@ mod tests {
             },
             engine::{trent_engine::TrentEngine, EngineFireOverheadPanel},
             failures::FailureType,
-            hydraulic::cargo_doors::{DoorControlState, HydraulicDoorController},
+            hydraulic::{
+                anti_skid::RunwayContamination,
+                cargo_doors::{DoorControlState, HydraulicDoorController},
+            },
             landing_gear::{GearSystemState, LandingGear, LandingGearControlInterfaceUnitSet},
             shared::{
                 arinc429::{Arinc429Word, SignStatus},
@@ -7315,7 +7619,7 @@ mod tests {
                     overhead: A380HydraulicOverheadPanel::new(context),
                     autobrake_panel: A380AutobrakePanel::new(context),
                     engine_fire_overhead: EngineFireOverheadPanel::new(context),
-                    landing_gear: LandingGear::new(context, true),
+                    landing_gear: A380LandingGearFactory::a380_landing_gear(context),
                     lgcius: LandingGearControlInterfaceUnitSet::new(
                         context,
                         ElectricalBusType::DirectCurrentEssential,
@@ -7574,6 +7878,8 @@ mod tests {
 
                 self.adirus.update(context);
 
+                self.landing_gear.update(context);
+
                 self.lgcius.update(
                     context,
                     &self.landing_gear,
@@ -7593,6 +7899,7 @@ mod tests {
                     &self.autobrake_panel,
                     &self.engine_fire_overhead,
                     &self.lgcius,
+                    &self.landing_gear,
                     &self.pneumatics,
                     &self.adirus,
                 );
@@ -8112,6 +8419,17 @@ mod tests {
                 self
             }
 
+            fn rolling_on_runway(
+                mut self,
+                ground_speed: Velocity,
+                contamination: RunwayContamination,
+            ) -> Self {
+                self.write_by_name("TOTAL WEIGHT", Mass::new::<kilogram>(390000.));
+                self.write_by_name("GPS GROUND SPEED", ground_speed.get::<knot>());
+                self.write_by_name("SURFACE CONDITION", f64::from(contamination));
+                self
+            }
+
             fn set_yellow_e_pump_a(mut self, is_on: bool) -> Self {
                 self.write_by_name("OVHD_HYD_EPUMPYA_ON_PB_IS_AUTO", !is_on);
                 self
@@ -9219,6 +9537,40 @@ mod tests {
             );
         }
 
+        #[test]
+        fn middle_left_aileron_panel_does_not_respond_with_no_hyds_when_eha_failed() {
+            let mut test_bed = test_bed_on_ground_with()
+                .engines_off()
+                .on_the_ground()
+                .set_cold_dark_inputs()
+                .reset_all_aileron_commands()
+                .run_waiting_for(Duration::from_secs(1));
+
+            // Artificially set plane not on ground to allow EHA activation
+            test_bed.set_on_ground(false);
+            test_bed.fail(FailureType::ElectroHydrostaticActuator(1));
+
+            assert!(!test_bed.is_green_pressure_switch_pressurised());
+            assert!(!test_bed.is_yellow_pressure_switch_pressurised());
+
+            test_bed = test_bed
+                .reset_all_aileron_commands()
+                .ac_ess_active()
+                .set_aileron_panel_neutral(
+                    ActuatorSide::Left,
+                    AileronPanelPosition::Middle,
+                    AileronActuatorPosition::Inward,
+                )
+                .run_waiting_for(Duration::from_secs(5));
+
+            assert!(
+                test_bed
+                    .get_left_aileron_panel_position(AileronPanelPosition::Middle)
+                    .get::<ratio>()
+                    < 0.1
+            );
+        }
+
         #[test]
         fn middle_right_aileron_panel_responds_with_no_hyds_on_eha_jack() {
             let mut test_bed = test_bed_on_ground_with()
@@ -9633,8 +9985,9 @@ mod tests {
                 .set_right_spoilers_out()
                 .run_waiting_for(Duration::from_secs(5));
 
-            // Artificially set plane not on ground to allow EHA activation
+            // Artificially set plane not on ground to allow EHA activation, struts need some time to extend
             test_bed.set_on_ground(false);
+            test_bed = test_bed.run_waiting_for(Duration::from_secs(1));
 
             assert!(!test_bed.is_green_pressure_switch_pressurised());
             assert!(!test_bed.is_yellow_pressure_switch_pressurised());
@@ -10254,6 +10607,57 @@ mod tests {
             );
         }
 
+        fn min_max_left_brake_pressure_while_braking_on_runway(
+            contamination: RunwayContamination,
+        ) -> (Pressure, Pressure) {
+            let mut test_bed = test_bed_on_ground_with()
+                .engines_off()
+                .on_the_ground_after_touchdown()
+                .set_cold_dark_inputs()
+                .start_eng1(Ratio::new::<percent>(80.))
+                .start_eng2(Ratio::new::<percent>(80.))
+                .start_eng3(Ratio::new::<percent>(80.))
+                .start_eng4(Ratio::new::<percent>(80.))
+                .set_park_brake(false)
+                .set_anti_skid(true)
+                .rolling_on_runway(Velocity::new::<knot>(100.), contamination)
+                .run_waiting_for(Duration::from_secs(5));
+
+            test_bed = test_bed
+                .set_left_brake(Ratio::new::<percent>(100.))
+                .set_right_brake(Ratio::new::<percent>(100.))
+                .run_waiting_for(Duration::from_secs(1));
+
+            let mut min_pressure = Pressure::new::<psi>(3000.);
+            let mut max_pressure = Pressure::default();
+            for _ in 0..50 {
+                test_bed = test_bed.run_waiting_for(Duration::from_millis(100));
+
+                let pressure = test_bed.get_brake_left_green_pressure();
+                min_pressure = min_pressure.min(pressure);
+                max_pressure = max_pressure.max(pressure);
+            }
+
+            (min_pressure, max_pressure)
+        }
+
+        #[test]
+        fn max_manual_braking_on_dry_runway_keeps_full_brake_pressure() {
+            let (min_pressure, _) =
+                min_max_left_brake_pressure_while_braking_on_runway(RunwayContamination::Dry);
+
+            assert_gt!(min_pressure, Pressure::new::<psi>(2000.));
+        }
+
+        #[test]
+        fn max_manual_braking_on_wet_runway_cycles_brake_pressure_with_anti_skid() {
+            let (min_pressure, max_pressure) =
+                min_max_left_brake_pressure_while_braking_on_runway(RunwayContamination::Wet);
+
+            assert_gt!(max_pressure, Pressure::new::<psi>(1000.));
+            assert_lt!(min_pressure, Pressure::new::<psi>(500.));
+        }
+
         #[test]
         fn autobrakes_arms_in_flight_lo_to_hi() {
             let mut test_bed = test_bed_on_ground_with()
@@ -11289,15 +11693,18 @@ mod tests {
 
         #[test]
         fn nose_wheel_steers_with_pushback_tug() {
+            // Parking brake released, as braking while pushed would break the towbar shear pin.
+            // The yaw rate keeps the tug below the towbar steering angle limit.
             let mut test_bed = test_bed_on_ground_with()
                 .engines_off()
                 .on_the_ground()
                 .set_cold_dark_inputs()
+                .set_park_brake(false)
                 .run_one_tick();
 
             test_bed = test_bed
                 .set_pushback_state(true)
-                .set_pushback_angle(AngularVelocity::new::<degree_per_second>(-5.))
+                .set_pushback_angle(AngularVelocity::new::<degree_per_second>(-2.5))
                 .run_waiting_for(Duration::from_secs_f64(5.));
 
             // Has turned fully after 5s
@@ -11306,7 +11713,7 @@ mod tests {
             // Going left
             test_bed = test_bed
                 .set_pushback_state(true)
-                .set_pushback_angle(AngularVelocity::new::<degree_per_second>(5.))
+                .set_pushback_angle(AngularVelocity::new::<degree_per_second>(2.5))
                 .run_waiting_for(Duration::from_secs_f64(5.));
 
             // Has turned fully left after 5s
@@ -11478,6 +11885,7 @@ mod tests {
 
             test_bed = test_bed
                 .on_the_ground_after_touchdown()
+                .rolling_on_runway(Velocity::new::<knot>(100.), RunwayContamination::Dry)
                 .set_left_brake(Ratio::new::<ratio>(1.))
                 .set_right_brake(Ratio::new::<ratio>(1.))
                 .run_waiting_for(Duration::from_secs_f64(2.));
@@ -11705,6 +12113,38 @@ mod tests {
             );
         }
 
+        #[test]
+        fn pushback_steering_does_not_turn_body_steering_with_both_channels_failed() {
+            let mut test_bed = test_bed_on_ground_with()
+                .engines_off()
+                .on_the_ground()
+                .set_cold_dark_inputs()
+                .run_one_tick();
+
+            test_bed.fail(FailureType::SteeringControlChannel(1));
+            test_bed.fail(FailureType::SteeringControlChannel(2));
+
+            test_bed = test_bed
+                .set_pushback_state(true)
+                .set_pushback_angle(AngularVelocity::new::<degree_per_second>(5.))
+                .run_waiting_for(Duration::from_secs(15));
+
+            assert!(
+                test_bed
+                    .get_left_body_steering_steering_ratio()
+                    .get::<ratio>()
+                    .abs()
+                    < 0.01
+            );
+            assert!(
+                test_bed
+                    .get_right_body_steering_steering_ratio()
+                    .get::<ratio>()
+                    .abs()
+                    < 0.01
+            );
+        }
+
         #[test]
         fn takeoff_mode_limits_nose_steering_to_2_degrees_after_150_knot() {
             let mut test_bed = test_bed_on_ground_with()
@@ -31,13 +31,19 @@ use electrical::{
     APU_START_MOTOR_BUS_TYPE,
 };
 use fire_and_smoke_protection::A380FireAndSmokeProtection;
-use hydraulic::{autobrakes::A380AutobrakePanel, A380Hydraulic, A380HydraulicOverheadPanel};
+use hydraulic::{
+    autobrakes::A380AutobrakePanel, A380Hydraulic, A380HydraulicOverheadPanel,
+    A380LandingGearFactory,
+};
 use icing::Icing;
 use navigation::{A380AirDataInertialReferenceSystemBuilder, A380RadioAltimeters};
 use payload::A380Payload;
 use power_consumption::A380PowerConsumption;
 use reverser::{A380ReverserController, A380Reversers};
-use uom::si::{f64::Length, length::nautical_mile};
+use uom::si::{
+    f64::Length,
+    length::{meter, nautical_mile},
+};
 
 use systems::{
     accept_iterable,
@@ -46,7 +52,10 @@ use systems::{
         AuxiliaryPowerUnitOverheadPanel, Pw980ApuGenerator, Pw980Constants, Pw980StartMotor,
     },
     electrical::{Electricity, ElectricitySource, ExternalPowerSource},
-    engine::{reverser_thrust::ReverserForce, trent_engine::TrentEngine, EngineFireOverheadPanel},
+    engine::{
+        native_engine::NativeEngineInputs, reverser_thrust::ReverserForce,
+        trent_engine::TrentEngine, EngineFireOverheadPanel,
+    },
     enhanced_gpwc::EnhancedGroundProximityWarningComputer,
     landing_gear::{LandingGear, LandingGearControlInterfaceUnitSet},
     navigation::adirs::{
@@ -142,7 +151,7 @@ impl A380 {
             hydraulic: A380Hydraulic::new(context),
             hydraulic_overhead: A380HydraulicOverheadPanel::new(context),
             autobrake_panel: A380AutobrakePanel::new(context),
-            landing_gear: LandingGear::new(context, true),
+            landing_gear: A380LandingGearFactory::a380_landing_gear(context),
             pneumatic: A380Pneumatic::new(context),
             radio_altimeters: A380RadioAltimeters::new(context),
             cds: A380ControlDisplaySystem::new(context),
@@ -169,9 +178,21 @@ impl A380 {
                 A380ReverserController::new(context, 3),
             ],
             reversers_assembly: A380Reversers::new(context),
-            reverse_thrust: ReverserForce::new(context),
+            reverse_thrust: ReverserForce::new(context, Length::new::<meter>(14.9)),
         }
     }
+
+    /// The engines run after the pneumatic system within the same update, the generator
+    /// loads are those of the current update.
+    fn native_engine_inputs(&self, number: usize) -> NativeEngineInputs {
+        NativeEngineInputs::new(
+            self.pneumatic.engine_fuel_is_on(number),
+            self.pneumatic.engine_starter_torque(number),
+            self.pneumatic.engine_bleed_air_extraction(number),
+            self.electrical
+                .engine_generator_shaft_power_extraction(number),
+        )
+    }
 }
 impl Aircraft for A380 {
     fn update_before_power_distribution(
@@ -184,14 +205,8 @@ impl Aircraft for A380 {
             &self.apu_overhead,
             self.fire_and_smoke_protection.apu_fire_on_ground(),
             &self.apu_fire_overhead,
-            self.pneumatic_overhead.apu_bleed_is_on(),
-            // This will be replaced when integrating the whole electrical system.
-            // For now we use the same logic as found in the JavaScript code; ignoring whether or not
-            // the engine generators are supplying electricity.
-            (self.electrical_overhead.apu_generator_is_on(1)
-                || self.electrical_overhead.apu_generator_is_on(2))
-                && !(self.electrical_overhead.external_power_is_on(1)
-                    && self.electrical_overhead.external_power_is_available(1)),
+            self.pneumatic_overhead.apu_bleed_is_on() && !self.pneumatic.apu_bleed_is_isolated(),
+            self.pneumatic.apu_bleed_air_flow(),
             self.pneumatic.apu_bleed_air_valve(),
             self.fuel.feed_four_tank_has_fuel(),
         );
@@ -237,6 +252,9 @@ impl Aircraft for A380 {
 
         self.adcn.update();
         self.adcn_simvar_translation.update(&self.adcn);
+
+        self.landing_gear.update(context);
+
         self.lgcius.update(
             context,
             &self.landing_gear,
@@ -264,6 +282,7 @@ impl Aircraft for A380 {
             &self.autobrake_panel,
             &self.engine_fire_overhead,
             &self.lgcius,
+            &self.landing_gear,
             &self.pneumatic,
             &self.adirs,
         );
@@ -327,14 +346,35 @@ impl Aircraft for A380 {
             self.hydraulic.up_down_rudder_aero_torques(),
             &self.hydraulic,
             &self.fuel,
+            [
+                &self.engine_1,
+                &self.engine_2,
+                &self.engine_3,
+                &self.engine_4,
+            ],
         );
         self.cds.update();
 
         self.icing_simulation.update(context);
 
         self.egpwc.update(&self.adirs, self.lgcius.lgciu1());
-        self.fuel
-            .update(context, &self.adcn, A380Airframe::get_loadsheet());
+        self.fuel.update(
+            context,
+            &self.adcn,
+            A380Airframe::get_loadsheet(),
+            self.engine_1.heat_rejected_to_fuel()
+                + self.engine_2.heat_rejected_to_fuel()
+                + self.engine_3.heat_rejected_to_fuel()
+                + self.engine_4.heat_rejected_to_fuel(),
+        );
+        self.engine_1
+            .update(context, &self.fuel, self.native_engine_inputs(1));
+        self.engine_2
+            .update(context, &self.fuel, self.native_engine_inputs(2));
+        self.engine_3
+            .update(context, &self.fuel, self.native_engine_inputs(3));
+        self.engine_4
+            .update(context, &self.fuel, self.native_engine_inputs(4));
 
         self.engine_reverser_control[0].update(
             &self.engine_2,
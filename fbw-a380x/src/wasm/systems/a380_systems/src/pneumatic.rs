@@ -12,19 +12,23 @@ use uom::si::{
 use systems::{
     accept_iterable,
     air_conditioning::PackFlowControllers,
+    engine::start_controller::{EngineStartCharacteristics, EngineStartController},
+    failures::{Failure, FailureType},
     overhead::{AutoOffFaultPushButton, OnOffFaultPushButton},
     pneumatic::{
-        valve::*, BleedMonitoringComputerIsAliveSignal, CompressionChamber,
-        ControllablePneumaticValve, CrossBleedValveSelectorKnob, CrossBleedValveSelectorMode,
-        DifferentialPressureTransducer, EngineCompressionChamberController, EngineModeSelector,
-        EngineState, PneumaticContainer, PneumaticPipe, PneumaticValveSignal, Precooler,
-        PressureTransducer, PressurisedReservoirWithExhaustValve, PressurizeableReservoir,
+        leak_detection::BleedLeakDetection, valve::*, BleedDuctLeak,
+        BleedMonitoringComputerIsAliveSignal, CompressionChamber, ControllablePneumaticValve,
+        CrossBleedValveSelectorKnob, CrossBleedValveSelectorMode, DifferentialPressureTransducer,
+        EngineCompressionChamberController, EngineModeSelector, EngineState, PneumaticContainer,
+        PneumaticPipe, PneumaticValveSignal, Precooler, PressureTransducer,
+        PressurisedReservoirWithExhaustValve, PressurizeableReservoir,
         TargetPressureTemperatureSignal, VariableVolumeContainer,
     },
     shared::{
-        pid::PidController, update_iterator::MaxStepLoop, ControllerSignal, ElectricalBusType,
-        ElectricalBuses, EngineBleedPushbutton, EngineCorrectedN1, EngineCorrectedN2,
-        EngineFirePushButtons, EngineStartState, HydraulicColor, PackFlowValveState,
+        pid::PidController, update_iterator::MaxStepLoop, BleedDuctZone, ControllerSignal,
+        ElectricalBusType, ElectricalBuses, EngineBleedPushbutton, EngineCorrectedN1,
+        EngineCorrectedN2, EngineExhaustGasTemperature, EngineFirePushButtons, EngineStartState,
+        EngineUncorrectedN2, FireDetectionLoopID, HydraulicColor, PackFlowValveState,
         PneumaticBleed, PneumaticValve, ReservoirAirPressure,
     },
     simulation::{
@@ -113,10 +117,14 @@ pub struct A380Pneumatic {
     cross_bleed_valves: [CrossBleedValve; 3],
 
     fadec: FullAuthorityDigitalEngineControl,
+    engine_start_controllers: [EngineStartController; 4],
     engine_starter_valve_controllers: [EngineStarterValveController; 4],
 
     apu_compression_chamber: CompressionChamber,
     apu_bleed_air_valve: DefaultValve,
+    apu_bleed_duct_leak: BleedDuctLeak,
+
+    leak_detection: A380BleedLeakDetection,
 
     hydraulic_reservoir_bleed_air_valves: [PurelyPneumaticValve; 2],
     hydraulic_reservoir_bleed_air_pipe: PneumaticPipe,
@@ -130,6 +138,7 @@ pub struct A380Pneumatic {
 }
 impl A380Pneumatic {
     const PNEUMATIC_SIM_MAX_TIME_STEP: Duration = Duration::from_millis(100);
+    const APU_BLEED_DUCT_LEAK_SPEED: f64 = 1.;
 
     pub fn new(context: &mut InitContext) -> Self {
         Self {
@@ -148,19 +157,36 @@ impl A380Pneumatic {
                 EngineBleedAirSystem::new(context, 4, ElectricalBusType::DirectCurrent(2)),
             ],
             cross_bleed_valves: [
-                CrossBleedValve::new(context.get_identifier("PNEU_XBLEED_VALVE_L_OPEN".to_owned())),
-                CrossBleedValve::new(context.get_identifier("PNEU_XBLEED_VALVE_C_OPEN".to_owned())),
-                CrossBleedValve::new(context.get_identifier("PNEU_XBLEED_VALVE_R_OPEN".to_owned())),
+                CrossBleedValve::new(
+                    context.get_identifier("PNEU_XBLEED_VALVE_L_OPEN".to_owned()),
+                    1,
+                ),
+                CrossBleedValve::new(
+                    context.get_identifier("PNEU_XBLEED_VALVE_C_OPEN".to_owned()),
+                    2,
+                ),
+                CrossBleedValve::new(
+                    context.get_identifier("PNEU_XBLEED_VALVE_R_OPEN".to_owned()),
+                    3,
+                ),
             ],
             fadec: FullAuthorityDigitalEngineControl::new(context),
+            engine_start_controllers: [1, 2, 3, 4].map(|number| {
+                EngineStartController::new(context, number, EngineStartCharacteristics::trent_972())
+            }),
             engine_starter_valve_controllers: [
-                EngineStarterValveController::new(1),
-                EngineStarterValveController::new(2),
-                EngineStarterValveController::new(3),
-                EngineStarterValveController::new(4),
+                EngineStarterValveController::new(),
+                EngineStarterValveController::new(),
+                EngineStarterValveController::new(),
+                EngineStarterValveController::new(),
             ],
             apu_compression_chamber: CompressionChamber::new(Volume::new::<cubic_meter>(5.)),
             apu_bleed_air_valve: DefaultValve::new_closed(),
+            apu_bleed_duct_leak: BleedDuctLeak::new(
+                BleedDuctZone::Apu,
+                Self::APU_BLEED_DUCT_LEAK_SPEED,
+            ),
+            leak_detection: A380BleedLeakDetection::new(context),
             hydraulic_reservoir_bleed_air_valves: [
                 PurelyPneumaticValve::new(),
                 PurelyPneumaticValve::new(),
@@ -199,7 +225,10 @@ impl A380Pneumatic {
     pub(crate) fn update(
         &mut self,
         context: &UpdateContext,
-        engines: [&(impl EngineCorrectedN1 + EngineCorrectedN2); 4],
+        engines: [&(impl EngineCorrectedN1
+              + EngineCorrectedN2
+              + EngineUncorrectedN2
+              + EngineExhaustGasTemperature); 4],
         pneumatic_overhead_panel: &A380PneumaticOverheadPanel,
         engine_fire_push_buttons: &impl EngineFirePushButtons,
         apu: &impl ControllerSignal<TargetPressureTemperatureSignal>,
@@ -222,7 +251,10 @@ impl A380Pneumatic {
     pub(crate) fn update_physics(
         &mut self,
         context: &UpdateContext,
-        engines: [&(impl EngineCorrectedN1 + EngineCorrectedN2); 4],
+        engines: [&(impl EngineCorrectedN1
+              + EngineCorrectedN2
+              + EngineUncorrectedN2
+              + EngineExhaustGasTemperature); 4],
         overhead_panel: &A380PneumaticOverheadPanel,
         engine_fire_push_buttons: &impl EngineFirePushButtons,
         apu: &impl ControllerSignal<TargetPressureTemperatureSignal>,
@@ -236,6 +268,7 @@ impl A380Pneumatic {
             &self.apu_bleed_air_valve,
             overhead_panel,
             engine_fire_push_buttons,
+            &self.leak_detection,
         );
 
         for cross_bleed_valve in self.cross_bleed_valves.iter_mut() {
@@ -245,8 +278,28 @@ impl A380Pneumatic {
                 .update_open_amount(&self.core_processing_input_output_module_a.units[0])
         }
 
-        for controller in self.engine_starter_valve_controllers.iter_mut() {
-            controller.update(&self.fadec);
+        for (index, (start_controller, engine_system)) in self
+            .engine_start_controllers
+            .iter_mut()
+            .zip(&self.engine_systems)
+            .enumerate()
+        {
+            start_controller.update(
+                context,
+                self.fadec.engine_state(index + 1),
+                self.fadec.engine_mode_selector(),
+                engines[index],
+                engine_system.engine_starter_container_pressure(),
+                engine_system.engine_starter_valve_is_open(),
+            );
+        }
+
+        for (controller, start_controller) in self
+            .engine_starter_valve_controllers
+            .iter_mut()
+            .zip(&self.engine_start_controllers)
+        {
+            controller.update(start_controller);
         }
 
         for (index, (engine_system, cpiom_unit)) in self
@@ -272,6 +325,8 @@ impl A380Pneumatic {
             &mut self.apu_compression_chamber,
             engine_1_system,
         );
+        self.apu_bleed_duct_leak
+            .update_move_fluid(context, engine_1_system);
 
         // Hydraulic reservoir pressurization.
         // G+Y are pressurized through a pipe that is shared between engine 1 and engine 4 systems (downstream the bleed valve)
@@ -326,6 +381,9 @@ impl A380Pneumatic {
             engine_4_system,
             pack_flow_valve_signals,
         );
+
+        self.leak_detection
+            .update(context, &self.engine_systems, &self.apu_bleed_duct_leak);
     }
 
     // TODO: Returning a mutable reference here is not great. I was running into an issue with the update order:
@@ -337,6 +395,26 @@ impl A380Pneumatic {
         &mut self.apu_bleed_air_valve
     }
 
+    pub fn apu_bleed_air_flow(&self) -> MassRate {
+        self.apu_bleed_air_valve.fluid_flow()
+    }
+
+    /// Air taken from the engine compressor through the pressure regulating valve.
+    pub fn engine_bleed_air_extraction(&self, number: usize) -> MassRate {
+        self.engine_systems[number - 1].bleed_air_extraction()
+    }
+
+    /// Fuel is supplied to a running engine and during a start once the start controller
+    /// has introduced it.
+    pub fn engine_fuel_is_on(&self, number: usize) -> bool {
+        self.fadec.engine_state(number) == EngineState::On
+            || self.engine_start_controllers[number - 1].start_fuel_is_on()
+    }
+
+    pub fn engine_starter_torque(&self, number: usize) -> Torque {
+        self.engine_start_controllers[number - 1].starter_torque()
+    }
+
     pub fn update_hydraulic_reservoir_spatial_volumes(
         &mut self,
         green_hydraulic_reservoir: &impl PressurizeableReservoir,
@@ -351,6 +429,12 @@ impl A380Pneumatic {
     pub fn packs(&mut self) -> &mut [PackComplex; 2] {
         &mut self.packs
     }
+
+    /// Whether the CPIOM requests the APU bleed valve to close because of a bleed leak.
+    pub fn apu_bleed_is_isolated(&self) -> bool {
+        self.core_processing_input_output_module_a
+            .should_isolate_apu_bleed()
+    }
 }
 impl PneumaticBleed for A380Pneumatic {
     fn apu_bleed_is_on(&self) -> bool {
@@ -399,9 +483,12 @@ impl PackFlowValveState for A380Pneumatic {
 impl SimulationElement for A380Pneumatic {
     fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
         self.fadec.accept(visitor);
+        self.apu_bleed_duct_leak.accept(visitor);
+        self.leak_detection.accept(visitor);
 
         accept_iterable!(self.cross_bleed_valves, visitor);
         accept_iterable!(self.engine_systems, visitor);
+        accept_iterable!(self.engine_start_controllers, visitor);
         accept_iterable!(self.packs, visitor);
 
         self.yellow_hydraulic_reservoir_with_valve.accept(visitor);
@@ -436,27 +523,24 @@ impl ReservoirAirPressure for A380Pneumatic {
 }
 
 struct EngineStarterValveController {
-    number: usize,
-    engine_state: EngineState,
+    should_open: bool,
 }
 impl ControllerSignal<EngineStarterValveSignal> for EngineStarterValveController {
     fn signal(&self) -> Option<EngineStarterValveSignal> {
-        match self.engine_state {
-            EngineState::Starting => Some(EngineStarterValveSignal::new_open()),
-            _ => Some(EngineStarterValveSignal::new_closed()),
+        if self.should_open {
+            Some(EngineStarterValveSignal::new_open())
+        } else {
+            Some(EngineStarterValveSignal::new_closed())
         }
     }
 }
 impl EngineStarterValveController {
-    fn new(number: usize) -> Self {
-        Self {
-            number,
-            engine_state: EngineState::Off,
-        }
+    fn new() -> Self {
+        Self { should_open: false }
     }
 
-    fn update(&mut self, fadec: &FullAuthorityDigitalEngineControl) {
-        self.engine_state = fadec.engine_state(self.number);
+    fn update(&mut self, start_controller: &EngineStartController) {
+        self.should_open = start_controller.starter_valve_should_open();
     }
 }
 
@@ -489,14 +573,21 @@ impl CoreProcessingInputOutputModuleA {
         apu_bleed_valve: &impl PneumaticValve,
         pneumatic_overhead_panel: &A380PneumaticOverheadPanel,
         engine_fire_push_buttons: &impl EngineFirePushButtons,
+        leak_detection: &A380BleedLeakDetection,
     ) {
         for (unit, sensor) in self.units.iter_mut().zip(sensors) {
+            // The leak isolation is latched until the CPIOM is reset by a power interruption
+            if !self.is_powered {
+                unit.reset_leak_isolation();
+            }
+
             unit.update(
                 context,
                 sensor,
                 engine_fire_push_buttons.is_released(unit.engine_number),
                 apu_bleed_valve,
                 pneumatic_overhead_panel,
+                leak_detection,
             );
         }
     }
@@ -504,6 +595,10 @@ impl CoreProcessingInputOutputModuleA {
     fn is_powered(&self) -> bool {
         self.is_powered
     }
+
+    fn should_isolate_apu_bleed(&self) -> bool {
+        self.is_powered() && self.units.iter().any(|unit| unit.should_isolate_apu_bleed)
+    }
 }
 impl SimulationElement for CoreProcessingInputOutputModuleA {
     fn receive_power(&mut self, buses: &impl ElectricalBuses) {
@@ -536,6 +631,9 @@ struct CoreProcessingInputOutputModuleAUnit {
     pressure_regulating_valve_pid: PidController,
     fan_air_valve_pid: PidController,
     cross_bleed_valve_selector: CrossBleedValveSelectorMode,
+    should_isolate_engine_bleed: bool,
+    should_isolate_cross_bleed: bool,
+    should_isolate_apu_bleed: bool,
 }
 impl CoreProcessingInputOutputModuleAUnit {
     const PRESSURE_REGULATING_VALVE_TARGET_PSI: f64 = 40.; // FCOM
@@ -565,6 +663,9 @@ impl CoreProcessingInputOutputModuleAUnit {
             ),
             fan_air_valve_pid: PidController::new(-0.005, -0.001, 0., 0., 1., 200., 1.),
             cross_bleed_valve_selector: CrossBleedValveSelectorMode::Auto,
+            should_isolate_engine_bleed: false,
+            should_isolate_cross_bleed: false,
+            should_isolate_apu_bleed: false,
         }
     }
 
@@ -575,6 +676,7 @@ impl CoreProcessingInputOutputModuleAUnit {
         is_engine_fire_pushbutton_released: bool,
         apu_bleed_valve: &impl PneumaticValve,
         pneumatic_overhead_panel: &A380PneumaticOverheadPanel,
+        leak_detection: &A380BleedLeakDetection,
     ) {
         self.intermediate_pressure_compressor_pressure = sensors.intermediate_pressure();
         self.high_pressure_compressor_pressure = sensors.high_pressure();
@@ -614,6 +716,20 @@ impl CoreProcessingInputOutputModuleAUnit {
         self.is_apu_bleed_on = pneumatic_overhead_panel.apu_bleed_is_on();
 
         self.cross_bleed_valve_selector = pneumatic_overhead_panel.cross_bleed_mode();
+
+        self.update_leak_isolation(leak_detection);
+    }
+
+    fn update_leak_isolation(&mut self, leak_detection: &A380BleedLeakDetection) {
+        self.should_isolate_engine_bleed |= leak_detection.engine_leak_detected(self.engine_number);
+        self.should_isolate_cross_bleed |= leak_detection.any_leak_detected();
+        self.should_isolate_apu_bleed |= leak_detection.apu_leak_detected();
+    }
+
+    fn reset_leak_isolation(&mut self) {
+        self.should_isolate_engine_bleed = false;
+        self.should_isolate_cross_bleed = false;
+        self.should_isolate_apu_bleed = false;
     }
 
     fn should_close_pressure_regulating_valve_because_apu_bleed_is_on(&self) -> bool {
@@ -641,6 +757,7 @@ impl ControllerSignal<PressureRegulatingValveSignal> for CoreProcessingInputOutp
             || (!self.is_engine_bleed_pushbutton_auto || self.is_engine_fire_pushbutton_released)
             || self.should_close_pressure_regulating_valve_because_apu_bleed_is_on()
             || self.engine_starter_valve_is_open
+            || self.should_isolate_engine_bleed
         {
             Some(PressureRegulatingValveSignal::new_closed())
         } else {
@@ -667,7 +784,11 @@ impl ControllerSignal<CrossBleedValveSignal> for CoreProcessingInputOutputModule
                 CrossBleedValveSignalType::Manual,
             )),
             CrossBleedValveSelectorMode::Auto => {
-                if self.is_apu_bleed_valve_open || self.is_any_bleed_pushbutton_off {
+                if self.should_isolate_cross_bleed {
+                    Some(CrossBleedValveSignal::new_closed(
+                        CrossBleedValveSignalType::Automatic,
+                    ))
+                } else if self.is_apu_bleed_valve_open || self.is_any_bleed_pushbutton_off {
                     Some(CrossBleedValveSignal::new_open(
                         CrossBleedValveSignalType::Automatic,
                     ))
@@ -681,6 +802,97 @@ impl ControllerSignal<CrossBleedValveSignal> for CoreProcessingInputOutputModule
     }
 }
 
+/// The overheat detection loops along the bleed ducts, monitored by the CPIOM.
+struct A380BleedLeakDetection {
+    pylons: [BleedLeakDetection; 4],
+    wings: [BleedLeakDetection; 4],
+    apu: BleedLeakDetection,
+}
+impl A380BleedLeakDetection {
+    const PYLON_ALARM_TEMPERATURE_C: f64 = 204.;
+    const WING_ALARM_TEMPERATURE_C: f64 = 124.;
+    const APU_ALARM_TEMPERATURE_C: f64 = 124.;
+
+    // All zones are monitored by a double loop
+    const LOOPS: [(FireDetectionLoopID, ElectricalBusType); 2] = [
+        (FireDetectionLoopID::A, ElectricalBusType::DirectCurrent(1)),
+        (FireDetectionLoopID::B, ElectricalBusType::DirectCurrent(2)),
+    ];
+
+    fn new(context: &mut InitContext) -> Self {
+        let pylon_alarm_temperature =
+            ThermodynamicTemperature::new::<degree_celsius>(Self::PYLON_ALARM_TEMPERATURE_C);
+        let wing_alarm_temperature =
+            ThermodynamicTemperature::new::<degree_celsius>(Self::WING_ALARM_TEMPERATURE_C);
+
+        Self {
+            pylons: [1, 2, 3, 4].map(|number| {
+                BleedLeakDetection::new(
+                    context,
+                    BleedDuctZone::Pylon(number),
+                    pylon_alarm_temperature,
+                    &Self::LOOPS,
+                )
+            }),
+            wings: [1, 2, 3, 4].map(|number| {
+                BleedLeakDetection::new(
+                    context,
+                    BleedDuctZone::Wing(number),
+                    wing_alarm_temperature,
+                    &Self::LOOPS,
+                )
+            }),
+            apu: BleedLeakDetection::new(
+                context,
+                BleedDuctZone::Apu,
+                ThermodynamicTemperature::new::<degree_celsius>(Self::APU_ALARM_TEMPERATURE_C),
+                &Self::LOOPS,
+            ),
+        }
+    }
+
+    fn update(
+        &mut self,
+        context: &UpdateContext,
+        engine_systems: &[EngineBleedAirSystem; 4],
+        apu_bleed_duct_leak: &BleedDuctLeak,
+    ) {
+        for ((pylon, wing), engine_system) in self
+            .pylons
+            .iter_mut()
+            .zip(self.wings.iter_mut())
+            .zip(engine_systems)
+        {
+            pylon.update(context, &engine_system.pylon_duct_leak);
+            wing.update(context, &engine_system.wing_duct_leak);
+        }
+
+        self.apu.update(context, apu_bleed_duct_leak);
+    }
+
+    fn engine_leak_detected(&self, engine_number: usize) -> bool {
+        self.pylons[engine_number - 1].leak_detected()
+            || self.wings[engine_number - 1].leak_detected()
+    }
+
+    fn apu_leak_detected(&self) -> bool {
+        self.apu.leak_detected()
+    }
+
+    fn any_leak_detected(&self) -> bool {
+        (1..=4).any(|number| self.engine_leak_detected(number)) || self.apu_leak_detected()
+    }
+}
+impl SimulationElement for A380BleedLeakDetection {
+    fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
+        accept_iterable!(self.pylons, visitor);
+        accept_iterable!(self.wings, visitor);
+        self.apu.accept(visitor);
+
+        visitor.visit(self);
+    }
+}
+
 struct EngineBleedAirSystem {
     high_pressure_id: VariableIdentifier,
     starter_container_pressure_id: VariableIdentifier,
@@ -722,8 +934,21 @@ struct EngineBleedAirSystem {
     transfer_pressure_transducer: PressureTransducer,
     regulated_pressure_transducer: PressureTransducer,
     differential_pressure_transducer: DifferentialPressureTransducer,
+
+    pylon_duct_leak: BleedDuctLeak,
+    wing_duct_leak: BleedDuctLeak,
+
+    high_pressure_valve_stuck_open_failure: Failure,
+    high_pressure_valve_stuck_closed_failure: Failure,
+    pressure_regulating_valve_stuck_open_failure: Failure,
+    pressure_regulating_valve_stuck_closed_failure: Failure,
+    fan_air_valve_stuck_closed_failure: Failure,
+    engine_starter_valve_stuck_open_failure: Failure,
 }
 impl EngineBleedAirSystem {
+    const PYLON_DUCT_LEAK_SPEED: f64 = 1.;
+    const WING_DUCT_LEAK_SPEED: f64 = 1.;
+
     fn new(context: &mut InitContext, number: usize, powered_by: ElectricalBusType) -> Self {
         Self {
             high_pressure_id: context.get_identifier(format!("PNEU_ENG_{}_HP_PRESSURE", number)),
@@ -811,9 +1036,71 @@ impl EngineBleedAirSystem {
             transfer_pressure_transducer: PressureTransducer::new(powered_by),
             regulated_pressure_transducer: PressureTransducer::new(powered_by),
             differential_pressure_transducer: DifferentialPressureTransducer::new(powered_by),
+            pylon_duct_leak: BleedDuctLeak::new(
+                BleedDuctZone::Pylon(number),
+                Self::PYLON_DUCT_LEAK_SPEED,
+            ),
+            wing_duct_leak: BleedDuctLeak::new(
+                BleedDuctZone::Wing(number),
+                Self::WING_DUCT_LEAK_SPEED,
+            ),
+            high_pressure_valve_stuck_open_failure: Failure::new(
+                FailureType::BleedHighPressureValveStuckOpen(number),
+            ),
+            high_pressure_valve_stuck_closed_failure: Failure::new(
+                FailureType::BleedHighPressureValveStuckClosed(number),
+            ),
+            pressure_regulating_valve_stuck_open_failure: Failure::new(
+                FailureType::BleedPressureRegulatingValveStuckOpen(number),
+            ),
+            pressure_regulating_valve_stuck_closed_failure: Failure::new(
+                FailureType::BleedPressureRegulatingValveStuckClosed(number),
+            ),
+            fan_air_valve_stuck_closed_failure: Failure::new(
+                FailureType::BleedFanAirValveStuckClosed(number),
+            ),
+            engine_starter_valve_stuck_open_failure: Failure::new(
+                FailureType::EngineStarterValveStuckOpen(number),
+            ),
+        }
+    }
+
+    fn stuck_open_amount(
+        stuck_open_failure: &Failure,
+        stuck_closed_failure: &Failure,
+    ) -> Option<Ratio> {
+        if stuck_open_failure.is_active() {
+            Some(Ratio::new::<ratio>(1.))
+        } else if stuck_closed_failure.is_active() {
+            Some(Ratio::new::<ratio>(0.))
+        } else {
+            None
         }
     }
 
+    fn update_valve_failures(&mut self) {
+        self.high_pressure_valve
+            .set_stuck_open_amount(Self::stuck_open_amount(
+                &self.high_pressure_valve_stuck_open_failure,
+                &self.high_pressure_valve_stuck_closed_failure,
+            ));
+        self.pressure_regulating_valve
+            .set_stuck_open_amount(Self::stuck_open_amount(
+                &self.pressure_regulating_valve_stuck_open_failure,
+                &self.pressure_regulating_valve_stuck_closed_failure,
+            ));
+        self.fan_air_valve.set_stuck_open_amount(
+            self.fan_air_valve_stuck_closed_failure
+                .is_active()
+                .then(|| Ratio::new::<ratio>(0.)),
+        );
+        self.engine_starter_valve.set_stuck_open_amount(
+            self.engine_starter_valve_stuck_open_failure
+                .is_active()
+                .then(|| Ratio::new::<ratio>(1.)),
+        );
+    }
+
     fn update(
         &mut self,
         context: &UpdateContext,
@@ -838,6 +1125,8 @@ impl EngineBleedAirSystem {
         self.high_pressure_compression_chamber
             .update(&self.high_pressure_compression_chamber_controller);
 
+        self.update_valve_failures();
+
         self.high_pressure_valve
             .update_open_amount(high_pressure_valve_controller);
         self.pressure_regulating_valve
@@ -873,6 +1162,10 @@ impl EngineBleedAirSystem {
             &mut self.precooler_supply_pipe,
             &mut self.precooler_outlet_pipe,
         );
+        self.pylon_duct_leak
+            .update_move_fluid(context, &mut self.precooler_inlet_pipe);
+        self.wing_duct_leak
+            .update_move_fluid(context, &mut self.precooler_outlet_pipe);
         self.engine_starter_valve.update_move_fluid(
             context,
             &mut self.precooler_inlet_pipe,
@@ -937,6 +1230,16 @@ impl EngineBleedAirSystem {
         self.engine_starter_valve.is_open()
     }
 
+    fn engine_starter_container_pressure(&self) -> Pressure {
+        self.engine_starter_container.pressure()
+    }
+
+    fn bleed_air_extraction(&self) -> MassRate {
+        self.pressure_regulating_valve
+            .fluid_flow()
+            .max(MassRate::default())
+    }
+
     fn pressure_regulating_valve_is_open(&self) -> bool {
         self.pressure_regulating_valve.is_open()
     }
@@ -968,6 +1271,19 @@ impl SimulationElement for EngineBleedAirSystem {
         self.regulated_pressure_transducer.accept(visitor);
         self.differential_pressure_transducer.accept(visitor);
 
+        self.pylon_duct_leak.accept(visitor);
+        self.wing_duct_leak.accept(visitor);
+
+        self.high_pressure_valve_stuck_open_failure.accept(visitor);
+        self.high_pressure_valve_stuck_closed_failure
+            .accept(visitor);
+        self.pressure_regulating_valve_stuck_open_failure
+            .accept(visitor);
+        self.pressure_regulating_valve_stuck_closed_failure
+            .accept(visitor);
+        self.fan_air_valve_stuck_closed_failure.accept(visitor);
+        self.engine_starter_valve_stuck_open_failure.accept(visitor);
+
         visitor.visit(self);
     }
 
@@ -1381,17 +1697,20 @@ pub struct CrossBleedValve {
     connector: PneumaticContainerConnector,
     is_powered_for_manual_control: bool,
     is_powered_for_automatic_control: bool,
+
+    jammed_failure: Failure,
 }
 impl CrossBleedValve {
     const SPRING_CHARACTERISTIC: f64 = 1.;
 
-    pub fn new(valve_id: VariableIdentifier) -> Self {
+    pub fn new(valve_id: VariableIdentifier, number: usize) -> Self {
         Self {
             valve_id,
             open_amount: Ratio::new::<ratio>(0.),
             connector: PneumaticContainerConnector::new(),
             is_powered_for_manual_control: false,
             is_powered_for_automatic_control: false,
+            jammed_failure: Failure::new(FailureType::CrossBleedValveJammed(number)),
         }
     }
 
@@ -1401,7 +1720,10 @@ impl CrossBleedValve {
         container_one: &mut impl PneumaticContainer,
         container_two: &mut impl PneumaticContainer,
     ) {
-        if !self.is_powered_for_manual_control && !self.is_powered_for_automatic_control {
+        if !self.jammed_failure.is_active()
+            && !self.is_powered_for_manual_control
+            && !self.is_powered_for_automatic_control
+        {
             self.set_open_amount_from_pressure_difference(
                 container_one.pressure() - container_two.pressure(),
             )
@@ -1422,6 +1744,10 @@ impl CrossBleedValve {
     }
 
     fn update_open_amount(&mut self, controller: &impl ControllerSignal<CrossBleedValveSignal>) {
+        if self.jammed_failure.is_active() {
+            return;
+        }
+
         if let Some(signal) = controller.signal() {
             if signal.signal_type == CrossBleedValveSignalType::Manual
                 && self.is_powered_for_manual_control
@@ -1439,6 +1765,12 @@ impl PneumaticValve for CrossBleedValve {
     }
 }
 impl SimulationElement for CrossBleedValve {
+    fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
+        self.jammed_failure.accept(visitor);
+
+        visitor.visit(self);
+    }
+
     fn receive_power(&mut self, buses: &impl ElectricalBuses) {
         self.is_powered_for_manual_control =
             buses.is_powered(ElectricalBusType::DirectCurrentEssentialShed);
@@ -1468,14 +1800,14 @@ mod tests {
         },
         shared::{
             arinc429::{Arinc429Word, SignStatus},
-            ApuBleedAirValveSignal, CargoDoorLocked, ControllerSignal, ElectricalBusType,
-            ElectricalBuses, EmergencyElectricalState, EngineBleedPushbutton, EngineCorrectedN1,
-            EngineFirePushButtons, EngineStartState, HydraulicColor,
+            ApuBleedAirValveSignal, BleedDuctZone, CargoDoorLocked, ControllerSignal,
+            ElectricalBusType, ElectricalBuses, EmergencyElectricalState, EngineBleedPushbutton,
+            EngineCorrectedN1, EngineFirePushButtons, EngineStartState, HydraulicColor,
             InternationalStandardAtmosphere, LgciuWeightOnWheels, MachNumber, PackFlowValveState,
             PneumaticBleed, PneumaticValve, PotentialOrigin,
         },
         simulation::{
-            test::{SimulationTestBed, TestBed, WriteByName},
+            test::{ReadByName, SimulationTestBed, TestBed, WriteByName},
             Aircraft, InitContext, SimulationElement, SimulationElementVisitor, UpdateContext,
         },
     };
@@ -2327,6 +2659,31 @@ mod tests {
             })
         }
 
+        fn leak_detected(&mut self, zone: BleedDuctZone) -> bool {
+            self.read_by_name(&format!("PNEU_{}_LEAK_DETECTED", zone))
+        }
+
+        fn run_until_leak_detected(&mut self, zone: BleedDuctZone) {
+            for _ in 0..20 {
+                if self.leak_detected(zone) {
+                    break;
+                }
+
+                self.test_bed.run_multiple_frames(Duration::from_secs(5));
+            }
+        }
+
+        fn apu_bleed_is_isolated(&self) -> bool {
+            self.query(|a| a.pneumatic.apu_bleed_is_isolated())
+        }
+
+        fn engine_bleed_is_isolated(&self, number: usize) -> bool {
+            self.query(|a| {
+                a.pneumatic.core_processing_input_output_module_a.units[number - 1]
+                    .should_isolate_engine_bleed
+            })
+        }
+
         fn cross_bleed_valve_selector(&self) -> CrossBleedValveSelectorMode {
             self.query(|a| a.pneumatic_overhead_panel.cross_bleed_mode())
         }
@@ -2957,6 +3314,93 @@ mod tests {
         assert!(!test_bed.cross_bleed_valves_are_open());
     }
 
+    #[test]
+    fn jammed_cross_bleed_valve_does_not_open_when_apu_bleed_valve_opens() {
+        let mut test_bed = test_bed_with()
+            .cross_bleed_valve_selector_knob(CrossBleedValveSelectorMode::Auto)
+            .and_run();
+        test_bed.fail(FailureType::CrossBleedValveJammed(2));
+
+        test_bed = test_bed
+            .set_apu_bleed_valve_signal(ApuBleedAirValveSignal::new_open())
+            .and_run();
+
+        assert!(test_bed.query(|a| a.pneumatic.cross_bleed_valves[0].is_open()));
+        assert!(!test_bed.query(|a| a.pneumatic.cross_bleed_valves[1].is_open()));
+        assert!(test_bed.query(|a| a.pneumatic.cross_bleed_valves[2].is_open()));
+    }
+
+    #[test]
+    fn hp_valve_stuck_closed_does_not_open_at_idle() {
+        let mut test_bed = test_bed_with()
+            .idle_eng1()
+            .idle_eng2()
+            .idle_eng3()
+            .idle_eng4()
+            .mach_number(MachNumber(0.))
+            .both_packs_auto();
+        test_bed.fail(FailureType::BleedHighPressureValveStuckClosed(3));
+        test_bed = test_bed.and_stabilize();
+
+        assert!(test_bed.hp_valve_is_open(1));
+        assert!(!test_bed.hp_valve_is_open(3));
+    }
+
+    #[test]
+    fn wing_duct_leak_is_detected_and_engine_bleed_is_isolated() {
+        let mut test_bed = test_bed_with()
+            .idle_eng1()
+            .toga_eng2()
+            .idle_eng3()
+            .idle_eng4()
+            .both_packs_auto();
+
+        // The air escaping the leak must be hotter than the alarm temperature of the wing loops
+        test_bed.write_by_name("TURB ENG CORRECTED N2:2", Ratio::new::<ratio>(0.95));
+        test_bed.write_by_name("TURB ENG CORRECTED N1:2", Ratio::new::<ratio>(0.9));
+        test_bed = test_bed.and_stabilize();
+
+        assert!(!test_bed.leak_detected(BleedDuctZone::Wing(2)));
+        assert!(!test_bed.engine_bleed_is_isolated(2));
+
+        test_bed.fail(FailureType::BleedDuctLeak(BleedDuctZone::Wing(2)));
+        test_bed.run_until_leak_detected(BleedDuctZone::Wing(2));
+
+        assert!(test_bed.leak_detected(BleedDuctZone::Wing(2)));
+
+        test_bed = test_bed.and_stabilize();
+
+        assert!(test_bed.engine_bleed_is_isolated(2));
+        assert!(!test_bed.pr_valve_is_open(2));
+        assert!(!test_bed.engine_bleed_is_isolated(1));
+        assert!(test_bed.pr_valve_is_open(1));
+        assert!(!test_bed.apu_bleed_is_isolated());
+    }
+
+    #[test]
+    fn apu_duct_leak_closes_cross_bleed_valves_in_auto() {
+        let mut test_bed = test_bed_with()
+            .cross_bleed_valve_selector_knob(CrossBleedValveSelectorMode::Auto)
+            .set_bleed_air_running()
+            .and_stabilize();
+
+        assert!(test_bed.cross_bleed_valves_are_open());
+
+        test_bed.fail(FailureType::BleedDuctLeak(BleedDuctZone::Apu));
+        test_bed.run_until_leak_detected(BleedDuctZone::Apu);
+
+        assert!(test_bed.leak_detected(BleedDuctZone::Apu));
+
+        test_bed = test_bed.and_stabilize();
+
+        assert!(test_bed.query(|a| a
+            .pneumatic
+            .cross_bleed_valves
+            .iter()
+            .all(|cbv| !cbv.is_open())));
+        assert!(test_bed.apu_bleed_is_isolated());
+    }
+
     #[test]
     fn cross_bleed_valve_manual_overrides_everything() {
         let mut test_bed = test_bed_with()
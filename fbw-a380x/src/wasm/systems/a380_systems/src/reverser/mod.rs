@@ -227,10 +227,14 @@ impl A380Reversers {
 
             reversers: [
                 A380ReverserAssembly::new(
+                    context,
+                    2,
                     Self::REVERSER_2_ETRAC_SUPPLY_POWER_BUS,
                     Self::REVERSER_2_TERTIARY_LOCK_SUPPLY_POWER_BUS,
                 ),
                 A380ReverserAssembly::new(
+                    context,
+                    3,
                     Self::REVERSER_3_ETRAC_SUPPLY_POWER_BUS,
                     Self::REVERSER_3_TERTIARY_LOCK_SUPPLY_POWER_BUS,
                 ),
@@ -1,4 +1,5 @@
 use systems::{
+    engine::EngineVibration,
     fuel::FuelPayload,
     shared::{random_from_range, SurfacesPositions},
     simulation::{
@@ -174,6 +175,7 @@ impl A380StructuralFlex {
         up_down_rudder_aero_torques: (Torque, Torque),
         surfaces_positions: &impl SurfacesPositions,
         fuel_mass: &impl FuelPayload,
+        engines: [&impl EngineVibration; 4],
     ) {
         self.cockpit_rumble.update(
             context,
@@ -196,8 +198,11 @@ impl A380StructuralFlex {
             fuel_mass,
         );
 
-        self.engines_flex_physics
-            .update(context, self.wing_flex.accelerations_at_engines_pylons());
+        self.engines_flex_physics.update(
+            context,
+            self.wing_flex.accelerations_at_engines_pylons(),
+            engines,
+        );
 
         self.surface_vibrations
             .update(context, self.wing_flex.ground_weight_ratio());
@@ -36,9 +36,9 @@ use systems::air_conditioning::{Channel, FdacId, OcsmId, VcmId};
 use systems::failures::FailureType;
 use systems::integrated_modular_avionics::core_processing_input_output_module::CpiomId;
 use systems::shared::{
-    report_diagnostic, AirbusElectricPumpId, AirbusEngineDrivenPumpId, ElectricalBusType,
-    FireDetectionLoopID, FireDetectionZone, GearActuatorId, HydraulicColor, LgciuId,
-    ProximityDetectorId,
+    report_diagnostic, AirbusElectricPumpId, AirbusEngineDrivenPumpId, BleedDuctZone, CargoDoorId,
+    ElectricalBusType, FireDetectionLoopID, FireDetectionZone, GearActuatorId, GearWheel,
+    HydraulicColor, LgciuId, ProximityDetectorId, SteeringActuatorId,
 };
 
 use systems_wasm::{MsfsSimulationBuilder, Variable};
@@ -277,6 +277,30 @@ async fn systems(mut gauge: msfs::Gauge) -> Result<(), Box<dyn Error>> {
             26_018,
             FailureType::FireDetectionLoop(FireDetectionLoopID::B, FireDetectionZone::Mlg),
         ),
+        (27_101, FailureType::ElectroHydrostaticActuator(1)),
+        (27_102, FailureType::ElectroHydrostaticActuator(2)),
+        (27_103, FailureType::ElectroHydrostaticActuator(3)),
+        (27_104, FailureType::ElectroHydrostaticActuator(4)),
+        (27_105, FailureType::ElectroHydrostaticActuator(5)),
+        (27_106, FailureType::ElectroHydrostaticActuator(6)),
+        (27_107, FailureType::ElectroHydrostaticActuator(7)),
+        (27_108, FailureType::ElectroHydrostaticActuator(8)),
+        (27_109, FailureType::ElectroHydrostaticActuator(9)),
+        (27_110, FailureType::ElectroHydrostaticActuator(10)),
+        (27_111, FailureType::ElectroHydrostaticActuator(11)),
+        (27_112, FailureType::ElectroHydrostaticActuator(12)),
+        (27_113, FailureType::ElectroHydrostaticActuator(13)),
+        (27_114, FailureType::ElectroHydrostaticActuator(14)),
+        // THS electric trim motors and trim runaway belong to the A320 mechanical pitch trim actuator
+        (27_200, FailureType::ThsJam),
+        (
+            27_201,
+            FailureType::ThsHydraulicMotor(HydraulicColor::Green),
+        ),
+        (
+            27_202,
+            FailureType::ThsHydraulicMotor(HydraulicColor::Yellow),
+        ),
         (29_000, FailureType::ReservoirLeak(HydraulicColor::Green)),
         (29_001, FailureType::ReservoirLeak(HydraulicColor::Yellow)),
         (29_002, FailureType::ReservoirAirLeak(HydraulicColor::Green)),
@@ -416,6 +440,103 @@ async fn systems(mut gauge: msfs::Gauge) -> Result<(), Box<dyn Error>> {
             32_025,
             FailureType::GearActuatorJammed(GearActuatorId::GearDoorRight),
         ),
+        (32_030, FailureType::GearStrutUnderServiced(GearWheel::NOSE)),
+        (32_031, FailureType::GearStrutUnderServiced(GearWheel::LEFT)),
+        (
+            32_032,
+            FailureType::GearStrutUnderServiced(GearWheel::RIGHT),
+        ),
+        (
+            32_033,
+            FailureType::GearStrutUnderServiced(GearWheel::WINGLEFT),
+        ),
+        (
+            32_034,
+            FailureType::GearStrutUnderServiced(GearWheel::WINGRIGHT),
+        ),
+        (
+            32_200,
+            FailureType::SteeringActuatorJammed(SteeringActuatorId::Nose),
+        ),
+        (
+            32_201,
+            FailureType::SteeringServoValve(SteeringActuatorId::Nose),
+        ),
+        (
+            32_202,
+            FailureType::SteeringShimmyDamper(SteeringActuatorId::Nose),
+        ),
+        (
+            32_203,
+            FailureType::SteeringActuatorJammed(SteeringActuatorId::BodyLeft),
+        ),
+        (
+            32_204,
+            FailureType::SteeringActuatorJammed(SteeringActuatorId::BodyRight),
+        ),
+        (
+            32_205,
+            FailureType::SteeringServoValve(SteeringActuatorId::BodyLeft),
+        ),
+        (
+            32_206,
+            FailureType::SteeringServoValve(SteeringActuatorId::BodyRight),
+        ),
+        (
+            32_207,
+            FailureType::SteeringShimmyDamper(SteeringActuatorId::BodyLeft),
+        ),
+        (
+            32_208,
+            FailureType::SteeringShimmyDamper(SteeringActuatorId::BodyRight),
+        ),
+        (32_210, FailureType::SteeringControlChannel(1)),
+        (32_211, FailureType::SteeringControlChannel(2)),
+        (32_301, FailureType::TyreSlowLeak(1)),
+        (32_302, FailureType::TyreSlowLeak(2)),
+        (32_303, FailureType::TyreSlowLeak(3)),
+        (32_304, FailureType::TyreSlowLeak(4)),
+        (32_305, FailureType::TyreSlowLeak(5)),
+        (32_306, FailureType::TyreSlowLeak(6)),
+        (32_307, FailureType::TyreSlowLeak(7)),
+        (32_308, FailureType::TyreSlowLeak(8)),
+        (32_309, FailureType::TyreSlowLeak(9)),
+        (32_310, FailureType::TyreSlowLeak(10)),
+        (32_311, FailureType::TyreSlowLeak(11)),
+        (32_312, FailureType::TyreSlowLeak(12)),
+        (32_313, FailureType::TyreSlowLeak(13)),
+        (32_314, FailureType::TyreSlowLeak(14)),
+        (32_315, FailureType::TyreSlowLeak(15)),
+        (32_316, FailureType::TyreSlowLeak(16)),
+        (32_317, FailureType::TyreSlowLeak(17)),
+        (32_318, FailureType::TyreSlowLeak(18)),
+        (32_319, FailureType::TyreSlowLeak(19)),
+        (32_320, FailureType::TyreSlowLeak(20)),
+        (32_321, FailureType::TyreSlowLeak(21)),
+        (32_322, FailureType::TyreSlowLeak(22)),
+        (32_351, FailureType::TyreBurst(1)),
+        (32_352, FailureType::TyreBurst(2)),
+        (32_353, FailureType::TyreBurst(3)),
+        (32_354, FailureType::TyreBurst(4)),
+        (32_355, FailureType::TyreBurst(5)),
+        (32_356, FailureType::TyreBurst(6)),
+        (32_357, FailureType::TyreBurst(7)),
+        (32_358, FailureType::TyreBurst(8)),
+        (32_359, FailureType::TyreBurst(9)),
+        (32_360, FailureType::TyreBurst(10)),
+        (32_361, FailureType::TyreBurst(11)),
+        (32_362, FailureType::TyreBurst(12)),
+        (32_363, FailureType::TyreBurst(13)),
+        (32_364, FailureType::TyreBurst(14)),
+        (32_365, FailureType::TyreBurst(15)),
+        (32_366, FailureType::TyreBurst(16)),
+        (32_367, FailureType::TyreBurst(17)),
+        (32_368, FailureType::TyreBurst(18)),
+        (32_369, FailureType::TyreBurst(19)),
+        (32_370, FailureType::TyreBurst(20)),
+        (32_371, FailureType::TyreBurst(21)),
+        (32_372, FailureType::TyreBurst(22)),
+        (32_400, FailureType::TyrePressureIndicatingSystem),
         (34_000, FailureType::RadioAltimeter(1)),
         (34_001, FailureType::RadioAltimeter(2)),
         (34_002, FailureType::RadioAltimeter(3)),
@@ -425,6 +546,193 @@ async fn systems(mut gauge: msfs::Gauge) -> Result<(), Box<dyn Error>> {
         (34_020, FailureType::RadioAntennaDirectCoupling(1)),
         (34_021, FailureType::RadioAntennaDirectCoupling(2)),
         (34_022, FailureType::RadioAntennaDirectCoupling(3)),
+        (36_000, FailureType::BleedHighPressureValveStuckOpen(1)),
+        (36_001, FailureType::BleedHighPressureValveStuckOpen(2)),
+        (36_002, FailureType::BleedHighPressureValveStuckOpen(3)),
+        (36_003, FailureType::BleedHighPressureValveStuckOpen(4)),
+        (36_010, FailureType::BleedHighPressureValveStuckClosed(1)),
+        (36_011, FailureType::BleedHighPressureValveStuckClosed(2)),
+        (36_012, FailureType::BleedHighPressureValveStuckClosed(3)),
+        (36_013, FailureType::BleedHighPressureValveStuckClosed(4)),
+        (
+            36_020,
+            FailureType::BleedPressureRegulatingValveStuckOpen(1),
+        ),
+        (
+            36_021,
+            FailureType::BleedPressureRegulatingValveStuckOpen(2),
+        ),
+        (
+            36_022,
+            FailureType::BleedPressureRegulatingValveStuckOpen(3),
+        ),
+        (
+            36_023,
+            FailureType::BleedPressureRegulatingValveStuckOpen(4),
+        ),
+        (
+            36_030,
+            FailureType::BleedPressureRegulatingValveStuckClosed(1),
+        ),
+        (
+            36_031,
+            FailureType::BleedPressureRegulatingValveStuckClosed(2),
+        ),
+        (
+            36_032,
+            FailureType::BleedPressureRegulatingValveStuckClosed(3),
+        ),
+        (
+            36_033,
+            FailureType::BleedPressureRegulatingValveStuckClosed(4),
+        ),
+        (36_040, FailureType::BleedFanAirValveStuckClosed(1)),
+        (36_041, FailureType::BleedFanAirValveStuckClosed(2)),
+        (36_042, FailureType::BleedFanAirValveStuckClosed(3)),
+        (36_043, FailureType::BleedFanAirValveStuckClosed(4)),
+        (36_050, FailureType::CrossBleedValveJammed(1)),
+        (36_051, FailureType::CrossBleedValveJammed(2)),
+        (36_052, FailureType::CrossBleedValveJammed(3)),
+        (36_060, FailureType::BleedDuctLeak(BleedDuctZone::Pylon(1))),
+        (36_061, FailureType::BleedDuctLeak(BleedDuctZone::Pylon(2))),
+        (36_062, FailureType::BleedDuctLeak(BleedDuctZone::Pylon(3))),
+        (36_063, FailureType::BleedDuctLeak(BleedDuctZone::Pylon(4))),
+        (36_070, FailureType::BleedDuctLeak(BleedDuctZone::Wing(1))),
+        (36_071, FailureType::BleedDuctLeak(BleedDuctZone::Wing(2))),
+        (36_072, FailureType::BleedDuctLeak(BleedDuctZone::Wing(3))),
+        (36_073, FailureType::BleedDuctLeak(BleedDuctZone::Wing(4))),
+        (36_080, FailureType::BleedDuctLeak(BleedDuctZone::Apu)),
+        (
+            36_100,
+            FailureType::BleedLeakDetectionLoop(FireDetectionLoopID::A, BleedDuctZone::Pylon(1)),
+        ),
+        (
+            36_101,
+            FailureType::BleedLeakDetectionLoop(FireDetectionLoopID::B, BleedDuctZone::Pylon(1)),
+        ),
+        (
+            36_102,
+            FailureType::BleedLeakDetectionLoop(FireDetectionLoopID::A, BleedDuctZone::Pylon(2)),
+        ),
+        (
+            36_103,
+            FailureType::BleedLeakDetectionLoop(FireDetectionLoopID::B, BleedDuctZone::Pylon(2)),
+        ),
+        (
+            36_104,
+            FailureType::BleedLeakDetectionLoop(FireDetectionLoopID::A, BleedDuctZone::Pylon(3)),
+        ),
+        (
+            36_105,
+            FailureType::BleedLeakDetectionLoop(FireDetectionLoopID::B, BleedDuctZone::Pylon(3)),
+        ),
+        (
+            36_106,
+            FailureType::BleedLeakDetectionLoop(FireDetectionLoopID::A, BleedDuctZone::Pylon(4)),
+        ),
+        (
+            36_107,
+            FailureType::BleedLeakDetectionLoop(FireDetectionLoopID::B, BleedDuctZone::Pylon(4)),
+        ),
+        (
+            36_110,
+            FailureType::BleedLeakDetectionLoop(FireDetectionLoopID::A, BleedDuctZone::Wing(1)),
+        ),
+        (
+            36_111,
+            FailureType::BleedLeakDetectionLoop(FireDetectionLoopID::B, BleedDuctZone::Wing(1)),
+        ),
+        (
+            36_112,
+            FailureType::BleedLeakDetectionLoop(FireDetectionLoopID::A, BleedDuctZone::Wing(2)),
+        ),
+        (
+            36_113,
+            FailureType::BleedLeakDetectionLoop(FireDetectionLoopID::B, BleedDuctZone::Wing(2)),
+        ),
+        (
+            36_114,
+            FailureType::BleedLeakDetectionLoop(FireDetectionLoopID::A, BleedDuctZone::Wing(3)),
+        ),
+        (
+            36_115,
+            FailureType::BleedLeakDetectionLoop(FireDetectionLoopID::B, BleedDuctZone::Wing(3)),
+        ),
+        (
+            36_116,
+            FailureType::BleedLeakDetectionLoop(FireDetectionLoopID::A, BleedDuctZone::Wing(4)),
+        ),
+        (
+            36_117,
+            FailureType::BleedLeakDetectionLoop(FireDetectionLoopID::B, BleedDuctZone::Wing(4)),
+        ),
+        (
+            36_120,
+            FailureType::BleedLeakDetectionLoop(FireDetectionLoopID::A, BleedDuctZone::Apu),
+        ),
+        (
+            36_121,
+            FailureType::BleedLeakDetectionLoop(FireDetectionLoopID::B, BleedDuctZone::Apu),
+        ),
+        (49_000, FailureType::ApuOverspeed),
+        (49_001, FailureType::ApuEgtOvertemperature),
+        (49_002, FailureType::ApuLowOilPressure),
+        (49_003, FailureType::ApuHighOilTemperature),
+        (49_004, FailureType::ApuAirIntakeFlapStuck),
+        (49_005, FailureType::ApuStartMotor),
+        (49_006, FailureType::ApuElectronicControlBox),
+        (49_007, FailureType::ApuGeneratorBearing),
+        (
+            52_000,
+            FailureType::CargoDoorLatchStuck(CargoDoorId::Forward),
+        ),
+        (52_001, FailureType::CargoDoorLatchStuck(CargoDoorId::Aft)),
+        (
+            52_010,
+            FailureType::CargoDoorHydraulicLock(CargoDoorId::Forward),
+        ),
+        (
+            52_011,
+            FailureType::CargoDoorHydraulicLock(CargoDoorId::Aft),
+        ),
+        (72_000, FailureType::EngineFanDamage(1)),
+        (72_001, FailureType::EngineFanDamage(2)),
+        (72_002, FailureType::EngineFanDamage(3)),
+        (72_003, FailureType::EngineFanDamage(4)),
+        (72_010, FailureType::EngineBirdStrike(1)),
+        (72_011, FailureType::EngineBirdStrike(2)),
+        (72_012, FailureType::EngineBirdStrike(3)),
+        (72_013, FailureType::EngineBirdStrike(4)),
+        (72_020, FailureType::EngineBearingDamage(1)),
+        (72_021, FailureType::EngineBearingDamage(2)),
+        (72_022, FailureType::EngineBearingDamage(3)),
+        (72_023, FailureType::EngineBearingDamage(4)),
+        (78_001, FailureType::ReverserUnlocked(2)),
+        (78_002, FailureType::ReverserUnlocked(3)),
+        (78_011, FailureType::ReverserFailToDeploy(2)),
+        (78_012, FailureType::ReverserFailToDeploy(3)),
+        (78_021, FailureType::ReverserFailToStow(2)),
+        (78_022, FailureType::ReverserFailToStow(3)),
+        (78_031, FailureType::ReverserSleeveJammed(2)),
+        (78_032, FailureType::ReverserSleeveJammed(3)),
+        (78_041, FailureType::ReverserActuationSupplyLoss(2)),
+        (78_042, FailureType::ReverserActuationSupplyLoss(3)),
+        (79_000, FailureType::EngineOilFilterClog(1)),
+        (79_001, FailureType::EngineOilFilterClog(2)),
+        (79_002, FailureType::EngineOilFilterClog(3)),
+        (79_003, FailureType::EngineOilFilterClog(4)),
+        (79_010, FailureType::EngineOilLowPressure(1)),
+        (79_011, FailureType::EngineOilLowPressure(2)),
+        (79_012, FailureType::EngineOilLowPressure(3)),
+        (79_013, FailureType::EngineOilLowPressure(4)),
+        (79_020, FailureType::EngineOilHighTemperature(1)),
+        (79_021, FailureType::EngineOilHighTemperature(2)),
+        (79_022, FailureType::EngineOilHighTemperature(3)),
+        (79_023, FailureType::EngineOilHighTemperature(4)),
+        (80_000, FailureType::EngineStarterValveStuckOpen(1)),
+        (80_001, FailureType::EngineStarterValveStuckOpen(2)),
+        (80_002, FailureType::EngineStarterValveStuckOpen(3)),
+        (80_003, FailureType::EngineStarterValveStuckOpen(4)),
     ])
     .provides_aircraft_variable("ACCELERATION BODY X", "feet per second squared", 0)?
     .provides_aircraft_variable("ACCELERATION BODY Y", "feet per second squared", 0)?
@@ -499,6 +807,7 @@ async fn systems(mut gauge: msfs::Gauge) -> Result<(), Box<dyn Error>> {
     .provides_aircraft_variable("PUSHBACK ANGLE", "Radians", 0)?
     .provides_aircraft_variable("SEA LEVEL PRESSURE", "Millibars", 0)?
     .provides_aircraft_variable("SIM ON GROUND", "Bool", 0)?
+    .provides_aircraft_variable("SURFACE CONDITION", "Enum", 0)?
     .provides_aircraft_variable("SURFACE TYPE", "Enum", 0)?
     .provides_aircraft_variable("TOTAL AIR TEMPERATURE", "celsius", 0)?
     .provides_aircraft_variable("TOTAL WEIGHT", "Pounds", 0)?
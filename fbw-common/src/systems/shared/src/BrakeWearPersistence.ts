@@ -0,0 +1,59 @@
+// Copyright (c) 2026 FlyByWire Simulations
+// SPDX-License-Identifier: GPL-3.0
+
+import { Instrument, SimVarValueType } from '@microsoft/msfs-sdk';
+import { NXDataStore } from '@flybywiresim/fbw-sdk';
+
+/**
+ * Keeps the brake heat sink wear computed by the systems wasm across flights.
+ * The wear is restored into the brake wear local vars on load, the systems read it back on their next frame and keep
+ * accumulating from there. It is periodically stored back to the data store.
+ */
+export class BrakeWearPersistence implements Instrument {
+  private static readonly STORAGE_KEY = 'BRAKE_WEAR';
+
+  private static readonly SAVE_INTERVAL_MS = 60_000;
+
+  private lastSaveTime = 0;
+
+  /**
+   * @param numberOfBrakes Number of braked wheels, matching the {index} range of the A32NX_BRAKE_WEAR_{index} vars
+   */
+  constructor(private readonly numberOfBrakes: number) {}
+
+  public init(): void {
+    const stored = NXDataStore.getLegacy(BrakeWearPersistence.STORAGE_KEY, '').split(',');
+
+    if (stored.length === this.numberOfBrakes) {
+      stored.forEach((value, i) => {
+        const wear = parseFloat(value);
+
+        if (Number.isFinite(wear)) {
+          SimVar.SetSimVarValue(this.wearVar(i + 1), SimVarValueType.Percent, wear);
+        }
+      });
+    }
+
+    this.lastSaveTime = Date.now();
+  }
+
+  public onUpdate(): void {
+    const now = Date.now();
+
+    if (now - this.lastSaveTime < BrakeWearPersistence.SAVE_INTERVAL_MS) {
+      return;
+    }
+    this.lastSaveTime = now;
+
+    const wear: string[] = [];
+    for (let i = 1; i <= this.numberOfBrakes; i++) {
+      wear.push((SimVar.GetSimVarValue(this.wearVar(i), SimVarValueType.Percent) as number).toFixed(3));
+    }
+
+    NXDataStore.setLegacy(BrakeWearPersistence.STORAGE_KEY, wear.join(','));
+  }
+
+  private wearVar(index: number): string {
+    return `L:A32NX_BRAKE_WEAR_${index}`;
+  }
+}
@@ -1,6 +1,7 @@
 // Copyright (c) 2023 FlyByWire Simulations
 // SPDX-License-Identifier: GPL-3.0
 
+#include <algorithm>
 #include <iostream>
 
 #include "AircraftVariable.h"
@@ -53,11 +54,13 @@ bool Pushback::initialize() {
   // Pushback Base Data
   //  will be updated every visual frame
   DataDefinitionVector pushbackBaseDataDef = {
-      {"L:A32NX_PUSHBACK_SYSTEM_ENABLED", 0, UNITS.Bool   },
-      {"L:A32NX_PARK_BRAKE_LEVER_POS",    0, UNITS.Bool   },
-      {"PUSHBACK ATTACHED",               0, UNITS.Bool   },
-      {"PLANE HEADING DEGREES TRUE",      0, UNITS.degrees},
-      {"RELATIVE WIND VELOCITY BODY Z",   0, UNITS.FeetSec}
+      {"L:A32NX_PUSHBACK_SYSTEM_ENABLED",          0, UNITS.Bool   },
+      {"L:A32NX_PARK_BRAKE_LEVER_POS",             0, UNITS.Bool   },
+      {"PUSHBACK ATTACHED",                        0, UNITS.Bool   },
+      {"PLANE HEADING DEGREES TRUE",               0, UNITS.degrees},
+      {"RELATIVE WIND VELOCITY BODY Z",            0, UNITS.FeetSec},
+      {"L:A32NX_PUSHBACK_TOWBAR_SHEAR_PIN_BROKEN", 0, UNITS.Bool   },
+      {"L:A32NX_PUSHBACK_TUG_MAX_SPEED",           0, UNITS.Number }
   };
   pushbackBaseInfoPtr = dataManager->make_datadefinition_var<PushbackBaseInfo>("PUSHBACK BASE DATA", pushbackBaseDataDef);
   if (pushbackBaseInfoPtr == nullptr) {
@@ -130,6 +133,11 @@ bool Pushback::update(sGaugeDrawData* pData) {
     return true;
   }
 
+  // A broken towbar no longer transmits any movement to the aircraft
+  if (static_cast<bool>(pushbackBaseInfoPtr->data().towbarShearPinBroken)) {
+    return true;
+  }
+
   //  profiler.start();
 
   const FLOAT64 timeStamp   = msfsHandler.getTimeStamp();
@@ -149,7 +157,12 @@ bool Pushback::update(sGaugeDrawData* pData) {
   // the inertia speed (current actual speed) is calculated in ft/sec.
   const double speedFactor =
       parkingBrakeEngaged ? (aircraftSpeedFactor->get() / aircraftParkingBrakeFactor->get()) : aircraftSpeedFactor->get();
-  const FLOAT64 tugCmdSpd    = tugCommandedSpeedFactor->get() * speedFactor;
+  FLOAT64 tugCmdSpd = tugCommandedSpeedFactor->get() * speedFactor;
+  // The tug can't go faster than its type allows (knots to ft/sec)
+  const FLOAT64 tugMaxSpeed = pushbackBaseInfoPtr->data().tugMaxSpeedKnots * 1.68781;
+  if (tugMaxSpeed > 0.0) {
+    tugCmdSpd = std::clamp(tugCmdSpd, -tugMaxSpeed, tugMaxSpeed);
+  }
   const FLOAT64 inertiaSpeed = speedDampener.updateTargetValue(tugCmdSpd);
 
   // Based on an aircraft-specific turn speed factor and the user input (0.0-1.0),
@@ -29,6 +29,10 @@ using DataDefinitionVector = std::vector<DataDefinition>;
  *
  * - Pushback Attached (simvar)
  * - SIM ON GROUND (simvar)
+ *
+ * The tug stops moving the aircraft when the systems report a broken towbar shear pin
+ * (A32NX_PUSHBACK_TOWBAR_SHEAR_PIN_BROKEN) and is limited to the speed of its type
+ * (A32NX_PUSHBACK_TUG_MAX_SPEED).
  */
 class Pushback : public Module {
  private:
@@ -52,6 +56,8 @@ class Pushback : public Module {
     FLOAT64 pushbackAttached;
     FLOAT64 aircraftHeading;
     FLOAT64 windVelBodyZ;
+    FLOAT64 towbarShearPinBroken;
+    FLOAT64 tugMaxSpeedKnots;
   };
   DataDefinitionVariablePtr<PushbackBaseInfo> pushbackBaseInfoPtr;
 
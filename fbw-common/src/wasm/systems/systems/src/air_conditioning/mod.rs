@@ -34,6 +34,7 @@ use uom::si::{
 pub mod acs_controller;
 pub mod cabin_air;
 pub mod cabin_pressure_controller;
+pub mod preconditioned_air_unit;
 pub mod pressure_valve;
 
 pub trait DuctTemperature {
@@ -0,0 +1,226 @@
+use crate::{
+    shared::low_pass_filter::LowPassFilter,
+    simulation::{
+        InitContext, Read, SimulationElement, SimulatorReader, UpdateContext, VariableIdentifier,
+    },
+};
+
+use super::{Air, OutletAir};
+
+use std::time::Duration;
+use uom::si::{f64::*, pressure::psi, thermodynamic_temperature::degree_celsius};
+
+/// Low pressure pre-conditioned air cart connected to the LP ground connection.
+/// The air bypasses the packs and is fed directly into the mixer unit.
+pub struct PreconditionedAirUnit {
+    turned_on_id: VariableIdentifier,
+    turned_on: bool,
+
+    rated_flow: MassRate,
+    discharge_temperature: LowPassFilter<f64>, // Degree Celsius
+    outlet_air: Air,
+}
+
+impl PreconditionedAirUnit {
+    // Pressure difference between the cabin and the outside at which the blower stops delivering air
+    const SHUTOFF_PRESSURE_DIFFERENCE_PSI: f64 = 1.;
+    const DISCHARGE_TEMPERATURE_TIME_CONSTANT: Duration = Duration::from_secs(30);
+    const COOLING_DISCHARGE_TEMPERATURE_C: f64 = 4.;
+    const HEATING_DISCHARGE_TEMPERATURE_C: f64 = 45.;
+    const COOLING_AMBIENT_TEMPERATURE_THRESHOLD_C: f64 = 20.;
+    const HEATING_AMBIENT_TEMPERATURE_THRESHOLD_C: f64 = 10.;
+
+    pub fn new(context: &mut InitContext, rated_flow: MassRate) -> Self {
+        Self {
+            turned_on_id: context.get_identifier("PCA_TURNED_ON".to_owned()),
+            turned_on: false,
+
+            rated_flow,
+            discharge_temperature: LowPassFilter::new_with_init_value(
+                Self::DISCHARGE_TEMPERATURE_TIME_CONSTANT,
+                15.,
+            ),
+            outlet_air: Air::new(),
+        }
+    }
+
+    /// The blower delivers less air as the pressure downstream of the LP ground connection rises
+    /// above ambient, and the LP duct pressure rises with the delivered flow.
+    pub fn update(&mut self, context: &UpdateContext, cabin_pressure: Pressure) {
+        let ambient_temperature = context.ambient_temperature().get::<degree_celsius>();
+
+        if self.turned_on {
+            let back_pressure = (cabin_pressure - context.ambient_pressure()).get::<psi>();
+            let flow_ratio_squared =
+                (1. - back_pressure / Self::SHUTOFF_PRESSURE_DIFFERENCE_PSI).clamp(0., 1.);
+
+            self.discharge_temperature.update(
+                context.delta(),
+                Self::target_discharge_temperature(ambient_temperature),
+            );
+            self.outlet_air
+                .set_flow_rate(self.rated_flow * flow_ratio_squared.sqrt());
+            self.outlet_air.set_pressure(
+                cabin_pressure
+                    + Pressure::new::<psi>(
+                        Self::SHUTOFF_PRESSURE_DIFFERENCE_PSI * flow_ratio_squared / 2.,
+                    ),
+            );
+        } else {
+            self.discharge_temperature.reset(ambient_temperature);
+            self.outlet_air.set_flow_rate(MassRate::default());
+            self.outlet_air.set_pressure(cabin_pressure);
+        }
+
+        self.outlet_air
+            .set_temperature(ThermodynamicTemperature::new::<degree_celsius>(
+                self.discharge_temperature.output(),
+            ));
+    }
+
+    fn target_discharge_temperature(ambient_temperature: f64) -> f64 {
+        if ambient_temperature > Self::COOLING_AMBIENT_TEMPERATURE_THRESHOLD_C {
+            Self::COOLING_DISCHARGE_TEMPERATURE_C
+        } else if ambient_temperature < Self::HEATING_AMBIENT_TEMPERATURE_THRESHOLD_C {
+            Self::HEATING_DISCHARGE_TEMPERATURE_C
+        } else {
+            ambient_temperature
+        }
+    }
+
+    pub fn is_supplying_air(&self) -> bool {
+        self.outlet_air.flow_rate() > MassRate::default()
+    }
+}
+
+impl OutletAir for PreconditionedAirUnit {
+    fn outlet_air(&self) -> Air {
+        self.outlet_air
+    }
+}
+
+impl SimulationElement for PreconditionedAirUnit {
+    fn read(&mut self, reader: &mut SimulatorReader) {
+        self.turned_on = reader.read(&self.turned_on_id);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::simulation::test::{
+        ElementCtorFn, SimulationTestBed, TestAircraft, TestBed, WriteByName,
+    };
+    use more_asserts::*;
+    use ntest::assert_about_eq;
+    use uom::si::mass_rate::kilogram_per_second;
+
+    const RATED_FLOW_KG_PER_S: f64 = 1.2;
+
+    fn test_bed_with_cabin_differential_pressure(
+        differential_pressure: Pressure,
+    ) -> SimulationTestBed<TestAircraft<PreconditionedAirUnit>> {
+        SimulationTestBed::from(ElementCtorFn(|context| {
+            PreconditionedAirUnit::new(
+                context,
+                MassRate::new::<kilogram_per_second>(RATED_FLOW_KG_PER_S),
+            )
+        }))
+        .with_update_after_power_distribution(move |pca, context| {
+            pca.update(context, context.ambient_pressure() + differential_pressure)
+        })
+    }
+
+    fn outlet_air(test_bed: &SimulationTestBed<TestAircraft<PreconditionedAirUnit>>) -> Air {
+        test_bed.query_element(|pca| pca.outlet_air())
+    }
+
+    #[test]
+    fn turned_off_unit_does_not_supply_air() {
+        let mut test_bed = test_bed_with_cabin_differential_pressure(Pressure::default());
+        test_bed.run_with_delta(Duration::from_secs(1));
+
+        assert!(!test_bed.query_element(|pca| pca.is_supplying_air()));
+    }
+
+    #[test]
+    fn turned_on_unit_supplies_rated_flow_to_unpressurized_cabin() {
+        let mut test_bed = test_bed_with_cabin_differential_pressure(Pressure::default());
+        test_bed.write_by_name("PCA_TURNED_ON", true);
+        test_bed.run_with_delta(Duration::from_secs(1));
+
+        assert_about_eq!(
+            outlet_air(&test_bed)
+                .flow_rate()
+                .get::<kilogram_per_second>(),
+            RATED_FLOW_KG_PER_S
+        );
+    }
+
+    #[test]
+    fn duct_pressure_rises_with_delivered_flow() {
+        let mut turned_off = test_bed_with_cabin_differential_pressure(Pressure::default());
+        turned_off.run_with_delta(Duration::from_secs(1));
+
+        let mut turned_on = test_bed_with_cabin_differential_pressure(Pressure::default());
+        turned_on.write_by_name("PCA_TURNED_ON", true);
+        turned_on.run_with_delta(Duration::from_secs(1));
+
+        assert_gt!(
+            outlet_air(&turned_on).pressure(),
+            outlet_air(&turned_off).pressure()
+        );
+    }
+
+    #[test]
+    fn flow_reduces_with_cabin_back_pressure() {
+        let mut test_bed = test_bed_with_cabin_differential_pressure(Pressure::new::<psi>(0.5));
+        test_bed.write_by_name("PCA_TURNED_ON", true);
+        test_bed.run_with_delta(Duration::from_secs(1));
+
+        assert_lt!(
+            outlet_air(&test_bed)
+                .flow_rate()
+                .get::<kilogram_per_second>(),
+            RATED_FLOW_KG_PER_S
+        );
+        assert!(test_bed.query_element(|pca| pca.is_supplying_air()));
+    }
+
+    #[test]
+    fn no_flow_above_shutoff_pressure() {
+        let mut test_bed = test_bed_with_cabin_differential_pressure(Pressure::new::<psi>(2.));
+        test_bed.write_by_name("PCA_TURNED_ON", true);
+        test_bed.run_with_delta(Duration::from_secs(1));
+
+        assert!(!test_bed.query_element(|pca| pca.is_supplying_air()));
+    }
+
+    #[test]
+    fn supplies_cold_air_on_hot_day() {
+        let mut test_bed = test_bed_with_cabin_differential_pressure(Pressure::default());
+        test_bed.set_ambient_temperature(ThermodynamicTemperature::new::<degree_celsius>(35.));
+        test_bed.write_by_name("PCA_TURNED_ON", true);
+        test_bed.run_iterations_with_delta(300, Duration::from_secs(1));
+
+        assert_about_eq!(
+            outlet_air(&test_bed).temperature().get::<degree_celsius>(),
+            PreconditionedAirUnit::COOLING_DISCHARGE_TEMPERATURE_C,
+            0.1
+        );
+    }
+
+    #[test]
+    fn supplies_warm_air_on_cold_day() {
+        let mut test_bed = test_bed_with_cabin_differential_pressure(Pressure::default());
+        test_bed.set_ambient_temperature(ThermodynamicTemperature::new::<degree_celsius>(-10.));
+        test_bed.write_by_name("PCA_TURNED_ON", true);
+        test_bed.run_iterations_with_delta(300, Duration::from_secs(1));
+
+        assert_about_eq!(
+            outlet_air(&test_bed).temperature().get::<degree_celsius>(),
+            PreconditionedAirUnit::HEATING_DISCHARGE_TEMPERATURE_C,
+            0.1
+        );
+    }
+}
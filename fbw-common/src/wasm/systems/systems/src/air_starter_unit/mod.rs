@@ -1,38 +1,106 @@
 use crate::{
     pneumatic::TargetPressureTemperatureSignal,
-    shared::ControllerSignal,
-    simulation::{Read, SimulationElement, SimulatorReader},
+    shared::{low_pass_filter::LowPassFilter, ControllerSignal},
+    simulation::{Read, Reader, SimulationElement, SimulatorReader, UpdateContext, Write, Writer},
+};
+use std::time::Duration;
+use uom::si::{
+    f64::*, mass_rate::kilogram_per_second, pressure::psi, ratio::ratio,
+    thermodynamic_temperature::degree_celsius,
 };
-use uom::si::thermodynamic_temperature::degree_celsius;
-use uom::si::{f64::*, pressure::psi};
 
 use crate::simulation::{InitContext, VariableIdentifier};
 
+/// The high pressure ground cart connected to the HP ground connection.
+/// Each type can only deliver a limited mass flow, above which its delivery pressure collapses.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum AirStarterUnitType {
+    Standard,
+    Low,
+    High,
+}
+try_read_write_enum!(AirStarterUnitType);
+impl TryFrom<f64> for AirStarterUnitType {
+    type Error = u8;
+
+    fn try_from(value: f64) -> Result<Self, Self::Error> {
+        match value as u8 {
+            0 => Ok(AirStarterUnitType::Standard),
+            1 => Ok(AirStarterUnitType::Low),
+            2 => Ok(AirStarterUnitType::High),
+            i => Err(i),
+        }
+    }
+}
+impl AirStarterUnitType {
+    fn nominal_pressure(&self) -> Pressure {
+        Pressure::new::<psi>(match self {
+            AirStarterUnitType::Standard => 50.,
+            AirStarterUnitType::Low => 40.,
+            AirStarterUnitType::High => 55.,
+        })
+    }
+
+    fn maximum_flow(&self) -> MassRate {
+        MassRate::new::<kilogram_per_second>(match self {
+            AirStarterUnitType::Standard => 2.5,
+            AirStarterUnitType::Low => 1.,
+            AirStarterUnitType::High => 4.,
+        })
+    }
+}
+
 pub struct AirStarterUnit {
     bleed_air_pressure: Pressure,
     bleed_air_temperature: ThermodynamicTemperature,
     turned_on_id: VariableIdentifier,
     turned_on: bool,
+    unit_type_id: VariableIdentifier,
+    unit_type: AirStarterUnitType,
+    delivery_pressure: LowPassFilter<Pressure>,
 }
 impl AirStarterUnit {
+    const DELIVERY_PRESSURE_TIME_CONSTANT: Duration = Duration::from_secs(2);
+    // Fraction of the nominal pressure lost when delivering the maximum flow
+    const PRESSURE_DROP_AT_MAXIMUM_FLOW: f64 = 0.2;
+
     pub fn new(context: &mut InitContext) -> AirStarterUnit {
         AirStarterUnit {
             bleed_air_pressure: Pressure::new::<psi>(0.1),
             bleed_air_temperature: ThermodynamicTemperature::new::<degree_celsius>(15.),
             turned_on_id: context.get_identifier("ASU_TURNED_ON".to_owned()),
             turned_on: false,
+            unit_type_id: context.get_identifier("ASU_TYPE".to_owned()),
+            unit_type: AirStarterUnitType::Standard,
+            delivery_pressure: LowPassFilter::new_with_init_value(
+                Self::DELIVERY_PRESSURE_TIME_CONSTANT,
+                Pressure::new::<psi>(0.1),
+            ),
         }
     }
 
-    pub fn update(&mut self) {
+    /// Updates the delivery pressure based on the mass flow the aircraft drew from the unit.
+    pub fn update(&mut self, context: &UpdateContext, delivered_flow: MassRate) {
         if self.turned_on {
-            self.bleed_air_pressure = Pressure::new::<psi>(50.0);
+            self.delivery_pressure
+                .update(context.delta(), self.pressure_for_flow(delivered_flow));
+            self.bleed_air_pressure = self.delivery_pressure.output();
             self.bleed_air_temperature = ThermodynamicTemperature::new::<degree_celsius>(165.);
         } else {
+            self.delivery_pressure.reset(Pressure::new::<psi>(0.1));
             self.bleed_air_pressure = Pressure::new::<psi>(0.1);
             self.bleed_air_temperature = ThermodynamicTemperature::new::<degree_celsius>(15.);
         }
     }
+
+    fn pressure_for_flow(&self, delivered_flow: MassRate) -> Pressure {
+        let flow_ratio = (delivered_flow / self.unit_type.maximum_flow())
+            .get::<ratio>()
+            .max(0.);
+
+        self.unit_type.nominal_pressure()
+            * (1. - Self::PRESSURE_DROP_AT_MAXIMUM_FLOW * flow_ratio.powi(4)).max(0.)
+    }
 }
 impl ControllerSignal<TargetPressureTemperatureSignal> for AirStarterUnit {
     fn signal(&self) -> Option<TargetPressureTemperatureSignal> {
@@ -44,6 +112,76 @@ impl ControllerSignal<TargetPressureTemperatureSignal> for AirStarterUnit {
 }
 impl SimulationElement for AirStarterUnit {
     fn read(&mut self, reader: &mut SimulatorReader) {
-        self.turned_on = reader.read(&self.turned_on_id)
+        self.turned_on = reader.read(&self.turned_on_id);
+        self.unit_type = reader.read_discrete_or_fallback(
+            &self.unit_type_id,
+            "AirStarterUnitType",
+            AirStarterUnitType::Standard,
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::simulation::test::{
+        ElementCtorFn, SimulationTestBed, TestAircraft, TestBed, WriteByName,
+    };
+    use more_asserts::*;
+    use ntest::assert_about_eq;
+
+    fn test_bed_delivering(flow: MassRate) -> SimulationTestBed<TestAircraft<AirStarterUnit>> {
+        SimulationTestBed::from(ElementCtorFn(AirStarterUnit::new))
+            .with_update_after_power_distribution(move |asu, context| asu.update(context, flow))
+    }
+
+    fn delivery_pressure(test_bed: &SimulationTestBed<TestAircraft<AirStarterUnit>>) -> Pressure {
+        test_bed.query_element(|asu| asu.signal().unwrap().target_pressure())
+    }
+
+    #[test]
+    fn turned_off_unit_provides_no_pressure() {
+        let mut test_bed = test_bed_delivering(MassRate::default());
+        test_bed.run_with_delta(Duration::from_secs(10));
+
+        assert_about_eq!(delivery_pressure(&test_bed).get::<psi>(), 0.1);
+    }
+
+    #[test]
+    fn turned_on_unit_provides_nominal_pressure_without_flow() {
+        let mut test_bed = test_bed_delivering(MassRate::default());
+        test_bed.write_by_name("ASU_TURNED_ON", true);
+        test_bed.run_iterations_with_delta(300, Duration::from_millis(100));
+
+        assert_about_eq!(delivery_pressure(&test_bed).get::<psi>(), 50., 0.1);
+    }
+
+    #[test]
+    fn delivery_pressure_drops_at_maximum_flow() {
+        let mut test_bed = test_bed_delivering(MassRate::new::<kilogram_per_second>(2.5));
+        test_bed.write_by_name("ASU_TURNED_ON", true);
+        test_bed.run_iterations_with_delta(300, Duration::from_millis(100));
+
+        assert_about_eq!(delivery_pressure(&test_bed).get::<psi>(), 40., 0.1);
+    }
+
+    #[test]
+    fn low_capacity_unit_cannot_sustain_high_flow() {
+        let mut test_bed = test_bed_delivering(MassRate::new::<kilogram_per_second>(1.5));
+        test_bed.write_by_name("ASU_TURNED_ON", true);
+        test_bed.write_by_name("ASU_TYPE", AirStarterUnitType::Low);
+        test_bed.run_iterations_with_delta(300, Duration::from_millis(100));
+
+        assert_lt!(delivery_pressure(&test_bed), Pressure::new::<psi>(5.));
+    }
+
+    #[test]
+    fn high_capacity_unit_sustains_higher_flow() {
+        let mut test_bed = test_bed_delivering(MassRate::new::<kilogram_per_second>(2.5));
+        test_bed.write_by_name("ASU_TURNED_ON", true);
+        test_bed.write_by_name("ASU_TYPE", AirStarterUnitType::High);
+        test_bed.run_iterations_with_delta(300, Duration::from_millis(100));
+
+        assert_gt!(delivery_pressure(&test_bed), Pressure::new::<psi>(50.));
     }
 }
@@ -3,25 +3,29 @@ use std::time::Duration;
 
 use uom::si::{
     f64::*, mass_concentration::kilogram_per_liter, power::watt, pressure::bar, pressure::psi,
-    ratio::percent, thermodynamic_temperature::degree_celsius, volume_rate::gallon_per_minute,
+    ratio::percent, ratio::ratio, thermodynamic_temperature::degree_celsius,
+    volume_rate::gallon_per_minute,
 };
 
 use crate::shared::{EngineCorrectedN1, LgciuWeightOnWheels};
 use crate::simulation::{InitContext, SimulatorReader, VariableIdentifier};
 use crate::{
+    failures::{Failure, FailureType},
     pneumatic::PneumaticValveSignal,
     shared::{
         arinc429::SignStatus, ApuBleedAirValveSignal, ApuMaster, ApuStart, ConsumePower,
         ContactorSignal, ControllerSignal, ElectricalBusType, ElectricalBuses, PneumaticValve,
     },
-    simulation::{Read, SimulationElement, SimulatorWriter, UpdateContext, Write},
+    simulation::{
+        Read, SimulationElement, SimulationElementVisitor, SimulatorWriter, UpdateContext, Write,
+    },
 };
 
 use super::ApuConstants;
 use super::{
-    air_intake_flap::AirIntakeFlapSignal, AirIntakeFlap, ApuStartMotor,
-    AuxiliaryPowerUnitFireOverheadPanel, AuxiliaryPowerUnitOverheadPanel, FuelPressureSwitch,
-    Turbine, TurbineSignal, TurbineState,
+    air_intake_flap::AirIntakeFlapSignal, oil_system::ApuOilSystem, AirIntakeFlap, ApuLoad,
+    ApuStartMotor, AuxiliaryPowerUnitFireOverheadPanel, AuxiliaryPowerUnitOverheadPanel,
+    FuelPressureSwitch, Turbine, TurbineSignal, TurbineState,
 };
 
 pub(super) struct ElectronicControlBox<C: ApuConstants> {
@@ -39,6 +43,8 @@ pub(super) struct ElectronicControlBox<C: ApuConstants> {
     apu_is_emergency_shutdown_id: VariableIdentifier,
     apu_bleed_air_pressure_id: VariableIdentifier,
     apu_fuel_line_flow_id: VariableIdentifier,
+    apu_shutdown_cause_id: VariableIdentifier,
+    apu_last_shutdown_cause_id: VariableIdentifier,
 
     powered_by: ElectricalBusType,
     is_powered: bool,
@@ -52,12 +58,22 @@ pub(super) struct ElectronicControlBox<C: ApuConstants> {
     bleed_is_on: bool,
     bleed_air_valve_last_open_time_ago: Duration,
     bleed_air_pressure: Pressure,
+    /// Position of the load compressor inlet guide vanes, which determine how much of the
+    /// bleed air demand the load compressor delivers.
+    inlet_guide_vane_position: Ratio,
+    load: ApuLoad,
     fault: Option<ApuFault>,
+    last_shutdown_cause: Option<ApuFault>,
+    air_intake_flap_opening_for: Duration,
+    start_motor_engaged_without_rotation_for: Duration,
     fuel_flow: VolumeRate,
     fuel_used: Mass,
     air_intake_flap_open_amount: Ratio,
     egt: ThermodynamicTemperature,
     egt_warning_temperature: ThermodynamicTemperature,
+    oil_pressure: Pressure,
+    oil_temperature: ThermodynamicTemperature,
+    generator_oil_outlet_temperature: ThermodynamicTemperature,
     n_above_95_duration: Duration,
     fire_button_is_released: bool,
     engines_on: bool,
@@ -69,11 +85,29 @@ pub(super) struct ElectronicControlBox<C: ApuConstants> {
     /// In the context of the ecb this means that the APU cooldown is skipped.
     aircraft_preset_quick_mode: bool,
 
+    start_motor_failure: Failure,
+    ecb_failure: Failure,
+
     constants: PhantomData<C>,
 }
 impl<C: ApuConstants> ElectronicControlBox<C> {
     const START_MOTOR_POWERED_UNTIL_N: f64 = 55.;
     const JET_A_1_DENSITY: f64 = 0.804; // Kilograms per Liter
+    const OVERSPEED_N: f64 = 107.;
+    const LOW_OIL_PRESSURE_MONITORING_ABOVE_N: f64 = 95.;
+    const LOW_OIL_PRESSURE_PSI: f64 = 35.;
+    const HIGH_OIL_TEMPERATURE: f64 = 140.; // Deg C
+    const HIGH_GENERATOR_OIL_OUTLET_TEMPERATURE: f64 = 185.; // Deg C
+    const AIR_INTAKE_FLAP_OPENING_TIME_LIMIT: Duration = Duration::from_secs(30);
+    const NO_ACCELERATION_TIME_LIMIT: Duration = Duration::from_secs(5);
+    // The inlet guide vanes start closing when the EGT comes within this margin
+    // of the caution temperature and reach their minimum opening at the caution temperature.
+    const INLET_GUIDE_VANE_REGULATION_EGT_MARGIN: f64 = 60.; // Deg C
+    const INLET_GUIDE_VANE_MINIMUM_OPENING: f64 = 0.2;
+    const INLET_GUIDE_VANE_TRAVEL_PER_SECOND: f64 = 0.25;
+    // Increase of the fuel flow at the rated bleed air flow and generator output respectively.
+    const BLEED_AIR_LOAD_FUEL_FLOW_FACTOR: f64 = 0.45;
+    const ELECTRICAL_LOAD_FUEL_FLOW_FACTOR: f64 = 0.2;
 
     pub fn new(context: &mut InitContext, powered_by: ElectricalBusType) -> Self {
         ElectronicControlBox {
@@ -94,6 +128,9 @@ impl<C: ApuConstants> ElectronicControlBox<C> {
             apu_bleed_air_pressure_id: context.get_identifier("APU_BLEED_AIR_PRESSURE".to_owned()),
             apu_fuel_line_flow_id: context
                 .get_identifier(format!("FUELSYSTEM LINE FUEL FLOW:{}", C::FUEL_LINE_ID)),
+            apu_shutdown_cause_id: context.get_identifier("APU_SHUTDOWN_CAUSE".to_owned()),
+            apu_last_shutdown_cause_id: context
+                .get_identifier("APU_LAST_SHUTDOWN_CAUSE".to_owned()),
 
             powered_by,
             is_powered: false,
@@ -107,7 +144,12 @@ impl<C: ApuConstants> ElectronicControlBox<C> {
             bleed_is_on: false,
             bleed_air_valve_last_open_time_ago: Duration::from_secs(1000),
             bleed_air_pressure: Pressure::new::<psi>(0.),
+            inlet_guide_vane_position: Ratio::new::<ratio>(1.),
+            load: ApuLoad::default(),
             fault: None,
+            last_shutdown_cause: None,
+            air_intake_flap_opening_for: Duration::ZERO,
+            start_motor_engaged_without_rotation_for: Duration::ZERO,
             fuel_flow: VolumeRate::default(),
             fuel_used: Mass::default(),
             air_intake_flap_open_amount: Ratio::new::<percent>(0.),
@@ -115,6 +157,9 @@ impl<C: ApuConstants> ElectronicControlBox<C> {
             egt_warning_temperature: ThermodynamicTemperature::new::<degree_celsius>(
                 C::RUNNING_WARNING_EGT,
             ),
+            oil_pressure: Pressure::default(),
+            oil_temperature: ThermodynamicTemperature::new::<degree_celsius>(15.),
+            generator_oil_outlet_temperature: ThermodynamicTemperature::new::<degree_celsius>(15.),
             n_above_95_duration: Duration::from_secs(0),
             fire_button_is_released: false,
             engines_on: false,
@@ -122,6 +167,9 @@ impl<C: ApuConstants> ElectronicControlBox<C> {
             inlet_pressure: Pressure::new::<bar>(0.94),
             aircraft_preset_quick_mode: false,
 
+            start_motor_failure: Failure::new(FailureType::ApuStartMotor),
+            ecb_failure: Failure::new(FailureType::ApuElectronicControlBox),
+
             constants: PhantomData,
         }
     }
@@ -148,13 +196,13 @@ impl<C: ApuConstants> ElectronicControlBox<C> {
         self.bleed_is_on = apu_bleed_is_on;
         self.fire_button_is_released = fire_overhead.fire_button_is_released();
         if fire_overhead.fire_button_is_released() {
-            self.fault = Some(ApuFault::ApuFire);
+            self.set_fault(ApuFault::ApuFire);
         }
     }
 
     pub fn update_apu_fire(&mut self, should_emergency_shut_down: bool) {
         if should_emergency_shut_down {
-            self.fault = Some(ApuFault::ApuFire)
+            self.set_fault(ApuFault::ApuFire)
         }
     }
 
@@ -175,10 +223,16 @@ impl<C: ApuConstants> ElectronicControlBox<C> {
             Some(ContactorSignal::Close)
         ) && !self.start_motor_is_powered
         {
-            self.fault = Some(ApuFault::DcPowerLoss);
+            self.set_fault(ApuFault::DcPowerLoss);
         }
     }
 
+    pub fn update_oil_system_state(&mut self, oil_system: &ApuOilSystem) {
+        self.oil_pressure = oil_system.pressure();
+        self.oil_temperature = oil_system.temperature();
+        self.generator_oil_outlet_temperature = oil_system.generator_outlet_temperature();
+    }
+
     pub fn update(&mut self, context: &UpdateContext, turbine: &dyn Turbine) {
         self.aircraft_preset_quick_mode = context.aircraft_preset_quick_mode();
 
@@ -189,7 +243,10 @@ impl<C: ApuConstants> ElectronicControlBox<C> {
         self.n = turbine.n();
         self.egt = turbine.egt();
         self.turbine_state = turbine.state();
-        self.bleed_air_pressure = turbine.bleed_air_pressure();
+        // Closing the inlet guide vanes lowers the delivery pressure towards the inlet pressure.
+        self.bleed_air_pressure = turbine.bleed_air_pressure()
+            - (turbine.bleed_air_pressure() - self.inlet_pressure)
+                * (Ratio::new::<ratio>(1.) - self.inlet_guide_vane_position);
 
         self.egt_warning_temperature = self.calculate_egt_warning_temperature(&self.turbine_state);
         if self.n.get::<percent>() > 95. {
@@ -198,6 +255,8 @@ impl<C: ApuConstants> ElectronicControlBox<C> {
             self.n_above_95_duration = Duration::from_secs(0);
         }
 
+        self.update_fault_monitoring(context);
+
         if !self.is_on() {
             self.fault = None;
         }
@@ -209,6 +268,33 @@ impl<C: ApuConstants> ElectronicControlBox<C> {
         }
     }
 
+    /// Regulates the load compressor inlet guide vanes to keep the EGT below the caution
+    /// temperature. Only the bleed air delivery is reduced, as the electrical load has
+    /// priority over the bleed air load.
+    pub fn update_load(&mut self, context: &UpdateContext, demanded: ApuLoad) {
+        let target_position = if self.turbine_state == TurbineState::Running {
+            let egt_margin = self.egt_caution_temperature().get::<degree_celsius>()
+                - self.egt.get::<degree_celsius>();
+
+            Self::INLET_GUIDE_VANE_MINIMUM_OPENING
+                + (1. - Self::INLET_GUIDE_VANE_MINIMUM_OPENING)
+                    * (egt_margin / Self::INLET_GUIDE_VANE_REGULATION_EGT_MARGIN).clamp(0., 1.)
+        } else {
+            1.
+        };
+
+        let max_travel = Self::INLET_GUIDE_VANE_TRAVEL_PER_SECOND * context.delta_as_secs_f64();
+        let position = self.inlet_guide_vane_position.get::<ratio>();
+        self.inlet_guide_vane_position = Ratio::new::<ratio>(
+            position + (target_position - position).clamp(-max_travel, max_travel),
+        );
+
+        self.load = ApuLoad::new(
+            demanded.bleed_air().min(self.inlet_guide_vane_position),
+            demanded.electrical(),
+        );
+    }
+
     pub fn update_bleed_air_valve_state(
         &mut self,
         context: &UpdateContext,
@@ -226,12 +312,83 @@ impl<C: ApuConstants> ElectronicControlBox<C> {
             && 0. < self.n.get::<percent>()
             && !fuel_pressure_switch.has_pressure()
         {
-            self.fault = Some(ApuFault::FuelLowPressure);
+            self.set_fault(ApuFault::FuelLowPressure);
+        }
+    }
+
+    fn update_fault_monitoring(&mut self, context: &UpdateContext) {
+        if matches!(
+            <ElectronicControlBox<C> as ControllerSignal<AirIntakeFlapSignal>>::signal(self),
+            Some(AirIntakeFlapSignal::Open)
+        ) && !self.air_intake_flap_is_fully_open()
+        {
+            self.air_intake_flap_opening_for += context.delta();
+        } else {
+            self.air_intake_flap_opening_for = Duration::ZERO;
+        }
+
+        // A failed start motor draws current but doesn't turn the APU.
+        if self.start_motor_is_powered && self.start_motor_failure.is_active() {
+            self.start_motor_engaged_without_rotation_for += context.delta();
+        } else {
+            self.start_motor_engaged_without_rotation_for = Duration::ZERO;
+        }
+
+        if !self.is_on() || self.fault.is_some() {
+            return;
+        }
+
+        // The EGT limits only apply while the ECB governs the fuel flow. While stopping after an
+        // aborted start the EGT is still allowed to decay from the higher starting limit.
+        let egt_is_monitored = matches!(
+            self.turbine_state,
+            TurbineState::Starting | TurbineState::Running
+        );
+        let detected_fault = if self.ecb_failure.is_active() {
+            Some(ApuFault::EcbFailure)
+        } else if self.n.get::<percent>() > Self::OVERSPEED_N {
+            Some(ApuFault::Overspeed)
+        } else if egt_is_monitored && self.egt > self.egt_warning_temperature {
+            Some(ApuFault::EgtOvertemperature)
+        } else if self.n.get::<percent>() > Self::LOW_OIL_PRESSURE_MONITORING_ABOVE_N
+            && self.oil_pressure < Pressure::new::<psi>(Self::LOW_OIL_PRESSURE_PSI)
+        {
+            Some(ApuFault::LowOilPressure)
+        } else if self.oil_temperature
+            > ThermodynamicTemperature::new::<degree_celsius>(Self::HIGH_OIL_TEMPERATURE)
+        {
+            Some(ApuFault::HighOilTemperature)
+        } else if self.generator_oil_outlet_temperature
+            > ThermodynamicTemperature::new::<degree_celsius>(
+                Self::HIGH_GENERATOR_OIL_OUTLET_TEMPERATURE,
+            )
+        {
+            Some(ApuFault::GeneratorBearing)
+        } else if self.air_intake_flap_opening_for > Self::AIR_INTAKE_FLAP_OPENING_TIME_LIMIT {
+            Some(ApuFault::AirIntakeFlapNotOpen)
+        } else if self.start_motor_engaged_without_rotation_for > Self::NO_ACCELERATION_TIME_LIMIT {
+            Some(ApuFault::NoAcceleration)
+        } else {
+            None
+        };
+
+        if let Some(fault) = detected_fault {
+            self.set_fault(fault);
         }
     }
 
+    fn set_fault(&mut self, fault: ApuFault) {
+        self.fault = Some(fault);
+        self.last_shutdown_cause = Some(fault);
+    }
+
     fn update_fuel_used(&mut self, context: &UpdateContext) {
+        let load_factor = 1.
+            + Self::BLEED_AIR_LOAD_FUEL_FLOW_FACTOR * self.load.bleed_air().get::<ratio>()
+            + Self::ELECTRICAL_LOAD_FUEL_FLOW_FACTOR * self.load.electrical().get::<ratio>();
+
         self.fuel_used += self.fuel_flow
+            * load_factor
             * MassConcentration::new::<kilogram_per_liter>(Self::JET_A_1_DENSITY)
             * context.delta_as_time();
     }
@@ -347,6 +504,14 @@ impl<C: ApuConstants> ElectronicControlBox<C> {
         self.n
     }
 
+    pub fn load(&self) -> ApuLoad {
+        self.load
+    }
+
+    pub fn bleed_air_pressure(&self) -> Pressure {
+        self.bleed_air_pressure
+    }
+
     fn n2(&self) -> Ratio {
         self.n2
     }
@@ -455,7 +620,7 @@ impl<C: ApuConstants> ControllerSignal<TurbineSignal> for ElectronicControlBox<C
                 && !self.is_cooldown_required())
         {
             Some(TurbineSignal::Stop)
-        } else if self.start_motor_is_powered
+        } else if (self.start_motor_is_powered && !self.start_motor_failure.is_active())
             || self.n.get::<percent>() >= Self::START_MOTOR_POWERED_UNTIL_N
         {
             Some(TurbineSignal::StartOrContinue)
@@ -482,8 +647,15 @@ impl<C: ApuConstants> ControllerSignal<ApuBleedAirValveSignal> for ElectronicCon
     }
 }
 impl<C: ApuConstants> SimulationElement for ElectronicControlBox<C> {
+    fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
+        self.start_motor_failure.accept(visitor);
+        self.ecb_failure.accept(visitor);
+
+        visitor.visit(self);
+    }
+
     fn write(&self, writer: &mut SimulatorWriter) {
-        let ssm = if self.is_on() {
+        let ssm = if self.is_on() && !self.ecb_failure.is_active() {
             SignStatus::NormalOperation
         } else {
             SignStatus::FailureWarning
@@ -525,6 +697,13 @@ impl<C: ApuConstants> SimulationElement for ElectronicControlBox<C> {
             &self.apu_is_emergency_shutdown_id,
             self.is_emergency_shutdown(),
         );
+
+        // Maintenance information recorded by the ECB.
+        writer.write(&self.apu_shutdown_cause_id, ApuFault::code(self.fault));
+        writer.write(
+            &self.apu_last_shutdown_cause_id,
+            ApuFault::code(self.last_shutdown_cause),
+        );
     }
 
     fn read(&mut self, reader: &mut SimulatorReader) {
@@ -543,9 +722,24 @@ impl<C: ApuConstants> SimulationElement for ElectronicControlBox<C> {
     }
 }
 
+/// The causes of an APU shutdown as recorded by the ECB. The discriminants are
+/// written to the simulation as the shutdown cause code.
 #[derive(Clone, Copy, Debug, PartialEq)]
 enum ApuFault {
-    ApuFire,
-    FuelLowPressure,
-    DcPowerLoss,
+    ApuFire = 1,
+    FuelLowPressure = 2,
+    DcPowerLoss = 3,
+    Overspeed = 4,
+    EgtOvertemperature = 5,
+    LowOilPressure = 6,
+    HighOilTemperature = 7,
+    AirIntakeFlapNotOpen = 8,
+    NoAcceleration = 9,
+    EcbFailure = 10,
+    GeneratorBearing = 11,
+}
+impl ApuFault {
+    fn code(fault: Option<ApuFault>) -> f64 {
+        fault.map_or(0., |fault| fault as u8 as f64)
+    }
 }
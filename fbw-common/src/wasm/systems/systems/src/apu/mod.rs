@@ -1,15 +1,19 @@
 use self::{
     air_intake_flap::AirIntakeFlap, aps3200::ShutdownAps3200Turbine,
-    electronic_control_box::ElectronicControlBox, pw980::ShutdownPw980Turbine,
+    electronic_control_box::ElectronicControlBox, oil_system::ApuOilSystem,
+    pw980::ShutdownPw980Turbine,
 };
 use crate::{
-    electrical::{ElectricalElement, ElectricitySource, ProvideFrequency, ProvidePotential},
+    electrical::{
+        ElectricalElement, ElectricitySource, ProvideFrequency, ProvideLoad, ProvidePotential,
+    },
+    failures::{Failure, FailureType},
     overhead::{FirePushButton, OnOffAvailablePushButton, OnOffFaultPushButton},
     pneumatic::{ControllablePneumaticValve, TargetPressureTemperatureSignal},
     shared::{
         ApuAvailable, ApuBleedAirValveSignal, ApuMaster, ApuStart, AuxiliaryPowerUnitElectrical,
         ContactorSignal, ControllerSignal, ElectricalBusType, EngineCorrectedN1,
-        LgciuWeightOnWheels,
+        InternationalStandardAtmosphere, LgciuWeightOnWheels,
     },
     simulation::{
         SimulationElement, SimulationElementVisitor, SimulatorWriter, UpdateContext, Write,
@@ -17,10 +21,16 @@ use crate::{
 };
 use std::time::Duration;
 use uom::si::f64::*;
-use uom::si::thermodynamic_temperature::degree_celsius;
+use uom::si::{
+    mass_rate::kilogram_per_second,
+    ratio::{percent, ratio},
+    temperature_interval,
+    thermodynamic_temperature::degree_celsius,
+};
 
 mod air_intake_flap;
 mod aps3200;
+mod oil_system;
 mod pw980;
 use crate::simulation::{InitContext, VariableIdentifier};
 pub use aps3200::{Aps3200ApuGenerator, Aps3200Constants, Aps3200StartMotor};
@@ -94,6 +104,38 @@ impl FuelPressureSwitch {
     }
 }
 
+/// The fuel control unit meters the fuel flow commanded by the ECB to the turbine.
+pub struct ApuFuelControlUnit {
+    overspeed_failure: Failure,
+    egt_overtemperature_failure: Failure,
+}
+impl ApuFuelControlUnit {
+    fn new() -> Self {
+        Self {
+            overspeed_failure: Failure::new(FailureType::ApuOverspeed),
+            egt_overtemperature_failure: Failure::new(FailureType::ApuEgtOvertemperature),
+        }
+    }
+
+    /// A failed speed governor no longer reduces the fuel flow once the rated speed is reached.
+    fn speed_governing_lost(&self) -> bool {
+        self.overspeed_failure.is_active()
+    }
+
+    /// A failed metering valve delivers more fuel than the ECB commands.
+    fn is_over_fuelling(&self) -> bool {
+        self.egt_overtemperature_failure.is_active()
+    }
+}
+impl SimulationElement for ApuFuelControlUnit {
+    fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
+        self.overspeed_failure.accept(visitor);
+        self.egt_overtemperature_failure.accept(visitor);
+
+        visitor.visit(self);
+    }
+}
+
 #[derive(Debug, PartialEq, Eq)]
 pub enum TurbineSignal {
     StartOrContinue,
@@ -109,6 +151,8 @@ pub struct AuxiliaryPowerUnit<T: ApuGenerator, U: ApuStartMotor, C: ApuConstants
     start_motor: U,
     air_intake_flap: AirIntakeFlap,
     fuel_pressure_switch: FuelPressureSwitch,
+    fuel_control_unit: ApuFuelControlUnit,
+    oil_system: ApuOilSystem,
 }
 impl<T: ApuGenerator, U: ApuStartMotor, C: ApuConstants, const N: usize>
     AuxiliaryPowerUnit<T, U, C, N>
@@ -131,6 +175,8 @@ impl<T: ApuGenerator, U: ApuStartMotor, C: ApuConstants, const N: usize>
             start_motor,
             air_intake_flap: AirIntakeFlap::new(air_intake_flap_powered_by),
             fuel_pressure_switch: FuelPressureSwitch::new(),
+            fuel_control_unit: ApuFuelControlUnit::new(),
+            oil_system: ApuOilSystem::new(),
         }
     }
 
@@ -141,7 +187,7 @@ impl<T: ApuGenerator, U: ApuStartMotor, C: ApuConstants, const N: usize>
         fire_on_ground: bool,
         fire_overhead: &AuxiliaryPowerUnitFireOverheadPanel,
         apu_bleed_is_on: bool,
-        apu_gen_is_used: bool,
+        apu_bleed_air_flow: MassRate,
         bleed_air_valve: &mut impl ControllablePneumaticValve,
         has_fuel_remaining: bool,
     ) {
@@ -157,14 +203,16 @@ impl<T: ApuGenerator, U: ApuStartMotor, C: ApuConstants, const N: usize>
         self.air_intake_flap.update(context, &self.ecb);
         self.ecb.update_air_intake_flap_state(&self.air_intake_flap);
 
+        let demanded_load = self.demanded_load(bleed_air_valve, apu_bleed_air_flow);
+        self.ecb.update_load(context, demanded_load);
+
         if let Some(turbine) = self.turbine.take() {
-            let updated_turbine = turbine.update(
-                context,
-                bleed_air_valve.is_open(),
-                apu_gen_is_used,
-                &self.ecb,
-            );
+            let updated_turbine =
+                turbine.update(context, self.ecb.load(), &self.fuel_control_unit, &self.ecb);
 
+            self.oil_system
+                .update(context, updated_turbine.n(), self.ecb.load());
+            self.ecb.update_oil_system_state(&self.oil_system);
             self.ecb.update(context, updated_turbine.as_ref());
 
             self.turbine = Some(updated_turbine);
@@ -185,6 +233,33 @@ impl<T: ApuGenerator, U: ApuStartMotor, C: ApuConstants, const N: usize>
         self.ecb.update_fuel_used_reset(engines, lgciu);
     }
 
+    /// The load demanded from the APU: the air flowing through the bleed air valve relative
+    /// to the rated flow of the load compressor and the load of the most loaded generator.
+    fn demanded_load(
+        &self,
+        bleed_air_valve: &impl ControllablePneumaticValve,
+        apu_bleed_air_flow: MassRate,
+    ) -> ApuLoad {
+        let bleed_air = if bleed_air_valve.is_open() {
+            Ratio::new::<ratio>(
+                (apu_bleed_air_flow.get::<kilogram_per_second>()
+                    / C::RATED_BLEED_AIR_FLOW_KG_PER_S)
+                    .clamp(0., 1.),
+            )
+        } else {
+            Ratio::default()
+        };
+
+        let electrical = self
+            .generators
+            .iter()
+            .map(|gen| gen.load())
+            .fold(Ratio::default(), Ratio::max)
+            .min(Ratio::new::<ratio>(1.));
+
+        ApuLoad::new(bleed_air, electrical)
+    }
+
     fn is_available(&self) -> bool {
         self.ecb.is_available()
     }
@@ -251,9 +326,9 @@ impl<T: ApuGenerator, U: ApuStartMotor, C: ApuConstants, const N: usize>
     fn signal(&self) -> Option<TargetPressureTemperatureSignal> {
         // TODO: Calculate the temperature depending on environmental conditions.
         // Currently the temperature is precalculated for a bleed pressure of 42 psi.
-        self.turbine.as_ref().map(|s| {
+        self.turbine.as_ref().map(|_| {
             TargetPressureTemperatureSignal::new(
-                s.bleed_air_pressure(),
+                self.ecb.bleed_air_pressure(),
                 ThermodynamicTemperature::new::<degree_celsius>(165.),
             )
         })
@@ -266,6 +341,8 @@ impl<T: ApuGenerator, U: ApuStartMotor, C: ApuConstants, const N: usize> Simulat
         accept_iterable!(self.generators, visitor);
         self.start_motor.accept(visitor);
         self.air_intake_flap.accept(visitor);
+        self.fuel_control_unit.accept(visitor);
+        self.oil_system.accept(visitor);
         self.ecb.accept(visitor);
 
         visitor.visit(self);
@@ -279,12 +356,123 @@ impl<T: ApuGenerator, U: ApuStartMotor, C: ApuConstants, const N: usize> Simulat
     }
 }
 
+/// The load imposed on the APU by its users. The bleed air load is relative to the
+/// rated flow of the load compressor, the electrical load to the rated generator output.
+#[derive(Clone, Copy, Default)]
+pub struct ApuLoad {
+    bleed_air: Ratio,
+    electrical: Ratio,
+}
+impl ApuLoad {
+    pub fn new(bleed_air: Ratio, electrical: Ratio) -> Self {
+        Self {
+            bleed_air,
+            electrical,
+        }
+    }
+
+    pub fn bleed_air(&self) -> Ratio {
+        self.bleed_air
+    }
+
+    pub fn electrical(&self) -> Ratio {
+        self.electrical
+    }
+}
+
+/// Above the standard sea level temperature the turbine has to run hotter to deliver
+/// the same power. The EGT scales with the ratio of the ambient to the standard temperature.
+fn hot_day_egt_factor(context: &UpdateContext) -> f64 {
+    (context.ambient_temperature() / InternationalStandardAtmosphere::ground_temperature())
+        .get::<ratio>()
+        .max(1.)
+}
+
+/// The ECB governs the running turbine at its rated speed. A change in load makes the speed
+/// deviate until the fuel control has caught up with the new load, after which it recovers.
+/// When speed governing is lost the fuel flow isn't reduced at the rated speed and the turbine
+/// keeps accelerating.
+struct GovernedSpeed {
+    load: f64,
+    deviation: f64,
+    runaway: f64,
+}
+impl GovernedSpeed {
+    const RATED_SPEED_PERCENT: f64 = 100.;
+    // Speed lost when the full bleed air and electrical load is applied at once
+    const DEVIATION_AT_FULL_LOAD_STEP_PERCENT: f64 = 3.;
+    const RECOVERY_PERCENT_PER_SECOND: f64 = 1.;
+    const RUNAWAY_PERCENT_PER_SECOND: f64 = 3.;
+
+    fn new() -> Self {
+        Self {
+            load: 0.,
+            deviation: 0.,
+            runaway: 0.,
+        }
+    }
+
+    fn update(
+        &mut self,
+        context: &UpdateContext,
+        load: ApuLoad,
+        fuel_control_unit: &ApuFuelControlUnit,
+    ) {
+        let load = (load.bleed_air() + load.electrical()).get::<ratio>() / 2.;
+
+        let deviation =
+            self.deviation + (load - self.load) * Self::DEVIATION_AT_FULL_LOAD_STEP_PERCENT;
+        let recovery =
+            (Self::RECOVERY_PERCENT_PER_SECOND * context.delta_as_secs_f64()).min(deviation.abs());
+
+        self.deviation = deviation - recovery * deviation.signum();
+        self.load = load;
+
+        let runaway_rate = if fuel_control_unit.speed_governing_lost() {
+            Self::RUNAWAY_PERCENT_PER_SECOND
+        } else {
+            -Self::RECOVERY_PERCENT_PER_SECOND
+        };
+        self.runaway = (self.runaway + runaway_rate * context.delta_as_secs_f64()).max(0.);
+    }
+
+    fn n(&self) -> Ratio {
+        Ratio::new::<percent>(Self::RATED_SPEED_PERCENT - self.deviation + self.runaway)
+    }
+}
+
+/// The EGT rise caused by the fuel control unit delivering more fuel than commanded.
+struct OverFuellingEgtDelta {
+    delta: f64,
+}
+impl OverFuellingEgtDelta {
+    const RISE_PER_SECOND: f64 = 40.;
+    const RECOVERY_PER_SECOND: f64 = 10.;
+
+    fn new() -> Self {
+        Self { delta: 0. }
+    }
+
+    fn update(&mut self, context: &UpdateContext, fuel_control_unit: &ApuFuelControlUnit) {
+        let rate = if fuel_control_unit.is_over_fuelling() {
+            Self::RISE_PER_SECOND
+        } else {
+            -Self::RECOVERY_PER_SECOND
+        };
+        self.delta = (self.delta + rate * context.delta_as_secs_f64()).max(0.);
+    }
+
+    fn egt_delta(&self) -> TemperatureInterval {
+        TemperatureInterval::new::<temperature_interval::degree_celsius>(self.delta)
+    }
+}
+
 pub trait Turbine {
     fn update(
         self: Box<Self>,
         context: &UpdateContext,
-        apu_bleed_is_used: bool,
-        apu_gen_is_used: bool,
+        load: ApuLoad,
+        fuel_control_unit: &ApuFuelControlUnit,
         controller: &dyn ControllerSignal<TurbineSignal>,
     ) -> Box<dyn Turbine>;
     fn n(&self) -> Ratio;
@@ -305,7 +493,12 @@ pub enum TurbineState {
 }
 
 pub trait ApuGenerator:
-    SimulationElement + ProvidePotential + ProvideFrequency + ElectricalElement + ElectricitySource
+    SimulationElement
+    + ProvidePotential
+    + ProvideFrequency
+    + ProvideLoad
+    + ElectricalElement
+    + ElectricitySource
 {
     fn update(&mut self, n: Ratio, is_emergency_shutdown: bool);
     fn output_within_normal_parameters(&self) -> bool;
@@ -318,6 +511,7 @@ pub trait ApuConstants {
     const AIR_INTAKE_FLAP_CLOSURE_PERCENT: f64;
     const SHOULD_BE_AVAILABLE_DURING_SHUTDOWN: bool;
     const FUEL_LINE_ID: u8;
+    const RATED_BLEED_AIR_FLOW_KG_PER_S: f64;
 }
 
 pub struct AuxiliaryPowerUnitFireOverheadPanel {
@@ -453,8 +647,8 @@ mod tests {
         fn update(
             self: Box<Self>,
             _: &UpdateContext,
-            _: bool,
-            _: bool,
+            _: ApuLoad,
+            _: &ApuFuelControlUnit,
             _: &dyn ControllerSignal<TurbineSignal>,
         ) -> Box<dyn Turbine> {
             self
@@ -477,6 +671,42 @@ mod tests {
         }
     }
 
+    struct InfinitelyRunningAtEgtTestTurbine {
+        egt: ThermodynamicTemperature,
+    }
+    impl InfinitelyRunningAtEgtTestTurbine {
+        fn new(egt: ThermodynamicTemperature) -> Self {
+            InfinitelyRunningAtEgtTestTurbine { egt }
+        }
+    }
+    impl Turbine for InfinitelyRunningAtEgtTestTurbine {
+        fn update(
+            self: Box<Self>,
+            _: &UpdateContext,
+            _: ApuLoad,
+            _: &ApuFuelControlUnit,
+            _: &dyn ControllerSignal<TurbineSignal>,
+        ) -> Box<dyn Turbine> {
+            self
+        }
+
+        fn n(&self) -> Ratio {
+            Ratio::new::<percent>(100.)
+        }
+
+        fn egt(&self) -> ThermodynamicTemperature {
+            self.egt
+        }
+
+        fn state(&self) -> TurbineState {
+            TurbineState::Running
+        }
+
+        fn bleed_air_pressure(&self) -> Pressure {
+            Pressure::new::<psi>(50.)
+        }
+    }
+
     struct TestPneumatic {
         apu_bleed_air_valve: DefaultValve,
     }
@@ -564,7 +794,7 @@ mod tests {
         apu_fire_overhead: AuxiliaryPowerUnitFireOverheadPanel,
         apu_overhead: AuxiliaryPowerUnitOverheadPanel,
         apu_bleed: OnOffFaultPushButton,
-        apu_gen_is_used: bool,
+        bleed_air_demand: Ratio,
         engine_1: TestEngine,
         engine_2: TestEngine,
         fire_detected_on_ground: bool,
@@ -603,7 +833,7 @@ mod tests {
                 apu_fire_overhead: AuxiliaryPowerUnitFireOverheadPanel::new(context),
                 apu_overhead: AuxiliaryPowerUnitOverheadPanel::new(context),
                 apu_bleed: OnOffFaultPushButton::new_on(context, "APU_BLEED"),
-                apu_gen_is_used: true,
+                bleed_air_demand: Ratio::new::<percent>(100.),
                 engine_1: TestEngine::new(Ratio::default()),
                 engine_2: TestEngine::new(Ratio::default()),
                 fire_detected_on_ground: false,
@@ -631,7 +861,7 @@ mod tests {
                 apu_fire_overhead: AuxiliaryPowerUnitFireOverheadPanel::new(context),
                 apu_overhead: AuxiliaryPowerUnitOverheadPanel::new(context),
                 apu_bleed: OnOffFaultPushButton::new_on(context, "APU_BLEED"),
-                apu_gen_is_used: true,
+                bleed_air_demand: Ratio::new::<percent>(100.),
                 engine_1: TestEngine::new(Ratio::default()),
                 engine_2: TestEngine::new(Ratio::default()),
                 fire_detected_on_ground: false,
@@ -649,8 +879,8 @@ mod tests {
             self.apu.set_air_intake_flap_travel_time(duration);
         }
 
-        fn set_apu_gen_is_used(&mut self, value: bool) {
-            self.apu_gen_is_used = value;
+        fn set_bleed_air_demand(&mut self, demand: Ratio) {
+            self.bleed_air_demand = demand;
         }
 
         fn set_has_fuel_remaining(&mut self, value: bool) {
@@ -662,6 +892,11 @@ mod tests {
                 .set_turbine(Some(Box::new(InfinitelyAtNTestTurbine::new(n))));
         }
 
+        fn set_turbine_infinitely_running_at_egt(&mut self, egt: ThermodynamicTemperature) {
+            self.apu
+                .set_turbine(Some(Box::new(InfinitelyRunningAtEgtTestTurbine::new(egt))));
+        }
+
         fn set_fire_on_ground(&mut self, fire_on_ground: bool) {
             self.fire_detected_on_ground = fire_on_ground;
         }
@@ -730,7 +965,9 @@ mod tests {
                 self.fire_detected_on_ground,
                 &self.apu_fire_overhead,
                 self.apu_bleed.is_on(),
-                self.apu_gen_is_used,
+                MassRate::new::<kilogram_per_second>(
+                    C::RATED_BLEED_AIR_FLOW_KG_PER_S * self.bleed_air_demand.get::<ratio>(),
+                ),
                 self.pneumatic.bleed_air_valve(),
                 self.has_fuel_remaining,
             );
@@ -893,8 +1130,8 @@ mod tests {
                 .run(Duration::from_secs(0))
         }
 
-        fn apu_gen_not_used(mut self) -> Self {
-            self.command(|a| a.set_apu_gen_is_used(false));
+        fn bleed_air_demand(mut self, demand: Ratio) -> Self {
+            self.command(|a| a.set_bleed_air_demand(demand));
             self
         }
 
@@ -931,8 +1168,14 @@ mod tests {
             self
         }
 
+        fn turbine_infinitely_running_at_egt(mut self, egt: ThermodynamicTemperature) -> Self {
+            self.command(|a| a.set_turbine_infinitely_running_at_egt(egt));
+            self
+        }
+
         fn cooling_down_apu(mut self) -> Self {
-            self = self.running_apu();
+            // The generator is loaded, as it is when the APU is shut down after use
+            self = self.power_demand(Power::new::<watt>(90000.)).running_apu();
             self = self.master_off();
             loop {
                 self = self.run(Duration::from_secs(1));
@@ -1029,6 +1272,16 @@ mod tests {
             self
         }
 
+        fn run_until_auto_shutdown(mut self, time_limit: Duration) -> Self {
+            let mut elapsed = Duration::ZERO;
+            while !self.is_auto_shutdown() && elapsed < time_limit {
+                self = self.run(Duration::from_millis(100));
+                elapsed += Duration::from_millis(100);
+            }
+
+            self
+        }
+
         fn unpower_start_motor_between(mut self, start: Ratio, end: Ratio) -> Self {
             loop {
                 self = self.run(Duration::from_millis(50));
@@ -1208,6 +1461,16 @@ mod tests {
         fn apu_fuel_used(&mut self) -> Arinc429Word<Mass> {
             self.read_arinc429_by_name("APU_FUEL_USED")
         }
+
+        fn shutdown_cause(&mut self) -> u8 {
+            let cause: f64 = self.read_by_name("APU_SHUTDOWN_CAUSE");
+            cause as u8
+        }
+
+        fn last_shutdown_cause(&mut self) -> u8 {
+            let cause: f64 = self.read_by_name("APU_LAST_SHUTDOWN_CAUSE");
+            cause as u8
+        }
     }
     impl<T: ApuGenerator, U: ApuStartMotor, C: ApuConstants, const N: usize> TestBed
         for AuxiliaryPowerUnitTestBed<T, U, C, N>
@@ -1231,6 +1494,7 @@ mod tests {
         use ntest::assert_about_eq;
 
         use super::*;
+        use crate::failures::FailureType;
         use uom::si::{mass::kilogram, power::watt};
 
         const APPROXIMATE_STARTUP_TIME: u64 = 49;
@@ -1834,8 +2098,6 @@ mod tests {
         ) {
             let mut test_bed = bed_with
                 .running_apu_without_bleed_air()
-                .and()
-                .apu_gen_not_used()
                 .run(Duration::from_secs(1_000));
 
             let egt = test_bed
@@ -1847,8 +2109,8 @@ mod tests {
         }
 
         #[rstest]
-        #[case::aps3200(test_bed_aps3200(), 350.)]
-        #[case::pw980(test_bed_pw980(), 490.)]
+        #[case::aps3200(test_bed_aps3200(), 350., 90000.)]
+        #[case::pw980(test_bed_pw980(), 490., 120000.)]
         /// For APS3200: Between 350 and 365
         /// For PW980: Between 490 and 505
         /// Komp: APU generator supplying will add maybe like 10-15 degrees.
@@ -1860,9 +2122,12 @@ mod tests {
         >(
             #[case] bed_with: AuxiliaryPowerUnitTestBed<T, U, C, N>,
             #[case] temp: f64,
+            #[case] rated_generator_output: f64,
         ) {
             let mut test_bed = bed_with
                 .running_apu_without_bleed_air()
+                .and()
+                .power_demand(Power::new::<watt>(rated_generator_output / 0.8))
                 .run(Duration::from_secs(1_000));
 
             let egt = test_bed
@@ -1889,8 +2154,6 @@ mod tests {
         ) {
             let mut test_bed = bed_with
                 .running_apu_with_bleed_air()
-                .and()
-                .apu_gen_not_used()
                 .run(Duration::from_secs(1_000));
 
             let egt = test_bed
@@ -1934,8 +2197,8 @@ mod tests {
         }
 
         #[rstest]
-        #[case::aps3200(test_bed_aps3200(), 435.)]
-        #[case::pw980(test_bed_pw980(), 530.)]
+        #[case::aps3200(test_bed_aps3200(), 435., 90000.)]
+        #[case::pw980(test_bed_pw980(), 530., 120000.)]
         /// For APS3200: Between 435 and 460
         /// For PW980: Between 530 and 555
         fn running_apu_supplying_bleed_air_and_electrical_increases_egt_to_ref_degrees<
@@ -1946,9 +2209,12 @@ mod tests {
         >(
             #[case] bed_with: AuxiliaryPowerUnitTestBed<T, U, C, N>,
             #[case] temp: f64,
+            #[case] rated_generator_output: f64,
         ) {
             let mut test_bed = bed_with
                 .running_apu_with_bleed_air()
+                .and()
+                .power_demand(Power::new::<watt>(rated_generator_output / 0.8))
                 .run(Duration::from_secs(1_000));
 
             let egt = test_bed
@@ -1959,6 +2225,202 @@ mod tests {
             assert!((temp..=temp + 25.).contains(&egt));
         }
 
+        #[rstest]
+        #[case::aps3200(test_bed_aps3200())]
+        #[case::pw980(test_bed_pw980())]
+        fn running_apu_egt_increases_with_bleed_air_demand<
+            T: ApuGenerator,
+            U: ApuStartMotor,
+            C: ApuConstants,
+            const N: usize,
+        >(
+            #[case] bed_with: AuxiliaryPowerUnitTestBed<T, U, C, N>,
+        ) {
+            let mut test_bed = bed_with
+                .running_apu_with_bleed_air()
+                .and()
+                .bleed_air_demand(Ratio::new::<percent>(50.))
+                .run(Duration::from_secs(1_000));
+
+            let egt_at_half_demand = test_bed.egt().normal_value().unwrap();
+
+            test_bed = test_bed
+                .then_continue_with()
+                .bleed_air_demand(Ratio::new::<percent>(100.))
+                .run(Duration::from_secs(1_000));
+
+            assert!(test_bed.egt().normal_value().unwrap() > egt_at_half_demand);
+        }
+
+        #[rstest]
+        #[case::aps3200(test_bed_aps3200())]
+        #[case::pw980(test_bed_pw980())]
+        fn running_apu_speed_drops_when_load_is_applied_and_recovers<
+            T: ApuGenerator,
+            U: ApuStartMotor,
+            C: ApuConstants,
+            const N: usize,
+        >(
+            #[case] bed_with: AuxiliaryPowerUnitTestBed<T, U, C, N>,
+        ) {
+            let mut test_bed = bed_with
+                .running_apu_with_bleed_air()
+                .and()
+                .bleed_air_demand(Ratio::default())
+                .run(Duration::from_secs(60));
+
+            assert_about_eq!(test_bed.n().normal_value().unwrap().get::<percent>(), 100.);
+
+            test_bed = test_bed
+                .then_continue_with()
+                .bleed_air_demand(Ratio::new::<percent>(100.))
+                .run(Duration::from_millis(100));
+
+            assert_lt!(test_bed.n().normal_value().unwrap().get::<percent>(), 99.);
+
+            test_bed = test_bed.run(Duration::from_secs(10));
+
+            assert_about_eq!(test_bed.n().normal_value().unwrap().get::<percent>(), 100.);
+        }
+
+        #[rstest]
+        #[case::aps3200(test_bed_aps3200(), test_bed_aps3200(), 90000.)]
+        #[case::pw980(test_bed_pw980(), test_bed_pw980(), 120000.)]
+        fn fuel_used_increases_with_apu_load<
+            T: ApuGenerator,
+            U: ApuStartMotor,
+            C: ApuConstants,
+            const N: usize,
+        >(
+            #[case] unloaded_bed_with: AuxiliaryPowerUnitTestBed<T, U, C, N>,
+            #[case] loaded_bed_with: AuxiliaryPowerUnitTestBed<T, U, C, N>,
+            #[case] rated_generator_output: f64,
+        ) {
+            let mut unloaded_test_bed = unloaded_bed_with
+                .running_apu_without_bleed_air()
+                .and()
+                .apu_fuel_line_flowing(true, C::FUEL_LINE_ID)
+                .run(Duration::from_secs(100));
+
+            let mut loaded_test_bed = loaded_bed_with
+                .running_apu_with_bleed_air()
+                .and()
+                .power_demand(Power::new::<watt>(rated_generator_output / 0.8))
+                .apu_fuel_line_flowing(true, C::FUEL_LINE_ID)
+                .run(Duration::from_secs(100));
+
+            assert!(
+                loaded_test_bed.apu_fuel_used().normal_value().unwrap()
+                    > unloaded_test_bed.apu_fuel_used().normal_value().unwrap()
+            );
+        }
+
+        #[rstest]
+        #[case::aps3200(test_bed_aps3200(), 460., 90000.)]
+        #[case::pw980(test_bed_pw980(), 555., 120000.)]
+        /// On a hot day with both packs and the galley supplied, the EGT is well above
+        /// the values seen on a standard day, while remaining below the caution temperature.
+        fn running_apu_on_hot_day_with_bleed_air_and_electrical_load_stays_below_egt_caution<
+            T: ApuGenerator,
+            U: ApuStartMotor,
+            C: ApuConstants,
+            const N: usize,
+        >(
+            #[case] bed_with: AuxiliaryPowerUnitTestBed<T, U, C, N>,
+            #[case] standard_day_max_egt: f64,
+            #[case] rated_generator_output: f64,
+        ) {
+            let mut test_bed = bed_with
+                .ambient_temperature(ThermodynamicTemperature::new::<degree_celsius>(45.))
+                .running_apu_with_bleed_air()
+                .and()
+                .power_demand(Power::new::<watt>(rated_generator_output / 0.8))
+                .run(Duration::from_secs(1_000));
+
+            let egt = test_bed.egt().normal_value().unwrap();
+            assert!(egt.get::<degree_celsius>() > standard_day_max_egt);
+            assert!(egt < test_bed.egt_caution_temperature().normal_value().unwrap());
+            assert!(test_bed.apu_is_available());
+            assert!(!test_bed.master_has_fault());
+        }
+
+        #[rstest]
+        #[case::aps3200(test_bed_aps3200())]
+        #[case::pw980(test_bed_pw980())]
+        fn when_egt_reaches_caution_inlet_guide_vanes_close_reducing_bleed_air_pressure<
+            T: ApuGenerator,
+            U: ApuStartMotor,
+            C: ApuConstants,
+            const N: usize,
+        >(
+            #[case] bed_with: AuxiliaryPowerUnitTestBed<T, U, C, N>,
+        ) {
+            let mut test_bed = bed_with
+                .running_apu_with_bleed_air()
+                .and()
+                .turbine_infinitely_running_at_egt(ThermodynamicTemperature::new::<degree_celsius>(
+                    400.,
+                ))
+                .run(Duration::from_secs(10));
+
+            let unrestricted_bleed_air_pressure =
+                test_bed.bleed_air_pressure().normal_value().unwrap();
+            assert_about_eq!(unrestricted_bleed_air_pressure.get::<psi>(), 50.);
+
+            let caution_egt = test_bed.egt_caution_temperature().normal_value().unwrap();
+            test_bed = test_bed
+                .then_continue_with()
+                .turbine_infinitely_running_at_egt(caution_egt)
+                .run(Duration::from_secs(1))
+                .run(Duration::from_secs(10));
+
+            assert!(
+                test_bed
+                    .bleed_air_pressure()
+                    .normal_value()
+                    .unwrap()
+                    .get::<psi>()
+                    < 25.
+            );
+            assert!(test_bed.apu_is_available());
+        }
+
+        #[rstest]
+        #[case::aps3200(test_bed_aps3200())]
+        #[case::pw980(test_bed_pw980())]
+        fn inlet_guide_vanes_reopen_when_egt_decreases<
+            T: ApuGenerator,
+            U: ApuStartMotor,
+            C: ApuConstants,
+            const N: usize,
+        >(
+            #[case] bed_with: AuxiliaryPowerUnitTestBed<T, U, C, N>,
+        ) {
+            let mut test_bed = bed_with.running_apu_with_bleed_air();
+            let caution_egt = test_bed.egt_caution_temperature().normal_value().unwrap();
+
+            test_bed = test_bed
+                .then_continue_with()
+                .turbine_infinitely_running_at_egt(caution_egt)
+                .run(Duration::from_secs(1))
+                .run(Duration::from_secs(10))
+                .then_continue_with()
+                .turbine_infinitely_running_at_egt(ThermodynamicTemperature::new::<degree_celsius>(
+                    400.,
+                ))
+                .run(Duration::from_secs(1))
+                .run(Duration::from_secs(10));
+
+            assert_about_eq!(
+                test_bed
+                    .bleed_air_pressure()
+                    .normal_value()
+                    .unwrap()
+                    .get::<psi>(),
+                50.
+            );
+        }
+
         #[rstest]
         #[case::aps3200(test_bed_aps3200())]
         #[case::pw980(test_bed_pw980())]
@@ -3410,5 +3872,235 @@ mod tests {
                     < 1.
             );
         }
+
+        #[rstest]
+        #[case::aps3200_overspeed(test_bed_aps3200(), FailureType::ApuOverspeed, 4)]
+        #[case::pw980_overspeed(test_bed_pw980(), FailureType::ApuOverspeed, 4)]
+        #[case::aps3200_egt(test_bed_aps3200(), FailureType::ApuEgtOvertemperature, 5)]
+        #[case::pw980_egt(test_bed_pw980(), FailureType::ApuEgtOvertemperature, 5)]
+        #[case::aps3200_low_oil_pressure(test_bed_aps3200(), FailureType::ApuLowOilPressure, 6)]
+        #[case::pw980_low_oil_pressure(test_bed_pw980(), FailureType::ApuLowOilPressure, 6)]
+        #[case::aps3200_high_oil_temperature(
+            test_bed_aps3200(),
+            FailureType::ApuHighOilTemperature,
+            7
+        )]
+        #[case::pw980_high_oil_temperature(test_bed_pw980(), FailureType::ApuHighOilTemperature, 7)]
+        #[case::aps3200_ecb(test_bed_aps3200(), FailureType::ApuElectronicControlBox, 10)]
+        #[case::pw980_ecb(test_bed_pw980(), FailureType::ApuElectronicControlBox, 10)]
+        #[case::aps3200_generator_bearing(test_bed_aps3200(), FailureType::ApuGeneratorBearing, 11)]
+        #[case::pw980_generator_bearing(test_bed_pw980(), FailureType::ApuGeneratorBearing, 11)]
+        fn running_apu_failure_causes_auto_shutdown_with_recorded_cause<
+            T: ApuGenerator,
+            U: ApuStartMotor,
+            C: ApuConstants,
+            const N: usize,
+        >(
+            #[case] bed_with: AuxiliaryPowerUnitTestBed<T, U, C, N>,
+            #[case] failure: FailureType,
+            #[case] cause: u8,
+        ) {
+            let mut test_bed = bed_with.running_apu();
+            assert_eq!(test_bed.shutdown_cause(), 0);
+
+            test_bed.fail(failure);
+            test_bed = test_bed.run_until_auto_shutdown(Duration::from_secs(60));
+
+            assert!(test_bed.is_auto_shutdown());
+            assert!(test_bed.master_has_fault());
+            assert!(!test_bed.apu_is_available());
+            assert_eq!(test_bed.shutdown_cause(), cause);
+            assert_eq!(test_bed.last_shutdown_cause(), cause);
+        }
+
+        #[rstest]
+        #[case::aps3200(test_bed_aps3200())]
+        #[case::pw980(test_bed_pw980())]
+        fn last_shutdown_cause_is_retained_after_the_apu_is_switched_off<
+            T: ApuGenerator,
+            U: ApuStartMotor,
+            C: ApuConstants,
+            const N: usize,
+        >(
+            #[case] bed_with: AuxiliaryPowerUnitTestBed<T, U, C, N>,
+        ) {
+            let mut test_bed = bed_with.running_apu();
+            test_bed.fail(FailureType::ApuOverspeed);
+            test_bed = test_bed
+                .run_until_auto_shutdown(Duration::from_secs(60))
+                .then_continue_with()
+                .master_off();
+
+            while !test_bed.turbine_is_shutdown() {
+                test_bed = test_bed.run(Duration::from_secs(1));
+            }
+            test_bed = test_bed.run(Duration::from_secs(30));
+
+            assert!(!test_bed.master_has_fault());
+            assert_eq!(test_bed.shutdown_cause(), 0);
+            assert_eq!(test_bed.last_shutdown_cause(), 4);
+        }
+
+        #[rstest]
+        #[case::aps3200(test_bed_aps3200())]
+        #[case::pw980(test_bed_pw980())]
+        fn speed_governing_failure_accelerates_the_turbine_past_the_overspeed_limit<
+            T: ApuGenerator,
+            U: ApuStartMotor,
+            C: ApuConstants,
+            const N: usize,
+        >(
+            #[case] bed_with: AuxiliaryPowerUnitTestBed<T, U, C, N>,
+        ) {
+            let mut test_bed = bed_with.running_apu();
+            test_bed.fail(FailureType::ApuOverspeed);
+
+            let mut max_n = test_bed.n_raw();
+            while !test_bed.is_auto_shutdown() {
+                test_bed = test_bed.run(Duration::from_millis(100));
+                max_n = max_n.max(test_bed.n_raw());
+            }
+
+            assert_gt!(max_n.get::<percent>(), 107.);
+            assert_lt!(max_n.get::<percent>(), 108.);
+            assert_eq!(test_bed.shutdown_cause(), 4);
+        }
+
+        #[rstest]
+        #[case::aps3200(test_bed_aps3200())]
+        #[case::pw980(test_bed_pw980())]
+        fn over_fuelling_raises_the_egt_past_the_warning_limit<
+            T: ApuGenerator,
+            U: ApuStartMotor,
+            C: ApuConstants,
+            const N: usize,
+        >(
+            #[case] bed_with: AuxiliaryPowerUnitTestBed<T, U, C, N>,
+        ) {
+            let mut test_bed = bed_with.running_apu();
+            test_bed.fail(FailureType::ApuEgtOvertemperature);
+            test_bed = test_bed.run(Duration::from_secs(1));
+
+            assert!(!test_bed.is_auto_shutdown());
+
+            let warning_egt = test_bed.egt_warning_temperature().normal_value().unwrap();
+            let mut max_egt = test_bed.egt().normal_value().unwrap();
+            while !test_bed.is_auto_shutdown() {
+                test_bed = test_bed.run(Duration::from_millis(100));
+                max_egt = max_egt.max(test_bed.egt().normal_value().unwrap());
+            }
+
+            assert_gt!(max_egt, warning_egt);
+            assert_eq!(test_bed.shutdown_cause(), 5);
+        }
+
+        #[rstest]
+        #[case::aps3200(test_bed_aps3200())]
+        #[case::pw980(test_bed_pw980())]
+        fn oil_temperature_faults_are_only_detected_once_the_oil_has_heated_up<
+            T: ApuGenerator,
+            U: ApuStartMotor,
+            C: ApuConstants,
+            const N: usize,
+        >(
+            #[case] bed_with: AuxiliaryPowerUnitTestBed<T, U, C, N>,
+        ) {
+            let mut test_bed = bed_with.running_apu();
+            test_bed.fail(FailureType::ApuHighOilTemperature);
+            test_bed.fail(FailureType::ApuGeneratorBearing);
+            test_bed = test_bed.run(Duration::from_secs(5));
+
+            assert!(!test_bed.is_auto_shutdown());
+
+            test_bed = test_bed.run_until_auto_shutdown(Duration::from_secs(60));
+
+            assert!(test_bed.is_auto_shutdown());
+        }
+
+        #[rstest]
+        #[case::aps3200(test_bed_aps3200(), 90000.)]
+        #[case::pw980(test_bed_pw980(), 120000.)]
+        fn apu_without_failures_keeps_running_on_a_hot_day_under_full_load<
+            T: ApuGenerator,
+            U: ApuStartMotor,
+            C: ApuConstants,
+            const N: usize,
+        >(
+            #[case] bed_with: AuxiliaryPowerUnitTestBed<T, U, C, N>,
+            #[case] rated_generator_output: f64,
+        ) {
+            let mut test_bed = bed_with
+                .ambient_temperature(ThermodynamicTemperature::new::<degree_celsius>(50.))
+                .running_apu_with_bleed_air()
+                .and()
+                .bleed_air_demand(Ratio::new::<percent>(100.))
+                .power_demand(Power::new::<watt>(rated_generator_output / 0.8));
+
+            test_bed = test_bed.run_until_auto_shutdown(Duration::from_secs(300));
+
+            assert!(!test_bed.is_auto_shutdown());
+        }
+
+        #[rstest]
+        #[case::aps3200(test_bed_aps3200())]
+        #[case::pw980(test_bed_pw980())]
+        fn low_oil_pressure_is_not_monitored_while_starting<
+            T: ApuGenerator,
+            U: ApuStartMotor,
+            C: ApuConstants,
+            const N: usize,
+        >(
+            #[case] bed_with: AuxiliaryPowerUnitTestBed<T, U, C, N>,
+        ) {
+            let mut test_bed = bed_with.starting_apu();
+            test_bed.fail(FailureType::ApuLowOilPressure);
+            test_bed = test_bed.run(Duration::from_secs(10));
+
+            assert!(!test_bed.is_auto_shutdown());
+            assert_gt!(test_bed.n().normal_value().unwrap().get::<percent>(), 0.);
+        }
+
+        #[rstest]
+        #[case::aps3200(test_bed_aps3200())]
+        #[case::pw980(test_bed_pw980())]
+        fn when_air_intake_flap_is_stuck_closed_start_is_aborted<
+            T: ApuGenerator,
+            U: ApuStartMotor,
+            C: ApuConstants,
+            const N: usize,
+        >(
+            #[case] bed_with: AuxiliaryPowerUnitTestBed<T, U, C, N>,
+        ) {
+            let mut test_bed = bed_with;
+            test_bed.fail(FailureType::ApuAirIntakeFlapStuck);
+            test_bed = test_bed.master_on().start_on().run(Duration::from_secs(35));
+
+            assert!(test_bed.is_air_intake_flap_fully_closed());
+            assert!(test_bed.master_has_fault());
+            assert!(!test_bed.start_is_on());
+            assert_about_eq!(test_bed.n().normal_value().unwrap().get::<percent>(), 0.);
+            assert_eq!(test_bed.last_shutdown_cause(), 8);
+        }
+
+        #[rstest]
+        #[case::aps3200(test_bed_aps3200())]
+        #[case::pw980(test_bed_pw980())]
+        fn when_start_motor_failed_apu_does_not_accelerate_and_start_is_aborted<
+            T: ApuGenerator,
+            U: ApuStartMotor,
+            C: ApuConstants,
+            const N: usize,
+        >(
+            #[case] bed_with: AuxiliaryPowerUnitTestBed<T, U, C, N>,
+        ) {
+            let mut test_bed = bed_with;
+            test_bed.fail(FailureType::ApuStartMotor);
+            test_bed = test_bed.starting_apu().run(Duration::from_secs(6));
+
+            assert!(test_bed.master_has_fault());
+            assert!(!test_bed.should_close_start_contactors_commanded());
+            assert_about_eq!(test_bed.n().normal_value().unwrap().get::<percent>(), 0.);
+            assert_eq!(test_bed.last_shutdown_cause(), 9);
+        }
     }
 }
@@ -0,0 +1,120 @@
+use crate::{
+    failures::{Failure, FailureType},
+    shared::calculate_towards_target_temperature,
+    simulation::{SimulationElement, SimulationElementVisitor, UpdateContext},
+};
+use uom::si::{f64::*, pressure::psi, ratio::ratio, thermodynamic_temperature::degree_celsius};
+
+use super::ApuLoad;
+
+/// The lubrication system of the gearbox and the generator(s). The oil pump is driven by the
+/// gearbox, so the oil pressure follows the turbine speed. The oil is cooled by an air cooled
+/// heat exchanger, the generator oil is heated further by the generator losses.
+pub(super) struct ApuOilSystem {
+    pressure: Pressure,
+    temperature: ThermodynamicTemperature,
+    generator_outlet_temperature: ThermodynamicTemperature,
+    temperatures_initialized: bool,
+
+    low_oil_pressure_failure: Failure,
+    high_oil_temperature_failure: Failure,
+    generator_bearing_failure: Failure,
+}
+impl ApuOilSystem {
+    const PRESSURE_AT_RATED_SPEED_PSI: f64 = 60.;
+    // A leak reduces the pressure the pump is able to deliver.
+    const LEAK_PRESSURE_FACTOR: f64 = 0.3;
+    // Temperature rise above ambient at the rated speed.
+    const TEMPERATURE_RISE_AT_RATED_SPEED: f64 = 60.;
+    // A clogged oil cooler only rejects a fraction of the heat.
+    const CLOGGED_COOLER_TEMPERATURE_RISE_FACTOR: f64 = 3.5;
+    const TEMPERATURE_COEFFICIENT: f64 = 3.;
+    const GENERATOR_TEMPERATURE_RISE_AT_RATED_LOAD: f64 = 20.;
+    const BEARING_FRICTION_TEMPERATURE_RISE: f64 = 150.;
+    const GENERATOR_TEMPERATURE_COEFFICIENT: f64 = 6.;
+
+    pub fn new() -> Self {
+        Self {
+            pressure: Pressure::default(),
+            temperature: ThermodynamicTemperature::new::<degree_celsius>(15.),
+            generator_outlet_temperature: ThermodynamicTemperature::new::<degree_celsius>(15.),
+            temperatures_initialized: false,
+
+            low_oil_pressure_failure: Failure::new(FailureType::ApuLowOilPressure),
+            high_oil_temperature_failure: Failure::new(FailureType::ApuHighOilTemperature),
+            generator_bearing_failure: Failure::new(FailureType::ApuGeneratorBearing),
+        }
+    }
+
+    pub fn update(&mut self, context: &UpdateContext, n: Ratio, load: ApuLoad) {
+        if !self.temperatures_initialized {
+            self.temperature = context.ambient_temperature();
+            self.generator_outlet_temperature = context.ambient_temperature();
+            self.temperatures_initialized = true;
+        }
+
+        let n = n.get::<ratio>().max(0.);
+
+        let leak_factor = if self.low_oil_pressure_failure.is_active() {
+            Self::LEAK_PRESSURE_FACTOR
+        } else {
+            1.
+        };
+        self.pressure =
+            Pressure::new::<psi>(Self::PRESSURE_AT_RATED_SPEED_PSI * n.powi(2) * leak_factor);
+
+        let cooler_factor = if self.high_oil_temperature_failure.is_active() {
+            Self::CLOGGED_COOLER_TEMPERATURE_RISE_FACTOR
+        } else {
+            1.
+        };
+        let target_temperature = ThermodynamicTemperature::new::<degree_celsius>(
+            context.ambient_temperature().get::<degree_celsius>()
+                + Self::TEMPERATURE_RISE_AT_RATED_SPEED * n * cooler_factor,
+        );
+        self.temperature = calculate_towards_target_temperature(
+            self.temperature,
+            target_temperature,
+            Self::TEMPERATURE_COEFFICIENT,
+            context.delta(),
+        );
+
+        let bearing_friction = if self.generator_bearing_failure.is_active() && n > 0. {
+            Self::BEARING_FRICTION_TEMPERATURE_RISE
+        } else {
+            0.
+        };
+        let target_generator_outlet_temperature = ThermodynamicTemperature::new::<degree_celsius>(
+            self.temperature.get::<degree_celsius>()
+                + Self::GENERATOR_TEMPERATURE_RISE_AT_RATED_LOAD * load.electrical().get::<ratio>()
+                + bearing_friction,
+        );
+        self.generator_outlet_temperature = calculate_towards_target_temperature(
+            self.generator_outlet_temperature,
+            target_generator_outlet_temperature,
+            Self::GENERATOR_TEMPERATURE_COEFFICIENT,
+            context.delta(),
+        );
+    }
+
+    pub fn pressure(&self) -> Pressure {
+        self.pressure
+    }
+
+    pub fn temperature(&self) -> ThermodynamicTemperature {
+        self.temperature
+    }
+
+    pub fn generator_outlet_temperature(&self) -> ThermodynamicTemperature {
+        self.generator_outlet_temperature
+    }
+}
+impl SimulationElement for ApuOilSystem {
+    fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
+        self.low_oil_pressure_failure.accept(visitor);
+        self.high_oil_temperature_failure.accept(visitor);
+        self.generator_bearing_failure.accept(visitor);
+
+        visitor.visit(self);
+    }
+}
@@ -3,7 +3,8 @@ use std::time::Duration;
 use uom::{
     si::{
         electric_potential::volt, f64::*, frequency::hertz, power::watt, pressure::psi,
-        ratio::percent, temperature_interval, thermodynamic_temperature::degree_celsius,
+        ratio::percent, ratio::ratio, temperature_interval,
+        thermodynamic_temperature::degree_celsius, thermodynamic_temperature::kelvin,
     },
     ConstZero,
 };
@@ -23,7 +24,10 @@ use crate::{
     simulation::{InitContext, SimulationElement, SimulatorWriter, UpdateContext},
 };
 
-use super::{ApuConstants, ApuGenerator, ApuStartMotor, Turbine, TurbineSignal, TurbineState};
+use super::{
+    hot_day_egt_factor, ApuConstants, ApuFuelControlUnit, ApuGenerator, ApuLoad, ApuStartMotor,
+    GovernedSpeed, OverFuellingEgtDelta, Turbine, TurbineSignal, TurbineState,
+};
 
 pub struct Pw980Constants;
 
@@ -34,6 +38,7 @@ impl ApuConstants for Pw980Constants {
     const AIR_INTAKE_FLAP_CLOSURE_PERCENT: f64 = 8.;
     const SHOULD_BE_AVAILABLE_DURING_SHUTDOWN: bool = false;
     const FUEL_LINE_ID: u8 = 141;
+    const RATED_BLEED_AIR_FLOW_KG_PER_S: f64 = 3.6;
 }
 
 pub struct ShutdownPw980Turbine {
@@ -54,8 +59,8 @@ impl Turbine for ShutdownPw980Turbine {
     fn update(
         mut self: Box<Self>,
         context: &UpdateContext,
-        _: bool,
-        _: bool,
+        _: ApuLoad,
+        _: &ApuFuelControlUnit,
         controller: &dyn ControllerSignal<TurbineSignal>,
     ) -> Box<dyn Turbine> {
         self.egt = calculate_towards_ambient_egt(self.egt, context);
@@ -284,8 +289,8 @@ impl Turbine for Starting {
     fn update(
         mut self: Box<Self>,
         context: &UpdateContext,
-        _: bool,
-        _: bool,
+        _: ApuLoad,
+        _: &ApuFuelControlUnit,
         controller: &dyn ControllerSignal<TurbineSignal>,
     ) -> Box<dyn Turbine> {
         self.since += context.delta();
@@ -303,7 +308,7 @@ impl Turbine for Starting {
             Some(TurbineSignal::StartOrContinue)
                 if { (self.n.get::<percent>() - 100.).abs() < f64::EPSILON } =>
             {
-                Box::new(Running::new(self.egt))
+                Box::new(Running::new(context, self.egt))
             }
             Some(TurbineSignal::StartOrContinue) => self,
         }
@@ -348,21 +353,17 @@ impl BleedAirUsageEgtDelta {
         }
     }
 
-    fn update(&mut self, context: &UpdateContext, apu_bleed_is_used: bool) {
-        self.target = if apu_bleed_is_used {
-            self.max
+    fn update(&mut self, context: &UpdateContext, bleed_air_load: Ratio) {
+        self.target = self.min + (self.max - self.min) * bleed_air_load.get::<ratio>();
+
+        // As the target depends on the bleed air load, make sure not to overshoot it.
+        let delta = self.delta_per_second() * context.delta_as_secs_f64();
+        self.current = if self.current > self.target {
+            (self.current - delta).max(self.target)
         } else {
-            self.min
+            (self.current + delta).min(self.target)
         };
 
-        if (self.current - self.target).abs() > f64::EPSILON {
-            if self.current > self.target {
-                self.current -= self.delta_per_second() * context.delta_as_secs_f64();
-            } else {
-                self.current += self.delta_per_second() * context.delta_as_secs_f64();
-            }
-        }
-
         self.current = self.current.clamp(self.min, self.max);
     }
 
@@ -415,14 +416,16 @@ impl ApuGenUsageEgtDelta {
         }
     }
 
-    fn update(&mut self, context: &UpdateContext, apu_gen_is_used: bool) {
-        self.time = if apu_gen_is_used {
-            (self.time + context.delta()).min(Duration::from_secs(
-                ApuGenUsageEgtDelta::SECONDS_TO_REACH_TARGET,
-            ))
+    fn update(&mut self, context: &UpdateContext, electrical_load: Ratio) {
+        // At the rated generator output the full EGT increase is reached.
+        let target =
+            ApuGenUsageEgtDelta::SECONDS_TO_REACH_TARGET as f64 * electrical_load.get::<ratio>();
+        let time = self.time.as_secs_f64();
+        self.time = Duration::from_secs_f64(if time < target {
+            (time + context.delta_as_secs_f64()).min(target)
         } else {
-            Duration::from_secs_f64((self.time.as_secs_f64() - context.delta_as_secs_f64()).max(0.))
-        };
+            (time - context.delta_as_secs_f64()).max(target)
+        });
     }
 
     fn egt_delta(&self) -> TemperatureInterval {
@@ -450,14 +453,16 @@ impl ApuBleedUsageN2Delta {
         }
     }
 
-    fn update(&mut self, context: &UpdateContext, apu_bleed_is_used: bool) {
-        self.time = if apu_bleed_is_used {
-            (self.time + context.delta()).min(Duration::from_secs(
-                ApuBleedUsageN2Delta::SECONDS_TO_REACH_TARGET,
-            ))
+    fn update(&mut self, context: &UpdateContext, bleed_air_load: Ratio) {
+        // At the rated bleed air flow the full N2 increase is reached.
+        let target =
+            ApuBleedUsageN2Delta::SECONDS_TO_REACH_TARGET as f64 * bleed_air_load.get::<ratio>();
+        let time = self.time.as_secs_f64();
+        self.time = Duration::from_secs_f64(if time < target {
+            (time + context.delta_as_secs_f64()).min(target)
         } else {
-            Duration::from_secs_f64((self.time.as_secs_f64() - context.delta_as_secs_f64()).max(0.))
-        };
+            (time - context.delta_as_secs_f64()).max(target)
+        });
     }
 
     fn n2_delta(&self) -> Ratio {
@@ -471,55 +476,68 @@ struct Running {
     base_egt_deviation: TemperatureInterval,
     bleed_air_usage: BleedAirUsageEgtDelta,
     apu_gen_usage: ApuGenUsageEgtDelta,
+    over_fuelling: OverFuellingEgtDelta,
     n2: Ratio,
     bleed_air_n2_delta: ApuBleedUsageN2Delta,
+    speed: GovernedSpeed,
 }
 impl Running {
-    fn new(egt: ThermodynamicTemperature) -> Running {
+    fn new(context: &UpdateContext, egt: ThermodynamicTemperature) -> Running {
         let base_egt = 480. + ((random_number() % 11) as f64);
+        let uncorrected_egt = ThermodynamicTemperature::new::<kelvin>(
+            egt.get::<kelvin>() / hot_day_egt_factor(context),
+        );
         Running {
             egt,
             base_egt: ThermodynamicTemperature::new::<degree_celsius>(base_egt),
-            // This contains the deviation from the base EGT at the moment of entering the running state.
+            // This contains the deviation from the base EGT at the moment of entering the running state,
+            // determined from the EGT without the hot day correction.
             base_egt_deviation: TemperatureInterval::new::<temperature_interval::degree_celsius>(
-                egt.get::<degree_celsius>() - base_egt,
+                uncorrected_egt.get::<degree_celsius>() - base_egt,
             ),
             bleed_air_usage: BleedAirUsageEgtDelta::new(),
             apu_gen_usage: ApuGenUsageEgtDelta::new(),
+            over_fuelling: OverFuellingEgtDelta::new(),
             n2: Ratio::default(),
             bleed_air_n2_delta: ApuBleedUsageN2Delta::new(),
+            speed: GovernedSpeed::new(),
         }
     }
 
     fn calculate_egt(
         &mut self,
         context: &UpdateContext,
-        apu_gen_is_used: bool,
-        apu_bleed_is_used: bool,
+        load: ApuLoad,
+        fuel_control_unit: &ApuFuelControlUnit,
     ) -> ThermodynamicTemperature {
         // Reduce the deviation by 1 per second to slowly creep back to normal temperatures
+        let deviation = self
+            .base_egt_deviation
+            .get::<temperature_interval::degree_celsius>();
         self.base_egt_deviation -= TemperatureInterval::new::<temperature_interval::degree_celsius>(
-            (context.delta_as_secs_f64() * 1.).min(
-                self.base_egt_deviation
-                    .get::<temperature_interval::degree_celsius>(),
-            ),
+            (context.delta_as_secs_f64() * 1.).min(deviation.abs()) * deviation.signum(),
         );
 
         let mut target = self.base_egt + self.base_egt_deviation;
-        self.apu_gen_usage.update(context, apu_gen_is_used);
+        self.apu_gen_usage.update(context, load.electrical());
         target += self.apu_gen_usage.egt_delta();
 
-        self.bleed_air_usage.update(context, apu_bleed_is_used);
+        self.bleed_air_usage.update(context, load.bleed_air());
         target += self.bleed_air_usage.egt_delta();
 
-        target
+        self.over_fuelling.update(context, fuel_control_unit);
+        target += self.over_fuelling.egt_delta();
+
+        ThermodynamicTemperature::new::<kelvin>(
+            target.get::<kelvin>() * hot_day_egt_factor(context),
+        )
     }
 
-    fn calculate_n2(&mut self, context: &UpdateContext, apu_bleed_is_used: bool) -> Ratio {
+    fn calculate_n2(&mut self, context: &UpdateContext, bleed_air_load: Ratio) -> Ratio {
         // Base N2 is 85%
         let mut target = Ratio::new::<percent>(85.);
 
-        self.bleed_air_n2_delta.update(context, apu_bleed_is_used);
+        self.bleed_air_n2_delta.update(context, bleed_air_load);
         target += self.bleed_air_n2_delta.n2_delta();
 
         target
@@ -529,25 +547,24 @@ impl Turbine for Running {
     fn update(
         mut self: Box<Self>,
         context: &UpdateContext,
-        apu_bleed_is_used: bool,
-        apu_gen_is_used: bool,
+        load: ApuLoad,
+        fuel_control_unit: &ApuFuelControlUnit,
         controller: &dyn ControllerSignal<TurbineSignal>,
     ) -> Box<dyn Turbine> {
-        self.egt = self.calculate_egt(context, apu_gen_is_used, apu_bleed_is_used);
-        self.n2 = self.calculate_n2(context, apu_bleed_is_used);
+        self.egt = self.calculate_egt(context, load, fuel_control_unit);
+        self.n2 = self.calculate_n2(context, load.bleed_air());
+        self.speed.update(context, load, fuel_control_unit);
 
         match controller.signal() {
             Some(TurbineSignal::StartOrContinue) => self,
-            Some(TurbineSignal::Stop) | None => Box::new(Stopping::new(
-                self.egt,
-                Ratio::new::<percent>(100.),
-                self.n2(),
-            )),
+            Some(TurbineSignal::Stop) | None => {
+                Box::new(Stopping::new(self.egt, self.n(), self.n2()))
+            }
         }
     }
 
     fn n(&self) -> Ratio {
-        Ratio::new::<percent>(100.)
+        self.speed.n()
     }
 
     fn n2(&self) -> Ratio {
@@ -747,8 +764,8 @@ impl Turbine for Stopping {
     fn update(
         mut self: Box<Self>,
         context: &UpdateContext,
-        _: bool,
-        _: bool,
+        _: ApuLoad,
+        _: &ApuFuelControlUnit,
         _: &dyn ControllerSignal<TurbineSignal>,
     ) -> Box<dyn Turbine> {
         self.since += context.delta();
@@ -1116,7 +1133,8 @@ mod apu_generator_tests {
 
     #[test]
     fn in_normal_conditions_when_n_100_frequency_400() {
-        let mut test_bed = test_bed_with().running_apu();
+        // Let the speed recover from the bleed air load applied once the APU is available
+        let mut test_bed = test_bed_with().running_apu().run(Duration::from_secs(5));
 
         for _ in 0..100 {
             test_bed = test_bed.run(Duration::from_millis(50));
@@ -139,6 +139,17 @@ impl<Drive: EngineGeneratorDrive> EngineGenerator<Drive> {
     pub fn is_drive_connected(&self) -> bool {
         self.drive.is_connected()
     }
+
+    /// Mechanical power drawn from the engine accessory gearbox to supply the generator load.
+    pub fn shaft_power_extraction(&self) -> Power {
+        const GENERATOR_EFFICIENCY: f64 = 0.85;
+
+        if self.should_provide_output() && self.drive.is_connected() {
+            self.max_true_power * self.load / GENERATOR_EFFICIENCY
+        } else {
+            Power::default()
+        }
+    }
 }
 impl<Drive: EngineGeneratorDrive> ElectricitySource for EngineGenerator<Drive> {
     fn output_potential(&self) -> Potential {
@@ -8,9 +8,13 @@ use crate::{
 };
 
 pub mod leap_engine;
+pub mod native_engine;
+pub mod oil_system;
 pub mod reverser;
 pub mod reverser_thrust;
+pub mod start_controller;
 pub mod trent_engine;
+pub mod vibration_monitoring;
 
 pub trait Engine: EngineCorrectedN2 + EngineUncorrectedN2 + EngineCorrectedN1 {
     fn hydraulic_pump_output_speed(&self) -> AngularVelocity;
@@ -20,6 +24,11 @@ pub trait Engine: EngineCorrectedN2 + EngineUncorrectedN2 + EngineCorrectedN1 {
     fn gearbox_speed(&self) -> AngularVelocity;
 }
 
+pub trait EngineVibration {
+    /// Highest spool vibration sensed by the engine vibration monitoring unit, in vibration units
+    fn vibration(&self) -> f64;
+}
+
 use std::convert::TryInto;
 pub struct EngineFireOverheadPanel<const N: usize> {
     engine_fire_push_buttons: [FirePushButton; N],
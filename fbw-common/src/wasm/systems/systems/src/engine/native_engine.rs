@@ -0,0 +1,1078 @@
+use std::time::Duration;
+
+use uom::si::{
+    angle::degree,
+    angular_velocity::revolution_per_minute,
+    f64::*,
+    mass::pound,
+    mass_rate::{kilogram_per_second, pound_per_hour},
+    power::watt,
+    pressure::psi,
+    ratio::{percent, ratio},
+    thermodynamic_temperature::{degree_celsius, kelvin},
+    torque::newton_meter,
+    velocity::knot,
+    volume::quart_liquid,
+};
+
+use crate::{
+    shared::{
+        calculate_towards_target_temperature, interpolation, low_pass_filter::LowPassFilter,
+        EngineCorrectedN1, EngineCorrectedN2, EngineExhaustGasTemperature, EngineUncorrectedN2,
+    },
+    simulation::{
+        InitContext, Read, SimulationElement, SimulatorReader, UpdateContext, VariableIdentifier,
+    },
+};
+
+use super::Engine;
+
+/// Defines a turbofan for the native engine model by:
+///     spool maps: fan and intermediate spool speeds vs high pressure spool speed
+///     thrust lever map: corrected N1 demanded by the FADEC vs thrust lever angle
+///     performance: sea level static thrust, fuel flow and EGT at idle and take off
+///     drag torque of the core at the nominal starter cranking speed
+pub struct NativeEngineCharacteristics {
+    has_intermediate_pressure_spool: bool,
+
+    high_pressure_spool_100_percent_rpm: f64,
+    pump_gear_ratio: f64,
+    minimum_idle_n2_percent: f64,
+
+    high_pressure_spool_breakpoints_percent: [f64; 7],
+    low_pressure_spool_map_percent: [f64; 7],
+    intermediate_pressure_spool_map_percent: [f64; 7],
+
+    thrust_lever_map_n1_percent: [f64; 5],
+
+    max_static_thrust: Mass,
+    core_mass_flow_at_max_thrust: MassRate,
+    core_power_at_max_thrust: Power,
+
+    idle_fuel_flow: MassRate,
+    max_fuel_flow: MassRate,
+
+    idle_egt_rise_celsius: f64,
+    max_egt_rise_celsius: f64,
+
+    cranking_drag_torque: Torque,
+
+    oil_tank_quantity: Volume,
+}
+impl NativeEngineCharacteristics {
+    // Thrust lever angles of the A320 and A380 throttle detents:
+    // full reverse, idle, CL, FLX/MCT, TOGA
+    const THRUST_LEVER_ANGLE_BREAKPOINTS_DEGREES: [f64; 5] = [-20., 0., 25., 35., 45.];
+
+    // According to the Type Certificate Data Sheet of LEAP 1A26
+    // 100% N2 @ 16645 RPM
+    const LEAP_1A26_100_PERCENT_N2_RPM: f64 = 16645.;
+    // Gear ratio from primary gearbox input to EDP drive shaft
+    const LEAP_1A26_PUMP_N2_GEAR_RATIO: f64 = 0.211;
+
+    const LEAP_1A26_N2_BREAKPOINTS_PERCENT: [f64; 7] = [0., 25., 58.5, 78., 90., 97.5, 100.];
+    const LEAP_1A26_N1_MAP_PERCENT: [f64; 7] = [0., 5., 19.5, 50., 80., 97., 101.];
+
+    const LEAP_1A26_THRUST_LEVER_MAP_N1_PERCENT: [f64; 5] = [72., 19.5, 85., 92., 97.];
+
+    // 100% N1 @ 2900 RPM
+    // 100% N2 @ 8300 RPM
+    // 100% N3 @ 12200 RPM
+    const TRENT_972_100_PERCENT_N3_RPM: f64 = 12200.;
+    // TODO find real value, 0.31 is guessed to get the target 3775 pump rpm
+    const TRENT_972_PUMP_N3_GEAR_RATIO: f64 = 0.31;
+
+    const TRENT_972_N3_BREAKPOINTS_PERCENT: [f64; 7] = [0., 25., 62., 80., 91., 97., 100.];
+    const TRENT_972_N1_MAP_PERCENT: [f64; 7] = [0., 4., 20., 50., 80., 95., 99.];
+    const TRENT_972_N2_MAP_PERCENT: [f64; 7] = [0., 20., 57., 75., 88., 95., 98.];
+
+    const TRENT_972_THRUST_LEVER_MAP_N1_PERCENT: [f64; 5] = [75., 20., 84., 90., 95.];
+
+    const MIN_IDLE_N2_UNCORRECTED_THRESHOLD_PERCENT: f64 = 55.;
+
+    pub fn leap_1a26() -> Self {
+        Self {
+            has_intermediate_pressure_spool: false,
+
+            high_pressure_spool_100_percent_rpm: Self::LEAP_1A26_100_PERCENT_N2_RPM,
+            pump_gear_ratio: Self::LEAP_1A26_PUMP_N2_GEAR_RATIO,
+            minimum_idle_n2_percent: Self::MIN_IDLE_N2_UNCORRECTED_THRESHOLD_PERCENT,
+
+            high_pressure_spool_breakpoints_percent: Self::LEAP_1A26_N2_BREAKPOINTS_PERCENT,
+            low_pressure_spool_map_percent: Self::LEAP_1A26_N1_MAP_PERCENT,
+            // A two spool engine has no intermediate spool: it simply mirrors the core
+            intermediate_pressure_spool_map_percent: Self::LEAP_1A26_N2_BREAKPOINTS_PERCENT,
+
+            thrust_lever_map_n1_percent: Self::LEAP_1A26_THRUST_LEVER_MAP_N1_PERCENT,
+
+            max_static_thrust: Mass::new::<pound>(27120.),
+            core_mass_flow_at_max_thrust: MassRate::new::<kilogram_per_second>(32.),
+            core_power_at_max_thrust: Power::new::<watt>(22_000_000.),
+
+            idle_fuel_flow: MassRate::new::<pound_per_hour>(620.),
+            max_fuel_flow: MassRate::new::<pound_per_hour>(9500.),
+
+            idle_egt_rise_celsius: 535.,
+            max_egt_rise_celsius: 885.,
+
+            cranking_drag_torque: Torque::new::<newton_meter>(540.),
+
+            oil_tank_quantity: Volume::new::<quart_liquid>(17.),
+        }
+    }
+
+    pub fn trent_972() -> Self {
+        Self {
+            has_intermediate_pressure_spool: true,
+
+            high_pressure_spool_100_percent_rpm: Self::TRENT_972_100_PERCENT_N3_RPM,
+            pump_gear_ratio: Self::TRENT_972_PUMP_N3_GEAR_RATIO,
+            minimum_idle_n2_percent: Self::MIN_IDLE_N2_UNCORRECTED_THRESHOLD_PERCENT,
+
+            high_pressure_spool_breakpoints_percent: Self::TRENT_972_N3_BREAKPOINTS_PERCENT,
+            low_pressure_spool_map_percent: Self::TRENT_972_N1_MAP_PERCENT,
+            intermediate_pressure_spool_map_percent: Self::TRENT_972_N2_MAP_PERCENT,
+
+            thrust_lever_map_n1_percent: Self::TRENT_972_THRUST_LEVER_MAP_N1_PERCENT,
+
+            max_static_thrust: Mass::new::<pound>(76750.),
+            core_mass_flow_at_max_thrust: MassRate::new::<kilogram_per_second>(125.),
+            core_power_at_max_thrust: Power::new::<watt>(70_000_000.),
+
+            idle_fuel_flow: MassRate::new::<pound_per_hour>(1430.),
+            max_fuel_flow: MassRate::new::<pound_per_hour>(23100.),
+
+            idle_egt_rise_celsius: 455.,
+            max_egt_rise_celsius: 805.,
+
+            cranking_drag_torque: Torque::new::<newton_meter>(1080.),
+
+            oil_tank_quantity: Volume::new::<quart_liquid>(33.),
+        }
+    }
+
+    fn idle_n1(&self) -> Ratio {
+        Ratio::new::<percent>(self.thrust_lever_map_n1_percent[1])
+    }
+
+    fn max_n1(&self) -> Ratio {
+        Ratio::new::<percent>(self.thrust_lever_map_n1_percent[4])
+    }
+
+    fn idle_high_pressure_spool_speed(&self) -> Ratio {
+        self.high_pressure_spool_speed_for_n1(self.idle_n1())
+    }
+
+    fn commanded_n1(&self, thrust_lever_angle: Angle) -> Ratio {
+        Ratio::new::<percent>(interpolation(
+            &Self::THRUST_LEVER_ANGLE_BREAKPOINTS_DEGREES,
+            &self.thrust_lever_map_n1_percent,
+            thrust_lever_angle.get::<degree>(),
+        ))
+    }
+
+    fn high_pressure_spool_speed_for_n1(&self, n1: Ratio) -> Ratio {
+        Ratio::new::<percent>(interpolation(
+            &self.low_pressure_spool_map_percent,
+            &self.high_pressure_spool_breakpoints_percent,
+            n1.get::<percent>(),
+        ))
+    }
+
+    fn n1_for_high_pressure_spool_speed(&self, high_pressure_spool_speed: Ratio) -> Ratio {
+        Ratio::new::<percent>(interpolation(
+            &self.high_pressure_spool_breakpoints_percent,
+            &self.low_pressure_spool_map_percent,
+            high_pressure_spool_speed.get::<percent>(),
+        ))
+    }
+
+    fn intermediate_spool_speed_for_high_pressure_spool_speed(
+        &self,
+        high_pressure_spool_speed: Ratio,
+    ) -> Ratio {
+        Ratio::new::<percent>(interpolation(
+            &self.high_pressure_spool_breakpoints_percent,
+            &self.intermediate_pressure_spool_map_percent,
+            high_pressure_spool_speed.get::<percent>(),
+        ))
+    }
+}
+
+/// What the aircraft systems command from the engine and the loads they impose on it.
+/// `bleed_air_extraction` is the mass flow taken from the compressor by the pneumatic
+/// system. `shaft_power_extraction` is the mechanical power taken from the accessory
+/// gearbox, typically by the engine generator. `starter_torque` is the torque the air
+/// turbine starter applies to the high pressure spool.
+#[derive(Clone, Copy, Default)]
+pub struct NativeEngineInputs {
+    fuel_is_on: bool,
+    starter_torque: Torque,
+    bleed_air_extraction: MassRate,
+    shaft_power_extraction: Power,
+}
+impl NativeEngineInputs {
+    pub fn new(
+        fuel_is_on: bool,
+        starter_torque: Torque,
+        bleed_air_extraction: MassRate,
+        shaft_power_extraction: Power,
+    ) -> Self {
+        Self {
+            fuel_is_on,
+            starter_torque,
+            bleed_air_extraction,
+            shaft_power_extraction,
+        }
+    }
+}
+
+/// A rotating assembly of the engine which accelerates and decelerates towards
+/// a target speed with different time constants.
+struct EngineSpool {
+    speed: LowPassFilter<Ratio>,
+}
+impl EngineSpool {
+    fn new() -> Self {
+        Self {
+            speed: LowPassFilter::new(Duration::from_secs(1)),
+        }
+    }
+
+    fn update(
+        &mut self,
+        context: &UpdateContext,
+        target: Ratio,
+        acceleration_time_constant: Duration,
+        deceleration_time_constant: Duration,
+    ) {
+        self.speed
+            .set_time_constant(if target > self.speed.output() {
+                acceleration_time_constant
+            } else {
+                deceleration_time_constant
+            });
+        self.speed.update(context.delta(), target);
+    }
+
+    fn speed(&self) -> Ratio {
+        self.speed.output()
+    }
+}
+
+/// A native turbofan model, driven by the thrust lever and the loads the aircraft
+/// systems impose on the engine, instead of the simulator engine variables.
+///
+/// The FADEC schedules a corrected N1 from the thrust lever angle. The high pressure
+/// spool accelerates towards the core speed needed for this N1, plus the additional
+/// speed needed to supply the bleed air and gearbox power extracted from the engine.
+/// The other spools, fuel flow, EGT and thrust are derived from the spool speeds and
+/// the ambient conditions.
+///
+/// Without combustion, the starter motors the high pressure spool up to the speed at which
+/// its torque is balanced by the drag of the core, which grows with the square of the speed.
+/// Below idle, the starter also assists the acceleration of the lit engine.
+pub struct NativeEngine {
+    thrust_lever_angle_id: VariableIdentifier,
+    thrust_lever_angle: Angle,
+
+    characteristics: NativeEngineCharacteristics,
+
+    low_pressure_spool: EngineSpool,
+    intermediate_pressure_spool: EngineSpool,
+    high_pressure_spool: EngineSpool,
+
+    is_burning_fuel: bool,
+    extraction_ratio: Ratio,
+    temperature_ratio: f64,
+
+    fuel_flow: MassRate,
+    exhaust_gas_temperature: ThermodynamicTemperature,
+    net_thrust: Mass,
+
+    oil_pressure: Pressure,
+    oil_temperature: ThermodynamicTemperature,
+    oil_quantity: Volume,
+}
+impl NativeEngine {
+    const ISA_SEA_LEVEL_TEMPERATURE_KELVIN: f64 = 288.15;
+    const ISA_SEA_LEVEL_PRESSURE_PSI: f64 = 14.696;
+
+    // Spool time constants when accelerating from idle to take off thrust
+    const LOW_PRESSURE_SPOOL_ACCELERATION_TIME_CONSTANT: Duration = Duration::from_millis(1500);
+    const LOW_PRESSURE_SPOOL_DECELERATION_TIME_CONSTANT: Duration = Duration::from_millis(2500);
+    const HIGH_PRESSURE_SPOOL_ACCELERATION_TIME_CONSTANT: Duration = Duration::from_millis(1000);
+    const HIGH_PRESSURE_SPOOL_DECELERATION_TIME_CONSTANT: Duration = Duration::from_millis(2000);
+
+    // Below idle the compressor is far from its design point and spools are much slower,
+    // the time constant is divided by one plus the starter to cranking drag torque ratio
+    const SUB_IDLE_ACCELERATION_TIME_CONSTANT: Duration = Duration::from_secs(12);
+    const SPOOL_DOWN_TIME_CONSTANT: Duration = Duration::from_secs(10);
+
+    // Speed at which the core drag balances the cranking drag torque
+    const STARTER_CRANKING_SPEED_PERCENT: f64 = 26.;
+    const LIGHT_OFF_HIGH_PRESSURE_SPOOL_SPEED_PERCENT: f64 = 18.;
+    const WINDMILLING_N1_PERCENT_PER_KNOT: f64 = 0.1;
+    const WINDMILLING_HIGH_PRESSURE_SPOOL_TO_N1_RATIO: f64 = 0.4;
+
+    // Core speed increase needed to supply a full extraction ratio at a given N1
+    const EXTRACTION_HIGH_PRESSURE_SPOOL_SPEED_PERCENT: f64 = 10.;
+    const EXTRACTION_EGT_RISE_CELSIUS: f64 = 200.;
+    const MAX_EXTRACTION_RATIO: f64 = 0.5;
+    // Core mass flow never drops below this fraction of its take off value while running
+    const MIN_CORE_MASS_FLOW_RATIO: f64 = 0.08;
+
+    const RAM_DRAG_THRUST_LOSS_PER_MACH: f64 = 0.45;
+    const THRUST_N1_BREAKPOINTS_RATIO: [f64; 6] = [0., 0.2, 0.4, 0.6, 0.8, 1.];
+    const THRUST_MAP_RATIO: [f64; 6] = [0., 0.04, 0.15, 0.34, 0.63, 1.];
+
+    const EGT_HEATING_RATE_CELSIUS_PER_SECOND: f64 = 60.;
+    const EGT_COOLING_RATE_CELSIUS_PER_SECOND: f64 = 8.;
+
+    const LOW_OIL_PRESSURE_THRESHOLD_PSI: f64 = 18.;
+    const OIL_PRESSURE_PSI_PER_N2_PERCENT: f64 = 0.9;
+    const OIL_PRESSURE_REFERENCE_TEMPERATURE_CELSIUS: f64 = 70.;
+    const OIL_PRESSURE_LOSS_PER_CELSIUS: f64 = 0.003;
+    const OIL_TEMPERATURE_RISE_CELSIUS: f64 = 20.;
+    const OIL_TEMPERATURE_RISE_CELSIUS_PER_N2_PERCENT: f64 = 0.9;
+    const OIL_TEMPERATURE_RATE_CELSIUS_PER_SECOND: f64 = 0.5;
+    const OIL_CONSUMPTION_QUART_PER_HOUR: f64 = 0.25;
+
+    pub fn new(
+        context: &mut InitContext,
+        number: usize,
+        characteristics: NativeEngineCharacteristics,
+    ) -> Self {
+        let oil_quantity = characteristics.oil_tank_quantity;
+
+        Self {
+            thrust_lever_angle_id: context.get_identifier(format!("AUTOTHRUST_TLA:{}", number)),
+            thrust_lever_angle: Angle::default(),
+
+            characteristics,
+
+            low_pressure_spool: EngineSpool::new(),
+            intermediate_pressure_spool: EngineSpool::new(),
+            high_pressure_spool: EngineSpool::new(),
+
+            is_burning_fuel: false,
+            extraction_ratio: Ratio::default(),
+            temperature_ratio: 1.,
+
+            fuel_flow: MassRate::default(),
+            exhaust_gas_temperature: ThermodynamicTemperature::new::<degree_celsius>(15.),
+            net_thrust: Mass::default(),
+
+            oil_pressure: Pressure::default(),
+            oil_temperature: ThermodynamicTemperature::new::<degree_celsius>(15.),
+            oil_quantity,
+        }
+    }
+
+    pub fn update(&mut self, context: &UpdateContext, inputs: NativeEngineInputs) {
+        self.temperature_ratio =
+            context.ambient_temperature().get::<kelvin>() / Self::ISA_SEA_LEVEL_TEMPERATURE_KELVIN;
+
+        self.update_combustion(inputs.fuel_is_on);
+        self.update_extraction(
+            context,
+            inputs.bleed_air_extraction,
+            inputs.shaft_power_extraction,
+        );
+        self.update_spools(context, inputs.starter_torque);
+        self.update_fuel_flow(context);
+        self.update_exhaust_gas_temperature(context);
+        self.update_net_thrust(context);
+        self.update_oil(context);
+    }
+
+    fn update_combustion(&mut self, fuel_is_on: bool) {
+        self.is_burning_fuel = fuel_is_on
+            && (self.is_burning_fuel
+                || self.high_pressure_spool.speed()
+                    >= Ratio::new::<percent>(Self::LIGHT_OFF_HIGH_PRESSURE_SPOOL_SPEED_PERCENT));
+    }
+
+    fn update_extraction(
+        &mut self,
+        context: &UpdateContext,
+        bleed_air_extraction: MassRate,
+        shaft_power_extraction: Power,
+    ) {
+        let core_flow_ratio = if self.is_burning_fuel {
+            (self.corrected_n1().get::<ratio>() / self.characteristics.max_n1().get::<ratio>())
+                .powi(2)
+                .max(Self::MIN_CORE_MASS_FLOW_RATIO)
+                * self.pressure_ratio(context)
+        } else {
+            0.
+        };
+
+        self.extraction_ratio = if core_flow_ratio > 0. {
+            let bleed_ratio = bleed_air_extraction.get::<kilogram_per_second>()
+                / (self
+                    .characteristics
+                    .core_mass_flow_at_max_thrust
+                    .get::<kilogram_per_second>()
+                    * core_flow_ratio);
+            let shaft_power_ratio = shaft_power_extraction.get::<watt>()
+                / (self.characteristics.core_power_at_max_thrust.get::<watt>() * core_flow_ratio);
+
+            Ratio::new::<ratio>(
+                (bleed_ratio + shaft_power_ratio).clamp(0., Self::MAX_EXTRACTION_RATIO),
+            )
+        } else {
+            Ratio::default()
+        };
+    }
+
+    fn update_spools(&mut self, context: &UpdateContext, starter_torque: Torque) {
+        let square_root_temperature_ratio = self.temperature_ratio.sqrt();
+        let idle_high_pressure_spool_speed =
+            self.characteristics.idle_high_pressure_spool_speed() * square_root_temperature_ratio;
+        let windmilling_n1 = Ratio::new::<percent>(
+            context.true_airspeed().get::<knot>().max(0.) * Self::WINDMILLING_N1_PERCENT_PER_KNOT,
+        );
+        let starter_torque_ratio = (starter_torque / self.characteristics.cranking_drag_torque)
+            .get::<ratio>()
+            .max(0.);
+        let sub_idle_acceleration_time_constant =
+            Self::SUB_IDLE_ACCELERATION_TIME_CONSTANT.div_f64(1. + starter_torque_ratio);
+
+        let (high_pressure_spool_target, acceleration_time_constant) = if self.is_burning_fuel {
+            let commanded_high_pressure_spool_speed =
+                self.characteristics.high_pressure_spool_speed_for_n1(
+                    self.characteristics.commanded_n1(self.thrust_lever_angle),
+                ) * square_root_temperature_ratio
+                    + Ratio::new::<percent>(
+                        self.extraction_ratio.get::<ratio>()
+                            * Self::EXTRACTION_HIGH_PRESSURE_SPOOL_SPEED_PERCENT,
+                    );
+
+            (
+                commanded_high_pressure_spool_speed,
+                if self.high_pressure_spool.speed() < idle_high_pressure_spool_speed * 0.95 {
+                    sub_idle_acceleration_time_constant
+                } else {
+                    Self::HIGH_PRESSURE_SPOOL_ACCELERATION_TIME_CONSTANT
+                },
+            )
+        } else {
+            // The drag torque grows with the square of the speed
+            let cranking_speed = Ratio::new::<percent>(
+                Self::STARTER_CRANKING_SPEED_PERCENT * starter_torque_ratio.sqrt(),
+            );
+
+            (
+                cranking_speed
+                    .max(windmilling_n1 * Self::WINDMILLING_HIGH_PRESSURE_SPOOL_TO_N1_RATIO),
+                sub_idle_acceleration_time_constant,
+            )
+        };
+
+        self.high_pressure_spool.update(
+            context,
+            high_pressure_spool_target,
+            acceleration_time_constant,
+            if self.is_burning_fuel {
+                Self::HIGH_PRESSURE_SPOOL_DECELERATION_TIME_CONSTANT
+            } else {
+                Self::SPOOL_DOWN_TIME_CONSTANT
+            },
+        );
+
+        let corrected_high_pressure_spool_speed =
+            self.high_pressure_spool.speed() / square_root_temperature_ratio;
+
+        self.intermediate_pressure_spool.update(
+            context,
+            self.characteristics
+                .intermediate_spool_speed_for_high_pressure_spool_speed(
+                    corrected_high_pressure_spool_speed,
+                )
+                * square_root_temperature_ratio,
+            Self::HIGH_PRESSURE_SPOOL_ACCELERATION_TIME_CONSTANT,
+            Self::HIGH_PRESSURE_SPOOL_DECELERATION_TIME_CONSTANT,
+        );
+
+        self.low_pressure_spool.update(
+            context,
+            (self
+                .characteristics
+                .n1_for_high_pressure_spool_speed(corrected_high_pressure_spool_speed)
+                * square_root_temperature_ratio)
+                .max(windmilling_n1),
+            Self::LOW_PRESSURE_SPOOL_ACCELERATION_TIME_CONSTANT,
+            if self.is_burning_fuel {
+                Self::LOW_PRESSURE_SPOOL_DECELERATION_TIME_CONSTANT
+            } else {
+                Self::SPOOL_DOWN_TIME_CONSTANT
+            },
+        );
+    }
+
+    /// Position of the corrected N1 between idle and take off, 0 at idle and 1 at take off.
+    fn power_ratio(&self) -> f64 {
+        let idle_n1 = self.characteristics.idle_n1();
+
+        ((self.corrected_n1() - idle_n1) / (self.characteristics.max_n1() - idle_n1))
+            .get::<ratio>()
+            .clamp(0., 1.)
+    }
+
+    fn pressure_ratio(&self, context: &UpdateContext) -> f64 {
+        context.ambient_pressure().get::<psi>() / Self::ISA_SEA_LEVEL_PRESSURE_PSI
+    }
+
+    fn update_fuel_flow(&mut self, context: &UpdateContext) {
+        self.fuel_flow = if self.is_burning_fuel {
+            let idle_fuel_flow = self.characteristics.idle_fuel_flow;
+            let sub_idle_ratio = (self.high_pressure_spool.speed()
+                / (self.characteristics.idle_high_pressure_spool_speed()
+                    * self.temperature_ratio.sqrt()))
+            .get::<ratio>()
+            .min(1.);
+
+            (idle_fuel_flow * sub_idle_ratio
+                + (self.characteristics.max_fuel_flow - idle_fuel_flow)
+                    * self.power_ratio().powf(1.5))
+                * self.pressure_ratio(context)
+                * self.temperature_ratio.sqrt()
+                * (1. + self.extraction_ratio.get::<ratio>())
+        } else {
+            MassRate::default()
+        };
+    }
+
+    fn update_exhaust_gas_temperature(&mut self, context: &UpdateContext) {
+        let (target, rate) = if self.is_burning_fuel {
+            let egt_rise = self.characteristics.idle_egt_rise_celsius
+                + (self.characteristics.max_egt_rise_celsius
+                    - self.characteristics.idle_egt_rise_celsius)
+                    * self.power_ratio()
+                + self.extraction_ratio.get::<ratio>() * Self::EXTRACTION_EGT_RISE_CELSIUS;
+
+            (
+                ThermodynamicTemperature::new::<kelvin>(
+                    self.temperature_ratio * (Self::ISA_SEA_LEVEL_TEMPERATURE_KELVIN + egt_rise),
+                ),
+                Self::EGT_HEATING_RATE_CELSIUS_PER_SECOND,
+            )
+        } else {
+            (
+                context.ambient_temperature(),
+                Self::EGT_COOLING_RATE_CELSIUS_PER_SECOND,
+            )
+        };
+
+        self.exhaust_gas_temperature = calculate_towards_target_temperature(
+            self.exhaust_gas_temperature,
+            target,
+            rate,
+            context.delta(),
+        );
+    }
+
+    fn update_net_thrust(&mut self, context: &UpdateContext) {
+        let n1_ratio = (self.corrected_n1() / self.characteristics.max_n1()).get::<ratio>();
+        let ram_drag_ratio =
+            (1. - context.mach_number().0 * Self::RAM_DRAG_THRUST_LOSS_PER_MACH).max(0.);
+
+        self.net_thrust = if self.is_burning_fuel {
+            self.characteristics.max_static_thrust
+                * interpolation(
+                    &Self::THRUST_N1_BREAKPOINTS_RATIO,
+                    &Self::THRUST_MAP_RATIO,
+                    n1_ratio,
+                )
+                * self.pressure_ratio(context)
+                * ram_drag_ratio
+        } else {
+            Mass::default()
+        };
+    }
+
+    fn update_oil(&mut self, context: &UpdateContext) {
+        let n2_percent = self.high_pressure_spool.speed().get::<percent>();
+
+        let oil_temperature_target = if n2_percent > 1. {
+            ThermodynamicTemperature::new::<degree_celsius>(
+                context.ambient_temperature().get::<degree_celsius>()
+                    + Self::OIL_TEMPERATURE_RISE_CELSIUS
+                    + n2_percent * Self::OIL_TEMPERATURE_RISE_CELSIUS_PER_N2_PERCENT,
+            )
+        } else {
+            context.ambient_temperature()
+        };
+        self.oil_temperature = calculate_towards_target_temperature(
+            self.oil_temperature,
+            oil_temperature_target,
+            Self::OIL_TEMPERATURE_RATE_CELSIUS_PER_SECOND,
+            context.delta(),
+        );
+
+        // Hot oil is less viscous which lowers the pressure delivered by the pump
+        let viscosity_factor = (1.
+            - (self.oil_temperature.get::<degree_celsius>()
+                - Self::OIL_PRESSURE_REFERENCE_TEMPERATURE_CELSIUS)
+                * Self::OIL_PRESSURE_LOSS_PER_CELSIUS)
+            .clamp(0.7, 1.15);
+        self.oil_pressure = Pressure::new::<psi>(
+            n2_percent * Self::OIL_PRESSURE_PSI_PER_N2_PERCENT * viscosity_factor,
+        );
+
+        if self.is_burning_fuel {
+            self.oil_quantity = (self.oil_quantity
+                - Volume::new::<quart_liquid>(
+                    Self::OIL_CONSUMPTION_QUART_PER_HOUR * context.delta_as_secs_f64() / 3600.,
+                ))
+            .max(Volume::default());
+        }
+    }
+
+    pub fn is_burning_fuel(&self) -> bool {
+        self.is_burning_fuel
+    }
+
+    pub fn uncorrected_n1(&self) -> Ratio {
+        self.low_pressure_spool.speed()
+    }
+
+    /// The high pressure spool speed of a three spool engine.
+    /// Returns `None` for a two spool engine, whose high pressure spool is N2.
+    pub fn uncorrected_n3(&self) -> Option<Ratio> {
+        if self.characteristics.has_intermediate_pressure_spool {
+            Some(self.high_pressure_spool.speed())
+        } else {
+            None
+        }
+    }
+
+    pub fn fuel_flow(&self) -> MassRate {
+        self.fuel_flow
+    }
+
+    pub fn oil_pressure(&self) -> Pressure {
+        self.oil_pressure
+    }
+
+    pub fn oil_temperature(&self) -> ThermodynamicTemperature {
+        self.oil_temperature
+    }
+
+    pub fn oil_quantity(&self) -> Volume {
+        self.oil_quantity
+    }
+
+    fn high_pressure_spool_speed(&self) -> AngularVelocity {
+        AngularVelocity::new::<revolution_per_minute>(
+            self.high_pressure_spool.speed().get::<ratio>()
+                * self.characteristics.high_pressure_spool_100_percent_rpm,
+        )
+    }
+}
+impl SimulationElement for NativeEngine {
+    fn read(&mut self, reader: &mut SimulatorReader) {
+        self.thrust_lever_angle = reader.read(&self.thrust_lever_angle_id);
+    }
+}
+impl EngineCorrectedN1 for NativeEngine {
+    fn corrected_n1(&self) -> Ratio {
+        self.low_pressure_spool.speed() / self.temperature_ratio.sqrt()
+    }
+}
+impl EngineCorrectedN2 for NativeEngine {
+    fn corrected_n2(&self) -> Ratio {
+        self.uncorrected_n2() / self.temperature_ratio.sqrt()
+    }
+}
+impl EngineUncorrectedN2 for NativeEngine {
+    fn uncorrected_n2(&self) -> Ratio {
+        if self.characteristics.has_intermediate_pressure_spool {
+            self.intermediate_pressure_spool.speed()
+        } else {
+            self.high_pressure_spool.speed()
+        }
+    }
+}
+impl EngineExhaustGasTemperature for NativeEngine {
+    fn exhaust_gas_temperature(&self) -> ThermodynamicTemperature {
+        self.exhaust_gas_temperature
+    }
+}
+impl Engine for NativeEngine {
+    fn hydraulic_pump_output_speed(&self) -> AngularVelocity {
+        self.high_pressure_spool_speed() * self.characteristics.pump_gear_ratio
+    }
+
+    fn oil_pressure_is_low(&self) -> bool {
+        self.oil_pressure.get::<psi>() < Self::LOW_OIL_PRESSURE_THRESHOLD_PSI
+    }
+
+    fn is_above_minimum_idle(&self) -> bool {
+        self.uncorrected_n2() >= Ratio::new::<percent>(self.characteristics.minimum_idle_n2_percent)
+    }
+
+    fn net_thrust(&self) -> Mass {
+        self.net_thrust
+    }
+
+    fn gearbox_speed(&self) -> AngularVelocity {
+        self.high_pressure_spool_speed()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::simulation::{
+        test::{SimulationTestBed, TestBed, WriteByName},
+        Aircraft, SimulationElementVisitor,
+    };
+    use uom::si::pressure::inch_of_mercury;
+
+    struct TestAircraft {
+        engine: NativeEngine,
+        fuel_is_on: bool,
+        starter_torque: Torque,
+        bleed_air_extraction: MassRate,
+        shaft_power_extraction: Power,
+    }
+    impl TestAircraft {
+        fn new(context: &mut InitContext, characteristics: NativeEngineCharacteristics) -> Self {
+            Self {
+                engine: NativeEngine::new(context, 1, characteristics),
+                fuel_is_on: false,
+                starter_torque: Torque::default(),
+                bleed_air_extraction: MassRate::default(),
+                shaft_power_extraction: Power::default(),
+            }
+        }
+    }
+    impl Aircraft for TestAircraft {
+        fn update_after_power_distribution(&mut self, context: &UpdateContext) {
+            self.engine.update(
+                context,
+                NativeEngineInputs::new(
+                    self.fuel_is_on,
+                    self.starter_torque,
+                    self.bleed_air_extraction,
+                    self.shaft_power_extraction,
+                ),
+            );
+        }
+    }
+    impl SimulationElement for TestAircraft {
+        fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
+            self.engine.accept(visitor);
+
+            visitor.visit(self);
+        }
+    }
+
+    struct NativeEngineTestBed {
+        test_bed: SimulationTestBed<TestAircraft>,
+    }
+    impl NativeEngineTestBed {
+        fn new(characteristics: fn() -> NativeEngineCharacteristics) -> Self {
+            let mut test_bed = Self {
+                test_bed: SimulationTestBed::new(|context| {
+                    TestAircraft::new(context, characteristics())
+                }),
+            };
+            test_bed.set_on_ground(true);
+            test_bed.set_indicated_airspeed(Velocity::new::<knot>(0.));
+            test_bed.set_true_airspeed(Velocity::new::<knot>(0.));
+            test_bed.set_ambient_temperature(ThermodynamicTemperature::new::<degree_celsius>(15.));
+            test_bed.set_ambient_pressure(Pressure::new::<inch_of_mercury>(29.92));
+
+            test_bed.thrust_lever_angle(Angle::new::<degree>(0.))
+        }
+
+        fn leap() -> Self {
+            Self::new(NativeEngineCharacteristics::leap_1a26)
+        }
+
+        fn trent() -> Self {
+            Self::new(NativeEngineCharacteristics::trent_972)
+        }
+
+        fn and_run_for(mut self, duration: Duration) -> Self {
+            let steps = (duration.as_secs_f64() / 0.1).ceil() as usize;
+            for _ in 0..steps {
+                self.run_with_delta(Duration::from_millis(100));
+            }
+
+            self
+        }
+
+        fn thrust_lever_angle(mut self, angle: Angle) -> Self {
+            self.write_by_name("AUTOTHRUST_TLA:1", angle);
+            self
+        }
+
+        fn starter_torque_ratio(mut self, torque_ratio: f64) -> Self {
+            self.command(|a| {
+                a.starter_torque = a.engine.characteristics.cranking_drag_torque * torque_ratio
+            });
+            self
+        }
+
+        fn started(mut self) -> Self {
+            self = self.starter_torque_ratio(1.);
+            self = self.and_run_for(Duration::from_secs(20));
+            self.command(|a| a.fuel_is_on = true);
+            self = self.and_run_for(Duration::from_secs(60));
+            self = self.starter_torque_ratio(0.);
+
+            self.and_run_for(Duration::from_secs(10))
+        }
+
+        fn fuel_off(mut self) -> Self {
+            self.command(|a| a.fuel_is_on = false);
+            self
+        }
+
+        fn bleed_air_extraction(mut self, flow: MassRate) -> Self {
+            self.command(|a| a.bleed_air_extraction = flow);
+            self
+        }
+
+        fn shaft_power_extraction(mut self, power: Power) -> Self {
+            self.command(|a| a.shaft_power_extraction = power);
+            self
+        }
+
+        fn n1(&self) -> Ratio {
+            self.query(|a| a.engine.corrected_n1())
+        }
+
+        fn n2(&self) -> Ratio {
+            self.query(|a| a.engine.uncorrected_n2())
+        }
+
+        fn n3(&self) -> Option<Ratio> {
+            self.query(|a| a.engine.uncorrected_n3())
+        }
+
+        fn egt(&self) -> ThermodynamicTemperature {
+            self.query(|a| a.engine.exhaust_gas_temperature())
+        }
+
+        fn fuel_flow(&self) -> MassRate {
+            self.query(|a| a.engine.fuel_flow())
+        }
+
+        fn net_thrust(&self) -> Mass {
+            self.query(|a| a.engine.net_thrust())
+        }
+
+        fn is_above_minimum_idle(&self) -> bool {
+            self.query(|a| a.engine.is_above_minimum_idle())
+        }
+
+        fn oil_pressure_is_low(&self) -> bool {
+            self.query(|a| a.engine.oil_pressure_is_low())
+        }
+
+        fn oil_quantity(&self) -> Volume {
+            self.query(|a| a.engine.oil_quantity())
+        }
+    }
+    impl TestBed for NativeEngineTestBed {
+        type Aircraft = TestAircraft;
+
+        fn test_bed(&self) -> &SimulationTestBed<TestAircraft> {
+            &self.test_bed
+        }
+
+        fn test_bed_mut(&mut self) -> &mut SimulationTestBed<TestAircraft> {
+            &mut self.test_bed
+        }
+    }
+
+    #[test]
+    fn engine_without_fuel_stays_shut_down() {
+        let test_bed = NativeEngineTestBed::leap().and_run_for(Duration::from_secs(30));
+
+        assert!(test_bed.n2().get::<percent>() < 1.);
+        assert!(!test_bed.is_above_minimum_idle());
+        assert!(test_bed.oil_pressure_is_low());
+        assert!(test_bed.fuel_flow() == MassRate::default());
+        assert!(test_bed.net_thrust() == Mass::default());
+    }
+
+    #[test]
+    fn cranking_without_fuel_does_not_light_off() {
+        let test_bed = NativeEngineTestBed::leap()
+            .starter_torque_ratio(1.)
+            .and_run_for(Duration::from_secs(60));
+
+        assert!(test_bed.n2().get::<percent>() > 20.);
+        assert!(!test_bed.is_above_minimum_idle());
+        assert!(test_bed.egt().get::<degree_celsius>() < 20.);
+    }
+
+    #[test]
+    fn low_starter_torque_cranks_the_engine_slower() {
+        let nominal = NativeEngineTestBed::leap()
+            .starter_torque_ratio(1.)
+            .and_run_for(Duration::from_secs(60));
+        let low = NativeEngineTestBed::leap()
+            .starter_torque_ratio(0.5)
+            .and_run_for(Duration::from_secs(60));
+
+        assert!((nominal.n2().get::<percent>() - 26.).abs() < 1.);
+        assert!(low.n2() < nominal.n2() * 0.8);
+    }
+
+    #[test]
+    fn starter_assists_the_acceleration_to_idle() {
+        let mut assisted = NativeEngineTestBed::leap()
+            .starter_torque_ratio(1.)
+            .and_run_for(Duration::from_secs(20));
+        assisted.command(|a| a.fuel_is_on = true);
+        let assisted = assisted.and_run_for(Duration::from_secs(5));
+
+        let mut unassisted = NativeEngineTestBed::leap()
+            .starter_torque_ratio(1.)
+            .and_run_for(Duration::from_secs(20))
+            .starter_torque_ratio(0.);
+        unassisted.command(|a| a.fuel_is_on = true);
+        let unassisted = unassisted.and_run_for(Duration::from_secs(5));
+
+        assert!(assisted.n2() > unassisted.n2());
+    }
+
+    #[test]
+    fn started_engine_stabilises_at_idle() {
+        let test_bed = NativeEngineTestBed::leap().started();
+
+        assert!(test_bed.is_above_minimum_idle());
+        assert!(!test_bed.oil_pressure_is_low());
+        assert!((test_bed.n1().get::<percent>() - 19.5).abs() < 1.);
+        assert!(test_bed.egt().get::<degree_celsius>() > 450.);
+        assert!(test_bed.fuel_flow() > MassRate::default());
+    }
+
+    #[test]
+    fn toga_thrust_lever_accelerates_engine() {
+        let mut test_bed = NativeEngineTestBed::leap().started();
+        let idle_thrust = test_bed.net_thrust();
+        let idle_fuel_flow = test_bed.fuel_flow();
+
+        test_bed = test_bed
+            .thrust_lever_angle(Angle::new::<degree>(45.))
+            .and_run_for(Duration::from_secs(10));
+
+        assert!((test_bed.n1().get::<percent>() - 97.).abs() < 1.);
+        assert!(test_bed.net_thrust() > idle_thrust * 10.);
+        assert!(test_bed.fuel_flow() > idle_fuel_flow * 5.);
+        assert!(test_bed.egt().get::<degree_celsius>() > 850.);
+    }
+
+    #[test]
+    fn bleed_air_extraction_raises_egt_and_fuel_flow() {
+        let test_bed = NativeEngineTestBed::leap().started();
+        let egt = test_bed.egt();
+        let fuel_flow = test_bed.fuel_flow();
+        let n2 = test_bed.n2();
+
+        let test_bed = test_bed
+            .bleed_air_extraction(MassRate::new::<kilogram_per_second>(0.5))
+            .and_run_for(Duration::from_secs(10));
+
+        assert!(test_bed.egt() > egt);
+        assert!(test_bed.fuel_flow() > fuel_flow);
+        assert!(test_bed.n2() > n2);
+    }
+
+    #[test]
+    fn generator_load_raises_fuel_flow() {
+        let test_bed = NativeEngineTestBed::leap().started();
+        let fuel_flow = test_bed.fuel_flow();
+
+        let test_bed = test_bed
+            .shaft_power_extraction(Power::new::<watt>(90_000.))
+            .and_run_for(Duration::from_secs(10));
+
+        assert!(test_bed.fuel_flow() > fuel_flow);
+    }
+
+    #[test]
+    fn less_thrust_is_produced_at_altitude() {
+        let mut test_bed = NativeEngineTestBed::leap()
+            .started()
+            .thrust_lever_angle(Angle::new::<degree>(45.))
+            .and_run_for(Duration::from_secs(10));
+        let sea_level_thrust = test_bed.net_thrust();
+
+        test_bed.set_ambient_pressure(Pressure::new::<inch_of_mercury>(20.58));
+        test_bed = test_bed.and_run_for(Duration::from_secs(10));
+
+        assert!(test_bed.net_thrust() < sea_level_thrust * 0.8);
+    }
+
+    #[test]
+    fn hot_day_raises_egt() {
+        let mut test_bed = NativeEngineTestBed::leap()
+            .started()
+            .thrust_lever_angle(Angle::new::<degree>(45.))
+            .and_run_for(Duration::from_secs(10));
+        let isa_egt = test_bed.egt();
+
+        test_bed.set_ambient_temperature(ThermodynamicTemperature::new::<degree_celsius>(40.));
+        test_bed = test_bed.and_run_for(Duration::from_secs(10));
+
+        assert!(test_bed.egt() > isa_egt);
+    }
+
+    #[test]
+    fn fuel_cut_off_spools_engine_down() {
+        let test_bed = NativeEngineTestBed::leap()
+            .started()
+            .fuel_off()
+            .and_run_for(Duration::from_secs(60));
+
+        assert!(!test_bed.is_above_minimum_idle());
+        assert!(test_bed.fuel_flow() == MassRate::default());
+        assert!(test_bed.oil_pressure_is_low());
+    }
+
+    #[test]
+    fn engine_windmills_in_flight_without_fuel() {
+        let mut test_bed = NativeEngineTestBed::leap();
+        test_bed.set_on_ground(false);
+        test_bed.set_true_airspeed(Velocity::new::<knot>(300.));
+        test_bed = test_bed.and_run_for(Duration::from_secs(60));
+
+        assert!(test_bed.n1().get::<percent>() > 20.);
+        assert!(!test_bed.is_above_minimum_idle());
+    }
+
+    #[test]
+    fn running_engine_consumes_oil() {
+        let test_bed = NativeEngineTestBed::leap();
+        let initial_quantity = test_bed.oil_quantity();
+
+        let test_bed = test_bed.started();
+
+        assert!(test_bed.oil_quantity() < initial_quantity);
+    }
+
+    #[test]
+    fn two_spool_engine_has_no_n3() {
+        let test_bed = NativeEngineTestBed::leap().started();
+
+        assert!(test_bed.n3().is_none());
+    }
+
+    #[test]
+    fn three_spool_engine_high_pressure_spool_is_faster_than_intermediate_spool() {
+        let test_bed = NativeEngineTestBed::trent().started();
+
+        assert!(test_bed.is_above_minimum_idle());
+        assert!(test_bed.n3().unwrap() > test_bed.n2());
+        assert!((test_bed.n1().get::<percent>() - 20.).abs() < 1.);
+    }
+}
@@ -0,0 +1,675 @@
+use std::time::Duration;
+
+use uom::si::{
+    f64::*,
+    mass_rate::kilogram_per_second,
+    power::watt,
+    pressure::psi,
+    ratio::{percent, ratio},
+    thermodynamic_temperature::{degree_celsius, kelvin},
+    volume::quart_liquid,
+};
+
+use crate::{
+    failures::{Failure, FailureType},
+    fuel::FUEL_SPECIFIC_HEAT_JOULE_PER_KILOGRAM_KELVIN,
+    shared::interpolation,
+    simulation::{
+        InitContext, SimulationElement, SimulationElementVisitor, SimulatorWriter, UpdateContext,
+        VariableIdentifier, Write,
+    },
+};
+
+/// Defines the oil system of an engine by:
+///     tank quantity and the quantity held by the lubrication circuit when the engine runs
+///     oil consumption
+///     oil pump delivery pressure vs high pressure spool speed
+///     heat rejected into the oil by bearings and gearboxes, and the thermal inertia of the oil
+pub struct EngineOilSystemCharacteristics {
+    tank_full_quantity: Volume,
+    circuit_quantity: Volume,
+    consumption_per_hour: Volume,
+
+    pressure_breakpoints_n2_percent: [f64; 5],
+    pressure_map_psi: [f64; 5],
+    low_pressure_threshold: Pressure,
+
+    high_temperature_threshold: ThermodynamicTemperature,
+    bearing_heat_at_full_speed: Power,
+    heat_per_fuel_mass_joule_per_kilogram: f64,
+    nacelle_heat_transfer_watt_per_kelvin: f64,
+    thermal_mass_joule_per_kelvin: f64,
+}
+impl EngineOilSystemCharacteristics {
+    pub fn leap_1a26() -> Self {
+        Self {
+            tank_full_quantity: Volume::new::<quart_liquid>(17.),
+            circuit_quantity: Volume::new::<quart_liquid>(4.),
+            consumption_per_hour: Volume::new::<quart_liquid>(0.3),
+
+            pressure_breakpoints_n2_percent: [0., 25., 60., 80., 100.],
+            pressure_map_psi: [0., 18., 45., 60., 72.],
+            low_pressure_threshold: Pressure::new::<psi>(18.),
+
+            high_temperature_threshold: ThermodynamicTemperature::new::<degree_celsius>(140.),
+            bearing_heat_at_full_speed: Power::new::<watt>(24_000.),
+            heat_per_fuel_mass_joule_per_kilogram: 93_000.,
+            nacelle_heat_transfer_watt_per_kelvin: 150.,
+            thermal_mass_joule_per_kelvin: 150_000.,
+        }
+    }
+
+    pub fn trent_972() -> Self {
+        Self {
+            tank_full_quantity: Volume::new::<quart_liquid>(33.),
+            circuit_quantity: Volume::new::<quart_liquid>(8.),
+            consumption_per_hour: Volume::new::<quart_liquid>(0.5),
+
+            pressure_breakpoints_n2_percent: [0., 25., 60., 80., 100.],
+            pressure_map_psi: [0., 18., 50., 70., 85.],
+            low_pressure_threshold: Pressure::new::<psi>(18.),
+
+            high_temperature_threshold: ThermodynamicTemperature::new::<degree_celsius>(177.),
+            bearing_heat_at_full_speed: Power::new::<watt>(50_000.),
+            heat_per_fuel_mass_joule_per_kilogram: 93_000.,
+            nacelle_heat_transfer_watt_per_kelvin: 300.,
+            thermal_mass_joule_per_kelvin: 300_000.,
+        }
+    }
+}
+
+/// The lubrication system of one engine.
+///
+/// The oil pump is driven by the accessory gearbox, so the delivered pressure follows the high
+/// pressure spool speed. When the engine starts, part of the tank content fills the sumps and
+/// lines (gulping), and drains back slowly after shutdown. The oil is cooled by the fuel going
+/// to the engine through the fuel/oil heat exchanger, so the oil temperature depends on both
+/// the engine power and the fuel flow and temperature. The heat taken from the oil warms the fuel.
+pub struct EngineOilSystem {
+    quantity_id: VariableIdentifier,
+    pressure_id: VariableIdentifier,
+    temperature_id: VariableIdentifier,
+    filter_clog_id: VariableIdentifier,
+    low_pressure_id: VariableIdentifier,
+    high_temperature_id: VariableIdentifier,
+
+    characteristics: EngineOilSystemCharacteristics,
+
+    total_quantity: Volume,
+    circuit_quantity: Volume,
+    pressure: Pressure,
+    filter_differential_pressure: Pressure,
+    temperature: ThermodynamicTemperature,
+    heat_rejected_to_fuel: Power,
+
+    filter_clog_failure: Failure,
+    low_pressure_failure: Failure,
+    high_temperature_failure: Failure,
+}
+impl EngineOilSystem {
+    const GULPING_COMPLETE_N2_PERCENT: f64 = 60.;
+    const GULPING_DURATION: Duration = Duration::from_secs(60);
+    const DRAIN_BACK_DURATION: Duration = Duration::from_secs(20 * 60);
+
+    // Below this tank quantity the pump inlet starts to ingest air
+    const MIN_PUMP_INLET_QUANTITY_QUARTS: f64 = 1.;
+
+    // The pressure loss of a clean filter is neglected
+    const CLOGGED_FILTER_PRESSURE_LOSS_RATIO: f64 = 0.4;
+    const FILTER_BYPASS_VALVE_OPENING_PSID: f64 = 20.;
+    const FILTER_CLOG_INDICATION_PSID: f64 = 10.;
+
+    const LOW_PRESSURE_FAILURE_PRESSURE_RATIO: f64 = 0.15;
+
+    // Hot oil is less viscous which lowers the pressure delivered by the pump
+    const VISCOSITY_REFERENCE_TEMPERATURE_CELSIUS: f64 = 100.;
+    const PRESSURE_LOSS_RATIO_PER_CELSIUS: f64 = 0.005;
+
+    const FUEL_OIL_HEAT_EXCHANGER_EFFECTIVENESS: f64 = 0.6;
+
+    pub fn new(
+        context: &mut InitContext,
+        number: usize,
+        characteristics: EngineOilSystemCharacteristics,
+    ) -> Self {
+        Self {
+            quantity_id: context.get_identifier(format!("ENGINE_{}_OIL_QUANTITY", number)),
+            pressure_id: context.get_identifier(format!("ENGINE_{}_OIL_PRESSURE", number)),
+            temperature_id: context.get_identifier(format!("ENGINE_{}_OIL_TEMPERATURE", number)),
+            filter_clog_id: context.get_identifier(format!("ENGINE_{}_OIL_FILTER_CLOG", number)),
+            low_pressure_id: context.get_identifier(format!("ENGINE_{}_OIL_LOW_PRESSURE", number)),
+            high_temperature_id: context
+                .get_identifier(format!("ENGINE_{}_OIL_HIGH_TEMPERATURE", number)),
+
+            total_quantity: characteristics.tank_full_quantity,
+            circuit_quantity: Volume::default(),
+            pressure: Pressure::default(),
+            filter_differential_pressure: Pressure::default(),
+            temperature: ThermodynamicTemperature::new::<degree_celsius>(15.),
+            heat_rejected_to_fuel: Power::default(),
+
+            characteristics,
+
+            filter_clog_failure: Failure::new(FailureType::EngineOilFilterClog(number)),
+            low_pressure_failure: Failure::new(FailureType::EngineOilLowPressure(number)),
+            high_temperature_failure: Failure::new(FailureType::EngineOilHighTemperature(number)),
+        }
+    }
+
+    /// Updates the oil quantity and temperature.
+    ///
+    /// `high_pressure_spool_speed` is the speed of the spool driving the accessory gearbox.
+    /// `fuel_flow` and `fuel_temperature` are the flow and temperature of the fuel going
+    /// through the fuel/oil heat exchanger.
+    pub fn update(
+        &mut self,
+        context: &UpdateContext,
+        high_pressure_spool_speed: Ratio,
+        fuel_flow: MassRate,
+        fuel_temperature: ThermodynamicTemperature,
+    ) {
+        self.update_quantity(context, high_pressure_spool_speed);
+        self.update_temperature(
+            context,
+            high_pressure_spool_speed,
+            fuel_flow,
+            fuel_temperature,
+        );
+    }
+
+    fn update_quantity(&mut self, context: &UpdateContext, high_pressure_spool_speed: Ratio) {
+        let running_ratio = (high_pressure_spool_speed.get::<percent>()
+            / Self::GULPING_COMPLETE_N2_PERCENT)
+            .clamp(0., 1.);
+
+        let circuit_target = self.characteristics.circuit_quantity * running_ratio;
+        if circuit_target > self.circuit_quantity {
+            self.circuit_quantity = (self.circuit_quantity
+                + self.characteristics.circuit_quantity * context.delta_as_secs_f64()
+                    / Self::GULPING_DURATION.as_secs_f64())
+            .min(circuit_target);
+        } else {
+            self.circuit_quantity = (self.circuit_quantity
+                - self.characteristics.circuit_quantity * context.delta_as_secs_f64()
+                    / Self::DRAIN_BACK_DURATION.as_secs_f64())
+            .max(circuit_target);
+        }
+
+        self.total_quantity = (self.total_quantity
+            - self.characteristics.consumption_per_hour
+                * running_ratio
+                * context.delta_as_secs_f64()
+                / 3600.)
+            .max(Volume::default());
+        self.circuit_quantity = self.circuit_quantity.min(self.total_quantity);
+    }
+
+    fn update_temperature(
+        &mut self,
+        context: &UpdateContext,
+        high_pressure_spool_speed: Ratio,
+        fuel_flow: MassRate,
+        fuel_temperature: ThermodynamicTemperature,
+    ) {
+        let fuel_flow_kilogram_per_second = fuel_flow.get::<kilogram_per_second>().max(0.);
+
+        let heat_input_watt = self
+            .characteristics
+            .bearing_heat_at_full_speed
+            .get::<watt>()
+            * high_pressure_spool_speed.get::<ratio>().max(0.).powi(2)
+            + self.characteristics.heat_per_fuel_mass_joule_per_kilogram
+                * fuel_flow_kilogram_per_second;
+
+        let heat_exchanger_conductance = if self.high_temperature_failure.is_active() {
+            0.
+        } else {
+            Self::FUEL_OIL_HEAT_EXCHANGER_EFFECTIVENESS
+                * FUEL_SPECIFIC_HEAT_JOULE_PER_KILOGRAM_KELVIN
+                * fuel_flow_kilogram_per_second
+        };
+        let nacelle_conductance = self.characteristics.nacelle_heat_transfer_watt_per_kelvin;
+        let total_conductance = heat_exchanger_conductance + nacelle_conductance;
+
+        // The oil converges exponentially towards the temperature at which the heat
+        // rejected to the fuel and the nacelle balances the heat it receives
+        let equilibrium_temperature_kelvin = (heat_input_watt
+            + heat_exchanger_conductance * fuel_temperature.get::<kelvin>()
+            + nacelle_conductance * context.ambient_temperature().get::<kelvin>())
+            / total_conductance;
+        let time_constant_seconds =
+            self.characteristics.thermal_mass_joule_per_kelvin / total_conductance;
+
+        let temperature_kelvin = self.temperature.get::<kelvin>();
+        self.temperature = ThermodynamicTemperature::new::<kelvin>(
+            temperature_kelvin
+                + (equilibrium_temperature_kelvin - temperature_kelvin)
+                    * (1. - (-context.delta_as_secs_f64() / time_constant_seconds).exp()),
+        );
+
+        self.heat_rejected_to_fuel = Power::new::<watt>(
+            heat_exchanger_conductance
+                * (self.temperature.get::<kelvin>() - fuel_temperature.get::<kelvin>()),
+        );
+    }
+
+    /// Updates the pressure delivered by the oil pump for the given speed of the spool
+    /// driving the accessory gearbox.
+    pub fn update_pressure(&mut self, high_pressure_spool_speed: Ratio) {
+        let mut pump_pressure_psi = interpolation(
+            &self.characteristics.pressure_breakpoints_n2_percent,
+            &self.characteristics.pressure_map_psi,
+            high_pressure_spool_speed.get::<percent>(),
+        )
+        .max(0.);
+
+        pump_pressure_psi *= (self.tank_quantity().get::<quart_liquid>()
+            / Self::MIN_PUMP_INLET_QUANTITY_QUARTS)
+            .clamp(0., 1.);
+
+        pump_pressure_psi *= (1.
+            - (self.temperature.get::<degree_celsius>()
+                - Self::VISCOSITY_REFERENCE_TEMPERATURE_CELSIUS)
+                .max(0.)
+                * Self::PRESSURE_LOSS_RATIO_PER_CELSIUS)
+            .max(0.);
+
+        if self.low_pressure_failure.is_active() {
+            pump_pressure_psi *= Self::LOW_PRESSURE_FAILURE_PRESSURE_RATIO;
+        }
+
+        let filter_differential_pressure_psi = if self.filter_clog_failure.is_active() {
+            (pump_pressure_psi * Self::CLOGGED_FILTER_PRESSURE_LOSS_RATIO)
+                .min(Self::FILTER_BYPASS_VALVE_OPENING_PSID)
+        } else {
+            0.
+        };
+
+        self.filter_differential_pressure = Pressure::new::<psi>(filter_differential_pressure_psi);
+        self.pressure = Pressure::new::<psi>(pump_pressure_psi - filter_differential_pressure_psi);
+    }
+
+    /// The oil quantity in the tank, as displayed to the crew.
+    pub fn tank_quantity(&self) -> Volume {
+        self.total_quantity - self.circuit_quantity
+    }
+
+    pub fn pressure(&self) -> Pressure {
+        self.pressure
+    }
+
+    pub fn temperature(&self) -> ThermodynamicTemperature {
+        self.temperature
+    }
+
+    /// The heat given to the fuel going through the fuel/oil heat exchanger.
+    pub fn heat_rejected_to_fuel(&self) -> Power {
+        self.heat_rejected_to_fuel
+    }
+
+    pub fn pressure_is_low(&self) -> bool {
+        self.pressure < self.characteristics.low_pressure_threshold
+    }
+
+    pub fn temperature_is_high(&self) -> bool {
+        self.temperature > self.characteristics.high_temperature_threshold
+    }
+
+    pub fn filter_is_clogged(&self) -> bool {
+        self.filter_differential_pressure > Pressure::new::<psi>(Self::FILTER_CLOG_INDICATION_PSID)
+    }
+}
+impl SimulationElement for EngineOilSystem {
+    fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
+        self.filter_clog_failure.accept(visitor);
+        self.low_pressure_failure.accept(visitor);
+        self.high_temperature_failure.accept(visitor);
+
+        visitor.visit(self);
+    }
+
+    fn write(&self, writer: &mut SimulatorWriter) {
+        writer.write(
+            &self.quantity_id,
+            self.tank_quantity().get::<quart_liquid>(),
+        );
+        writer.write(&self.pressure_id, self.pressure);
+        writer.write(&self.temperature_id, self.temperature);
+        writer.write(&self.filter_clog_id, self.filter_is_clogged());
+        writer.write(&self.low_pressure_id, self.pressure_is_low());
+        writer.write(&self.high_temperature_id, self.temperature_is_high());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::simulation::{
+        test::{SimulationTestBed, TestBed},
+        Aircraft,
+    };
+
+    struct TestAircraft {
+        oil_system: EngineOilSystem,
+        n2: Ratio,
+        fuel_flow: MassRate,
+        fuel_temperature: ThermodynamicTemperature,
+    }
+    impl TestAircraft {
+        fn new(context: &mut InitContext) -> Self {
+            Self {
+                oil_system: EngineOilSystem::new(
+                    context,
+                    1,
+                    EngineOilSystemCharacteristics::leap_1a26(),
+                ),
+                n2: Ratio::default(),
+                fuel_flow: MassRate::default(),
+                fuel_temperature: ThermodynamicTemperature::new::<degree_celsius>(15.),
+            }
+        }
+    }
+    impl Aircraft for TestAircraft {
+        fn update_after_power_distribution(&mut self, context: &UpdateContext) {
+            self.oil_system.update_pressure(self.n2);
+            self.oil_system
+                .update(context, self.n2, self.fuel_flow, self.fuel_temperature);
+        }
+    }
+    impl SimulationElement for TestAircraft {
+        fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
+            self.oil_system.accept(visitor);
+
+            visitor.visit(self);
+        }
+    }
+
+    struct OilSystemTestBed {
+        test_bed: SimulationTestBed<TestAircraft>,
+    }
+    impl OilSystemTestBed {
+        fn new() -> Self {
+            let mut test_bed = Self {
+                test_bed: SimulationTestBed::new(TestAircraft::new),
+            };
+            test_bed.set_on_ground(true);
+            test_bed.set_ambient_temperature(ThermodynamicTemperature::new::<degree_celsius>(15.));
+
+            test_bed
+        }
+
+        fn and_run_for(mut self, duration: Duration) -> Self {
+            for _ in 0..duration.as_secs() {
+                self.run_with_delta(Duration::from_secs(1));
+            }
+
+            self
+        }
+
+        fn engine_stopped(mut self) -> Self {
+            self.command(|a| {
+                a.n2 = Ratio::default();
+                a.fuel_flow = MassRate::default();
+            });
+
+            self
+        }
+
+        fn engine_at_idle(mut self) -> Self {
+            self.command(|a| {
+                a.n2 = Ratio::new::<percent>(65.);
+                a.fuel_flow = MassRate::new::<kilogram_per_second>(0.08);
+            });
+
+            self
+        }
+
+        fn engine_at_take_off(mut self) -> Self {
+            self.command(|a| {
+                a.n2 = Ratio::new::<percent>(100.);
+                a.fuel_flow = MassRate::new::<kilogram_per_second>(1.25);
+            });
+
+            self
+        }
+
+        fn n2(mut self, n2_percent: f64) -> Self {
+            self.command(|a| a.n2 = Ratio::new::<percent>(n2_percent));
+
+            self
+        }
+
+        fn tank_quantity(&self) -> Volume {
+            self.query(|a| a.oil_system.tank_quantity())
+        }
+
+        fn pressure(&self) -> Pressure {
+            self.query(|a| a.oil_system.pressure())
+        }
+
+        fn temperature(&self) -> ThermodynamicTemperature {
+            self.query(|a| a.oil_system.temperature())
+        }
+
+        fn pressure_is_low(&self) -> bool {
+            self.query(|a| a.oil_system.pressure_is_low())
+        }
+
+        fn temperature_is_high(&self) -> bool {
+            self.query(|a| a.oil_system.temperature_is_high())
+        }
+
+        fn filter_is_clogged(&self) -> bool {
+            self.query(|a| a.oil_system.filter_is_clogged())
+        }
+
+        fn heat_rejected_to_fuel(&self) -> Power {
+            self.query(|a| a.oil_system.heat_rejected_to_fuel())
+        }
+    }
+    impl TestBed for OilSystemTestBed {
+        type Aircraft = TestAircraft;
+
+        fn test_bed(&self) -> &SimulationTestBed<TestAircraft> {
+            &self.test_bed
+        }
+
+        fn test_bed_mut(&mut self) -> &mut SimulationTestBed<TestAircraft> {
+            &mut self.test_bed
+        }
+    }
+
+    fn test_bed() -> OilSystemTestBed {
+        OilSystemTestBed::new()
+    }
+
+    #[test]
+    fn tank_is_full_and_pressure_is_low_with_engine_stopped() {
+        let test_bed = test_bed()
+            .engine_stopped()
+            .and_run_for(Duration::from_secs(1));
+
+        assert!(
+            (test_bed.tank_quantity().get::<quart_liquid>() - 17.).abs() < 0.01,
+            "Tank quantity was {:.2} qt",
+            test_bed.tank_quantity().get::<quart_liquid>()
+        );
+        assert!(test_bed.pressure_is_low());
+    }
+
+    #[test]
+    fn pressure_crosses_low_pressure_threshold_at_25_percent_n2() {
+        let mut test_bed = test_bed().n2(24.).and_run_for(Duration::from_secs(1));
+        assert!(test_bed.pressure_is_low());
+
+        test_bed = test_bed.n2(26.).and_run_for(Duration::from_secs(1));
+        assert!(!test_bed.pressure_is_low());
+    }
+
+    #[test]
+    fn pressure_increases_with_n2() {
+        let test_bed = test_bed()
+            .engine_at_idle()
+            .and_run_for(Duration::from_secs(1));
+        let idle_pressure = test_bed.pressure();
+
+        let test_bed = test_bed
+            .engine_at_take_off()
+            .and_run_for(Duration::from_secs(1));
+
+        assert!(test_bed.pressure() > idle_pressure);
+    }
+
+    #[test]
+    fn tank_quantity_drops_when_engine_starts_and_recovers_after_shutdown() {
+        let mut test_bed = test_bed()
+            .engine_at_idle()
+            .and_run_for(Duration::from_secs(120));
+
+        assert!(
+            (test_bed.tank_quantity().get::<quart_liquid>() - 13.).abs() < 0.1,
+            "Tank quantity was {:.2} qt",
+            test_bed.tank_quantity().get::<quart_liquid>()
+        );
+
+        test_bed = test_bed
+            .engine_stopped()
+            .and_run_for(Duration::from_secs(5 * 60));
+        let quantity_shortly_after_shutdown = test_bed.tank_quantity();
+        assert!(quantity_shortly_after_shutdown.get::<quart_liquid>() < 16.);
+
+        test_bed = test_bed.and_run_for(Duration::from_secs(20 * 60));
+        assert!(test_bed.tank_quantity() > quantity_shortly_after_shutdown);
+        assert!(test_bed.tank_quantity().get::<quart_liquid>() > 16.9);
+    }
+
+    #[test]
+    fn oil_is_consumed_when_engine_runs() {
+        let test_bed = test_bed()
+            .engine_at_take_off()
+            .and_run_for(Duration::from_secs(2 * 3600))
+            .engine_stopped()
+            .and_run_for(Duration::from_secs(30 * 60));
+
+        let quantity = test_bed.tank_quantity().get::<quart_liquid>();
+        assert!(
+            (quantity - (17. - 0.6)).abs() < 0.05,
+            "Tank quantity was {:.2} qt",
+            quantity
+        );
+    }
+
+    #[test]
+    fn oil_temperature_stabilizes_in_normal_range_at_idle() {
+        let test_bed = test_bed()
+            .engine_at_idle()
+            .and_run_for(Duration::from_secs(30 * 60));
+
+        let temperature = test_bed.temperature().get::<degree_celsius>();
+        assert!(
+            (60. ..100.).contains(&temperature),
+            "Oil temperature was {:.1}°C",
+            temperature
+        );
+        assert!(!test_bed.temperature_is_high());
+    }
+
+    #[test]
+    fn oil_temperature_stays_below_high_temperature_threshold_at_take_off() {
+        let test_bed = test_bed()
+            .engine_at_take_off()
+            .and_run_for(Duration::from_secs(10 * 60));
+
+        let temperature = test_bed.temperature().get::<degree_celsius>();
+        assert!(
+            (80. ..140.).contains(&temperature),
+            "Oil temperature was {:.1}°C",
+            temperature
+        );
+        assert!(!test_bed.temperature_is_high());
+    }
+
+    #[test]
+    fn warmer_fuel_gives_warmer_oil() {
+        let test_bed = test_bed()
+            .engine_at_take_off()
+            .and_run_for(Duration::from_secs(10 * 60));
+        let temperature_with_cold_fuel = test_bed.temperature();
+
+        let mut test_bed = OilSystemTestBed::new().engine_at_take_off();
+        test_bed
+            .command(|a| a.fuel_temperature = ThermodynamicTemperature::new::<degree_celsius>(45.));
+        test_bed = test_bed.and_run_for(Duration::from_secs(10 * 60));
+
+        assert!(test_bed.temperature() > temperature_with_cold_fuel);
+    }
+
+    #[test]
+    fn oil_heat_is_rejected_to_the_fuel_when_running() {
+        let test_bed = test_bed()
+            .engine_at_idle()
+            .and_run_for(Duration::from_secs(10 * 60));
+        assert!(test_bed.heat_rejected_to_fuel() > Power::default());
+
+        let test_bed = test_bed
+            .engine_stopped()
+            .and_run_for(Duration::from_secs(1));
+        assert_eq!(test_bed.heat_rejected_to_fuel(), Power::default());
+    }
+
+    #[test]
+    fn oil_cools_down_after_shutdown() {
+        let test_bed = test_bed()
+            .engine_at_idle()
+            .and_run_for(Duration::from_secs(30 * 60));
+        let running_temperature = test_bed.temperature();
+
+        let test_bed = test_bed
+            .engine_stopped()
+            .and_run_for(Duration::from_secs(30 * 60));
+
+        assert!(test_bed.temperature() < running_temperature);
+    }
+
+    #[test]
+    fn high_temperature_failure_overheats_the_oil() {
+        let mut test_bed = test_bed()
+            .engine_at_take_off()
+            .and_run_for(Duration::from_secs(5 * 60));
+        assert!(!test_bed.temperature_is_high());
+
+        test_bed.fail(FailureType::EngineOilHighTemperature(1));
+        test_bed = test_bed.and_run_for(Duration::from_secs(5 * 60));
+
+        assert!(test_bed.temperature_is_high());
+    }
+
+    #[test]
+    fn low_pressure_failure_gives_low_pressure_at_idle() {
+        let mut test_bed = test_bed()
+            .engine_at_idle()
+            .and_run_for(Duration::from_secs(5));
+        assert!(!test_bed.pressure_is_low());
+
+        test_bed.fail(FailureType::EngineOilLowPressure(1));
+        test_bed = test_bed.and_run_for(Duration::from_secs(1));
+
+        assert!(test_bed.pressure_is_low());
+    }
+
+    #[test]
+    fn filter_clog_is_indicated_and_slightly_lowers_pressure() {
+        let mut test_bed = test_bed()
+            .engine_at_idle()
+            .and_run_for(Duration::from_secs(5));
+        let normal_pressure = test_bed.pressure();
+        assert!(!test_bed.filter_is_clogged());
+
+        test_bed.fail(FailureType::EngineOilFilterClog(1));
+        test_bed = test_bed.and_run_for(Duration::from_secs(1));
+
+        assert!(test_bed.filter_is_clogged());
+        assert!(test_bed.pressure() < normal_pressure);
+        assert!(!test_bed.pressure_is_low());
+    }
+}
@@ -2,13 +2,152 @@ use std::time::Duration;
 use uom::si::{f64::*, power::watt, ratio::ratio};
 
 use crate::{
+    failures::{Failure, FailureType},
     shared::{
         low_pass_filter::LowPassFilter, random_from_normal_distribution, ConsumePower,
         ElectricalBusType, ElectricalBuses, ReverserPosition,
     },
-    simulation::{SimulationElement, SimulationElementVisitor, UpdateContext},
+    simulation::{
+        InitContext, Read, SimulationElement, SimulationElementVisitor, SimulatorReader,
+        UpdateContext, VariableIdentifier,
+    },
 };
 
+/// Failures and maintenance lockout of a thrust reverser, shared by hydraulic and electric
+/// actuation systems.
+///
+/// The reverser is made of two translating sleeves driven together by the actuation system.
+/// A jammed sleeve stays where it was when it jammed, while a failed lock lets the sleeves
+/// float slightly open under airloads, which is enough to lose the stowed indication.
+pub(crate) struct ReverserFailures {
+    maintenance_lockout_id: VariableIdentifier,
+    maintenance_lockout_engaged: bool,
+
+    unlocked_failure: Failure,
+    fail_to_deploy_failure: Failure,
+    fail_to_stow_failure: Failure,
+    sleeve_jammed_failure: Failure,
+    actuation_supply_failure: Failure,
+
+    unlocked_drift_position: Ratio,
+    jammed_sleeve_position: Option<Ratio>,
+}
+impl ReverserFailures {
+    const UNLOCKED_SLEEVE_DRIFT_POSITION: f64 = 0.06;
+    const UNLOCKED_SLEEVE_DRIFT_SPEED_RATIO_PER_S: f64 = 0.02;
+
+    const STOWED_POSITION_THRESHOLD: f64 = 0.01;
+    const DEPLOYED_POSITION_THRESHOLD: f64 = 0.95;
+
+    pub(crate) fn new(context: &mut InitContext, number: usize) -> Self {
+        Self {
+            maintenance_lockout_id: context
+                .get_identifier(format!("REVERSER_{}_MAINTENANCE_LOCKOUT", number)),
+            maintenance_lockout_engaged: false,
+
+            unlocked_failure: Failure::new(FailureType::ReverserUnlocked(number)),
+            fail_to_deploy_failure: Failure::new(FailureType::ReverserFailToDeploy(number)),
+            fail_to_stow_failure: Failure::new(FailureType::ReverserFailToStow(number)),
+            sleeve_jammed_failure: Failure::new(FailureType::ReverserSleeveJammed(number)),
+            actuation_supply_failure: Failure::new(FailureType::ReverserActuationSupplyLoss(
+                number,
+            )),
+
+            unlocked_drift_position: Ratio::default(),
+            jammed_sleeve_position: None,
+        }
+    }
+
+    pub(crate) fn update(&mut self, context: &UpdateContext, actuator_position: Ratio) {
+        self.unlocked_drift_position = if self.unlocked_failure.is_active() {
+            (self.unlocked_drift_position
+                + Ratio::new::<ratio>(
+                    Self::UNLOCKED_SLEEVE_DRIFT_SPEED_RATIO_PER_S * context.delta_as_secs_f64(),
+                ))
+            .min(Ratio::new::<ratio>(Self::UNLOCKED_SLEEVE_DRIFT_POSITION))
+        } else {
+            Ratio::default()
+        };
+
+        self.jammed_sleeve_position = if self.sleeve_jammed_failure.is_active() {
+            Some(
+                self.jammed_sleeve_position
+                    .unwrap_or_else(|| self.free_sleeve_position(actuator_position)),
+            )
+        } else {
+            None
+        };
+    }
+
+    fn free_sleeve_position(&self, actuator_position: Ratio) -> Ratio {
+        actuator_position.max(self.unlocked_drift_position)
+    }
+
+    fn sleeves_position(&self, actuator_position: Ratio) -> [Ratio; 2] {
+        let free_sleeve_position = self.free_sleeve_position(actuator_position);
+
+        [
+            free_sleeve_position,
+            self.jammed_sleeve_position.unwrap_or(free_sleeve_position),
+        ]
+    }
+
+    /// Mean position of both sleeves, which sets the ratio of the fan flow being reversed
+    pub(crate) fn reverser_position(&self, actuator_position: Ratio) -> Ratio {
+        let [first_sleeve, second_sleeve] = self.sleeves_position(actuator_position);
+
+        (first_sleeve + second_sleeve) / 2.
+    }
+
+    pub(crate) fn all_sleeves_stowed(&self, actuator_position: Ratio) -> bool {
+        self.sleeves_position(actuator_position)
+            .iter()
+            .all(|position| position.get::<ratio>() < Self::STOWED_POSITION_THRESHOLD)
+    }
+
+    pub(crate) fn at_least_one_sleeve_stowed(&self, actuator_position: Ratio) -> bool {
+        !self.all_sleeves_stowed(actuator_position)
+            && self
+                .sleeves_position(actuator_position)
+                .iter()
+                .any(|position| position.get::<ratio>() < Self::STOWED_POSITION_THRESHOLD)
+    }
+
+    pub(crate) fn all_sleeves_deployed(&self, actuator_position: Ratio) -> bool {
+        self.sleeves_position(actuator_position)
+            .iter()
+            .all(|position| position.get::<ratio>() > Self::DEPLOYED_POSITION_THRESHOLD)
+    }
+
+    pub(crate) fn actuator_can_move(&self, speed: Ratio) -> bool {
+        !(speed.get::<ratio>() > 0. && self.fail_to_deploy_failure.is_active()
+            || speed.get::<ratio>() < 0. && self.fail_to_stow_failure.is_active())
+    }
+
+    pub(crate) fn actuation_is_available(&self) -> bool {
+        !self.actuation_supply_failure.is_active() && !self.maintenance_lockout_engaged
+    }
+
+    pub(crate) fn maintenance_lockout_engaged(&self) -> bool {
+        self.maintenance_lockout_engaged
+    }
+}
+impl SimulationElement for ReverserFailures {
+    fn accept<V: SimulationElementVisitor>(&mut self, visitor: &mut V) {
+        self.unlocked_failure.accept(visitor);
+        self.fail_to_deploy_failure.accept(visitor);
+        self.fail_to_stow_failure.accept(visitor);
+        self.sleeve_jammed_failure.accept(visitor);
+        self.actuation_supply_failure.accept(visitor);
+
+        visitor.visit(self);
+    }
+
+    fn read(&mut self, reader: &mut SimulatorReader) {
+        self.maintenance_lockout_engaged = reader.read(&self.maintenance_lockout_id);
+    }
+}
+
 struct PowerDistributionUnit {
     position: Ratio,
     current_speed: LowPassFilter<Ratio>,
@@ -49,11 +188,13 @@ impl PowerDistributionUnit {
         context: &UpdateContext,
         electrical_command_should_deploy: bool,
         is_mechanically_locked: bool,
+        failures: &ReverserFailures,
     ) {
         self.update_current_speed(
             context,
             electrical_command_should_deploy,
             is_mechanically_locked,
+            failures,
         );
 
         self.position += context.delta_as_secs_f64() * self.current_speed.output();
@@ -77,8 +218,9 @@ impl PowerDistributionUnit {
         context: &UpdateContext,
         electrical_command_should_deploy: bool,
         is_mechanically_locked: bool,
+        failures: &ReverserFailures,
     ) {
-        let final_command = if self.is_powered {
+        let final_command = if self.is_powered && failures.actuation_is_available() {
             if electrical_command_should_deploy {
                 Ratio::new::<ratio>(1.)
             } else {
@@ -88,7 +230,7 @@ impl PowerDistributionUnit {
             Ratio::default()
         };
 
-        if is_mechanically_locked {
+        if is_mechanically_locked || !failures.actuator_can_move(final_command) {
             self.current_speed.reset(Ratio::default());
         } else {
             self.current_speed
@@ -174,9 +316,13 @@ pub struct A380ReverserAssembly {
     electrical_lock2: ElectricalLock,
     electrical_lock3: ElectricalLock,
     pdu: PowerDistributionUnit,
+
+    failures: ReverserFailures,
 }
 impl A380ReverserAssembly {
     pub fn new(
+        context: &mut InitContext,
+        number: usize,
         etrac_powered_by: ElectricalBusType,
         third_lock_powered_by: ElectricalBusType,
     ) -> Self {
@@ -185,16 +331,29 @@ impl A380ReverserAssembly {
             electrical_lock2: ElectricalLock::new(etrac_powered_by),
             electrical_lock3: ElectricalLock::new(third_lock_powered_by),
             pdu: PowerDistributionUnit::new(etrac_powered_by),
+
+            failures: ReverserFailures::new(context, number),
         }
     }
 
     pub fn update(&mut self, context: &UpdateContext, controller: &impl ElecReverserInterface) {
-        self.electrical_lock1
-            .update(controller.should_unlock_first(), self.reverser_position());
-        self.electrical_lock2
-            .update(controller.should_unlock_second(), self.reverser_position());
-        self.electrical_lock3
-            .update(controller.should_unlock_third(), self.reverser_position());
+        self.failures.update(context, self.pdu.position());
+
+        // Maintenance lockout mechanically holds the locks engaged
+        let unlock_allowed = !self.failures.maintenance_lockout_engaged();
+
+        self.electrical_lock1.update(
+            unlock_allowed && controller.should_unlock_first(),
+            self.reverser_position(),
+        );
+        self.electrical_lock2.update(
+            unlock_allowed && controller.should_unlock_second(),
+            self.reverser_position(),
+        );
+        self.electrical_lock3.update(
+            unlock_allowed && controller.should_unlock_third(),
+            self.reverser_position(),
+        );
 
         self.pdu.update(
             context,
@@ -202,6 +361,7 @@ impl A380ReverserAssembly {
             self.electrical_lock1.is_locked()
                 || self.electrical_lock2.is_locked()
                 || self.electrical_lock3.is_locked(),
+            &self.failures,
         );
     }
 }
@@ -211,17 +371,16 @@ impl ReverserFeedback for A380ReverserAssembly {
     }
 
     fn proximity_sensor_all_stowed(&self) -> bool {
-        self.reverser_position().get::<ratio>() < 0.01
+        self.failures.all_sleeves_stowed(self.pdu.position())
     }
 
     fn proximity_sensor_all_deployed(&self) -> bool {
-        self.reverser_position().get::<ratio>() > 0.95
+        self.failures.all_sleeves_deployed(self.pdu.position())
     }
 
     fn proximity_sensor_at_least_one_stowed(&self) -> bool {
-        // We do not model multiple doors for now, placeholder is a higher threshold for one door stowed only
-        self.reverser_position().get::<ratio>() < 0.2
-            && self.reverser_position().get::<ratio>() >= 0.01
+        self.failures
+            .at_least_one_sleeve_stowed(self.pdu.position())
     }
 
     fn tertiary_lock_is_locked(&self) -> bool {
@@ -230,7 +389,7 @@ impl ReverserFeedback for A380ReverserAssembly {
 }
 impl ReverserPosition for A380ReverserAssembly {
     fn reverser_position(&self) -> Ratio {
-        self.pdu.position()
+        self.failures.reverser_position(self.pdu.position())
     }
 }
 impl SimulationElement for A380ReverserAssembly {
@@ -239,6 +398,7 @@ impl SimulationElement for A380ReverserAssembly {
         self.electrical_lock2.accept(visitor);
         self.electrical_lock3.accept(visitor);
         self.pdu.accept(visitor);
+        self.failures.accept(visitor);
 
         visitor.visit(self);
     }
@@ -247,6 +407,7 @@ impl SimulationElement for A380ReverserAssembly {
 #[cfg(test)]
 mod tests {
     use more_asserts::*;
+    use ntest::assert_about_eq;
 
     use uom::si::electric_potential::volt;
 
@@ -255,8 +416,9 @@ mod tests {
     use crate::electrical::Electricity;
 
     use super::*;
+    use crate::failures::FailureType;
     use crate::shared::{update_iterator::FixedStepLoop, PotentialOrigin};
-    use crate::simulation::test::{SimulationTestBed, TestBed};
+    use crate::simulation::test::{SimulationTestBed, TestBed, WriteByName};
     use crate::simulation::{Aircraft, InitContext, SimulationElement};
 
     use std::time::Duration;
@@ -316,6 +478,8 @@ mod tests {
                 controller: TestReverserController::default(),
 
                 reverser: A380ReverserAssembly::new(
+                    context,
+                    2,
                     ElectricalBusType::DirectCurrent(2),
                     ElectricalBusType::AlternatingCurrent(4),
                 ),
@@ -498,4 +662,95 @@ mod tests {
         );
         assert!(test_bed.query(|a| a.reverser_is_locked()));
     }
+
+    fn command_deploy(test_bed: &mut SimulationTestBed<TestAircraft>, is_deploying: bool) {
+        test_bed.command(|a| a.set_ac_elec_power(true));
+        test_bed.command(|a| a.set_dc_elec_power(true));
+        test_bed.command(|a| a.set_deploy_reverser(is_deploying));
+        test_bed.command(|a| a.set_lock_reverser(!is_deploying));
+    }
+
+    #[test]
+    fn reverser_do_not_deploy_if_failed_to_deploy() {
+        let mut test_bed = SimulationTestBed::new(TestAircraft::new);
+
+        test_bed.fail(FailureType::ReverserFailToDeploy(2));
+        command_deploy(&mut test_bed, true);
+        test_bed.run_with_delta(Duration::from_millis(3000));
+
+        assert_eq!(test_bed.query(|a| a.reverser_position().get::<ratio>()), 0.);
+    }
+
+    #[test]
+    fn reverser_stays_deployed_if_failed_to_stow() {
+        let mut test_bed = SimulationTestBed::new(TestAircraft::new);
+
+        command_deploy(&mut test_bed, true);
+        test_bed.run_with_delta(Duration::from_millis(3000));
+
+        test_bed.fail(FailureType::ReverserFailToStow(2));
+        command_deploy(&mut test_bed, false);
+        test_bed.run_with_delta(Duration::from_millis(3000));
+
+        assert_ge!(
+            test_bed.query(|a| a.reverser_position().get::<ratio>()),
+            0.97
+        );
+        assert!(test_bed.query(|a| a.reverser.proximity_sensor_all_deployed()));
+    }
+
+    #[test]
+    fn reverser_with_jammed_sleeve_deploys_halfway() {
+        let mut test_bed = SimulationTestBed::new(TestAircraft::new);
+
+        test_bed.fail(FailureType::ReverserSleeveJammed(2));
+        command_deploy(&mut test_bed, true);
+        test_bed.run_with_delta(Duration::from_millis(3000));
+
+        assert_about_eq!(
+            test_bed.query(|a| a.reverser_position().get::<ratio>()),
+            0.5,
+            0.01
+        );
+        assert!(test_bed.query(|a| !a.reverser.proximity_sensor_all_deployed()));
+        assert!(test_bed.query(|a| a.reverser.proximity_sensor_at_least_one_stowed()));
+    }
+
+    #[test]
+    fn reverser_unlocked_failure_loses_stowed_indication() {
+        let mut test_bed = SimulationTestBed::new(TestAircraft::new);
+
+        test_bed.fail(FailureType::ReverserUnlocked(2));
+        test_bed.run_with_delta(Duration::from_millis(5000));
+
+        assert_ge!(
+            test_bed.query(|a| a.reverser_position().get::<ratio>()),
+            0.05
+        );
+        assert!(test_bed.query(|a| !a.reverser.proximity_sensor_all_stowed()));
+        assert!(test_bed.query(|a| !a.reverser.tertiary_lock_is_locked()));
+    }
+
+    #[test]
+    fn reverser_do_not_deploy_without_actuation_supply() {
+        let mut test_bed = SimulationTestBed::new(TestAircraft::new);
+
+        test_bed.fail(FailureType::ReverserActuationSupplyLoss(2));
+        command_deploy(&mut test_bed, true);
+        test_bed.run_with_delta(Duration::from_millis(3000));
+
+        assert_eq!(test_bed.query(|a| a.reverser_position().get::<ratio>()), 0.);
+    }
+
+    #[test]
+    fn reverser_do_not_deploy_with_maintenance_lockout() {
+        let mut test_bed = SimulationTestBed::new(TestAircraft::new);
+
+        test_bed.write_by_name("REVERSER_2_MAINTENANCE_LOCKOUT", true);
+        command_deploy(&mut test_bed, true);
+        test_bed.run_with_delta(Duration::from_millis(3000));
+
+        assert_eq!(test_bed.query(|a| a.reverser_position().get::<ratio>()), 0.);
+        assert!(test_bed.query(|a| a.reverser_is_locked()));
+    }
 }
@@ -3,6 +3,7 @@ use uom::si::{
     angular_acceleration::radian_per_second_squared,
     f64::*,
     force::newton,
+    length::meter,
     mass::kilogram,
     ratio::{percent, ratio},
     torque::newton_meter,
@@ -88,12 +89,14 @@ pub struct ReverserForce {
     plane_delta_speed_due_to_reverse_thrust: Velocity,
     plane_delta_acceleration_due_to_reverse_thrust: Acceleration,
 
+    distance_from_cg_to_engine: Length,
     dissimetry_acceleration: AngularAcceleration,
 }
 impl ReverserForce {
-    const DISTANCE_FROM_CG_TO_ENGINE_METER: f64 = 5.;
-
-    pub fn new(context: &mut InitContext) -> Self {
+    /// Distance from cg to engine is the lateral arm of the engines fitted with a reverser. Any
+    /// thrust difference between both sides, from an asymmetric or partial deployment, creates a
+    /// yawing moment around this arm.
+    pub fn new(context: &mut InitContext, distance_from_cg_to_engine: Length) -> Self {
         Self {
             reverser_delta_speed_id: context.get_identifier("REVERSER_DELTA_SPEED".to_owned()),
             reverser_angular_accel_id: context
@@ -103,6 +106,7 @@ impl ReverserForce {
             reversers: [ReverserThrust::new(); 2],
             plane_delta_speed_due_to_reverse_thrust: Velocity::default(),
             plane_delta_acceleration_due_to_reverse_thrust: Acceleration::default(),
+            distance_from_cg_to_engine,
             dissimetry_acceleration: AngularAcceleration::default(),
         }
     }
@@ -140,7 +144,7 @@ impl ReverserForce {
             self.reversers[1].current_thrust() - self.reversers[0].current_thrust();
 
         let dissimetry_torque = Torque::new::<newton_meter>(
-            total_dissimetry.get::<newton>() * Self::DISTANCE_FROM_CG_TO_ENGINE_METER,
+            total_dissimetry.get::<newton>() * self.distance_from_cg_to_engine.get::<meter>(),
         );
 
         self.dissimetry_acceleration = if context.total_yaw_inertia_kg_m2().abs() > 0. {
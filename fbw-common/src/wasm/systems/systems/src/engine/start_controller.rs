@@ -0,0 +1,911 @@
+use std::time::Duration;
+
+use uom::si::{
+    f64::*,
+    pressure::psi,
+    ratio::{percent, ratio},
+    thermodynamic_temperature::degree_celsius,
+    torque::newton_meter,
+};
+
+use crate::{
+    pneumatic::{EngineModeSelector, EngineState},
+    shared::{EngineExhaustGasTemperature, EngineUncorrectedN2},
+    simulation::{
+        InitContext, Read, Reader, SimulationElement, SimulatorWriter, UpdateContext,
+        VariableIdentifier, Write, Writer,
+    },
+};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum EngineStartFault {
+    None = 0,
+    HungStart = 1,
+    HotStart = 2,
+    NoLightUp = 3,
+    StarterTimeExceeded = 4,
+}
+
+try_read_write_enum!(EngineStartFault);
+
+impl TryFrom<f64> for EngineStartFault {
+    type Error = u8;
+
+    fn try_from(value: f64) -> Result<Self, Self::Error> {
+        match value as u8 {
+            0 => Ok(EngineStartFault::None),
+            1 => Ok(EngineStartFault::HungStart),
+            2 => Ok(EngineStartFault::HotStart),
+            3 => Ok(EngineStartFault::NoLightUp),
+            4 => Ok(EngineStartFault::StarterTimeExceeded),
+            i => Err(i),
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum EngineStartPhase {
+    Idle,
+    Motoring,
+    Ignition,
+    Acceleration,
+    Running,
+    Aborted,
+}
+
+/// Defines the start schedule and limits of an engine:
+///     N2 at which fuel is introduced on ground and in flight
+///     N2 at which the starter cuts out and the engine is considered started
+///     light up time and EGT limits
+///     air turbine starter stall torque and free running speed
+///     minimum starter torque needed to motor the engine up to the fuel on N2
+pub struct EngineStartCharacteristics {
+    fuel_on_n2: Ratio,
+    in_flight_fuel_on_n2: Ratio,
+    starter_cut_out_n2: Ratio,
+    minimum_idle_n2: Ratio,
+    light_up_time_limit: Duration,
+    start_egt_limit: ThermodynamicTemperature,
+    starter_stall_torque: Torque,
+    starter_free_running_n2: Ratio,
+    minimum_cranking_torque: Torque,
+}
+impl EngineStartCharacteristics {
+    pub fn leap_1a26() -> Self {
+        Self {
+            fuel_on_n2: Ratio::new::<percent>(22.),
+            in_flight_fuel_on_n2: Ratio::new::<percent>(15.),
+            // FIXME the valve should start closing at around 60% N2 and complete at 65% N2
+            // because of its travel time
+            starter_cut_out_n2: Ratio::new::<percent>(65.),
+            minimum_idle_n2: Ratio::new::<percent>(55.),
+            light_up_time_limit: Duration::from_secs(15),
+            start_egt_limit: ThermodynamicTemperature::new::<degree_celsius>(750.),
+            starter_stall_torque: Torque::new::<newton_meter>(800.),
+            starter_free_running_n2: Ratio::new::<percent>(80.),
+            minimum_cranking_torque: Torque::new::<newton_meter>(390.),
+        }
+    }
+
+    pub fn trent_972() -> Self {
+        Self {
+            fuel_on_n2: Ratio::new::<percent>(20.),
+            in_flight_fuel_on_n2: Ratio::new::<percent>(12.),
+            starter_cut_out_n2: Ratio::new::<percent>(50.),
+            minimum_idle_n2: Ratio::new::<percent>(55.),
+            light_up_time_limit: Duration::from_secs(20),
+            start_egt_limit: ThermodynamicTemperature::new::<degree_celsius>(700.),
+            starter_stall_torque: Torque::new::<newton_meter>(1600.),
+            starter_free_running_n2: Ratio::new::<percent>(80.),
+            minimum_cranking_torque: Torque::new::<newton_meter>(640.),
+        }
+    }
+}
+
+/// The start function of the FADEC of one engine.
+///
+/// When a start is requested, it motors the engine with the air turbine starter, selects an
+/// igniter, introduces fuel at the target N2 and monitors light up and acceleration up to idle.
+/// A start is aborted on hung start, hot start, no light up or when the starter has been engaged
+/// for too long. The starter must cool down between consecutive start attempts.
+///
+/// The starter is driven by the air in the starter duct, which can be supplied by the APU,
+/// the other engine through the crossbleed valve or an air starter unit. The starter torque
+/// is proportional to the duct pressure. When it is too low to motor the engine up to the
+/// fuel on N2, the start is aborted as a hung start.
+///
+/// N2 and EGT are read from the engine model, so light up, hot start and hung start
+/// detection after light up depend on it.
+pub struct EngineStartController {
+    start_fault_id: VariableIdentifier,
+    start_valve_fault_id: VariableIdentifier,
+    igniter_a_active_id: VariableIdentifier,
+    igniter_b_active_id: VariableIdentifier,
+    start_fuel_on_id: VariableIdentifier,
+    starter_cooling_id: VariableIdentifier,
+
+    characteristics: EngineStartCharacteristics,
+
+    phase: EngineStartPhase,
+    fault: EngineStartFault,
+
+    igniter_a_selected: bool,
+    igniter_a_active: bool,
+    igniter_b_active: bool,
+
+    starter_valve_commanded_open: bool,
+    starter_valve_uncommanded_open_duration: Duration,
+    starter_torque: Torque,
+    low_starter_torque_duration: Duration,
+
+    starter_engaged_duration: Duration,
+    consecutive_starter_cycles: usize,
+    starter_cooldown_remaining: Duration,
+
+    egt_at_fuel_on: ThermodynamicTemperature,
+    duration_since_fuel_on: Duration,
+
+    acceleration_monitor_duration: Duration,
+    n2_at_acceleration_monitor_start: Ratio,
+}
+impl EngineStartController {
+    const MAX_STARTER_ENGAGEMENT: Duration = Duration::from_secs(120);
+    const MAX_CONSECUTIVE_STARTER_CYCLES: usize = 3;
+    const STARTER_COOLDOWN_BETWEEN_CYCLES: Duration = Duration::from_secs(20);
+    const STARTER_COOLDOWN_AFTER_MAX_CYCLES: Duration = Duration::from_secs(15 * 60);
+
+    // Starter container pressure of a start on APU bleed air
+    const STARTER_NOMINAL_PRESSURE_PSIG: f64 = 10.;
+    const MAX_STARTER_PRESSURE_RATIO: f64 = 1.2;
+
+    const LIGHT_UP_EGT_RISE_CELSIUS: f64 = 50.;
+
+    const ACCELERATION_MONITOR_PERIOD: Duration = Duration::from_secs(10);
+    const MIN_N2_INCREASE_PER_MONITOR_PERIOD_PERCENT: f64 = 1.;
+
+    const STARTER_VALVE_FAULT_DELAY: Duration = Duration::from_secs(5);
+
+    pub fn new(
+        context: &mut InitContext,
+        number: usize,
+        characteristics: EngineStartCharacteristics,
+    ) -> Self {
+        Self {
+            start_fault_id: context.get_identifier(format!("ENGINE_{}_START_FAULT", number)),
+            start_valve_fault_id: context
+                .get_identifier(format!("ENGINE_{}_START_VALVE_FAULT", number)),
+            igniter_a_active_id: context
+                .get_identifier(format!("ENGINE_{}_IGNITER_A_ACTIVE", number)),
+            igniter_b_active_id: context
+                .get_identifier(format!("ENGINE_{}_IGNITER_B_ACTIVE", number)),
+            start_fuel_on_id: context.get_identifier(format!("ENGINE_{}_START_FUEL_ON", number)),
+            starter_cooling_id: context
+                .get_identifier(format!("ENGINE_{}_STARTER_COOLING", number)),
+
+            characteristics,
+
+            phase: EngineStartPhase::Idle,
+            fault: EngineStartFault::None,
+
+            // Igniters alternate at each start
+            igniter_a_selected: false,
+            igniter_a_active: false,
+            igniter_b_active: false,
+
+            starter_valve_commanded_open: false,
+            starter_valve_uncommanded_open_duration: Duration::ZERO,
+            starter_torque: Torque::default(),
+            low_starter_torque_duration: Duration::ZERO,
+
+            starter_engaged_duration: Duration::ZERO,
+            consecutive_starter_cycles: 0,
+            starter_cooldown_remaining: Duration::ZERO,
+
+            egt_at_fuel_on: ThermodynamicTemperature::new::<degree_celsius>(0.),
+            duration_since_fuel_on: Duration::ZERO,
+
+            acceleration_monitor_duration: Duration::ZERO,
+            n2_at_acceleration_monitor_start: Ratio::default(),
+        }
+    }
+
+    pub fn update(
+        &mut self,
+        context: &UpdateContext,
+        engine_state: EngineState,
+        engine_mode_selector: EngineModeSelector,
+        engine: &(impl EngineUncorrectedN2 + EngineExhaustGasTemperature),
+        starter_duct_pressure: Pressure,
+        starter_valve_is_open: bool,
+    ) {
+        self.update_starter_torque(context, engine, starter_duct_pressure);
+        self.update_phase(context, engine_state, engine);
+        self.update_starter_duty_cycle(context);
+        self.update_ignition(context, engine_mode_selector, engine);
+        self.update_starter_valve_fault(context, starter_valve_is_open);
+    }
+
+    fn update_phase(
+        &mut self,
+        context: &UpdateContext,
+        engine_state: EngineState,
+        engine: &(impl EngineUncorrectedN2 + EngineExhaustGasTemperature),
+    ) {
+        let start_is_requested = matches!(
+            engine_state,
+            EngineState::Starting | EngineState::Restarting
+        );
+        let n2 = engine.uncorrected_n2();
+        let egt = engine.exhaust_gas_temperature();
+
+        if !start_is_requested {
+            // The fault is cleared when the master switch is set to OFF
+            self.phase = EngineStartPhase::Idle;
+            self.fault = EngineStartFault::None;
+            self.starter_valve_commanded_open = false;
+            return;
+        }
+
+        self.phase = match self.phase {
+            EngineStartPhase::Idle if self.starter_cooldown_remaining == Duration::ZERO => {
+                self.igniter_a_selected = !self.igniter_a_selected;
+                self.consecutive_starter_cycles += 1;
+                self.low_starter_torque_duration = Duration::ZERO;
+
+                EngineStartPhase::Motoring
+            }
+            EngineStartPhase::Motoring if n2 >= self.fuel_on_n2(context) => {
+                self.egt_at_fuel_on = egt;
+                self.duration_since_fuel_on = Duration::ZERO;
+
+                EngineStartPhase::Ignition
+            }
+            EngineStartPhase::Motoring => self.update_motoring_monitor(context),
+            EngineStartPhase::Ignition => {
+                self.duration_since_fuel_on += context.delta();
+
+                if egt.get::<degree_celsius>() - self.egt_at_fuel_on.get::<degree_celsius>()
+                    >= Self::LIGHT_UP_EGT_RISE_CELSIUS
+                {
+                    self.acceleration_monitor_duration = Duration::ZERO;
+                    self.n2_at_acceleration_monitor_start = n2;
+
+                    EngineStartPhase::Acceleration
+                } else if self.duration_since_fuel_on > self.characteristics.light_up_time_limit {
+                    self.abort(EngineStartFault::NoLightUp)
+                } else {
+                    EngineStartPhase::Ignition
+                }
+            }
+            EngineStartPhase::Acceleration => {
+                if n2 >= self.characteristics.minimum_idle_n2 {
+                    self.consecutive_starter_cycles = 0;
+
+                    EngineStartPhase::Running
+                } else {
+                    self.update_acceleration_monitor(context, n2)
+                }
+            }
+            phase => phase,
+        };
+
+        if matches!(
+            self.phase,
+            EngineStartPhase::Ignition | EngineStartPhase::Acceleration
+        ) && egt > self.characteristics.start_egt_limit
+        {
+            self.phase = self.abort(EngineStartFault::HotStart);
+        }
+
+        self.starter_valve_commanded_open = matches!(
+            self.phase,
+            EngineStartPhase::Motoring
+                | EngineStartPhase::Ignition
+                | EngineStartPhase::Acceleration
+        ) && n2 < self.characteristics.starter_cut_out_n2;
+    }
+
+    fn update_motoring_monitor(&mut self, context: &UpdateContext) -> EngineStartPhase {
+        if self.starter_torque < self.characteristics.minimum_cranking_torque {
+            self.low_starter_torque_duration += context.delta();
+        } else {
+            self.low_starter_torque_duration = Duration::ZERO;
+        }
+
+        if self.low_starter_torque_duration >= Self::ACCELERATION_MONITOR_PERIOD {
+            self.abort(EngineStartFault::HungStart)
+        } else {
+            EngineStartPhase::Motoring
+        }
+    }
+
+    fn update_acceleration_monitor(
+        &mut self,
+        context: &UpdateContext,
+        n2: Ratio,
+    ) -> EngineStartPhase {
+        self.acceleration_monitor_duration += context.delta();
+
+        if self.acceleration_monitor_duration < Self::ACCELERATION_MONITOR_PERIOD {
+            EngineStartPhase::Acceleration
+        } else if (n2 - self.n2_at_acceleration_monitor_start).get::<percent>()
+            < Self::MIN_N2_INCREASE_PER_MONITOR_PERIOD_PERCENT
+        {
+            self.abort(EngineStartFault::HungStart)
+        } else {
+            self.acceleration_monitor_duration = Duration::ZERO;
+            self.n2_at_acceleration_monitor_start = n2;
+
+            EngineStartPhase::Acceleration
+        }
+    }
+
+    fn abort(&mut self, fault: EngineStartFault) -> EngineStartPhase {
+        self.fault = fault;
+
+        EngineStartPhase::Aborted
+    }
+
+    fn fuel_on_n2(&self, context: &UpdateContext) -> Ratio {
+        if context.is_on_ground() {
+            self.characteristics.fuel_on_n2
+        } else {
+            self.characteristics.in_flight_fuel_on_n2
+        }
+    }
+
+    fn update_starter_duty_cycle(&mut self, context: &UpdateContext) {
+        if self.starter_valve_commanded_open {
+            self.starter_engaged_duration += context.delta();
+
+            if self.starter_engaged_duration > Self::MAX_STARTER_ENGAGEMENT {
+                self.phase = self.abort(EngineStartFault::StarterTimeExceeded);
+                self.starter_valve_commanded_open = false;
+            }
+        } else if self.starter_engaged_duration > Duration::ZERO {
+            // The starter has just been disengaged
+            self.starter_engaged_duration = Duration::ZERO;
+            self.starter_cooldown_remaining =
+                if self.consecutive_starter_cycles >= Self::MAX_CONSECUTIVE_STARTER_CYCLES {
+                    self.consecutive_starter_cycles = 0;
+                    Self::STARTER_COOLDOWN_AFTER_MAX_CYCLES
+                } else {
+                    Self::STARTER_COOLDOWN_BETWEEN_CYCLES
+                };
+        } else {
+            self.starter_cooldown_remaining = self
+                .starter_cooldown_remaining
+                .saturating_sub(context.delta());
+        }
+    }
+
+    fn update_ignition(
+        &mut self,
+        context: &UpdateContext,
+        engine_mode_selector: EngineModeSelector,
+        engine: &impl EngineUncorrectedN2,
+    ) {
+        let start_ignition = matches!(
+            self.phase,
+            EngineStartPhase::Ignition | EngineStartPhase::Acceleration
+        );
+        // Both igniters are used for in flight starts, and continuously with the mode selector
+        // on IGN/START once the engine is running in flight
+        let dual_ignition = !context.is_on_ground()
+            && (start_ignition
+                || (engine_mode_selector == EngineModeSelector::Ignition
+                    && engine.uncorrected_n2() >= self.characteristics.minimum_idle_n2));
+
+        self.igniter_a_active = dual_ignition || (start_ignition && self.igniter_a_selected);
+        self.igniter_b_active = dual_ignition || (start_ignition && !self.igniter_a_selected);
+    }
+
+    fn update_starter_valve_fault(&mut self, context: &UpdateContext, starter_valve_is_open: bool) {
+        if starter_valve_is_open && !self.starter_valve_commanded_open {
+            self.starter_valve_uncommanded_open_duration += context.delta();
+        } else {
+            self.starter_valve_uncommanded_open_duration = Duration::ZERO;
+        }
+    }
+
+    fn update_starter_torque(
+        &mut self,
+        context: &UpdateContext,
+        engine: &impl EngineUncorrectedN2,
+        starter_duct_pressure: Pressure,
+    ) {
+        // An air turbine starter delivers its stall torque at standstill and none at its
+        // free running speed. The torque is proportional to the air pressure it receives.
+        let pressure_ratio = ((starter_duct_pressure - context.ambient_pressure()).get::<psi>()
+            / Self::STARTER_NOMINAL_PRESSURE_PSIG)
+            .clamp(0., Self::MAX_STARTER_PRESSURE_RATIO);
+        let speed_ratio = (1.
+            - (engine.uncorrected_n2() / self.characteristics.starter_free_running_n2)
+                .get::<ratio>())
+        .max(0.);
+
+        self.starter_torque =
+            self.characteristics.starter_stall_torque * pressure_ratio * speed_ratio;
+    }
+
+    pub fn starter_valve_should_open(&self) -> bool {
+        self.starter_valve_commanded_open
+    }
+
+    pub fn starter_torque(&self) -> Torque {
+        self.starter_torque
+    }
+
+    pub fn start_fuel_is_on(&self) -> bool {
+        matches!(
+            self.phase,
+            EngineStartPhase::Ignition | EngineStartPhase::Acceleration | EngineStartPhase::Running
+        )
+    }
+
+    pub fn igniter_a_is_active(&self) -> bool {
+        self.igniter_a_active
+    }
+
+    pub fn igniter_b_is_active(&self) -> bool {
+        self.igniter_b_active
+    }
+
+    pub fn start_fault(&self) -> EngineStartFault {
+        self.fault
+    }
+
+    pub fn has_starter_valve_fault(&self) -> bool {
+        self.starter_valve_uncommanded_open_duration > Self::STARTER_VALVE_FAULT_DELAY
+    }
+
+    pub fn starter_is_cooling(&self) -> bool {
+        self.starter_cooldown_remaining > Duration::ZERO
+    }
+}
+impl SimulationElement for EngineStartController {
+    fn write(&self, writer: &mut SimulatorWriter) {
+        writer.write(&self.start_fault_id, self.fault);
+        writer.write(&self.start_valve_fault_id, self.has_starter_valve_fault());
+        writer.write(&self.igniter_a_active_id, self.igniter_a_active);
+        writer.write(&self.igniter_b_active_id, self.igniter_b_active);
+        writer.write(&self.start_fuel_on_id, self.start_fuel_is_on());
+        writer.write(&self.starter_cooling_id, self.starter_is_cooling());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::simulation::{
+        test::{SimulationTestBed, TestBed},
+        Aircraft, SimulationElementVisitor,
+    };
+
+    struct TestEngine {
+        n2: Ratio,
+        egt: ThermodynamicTemperature,
+    }
+    impl EngineUncorrectedN2 for TestEngine {
+        fn uncorrected_n2(&self) -> Ratio {
+            self.n2
+        }
+    }
+    impl EngineExhaustGasTemperature for TestEngine {
+        fn exhaust_gas_temperature(&self) -> ThermodynamicTemperature {
+            self.egt
+        }
+    }
+
+    struct TestAircraft {
+        controller: EngineStartController,
+        engine: TestEngine,
+        engine_state: EngineState,
+        engine_mode_selector: EngineModeSelector,
+        starter_duct_pressure: Pressure,
+        starter_valve_is_open: Option<bool>,
+    }
+    impl TestAircraft {
+        fn new(context: &mut InitContext) -> Self {
+            Self {
+                controller: EngineStartController::new(
+                    context,
+                    1,
+                    EngineStartCharacteristics::leap_1a26(),
+                ),
+                engine: TestEngine {
+                    n2: Ratio::default(),
+                    egt: ThermodynamicTemperature::new::<degree_celsius>(15.),
+                },
+                engine_state: EngineState::Off,
+                engine_mode_selector: EngineModeSelector::Norm,
+                starter_duct_pressure: Pressure::new::<psi>(14.7 + 10.),
+                starter_valve_is_open: None,
+            }
+        }
+    }
+    impl Aircraft for TestAircraft {
+        fn update_after_power_distribution(&mut self, context: &UpdateContext) {
+            // Unless stated otherwise, the starter valve follows its command
+            let starter_valve_commanded_open = self.controller.starter_valve_should_open();
+            let starter_valve_is_open = self
+                .starter_valve_is_open
+                .unwrap_or(starter_valve_commanded_open);
+
+            self.controller.update(
+                context,
+                self.engine_state,
+                self.engine_mode_selector,
+                &self.engine,
+                self.starter_duct_pressure,
+                starter_valve_is_open,
+            );
+        }
+    }
+    impl SimulationElement for TestAircraft {
+        fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
+            self.controller.accept(visitor);
+
+            visitor.visit(self);
+        }
+    }
+
+    struct StartControllerTestBed {
+        test_bed: SimulationTestBed<TestAircraft>,
+    }
+    impl StartControllerTestBed {
+        fn new() -> Self {
+            let mut test_bed = Self {
+                test_bed: SimulationTestBed::new(TestAircraft::new),
+            };
+            test_bed.set_on_ground(true);
+            test_bed.set_ambient_pressure(Pressure::new::<psi>(14.7));
+
+            test_bed
+        }
+
+        fn and_run_for(mut self, duration: Duration) -> Self {
+            for _ in 0..duration.as_secs() {
+                self.run_with_delta(Duration::from_secs(1));
+            }
+
+            self
+        }
+
+        fn start_requested(mut self) -> Self {
+            self.command(|a| a.engine_state = EngineState::Starting);
+            self.and_run_for(Duration::from_secs(1))
+        }
+
+        fn master_off(mut self) -> Self {
+            self.command(|a| a.engine_state = EngineState::Off);
+            self.and_run_for(Duration::from_secs(1))
+        }
+
+        fn n2(mut self, n2_percent: f64) -> Self {
+            self.command(|a| a.engine.n2 = Ratio::new::<percent>(n2_percent));
+            self.and_run_for(Duration::from_secs(1))
+        }
+
+        fn egt(mut self, egt_celsius: f64) -> Self {
+            self.command(|a| {
+                a.engine.egt = ThermodynamicTemperature::new::<degree_celsius>(egt_celsius)
+            });
+            self.and_run_for(Duration::from_secs(1))
+        }
+
+        fn lit(self) -> Self {
+            self.n2(25.).egt(200.)
+        }
+
+        fn starter_duct_pressure_psig(mut self, pressure: f64) -> Self {
+            self.command(|a| a.starter_duct_pressure = Pressure::new::<psi>(14.7 + pressure));
+            self
+        }
+
+        fn starter_valve_stuck_open(mut self) -> Self {
+            self.command(|a| a.starter_valve_is_open = Some(true));
+            self
+        }
+
+        fn starter_valve_should_open(&self) -> bool {
+            self.query(|a| a.controller.starter_valve_should_open())
+        }
+
+        fn start_fuel_is_on(&self) -> bool {
+            self.query(|a| a.controller.start_fuel_is_on())
+        }
+
+        fn start_fault(&self) -> EngineStartFault {
+            self.query(|a| a.controller.start_fault())
+        }
+
+        fn igniters_active(&self) -> (bool, bool) {
+            self.query(|a| {
+                (
+                    a.controller.igniter_a_is_active(),
+                    a.controller.igniter_b_is_active(),
+                )
+            })
+        }
+
+        fn starter_torque(&self) -> Torque {
+            self.query(|a| a.controller.starter_torque())
+        }
+
+        fn has_starter_valve_fault(&self) -> bool {
+            self.query(|a| a.controller.has_starter_valve_fault())
+        }
+
+        fn starter_is_cooling(&self) -> bool {
+            self.query(|a| a.controller.starter_is_cooling())
+        }
+    }
+    impl TestBed for StartControllerTestBed {
+        type Aircraft = TestAircraft;
+
+        fn test_bed(&self) -> &SimulationTestBed<TestAircraft> {
+            &self.test_bed
+        }
+
+        fn test_bed_mut(&mut self) -> &mut SimulationTestBed<TestAircraft> {
+            &mut self.test_bed
+        }
+    }
+
+    fn test_bed() -> StartControllerTestBed {
+        StartControllerTestBed::new()
+    }
+
+    #[test]
+    fn starter_valve_is_closed_without_start_request() {
+        let test_bed = test_bed().and_run_for(Duration::from_secs(1));
+
+        assert!(!test_bed.starter_valve_should_open());
+        assert!(!test_bed.start_fuel_is_on());
+        assert_eq!(test_bed.igniters_active(), (false, false));
+    }
+
+    #[test]
+    fn starter_valve_opens_when_start_is_requested() {
+        let test_bed = test_bed().start_requested();
+
+        assert!(test_bed.starter_valve_should_open());
+        assert!(!test_bed.start_fuel_is_on());
+    }
+
+    #[test]
+    fn fuel_and_ignition_are_on_at_target_n2() {
+        let test_bed = test_bed().start_requested().n2(15.);
+
+        assert!(!test_bed.start_fuel_is_on());
+
+        let test_bed = test_bed.n2(22.);
+
+        assert!(test_bed.start_fuel_is_on());
+        assert_ne!(test_bed.igniters_active(), (false, false));
+    }
+
+    #[test]
+    fn starter_cuts_out_after_light_up() {
+        let test_bed = test_bed().start_requested().lit().n2(45.);
+
+        assert!(test_bed.starter_valve_should_open());
+
+        let test_bed = test_bed.n2(60.);
+
+        assert!(!test_bed.starter_valve_should_open());
+        assert!(test_bed.start_fuel_is_on());
+        assert_eq!(test_bed.start_fault(), EngineStartFault::None);
+    }
+
+    #[test]
+    fn no_light_up_aborts_start() {
+        let test_bed = test_bed()
+            .start_requested()
+            .n2(25.)
+            .and_run_for(Duration::from_secs(16));
+
+        assert_eq!(test_bed.start_fault(), EngineStartFault::NoLightUp);
+        assert!(!test_bed.starter_valve_should_open());
+        assert!(!test_bed.start_fuel_is_on());
+    }
+
+    #[test]
+    fn hot_start_aborts_start() {
+        let test_bed = test_bed().start_requested().lit().egt(760.);
+
+        assert_eq!(test_bed.start_fault(), EngineStartFault::HotStart);
+        assert!(!test_bed.start_fuel_is_on());
+    }
+
+    #[test]
+    fn hung_start_aborts_start() {
+        let test_bed = test_bed()
+            .start_requested()
+            .lit()
+            .n2(40.)
+            .and_run_for(Duration::from_secs(20));
+
+        assert_eq!(test_bed.start_fault(), EngineStartFault::HungStart);
+        assert!(!test_bed.starter_valve_should_open());
+    }
+
+    #[test]
+    fn accelerating_engine_is_not_a_hung_start() {
+        let mut test_bed = test_bed().start_requested().lit();
+
+        for n2 in [30., 35., 40., 45., 50.] {
+            test_bed = test_bed.n2(n2).and_run_for(Duration::from_secs(5));
+        }
+
+        assert_eq!(test_bed.start_fault(), EngineStartFault::None);
+    }
+
+    #[test]
+    fn starter_time_limit_aborts_start() {
+        let test_bed = test_bed()
+            .start_requested()
+            .n2(10.)
+            .and_run_for(Duration::from_secs(120));
+
+        assert_eq!(
+            test_bed.start_fault(),
+            EngineStartFault::StarterTimeExceeded
+        );
+        assert!(!test_bed.starter_valve_should_open());
+    }
+
+    #[test]
+    fn start_fault_is_cleared_when_master_is_set_off() {
+        let test_bed = test_bed().start_requested().lit().egt(760.);
+
+        assert_eq!(test_bed.start_fault(), EngineStartFault::HotStart);
+
+        let test_bed = test_bed.master_off();
+
+        assert_eq!(test_bed.start_fault(), EngineStartFault::None);
+    }
+
+    #[test]
+    fn starter_must_cool_down_between_start_attempts() {
+        let test_bed = test_bed()
+            .start_requested()
+            .n2(25.)
+            .and_run_for(Duration::from_secs(16))
+            .n2(0.)
+            .egt(15.)
+            .master_off()
+            .start_requested();
+
+        assert!(test_bed.starter_is_cooling());
+        assert!(!test_bed.starter_valve_should_open());
+
+        let test_bed = test_bed.and_run_for(Duration::from_secs(20));
+
+        assert!(!test_bed.starter_is_cooling());
+        assert!(test_bed.starter_valve_should_open());
+    }
+
+    #[test]
+    fn starter_needs_long_cool_down_after_three_start_attempts() {
+        let mut test_bed = test_bed();
+
+        for _ in 0..3 {
+            test_bed = test_bed
+                .start_requested()
+                .n2(25.)
+                .and_run_for(Duration::from_secs(16))
+                .n2(0.)
+                .master_off()
+                .and_run_for(Duration::from_secs(30));
+        }
+
+        let test_bed = test_bed
+            .start_requested()
+            .and_run_for(Duration::from_secs(60));
+
+        assert!(test_bed.starter_is_cooling());
+        assert!(!test_bed.starter_valve_should_open());
+    }
+
+    #[test]
+    fn igniters_alternate_between_ground_starts() {
+        let test_bed = test_bed().start_requested().n2(25.);
+        let first_start_igniters = test_bed.igniters_active();
+
+        let test_bed = test_bed
+            .master_off()
+            .n2(0.)
+            .and_run_for(Duration::from_secs(30))
+            .start_requested()
+            .n2(25.);
+        let second_start_igniters = test_bed.igniters_active();
+
+        assert_ne!(first_start_igniters.0, first_start_igniters.1);
+        assert_ne!(second_start_igniters.0, second_start_igniters.1);
+        assert_ne!(first_start_igniters, second_start_igniters);
+    }
+
+    #[test]
+    fn both_igniters_are_used_for_in_flight_start() {
+        let mut test_bed = test_bed();
+        test_bed.set_on_ground(false);
+
+        let test_bed = test_bed.start_requested().n2(15.);
+
+        assert!(test_bed.start_fuel_is_on());
+        assert_eq!(test_bed.igniters_active(), (true, true));
+    }
+
+    #[test]
+    fn continuous_ignition_in_flight_with_mode_selector_on_ign() {
+        let mut test_bed = test_bed().n2(80.);
+        test_bed.set_on_ground(false);
+        test_bed.command(|a| a.engine_mode_selector = EngineModeSelector::Ignition);
+
+        let test_bed = test_bed.and_run_for(Duration::from_secs(1));
+
+        assert_eq!(test_bed.igniters_active(), (true, true));
+    }
+
+    #[test]
+    fn starter_torque_decreases_with_n2() {
+        let test_bed = test_bed().start_requested();
+        let standstill_torque = test_bed.starter_torque();
+
+        let test_bed = test_bed.n2(40.);
+
+        assert!(standstill_torque > Torque::default());
+        assert!(test_bed.starter_torque() < standstill_torque);
+    }
+
+    #[test]
+    fn starter_torque_depends_on_duct_pressure() {
+        let test_bed = test_bed().start_requested();
+        let nominal_torque = test_bed.starter_torque();
+
+        let test_bed = test_bed
+            .starter_duct_pressure_psig(5.)
+            .and_run_for(Duration::from_secs(1));
+
+        assert!(test_bed.starter_torque() < nominal_torque);
+    }
+
+    #[test]
+    fn low_starter_duct_pressure_is_a_hung_start() {
+        let test_bed = test_bed()
+            .starter_duct_pressure_psig(5.)
+            .start_requested()
+            .n2(18.)
+            .and_run_for(Duration::from_secs(10));
+
+        assert_eq!(test_bed.start_fault(), EngineStartFault::HungStart);
+        assert!(!test_bed.starter_valve_should_open());
+        assert!(!test_bed.start_fuel_is_on());
+    }
+
+    #[test]
+    fn nominal_starter_duct_pressure_motors_the_engine_without_fault() {
+        let test_bed = test_bed()
+            .start_requested()
+            .n2(18.)
+            .and_run_for(Duration::from_secs(30));
+
+        assert_eq!(test_bed.start_fault(), EngineStartFault::None);
+        assert!(test_bed.starter_valve_should_open());
+    }
+
+    #[test]
+    fn uncommanded_open_starter_valve_is_a_fault() {
+        let test_bed = test_bed()
+            .starter_valve_stuck_open()
+            .and_run_for(Duration::from_secs(4));
+
+        assert!(!test_bed.has_starter_valve_fault());
+
+        let test_bed = test_bed.and_run_for(Duration::from_secs(2));
+
+        assert!(test_bed.has_starter_valve_fault());
+    }
+}
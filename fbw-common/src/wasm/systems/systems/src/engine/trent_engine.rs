@@ -1,21 +1,40 @@
-use uom::si::{angular_velocity::revolution_per_minute, f64::*, pressure::psi, ratio::percent};
+use uom::si::{
+    angular_velocity::revolution_per_minute, f64::*, mass_rate::kilogram_per_second,
+    ratio::percent, thermodynamic_temperature::degree_celsius,
+};
 
 use crate::{
-    shared::{EngineCorrectedN1, EngineCorrectedN2, EngineUncorrectedN2},
-    simulation::{Read, SimulationElement, SimulatorReader, UpdateContext},
+    fuel::FuelTemperature,
+    shared::{
+        EngineCorrectedN1, EngineCorrectedN2, EngineExhaustGasTemperature, EngineUncorrectedN2,
+    },
+    simulation::{
+        Read, SimulationElement, SimulationElementVisitor, SimulatorReader, UpdateContext,
+    },
 };
 
-use super::Engine;
+use super::{
+    native_engine::{NativeEngine, NativeEngineCharacteristics, NativeEngineInputs},
+    oil_system::{EngineOilSystem, EngineOilSystemCharacteristics},
+    vibration_monitoring::{
+        EngineVibrationMonitoringCharacteristics, EngineVibrationMonitoringUnit,
+    },
+    Engine, EngineVibration,
+};
 use crate::simulation::{InitContext, VariableIdentifier};
 
 pub struct TrentEngine {
     thrust_id: VariableIdentifier,
+    egt_id: VariableIdentifier,
+    fuel_flow_id: VariableIdentifier,
 
     corrected_n1_id: VariableIdentifier,
     corrected_n1: Ratio,
     corrected_n2_id: VariableIdentifier,
     corrected_n2: Ratio,
 
+    uncorrected_n1_id: VariableIdentifier,
+    uncorrected_n1: Ratio,
     uncorrected_n2_id: VariableIdentifier,
     uncorrected_n2: Ratio,
     uncorrected_n3_id: VariableIdentifier,
@@ -23,9 +42,16 @@ pub struct TrentEngine {
 
     n3_speed: AngularVelocity,
     hydraulic_pump_output_speed: AngularVelocity,
-    oil_pressure: Pressure,
+    oil_system: EngineOilSystem,
+    vibration_monitoring: EngineVibrationMonitoringUnit,
+
+    native_engine_enabled_id: VariableIdentifier,
+    native_engine_enabled: bool,
+    native_engine: NativeEngine,
 
     net_thrust: Mass,
+    egt: ThermodynamicTemperature,
+    fuel_flow: MassRate,
 }
 impl TrentEngine {
     // 100% N1 @ 2900 RPM
@@ -40,16 +66,18 @@ impl TrentEngine {
 
     const MIN_IDLE_N2_UNCORRECTED_THRESHOLD_PERCENT: f64 = 55.;
 
-    const LOW_OIL_PRESSURE_THRESHOLD_PSI: f64 = 18.;
-
     pub fn new(context: &mut InitContext, number: usize) -> TrentEngine {
         TrentEngine {
             thrust_id: context.get_identifier(format!("TURB ENG JET THRUST:{}", number)),
+            egt_id: context.get_identifier(format!("ENGINE_EGT:{}", number)),
+            fuel_flow_id: context.get_identifier(format!("ENGINE_FF:{}", number)),
 
             corrected_n1_id: context.get_identifier(format!("TURB ENG CORRECTED N1:{}", number)),
             corrected_n1: Ratio::new::<percent>(0.),
             corrected_n2_id: context.get_identifier(format!("TURB ENG CORRECTED N2:{}", number)),
             corrected_n2: Ratio::new::<percent>(0.),
+            uncorrected_n1_id: context.get_identifier(format!("ENGINE_N1:{}", number)),
+            uncorrected_n1: Ratio::new::<percent>(0.),
             uncorrected_n2_id: context.get_identifier(format!("ENGINE_N2:{}", number)),
             uncorrected_n2: Ratio::new::<percent>(0.),
             uncorrected_n3_id: context.get_identifier(format!("ENGINE_N3:{}", number)),
@@ -57,13 +85,83 @@ impl TrentEngine {
 
             n3_speed: AngularVelocity::default(),
             hydraulic_pump_output_speed: AngularVelocity::new::<revolution_per_minute>(0.),
-            oil_pressure: Pressure::new::<psi>(0.),
+            oil_system: EngineOilSystem::new(
+                context,
+                number,
+                EngineOilSystemCharacteristics::trent_972(),
+            ),
+            vibration_monitoring: EngineVibrationMonitoringUnit::new(
+                context,
+                number,
+                EngineVibrationMonitoringCharacteristics::trent_972(),
+            ),
+
+            native_engine_enabled_id: context
+                .get_identifier("ENGINE_NATIVE_MODEL_ENABLED".to_owned()),
+            native_engine_enabled: false,
+            native_engine: NativeEngine::new(
+                context,
+                number,
+                NativeEngineCharacteristics::trent_972(),
+            ),
 
             net_thrust: Mass::default(),
+            egt: ThermodynamicTemperature::new::<degree_celsius>(0.),
+            fuel_flow: MassRate::default(),
         }
     }
 
-    pub fn update(&mut self, _: &UpdateContext) {}
+    /// Updates the engine. When the native engine model is enabled, the spool speeds, EGT,
+    /// fuel flow and thrust are computed from `native_engine_inputs` instead of being read
+    /// from the simulator engine.
+    pub fn update(
+        &mut self,
+        context: &UpdateContext,
+        fuel: &impl FuelTemperature,
+        native_engine_inputs: NativeEngineInputs,
+    ) {
+        if self.native_engine_enabled {
+            self.native_engine.update(context, native_engine_inputs);
+            self.update_from_native_engine();
+        }
+
+        self.oil_system.update(
+            context,
+            self.uncorrected_n3,
+            self.fuel_flow,
+            fuel.fuel_temperature(),
+        );
+
+        self.vibration_monitoring.update(
+            context,
+            &[
+                self.uncorrected_n1,
+                self.uncorrected_n2,
+                self.uncorrected_n3,
+            ],
+        );
+    }
+
+    /// The heat given to the fuel by the fuel/oil heat exchanger during the last update.
+    pub fn heat_rejected_to_fuel(&self) -> Power {
+        self.oil_system.heat_rejected_to_fuel()
+    }
+
+    fn update_from_native_engine(&mut self) {
+        self.corrected_n1 = self.native_engine.corrected_n1();
+        self.corrected_n2 = self.native_engine.corrected_n2();
+        self.uncorrected_n1 = self.native_engine.uncorrected_n1();
+        self.uncorrected_n2 = self.native_engine.uncorrected_n2();
+        self.uncorrected_n3 = self
+            .native_engine
+            .uncorrected_n3()
+            .unwrap_or(self.uncorrected_n2);
+        self.net_thrust = self.native_engine.net_thrust();
+        self.egt = self.native_engine.exhaust_gas_temperature();
+        self.fuel_flow = self.native_engine.fuel_flow();
+
+        self.update_parameters();
+    }
 
     fn update_parameters(&mut self) {
         self.n3_speed = AngularVelocity::new::<revolution_per_minute>(
@@ -71,17 +169,34 @@ impl TrentEngine {
         );
         self.hydraulic_pump_output_speed = self.n3_speed * Self::PUMP_N3_GEAR_RATIO;
 
-        // Ultra stupid model just to have 18psi crossing at 25% N2
-        self.oil_pressure = Pressure::new::<psi>(18. / 25. * self.uncorrected_n2.get::<percent>());
+        self.oil_system.update_pressure(self.uncorrected_n3);
     }
 }
 impl SimulationElement for TrentEngine {
+    fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
+        self.oil_system.accept(visitor);
+        self.vibration_monitoring.accept(visitor);
+        self.native_engine.accept(visitor);
+
+        visitor.visit(self);
+    }
+
     fn read(&mut self, reader: &mut SimulatorReader) {
+        self.native_engine_enabled = reader.read(&self.native_engine_enabled_id);
+        if self.native_engine_enabled {
+            return;
+        }
+
         self.corrected_n1 = reader.read(&self.corrected_n1_id);
         self.corrected_n2 = reader.read(&self.corrected_n2_id);
+        self.uncorrected_n1 = reader.read(&self.uncorrected_n1_id);
         self.uncorrected_n2 = reader.read(&self.uncorrected_n2_id);
         self.uncorrected_n3 = reader.read(&self.uncorrected_n3_id);
         self.net_thrust = reader.read(&self.thrust_id);
+        self.egt = reader.read(&self.egt_id);
+
+        let fuel_flow_kilogram_per_hour: f64 = reader.read(&self.fuel_flow_id);
+        self.fuel_flow = MassRate::new::<kilogram_per_second>(fuel_flow_kilogram_per_hour / 3600.);
 
         self.update_parameters();
     }
@@ -101,13 +216,23 @@ impl EngineUncorrectedN2 for TrentEngine {
         self.uncorrected_n2
     }
 }
+impl EngineExhaustGasTemperature for TrentEngine {
+    fn exhaust_gas_temperature(&self) -> ThermodynamicTemperature {
+        self.egt
+    }
+}
+impl EngineVibration for TrentEngine {
+    fn vibration(&self) -> f64 {
+        self.vibration_monitoring.max_vibration()
+    }
+}
 impl Engine for TrentEngine {
     fn hydraulic_pump_output_speed(&self) -> AngularVelocity {
         self.hydraulic_pump_output_speed
     }
 
     fn oil_pressure_is_low(&self) -> bool {
-        self.oil_pressure.get::<psi>() < TrentEngine::LOW_OIL_PRESSURE_THRESHOLD_PSI
+        self.oil_system.pressure_is_low()
     }
 
     fn is_above_minimum_idle(&self) -> bool {
@@ -123,3 +248,148 @@ impl Engine for TrentEngine {
         self.n3_speed
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::simulation::{
+        test::{SimulationTestBed, TestBed, WriteByName},
+        Aircraft,
+    };
+    use std::time::Duration;
+    use uom::si::{angle::degree, torque::newton_meter, velocity::knot};
+
+    struct TestFuel {}
+    impl FuelTemperature for TestFuel {
+        fn fuel_temperature(&self) -> ThermodynamicTemperature {
+            ThermodynamicTemperature::new::<degree_celsius>(15.)
+        }
+    }
+
+    struct TestAircraft {
+        engine: TrentEngine,
+        fuel: TestFuel,
+        fuel_is_on: bool,
+        starter_torque: Torque,
+    }
+    impl TestAircraft {
+        fn new(context: &mut InitContext) -> Self {
+            Self {
+                engine: TrentEngine::new(context, 1),
+                fuel: TestFuel {},
+                fuel_is_on: false,
+                starter_torque: Torque::default(),
+            }
+        }
+    }
+    impl Aircraft for TestAircraft {
+        fn update_after_power_distribution(&mut self, context: &UpdateContext) {
+            self.engine.update(
+                context,
+                &self.fuel,
+                NativeEngineInputs::new(
+                    self.fuel_is_on,
+                    self.starter_torque,
+                    MassRate::default(),
+                    Power::default(),
+                ),
+            );
+        }
+    }
+    impl SimulationElement for TestAircraft {
+        fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
+            self.engine.accept(visitor);
+
+            visitor.visit(self);
+        }
+    }
+
+    struct TrentEngineTestBed {
+        test_bed: SimulationTestBed<TestAircraft>,
+    }
+    impl TrentEngineTestBed {
+        fn new() -> Self {
+            let mut test_bed = Self {
+                test_bed: SimulationTestBed::new(TestAircraft::new),
+            };
+            test_bed.set_on_ground(true);
+            test_bed.set_true_airspeed(Velocity::new::<knot>(0.));
+            test_bed.set_ambient_temperature(ThermodynamicTemperature::new::<degree_celsius>(15.));
+            test_bed.write_by_name("AUTOTHRUST_TLA:1", Angle::new::<degree>(0.));
+            test_bed.write_by_name("ENGINE_NATIVE_MODEL_ENABLED", true);
+
+            test_bed
+        }
+
+        fn and_run_for(mut self, duration: Duration) -> Self {
+            let steps = (duration.as_secs_f64() / 0.1).ceil() as usize;
+            for _ in 0..steps {
+                self.run_with_delta(Duration::from_millis(100));
+            }
+
+            self
+        }
+
+        fn started(mut self) -> Self {
+            self.command(|a| a.starter_torque = Torque::new::<newton_meter>(1080.));
+            self = self.and_run_for(Duration::from_secs(20));
+            self.command(|a| a.fuel_is_on = true);
+            self = self.and_run_for(Duration::from_secs(60));
+            self.command(|a| a.starter_torque = Torque::default());
+
+            self.and_run_for(Duration::from_secs(10))
+        }
+
+        fn fuel_off(mut self) -> Self {
+            self.command(|a| a.fuel_is_on = false);
+            self
+        }
+
+        fn n2(&self) -> Ratio {
+            self.query(|a| a.engine.uncorrected_n2())
+        }
+
+        fn n3(&self) -> Ratio {
+            self.query(|a| a.engine.uncorrected_n3)
+        }
+
+        fn is_above_minimum_idle(&self) -> bool {
+            self.query(|a| a.engine.is_above_minimum_idle())
+        }
+
+        fn oil_pressure_is_low(&self) -> bool {
+            self.query(|a| a.engine.oil_pressure_is_low())
+        }
+    }
+    impl TestBed for TrentEngineTestBed {
+        type Aircraft = TestAircraft;
+
+        fn test_bed(&self) -> &SimulationTestBed<TestAircraft> {
+            &self.test_bed
+        }
+
+        fn test_bed_mut(&mut self) -> &mut SimulationTestBed<TestAircraft> {
+            &mut self.test_bed
+        }
+    }
+
+    #[test]
+    fn native_engine_starts_with_high_pressure_spool_above_intermediate_spool() {
+        let test_bed = TrentEngineTestBed::new().started();
+
+        assert!(test_bed.is_above_minimum_idle());
+        assert!(!test_bed.oil_pressure_is_low());
+        assert!(test_bed.n3() > test_bed.n2());
+    }
+
+    #[test]
+    fn native_engine_spools_down_when_fuel_is_cut_off() {
+        let test_bed = TrentEngineTestBed::new()
+            .started()
+            .fuel_off()
+            .and_run_for(Duration::from_secs(60));
+
+        assert!(!test_bed.is_above_minimum_idle());
+        assert!(test_bed.oil_pressure_is_low());
+    }
+}
@@ -0,0 +1,481 @@
+use std::time::Duration;
+
+use uom::si::{
+    f64::*,
+    ratio::{percent, ratio},
+};
+
+use crate::{
+    failures::{Failure, FailureType},
+    icing_state::{ActiveDeicingController, IcingState},
+    shared::low_pass_filter::LowPassFilter,
+    simulation::{
+        InitContext, SimulationElement, SimulationElementVisitor, SimulatorWriter, UpdateContext,
+        VariableIdentifier, Write,
+    },
+};
+
+/// Defines the vibration signature of an engine by, for each spool from the fan to the high
+/// pressure spool:
+///     the residual unbalance of a healthy spool, in vibration units at 100% speed
+///     the vibration level above which the ECAM advisory is triggered
+pub struct EngineVibrationMonitoringCharacteristics {
+    residual_unbalance: Vec<f64>,
+    advisory_thresholds: Vec<f64>,
+}
+impl EngineVibrationMonitoringCharacteristics {
+    pub fn leap_1a26() -> Self {
+        Self {
+            residual_unbalance: vec![0.6, 0.5],
+            advisory_thresholds: vec![6., 4.3],
+        }
+    }
+
+    pub fn trent_972() -> Self {
+        Self {
+            residual_unbalance: vec![0.4, 0.5, 0.6],
+            advisory_thresholds: vec![4.5, 4., 4.5],
+        }
+    }
+
+    fn spools_count(&self) -> usize {
+        self.residual_unbalance.len()
+    }
+}
+
+/// Ice accreted on the fan blades is thrown off by centrifugal effect when the fan spins fast
+/// enough.
+struct FanIceShedding {
+    n1: Ratio,
+}
+impl FanIceShedding {
+    const SHEDDING_START_N1_PERCENT: f64 = 60.;
+    const FULL_SHEDDING_N1_PERCENT: f64 = 90.;
+
+    fn new() -> Self {
+        Self {
+            n1: Ratio::default(),
+        }
+    }
+
+    fn update(&mut self, n1: Ratio) {
+        self.n1 = n1;
+    }
+}
+impl ActiveDeicingController for FanIceShedding {
+    fn active_deicing_normalized_rate(&self) -> Ratio {
+        Ratio::new::<ratio>(
+            ((self.n1.get::<percent>() - Self::SHEDDING_START_N1_PERCENT)
+                / (Self::FULL_SHEDDING_N1_PERCENT - Self::SHEDDING_START_N1_PERCENT))
+                .clamp(0., 1.),
+        )
+    }
+}
+
+/// The engine vibration monitoring unit computes the vibration level of each spool from the
+/// unbalance of its rotating parts.
+///
+/// The unbalance force grows with the square of the rotational speed. A healthy spool only has
+/// a small residual unbalance, while ice on the fan blades, a bird strike or a damaged fan add
+/// unbalance to the fan, and a damaged bearing adds unbalance to the high pressure spool. Part
+/// of the unbalance of a spool is felt by the sensors of the other spools through the engine
+/// structure.
+pub struct EngineVibrationMonitoringUnit {
+    vibration_ids: Vec<VariableIdentifier>,
+    advisory_ids: Vec<VariableIdentifier>,
+
+    characteristics: EngineVibrationMonitoringCharacteristics,
+
+    fan_icing: IcingState,
+    fan_ice_shedding: FanIceShedding,
+
+    vibrations: Vec<LowPassFilter<f64>>,
+
+    fan_damage_failure: Failure,
+    bird_strike_failure: Failure,
+    bearing_damage_failure: Failure,
+}
+impl EngineVibrationMonitoringUnit {
+    const MAX_VIBRATION_UNITS: f64 = 10.;
+    const VIBRATION_SENSING_TIME_CONSTANT: Duration = Duration::from_millis(1500);
+
+    const FULLY_ICED_FAN_UNBALANCE: f64 = 5.5;
+    const BIRD_STRIKE_FAN_UNBALANCE: f64 = 7.;
+    const DAMAGED_FAN_UNBALANCE: f64 = 12.;
+    const DAMAGED_BEARING_UNBALANCE: f64 = 6.;
+
+    const STRUCTURAL_COUPLING_RATIO: f64 = 0.15;
+
+    const TIME_TO_FAN_FULLY_ICED: Duration = Duration::from_secs(300);
+    const TIME_TO_FAN_PASSIVELY_DEICED: Duration = Duration::from_secs(600);
+    const TIME_TO_FAN_ICE_SHED: Duration = Duration::from_secs(20);
+
+    pub fn new(
+        context: &mut InitContext,
+        number: usize,
+        characteristics: EngineVibrationMonitoringCharacteristics,
+    ) -> Self {
+        let spools_count = characteristics.spools_count();
+
+        Self {
+            vibration_ids: (1..=spools_count)
+                .map(|spool| {
+                    context.get_identifier(format!("ENGINE_{}_N{}_VIBRATION", number, spool))
+                })
+                .collect(),
+            advisory_ids: (1..=spools_count)
+                .map(|spool| {
+                    context
+                        .get_identifier(format!("ENGINE_{}_N{}_VIBRATION_ADVISORY", number, spool))
+                })
+                .collect(),
+
+            characteristics,
+
+            fan_icing: IcingState::new(
+                context,
+                &format!("ENGINE_{}_FAN", number),
+                Self::TIME_TO_FAN_FULLY_ICED,
+                Self::TIME_TO_FAN_PASSIVELY_DEICED,
+                Some(Self::TIME_TO_FAN_ICE_SHED),
+            ),
+            fan_ice_shedding: FanIceShedding::new(),
+
+            vibrations: (0..spools_count)
+                .map(|_| LowPassFilter::new(Self::VIBRATION_SENSING_TIME_CONSTANT))
+                .collect(),
+
+            fan_damage_failure: Failure::new(FailureType::EngineFanDamage(number)),
+            bird_strike_failure: Failure::new(FailureType::EngineBirdStrike(number)),
+            bearing_damage_failure: Failure::new(FailureType::EngineBearingDamage(number)),
+        }
+    }
+
+    /// Spools speeds are given from the fan to the high pressure spool
+    pub fn update(&mut self, context: &UpdateContext, spools_speeds: &[Ratio]) {
+        self.fan_ice_shedding.update(spools_speeds[0]);
+        self.fan_icing.update(context, Some(&self.fan_ice_shedding));
+
+        let fan_extra_unbalance = self.fan_extra_unbalance();
+        let core_extra_unbalance = self.core_extra_unbalance();
+        let core_spool_index = self.characteristics.spools_count() - 1;
+
+        for (spool_index, ((vibration, residual), speed)) in self
+            .vibrations
+            .iter_mut()
+            .zip(&self.characteristics.residual_unbalance)
+            .zip(spools_speeds)
+            .enumerate()
+        {
+            let unbalance = residual
+                + Self::felt_unbalance(spool_index == 0, fan_extra_unbalance)
+                + Self::felt_unbalance(spool_index == core_spool_index, core_extra_unbalance);
+
+            let raw_vibration =
+                (unbalance * speed.get::<ratio>().max(0.).powi(2)).min(Self::MAX_VIBRATION_UNITS);
+
+            vibration.update(context.delta(), raw_vibration);
+        }
+    }
+
+    fn fan_extra_unbalance(&self) -> f64 {
+        self.fan_icing.normalized_state().get::<ratio>() * Self::FULLY_ICED_FAN_UNBALANCE
+            + if self.bird_strike_failure.is_active() {
+                Self::BIRD_STRIKE_FAN_UNBALANCE
+            } else {
+                0.
+            }
+            + if self.fan_damage_failure.is_active() {
+                Self::DAMAGED_FAN_UNBALANCE
+            } else {
+                0.
+            }
+    }
+
+    fn core_extra_unbalance(&self) -> f64 {
+        if self.bearing_damage_failure.is_active() {
+            Self::DAMAGED_BEARING_UNBALANCE
+        } else {
+            0.
+        }
+    }
+
+    /// Unbalance of a spool is fully felt on its own sensor, and only partly on other spools
+    fn felt_unbalance(is_own_spool: bool, unbalance: f64) -> f64 {
+        if is_own_spool {
+            unbalance
+        } else {
+            Self::STRUCTURAL_COUPLING_RATIO * unbalance
+        }
+    }
+
+    /// Vibration level of a spool in vibration units, spool 1 being the fan
+    pub fn vibration(&self, spool_number: usize) -> f64 {
+        self.vibrations[spool_number - 1].output()
+    }
+
+    /// Highest vibration level sensed on any spool, in vibration units
+    pub fn max_vibration(&self) -> f64 {
+        self.vibrations
+            .iter()
+            .map(|vibration| vibration.output())
+            .fold(0., f64::max)
+    }
+
+    pub fn advisory(&self, spool_number: usize) -> bool {
+        self.vibration(spool_number) >= self.characteristics.advisory_thresholds[spool_number - 1]
+    }
+}
+impl SimulationElement for EngineVibrationMonitoringUnit {
+    fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
+        self.fan_icing.accept(visitor);
+
+        self.fan_damage_failure.accept(visitor);
+        self.bird_strike_failure.accept(visitor);
+        self.bearing_damage_failure.accept(visitor);
+
+        visitor.visit(self);
+    }
+
+    fn write(&self, writer: &mut SimulatorWriter) {
+        for (spool_index, (vibration_id, advisory_id)) in self
+            .vibration_ids
+            .iter()
+            .zip(&self.advisory_ids)
+            .enumerate()
+        {
+            writer.write(vibration_id, self.vibration(spool_index + 1));
+            writer.write(advisory_id, self.advisory(spool_index + 1));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::simulation::{
+        test::{SimulationTestBed, TestBed, WriteByName},
+        Aircraft,
+    };
+    use more_asserts::{assert_gt, assert_lt};
+    use uom::si::thermodynamic_temperature::degree_celsius;
+
+    struct TestAircraft {
+        vibration_monitoring: EngineVibrationMonitoringUnit,
+        spools_speeds: Vec<Ratio>,
+    }
+    impl TestAircraft {
+        fn new(context: &mut InitContext) -> Self {
+            Self {
+                vibration_monitoring: EngineVibrationMonitoringUnit::new(
+                    context,
+                    1,
+                    EngineVibrationMonitoringCharacteristics::leap_1a26(),
+                ),
+                spools_speeds: vec![Ratio::default(); 2],
+            }
+        }
+    }
+    impl Aircraft for TestAircraft {
+        fn update_after_power_distribution(&mut self, context: &UpdateContext) {
+            self.vibration_monitoring
+                .update(context, &self.spools_speeds);
+        }
+    }
+    impl SimulationElement for TestAircraft {
+        fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
+            self.vibration_monitoring.accept(visitor);
+
+            visitor.visit(self);
+        }
+    }
+
+    struct VibrationTestBed {
+        test_bed: SimulationTestBed<TestAircraft>,
+    }
+    impl VibrationTestBed {
+        fn new() -> Self {
+            let mut test_bed = Self {
+                test_bed: SimulationTestBed::new(TestAircraft::new),
+            };
+            test_bed.set_ambient_temperature(ThermodynamicTemperature::new::<degree_celsius>(15.));
+
+            test_bed
+        }
+
+        fn and_run_for(mut self, duration: Duration) -> Self {
+            for _ in 0..duration.as_secs() {
+                self.run_with_delta(Duration::from_secs(1));
+            }
+
+            self
+        }
+
+        fn spools_speeds(mut self, n1_percent: f64, n2_percent: f64) -> Self {
+            self.command(|a| {
+                a.spools_speeds = vec![
+                    Ratio::new::<percent>(n1_percent),
+                    Ratio::new::<percent>(n2_percent),
+                ]
+            });
+
+            self
+        }
+
+        fn engine_at_idle(self) -> Self {
+            self.spools_speeds(20., 65.)
+        }
+
+        fn engine_at_take_off(self) -> Self {
+            self.spools_speeds(95., 98.)
+        }
+
+        fn in_icing_conditions(mut self) -> Self {
+            self.set_ambient_temperature(ThermodynamicTemperature::new::<degree_celsius>(-10.));
+            self.write_by_name("AMBIENT IN CLOUD", 1.);
+
+            self
+        }
+
+        fn vibration(&self, spool_number: usize) -> f64 {
+            self.query(|a| a.vibration_monitoring.vibration(spool_number))
+        }
+
+        fn advisory(&self, spool_number: usize) -> bool {
+            self.query(|a| a.vibration_monitoring.advisory(spool_number))
+        }
+    }
+    impl TestBed for VibrationTestBed {
+        type Aircraft = TestAircraft;
+
+        fn test_bed(&self) -> &SimulationTestBed<TestAircraft> {
+            &self.test_bed
+        }
+
+        fn test_bed_mut(&mut self) -> &mut SimulationTestBed<TestAircraft> {
+            &mut self.test_bed
+        }
+    }
+
+    fn test_bed() -> VibrationTestBed {
+        VibrationTestBed::new()
+    }
+
+    #[test]
+    fn stopped_engine_does_not_vibrate() {
+        let test_bed = test_bed().and_run_for(Duration::from_secs(10));
+
+        assert_eq!(test_bed.vibration(1), 0.);
+        assert_eq!(test_bed.vibration(2), 0.);
+    }
+
+    #[test]
+    fn healthy_engine_vibrates_below_one_unit_without_advisory() {
+        let test_bed = test_bed()
+            .engine_at_take_off()
+            .and_run_for(Duration::from_secs(20));
+
+        assert_gt!(test_bed.vibration(1), 0.3);
+        assert_lt!(test_bed.vibration(1), 1.);
+        assert_gt!(test_bed.vibration(2), 0.3);
+        assert_lt!(test_bed.vibration(2), 1.);
+        assert!(!test_bed.advisory(1));
+        assert!(!test_bed.advisory(2));
+    }
+
+    #[test]
+    fn vibration_increases_with_spool_speed() {
+        let mut test_bed = test_bed()
+            .engine_at_idle()
+            .and_run_for(Duration::from_secs(20));
+
+        let idle_vibration = test_bed.vibration(1);
+
+        test_bed = test_bed
+            .engine_at_take_off()
+            .and_run_for(Duration::from_secs(20));
+
+        assert_gt!(test_bed.vibration(1), idle_vibration);
+    }
+
+    #[test]
+    fn damaged_fan_triggers_n1_advisory() {
+        let mut test_bed = test_bed().engine_at_take_off();
+
+        test_bed.fail(FailureType::EngineFanDamage(1));
+        test_bed = test_bed.and_run_for(Duration::from_secs(20));
+
+        assert_gt!(test_bed.vibration(1), 9.);
+        assert!(test_bed.advisory(1));
+    }
+
+    #[test]
+    fn damaged_fan_is_felt_on_n2() {
+        let mut test_bed = test_bed().engine_at_take_off();
+
+        test_bed.fail(FailureType::EngineFanDamage(1));
+        test_bed = test_bed.and_run_for(Duration::from_secs(20));
+
+        assert_gt!(test_bed.vibration(2), 2.);
+    }
+
+    #[test]
+    fn bird_strike_triggers_n1_advisory_at_high_power_only() {
+        let mut test_bed = test_bed().engine_at_take_off();
+
+        test_bed.fail(FailureType::EngineBirdStrike(1));
+        test_bed = test_bed.and_run_for(Duration::from_secs(20));
+
+        assert!(test_bed.advisory(1));
+        assert!(!test_bed.advisory(2));
+
+        test_bed = test_bed
+            .engine_at_idle()
+            .and_run_for(Duration::from_secs(20));
+
+        assert!(!test_bed.advisory(1));
+    }
+
+    #[test]
+    fn damaged_bearing_triggers_n2_advisory() {
+        let mut test_bed = test_bed().engine_at_take_off();
+
+        test_bed.fail(FailureType::EngineBearingDamage(1));
+        test_bed = test_bed.and_run_for(Duration::from_secs(20));
+
+        assert!(test_bed.advisory(2));
+        assert!(!test_bed.advisory(1));
+    }
+
+    #[test]
+    fn fan_ice_builds_up_at_idle_in_icing_conditions() {
+        let clean_fan_vibration = test_bed()
+            .engine_at_idle()
+            .and_run_for(Duration::from_secs(20))
+            .vibration(1);
+
+        let test_bed = test_bed()
+            .in_icing_conditions()
+            .engine_at_idle()
+            .and_run_for(Duration::from_secs(300));
+
+        assert_gt!(test_bed.vibration(1), 2. * clean_fan_vibration);
+    }
+
+    #[test]
+    fn fan_ice_is_shed_at_high_power() {
+        let mut test_bed = test_bed()
+            .in_icing_conditions()
+            .engine_at_idle()
+            .and_run_for(Duration::from_secs(300))
+            .engine_at_take_off()
+            .and_run_for(Duration::from_secs(5));
+
+        let iced_vibration = test_bed.vibration(1);
+
+        test_bed = test_bed.and_run_for(Duration::from_secs(30));
+
+        assert_gt!(iced_vibration, 2.);
+        assert_lt!(test_bed.vibration(1), 1.);
+    }
+}
@@ -4,8 +4,9 @@ use crate::air_conditioning::{
 use crate::air_conditioning::{FdacId, OcsmId};
 use crate::integrated_modular_avionics::core_processing_input_output_module::CpiomId;
 use crate::shared::{
-    AirbusElectricPumpId, AirbusEngineDrivenPumpId, ElectricalBusType, FireDetectionLoopID,
-    FireDetectionZone, GearActuatorId, HydraulicColor, LgciuId, ProximityDetectorId,
+    AirbusElectricPumpId, AirbusEngineDrivenPumpId, BleedDuctZone, CargoDoorId, ElectricalBusType,
+    FireDetectionLoopID, FireDetectionZone, GearActuatorId, GearWheel, HydraulicColor, LgciuId,
+    ProximityDetectorId, SteeringActuatorId,
 };
 use crate::simulation::SimulationElement;
 use rustc_hash::FxHashSet;
@@ -47,6 +48,12 @@ pub enum FailureType {
     // ATA26
     SetOnFire(FireDetectionZone),
     FireDetectionLoop(FireDetectionLoopID, FireDetectionZone),
+    // ATA27
+    ElectroHydrostaticActuator(usize),
+    ThsJam,
+    ThsHydraulicMotor(HydraulicColor),
+    ThsElectricMotor(usize),
+    ThsTrimRunaway,
     // ATA29
     ReservoirLeak(HydraulicColor),
     ReservoirAirLeak(HydraulicColor),
@@ -58,15 +65,62 @@ pub enum FailureType {
     LgciuInternalError(LgciuId),
     GearProxSensorDamage(ProximityDetectorId),
     GearActuatorJammed(GearActuatorId),
+    GearStrutUnderServiced(GearWheel),
     BrakeHydraulicLeak(HydraulicColor),
     BrakeAccumulatorGasLeak,
+    TyreSlowLeak(usize),
+    TyreBurst(usize),
+    TyrePressureIndicatingSystem,
+    SteeringActuatorJammed(SteeringActuatorId),
+    SteeringServoValve(SteeringActuatorId),
+    SteeringShimmyDamper(SteeringActuatorId),
+    SteeringControlChannel(usize),
     // ATA34
     RadioAltimeter(usize),
     RadioAntennaInterrupted(usize),
     RadioAntennaDirectCoupling(usize),
     EnhancedGroundProximityWarningSystemComputer,
+    // ATA36
+    BleedHighPressureValveStuckOpen(usize),
+    BleedHighPressureValveStuckClosed(usize),
+    BleedPressureRegulatingValveStuckOpen(usize),
+    BleedPressureRegulatingValveStuckClosed(usize),
+    BleedFanAirValveStuckClosed(usize),
+    CrossBleedValveJammed(usize),
+    BleedDuctLeak(BleedDuctZone),
+    BleedMonitoringComputerChannel(usize, Channel),
+    BleedLeakDetectionLoop(FireDetectionLoopID, BleedDuctZone),
+    // ATA49
+    ApuOverspeed,
+    ApuEgtOvertemperature,
+    ApuLowOilPressure,
+    ApuHighOilTemperature,
+    ApuAirIntakeFlapStuck,
+    ApuStartMotor,
+    ApuElectronicControlBox,
+    ApuGeneratorBearing,
+    // ATA52
+    CargoDoorLatchStuck(CargoDoorId),
+    CargoDoorHydraulicLock(CargoDoorId),
+    // ATA72
+    EngineFanDamage(usize),
+    EngineBirdStrike(usize),
+    EngineBearingDamage(usize),
+    // ATA78
+    ReverserUnlocked(usize),
+    ReverserFailToDeploy(usize),
+    ReverserFailToStow(usize),
+    ReverserSleeveJammed(usize),
+    ReverserActuationSupplyLoss(usize),
+    // ATA79
+    EngineOilFilterClog(usize),
+    EngineOilLowPressure(usize),
+    EngineOilHighTemperature(usize),
+    // ATA80
+    EngineStarterValveStuckOpen(usize),
 }
 
+#[derive(Clone, Copy, PartialEq)]
 pub struct Failure {
     failure_type: FailureType,
     is_active: bool,
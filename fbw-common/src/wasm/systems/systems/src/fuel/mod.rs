@@ -1,5 +1,7 @@
 use crate::{
-    shared::{ConsumePower, ElectricalBusType, ElectricalBuses},
+    shared::{
+        calculate_towards_target_temperature, ConsumePower, ElectricalBusType, ElectricalBuses,
+    },
     simulation::{
         InitContext, Read, Reader, SimulationElement, SimulationElementVisitor, SimulatorReader,
         SimulatorWriter, UpdateContext, VariableIdentifier, Write, Writer,
@@ -7,9 +9,13 @@ use crate::{
 };
 use nalgebra::Vector3;
 use num_traits::Zero;
-use uom::si::{electric_current::ampere, f64::*, mass::kilogram};
+use uom::si::{
+    electric_current::ampere, f64::*, mass::kilogram, power::watt, temperature_interval::kelvin,
+    thermodynamic_temperature::degree_celsius,
+};
 
 pub const FUEL_GALLONS_TO_KG: f64 = 3.039075693483925;
+pub const FUEL_SPECIFIC_HEAT_JOULE_PER_KILOGRAM_KELVIN: f64 = 2000.;
 
 #[derive(Clone, Copy, PartialEq, Debug)]
 pub enum RefuelRate {
@@ -43,6 +49,10 @@ pub trait FuelCG {
     fn center_of_gravity(&self) -> Vector3<f64>;
 }
 
+pub trait FuelTemperature {
+    fn fuel_temperature(&self) -> ThermodynamicTemperature;
+}
+
 #[derive(Clone, Copy, Debug)]
 pub struct FuelPumpProperties {
     pub powered_by: ElectricalBusType,
@@ -124,8 +134,14 @@ pub struct FuelSystem<const N: usize, const PUMP_COUNT: usize> {
 
     fuel_tanks: [FuelTank; N],
     fuel_pumps: [FuelPump; PUMP_COUNT],
+
+    fuel_temperature: ThermodynamicTemperature,
+    fuel_temperature_is_initialised: bool,
 }
 impl<const N: usize, const PUMP_COUNT: usize> FuelSystem<N, PUMP_COUNT> {
+    // The fuel mass is large, it takes hours to settle to the outside air temperature
+    const FUEL_TEMPERATURE_CHANGE_RATE_CELSIUS_PER_SECOND: f64 = 0.002;
+
     pub fn new(
         context: &mut InitContext,
         fuel_tanks: [FuelTank; N],
@@ -138,7 +154,33 @@ impl<const N: usize, const PUMP_COUNT: usize> FuelSystem<N, PUMP_COUNT> {
             total_fuel_volume_id: context.get_identifier("TOTAL_FUEL_VOLUME".to_owned()),
             fuel_tanks,
             fuel_pumps,
+            fuel_temperature: ThermodynamicTemperature::new::<degree_celsius>(15.),
+            fuel_temperature_is_initialised: false,
+        }
+    }
+
+    /// `heat_input` is the heat given to the fuel by the engines fuel/oil heat exchangers.
+    pub fn update(&mut self, context: &UpdateContext, heat_input: Power) {
+        // Fuel has soaked to the outside air temperature when the aircraft is loaded
+        if !self.fuel_temperature_is_initialised {
+            self.fuel_temperature = context.ambient_temperature();
+            self.fuel_temperature_is_initialised = true;
+        }
+
+        let fuel_mass = self.total_load().get::<kilogram>();
+        if fuel_mass > 0. {
+            self.fuel_temperature += TemperatureInterval::new::<kelvin>(
+                heat_input.get::<watt>() * context.delta_as_secs_f64()
+                    / (fuel_mass * FUEL_SPECIFIC_HEAT_JOULE_PER_KILOGRAM_KELVIN),
+            );
         }
+
+        self.fuel_temperature = calculate_towards_target_temperature(
+            self.fuel_temperature,
+            context.ambient_temperature(),
+            Self::FUEL_TEMPERATURE_CHANGE_RATE_CELSIUS_PER_SECOND,
+            context.delta(),
+        );
     }
 
     pub fn total_load(&self) -> Mass {
@@ -180,6 +222,11 @@ impl<const N: usize, const PUMP_COUNT: usize> FuelSystem<N, PUMP_COUNT> {
         self.fuel_pumps[i].is_running()
     }
 }
+impl<const N: usize, const PUMP_COUNT: usize> FuelTemperature for FuelSystem<N, PUMP_COUNT> {
+    fn fuel_temperature(&self) -> ThermodynamicTemperature {
+        self.fuel_temperature
+    }
+}
 impl<const N: usize, const PUMP_COUNT: usize> SimulationElement for FuelSystem<N, PUMP_COUNT> {
     fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
         accept_iterable!(self.fuel_tanks, visitor);
@@ -0,0 +1,707 @@
+use crate::{
+    shared::{interpolation, InternationalStandardAtmosphere},
+    simulation::{
+        InitContext, Read, SimulationElement, SimulatorReader, SimulatorWriter, SurfaceTypeMsfs,
+        UpdateContext, VariableIdentifier, Write,
+    },
+};
+use std::time::Duration;
+use uom::si::{
+    acceleration::meter_per_second_squared,
+    angular_velocity::radian_per_second,
+    f64::{Acceleration, AngularVelocity, Force, Length, Pressure, Ratio, Torque, Velocity},
+    force::newton,
+    length::{meter, millimeter},
+    ratio::ratio,
+    torque::newton_meter,
+    velocity::{knot, meter_per_second},
+};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum RunwayContamination {
+    Dry,
+    Wet,
+    Ice,
+    Snow,
+}
+impl From<f64> for RunwayContamination {
+    fn from(value: f64) -> Self {
+        match value as u8 {
+            1 => RunwayContamination::Wet,
+            2 => RunwayContamination::Ice,
+            3 => RunwayContamination::Snow,
+            _ => RunwayContamination::Dry,
+        }
+    }
+}
+impl From<RunwayContamination> for f64 {
+    fn from(value: RunwayContamination) -> Self {
+        match value {
+            RunwayContamination::Dry => 0.,
+            RunwayContamination::Wet => 1.,
+            RunwayContamination::Ice => 2.,
+            RunwayContamination::Snow => 3.,
+        }
+    }
+}
+
+/// Friction between the tyres and the surface the aircraft is rolling on.
+/// Friction depends on the surface type, its contamination and the precipitation rate.
+pub struct TyreRunwayFriction {
+    surface_condition_id: VariableIdentifier,
+    reported_contamination: RunwayContamination,
+
+    contamination: RunwayContamination,
+    surface_factor: f64,
+    ground_speed: Velocity,
+}
+impl TyreRunwayFriction {
+    /// Above that rain rate a runway reported dry is considered wet
+    const WET_RUNWAY_PRECIPITATION_RATE_MM: f64 = 0.5;
+
+    const DRY_MAX_FRICTION_COEFFICIENT: f64 = 0.8;
+    const SNOW_MAX_FRICTION_COEFFICIENT: f64 = 0.2;
+    const ICE_MAX_FRICTION_COEFFICIENT: f64 = 0.07;
+
+    // Wet friction drops with speed as the water film can't be evacuated from the tyre footprint
+    const WET_FRICTION_SPEED_BREAKPOINTS_KNOT: [f64; 4] = [0., 50., 100., 150.];
+    const WET_MAX_FRICTION_COEFFICIENT: [f64; 4] = [0.5, 0.42, 0.33, 0.25];
+
+    const SOFT_SURFACE_FACTOR: f64 = 0.55;
+    const WATER_SURFACE_FACTOR: f64 = 0.05;
+
+    // Friction coefficient normalized by max friction vs tyre slip ratio
+    const SLIP_RATIO_BREAKPOINTS: [f64; 5] = [0., 0.05, 0.12, 0.3, 1.];
+    const NORMALIZED_FRICTION_COEFFICIENT: [f64; 5] = [0., 0.75, 1., 0.85, 0.7];
+
+    pub fn new(context: &mut InitContext) -> Self {
+        Self {
+            surface_condition_id: context.get_identifier("SURFACE CONDITION".to_owned()),
+            reported_contamination: RunwayContamination::Dry,
+
+            contamination: RunwayContamination::Dry,
+            surface_factor: 1.,
+            ground_speed: Velocity::default(),
+        }
+    }
+
+    pub fn update(&mut self, context: &UpdateContext) {
+        self.ground_speed = context.ground_speed();
+
+        let surface_type = context.surface_type();
+
+        self.contamination = match surface_type {
+            SurfaceTypeMsfs::Snow => RunwayContamination::Snow,
+            SurfaceTypeMsfs::Ice => RunwayContamination::Ice,
+            _ if self.reported_contamination == RunwayContamination::Dry
+                && context.precipitation_rate().get::<millimeter>()
+                    > Self::WET_RUNWAY_PRECIPITATION_RATE_MM =>
+            {
+                RunwayContamination::Wet
+            }
+            _ => self.reported_contamination,
+        };
+
+        self.surface_factor = Self::surface_factor(surface_type);
+    }
+
+    fn surface_factor(surface_type: SurfaceTypeMsfs) -> f64 {
+        match surface_type {
+            SurfaceTypeMsfs::Grass
+            | SurfaceTypeMsfs::GrassBumpy
+            | SurfaceTypeMsfs::ShortGrass
+            | SurfaceTypeMsfs::LongGrass
+            | SurfaceTypeMsfs::HardTurf
+            | SurfaceTypeMsfs::Forest
+            | SurfaceTypeMsfs::Dirt
+            | SurfaceTypeMsfs::Gravel
+            | SurfaceTypeMsfs::Sand
+            | SurfaceTypeMsfs::Shale => Self::SOFT_SURFACE_FACTOR,
+            SurfaceTypeMsfs::WaterFsx
+            | SurfaceTypeMsfs::Ocean
+            | SurfaceTypeMsfs::Water
+            | SurfaceTypeMsfs::Pond
+            | SurfaceTypeMsfs::Lake
+            | SurfaceTypeMsfs::River
+            | SurfaceTypeMsfs::WasteWater => Self::WATER_SURFACE_FACTOR,
+            _ => 1.,
+        }
+    }
+
+    pub fn contamination(&self) -> RunwayContamination {
+        self.contamination
+    }
+
+    /// Friction coefficient at the optimal slip ratio
+    pub fn max_friction_coefficient(&self) -> f64 {
+        let contamination_coefficient = match self.contamination {
+            RunwayContamination::Dry => Self::DRY_MAX_FRICTION_COEFFICIENT,
+            RunwayContamination::Wet => interpolation(
+                &Self::WET_FRICTION_SPEED_BREAKPOINTS_KNOT,
+                &Self::WET_MAX_FRICTION_COEFFICIENT,
+                self.ground_speed.get::<knot>().abs(),
+            ),
+            RunwayContamination::Snow => Self::SNOW_MAX_FRICTION_COEFFICIENT,
+            RunwayContamination::Ice => Self::ICE_MAX_FRICTION_COEFFICIENT,
+        };
+
+        contamination_coefficient * self.surface_factor
+    }
+
+    /// Friction coefficient for a given slip ratio. Negative slip means the tyre runs faster than the ground.
+    pub fn friction_coefficient(&self, slip_ratio: f64) -> f64 {
+        slip_ratio.signum()
+            * self.max_friction_coefficient()
+            * interpolation(
+                &Self::SLIP_RATIO_BREAKPOINTS,
+                &Self::NORMALIZED_FRICTION_COEFFICIENT,
+                slip_ratio.abs(),
+            )
+    }
+}
+impl SimulationElement for TyreRunwayFriction {
+    fn read(&mut self, reader: &mut SimulatorReader) {
+        let surface_condition: f64 = reader.read(&self.surface_condition_id);
+        self.reported_contamination = surface_condition.into();
+    }
+}
+
+/// Rotational dynamics of a braked wheel rolling on the ground.
+/// Brake torque decelerates the wheel while the tyre to ground friction spins it back up.
+pub struct BrakedWheel {
+    slip_ratio_id: VariableIdentifier,
+
+    radius: Length,
+    inertia: f64,
+    max_brake_torque: Torque,
+    reference_pressure: Pressure,
+    static_load_ratio: Ratio,
+
+    angular_speed: AngularVelocity,
+    slip_ratio: f64,
+    tyre_force: Force,
+    brake_torque: Torque,
+}
+impl BrakedWheel {
+    const INTEGRATION_TIME_STEP: Duration = Duration::from_millis(1);
+
+    /// Under that speed the wheel is considered rolling without slip: tyre force is only limited by friction
+    const MIN_GROUND_SPEED_FOR_SLIP_DYNAMICS_KNOT: f64 = 20.;
+
+    /// Creates a new braked wheel
+    /// ## Parameters
+    /// `radius` - rolling radius of the tyre
+    /// `inertia` - rotating inertia of tyre, wheel and brake rotors in kg.m^2
+    /// `max_brake_torque` - brake torque when `reference_pressure` is applied on the brake
+    /// `static_load_ratio` - ratio of the aircraft weight supported by this wheel on ground
+    pub fn new(
+        context: &mut InitContext,
+        index: usize,
+        radius: Length,
+        inertia: f64,
+        max_brake_torque: Torque,
+        reference_pressure: Pressure,
+        static_load_ratio: Ratio,
+    ) -> Self {
+        Self {
+            slip_ratio_id: context.get_identifier(format!("WHEEL_SLIP_RATIO_{index}")),
+
+            radius,
+            inertia,
+            max_brake_torque,
+            reference_pressure,
+            static_load_ratio,
+
+            angular_speed: AngularVelocity::default(),
+            slip_ratio: 0.,
+            tyre_force: Force::default(),
+            brake_torque: Torque::default(),
+        }
+    }
+
+    pub fn update(
+        &mut self,
+        context: &UpdateContext,
+        brake_pressure: Pressure,
+        friction: &TyreRunwayFriction,
+    ) {
+        self.brake_torque =
+            self.max_brake_torque * (brake_pressure / self.reference_pressure).get::<ratio>();
+
+        let ground_speed = context.ground_speed().get::<meter_per_second>().abs();
+
+        if !context.is_on_ground() {
+            self.update_airborne(context);
+        } else if context.ground_speed().get::<knot>().abs()
+            < Self::MIN_GROUND_SPEED_FOR_SLIP_DYNAMICS_KNOT
+        {
+            self.update_rolling(ground_speed, self.normal_load(context), friction);
+        } else {
+            self.update_slip_dynamics(context, ground_speed, self.normal_load(context), friction);
+        }
+    }
+
+    fn normal_load(&self, context: &UpdateContext) -> Force {
+        context.total_weight()
+            * Acceleration::new::<meter_per_second_squared>(
+                InternationalStandardAtmosphere::GRAVITY_ACCELERATION,
+            )
+            * self.static_load_ratio.get::<ratio>()
+    }
+
+    fn update_airborne(&mut self, context: &UpdateContext) {
+        self.slip_ratio = 0.;
+        self.tyre_force = Force::default();
+
+        let deceleration =
+            self.brake_torque.get::<newton_meter>() / self.inertia * context.delta_as_secs_f64();
+        self.angular_speed = AngularVelocity::new::<radian_per_second>(
+            (self.angular_speed.get::<radian_per_second>() - deceleration).max(0.),
+        );
+    }
+
+    fn update_rolling(
+        &mut self,
+        ground_speed: f64,
+        normal_load: Force,
+        friction: &TyreRunwayFriction,
+    ) {
+        let max_tyre_force = normal_load * friction.max_friction_coefficient();
+        let brake_force = self.brake_force();
+
+        if brake_force > max_tyre_force && ground_speed > 0. {
+            // Brake is stronger than what the tyre can hold: wheel is locked and skidding
+            self.angular_speed = AngularVelocity::default();
+            self.slip_ratio = 1.;
+            self.tyre_force = normal_load * friction.friction_coefficient(1.);
+        } else {
+            self.angular_speed = AngularVelocity::new::<radian_per_second>(
+                ground_speed / self.radius.get::<meter>(),
+            );
+            self.slip_ratio = 0.;
+            self.tyre_force = brake_force;
+        }
+    }
+
+    fn update_slip_dynamics(
+        &mut self,
+        context: &UpdateContext,
+        ground_speed: f64,
+        normal_load: Force,
+        friction: &TyreRunwayFriction,
+    ) {
+        let radius = self.radius.get::<meter>();
+        let brake_torque = self.brake_torque.get::<newton_meter>();
+        let normal_load = normal_load.get::<newton>();
+
+        let mut angular_speed = self.angular_speed.get::<radian_per_second>();
+        let mut remaining_time = context.delta_as_secs_f64();
+        let mut tyre_force_sum = 0.;
+
+        while remaining_time > 0. {
+            let time_step = remaining_time.min(Self::INTEGRATION_TIME_STEP.as_secs_f64());
+
+            let slip_ratio =
+                ((ground_speed - angular_speed * radius) / ground_speed).clamp(-1., 1.);
+            let tyre_force = normal_load * friction.friction_coefficient(slip_ratio);
+
+            // Brake torque can only oppose rotation: a stopped wheel stays locked until friction spins it up
+            let resisting_torque = if angular_speed > 0. {
+                brake_torque
+            } else {
+                brake_torque.min(tyre_force * radius)
+            };
+
+            angular_speed = (angular_speed
+                + (tyre_force * radius - resisting_torque) / self.inertia * time_step)
+                .max(0.);
+
+            tyre_force_sum += tyre_force * time_step;
+            remaining_time -= time_step;
+        }
+
+        self.angular_speed = AngularVelocity::new::<radian_per_second>(angular_speed);
+        self.slip_ratio = ((ground_speed - angular_speed * radius) / ground_speed).clamp(-1., 1.);
+        self.tyre_force = Force::new::<newton>(if context.delta_as_secs_f64() > 0. {
+            tyre_force_sum / context.delta_as_secs_f64()
+        } else {
+            normal_load * friction.friction_coefficient(self.slip_ratio)
+        });
+    }
+
+    fn brake_force(&self) -> Force {
+        Force::new::<newton>(self.brake_torque.get::<newton_meter>() / self.radius.get::<meter>())
+    }
+
+    /// Tangential speed of the tyre
+    pub fn wheel_speed(&self) -> Velocity {
+        Velocity::new::<meter_per_second>(
+            self.angular_speed.get::<radian_per_second>() * self.radius.get::<meter>(),
+        )
+    }
+
+    pub fn slip_ratio(&self) -> Ratio {
+        Ratio::new::<ratio>(self.slip_ratio)
+    }
+
+    /// Longitudinal force the tyre is actually applying on the ground
+    pub fn tyre_force(&self) -> Force {
+        self.tyre_force
+    }
+
+    /// Ratio of the braking force requested by the brake that the tyre really transmits to the ground
+    pub fn braking_force_transmission_ratio(&self) -> f64 {
+        let brake_force = self.brake_force();
+
+        if brake_force.get::<newton>() > 1. {
+            (self.tyre_force / brake_force).get::<ratio>().clamp(0., 1.)
+        } else {
+            1.
+        }
+    }
+}
+/// Average ratio of the requested braking force transmitted to the ground by a set of wheels
+pub fn braking_force_transmission_ratio(wheels: &[BrakedWheel]) -> f64 {
+    wheels
+        .iter()
+        .map(|wheel| wheel.braking_force_transmission_ratio())
+        .sum::<f64>()
+        / wheels.len().max(1) as f64
+}
+
+impl SimulationElement for BrakedWheel {
+    fn write(&self, writer: &mut SimulatorWriter) {
+        writer.write(&self.slip_ratio_id, self.slip_ratio());
+    }
+}
+
+/// Anti-skid channel controlling one brake pressure output.
+/// It compares the speed of the wheels it controls to the reference ground speed and releases brake
+/// pressure as soon as one wheel starts to skid. Once the wheel spun up pressure is quickly reapplied
+/// up to just below the level that made it skid, then slowly increased to find the friction limit again.
+pub struct AntiSkidController {
+    release_ratio: Ratio,
+    skid_release_ratio: Ratio,
+    is_releasing: bool,
+}
+impl AntiSkidController {
+    /// Anti-skid is inhibited under that reference speed
+    const MIN_ACTIVATION_SPEED_KNOT: f64 = 20.;
+
+    const RELEASE_SLIP_RATIO: f64 = 0.15;
+    const REAPPLY_SLIP_RATIO: f64 = 0.08;
+
+    const RELEASE_RATE_PER_SECOND: f64 = 6.;
+    const FAST_REAPPLY_RATE_PER_SECOND: f64 = 5.;
+    const SLOW_REAPPLY_RATE_PER_SECOND: f64 = 0.5;
+
+    /// Ratio of the last skidding level up to which pressure is reapplied at fast rate
+    const FAST_REAPPLY_LEVEL: f64 = 0.85;
+
+    pub fn new() -> Self {
+        Self {
+            release_ratio: Ratio::new::<ratio>(1.),
+            skid_release_ratio: Ratio::new::<ratio>(1.),
+            is_releasing: false,
+        }
+    }
+
+    pub fn update(&mut self, context: &UpdateContext, is_active: bool, wheels: &[BrakedWheel]) {
+        let reference_speed = context.ground_speed().abs();
+
+        if !is_active
+            || !context.is_on_ground()
+            || reference_speed.get::<knot>() < Self::MIN_ACTIVATION_SPEED_KNOT
+        {
+            self.release_ratio = Ratio::new::<ratio>(1.);
+            self.skid_release_ratio = Ratio::new::<ratio>(1.);
+            self.is_releasing = false;
+            return;
+        }
+
+        let max_slip_ratio = wheels
+            .iter()
+            .map(|wheel| (1. - (wheel.wheel_speed() / reference_speed).get::<ratio>()).max(0.))
+            .fold(0., f64::max);
+
+        if max_slip_ratio > Self::RELEASE_SLIP_RATIO {
+            if !self.is_releasing {
+                self.skid_release_ratio = self.release_ratio;
+            }
+            self.is_releasing = true;
+        } else if max_slip_ratio < Self::REAPPLY_SLIP_RATIO {
+            self.is_releasing = false;
+        }
+
+        let delta = context.delta_as_secs_f64();
+        let release_ratio = self.release_ratio.get::<ratio>();
+        self.release_ratio = Ratio::new::<ratio>(if self.is_releasing {
+            (release_ratio - Self::RELEASE_RATE_PER_SECOND * delta).max(0.)
+        } else if release_ratio < Self::FAST_REAPPLY_LEVEL * self.skid_release_ratio.get::<ratio>()
+        {
+            release_ratio + Self::FAST_REAPPLY_RATE_PER_SECOND * delta
+        } else {
+            (release_ratio + Self::SLOW_REAPPLY_RATE_PER_SECOND * delta).min(1.)
+        });
+    }
+
+    /// Brake demand after anti-skid modulation
+    pub fn modulated_demand(&self, demand: Ratio) -> Ratio {
+        demand * self.release_ratio
+    }
+
+    pub fn is_releasing(&self) -> bool {
+        self.is_releasing
+    }
+}
+impl Default for AntiSkidController {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::shared::low_pass_filter::LowPassFilter;
+    use crate::simulation::test::{SimulationTestBed, TestBed, WriteByName};
+    use crate::simulation::{Aircraft, SimulationElementVisitor};
+    use uom::si::{f64::Mass, mass::kilogram, pressure::psi};
+
+    const AIRCRAFT_MASS_KG: f64 = 64000.;
+    const BRAKED_WHEELS_NUMBER: f64 = 4.;
+
+    struct TestAircraft {
+        friction: TyreRunwayFriction,
+        wheel: BrakedWheel,
+        anti_skid: AntiSkidController,
+
+        anti_skid_active: bool,
+        brake_demand: Ratio,
+        brake_pressure: LowPassFilter<Pressure>,
+    }
+    impl TestAircraft {
+        fn new(context: &mut InitContext) -> Self {
+            Self {
+                friction: TyreRunwayFriction::new(context),
+                wheel: BrakedWheel::new(
+                    context,
+                    1,
+                    Length::new::<meter>(0.57),
+                    40.,
+                    Torque::new::<newton_meter>(40000.),
+                    Pressure::new::<psi>(2538.),
+                    Ratio::new::<ratio>(0.23),
+                ),
+                anti_skid: AntiSkidController::new(),
+
+                anti_skid_active: true,
+                brake_demand: Ratio::default(),
+                brake_pressure: LowPassFilter::new(Duration::from_millis(50)),
+            }
+        }
+
+        fn set_brake_demand(&mut self, demand: Ratio) {
+            self.brake_demand = demand;
+        }
+
+        fn set_anti_skid_active(&mut self, is_active: bool) {
+            self.anti_skid_active = is_active;
+        }
+
+        fn brake_pressure(&self) -> Pressure {
+            self.brake_pressure.output()
+        }
+    }
+    impl Aircraft for TestAircraft {
+        fn update_after_power_distribution(&mut self, context: &UpdateContext) {
+            self.friction.update(context);
+
+            self.anti_skid.update(
+                context,
+                self.anti_skid_active,
+                std::slice::from_ref(&self.wheel),
+            );
+
+            self.brake_pressure.update(
+                context.delta(),
+                Pressure::new::<psi>(2538.)
+                    * self
+                        .anti_skid
+                        .modulated_demand(self.brake_demand)
+                        .get::<ratio>(),
+            );
+
+            self.wheel
+                .update(context, self.brake_pressure.output(), &self.friction);
+        }
+    }
+    impl SimulationElement for TestAircraft {
+        fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
+            self.friction.accept(visitor);
+            self.wheel.accept(visitor);
+            visitor.visit(self);
+        }
+    }
+
+    fn test_bed_on_runway(
+        contamination: RunwayContamination,
+        speed: Velocity,
+    ) -> SimulationTestBed<TestAircraft> {
+        let mut test_bed = SimulationTestBed::new(TestAircraft::new);
+        test_bed.set_on_ground(true);
+        test_bed.write_by_name("TOTAL WEIGHT", Mass::new::<kilogram>(AIRCRAFT_MASS_KG));
+        test_bed.write_by_name("SURFACE CONDITION", f64::from(contamination));
+        test_bed.write_by_name("GPS GROUND SPEED", speed.get::<knot>());
+
+        // Let the wheel spin up to ground speed
+        test_bed.run_multiple_frames(Duration::from_secs(1));
+
+        test_bed
+    }
+
+    /// Decelerates the aircraft from the tyre force until stopped, returning the stopping distance
+    fn stopping_distance(
+        test_bed: &mut SimulationTestBed<TestAircraft>,
+        speed: Velocity,
+    ) -> Length {
+        let time_step = Duration::from_millis(20);
+        let mut speed = speed.get::<meter_per_second>();
+        let mut distance = 0.;
+
+        while speed > 0.5 {
+            test_bed.write_by_name(
+                "GPS GROUND SPEED",
+                Velocity::new::<meter_per_second>(speed).get::<knot>(),
+            );
+            test_bed.run_with_delta(time_step);
+
+            let deceleration = BRAKED_WHEELS_NUMBER
+                * test_bed.query(|a| a.wheel.tyre_force().get::<newton>())
+                / AIRCRAFT_MASS_KG;
+
+            speed -= deceleration * time_step.as_secs_f64();
+            distance += speed.max(0.) * time_step.as_secs_f64();
+        }
+
+        Length::new::<meter>(distance)
+    }
+
+    #[test]
+    fn rain_makes_dry_runway_wet() {
+        let mut test_bed =
+            test_bed_on_runway(RunwayContamination::Dry, Velocity::new::<knot>(100.));
+
+        assert_eq!(
+            test_bed.query(|a| a.friction.contamination()),
+            RunwayContamination::Dry
+        );
+
+        test_bed.write_by_name("AMBIENT PRECIP RATE", 5.);
+        test_bed.run_with_delta(Duration::from_millis(20));
+
+        assert_eq!(
+            test_bed.query(|a| a.friction.contamination()),
+            RunwayContamination::Wet
+        );
+        assert!(
+            test_bed.query(|a| a.friction.max_friction_coefficient())
+                < TyreRunwayFriction::DRY_MAX_FRICTION_COEFFICIENT
+        );
+    }
+
+    #[test]
+    fn max_braking_on_dry_runway_does_not_skid() {
+        let mut test_bed =
+            test_bed_on_runway(RunwayContamination::Dry, Velocity::new::<knot>(100.));
+
+        test_bed.command(|a| a.set_brake_demand(Ratio::new::<ratio>(1.)));
+        for _ in 0..100 {
+            test_bed.run_with_delta(Duration::from_millis(20));
+
+            assert!(test_bed.query(|a| a.wheel.slip_ratio()) < Ratio::new::<ratio>(0.15));
+            assert!(!test_bed.query(|a| a.anti_skid.is_releasing()));
+        }
+
+        assert!(test_bed.query(|a| a.brake_pressure()) > Pressure::new::<psi>(2500.));
+        assert!(test_bed.query(|a| a.wheel.braking_force_transmission_ratio()) > 0.95);
+    }
+
+    #[test]
+    fn max_braking_on_wet_runway_without_anti_skid_locks_the_wheel() {
+        let mut test_bed =
+            test_bed_on_runway(RunwayContamination::Wet, Velocity::new::<knot>(100.));
+
+        test_bed.command(|a| a.set_anti_skid_active(false));
+        test_bed.command(|a| a.set_brake_demand(Ratio::new::<ratio>(1.)));
+        test_bed.run_multiple_frames(Duration::from_secs(2));
+
+        assert!(test_bed.query(|a| a.wheel.slip_ratio()) > Ratio::new::<ratio>(0.99));
+        assert!(test_bed.query(|a| a.wheel.braking_force_transmission_ratio()) < 0.8);
+    }
+
+    #[test]
+    fn max_braking_on_wet_runway_with_anti_skid_cycles_brake_pressure() {
+        let mut test_bed =
+            test_bed_on_runway(RunwayContamination::Wet, Velocity::new::<knot>(100.));
+
+        test_bed.command(|a| a.set_brake_demand(Ratio::new::<ratio>(1.)));
+
+        let mut release_number = 0;
+        let mut was_releasing = false;
+        let mut min_pressure = Pressure::new::<psi>(3000.);
+        let mut max_pressure = Pressure::default();
+        for _ in 0..250 {
+            test_bed.run_with_delta(Duration::from_millis(20));
+
+            let is_releasing = test_bed.query(|a| a.anti_skid.is_releasing());
+            if is_releasing && !was_releasing {
+                release_number += 1;
+            }
+            was_releasing = is_releasing;
+
+            if release_number > 0 {
+                let pressure = test_bed.query(|a| a.brake_pressure());
+                min_pressure = min_pressure.min(pressure);
+                max_pressure = max_pressure.max(pressure);
+            }
+        }
+
+        assert!(release_number >= 3);
+        assert!(max_pressure - min_pressure > Pressure::new::<psi>(500.));
+        assert!(test_bed.query(|a| a.wheel.slip_ratio()) < Ratio::new::<ratio>(0.9));
+    }
+
+    #[test]
+    fn anti_skid_is_inhibited_at_low_speed() {
+        let mut test_bed = test_bed_on_runway(RunwayContamination::Ice, Velocity::new::<knot>(10.));
+
+        test_bed.command(|a| a.set_brake_demand(Ratio::new::<ratio>(1.)));
+        test_bed.run_multiple_frames(Duration::from_secs(1));
+
+        assert!(!test_bed.query(|a| a.anti_skid.is_releasing()));
+        assert!(test_bed.query(|a| a.brake_pressure()) > Pressure::new::<psi>(2500.));
+    }
+
+    #[test]
+    fn wet_runway_stopping_distance_is_longer_and_reduced_by_anti_skid() {
+        let landing_speed = Velocity::new::<knot>(130.);
+
+        let mut test_bed = test_bed_on_runway(RunwayContamination::Dry, landing_speed);
+        test_bed.command(|a| a.set_brake_demand(Ratio::new::<ratio>(1.)));
+        let dry_distance = stopping_distance(&mut test_bed, landing_speed);
+
+        let mut test_bed = test_bed_on_runway(RunwayContamination::Wet, landing_speed);
+        test_bed.command(|a| a.set_brake_demand(Ratio::new::<ratio>(1.)));
+        let wet_distance = stopping_distance(&mut test_bed, landing_speed);
+
+        let mut test_bed = test_bed_on_runway(RunwayContamination::Wet, landing_speed);
+        test_bed.command(|a| a.set_anti_skid_active(false));
+        test_bed.command(|a| a.set_brake_demand(Ratio::new::<ratio>(1.)));
+        let wet_no_anti_skid_distance = stopping_distance(&mut test_bed, landing_speed);
+
+        assert!(wet_distance > dry_distance * 1.2);
+        assert!(wet_no_anti_skid_distance > wet_distance);
+    }
+}
@@ -1,25 +1,30 @@
 use crate::{
-    shared::{ConsumePower, ControllerSignal, ElectricalBusType, ElectricalBuses},
+    failures::{Failure, FailureType},
+    shared::{
+        interpolation, ConsumePower, ControllerSignal, ElectricalBusType, ElectricalBuses,
+        TyrePressure,
+    },
     simulation::{
         InitContext, Read, SimulationElement, SimulationElementVisitor, SimulatorReader,
         SimulatorWriter, UpdateContext, VariableIdentifier, Write,
     },
 };
-use std::f64::consts::PI;
+use std::{f64::consts::PI, time::Duration};
 use uom::si::{
     angular_velocity::revolution_per_minute,
     area::square_meter,
-    energy::joule,
     f64::{
-        AngularVelocity, Area, Energy, HeatCapacity, Length, Mass, Power, Pressure,
-        SpecificHeatCapacity, TemperatureInterval, ThermodynamicTemperature,
+        AngularVelocity, Area, HeatCapacity, Length, Mass, Power, Pressure, Ratio,
+        SpecificHeatCapacity, TemperatureInterval, ThermodynamicTemperature, Time,
     },
     length::meter,
     mass_density::kilogram_per_cubic_meter,
     power::watt,
+    ratio::ratio,
     specific_heat_capacity::joule_per_kilogram_kelvin,
     temperature_interval::kelvin,
     thermodynamic_temperature::{self, degree_celsius},
+    time::second,
     velocity::meter_per_second,
 };
 
@@ -29,20 +34,30 @@ pub struct BrakeAssembly<const N: usize> {
     brakes: [Brake; N],
     brake_probes: [BrakeProbe; N],
     brake_fans: Option<[BrakeFan; N]>,
+    wheels: [Wheel; N],
+    temperature_predictors: [BrakeTemperaturePredictor; N],
 }
 impl<const N: usize> BrakeAssembly<N> {
+    /// Converts the additional rolling resistance of under-inflated tyres into brake force factor
+    const ROLLING_RESISTANCE_TO_FORCE_FACTOR: f64 = 0.04;
+
     /// Creates a new brake assembly
     /// ## Parameters
     /// `wheel_speed_variable_name` - the simvar to be used for the rotational velocity of the tyres
+    /// `tyre_nominal_pressure` - the cold inflation pressure of the tyres
     pub fn new(
         context: &mut InitContext,
         wheel_speed_variable_name: String,
         indices: [usize; N],
         sensors_powered_by: [ElectricalBusType; N],
         brake_fan_bus: Option<ElectricalBusType>,
+        tyre_nominal_pressure: Pressure,
     ) -> Self {
         let brakes = indices.map(|index| Brake::new(context, index));
+        let wheels = indices.map(|index| Wheel::new(context, index, tyre_nominal_pressure));
         let brake_probes = sensors_powered_by.map(BrakeProbe::new);
+        let temperature_predictors = indices
+            .map(|index| BrakeTemperaturePredictor::new(context, index, brake_fan_bus.is_some()));
         let brake_fans = brake_fan_bus.map(|bus| {
             brakes
                 .iter()
@@ -57,6 +72,8 @@ impl<const N: usize> BrakeAssembly<N> {
             brakes,
             brake_probes,
             brake_fans,
+            wheels,
+            temperature_predictors,
         }
     }
 
@@ -86,10 +103,12 @@ impl<const N: usize> BrakeAssembly<N> {
             [false; N]
         };
 
-        for ((brake, brake_probe), brake_fan_is_running) in self
+        for ((((brake, brake_probe), wheel), predictor), brake_fan_is_running) in self
             .brakes
             .iter_mut()
             .zip(&mut self.brake_probes)
+            .zip(&mut self.wheels)
+            .zip(&mut self.temperature_predictors)
             .zip(brake_fan_are_running)
         {
             brake.update(
@@ -100,10 +119,47 @@ impl<const N: usize> BrakeAssembly<N> {
                 brake_fan_is_running,
                 gear_extended_phys,
             );
-            brake_probe.update(context, brake, brake_fan_is_running)
+            brake_probe.update(context, brake, brake_fan_is_running);
+            wheel.update(context, Some(brake.temperature()));
+            predictor.update(context, brake_properties, brake, brake_fan_is_running);
         }
     }
 
+    /// Ratio of the nominal braking force the tyres can transmit given their inflation
+    pub fn braking_efficiency(&self) -> Ratio {
+        self.wheels
+            .iter()
+            .map(|wheel| wheel.braking_efficiency())
+            .sum::<Ratio>()
+            / N as f64
+    }
+
+    /// Rolling resistance relative to correctly inflated tyres
+    pub fn rolling_resistance_factor(&self) -> Ratio {
+        self.wheels
+            .iter()
+            .map(|wheel| wheel.rolling_resistance_factor())
+            .sum::<Ratio>()
+            / N as f64
+    }
+
+    /// Corrects a brake force factor for the state of the tyres: under-inflated tyres transmit
+    /// less braking force but add rolling drag
+    pub fn tyre_corrected_force_factor(&self, force_factor: f64) -> f64 {
+        (force_factor * self.braking_efficiency().get::<ratio>()
+            + (self.rolling_resistance_factor().get::<ratio>() - 1.)
+                * Self::ROLLING_RESISTANCE_TO_FORCE_FACTOR)
+            .clamp(0., 1.)
+    }
+
+    pub fn any_tyre_deflated(&self) -> bool {
+        self.wheels.iter().any(|wheel| wheel.is_tyre_deflated())
+    }
+
+    pub fn tyres(&self) -> impl Iterator<Item = &dyn TyrePressure> {
+        self.wheels.iter().map(|wheel| wheel as &dyn TyrePressure)
+    }
+
     pub fn brake_temperature_sensors(
         &self,
     ) -> &[impl ControllerSignal<ThermodynamicTemperature>; N] {
@@ -122,6 +178,8 @@ impl<const N: usize> SimulationElement for BrakeAssembly<N> {
     fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
         accept_iterable!(self.brakes, visitor);
         accept_iterable!(self.brake_probes, visitor);
+        accept_iterable!(self.wheels, visitor);
+        accept_iterable!(self.temperature_predictors, visitor);
         if let Some(brake_fans) = &mut self.brake_fans {
             accept_iterable!(brake_fans, visitor);
         }
@@ -134,6 +192,44 @@ impl<const N: usize> SimulationElement for BrakeAssembly<N> {
     }
 }
 
+/// Wheels without brakes (nose gear, A380 body gear centre axle), whose tyres only exchange heat
+/// with ambient air
+pub struct UnbrakedWheels<const N: usize> {
+    wheels: [Wheel; N],
+}
+impl<const N: usize> UnbrakedWheels<N> {
+    pub fn new(
+        context: &mut InitContext,
+        indices: [usize; N],
+        tyre_nominal_pressure: Pressure,
+    ) -> Self {
+        Self {
+            wheels: indices.map(|index| Wheel::new(context, index, tyre_nominal_pressure)),
+        }
+    }
+
+    pub fn update(&mut self, context: &UpdateContext) {
+        for wheel in &mut self.wheels {
+            wheel.update(context, None);
+        }
+    }
+
+    pub fn any_tyre_deflated(&self) -> bool {
+        self.wheels.iter().any(|wheel| wheel.is_tyre_deflated())
+    }
+
+    pub fn tyres(&self) -> impl Iterator<Item = &dyn TyrePressure> {
+        self.wheels.iter().map(|wheel| wheel as &dyn TyrePressure)
+    }
+}
+impl<const N: usize> SimulationElement for UnbrakedWheels<N> {
+    fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
+        accept_iterable!(self.wheels, visitor);
+
+        visitor.visit(self);
+    }
+}
+
 pub struct BrakeProperties {
     brake_radius: Length,
     surface_area: Area,
@@ -169,9 +265,15 @@ impl BrakeProperties {
 }
 
 /// Simulates a carbon brake (C/C composite)
+///
+/// Besides the mechanical wear from braking, the carbon heat sink oxidises when it stays very hot,
+/// faster when brake fans blow fresh air through it.
 struct Brake {
     temperature_id: VariableIdentifier,
+    wear_id: VariableIdentifier,
     temperature: ThermodynamicTemperature,
+    wear: Ratio,
+    convection_coefficient: f64,
     initialized: bool,
 }
 impl Brake {
@@ -189,11 +291,25 @@ impl Brake {
     const BRAKE_EMISSIVITY: f64 = 0.71;
     /// Stefan-Boltzmann constant, W/(m^2*K^4)
     const BOLTZMANN_CONSTANT: f64 = 5.670374419e-8;
+    /// Cumulated heat sink temperature rise wearing the brake out, around 2000 landings of 100K
+    const TEMPERATURE_RISE_FOR_FULL_WEAR_KELVIN: f64 = 200_000.;
+    /// Heat sink temperature above which carbon oxidation becomes significant
+    const OXIDATION_ONSET_TEMPERATURE_DEGREE_CELSIUS: f64 = 500.;
+    /// Time spent above oxidation onset temperature wearing the brake out
+    const OXIDATION_TIME_FOR_FULL_WEAR_S: f64 = 200. * 3600.;
+    /// Oxidation rate increase due to the air flow of the brake fan
+    const BRAKE_FAN_OXIDATION_FACTOR: f64 = 1.5;
+
+    /// Cool down predictions are not computed further than this
+    const COOL_DOWN_PREDICTION_HORIZON: Duration = Duration::from_secs(4 * 3600);
 
     fn new(context: &mut InitContext, index: usize) -> Self {
         Self {
             temperature_id: context.get_identifier(format!("BRAKE_TEMPERATURE_{index}")),
+            wear_id: context.get_identifier(format!("BRAKE_WEAR_{index}")),
             temperature: ThermodynamicTemperature::default(),
+            wear: Ratio::default(),
+            convection_coefficient: Self::HEAT_TRANSFER_COEFFICIENT,
             initialized: false,
         }
     }
@@ -218,15 +334,15 @@ impl Brake {
         let energy = force * passed_length;
         let delta = energy / brake_properties.heat_capacity();
         self.temperature += delta;
+        self.wear = (self.wear
+            + Ratio::new::<ratio>(
+                delta.get::<kelvin>() / Self::TEMPERATURE_RISE_FOR_FULL_WEAR_KELVIN,
+            )
+            + self.oxidation_wear(context, brake_fan_on))
+        .min(Ratio::new::<ratio>(1.));
 
         // Cool down process
-        let radiated_energy = self.calculate_radiated_energy(context, brake_properties);
-        let brake_fan_coefficient = if brake_fan_on {
-            Self::BRAKE_FAN_CONVECTIVE_COEFFICIENT
-        } else {
-            0.
-        };
-        let convection_coefficient = if gear_extended_phys {
+        self.convection_coefficient = if gear_extended_phys {
             // We halve the gear heat coefficient because the brake disk is not directly exposed to the air
             0.5 * Self::calculate_gear_convection_coefficient(
                 context,
@@ -235,37 +351,98 @@ impl Brake {
         } else {
             Self::HEAT_TRANSFER_COEFFICIENT
         };
-        let delta_ambient = self.temperature.get::<degree_celsius>()
-            - context.ambient_temperature().get::<degree_celsius>();
-        let energy = radiated_energy
-            + Energy::new::<joule>(
-                (convection_coefficient + brake_fan_coefficient)
-                    * brake_properties.surface_area().get::<square_meter>()
-                    * (delta_ambient * context.delta_as_secs_f64()),
-            );
+        let energy = self.cooling_power(context, brake_properties, self.temperature, brake_fan_on)
+            * context.delta_as_time();
         self.temperature -= energy / brake_properties.heat_capacity();
     }
 
-    fn calculate_radiated_energy(
+    fn oxidation_wear(&self, context: &UpdateContext, brake_fan_on: bool) -> Ratio {
+        if self.temperature.get::<degree_celsius>()
+            > Self::OXIDATION_ONSET_TEMPERATURE_DEGREE_CELSIUS
+        {
+            let fan_factor = if brake_fan_on {
+                Self::BRAKE_FAN_OXIDATION_FACTOR
+            } else {
+                1.
+            };
+            Ratio::new::<ratio>(
+                fan_factor * context.delta_as_secs_f64() / Self::OXIDATION_TIME_FOR_FULL_WEAR_S,
+            )
+        } else {
+            Ratio::default()
+        }
+    }
+
+    /// Heat lost to ambient air by radiation and convection at a given heat sink temperature,
+    /// in the air flow conditions of the last update
+    fn cooling_power(
         &self,
         context: &UpdateContext,
         brake_properties: &BrakeProperties,
-    ) -> Energy {
-        // We are using the Stefan-Boltzmann law to calculate the energy radiated
-        Energy::new::<joule>(
-            Self::BRAKE_EMISSIVITY
-                * Self::BOLTZMANN_CONSTANT
-                * context.delta_as_secs_f64()
-                * brake_properties.surface_area().get::<square_meter>()
-                * (self
-                    .temperature
+        temperature: ThermodynamicTemperature,
+        brake_fan_on: bool,
+    ) -> Power {
+        let brake_fan_coefficient = if brake_fan_on {
+            Self::BRAKE_FAN_CONVECTIVE_COEFFICIENT
+        } else {
+            0.
+        };
+        let surface_area = brake_properties.surface_area().get::<square_meter>();
+
+        // We are using the Stefan-Boltzmann law to calculate the power radiated
+        let radiated_power = Self::BRAKE_EMISSIVITY
+            * Self::BOLTZMANN_CONSTANT
+            * surface_area
+            * (temperature
+                .get::<thermodynamic_temperature::kelvin>()
+                .powi(4)
+                - context
+                    .ambient_temperature()
                     .get::<thermodynamic_temperature::kelvin>()
-                    .powi(4)
-                    - context
-                        .ambient_temperature()
-                        .get::<thermodynamic_temperature::kelvin>()
-                        .powi(4)),
-        )
+                    .powi(4));
+        let convected_power = (self.convection_coefficient + brake_fan_coefficient)
+            * surface_area
+            * (temperature.get::<degree_celsius>()
+                - context.ambient_temperature().get::<degree_celsius>());
+
+        Power::new::<watt>(radiated_power + convected_power)
+    }
+
+    /// Predicts how long the heat sink takes to cool down to `target_temperature` if current
+    /// cooling conditions persist. Capped to the prediction horizon.
+    ///
+    /// Cooling power is linearised at the mean temperature of the cool down, giving an exponential
+    /// decay of the heat sink temperature towards ambient.
+    fn time_to_cool_down(
+        &self,
+        context: &UpdateContext,
+        brake_properties: &BrakeProperties,
+        target_temperature: ThermodynamicTemperature,
+        brake_fan_on: bool,
+    ) -> Duration {
+        let ambient = context.ambient_temperature().get::<degree_celsius>();
+        let initial_delta = self.temperature.get::<degree_celsius>() - ambient;
+        let target_delta = target_temperature.get::<degree_celsius>() - ambient;
+
+        if initial_delta <= target_delta {
+            return Duration::ZERO;
+        }
+        if target_delta <= 0. {
+            return Self::COOL_DOWN_PREDICTION_HORIZON;
+        }
+
+        let mean_delta = TemperatureInterval::new::<kelvin>((initial_delta + target_delta) / 2.);
+        let time_constant: Time = brake_properties.heat_capacity() * mean_delta
+            / self.cooling_power(
+                context,
+                brake_properties,
+                context.ambient_temperature() + mean_delta,
+                brake_fan_on,
+            );
+
+        let time_to_cool_down = time_constant.get::<second>() * (initial_delta / target_delta).ln();
+
+        Duration::from_secs_f64(time_to_cool_down.max(0.)).min(Self::COOL_DOWN_PREDICTION_HORIZON)
     }
 
     fn calculate_gear_convection_coefficient(
@@ -291,8 +468,183 @@ impl Brake {
     }
 }
 impl SimulationElement for Brake {
+    fn read(&mut self, reader: &mut SimulatorReader) {
+        // Wear is read back so it can be restored from one flight to the next
+        self.wear = reader.read(&self.wear_id);
+    }
+
     fn write(&self, writer: &mut SimulatorWriter) {
         writer.write(&self.temperature_id, self.temperature);
+        writer.write(&self.wear_id, self.wear);
+    }
+}
+
+/// Simulates a wheel rim heated by its brake, with the fuse plugs deflating the tyre mounted on it
+/// once the rim overheats. Tyre gas is considered at rim temperature.
+struct Wheel {
+    tyre_pressure_id: VariableIdentifier,
+    tyre_temperature_id: VariableIdentifier,
+    fuse_plug_melted_id: VariableIdentifier,
+    tyre_burst_id: VariableIdentifier,
+
+    temperature: ThermodynamicTemperature,
+    ambient_pressure: Pressure,
+    initialized: bool,
+
+    nominal_pressure: Pressure,
+    cold_pressure: Pressure,
+    fuse_plug_melted: bool,
+    burst: bool,
+
+    slow_leak_failure: Failure,
+    burst_failure: Failure,
+}
+impl Wheel {
+    /// Heat capacity of the wheel rim and tyre, J/K
+    const HEAT_CAPACITY: f64 = 54000.;
+    /// Thermal conductance from the brake heat sink to the rim, W/K
+    const BRAKE_TO_WHEEL_CONDUCTANCE: f64 = 60.;
+    /// Thermal conductance from the rim to ambient air, W/K
+    const WHEEL_TO_AMBIENT_CONDUCTANCE: f64 = 120.;
+
+    const FUSE_PLUG_MELTING_TEMPERATURE_DEGREE_CELSIUS: f64 = 177.;
+    const DEFLATION_TIME_CONSTANT_S: f64 = 8.;
+    const SLOW_LEAK_TIME_CONSTANT_S: f64 = 3600.;
+    const BURST_DEFLATION_TIME_CONSTANT_S: f64 = 0.1;
+    /// Temperature at which the cold inflation pressure is defined, K
+    const INFLATION_REFERENCE_TEMPERATURE: f64 = 288.15;
+
+    const DEFLATED_INFLATION_RATIO: f64 = 0.2;
+
+    const INFLATION_RATIO_BREAKPOINTS: [f64; 4] = [0., 0.5, 0.9, 1.];
+    const BRAKING_EFFICIENCY: [f64; 4] = [0.4, 0.75, 1., 1.];
+    const ROLLING_RESISTANCE_FACTOR: [f64; 4] = [3.5, 1.8, 1., 1.];
+
+    fn new(context: &mut InitContext, index: usize, nominal_pressure: Pressure) -> Self {
+        Self {
+            tyre_pressure_id: context.get_identifier(format!("TYRE_PRESSURE_{index}")),
+            tyre_temperature_id: context.get_identifier(format!("TYRE_TEMPERATURE_{index}")),
+            fuse_plug_melted_id: context.get_identifier(format!("WHEEL_FUSE_PLUG_MELTED_{index}")),
+            tyre_burst_id: context.get_identifier(format!("TYRE_BURST_{index}")),
+
+            temperature: ThermodynamicTemperature::default(),
+            ambient_pressure: Pressure::default(),
+            initialized: false,
+
+            nominal_pressure,
+            cold_pressure: nominal_pressure,
+            fuse_plug_melted: false,
+            burst: false,
+
+            slow_leak_failure: Failure::new(FailureType::TyreSlowLeak(index)),
+            burst_failure: Failure::new(FailureType::TyreBurst(index)),
+        }
+    }
+
+    fn update(
+        &mut self,
+        context: &UpdateContext,
+        brake_temperature: Option<ThermodynamicTemperature>,
+    ) {
+        if !self.initialized {
+            self.temperature = context.ambient_temperature();
+            self.initialized = true;
+        }
+        self.ambient_pressure = context.ambient_pressure();
+
+        let heat_from_brake = brake_temperature.map_or(0., |brake_temperature| {
+            Self::BRAKE_TO_WHEEL_CONDUCTANCE
+                * (brake_temperature.get::<degree_celsius>()
+                    - self.temperature.get::<degree_celsius>())
+        });
+        let heat_to_ambient = Self::WHEEL_TO_AMBIENT_CONDUCTANCE
+            * (self.temperature.get::<degree_celsius>()
+                - context.ambient_temperature().get::<degree_celsius>());
+
+        self.temperature += TemperatureInterval::new::<kelvin>(
+            (heat_from_brake - heat_to_ambient) * context.delta_as_secs_f64() / Self::HEAT_CAPACITY,
+        );
+
+        if self.temperature.get::<degree_celsius>()
+            > Self::FUSE_PLUG_MELTING_TEMPERATURE_DEGREE_CELSIUS
+        {
+            self.fuse_plug_melted = true;
+        }
+
+        if self.burst_failure.is_active() {
+            self.burst = true;
+        }
+
+        let deflation_time_constant = if self.burst {
+            Some(Self::BURST_DEFLATION_TIME_CONSTANT_S)
+        } else if self.fuse_plug_melted {
+            Some(Self::DEFLATION_TIME_CONSTANT_S)
+        } else if self.slow_leak_failure.is_active() {
+            Some(Self::SLOW_LEAK_TIME_CONSTANT_S)
+        } else {
+            None
+        };
+        if let Some(time_constant) = deflation_time_constant {
+            // Gas escapes until the tyre is at ambient pressure at its current temperature
+            let deflated_cold_pressure = (self.ambient_pressure
+                * Self::INFLATION_REFERENCE_TEMPERATURE
+                / self.temperature.get::<thermodynamic_temperature::kelvin>())
+                - self.ambient_pressure;
+            self.cold_pressure -= (self.cold_pressure - deflated_cold_pressure)
+                * (context.delta_as_secs_f64() / time_constant).min(1.);
+        }
+    }
+
+    fn inflation_ratio(&self) -> f64 {
+        (self.cold_pressure / self.nominal_pressure).get::<ratio>()
+    }
+
+    fn is_tyre_deflated(&self) -> bool {
+        self.inflation_ratio() < Self::DEFLATED_INFLATION_RATIO
+    }
+
+    fn braking_efficiency(&self) -> Ratio {
+        Ratio::new::<ratio>(interpolation(
+            &Self::INFLATION_RATIO_BREAKPOINTS,
+            &Self::BRAKING_EFFICIENCY,
+            self.inflation_ratio(),
+        ))
+    }
+
+    fn rolling_resistance_factor(&self) -> Ratio {
+        Ratio::new::<ratio>(interpolation(
+            &Self::INFLATION_RATIO_BREAKPOINTS,
+            &Self::ROLLING_RESISTANCE_FACTOR,
+            self.inflation_ratio(),
+        ))
+    }
+}
+impl TyrePressure for Wheel {
+    // Tyre pressure is a gauge pressure, the gas law applies to the absolute pressure
+    fn tyre_pressure(&self) -> Pressure {
+        (self.cold_pressure + self.ambient_pressure)
+            * self.temperature.get::<thermodynamic_temperature::kelvin>()
+            / Self::INFLATION_REFERENCE_TEMPERATURE
+            - self.ambient_pressure
+    }
+
+    fn tyre_temperature(&self) -> ThermodynamicTemperature {
+        self.temperature
+    }
+}
+impl SimulationElement for Wheel {
+    fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
+        self.slow_leak_failure.accept(visitor);
+        self.burst_failure.accept(visitor);
+
+        visitor.visit(self);
+    }
+
+    fn write(&self, writer: &mut SimulatorWriter) {
+        writer.write(&self.tyre_pressure_id, self.tyre_pressure());
+        writer.write(&self.tyre_temperature_id, self.temperature);
+        writer.write(&self.fuse_plug_melted_id, self.fuse_plug_melted);
+        writer.write(&self.tyre_burst_id, self.burst);
     }
 }
 
@@ -358,21 +710,31 @@ impl BrakeProbe {
             self.initialised = true;
         }
 
-        // TODO: implement a more physical based simulation
-        let target_temperature = if brake_fan_is_on {
-            (context.ambient_temperature().get::<degree_celsius>()
-                + brake.temperature().get::<degree_celsius>())
-                / 2.
-        } else {
-            brake.temperature().get::<degree_celsius>()
-        };
-
         let target_temperature_diff = TemperatureInterval::new::<kelvin>(
-            target_temperature - self.temperature.get::<degree_celsius>(),
+            Self::target_temperature(context, brake, brake_fan_is_on).get::<degree_celsius>()
+                - self.temperature.get::<degree_celsius>(),
         );
 
         self.temperature += target_temperature_diff * Self::THERMAL_INERTIA;
     }
+
+    /// Temperature the probe converges to once heat has soaked from the heat sink
+    fn target_temperature(
+        context: &UpdateContext,
+        brake: &Brake,
+        brake_fan_is_on: bool,
+    ) -> ThermodynamicTemperature {
+        // TODO: implement a more physical based simulation
+        if brake_fan_is_on {
+            ThermodynamicTemperature::new::<degree_celsius>(
+                (context.ambient_temperature().get::<degree_celsius>()
+                    + brake.temperature().get::<degree_celsius>())
+                    / 2.,
+            )
+        } else {
+            brake.temperature()
+        }
+    }
 }
 impl ControllerSignal<ThermodynamicTemperature> for BrakeProbe {
     fn signal(&self) -> Option<ThermodynamicTemperature> {
@@ -385,6 +747,76 @@ impl SimulationElement for BrakeProbe {
     }
 }
 
+/// Predicts brake temperatures from the heat content of the brake and its cooling model, for
+/// turnaround planning.
+///
+/// The soak temperature is the indication the temperature probe converges to once the heat has
+/// soaked from the heat sink. Times to dispatch are the times for the heat sink to cool below
+/// the takeoff limit, with and without brake fans if installed.
+struct BrakeTemperaturePredictor {
+    soak_temperature_id: VariableIdentifier,
+    time_to_dispatch_id: VariableIdentifier,
+    time_to_dispatch_with_fans_id: VariableIdentifier,
+
+    has_brake_fans: bool,
+
+    soak_temperature: ThermodynamicTemperature,
+    time_to_dispatch: Duration,
+    time_to_dispatch_with_fans: Duration,
+}
+impl BrakeTemperaturePredictor {
+    const DISPATCH_TEMPERATURE_LIMIT_DEGREE_CELSIUS: f64 = 300.;
+
+    fn new(context: &mut InitContext, index: usize, has_brake_fans: bool) -> Self {
+        Self {
+            soak_temperature_id: context.get_identifier(format!("BRAKE_SOAK_TEMPERATURE_{index}")),
+            time_to_dispatch_id: context.get_identifier(format!("BRAKE_TIME_TO_DISPATCH_{index}")),
+            time_to_dispatch_with_fans_id: context
+                .get_identifier(format!("BRAKE_TIME_TO_DISPATCH_WITH_FANS_{index}")),
+
+            has_brake_fans,
+
+            soak_temperature: ThermodynamicTemperature::default(),
+            time_to_dispatch: Duration::ZERO,
+            time_to_dispatch_with_fans: Duration::ZERO,
+        }
+    }
+
+    fn update(
+        &mut self,
+        context: &UpdateContext,
+        brake_properties: &BrakeProperties,
+        brake: &Brake,
+        brake_fan_is_running: bool,
+    ) {
+        self.soak_temperature =
+            BrakeProbe::target_temperature(context, brake, brake_fan_is_running);
+
+        let dispatch_limit = ThermodynamicTemperature::new::<degree_celsius>(
+            Self::DISPATCH_TEMPERATURE_LIMIT_DEGREE_CELSIUS,
+        );
+        self.time_to_dispatch =
+            brake.time_to_cool_down(context, brake_properties, dispatch_limit, false);
+        self.time_to_dispatch_with_fans = if self.has_brake_fans {
+            brake.time_to_cool_down(context, brake_properties, dispatch_limit, true)
+        } else {
+            self.time_to_dispatch
+        };
+    }
+}
+impl SimulationElement for BrakeTemperaturePredictor {
+    fn write(&self, writer: &mut SimulatorWriter) {
+        writer.write(&self.soak_temperature_id, self.soak_temperature);
+        writer.write(&self.time_to_dispatch_id, self.time_to_dispatch);
+        if self.has_brake_fans {
+            writer.write(
+                &self.time_to_dispatch_with_fans_id,
+                self.time_to_dispatch_with_fans,
+            );
+        }
+    }
+}
+
 pub struct BrakeFanPanel {
     brake_fan_pb_identifier: VariableIdentifier,
     brakes_hot_identifier: VariableIdentifier,
@@ -426,13 +858,14 @@ mod tests {
 
     use super::*;
     use crate::simulation::{
-        test::{ReadByName, SimulationTestBed, TestBed},
+        test::{ReadByName, SimulationTestBed, TestBed, WriteByName},
         Aircraft,
     };
     use uom::si::{
         length::meter,
         mass::kilogram,
         pressure::{pascal, psi},
+        ratio::percent,
         thermodynamic_temperature::degree_celsius,
     };
 
@@ -570,9 +1003,229 @@ mod tests {
         );
     }
 
+    #[test]
+    fn braking_wears_the_brake() {
+        let mut test_bed = SimulationTestBed::new(TestAircraft::new);
+        test_bed.run_without_delta();
+
+        test_bed.command(|a| a.set_actuator_pressure(Pressure::new::<psi>(3000.0)));
+        test_bed.command(|a| a.set_passed_length(Length::new::<meter>(0.1)));
+
+        test_bed.run_with_delta(Duration::from_millis(10));
+
+        let wear: Ratio = test_bed.read_by_name("BRAKE_WEAR_0");
+        assert!(wear > Ratio::default(), "Brake should wear when applied");
+    }
+
+    #[test]
+    fn brake_wear_is_restored_from_previous_flight() {
+        let mut test_bed = SimulationTestBed::new(TestAircraft::new);
+        test_bed.write_by_name("BRAKE_WEAR_0", 40.);
+        test_bed.run_without_delta();
+
+        test_bed.command(|a| a.set_actuator_pressure(Pressure::new::<psi>(3000.0)));
+        test_bed.command(|a| a.set_passed_length(Length::new::<meter>(0.1)));
+
+        test_bed.run_with_delta(Duration::from_millis(10));
+
+        let wear: Ratio = test_bed.read_by_name("BRAKE_WEAR_0");
+        assert!(wear > Ratio::new::<percent>(40.));
+        assert!(wear < Ratio::new::<percent>(41.));
+    }
+
+    #[test]
+    fn hot_brake_heats_up_tyre_without_melting_fuse_plug() {
+        let mut test_bed = SimulationTestBed::new(TestAircraft::new);
+        test_bed.set_ambient_temperature(ThermodynamicTemperature::new::<degree_celsius>(15.));
+        test_bed.run_without_delta();
+
+        for _ in 0..600 {
+            test_bed.command(|a| {
+                a.set_brake_temperature(ThermodynamicTemperature::new::<degree_celsius>(300.))
+            });
+            test_bed.run_with_delta(Duration::from_secs(1));
+        }
+
+        let tyre_pressure: Pressure = test_bed.read_by_name("TYRE_PRESSURE_0");
+        let fuse_plug_melted: bool = test_bed.read_by_name("WHEEL_FUSE_PLUG_MELTED_0");
+
+        assert!(tyre_pressure > Pressure::new::<psi>(200.));
+        assert!(!fuse_plug_melted);
+        assert!(!test_bed.query(|a| a.wheel.is_tyre_deflated()));
+    }
+
+    #[test]
+    fn overheated_wheel_melts_fuse_plug_and_deflates_tyre() {
+        let mut test_bed = SimulationTestBed::new(TestAircraft::new);
+        test_bed.set_ambient_temperature(ThermodynamicTemperature::new::<degree_celsius>(15.));
+        test_bed.run_without_delta();
+
+        for _ in 0..900 {
+            test_bed.command(|a| {
+                a.set_brake_temperature(ThermodynamicTemperature::new::<degree_celsius>(800.))
+            });
+            test_bed.run_with_delta(Duration::from_secs(1));
+        }
+
+        let tyre_pressure: Pressure = test_bed.read_by_name("TYRE_PRESSURE_0");
+        let fuse_plug_melted: bool = test_bed.read_by_name("WHEEL_FUSE_PLUG_MELTED_0");
+
+        assert!(fuse_plug_melted);
+        assert!(tyre_pressure < Pressure::new::<psi>(10.));
+        assert!(test_bed.query(|a| a.wheel.is_tyre_deflated()));
+        assert!(test_bed.query(|a| a.wheel.braking_efficiency()) < Ratio::new::<ratio>(0.5));
+        assert!(test_bed.query(|a| a.wheel.rolling_resistance_factor()) > Ratio::new::<ratio>(3.));
+    }
+
+    #[test]
+    fn slow_leak_deflates_tyre_over_hours() {
+        let mut test_bed = SimulationTestBed::new(TestAircraft::new);
+        test_bed.set_ambient_temperature(ThermodynamicTemperature::new::<degree_celsius>(15.));
+        test_bed.run_without_delta();
+
+        test_bed.fail(FailureType::TyreSlowLeak(0));
+        for _ in 0..10 {
+            test_bed.run_with_delta(Duration::from_secs(60));
+        }
+
+        let tyre_pressure: Pressure = test_bed.read_by_name("TYRE_PRESSURE_0");
+        assert!(tyre_pressure < Pressure::new::<psi>(195.));
+        assert!(tyre_pressure > Pressure::new::<psi>(160.));
+        assert!(!test_bed.query(|a| a.wheel.is_tyre_deflated()));
+
+        for _ in 0..120 {
+            test_bed.run_with_delta(Duration::from_secs(60));
+        }
+
+        assert!(test_bed.query(|a| a.wheel.is_tyre_deflated()));
+    }
+
+    #[test]
+    fn burst_tyre_deflates_instantly_and_stays_deflated() {
+        let mut test_bed = SimulationTestBed::new(TestAircraft::new);
+        test_bed.set_ambient_temperature(ThermodynamicTemperature::new::<degree_celsius>(15.));
+        test_bed.run_without_delta();
+
+        test_bed.fail(FailureType::TyreBurst(0));
+        test_bed.run_with_delta(Duration::from_secs(1));
+
+        let tyre_burst: bool = test_bed.read_by_name("TYRE_BURST_0");
+        assert!(tyre_burst);
+        assert!(test_bed.query(|a| a.wheel.is_tyre_deflated()));
+
+        test_bed.unfail(FailureType::TyreBurst(0));
+        test_bed.run_with_delta(Duration::from_secs(1));
+
+        assert!(test_bed.query(|a| a.wheel.is_tyre_deflated()));
+    }
+
+    #[test]
+    fn cold_brake_can_be_dispatched_immediately() {
+        let mut test_bed = SimulationTestBed::new(TestAircraft::new);
+        test_bed.run_without_delta();
+        test_bed.run_with_delta(Duration::from_millis(10));
+
+        let time_to_dispatch: Duration = test_bed.read_by_name("BRAKE_TIME_TO_DISPATCH_0");
+        let time_to_dispatch_with_fans: Duration =
+            test_bed.read_by_name("BRAKE_TIME_TO_DISPATCH_WITH_FANS_0");
+
+        assert_eq!(time_to_dispatch, Duration::ZERO);
+        assert_eq!(time_to_dispatch_with_fans, Duration::ZERO);
+    }
+
+    #[test]
+    fn hot_brake_time_to_dispatch_is_shorter_with_brake_fans() {
+        let mut test_bed = SimulationTestBed::new(TestAircraft::new);
+        test_bed.run_without_delta();
+
+        test_bed.command(|a| {
+            a.set_brake_temperature(ThermodynamicTemperature::new::<degree_celsius>(500.))
+        });
+        test_bed.run_with_delta(Duration::from_millis(10));
+
+        let time_to_dispatch: Duration = test_bed.read_by_name("BRAKE_TIME_TO_DISPATCH_0");
+        let time_to_dispatch_with_fans: Duration =
+            test_bed.read_by_name("BRAKE_TIME_TO_DISPATCH_WITH_FANS_0");
+
+        assert!(time_to_dispatch_with_fans > Duration::ZERO);
+        assert!(time_to_dispatch_with_fans < time_to_dispatch);
+    }
+
+    #[test]
+    fn brake_cools_down_to_dispatch_limit_in_predicted_time() {
+        let mut test_bed = SimulationTestBed::new(TestAircraft::new);
+        test_bed.set_ambient_temperature(ThermodynamicTemperature::new::<degree_celsius>(15.));
+        test_bed.run_without_delta();
+
+        test_bed.command(|a| {
+            a.set_brake_temperature(ThermodynamicTemperature::new::<degree_celsius>(400.))
+        });
+        test_bed.run_with_delta(Duration::from_millis(10));
+
+        let time_to_dispatch: Duration = test_bed.read_by_name("BRAKE_TIME_TO_DISPATCH_0");
+        assert!(time_to_dispatch > Duration::from_secs(60));
+
+        for _ in 0..time_to_dispatch.as_secs() {
+            test_bed.run_with_delta(Duration::from_secs(1));
+        }
+
+        let brake_temperature: ThermodynamicTemperature =
+            test_bed.read_by_name("BRAKE_TEMPERATURE_0");
+        assert!(brake_temperature < ThermodynamicTemperature::new::<degree_celsius>(303.));
+        assert!(brake_temperature > ThermodynamicTemperature::new::<degree_celsius>(297.));
+    }
+
+    #[test]
+    fn soak_temperature_anticipates_probe_heat_soak() {
+        let mut test_bed = SimulationTestBed::new(TestAircraft::new);
+        test_bed.set_ambient_temperature(ThermodynamicTemperature::new::<degree_celsius>(15.));
+        test_bed.run_without_delta();
+
+        test_bed.command(|a| {
+            a.set_brake_temperature(ThermodynamicTemperature::new::<degree_celsius>(400.))
+        });
+        test_bed.run_with_delta(Duration::from_millis(10));
+
+        let soak_temperature: ThermodynamicTemperature =
+            test_bed.read_by_name("BRAKE_SOAK_TEMPERATURE_0");
+
+        assert!(
+            test_bed.query(|a| a.brake_probe.temperature)
+                < ThermodynamicTemperature::new::<degree_celsius>(100.)
+        );
+        assert!(soak_temperature > ThermodynamicTemperature::new::<degree_celsius>(390.));
+    }
+
+    #[test]
+    fn very_hot_brake_oxidises_faster_with_brake_fan() {
+        fn wear_after_an_hour_at_600_degrees(brake_fan_on: bool) -> Ratio {
+            let mut test_bed = SimulationTestBed::new(TestAircraft::new);
+            test_bed.run_without_delta();
+            test_bed.command(|a| a.set_brake_fan_on(brake_fan_on));
+
+            for _ in 0..60 {
+                test_bed.command(|a| {
+                    a.set_brake_temperature(ThermodynamicTemperature::new::<degree_celsius>(600.))
+                });
+                test_bed.run_with_delta(Duration::from_secs(60));
+            }
+
+            test_bed.read_by_name("BRAKE_WEAR_0")
+        }
+
+        let wear_without_fan = wear_after_an_hour_at_600_degrees(false);
+        let wear_with_fan = wear_after_an_hour_at_600_degrees(true);
+
+        assert!(wear_without_fan > Ratio::default());
+        assert!(wear_with_fan > wear_without_fan);
+    }
+
     struct TestAircraft {
         brake_properties: BrakeProperties,
         brake: Brake,
+        brake_probe: BrakeProbe,
+        temperature_predictor: BrakeTemperaturePredictor,
+        wheel: Wheel,
         passed_length: Length,
         actuator_pressure: Pressure,
         brake_fan_on: bool,
@@ -587,6 +1240,9 @@ mod tests {
                     Mass::new::<kilogram>(8.0),
                 ),
                 brake: Brake::new(context, 0),
+                brake_probe: BrakeProbe::new(ElectricalBusType::DirectCurrent(1)),
+                temperature_predictor: BrakeTemperaturePredictor::new(context, 0, true),
+                wheel: Wheel::new(context, 0, Pressure::new::<psi>(200.)),
                 passed_length: Length::default(),
                 actuator_pressure: Pressure::default(),
                 brake_fan_on: false,
@@ -622,11 +1278,23 @@ mod tests {
                 self.brake_fan_on,
                 self.gear_extended_phys,
             );
+            self.brake_probe
+                .update(context, &self.brake, self.brake_fan_on);
+            self.wheel.update(context, Some(self.brake.temperature()));
+            self.temperature_predictor.update(
+                context,
+                &self.brake_properties,
+                &self.brake,
+                self.brake_fan_on,
+            );
         }
     }
     impl SimulationElement for TestAircraft {
         fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
             self.brake.accept(visitor);
+            self.brake_probe.accept(visitor);
+            self.temperature_predictor.accept(visitor);
+            self.wheel.accept(visitor);
 
             visitor.visit(self);
         }
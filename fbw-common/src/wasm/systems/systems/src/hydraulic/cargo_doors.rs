@@ -1,10 +1,11 @@
 use uom::si::{f64::*, pressure::psi, ratio::ratio};
 
 use crate::{
-    shared::{random_from_normal_distribution, SectionPressure},
+    failures::{Failure, FailureType},
+    shared::{random_from_normal_distribution, CargoDoorId, SectionPressure},
     simulation::{
-        InitContext, Read, SimulationElement, SimulatorReader, SimulatorWriter, UpdateContext,
-        VariableIdentifier, Write,
+        InitContext, Read, SimulationElement, SimulationElementVisitor, SimulatorReader,
+        SimulatorWriter, UpdateContext, VariableIdentifier, Write,
     },
 };
 
@@ -14,7 +15,7 @@ use super::{
         ElectroHydrostaticPowered, HydraulicAssemblyController, HydraulicLinearActuatorAssembly,
         HydraulicLocking, LinearActuatorMode,
     },
-    Actuator,
+    Actuator, PumpController,
 };
 
 use std::time::Duration;
@@ -42,6 +43,11 @@ pub struct HydraulicDoorController {
     should_close_valves: bool,
     control_position_request: Ratio,
     should_unlock: bool,
+
+    is_latch_stuck: bool,
+    is_door_locked: bool,
+    is_door_held_in_position: bool,
+    hydraulic_lock_position: Option<Ratio>,
 }
 impl HydraulicDoorController {
     // Duration which the hydraulic valves sends a open request when request is closing (this is done on real aircraft so uplock can be easily unlocked without friction)
@@ -65,6 +71,11 @@ impl HydraulicDoorController {
             should_close_valves: true,
             control_position_request: Ratio::new::<ratio>(0.),
             should_unlock: false,
+
+            is_latch_stuck: false,
+            is_door_locked: true,
+            is_door_held_in_position: false,
+            hydraulic_lock_position: None,
         }
     }
 
@@ -81,6 +92,18 @@ impl HydraulicDoorController {
         door: &CargoDoor,
         current_pressure: &impl SectionPressure,
     ) {
+        self.is_latch_stuck = door.is_latch_stuck();
+        self.is_door_locked = door.is_locked();
+        self.is_door_held_in_position = door.is_held_in_position();
+
+        // Trapped fluid in the actuator: whatever the selector valve does the door stays where it
+        // was when the fluid got trapped
+        self.hydraulic_lock_position = if door.has_hydraulic_lock() {
+            Some(self.hydraulic_lock_position.unwrap_or(door.position()))
+        } else {
+            None
+        };
+
         self.control_state =
             self.determine_control_state_and_lock_action(door, current_pressure.pressure());
         self.update_timers(context);
@@ -129,7 +152,9 @@ impl HydraulicDoorController {
         current_pressure: Pressure,
     ) -> DoorControlState {
         match self.control_state {
-            DoorControlState::DownLocked if self.position_requested > Ratio::new::<ratio>(0.) => {
+            DoorControlState::DownLocked
+                if self.position_requested > Ratio::new::<ratio>(0.) && !door.is_latch_stuck() =>
+            {
                 self.should_unlock = true;
                 DoorControlState::NoControl
             }
@@ -186,11 +211,23 @@ impl HydraulicAssemblyController for HydraulicDoorController {
     }
 
     fn should_lock(&self) -> bool {
-        !self.should_unlock
+        // A stuck latch stays in the state it jammed in whatever the ground crew does
+        if self.is_latch_stuck {
+            self.is_door_locked
+        } else if self.hydraulic_lock_position.is_some() {
+            true
+        } else {
+            // Once the trapped fluid is released the door is free to move again
+            !self.should_unlock && !self.is_door_held_in_position
+        }
     }
 
     fn requested_lock_position(&self) -> Ratio {
-        Ratio::new::<ratio>(0.)
+        // A latched door can't be released either, as the actuator can't lift it off the latch
+        match self.hydraulic_lock_position {
+            Some(position) if !self.is_door_locked => position,
+            _ => Ratio::new::<ratio>(0.),
+        }
     }
 }
 impl SimulationElement for HydraulicDoorController {
@@ -206,16 +243,29 @@ pub struct CargoDoor {
 
     position_id: VariableIdentifier,
     locked_id: VariableIdentifier,
+    closed_id: VariableIdentifier,
+    not_locked_id: VariableIdentifier,
     position: Ratio,
 
     is_locked: bool,
 
+    latch_stuck_failure: Failure,
+    hydraulic_lock_failure: Failure,
+
     aerodynamic_model: AerodynamicModel,
 }
 impl CargoDoor {
+    // Door closed proximity sensor target position
+    const CLOSED_SENSOR_MAX_POSITION: f64 = 0.02;
+
+    // Above this position the door is considered fully open, held by its uplock. Once the valves
+    // close, the door settles slightly below the position where the uplock engages.
+    const FULLY_OPEN_MIN_POSITION: f64 = 0.85;
+
     pub fn new(
         context: &mut InitContext,
         id: &str,
+        door_id: CargoDoorId,
         hydraulic_assembly: HydraulicLinearActuatorAssembly<1>,
         aerodynamic_model: AerodynamicModel,
     ) -> Self {
@@ -223,11 +273,16 @@ impl CargoDoor {
             hydraulic_assembly,
             position_id: context.get_identifier(format!("{}_DOOR_CARGO_POSITION", id)),
             locked_id: context.get_identifier(format!("{}_DOOR_CARGO_LOCKED", id)),
+            closed_id: context.get_identifier(format!("{}_DOOR_CARGO_CLOSED", id)),
+            not_locked_id: context.get_identifier(format!("{}_DOOR_CARGO_NOT_LOCKED", id)),
 
             position: Ratio::new::<ratio>(0.),
 
             is_locked: true,
 
+            latch_stuck_failure: Failure::new(FailureType::CargoDoorLatchStuck(door_id)),
+            hydraulic_lock_failure: Failure::new(FailureType::CargoDoorHydraulicLock(door_id)),
+
             aerodynamic_model,
         }
     }
@@ -240,6 +295,29 @@ impl CargoDoor {
         self.is_locked
     }
 
+    pub fn is_closed(&self) -> bool {
+        self.position <= Ratio::new::<ratio>(Self::CLOSED_SENSOR_MAX_POSITION)
+    }
+
+    /// Door is neither latched closed nor fully open: partially closed, or closed without the
+    /// latch engaged.
+    pub fn is_not_locked(&self) -> bool {
+        !self.is_locked && self.position < Ratio::new::<ratio>(Self::FULLY_OPEN_MIN_POSITION)
+    }
+
+    /// Door is held away from its latch by fluid trapped in the actuator.
+    pub fn is_held_in_position(&self) -> bool {
+        self.hydraulic_assembly.is_locked() && !self.is_locked
+    }
+
+    pub fn is_latch_stuck(&self) -> bool {
+        self.latch_stuck_failure.is_active()
+    }
+
+    pub fn has_hydraulic_lock(&self) -> bool {
+        self.hydraulic_lock_failure.is_active()
+    }
+
     pub fn actuator(&mut self) -> &mut impl Actuator {
         self.hydraulic_assembly.actuator(0)
     }
@@ -261,12 +339,53 @@ impl CargoDoor {
         );
 
         self.position = self.hydraulic_assembly.position_normalized();
-        self.is_locked = self.hydraulic_assembly.is_locked();
+        // A hydraulic lock holds the body wherever it is, only the latch at the closed position
+        // counts as locked
+        self.is_locked = self.hydraulic_assembly.is_locked() && self.is_closed();
     }
 }
 impl SimulationElement for CargoDoor {
+    fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
+        self.latch_stuck_failure.accept(visitor);
+        self.hydraulic_lock_failure.accept(visitor);
+
+        visitor.visit(self);
+    }
+
     fn write(&self, writer: &mut SimulatorWriter) {
         writer.write(&self.position_id, self.position());
         writer.write(&self.locked_id, self.is_locked());
+        writer.write(&self.closed_id, self.is_closed());
+        writer.write(&self.not_locked_id, self.is_not_locked());
+    }
+}
+
+/// Cargo door hand pump lever, operated by the ground crew. It is the fallback to move the cargo
+/// doors when no electrical power is available to run the electric pump.
+pub struct CargoDoorHandPumpController {
+    operated_id: VariableIdentifier,
+
+    is_operated: bool,
+}
+impl CargoDoorHandPumpController {
+    pub fn new(context: &mut InitContext) -> Self {
+        Self {
+            operated_id: context.get_identifier("HYD_CARGO_DOOR_HAND_PUMP_OPERATED".to_owned()),
+            is_operated: false,
+        }
+    }
+
+    pub fn is_operated(&self) -> bool {
+        self.is_operated
+    }
+}
+impl PumpController for CargoDoorHandPumpController {
+    fn should_pressurise(&self) -> bool {
+        self.is_operated
+    }
+}
+impl SimulationElement for CargoDoorHandPumpController {
+    fn read(&mut self, reader: &mut SimulatorReader) {
+        self.is_operated = reader.read(&self.operated_id);
     }
 }
@@ -1,9 +1,9 @@
 use crate::{
     failures::{Failure, FailureType},
-    landing_gear::GearSystemSensors,
+    landing_gear::{GearSystemSensors, LandingGear},
     shared::{
-        random_from_range, ElectricalBusType, GearActuatorId, GearWheel, LgciuGearControl, LgciuId,
-        ProximityDetectorId, SectionPressure,
+        interpolation, random_from_range, ElectricalBusType, GearActuatorId, GearWheel,
+        LgciuGearControl, LgciuId, ProximityDetectorId, SectionPressure,
     },
     simulation::{
         InitContext, SimulationElement, SimulationElementVisitor, SimulatorWriter, UpdateContext,
@@ -20,12 +20,131 @@ use super::{
     HydraulicValve, HydraulicValveType,
 };
 
-use uom::si::{f64::*, pressure::psi, ratio::ratio};
+use uom::si::{
+    f64::*,
+    mass::kilogram,
+    pressure::psi,
+    ratio::ratio,
+    velocity::{foot_per_second, knot},
+};
 
 pub trait GearGravityExtension {
     fn extension_handle_number_of_turns(&self) -> u8;
 }
 
+#[derive(PartialEq, Eq, PartialOrd, Ord, Clone, Copy, Debug)]
+pub enum GearDamage {
+    None = 0,
+    Damaged = 1,
+    Collapsed = 2,
+}
+
+/// Structural limits of the gear installation.
+/// Doors are damaged when the gear is operated above VLO or flown extended above VLE.
+/// Touchdown sink rate limit is interpolated between max landing weight and max takeoff weight
+pub struct GearStructuralLimits {
+    max_operating_speed: Velocity,
+    max_extended_speed: Velocity,
+    max_landing_weight: Mass,
+    max_takeoff_weight: Mass,
+}
+impl GearStructuralLimits {
+    const LIMIT_SINK_RATE_AT_MAX_LANDING_WEIGHT_FT_S: f64 = 10.;
+    const LIMIT_SINK_RATE_AT_MAX_TAKEOFF_WEIGHT_FT_S: f64 = 6.;
+
+    // Strut load is roughly proportional to sink rate. Above ultimate load the strut fails
+    const ULTIMATE_TO_LIMIT_LOAD_RATIO: f64 = 1.5;
+
+    pub fn new(
+        max_operating_speed: Velocity,
+        max_extended_speed: Velocity,
+        max_landing_weight: Mass,
+        max_takeoff_weight: Mass,
+    ) -> Self {
+        Self {
+            max_operating_speed,
+            max_extended_speed,
+            max_landing_weight,
+            max_takeoff_weight,
+        }
+    }
+
+    fn limit_sink_rate(&self, weight: Mass) -> Velocity {
+        Velocity::new::<foot_per_second>(interpolation(
+            &[
+                self.max_landing_weight.get::<kilogram>(),
+                self.max_takeoff_weight.get::<kilogram>(),
+            ],
+            &[
+                Self::LIMIT_SINK_RATE_AT_MAX_LANDING_WEIGHT_FT_S,
+                Self::LIMIT_SINK_RATE_AT_MAX_TAKEOFF_WEIGHT_FT_S,
+            ],
+            weight.get::<kilogram>(),
+        ))
+    }
+
+    fn ultimate_sink_rate(&self, weight: Mass) -> Velocity {
+        self.limit_sink_rate(weight) * Self::ULTIMATE_TO_LIMIT_LOAD_RATIO
+    }
+
+    fn touchdown_damage(&self, sink_rate: Velocity, weight: Mass) -> GearDamage {
+        if sink_rate > self.ultimate_sink_rate(weight) {
+            GearDamage::Collapsed
+        } else if sink_rate > self.limit_sink_rate(weight) {
+            GearDamage::Damaged
+        } else {
+            GearDamage::None
+        }
+    }
+
+    fn door_overspeed(
+        &self,
+        context: &UpdateContext,
+        door_is_out_of_uplock: bool,
+        gear_is_out_of_uplock: bool,
+    ) -> bool {
+        let airspeed = context.indicated_airspeed();
+
+        (door_is_out_of_uplock && airspeed > self.max_operating_speed)
+            || (gear_is_out_of_uplock && airspeed > self.max_extended_speed)
+    }
+}
+
+/// Records the sink rate while the strut is extended and assesses the touchdown once it compresses
+struct GearTouchdownMonitor {
+    was_compressed: bool,
+    sink_rate: Velocity,
+}
+impl GearTouchdownMonitor {
+    fn new(context: &mut InitContext) -> Self {
+        Self {
+            was_compressed: !context.is_in_flight(),
+            sink_rate: Velocity::default(),
+        }
+    }
+
+    fn update(
+        &mut self,
+        context: &UpdateContext,
+        is_compressed: bool,
+        limits: &GearStructuralLimits,
+    ) -> GearDamage {
+        let damage = if is_compressed && !self.was_compressed {
+            limits.touchdown_damage(self.sink_rate, context.total_weight())
+        } else {
+            GearDamage::None
+        };
+
+        if !is_compressed {
+            self.sink_rate =
+                (-context.local_velocity().vert_velocity()).max(Velocity::new::<knot>(0.));
+        }
+        self.was_compressed = is_compressed;
+
+        damage
+    }
+}
+
 pub struct HydraulicGearSystem {
     door_center_position_id: VariableIdentifier,
     door_center_gear_slaved_position_id: VariableIdentifier,
@@ -37,8 +156,21 @@ pub struct HydraulicGearSystem {
     gear_left_position_id: VariableIdentifier,
     gear_right_position_id: VariableIdentifier,
 
+    door_center_damage_id: VariableIdentifier,
+    door_left_damage_id: VariableIdentifier,
+    door_right_damage_id: VariableIdentifier,
+
+    gear_center_damage_id: VariableIdentifier,
+    gear_left_damage_id: VariableIdentifier,
+    gear_right_damage_id: VariableIdentifier,
+
     hydraulic_supply: GearSystemHydraulicSupply,
 
+    structural_limits: GearStructuralLimits,
+    nose_touchdown_monitor: GearTouchdownMonitor,
+    left_touchdown_monitor: GearTouchdownMonitor,
+    right_touchdown_monitor: GearTouchdownMonitor,
+
     nose_door_assembly: GearSystemComponentAssembly,
     left_door_assembly: GearSystemComponentAssembly,
     right_door_assembly: GearSystemComponentAssembly,
@@ -62,6 +194,7 @@ impl HydraulicGearSystem {
         gear_left_aerodynamic: AerodynamicModel,
         gear_right_aerodynamic: AerodynamicModel,
         gear_nose_aerodynamic: AerodynamicModel,
+        structural_limits: GearStructuralLimits,
     ) -> Self {
         Self {
             door_center_position_id: context.get_identifier("GEAR_DOOR_CENTER_POSITION".to_owned()),
@@ -75,8 +208,21 @@ impl HydraulicGearSystem {
             gear_left_position_id: context.get_identifier("GEAR_LEFT_POSITION".to_owned()),
             gear_right_position_id: context.get_identifier("GEAR_RIGHT_POSITION".to_owned()),
 
+            door_center_damage_id: context.get_identifier("GEAR_DOOR_CENTER_DAMAGE".to_owned()),
+            door_left_damage_id: context.get_identifier("GEAR_DOOR_LEFT_DAMAGE".to_owned()),
+            door_right_damage_id: context.get_identifier("GEAR_DOOR_RIGHT_DAMAGE".to_owned()),
+
+            gear_center_damage_id: context.get_identifier("GEAR_CENTER_DAMAGE".to_owned()),
+            gear_left_damage_id: context.get_identifier("GEAR_LEFT_DAMAGE".to_owned()),
+            gear_right_damage_id: context.get_identifier("GEAR_RIGHT_DAMAGE".to_owned()),
+
             hydraulic_supply: GearSystemHydraulicSupply::new(),
 
+            structural_limits,
+            nose_touchdown_monitor: GearTouchdownMonitor::new(context),
+            left_touchdown_monitor: GearTouchdownMonitor::new(context),
+            right_touchdown_monitor: GearTouchdownMonitor::new(context),
+
             nose_door_assembly: GearSystemComponentAssembly::new(
                 GearActuatorId::GearDoorNose,
                 false,
@@ -178,7 +324,10 @@ impl HydraulicGearSystem {
         valves_controller: &impl GearSystemController,
         lgciu_controller: &impl LgciuGearControl,
         main_hydraulic_circuit: &impl SectionPressure,
+        landing_gear: &LandingGear,
     ) {
+        self.update_structural_damage(context, landing_gear);
+
         self.hydraulic_supply.update(
             context,
             valves_controller,
@@ -227,6 +376,67 @@ impl HydraulicGearSystem {
         );
     }
 
+    fn update_structural_damage(&mut self, context: &UpdateContext, landing_gear: &LandingGear) {
+        let nose_compressed = landing_gear.is_wheel_id_compressed(GearWheel::NOSE);
+        let left_compressed = landing_gear.is_wheel_id_compressed(GearWheel::LEFT)
+            || landing_gear.is_wheel_id_compressed(GearWheel::WINGLEFT);
+        let right_compressed = landing_gear.is_wheel_id_compressed(GearWheel::RIGHT)
+            || landing_gear.is_wheel_id_compressed(GearWheel::WINGRIGHT);
+
+        self.nose_gear_assembly
+            .apply_damage(self.nose_touchdown_monitor.update(
+                context,
+                nose_compressed,
+                &self.structural_limits,
+            ));
+        self.left_gear_assembly
+            .apply_damage(self.left_touchdown_monitor.update(
+                context,
+                left_compressed,
+                &self.structural_limits,
+            ));
+        self.right_gear_assembly
+            .apply_damage(self.right_touchdown_monitor.update(
+                context,
+                right_compressed,
+                &self.structural_limits,
+            ));
+
+        self.nose_gear_assembly.update_collapse(nose_compressed);
+        self.left_gear_assembly.update_collapse(left_compressed);
+        self.right_gear_assembly.update_collapse(right_compressed);
+
+        for (door, gear) in [
+            (&mut self.nose_door_assembly, &self.nose_gear_assembly),
+            (&mut self.left_door_assembly, &self.left_gear_assembly),
+            (&mut self.right_door_assembly, &self.right_gear_assembly),
+        ] {
+            if self.structural_limits.door_overspeed(
+                context,
+                door.is_out_of_uplock(),
+                gear.is_out_of_uplock(),
+            ) {
+                door.apply_damage(GearDamage::Damaged);
+            }
+        }
+    }
+
+    pub fn gear_damage(&self, wheel_id: GearWheel) -> GearDamage {
+        match wheel_id {
+            GearWheel::NOSE => self.nose_gear_assembly.structural_damage(),
+            GearWheel::LEFT | GearWheel::WINGLEFT => self.left_gear_assembly.structural_damage(),
+            GearWheel::RIGHT | GearWheel::WINGRIGHT => self.right_gear_assembly.structural_damage(),
+        }
+    }
+
+    pub fn door_damage(&self, wheel_id: GearWheel) -> GearDamage {
+        match wheel_id {
+            GearWheel::NOSE => self.nose_door_assembly.structural_damage(),
+            GearWheel::LEFT | GearWheel::WINGLEFT => self.left_door_assembly.structural_damage(),
+            GearWheel::RIGHT | GearWheel::WINGRIGHT => self.right_door_assembly.structural_damage(),
+        }
+    }
+
     pub fn gear_hydraulic_manifold_pressure(&self) -> Pressure {
         self.hydraulic_supply.gear_system_manifold_pressure()
     }
@@ -290,6 +500,14 @@ impl GearSystemSensors for HydraulicGearSystem {
             GearWheel::WINGRIGHT => self.right_door_assembly.is_sensor_fully_opened(lgciu_id),
         }
     }
+
+    fn is_wheel_id_damaged(&self, wheel_id: GearWheel) -> bool {
+        self.gear_damage(wheel_id) != GearDamage::None
+    }
+
+    fn is_door_id_damaged(&self, wheel_id: GearWheel) -> bool {
+        self.door_damage(wheel_id) != GearDamage::None
+    }
 }
 impl SimulationElement for HydraulicGearSystem {
     fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
@@ -336,6 +554,32 @@ impl SimulationElement for HydraulicGearSystem {
             &self.gear_right_position_id,
             self.right_gear_assembly.position_normalized(),
         );
+
+        writer.write(
+            &self.door_center_damage_id,
+            self.door_damage(GearWheel::NOSE) as u8 as f64,
+        );
+        writer.write(
+            &self.door_left_damage_id,
+            self.door_damage(GearWheel::LEFT) as u8 as f64,
+        );
+        writer.write(
+            &self.door_right_damage_id,
+            self.door_damage(GearWheel::RIGHT) as u8 as f64,
+        );
+
+        writer.write(
+            &self.gear_center_damage_id,
+            self.gear_damage(GearWheel::NOSE) as u8 as f64,
+        );
+        writer.write(
+            &self.gear_left_damage_id,
+            self.gear_damage(GearWheel::LEFT) as u8 as f64,
+        );
+        writer.write(
+            &self.gear_right_damage_id,
+            self.gear_damage(GearWheel::RIGHT) as u8 as f64,
+        );
     }
 }
 
@@ -443,6 +687,8 @@ struct GearSystemComponentAssembly {
     hydraulic_downlock: Option<HydraulicLock>,
 
     aerodynamic_model: AerodynamicModel,
+
+    structural_damage: GearDamage,
 }
 impl GearSystemComponentAssembly {
     const OPENED_PROXIMITY_DETECTOR_MOUNTING_POSITION_RATIO: f64 = 1.;
@@ -500,6 +746,7 @@ impl GearSystemComponentAssembly {
                 None
             },
             aerodynamic_model,
+            structural_damage: GearDamage::None,
         };
 
         obj.update_proximity_detectors();
@@ -571,14 +818,53 @@ impl GearSystemComponentAssembly {
             should_lock_down = hyd_lock.is_locked_or_ready_to_latch();
         }
 
+        // A damaged component keeps a lock already engaged but can't latch it again once released
+        let lock_can_engage =
+            self.structural_damage == GearDamage::None || self.hydraulic_assembly.is_locked();
+
+        let (should_uplock, should_lock_down) = match self.component_id {
+            GearSysComponentId::Door => (
+                self.hydraulic_uplock.is_locked_or_ready_to_latch() && lock_can_engage,
+                should_lock_down,
+            ),
+            GearSysComponentId::Gear => (
+                self.hydraulic_uplock.is_locked_or_ready_to_latch(),
+                should_lock_down && lock_can_engage,
+            ),
+        };
+
         self.hydraulic_controller.update(
             should_mechanically_open || should_hydraulically_open,
-            self.hydraulic_uplock.is_locked_or_ready_to_latch(),
+            should_uplock,
             should_lock_down,
             self.position_normalized(),
+            self.structural_damage == GearDamage::Collapsed,
         );
     }
 
+    fn apply_damage(&mut self, damage: GearDamage) {
+        self.structural_damage = self.structural_damage.max(damage);
+    }
+
+    // A damaged gear that is not locked down can't hold the aircraft weight
+    fn update_collapse(&mut self, is_compressed: bool) {
+        if is_compressed
+            && self.structural_damage == GearDamage::Damaged
+            && !self.hydraulic_assembly.is_locked()
+        {
+            self.structural_damage = GearDamage::Collapsed;
+        }
+    }
+
+    fn structural_damage(&self) -> GearDamage {
+        self.structural_damage
+    }
+
+    fn is_out_of_uplock(&self) -> bool {
+        self.position_normalized().get::<ratio>()
+            > Self::UPLOCKED_PROXIMITY_DETECTOR_TRIG_DISTANCE_RATIO
+    }
+
     fn position_normalized(&self) -> Ratio {
         if !self.is_inverted_control {
             self.hydraulic_assembly.position_normalized()
@@ -591,12 +877,17 @@ impl GearSystemComponentAssembly {
         self.hydraulic_assembly.actuator(0)
     }
 
+    // Damaged door lock targets are bent and no longer detected by uplock sensors
     fn is_sensor_uplock(&self, lgciu_id: LgciuId) -> bool {
-        self.uplock_proximity_detectors[lgciu_id as usize].proximity_detected()
+        let is_bent_door = matches!(self.component_id, GearSysComponentId::Door)
+            && self.structural_damage != GearDamage::None;
+
+        !is_bent_door && self.uplock_proximity_detectors[lgciu_id as usize].proximity_detected()
     }
 
     fn is_sensor_fully_opened(&self, lgciu_id: LgciuId) -> bool {
-        self.fully_opened_proximity_detectors[lgciu_id as usize].proximity_detected()
+        self.structural_damage != GearDamage::Collapsed
+            && self.fully_opened_proximity_detectors[lgciu_id as usize].proximity_detected()
     }
 
     #[cfg(test)]
@@ -633,6 +924,8 @@ struct GearSystemComponentHydraulicController {
     jamming_is_effective: bool,
 
     soft_downlock_is_active: bool,
+
+    is_collapsed: bool,
 }
 impl GearSystemComponentHydraulicController {
     fn new(id: GearActuatorId, is_inverted_control: bool, is_soft_downlock: bool) -> Self {
@@ -647,6 +940,7 @@ impl GearSystemComponentHydraulicController {
             jamming_position: Ratio::new::<ratio>(random_from_range(0., 1.)),
             jamming_is_effective: false,
             soft_downlock_is_active: false,
+            is_collapsed: false,
         }
     }
 
@@ -657,8 +951,10 @@ impl GearSystemComponentHydraulicController {
         should_uplock: bool,
         should_downlock: bool,
         actual_position: Ratio,
+        is_collapsed: bool,
     ) {
         self.actual_position = actual_position;
+        self.is_collapsed = is_collapsed;
 
         self.requested_position = if should_open {
             Ratio::new::<ratio>(1.1)
@@ -666,8 +962,9 @@ impl GearSystemComponentHydraulicController {
             Ratio::new::<ratio>(-0.1)
         };
 
-        self.should_lock = actual_position.get::<ratio>() > 0.5 && should_downlock
-            || actual_position.get::<ratio>() < 0.5 && should_uplock;
+        self.should_lock = !is_collapsed
+            && (actual_position.get::<ratio>() > 0.5 && should_downlock
+                || actual_position.get::<ratio>() < 0.5 && should_uplock);
 
         self.lock_position = if should_downlock {
             Ratio::new::<ratio>(1.)
@@ -724,6 +1021,10 @@ impl GearSystemComponentHydraulicController {
 }
 impl HydraulicAssemblyController for GearSystemComponentHydraulicController {
     fn requested_mode(&self) -> LinearActuatorMode {
+        if self.is_collapsed {
+            return LinearActuatorMode::ClosedCircuitDamping;
+        }
+
         if self.jamming_is_effective {
             return LinearActuatorMode::ClosedValves;
         }
@@ -1160,6 +1461,14 @@ mod tests {
         fn is_door_physically_locked(&self) -> bool {
             self.door_assembly.is_locked()
         }
+
+        fn damage_door(&mut self) {
+            self.door_assembly.apply_damage(GearDamage::Damaged);
+        }
+
+        fn damage_gear(&mut self) {
+            self.gear_assembly.apply_damage(GearDamage::Damaged);
+        }
     }
     impl Aircraft for TestSingleGearAircraft {
         fn update_after_power_distribution(&mut self, context: &UpdateContext) {
@@ -1536,6 +1845,98 @@ mod tests {
         assert!(test_bed.query(|a| a.is_door_sensor_uplock(LgciuId::Lgciu2)));
     }
 
+    #[test]
+    fn touchdown_damage_depends_on_sink_rate_and_weight() {
+        let limits = test_structural_limits();
+
+        assert_eq!(
+            limits.touchdown_damage(
+                Velocity::new::<foot_per_second>(8.),
+                Mass::new::<kilogram>(60000.)
+            ),
+            GearDamage::None
+        );
+        assert_eq!(
+            limits.touchdown_damage(
+                Velocity::new::<foot_per_second>(12.),
+                Mass::new::<kilogram>(60000.)
+            ),
+            GearDamage::Damaged
+        );
+        assert_eq!(
+            limits.touchdown_damage(
+                Velocity::new::<foot_per_second>(8.),
+                Mass::new::<kilogram>(79000.)
+            ),
+            GearDamage::Damaged
+        );
+        assert_eq!(
+            limits.touchdown_damage(
+                Velocity::new::<foot_per_second>(16.),
+                Mass::new::<kilogram>(60000.)
+            ),
+            GearDamage::Collapsed
+        );
+    }
+
+    #[test]
+    fn damaged_door_stays_locked_but_uplock_sensor_lost() {
+        let mut test_bed = SimulationTestBed::new(|context| {
+            let gear_door = main_gear_door_right_assembly(context);
+
+            TestSingleGearAircraft::new(
+                Duration::from_millis(10),
+                gear_door,
+                main_gear_right_assembly(context, true),
+            )
+        });
+        test_bed.run_with_delta(Duration::from_millis(10));
+
+        test_bed.command(|a| a.damage_door());
+        test_bed.run_with_delta(Duration::from_millis(100));
+
+        assert!(test_bed.query(|a| a.is_door_physically_locked()));
+        assert!(!test_bed.query(|a| a.is_door_sensor_uplock(LgciuId::Lgciu1)));
+        assert!(!test_bed.query(|a| a.is_door_sensor_uplock(LgciuId::Lgciu2)));
+    }
+
+    #[test]
+    fn damaged_gear_does_not_relock_down_after_retraction() {
+        let mut test_bed = SimulationTestBed::new(|context| {
+            let gear_door = main_gear_door_right_assembly(context);
+
+            TestSingleGearAircraft::new(
+                Duration::from_millis(10),
+                gear_door,
+                main_gear_right_assembly(context, true),
+            )
+        });
+        test_bed.run_with_delta(Duration::from_millis(10));
+
+        test_bed.command(|a| a.damage_gear());
+
+        test_bed.command(|a| a.command_doors_opening());
+        test_bed.run_with_delta(Duration::from_millis(4000));
+        test_bed.command(|a| a.command_gears_retracting());
+        test_bed.run_with_delta(Duration::from_millis(10000));
+
+        assert!(!test_bed.query(|a| a.is_gear_sensor_fully_opened(LgciuId::Lgciu1)));
+
+        test_bed.command(|a| a.command_gears_extending());
+        test_bed.run_with_delta(Duration::from_millis(12000));
+
+        assert!(!test_bed.query(|a| a.is_gear_physically_locked()));
+    }
+
+    fn test_structural_limits() -> GearStructuralLimits {
+        GearStructuralLimits::new(
+            Velocity::new::<knot>(250.),
+            Velocity::new::<knot>(280.),
+            Mass::new::<kilogram>(67400.),
+            Mass::new::<kilogram>(79000.),
+        )
+    }
+
     fn main_gear_door_right_assembly(
         context: &mut InitContext,
     ) -> HydraulicLinearActuatorAssembly<1> {
@@ -5,13 +5,16 @@ use uom::si::{
     angular_acceleration::radian_per_second_squared,
     angular_velocity::{radian_per_second, revolution_per_minute},
     area::square_meter,
+    energy::joule,
     f64::*,
     force::newton,
+    heat_capacity::joule_per_kelvin,
     length::meter,
     mass::kilogram,
     power::watt,
     pressure::{pascal, psi},
     ratio::ratio,
+    thermodynamic_temperature::degree_celsius,
     torque::newton_meter,
     velocity::meter_per_second,
     volume::{cubic_meter, gallon},
@@ -19,6 +22,7 @@ use uom::si::{
 };
 
 use crate::{
+    failures::{Failure, FailureType},
     shared::{
         interpolation, low_pass_filter::LowPassFilter, pid::PidController,
         random_from_normal_distribution, random_from_range, ConsumePower, ElectricalBusType,
@@ -59,6 +63,75 @@ pub enum LinearActuatorMode {
     ClosedCircuitDamping,
 }
 
+/// Thermal model of an electro hydrostatic motor.
+///
+/// Motor losses heat the unit while it exchanges heat with surrounding air. When the motor gets too hot
+/// its duty cycle protection cuts it off until it has cooled down under a reset temperature.
+#[derive(PartialEq, Copy, Clone)]
+struct MotorThermalProtection {
+    temperature: ThermodynamicTemperature,
+    is_protection_active: bool,
+}
+impl MotorThermalProtection {
+    // Ratio of the electrical power drawn by the motor that is dissipated as heat
+    const MOTOR_LOSSES_RATIO: f64 = 0.35;
+    const HEAT_CAPACITY_JOULE_PER_KELVIN: f64 = 4000.;
+    const HEAT_TRANSFER_TO_AMBIENT_WATT_PER_KELVIN: f64 = 10.;
+
+    const INIT_TEMPERATURE_DEGREE_CELSIUS: f64 = 15.;
+    const PROTECTION_TRIGGER_TEMPERATURE_DEGREE_CELSIUS: f64 = 150.;
+    const PROTECTION_RESET_TEMPERATURE_DEGREE_CELSIUS: f64 = 110.;
+
+    fn new() -> Self {
+        Self {
+            temperature: ThermodynamicTemperature::new::<degree_celsius>(
+                Self::INIT_TEMPERATURE_DEGREE_CELSIUS,
+            ),
+            is_protection_active: false,
+        }
+    }
+
+    fn update(&mut self, context: &UpdateContext, consumed_power: Power) {
+        let heating_energy = Energy::new::<joule>(
+            consumed_power.get::<watt>() * Self::MOTOR_LOSSES_RATIO * context.delta_as_secs_f64(),
+        );
+
+        let delta_ambient = self.temperature.get::<degree_celsius>()
+            - context.ambient_temperature().get::<degree_celsius>();
+        let cooling_energy = Energy::new::<joule>(
+            Self::HEAT_TRANSFER_TO_AMBIENT_WATT_PER_KELVIN
+                * delta_ambient
+                * context.delta_as_secs_f64(),
+        );
+
+        self.temperature += (heating_energy - cooling_energy)
+            / HeatCapacity::new::<joule_per_kelvin>(Self::HEAT_CAPACITY_JOULE_PER_KELVIN);
+
+        if self.temperature.get::<degree_celsius>()
+            > Self::PROTECTION_TRIGGER_TEMPERATURE_DEGREE_CELSIUS
+        {
+            self.is_protection_active = true;
+        } else if self.temperature.get::<degree_celsius>()
+            < Self::PROTECTION_RESET_TEMPERATURE_DEGREE_CELSIUS
+        {
+            self.is_protection_active = false;
+        }
+    }
+
+    fn temperature(&self) -> ThermodynamicTemperature {
+        self.temperature
+    }
+
+    fn is_protection_active(&self) -> bool {
+        self.is_protection_active
+    }
+
+    #[cfg(test)]
+    fn set_temperature(&mut self, temperature: ThermodynamicTemperature) {
+        self.temperature = temperature;
+    }
+}
+
 #[derive(PartialEq, Copy, Clone)]
 struct VariableSpeedPump {
     speed: LowPassFilter<AngularVelocity>,
@@ -67,6 +140,9 @@ struct VariableSpeedPump {
     should_activate_electrical_mode: bool,
     powered_by: ElectricalBusType,
     consumed_power: Power,
+
+    thermal_protection: MotorThermalProtection,
+    failure: Failure,
 }
 impl VariableSpeedPump {
     // Coefficient to convert hyd power in elec power 1.1 means we lose 10% efficiency from the pump
@@ -79,7 +155,7 @@ impl VariableSpeedPump {
 
     const LOW_PASS_RPM_TRANSIENT_TIME_CONSTANT: Duration = Duration::from_millis(100);
 
-    pub fn new(powered_by: ElectricalBusType) -> Self {
+    pub fn new(powered_by: ElectricalBusType, failure_type: FailureType) -> Self {
         Self {
             speed: LowPassFilter::<AngularVelocity>::new(
                 Self::LOW_PASS_RPM_TRANSIENT_TIME_CONSTANT,
@@ -88,6 +164,8 @@ impl VariableSpeedPump {
             should_activate_electrical_mode: false,
             powered_by,
             consumed_power: Power::default(),
+            thermal_protection: MotorThermalProtection::new(),
+            failure: Failure::new(failure_type),
         }
     }
 
@@ -111,6 +189,7 @@ impl VariableSpeedPump {
         };
 
         self.update_power_consumed(actuator_flow, actuator_pressure);
+        self.thermal_protection.update(context, self.consumed_power);
         self.speed.update(context.delta(), new_speed);
     }
 
@@ -128,7 +207,15 @@ impl VariableSpeedPump {
     }
 
     fn is_active(&self) -> bool {
-        self.should_activate_electrical_mode && self.is_powered
+        self.should_activate_electrical_mode && self.is_available()
+    }
+
+    fn is_available(&self) -> bool {
+        self.is_powered && !self.failure.is_active() && !self.is_overheat_protected()
+    }
+
+    fn is_overheat_protected(&self) -> bool {
+        self.thermal_protection.is_protection_active()
     }
 
     fn max_available_pressure(&self, accumulator_pressure: Pressure) -> Pressure {
@@ -140,6 +227,12 @@ impl VariableSpeedPump {
     }
 }
 impl SimulationElement for VariableSpeedPump {
+    fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
+        self.failure.accept(visitor);
+
+        visitor.visit(self);
+    }
+
     fn receive_power(&mut self, buses: &impl ElectricalBuses) {
         self.is_powered = buses.is_powered(self.powered_by);
     }
@@ -152,14 +245,19 @@ impl Debug for VariableSpeedPump {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(
             f,
-            "\nEHA pump => is powered {:?} / Is active? {:?} / Rpm {:0}",
+            "\nEHA pump => is powered {:?} / Is active? {:?} / Rpm {:0} / Motor temp {:.0}",
             self.is_powered,
             self.is_active(),
-            self.speed.output().get::<revolution_per_minute>()
+            self.speed.output().get::<revolution_per_minute>(),
+            self.thermal_protection
+                .temperature()
+                .get::<degree_celsius>()
         )
     }
 }
 
+/// Local reservoir of the electro hydrostatic unit, pressurised so the motor driven pump is never starved.
+/// It is refilled from aircraft hydraulic circuit through its refill valve.
 #[derive(PartialEq, Copy, Clone)]
 struct LowPressureAccumulator {
     pressure: LowPassFilter<Pressure>,
@@ -271,6 +369,10 @@ pub enum ElectroHydrostaticActuatorType {
     ElectricalBackupHydraulicActuator, // Can run either in electric backup mode or from aircraft hydraulic pressure
 }
 
+/// Electro hydrostatic unit of an actuator: an electric motor driving a local pump fed from a local reservoir.
+///
+/// Depending on its type it is the only power source of the actuator (EHA), or a backup of the
+/// aircraft hydraulic circuit (EBHA). Switching to electrical mode is commanded through the [ElectroHydrostaticPowered] controller.
 #[derive(PartialEq, Copy, Clone)]
 pub struct ElectroHydrostaticBackup {
     accumulator: LowPressureAccumulator,
@@ -279,10 +381,14 @@ pub struct ElectroHydrostaticBackup {
     backup_type: ElectroHydrostaticActuatorType,
 }
 impl ElectroHydrostaticBackup {
-    pub fn new(powered_by: ElectricalBusType, backup_type: ElectroHydrostaticActuatorType) -> Self {
+    pub fn new(
+        powered_by: ElectricalBusType,
+        backup_type: ElectroHydrostaticActuatorType,
+        failure_type: FailureType,
+    ) -> Self {
         Self {
             accumulator: LowPressureAccumulator::new(),
-            pump: VariableSpeedPump::new(powered_by),
+            pump: VariableSpeedPump::new(powered_by, failure_type),
             backup_type,
         }
     }
@@ -321,6 +427,19 @@ impl ElectroHydrostaticBackup {
     fn is_electrical_mode_active(&self) -> bool {
         self.pump.is_active()
     }
+
+    /// Unit is powered, not failed and not cut by its thermal protection
+    pub fn is_available(&self) -> bool {
+        self.pump.is_available()
+    }
+
+    pub fn is_overheat_protected(&self) -> bool {
+        self.pump.is_overheat_protected()
+    }
+
+    pub fn motor_temperature(&self) -> ThermodynamicTemperature {
+        self.pump.thermal_protection.temperature()
+    }
 }
 impl SimulationElement for ElectroHydrostaticBackup {
     fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
@@ -1113,9 +1232,12 @@ impl LinearActuator {
                 eha.can_move_using_aircraft_hydraulic_pressure();
         }
 
+        // An EBHA commanded in electrical mode reverts to aircraft hydraulic supply if its electrical unit
+        // is not available, while an EHA has no other source of power
         let internal_actuator_pressure = if controller.should_activate_electrical_mode()
-            && self.electro_hydrostatic_backup.is_some()
-        {
+            && self.electro_hydrostatic_backup.is_some_and(|eha| {
+                eha.is_available() || !eha.can_move_using_aircraft_hydraulic_pressure()
+            }) {
             match self.electro_hydrostatic_backup {
                 Some(ehba) => ehba.max_available_pressure(),
                 None => unreachable!(),
@@ -1198,6 +1320,10 @@ impl LinearActuator {
         }
     }
 
+    pub fn electro_hydrostatic_backup(&self) -> Option<&ElectroHydrostaticBackup> {
+        self.electro_hydrostatic_backup.as_ref()
+    }
+
     fn eha_backup_is_active(&self) -> bool {
         self.electro_hydrostatic_backup
             .is_some_and(|eha| eha.is_electrical_mode_active())
@@ -2218,6 +2344,40 @@ mod tests {
             }
         }
 
+        fn command_eha_motor_temperature(
+            &mut self,
+            temperature: ThermodynamicTemperature,
+            actuator_id: usize,
+        ) {
+            assert_lt!(actuator_id, N);
+            if let Some(eha) = self.hydraulic_assembly.linear_actuators[actuator_id]
+                .electro_hydrostatic_backup
+                .as_mut()
+            {
+                eha.pump.thermal_protection.set_temperature(temperature);
+            }
+        }
+
+        fn eha_is_available(&self, actuator_id: usize) -> bool {
+            self.hydraulic_assembly.linear_actuators[actuator_id]
+                .electro_hydrostatic_backup()
+                .is_some_and(|eha| eha.is_available())
+        }
+
+        fn eha_motor_temperature(&self, actuator_id: usize) -> ThermodynamicTemperature {
+            self.hydraulic_assembly.linear_actuators[actuator_id]
+                .electro_hydrostatic_backup()
+                .map_or(ThermodynamicTemperature::default(), |eha| {
+                    eha.motor_temperature()
+                })
+        }
+
+        fn eha_is_overheat_protected(&self, actuator_id: usize) -> bool {
+            self.hydraulic_assembly.linear_actuators[actuator_id]
+                .electro_hydrostatic_backup()
+                .is_some_and(|eha| eha.is_overheat_protected())
+        }
+
         fn command_lock(&mut self, lock_position: Ratio) {
             for controller in &mut self.controllers {
                 controller.set_lock(lock_position);
@@ -4199,6 +4359,127 @@ mod tests {
         );
     }
 
+    #[test]
+    fn spoiler_electro_hydrostatic_cannot_move_without_pressure_when_failed() {
+        let mut test_bed = SimulationTestBed::new(|context| {
+            let tested_object = spoiler_assembly(context, true);
+            TestAircraft::new(context, tested_object)
+        });
+
+        test_bed.command(|a| a.command_unlock());
+        test_bed.command(|a| a.set_pressures([Pressure::new::<psi>(0.)]));
+        test_bed.fail(FailureType::ElectroHydrostaticActuator(1));
+
+        test_bed.command(|a| a.set_ac_1_power(true));
+        test_bed.command(|a| a.command_electro_backup(true, 0));
+        test_bed.command(|a| a.command_position_control(Ratio::new::<ratio>(1.), 0));
+        test_bed.run_with_delta(Duration::from_secs_f64(0.8));
+
+        assert!(!test_bed.query(|a| a.eha_is_available(0)));
+        assert_lt!(
+            test_bed.query(|a| a.body_position()),
+            Ratio::new::<ratio>(0.1)
+        );
+    }
+
+    #[test]
+    fn electro_hydrostatic_actuator_motor_heats_up_when_moving() {
+        let mut test_bed = SimulationTestBed::new(|context| {
+            let tested_object = spoiler_assembly(context, true);
+            TestAircraft::new(context, tested_object)
+        });
+
+        test_bed.command(|a| a.command_unlock());
+        test_bed.command(|a| a.set_pressures([Pressure::new::<psi>(0.)]));
+        test_bed.set_ambient_temperature(ThermodynamicTemperature::new::<degree_celsius>(15.));
+        test_bed.command(|a| {
+            a.command_eha_motor_temperature(ThermodynamicTemperature::new::<degree_celsius>(15.), 0)
+        });
+
+        test_bed.command(|a| a.set_ac_1_power(true));
+        test_bed.command(|a| a.command_electro_backup(true, 0));
+        test_bed.command(|a| a.command_position_control(Ratio::new::<ratio>(1.), 0));
+        test_bed.run_with_delta(Duration::from_secs_f64(5.));
+
+        assert_gt!(
+            test_bed.query(|a| a.eha_motor_temperature(0)),
+            ThermodynamicTemperature::new::<degree_celsius>(15.)
+        );
+    }
+
+    #[test]
+    fn spoiler_electro_hydrostatic_cannot_move_without_pressure_when_overheat_protected() {
+        let mut test_bed = SimulationTestBed::new(|context| {
+            let tested_object = spoiler_assembly(context, true);
+            TestAircraft::new(context, tested_object)
+        });
+
+        test_bed.command(|a| a.command_unlock());
+        test_bed.command(|a| a.set_pressures([Pressure::new::<psi>(0.)]));
+        test_bed.command(|a| {
+            a.command_eha_motor_temperature(
+                ThermodynamicTemperature::new::<degree_celsius>(170.),
+                0,
+            )
+        });
+
+        test_bed.command(|a| a.set_ac_1_power(true));
+        test_bed.command(|a| a.command_electro_backup(true, 0));
+        test_bed.command(|a| a.command_position_control(Ratio::new::<ratio>(1.), 0));
+        test_bed.run_with_delta(Duration::from_secs_f64(0.8));
+
+        assert!(test_bed.query(|a| a.eha_is_overheat_protected(0)));
+        assert_lt!(
+            test_bed.query(|a| a.body_position()),
+            Ratio::new::<ratio>(0.1)
+        );
+    }
+
+    #[test]
+    fn electro_hydrostatic_overheat_protection_resets_once_motor_cooled_down() {
+        let mut test_bed = SimulationTestBed::new(|context| {
+            let tested_object = spoiler_assembly(context, true);
+            TestAircraft::new(context, tested_object)
+        });
+
+        test_bed.command(|a| a.command_unlock());
+        test_bed.command(|a| a.set_pressures([Pressure::new::<psi>(0.)]));
+        test_bed.command(|a| a.set_ac_1_power(true));
+        test_bed.command(|a| {
+            a.command_eha_motor_temperature(
+                ThermodynamicTemperature::new::<degree_celsius>(170.),
+                0,
+            )
+        });
+        test_bed.run_with_delta(Duration::from_secs_f64(0.1));
+
+        assert!(test_bed.query(|a| a.eha_is_overheat_protected(0)));
+
+        // Still above reset temperature, protection stays active
+        test_bed.command(|a| {
+            a.command_eha_motor_temperature(
+                ThermodynamicTemperature::new::<degree_celsius>(130.),
+                0,
+            )
+        });
+        test_bed.run_with_delta(Duration::from_secs_f64(0.1));
+
+        assert!(test_bed.query(|a| a.eha_is_overheat_protected(0)));
+
+        test_bed.command(|a| {
+            a.command_eha_motor_temperature(ThermodynamicTemperature::new::<degree_celsius>(90.), 0)
+        });
+        test_bed.command(|a| a.command_electro_backup(true, 0));
+        test_bed.command(|a| a.command_position_control(Ratio::new::<ratio>(1.), 0));
+        test_bed.run_with_delta(Duration::from_secs_f64(0.8));
+
+        assert!(!test_bed.query(|a| a.eha_is_overheat_protected(0)));
+        assert_gt!(
+            test_bed.query(|a| a.body_position()),
+            Ratio::new::<ratio>(0.8)
+        );
+    }
+
     #[test]
     fn spoiler_electro_hydrostatic_cannot_move_once_accumulator_empty() {
         let mut test_bed = SimulationTestBed::new(|context| {
@@ -4930,6 +5211,7 @@ mod tests {
                 Some(ElectroHydrostaticBackup::new(
                     ElectricalBusType::AlternatingCurrent(1),
                     ElectroHydrostaticActuatorType::ElectroHydrostaticActuator,
+                    FailureType::ElectroHydrostaticActuator(1),
                 ))
             } else {
                 None
@@ -5009,6 +5291,7 @@ mod tests {
                 Some(ElectroHydrostaticBackup::new(
                     ElectricalBusType::AlternatingCurrent(1),
                     ElectroHydrostaticActuatorType::ElectricalBackupHydraulicActuator,
+                    FailureType::ElectroHydrostaticActuator(1),
                 ))
             } else {
                 None
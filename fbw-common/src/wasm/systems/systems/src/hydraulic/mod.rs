@@ -33,6 +33,7 @@ use uom::si::{
 };
 
 pub mod aerodynamic_model;
+pub mod anti_skid;
 pub mod brake;
 pub mod brake_circuit;
 pub mod bypass_pin;
@@ -1137,6 +1138,22 @@ impl HydraulicCircuit {
         }
     }
 
+    /// Updates the volumes of an actuator fed through a shuttle valve by either the system or the
+    /// auxiliary section, see [HydraulicCircuit::shuttle_valve_section].
+    pub fn update_shuttle_valve_actuator_volumes(&mut self, actuator: &mut impl Actuator) {
+        if self.is_shuttle_valve_fed_by_auxiliary_section() {
+            self.update_auxiliary_actuator_volumes(actuator);
+        } else {
+            self.update_system_actuator_volumes(actuator);
+        }
+    }
+
+    fn is_shuttle_valve_fed_by_auxiliary_section(&self) -> bool {
+        self.auxiliary_section
+            .as_ref()
+            .is_some_and(|section| section.pressure() > self.system_section.pressure())
+    }
+
     pub fn update(
         &mut self,
         context: &UpdateContext,
@@ -1463,6 +1480,17 @@ impl HydraulicCircuit {
         }
     }
 
+    /// Section seen downstream of a shuttle valve connected to both the system and the auxiliary
+    /// section, which selects whichever has the highest pressure.
+    pub fn shuttle_valve_section(&self) -> &impl SectionPressure {
+        match &self.auxiliary_section {
+            Some(auxiliary_section) if self.is_shuttle_valve_fed_by_auxiliary_section() => {
+                auxiliary_section
+            }
+            _ => &self.system_section,
+        }
+    }
+
     pub fn pump_section(&self, pump_index: usize) -> &impl SectionPressure {
         &self.pump_sections[pump_index]
     }
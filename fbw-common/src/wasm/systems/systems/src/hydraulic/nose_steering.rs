@@ -1,8 +1,13 @@
+use crate::failures::{Failure, FailureType};
 use crate::hydraulic::linear_actuator::Actuator;
 use crate::shared::Clamp;
-use crate::shared::{interpolation, low_pass_filter::LowPassFilter, SectionPressure};
+use crate::shared::{
+    interpolation, low_pass_filter::LowPassFilter, ElectricalBusType, ElectricalBuses,
+    LgciuWeightOnWheels, SectionPressure, SteeringActuatorId,
+};
 use crate::simulation::{
-    InitContext, SimulationElement, SimulatorWriter, UpdateContext, VariableIdentifier, Write,
+    InitContext, Read, SimulationElement, SimulationElementVisitor, SimulatorReader,
+    SimulatorWriter, UpdateContext, VariableIdentifier, Write,
 };
 
 use std::time::Duration;
@@ -26,6 +31,12 @@ pub trait Pushback {
 
 pub trait SteeringController {
     fn requested_position(&self) -> Angle;
+
+    /// False when the steering control has lost all its channels and no longer drives the
+    /// steering servo valve
+    fn is_steering_available(&self) -> bool {
+        true
+    }
 }
 
 /// Computes steering angle based on angle demand and input speed
@@ -94,6 +105,299 @@ impl<const N: usize> SteeringRatioToAngle<N> {
     }
 }
 
+/// Pedal steering authority from speed, with a takeoff and a landing law.
+/// Landing law engages as soon as main gears are in the air, and takeoff law is restored once
+/// back on ground below a switching speed.
+pub struct PedalSteeringDynamicLimiter {
+    pedal_steering_limiter_takeoff: SteeringAngleLimiter<5>,
+    pedal_steering_limiter_landing: SteeringAngleLimiter<5>,
+    speed_to_switch_takeoff_mode: Velocity,
+    is_landing_mode: bool,
+}
+impl PedalSteeringDynamicLimiter {
+    pub fn new(
+        pedal_steering_limiter_takeoff: SteeringAngleLimiter<5>,
+        pedal_steering_limiter_landing: SteeringAngleLimiter<5>,
+        speed_to_switch_takeoff_mode: Velocity,
+    ) -> Self {
+        Self {
+            pedal_steering_limiter_takeoff,
+            pedal_steering_limiter_landing,
+            speed_to_switch_takeoff_mode,
+            is_landing_mode: false,
+        }
+    }
+
+    fn update(&mut self, ground_speed: Velocity, lgciu: &impl LgciuWeightOnWheels) {
+        if !lgciu.left_and_right_gear_compressed(false) {
+            self.is_landing_mode = true;
+        }
+        if ground_speed < self.speed_to_switch_takeoff_mode
+            && lgciu.left_and_right_gear_compressed(false)
+        {
+            self.is_landing_mode = false;
+        }
+    }
+
+    fn angle_from_speed(&self, speed: Velocity, angle_demand: Angle) -> Angle {
+        if self.is_landing_mode {
+            self.pedal_steering_limiter_landing
+                .angle_from_speed(speed, angle_demand)
+        } else {
+            self.pedal_steering_limiter_takeoff
+                .angle_from_speed(speed, angle_demand)
+        }
+    }
+}
+
+pub struct SteeringAngleLimits {
+    max_pedal_and_autopilot_angle: Angle,
+    max_steering_angle: Angle,
+    oversteer_angle: Angle,
+}
+impl SteeringAngleLimits {
+    /// ## Parameters
+    /// `max_pedal_and_autopilot_angle` - the authority of rudder pedals and autopilot together
+    /// `max_steering_angle` - the maximum angle the steering control can request
+    /// `oversteer_angle` - the wheel angle beyond which the steering is damaged, mostly by towing
+    pub fn new(
+        max_pedal_and_autopilot_angle: Angle,
+        max_steering_angle: Angle,
+        oversteer_angle: Angle,
+    ) -> Self {
+        Self {
+            max_pedal_and_autopilot_angle,
+            max_steering_angle,
+            oversteer_angle,
+        }
+    }
+}
+
+/// Nose wheel steering part of a brake and steering control unit.
+///
+/// Tiller, rudder pedals and autopilot demands are mapped to angles, limited by speed and
+/// summed. Pedal authority is blended out as the tiller demand grows so the tiller has full
+/// authority on tight turns.
+/// The unit runs a self-test at power-up during which no steering is commanded, and latches an
+/// oversteer warning whenever the wheel angle exceeds its structural limit.
+pub struct NoseWheelSteeringControl {
+    rudder_pedal_input_id: VariableIdentifier,
+    tiller_handle_input_id: VariableIdentifier,
+    tiller_pedal_disconnect_id: VariableIdentifier,
+    autopilot_nosewheel_demand_id: VariableIdentifier,
+    oversteer_id: VariableIdentifier,
+
+    tiller_pedal_disconnect: bool,
+    tiller_handle_position: Ratio,
+    rudder_pedal_position: Ratio,
+    autopilot_nosewheel_demand: Ratio,
+
+    pedal_input_map: SteeringRatioToAngle<6>,
+    pedal_steering_limiter: PedalSteeringDynamicLimiter,
+    tiller_input_map: SteeringRatioToAngle<6>,
+    tiller_steering_limiter: SteeringAngleLimiter<5>,
+    limits: SteeringAngleLimits,
+
+    powered_by: ElectricalBusType,
+    is_powered: bool,
+    is_powered_previous_state: bool,
+    self_test_remaining: Duration,
+
+    requested_position: Angle,
+    is_oversteered: bool,
+}
+impl NoseWheelSteeringControl {
+    const AUTOPILOT_STEERING_INPUT_GAIN: f64 = 6.;
+
+    const TILLER_DEMAND_FOR_PEDAL_BLENDING_DEGREE: [f64; 3] = [0., 10., 30.];
+    const PEDAL_AUTHORITY_RATIO: [f64; 3] = [1., 1., 0.];
+
+    const PILOT_DEMAND_DETECTION_THRESHOLD_DEGREE: f64 = 0.1;
+
+    const SELF_TEST_DURATION: Duration = Duration::from_secs(1);
+
+    pub fn new(
+        context: &mut InitContext,
+        powered_by: ElectricalBusType,
+        pedal_input_map: SteeringRatioToAngle<6>,
+        pedal_steering_limiter: PedalSteeringDynamicLimiter,
+        tiller_input_map: SteeringRatioToAngle<6>,
+        tiller_steering_limiter: SteeringAngleLimiter<5>,
+        limits: SteeringAngleLimits,
+    ) -> Self {
+        Self {
+            rudder_pedal_input_id: context.get_identifier("RUDDER_PEDAL_POSITION_RATIO".to_owned()),
+            tiller_handle_input_id: context.get_identifier("TILLER_HANDLE_POSITION".to_owned()),
+            tiller_pedal_disconnect_id: context
+                .get_identifier("TILLER_PEDAL_DISCONNECT".to_owned()),
+            autopilot_nosewheel_demand_id: context
+                .get_identifier("AUTOPILOT_NOSEWHEEL_DEMAND".to_owned()),
+            oversteer_id: context.get_identifier("NOSE_WHEEL_STEERING_OVERSTEER".to_owned()),
+
+            tiller_pedal_disconnect: false,
+            tiller_handle_position: Ratio::default(),
+            rudder_pedal_position: Ratio::default(),
+            autopilot_nosewheel_demand: Ratio::default(),
+
+            pedal_input_map,
+            pedal_steering_limiter,
+            tiller_input_map,
+            tiller_steering_limiter,
+            limits,
+
+            powered_by,
+            is_powered: false,
+            // Self-test is considered done when starting with the aircraft already running
+            is_powered_previous_state: context.has_engines_running(),
+            self_test_remaining: Duration::ZERO,
+
+            requested_position: Angle::default(),
+            is_oversteered: false,
+        }
+    }
+
+    /// ## Parameters
+    /// `is_steering_allowed` - aircraft specific conditions for steering (anti-skid, engines...)
+    /// `additional_demand` - steering demand from other functions, added to the pilot demands
+    pub fn update(
+        &mut self,
+        context: &UpdateContext,
+        lgciu: &impl LgciuWeightOnWheels,
+        nose_steering_feedback: Angle,
+        is_steering_allowed: bool,
+        additional_demand: Angle,
+    ) {
+        self.update_self_test(context);
+
+        // Oversteer is recorded by the steering collar itself, whether the aircraft is powered or not
+        if nose_steering_feedback.abs() > self.limits.oversteer_angle {
+            self.is_oversteered = true;
+        }
+
+        self.pedal_steering_limiter
+            .update(context.ground_speed(), lgciu);
+
+        let steer_angle_from_autopilot = self.steer_angle_from_autopilot();
+        let steer_angle_from_pedals = self.steer_angle_from_pedals();
+        let steer_angle_from_tiller = self.steer_angle_from_tiller(context);
+
+        let pedal_authority = interpolation(
+            &Self::TILLER_DEMAND_FOR_PEDAL_BLENDING_DEGREE,
+            &Self::PEDAL_AUTHORITY_RATIO,
+            steer_angle_from_tiller.get::<degree>().abs(),
+        );
+
+        // TODO Here ground speed would be probably computed from wheel sensor logic
+        let steer_angle_from_pedals_and_autopilot = self.pedal_steering_limiter.angle_from_speed(
+            context.ground_speed(),
+            (steer_angle_from_pedals + steer_angle_from_autopilot).clamp(
+                -self.limits.max_pedal_and_autopilot_angle,
+                self.limits.max_pedal_and_autopilot_angle,
+            ),
+        ) * pedal_authority;
+
+        self.requested_position = if self.is_operative()
+            && is_steering_allowed
+            && lgciu.nose_gear_compressed(false)
+        {
+            (steer_angle_from_pedals_and_autopilot + steer_angle_from_tiller + additional_demand)
+                .clamp(
+                    -self.limits.max_steering_angle,
+                    self.limits.max_steering_angle,
+                )
+        } else {
+            Angle::default()
+        };
+    }
+
+    fn steer_angle_from_autopilot(&self) -> Angle {
+        Angle::new::<degree>(
+            self.autopilot_nosewheel_demand.get::<ratio>() * Self::AUTOPILOT_STEERING_INPUT_GAIN,
+        )
+    }
+
+    fn steer_angle_from_pedals(&self) -> Angle {
+        if self.tiller_pedal_disconnect {
+            Angle::default()
+        } else {
+            self.pedal_input_map
+                .angle_demand_from_input_demand(self.rudder_pedal_position)
+        }
+    }
+
+    fn steer_angle_from_tiller(&self, context: &UpdateContext) -> Angle {
+        self.tiller_steering_limiter.angle_from_speed(
+            context.ground_speed(),
+            self.tiller_input_map
+                .angle_demand_from_input_demand(self.tiller_handle_position),
+        )
+    }
+
+    fn update_self_test(&mut self, context: &UpdateContext) {
+        if self.is_powered && !self.is_powered_previous_state {
+            self.self_test_remaining = Self::SELF_TEST_DURATION;
+        } else if self.is_powered {
+            self.self_test_remaining = self.self_test_remaining.saturating_sub(context.delta());
+        }
+
+        self.is_powered_previous_state = self.is_powered;
+    }
+
+    /// Powered and done with its power-up self-test
+    pub fn is_operative(&self) -> bool {
+        self.is_powered && self.self_test_remaining == Duration::ZERO
+    }
+
+    pub fn is_self_test_in_progress(&self) -> bool {
+        self.is_powered && self.self_test_remaining > Duration::ZERO
+    }
+
+    /// True when pilots or autopilot are commanding steering.
+    /// Computed from the current inputs so it can be used before updating the steering demand.
+    pub fn is_pilot_demand_active(&self, context: &UpdateContext) -> bool {
+        self.steer_angle_from_autopilot().abs() > Angle::default()
+            || self.steer_angle_from_pedals().get::<degree>().abs()
+                > Self::PILOT_DEMAND_DETECTION_THRESHOLD_DEGREE
+            || self.steer_angle_from_tiller(context).get::<degree>().abs()
+                > Self::PILOT_DEMAND_DETECTION_THRESHOLD_DEGREE
+    }
+
+    pub fn is_oversteered(&self) -> bool {
+        self.is_oversteered
+    }
+}
+impl SteeringController for NoseWheelSteeringControl {
+    fn requested_position(&self) -> Angle {
+        self.requested_position
+    }
+
+    fn is_steering_available(&self) -> bool {
+        self.is_operative()
+    }
+}
+impl SimulationElement for NoseWheelSteeringControl {
+    fn receive_power(&mut self, buses: &impl ElectricalBuses) {
+        self.is_powered = buses.is_powered(self.powered_by);
+    }
+
+    fn read(&mut self, reader: &mut SimulatorReader) {
+        self.tiller_handle_position =
+            Ratio::new::<ratio>(reader.read(&self.tiller_handle_input_id));
+        self.rudder_pedal_position = Ratio::new::<ratio>(reader.read(&self.rudder_pedal_input_id));
+        self.tiller_pedal_disconnect = reader.read(&self.tiller_pedal_disconnect_id);
+
+        self.autopilot_nosewheel_demand =
+            Ratio::new::<ratio>(reader.read(&self.autopilot_nosewheel_demand_id));
+
+        // Warning is read back so ground crew can reset it after inspection
+        self.is_oversteered = reader.read(&self.oversteer_id);
+    }
+
+    fn write(&self, writer: &mut SimulatorWriter) {
+        writer.write(&self.oversteer_id, self.is_oversteered);
+    }
+}
+
 pub struct SteeringActuator {
     position_id: VariableIdentifier,
 
@@ -114,6 +418,13 @@ pub struct SteeringActuator {
     reference_pressure_for_max_speed: Pressure,
 
     is_steered_by_tug: bool,
+
+    jam_failure: Failure,
+    servo_valve_failure: Failure,
+    shimmy_damper_failure: Failure,
+
+    shimmy_phase: Angle,
+    shimmy_angle: Angle,
 }
 impl SteeringActuator {
     const MIN_PRESSURE_ALLOWING_STEERING_PSI: f64 = 300.;
@@ -126,9 +437,15 @@ impl SteeringActuator {
     // Note this is open loop: it will overshoot or undershoot depending on this factor
     const POSITION_ERROR_TO_MAX_SPEED_GAIN: f64 = 0.18;
 
+    // Without shimmy damper the wheel oscillates once rolling, amplitude growing with ground speed
+    const SHIMMY_FREQUENCY_HZ: f64 = 6.;
+    const SHIMMY_GROUND_SPEED_BREAKPOINT_KNOT: [f64; 4] = [0., 10., 60., 200.];
+    const SHIMMY_AMPLITUDE_DEGREE: [f64; 4] = [0., 0., 3., 3.];
+
     pub fn new(
         context: &mut InitContext,
         wheel_id: &str,
+        id: SteeringActuatorId,
         max_half_angle: Angle,
         nominal_speed: AngularVelocity,
         actuator_diameter: Length,
@@ -159,6 +476,13 @@ impl SteeringActuator {
             reference_pressure_for_max_speed,
 
             is_steered_by_tug,
+
+            jam_failure: Failure::new(FailureType::SteeringActuatorJammed(id)),
+            servo_valve_failure: Failure::new(FailureType::SteeringServoValve(id)),
+            shimmy_damper_failure: Failure::new(FailureType::SteeringShimmyDamper(id)),
+
+            shimmy_phase: Angle::default(),
+            shimmy_angle: Angle::default(),
         }
     }
 
@@ -170,21 +494,65 @@ impl SteeringActuator {
         pushback_tug: &impl Pushback,
         bypass_pin: &BypassPin,
     ) {
-        if !bypass_pin.is_nose_wheel_steering_pin_inserted() || !self.is_steered_by_tug {
-            let limited_requested_angle = steering_controller
-                .requested_position()
-                .clamp(-self.max_half_angle, self.max_half_angle);
+        if self.jam_failure.is_active() {
+            self.current_speed
+                .reset(AngularVelocity::new::<radian_per_second>(0.));
+        } else if !bypass_pin.is_nose_wheel_steering_pin_inserted() || !self.is_steered_by_tug {
+            let is_hydraulically_steered = !self.servo_valve_failure.is_active()
+                && steering_controller.is_steering_available();
+
+            // A wheel that is not hydraulically steered castors back to neutral
+            let limited_requested_angle = if is_hydraulically_steered {
+                steering_controller
+                    .requested_position()
+                    .clamp(-self.max_half_angle, self.max_half_angle)
+            } else {
+                Angle::default()
+            };
 
-            self.update_current_speed(context, section_pressure, limited_requested_angle);
+            self.update_current_speed(
+                context,
+                section_pressure,
+                limited_requested_angle,
+                is_hydraulically_steered,
+            );
 
             self.update_final_speed_position(context);
         } else {
             self.update_speed_position_during_pushback(pushback_tug);
         }
 
+        self.update_shimmy(context, bypass_pin);
+
         self.update_flow(context, bypass_pin);
     }
 
+    fn update_shimmy(&mut self, context: &UpdateContext, bypass_pin: &BypassPin) {
+        let amplitude = if self.shimmy_damper_failure.is_active()
+            && !self.jam_failure.is_active()
+            && !bypass_pin.is_nose_wheel_steering_pin_inserted()
+            && context.is_on_ground()
+        {
+            Angle::new::<degree>(interpolation(
+                &Self::SHIMMY_GROUND_SPEED_BREAKPOINT_KNOT,
+                &Self::SHIMMY_AMPLITUDE_DEGREE,
+                context.ground_speed().get::<knot>().abs(),
+            ))
+        } else {
+            Angle::default()
+        };
+
+        self.shimmy_phase = Angle::new::<radian>(
+            (self.shimmy_phase.get::<radian>()
+                + 2. * std::f64::consts::PI
+                    * Self::SHIMMY_FREQUENCY_HZ
+                    * context.delta_as_secs_f64())
+                % (2. * std::f64::consts::PI),
+        );
+
+        self.shimmy_angle = amplitude * self.shimmy_phase.get::<radian>().sin();
+    }
+
     fn update_final_speed_position(&mut self, context: &UpdateContext) {
         self.current_position += Angle::new::<radian>(
             self.current_speed.output().get::<radian_per_second>() * context.delta_as_secs_f64(),
@@ -202,8 +570,14 @@ impl SteeringActuator {
         context: &UpdateContext,
         section_pressure: &impl SectionPressure,
         requested_angle: Angle,
+        is_hydraulically_steered: bool,
     ) {
-        let current_pressure = section_pressure.pressure_downstream_priority_valve();
+        // Without servo valve control, the wheel is not hydraulically steered and only castors
+        let current_pressure = if is_hydraulically_steered {
+            section_pressure.pressure_downstream_priority_valve()
+        } else {
+            Pressure::default()
+        };
 
         let max_speed_for_current_hydraulics_pressure =
             self.max_speed_for_current_hydraulics_pressure(context, current_pressure);
@@ -217,7 +591,7 @@ impl SteeringActuator {
 
         self.current_speed.update(
             context.delta(),
-            if requested_angle > self.position_feedback() {
+            if requested_angle > self.current_position {
                 final_absolute_speed
             } else {
                 -final_absolute_speed
@@ -241,7 +615,7 @@ impl SteeringActuator {
     }
 
     fn max_speed_for_position_error(&self, requested_angle: Angle) -> AngularVelocity {
-        let position_error_abs = (requested_angle - self.position_feedback()).abs();
+        let position_error_abs = (requested_angle - self.current_position).abs();
 
         // When closing to requested position, speed decreases using coefficient formula
         //   This yields a 0..1 coefficient that reduces the nominal max speed
@@ -253,7 +627,10 @@ impl SteeringActuator {
     }
 
     fn update_flow(&mut self, context: &UpdateContext, bypass_pin: &BypassPin) {
-        if !bypass_pin.is_nose_wheel_steering_pin_inserted() {
+        if self.servo_valve_failure.is_active() {
+            self.total_volume_to_actuator = Volume::new::<gallon>(0.);
+            self.total_volume_to_reservoir = Volume::new::<gallon>(0.);
+        } else if !bypass_pin.is_nose_wheel_steering_pin_inserted() {
             let angular_position_delta_abs = Angle::new::<radian>(
                 self.current_speed.output().get::<radian_per_second>().abs()
                     * context.delta_as_secs_f64(),
@@ -270,12 +647,12 @@ impl SteeringActuator {
     }
 
     pub fn position_feedback(&self) -> Angle {
-        self.current_position
+        self.current_position + self.shimmy_angle
     }
 
     fn position_normalized(&self) -> Ratio {
         Ratio::new::<ratio>(
-            self.current_position.get::<radian>() / self.max_half_angle.get::<radian>(),
+            self.position_feedback().get::<radian>() / self.max_half_angle.get::<radian>(),
         )
     }
 }
@@ -294,6 +671,13 @@ impl Actuator for SteeringActuator {
     }
 }
 impl SimulationElement for SteeringActuator {
+    fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
+        self.jam_failure.accept(visitor);
+        self.servo_valve_failure.accept(visitor);
+        self.shimmy_damper_failure.accept(visitor);
+        visitor.visit(self);
+    }
+
     fn write(&self, writer: &mut SimulatorWriter) {
         writer.write(&self.position_id, self.position_normalized().get::<ratio>());
     }
@@ -305,10 +689,12 @@ mod tests {
 
     use super::*;
 
+    use crate::electrical::{test::TestElectricitySource, ElectricalBus, Electricity};
+    use crate::shared::PotentialOrigin;
     use crate::simulation::test::{ReadByName, SimulationTestBed, TestBed, WriteByName};
-    use crate::simulation::{Aircraft, SimulationElement, SimulationElementVisitor};
+    use crate::simulation::{Aircraft, SimulationElement, SimulationElementVisitor, StartState};
     use std::time::Duration;
-    use uom::si::{angle::degree, pressure::psi};
+    use uom::si::{angle::degree, electric_potential::volt, pressure::psi};
 
     struct TestPushBack {
         steering: Angle,
@@ -342,22 +728,32 @@ mod tests {
 
     struct TestSteeringController {
         requested_position: Angle,
+        is_available: bool,
     }
     impl TestSteeringController {
         fn new() -> Self {
             Self {
                 requested_position: Angle::new::<radian>(0.),
+                is_available: true,
             }
         }
 
         fn set_requested_position(&mut self, requested_position: Angle) {
             self.requested_position = requested_position;
         }
+
+        fn set_available(&mut self, is_available: bool) {
+            self.is_available = is_available;
+        }
     }
     impl SteeringController for TestSteeringController {
         fn requested_position(&self) -> Angle {
             self.requested_position
         }
+
+        fn is_steering_available(&self) -> bool {
+            self.is_available
+        }
     }
 
     #[derive(Default)]
@@ -428,6 +824,10 @@ mod tests {
         fn set_pushback(&mut self) {
             self.pushback.set_pin_inserted();
         }
+
+        fn set_steering_control_available(&mut self, is_available: bool) {
+            self.controller.set_available(is_available);
+        }
     }
     impl Aircraft for TestAircraft {
         fn update_after_power_distribution(&mut self, context: &UpdateContext) {
@@ -722,10 +1122,346 @@ mod tests {
         );
     }
 
+    #[test]
+    fn jammed_steering_does_not_move_with_pressure() {
+        let mut test_bed = SimulationTestBed::new(TestAircraft::new);
+        test_bed.fail(FailureType::SteeringActuatorJammed(
+            SteeringActuatorId::Nose,
+        ));
+
+        test_bed.command(|a| a.set_pressure(Pressure::new::<psi>(3000.)));
+        test_bed.command(|a| a.command_steer_angle(Angle::new::<degree>(20.)));
+
+        test_bed.run_multiple_frames(Duration::from_secs(5));
+
+        assert!(is_equal_angle(
+            test_bed.query(|a| a.steering_actuator.position_feedback()),
+            Angle::new::<degree>(0.)
+        ));
+        assert!(test_bed.query(|a| a.steering_actuator.used_volume()) == Volume::default());
+    }
+
+    #[test]
+    fn steering_with_failed_servo_valve_castors_back_to_neutral() {
+        let mut test_bed = SimulationTestBed::new(TestAircraft::new);
+
+        test_bed.command(|a| a.set_pressure(Pressure::new::<psi>(3000.)));
+        test_bed.command(|a| a.command_steer_angle(Angle::new::<degree>(20.)));
+        test_bed.run_multiple_frames(Duration::from_secs(5));
+
+        assert!(is_equal_angle(
+            test_bed.query(|a| a.steering_actuator.position_feedback()),
+            Angle::new::<degree>(20.)
+        ));
+
+        test_bed.fail(FailureType::SteeringServoValve(SteeringActuatorId::Nose));
+        test_bed.run_multiple_frames(Duration::from_secs(2));
+
+        assert!(is_equal_angle(
+            test_bed.query(|a| a.steering_actuator.position_feedback()),
+            Angle::new::<degree>(20.)
+        ));
+
+        test_bed.write_by_name("GPS GROUND SPEED", 20.);
+        test_bed.run_multiple_frames(Duration::from_secs(8));
+
+        assert!(
+            test_bed
+                .query(|a| a.steering_actuator.position_feedback())
+                .abs()
+                < Angle::new::<degree>(0.1)
+        );
+    }
+
+    #[test]
+    fn steering_does_not_respond_when_steering_control_unavailable() {
+        let mut test_bed = SimulationTestBed::new(TestAircraft::new);
+
+        test_bed.command(|a| a.set_steering_control_available(false));
+        test_bed.command(|a| a.set_pressure(Pressure::new::<psi>(3000.)));
+        test_bed.command(|a| a.command_steer_angle(Angle::new::<degree>(20.)));
+
+        test_bed.run_multiple_frames(Duration::from_secs(5));
+
+        assert!(is_equal_angle(
+            test_bed.query(|a| a.steering_actuator.position_feedback()),
+            Angle::new::<degree>(0.)
+        ));
+    }
+
+    #[test]
+    fn steering_shimmies_when_rolling_without_shimmy_damper() {
+        let mut test_bed = SimulationTestBed::new(TestAircraft::new);
+        test_bed.set_on_ground(true);
+        test_bed.fail(FailureType::SteeringShimmyDamper(SteeringActuatorId::Nose));
+
+        test_bed.command(|a| a.set_pressure(Pressure::new::<psi>(3000.)));
+        test_bed.run_multiple_frames(Duration::from_secs(1));
+
+        assert!(is_equal_angle(
+            test_bed.query(|a| a.steering_actuator.position_feedback()),
+            Angle::new::<degree>(0.)
+        ));
+
+        test_bed.write_by_name("GPS GROUND SPEED", 60.);
+
+        let mut max_deflection = Angle::default();
+        for _ in 0..50 {
+            test_bed.run_with_delta(Duration::from_millis(10));
+            max_deflection =
+                max_deflection.max(test_bed.query(|a| a.steering_actuator.position_feedback()));
+        }
+
+        assert_gt!(max_deflection, Angle::new::<degree>(2.));
+    }
+
+    struct TestLgciuSensors {
+        nose_gear_compressed: bool,
+    }
+    impl TestLgciuSensors {
+        fn compressed() -> Self {
+            Self {
+                nose_gear_compressed: true,
+            }
+        }
+    }
+    impl LgciuWeightOnWheels for TestLgciuSensors {
+        fn right_gear_compressed(&self, _: bool) -> bool {
+            self.nose_gear_compressed
+        }
+        fn right_gear_extended(&self, _: bool) -> bool {
+            !self.nose_gear_compressed
+        }
+
+        fn left_gear_compressed(&self, _: bool) -> bool {
+            self.nose_gear_compressed
+        }
+        fn left_gear_extended(&self, _: bool) -> bool {
+            !self.nose_gear_compressed
+        }
+
+        fn left_and_right_gear_compressed(&self, _: bool) -> bool {
+            self.nose_gear_compressed
+        }
+        fn left_and_right_gear_extended(&self, _: bool) -> bool {
+            !self.nose_gear_compressed
+        }
+
+        fn nose_gear_compressed(&self, _: bool) -> bool {
+            self.nose_gear_compressed
+        }
+        fn nose_gear_extended(&self, _: bool) -> bool {
+            !self.nose_gear_compressed
+        }
+    }
+
+    struct TestSteeringControlAircraft {
+        electricity_source: TestElectricitySource,
+        dc_1_bus: ElectricalBus,
+        is_dc_1_powered: bool,
+
+        lgciu: TestLgciuSensors,
+        nose_steering_feedback: Angle,
+
+        steering_control: NoseWheelSteeringControl,
+    }
+    impl TestSteeringControlAircraft {
+        fn new(context: &mut InitContext) -> Self {
+            Self {
+                electricity_source: TestElectricitySource::powered(
+                    context,
+                    PotentialOrigin::EngineGenerator(1),
+                ),
+                dc_1_bus: ElectricalBus::new(context, ElectricalBusType::DirectCurrent(1)),
+                is_dc_1_powered: true,
+
+                lgciu: TestLgciuSensors::compressed(),
+                nose_steering_feedback: Angle::default(),
+
+                steering_control: NoseWheelSteeringControl::new(
+                    context,
+                    ElectricalBusType::DirectCurrent(1),
+                    pedal_input_angle(),
+                    PedalSteeringDynamicLimiter::new(
+                        pedal_speed_angle_limiter(),
+                        pedal_speed_angle_limiter(),
+                        Velocity::default(),
+                    ),
+                    tiller_input_angle(),
+                    tiller_speed_angle_limiter(),
+                    SteeringAngleLimits::new(
+                        Angle::new::<degree>(6.),
+                        Angle::new::<degree>(75.),
+                        Angle::new::<degree>(95.),
+                    ),
+                ),
+            }
+        }
+
+        fn set_dc_1_power(&mut self, is_powered: bool) {
+            self.is_dc_1_powered = is_powered;
+        }
+
+        fn set_nose_steering_feedback(&mut self, angle: Angle) {
+            self.nose_steering_feedback = angle;
+        }
+    }
+    impl Aircraft for TestSteeringControlAircraft {
+        fn update_before_power_distribution(
+            &mut self,
+            _: &UpdateContext,
+            electricity: &mut Electricity,
+        ) {
+            self.electricity_source
+                .power_with_potential(ElectricPotential::new::<volt>(28.));
+            electricity.supplied_by(&self.electricity_source);
+
+            if self.is_dc_1_powered {
+                electricity.flow(&self.electricity_source, &self.dc_1_bus);
+            }
+        }
+
+        fn update_after_power_distribution(&mut self, context: &UpdateContext) {
+            self.steering_control.update(
+                context,
+                &self.lgciu,
+                self.nose_steering_feedback,
+                true,
+                Angle::default(),
+            );
+        }
+    }
+    impl SimulationElement for TestSteeringControlAircraft {
+        fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
+            self.steering_control.accept(visitor);
+            visitor.visit(self);
+        }
+    }
+
+    #[test]
+    fn steering_control_does_not_steer_during_power_up_self_test() {
+        let mut test_bed = SimulationTestBed::new_with_start_state(
+            StartState::Apron,
+            TestSteeringControlAircraft::new,
+        );
+        test_bed.write_by_name("TILLER_HANDLE_POSITION", 1.);
+
+        test_bed.run_multiple_frames(Duration::from_millis(500));
+
+        assert!(test_bed.query(|a| a.steering_control.is_self_test_in_progress()));
+        assert!(!test_bed.query(|a| a.steering_control.is_steering_available()));
+        assert!(is_equal_angle(
+            test_bed.query(|a| a.steering_control.requested_position()),
+            Angle::default()
+        ));
+
+        test_bed.run_multiple_frames(Duration::from_secs(1));
+
+        assert!(!test_bed.query(|a| a.steering_control.is_self_test_in_progress()));
+        assert!(test_bed.query(|a| a.steering_control.is_steering_available()));
+        assert!(is_equal_angle(
+            test_bed.query(|a| a.steering_control.requested_position()),
+            Angle::new::<degree>(74.)
+        ));
+    }
+
+    #[test]
+    fn steering_control_runs_self_test_again_after_power_loss() {
+        let mut test_bed = SimulationTestBed::new(TestSteeringControlAircraft::new);
+        test_bed.run_multiple_frames(Duration::from_secs(3));
+        assert!(test_bed.query(|a| a.steering_control.is_operative()));
+
+        test_bed.command(|a| a.set_dc_1_power(false));
+        test_bed.run_multiple_frames(Duration::from_secs(1));
+        assert!(!test_bed.query(|a| a.steering_control.is_operative()));
+
+        test_bed.command(|a| a.set_dc_1_power(true));
+        test_bed.run_multiple_frames(Duration::from_millis(500));
+        assert!(test_bed.query(|a| a.steering_control.is_self_test_in_progress()));
+    }
+
+    #[test]
+    fn steering_control_pedals_add_to_small_tiller_demand() {
+        let mut test_bed = SimulationTestBed::new(TestSteeringControlAircraft::new);
+        test_bed.run_multiple_frames(Duration::from_secs(3));
+
+        test_bed.write_by_name("TILLER_HANDLE_POSITION", 0.1);
+        test_bed.run();
+        let tiller_only = test_bed.query(|a| a.steering_control.requested_position());
+
+        test_bed.write_by_name("RUDDER_PEDAL_POSITION_RATIO", 1.);
+        test_bed.run();
+
+        assert!(is_equal_angle(
+            test_bed.query(|a| a.steering_control.requested_position()),
+            tiller_only + Angle::new::<degree>(6.)
+        ));
+    }
+
+    #[test]
+    fn steering_control_pedals_have_no_authority_on_large_tiller_demand() {
+        let mut test_bed = SimulationTestBed::new(TestSteeringControlAircraft::new);
+        test_bed.run_multiple_frames(Duration::from_secs(3));
+
+        test_bed.write_by_name("TILLER_HANDLE_POSITION", 0.8);
+        test_bed.run();
+        let tiller_only = test_bed.query(|a| a.steering_control.requested_position());
+        assert_gt!(tiller_only, Angle::new::<degree>(30.));
+
+        test_bed.write_by_name("RUDDER_PEDAL_POSITION_RATIO", 1.);
+        test_bed.run();
+
+        assert!(is_equal_angle(
+            test_bed.query(|a| a.steering_control.requested_position()),
+            tiller_only
+        ));
+    }
+
+    #[test]
+    fn steering_control_latches_oversteer_even_unpowered() {
+        let mut test_bed = SimulationTestBed::new(TestSteeringControlAircraft::new);
+        test_bed.command(|a| a.set_dc_1_power(false));
+        test_bed.run();
+
+        test_bed.command(|a| a.set_nose_steering_feedback(Angle::new::<degree>(-100.)));
+        test_bed.run();
+        test_bed.command(|a| a.set_nose_steering_feedback(Angle::new::<degree>(0.)));
+        test_bed.run();
+
+        assert!(test_bed.query(|a| a.steering_control.is_oversteered()));
+        let is_oversteer_written: bool = test_bed.read_by_name("NOSE_WHEEL_STEERING_OVERSTEER");
+        assert!(is_oversteer_written);
+    }
+
+    #[test]
+    fn steering_control_oversteer_is_reset_by_ground_crew() {
+        let mut test_bed = SimulationTestBed::new(TestSteeringControlAircraft::new);
+        test_bed.command(|a| a.set_nose_steering_feedback(Angle::new::<degree>(100.)));
+        test_bed.run();
+        test_bed.command(|a| a.set_nose_steering_feedback(Angle::new::<degree>(0.)));
+        test_bed.run();
+        assert!(test_bed.query(|a| a.steering_control.is_oversteered()));
+
+        test_bed.write_by_name("NOSE_WHEEL_STEERING_OVERSTEER", false);
+        test_bed.run();
+
+        assert!(!test_bed.query(|a| a.steering_control.is_oversteered()));
+    }
+
+    #[test]
+    fn steering_control_does_not_warn_oversteer_within_limits() {
+        let mut test_bed = SimulationTestBed::new(TestSteeringControlAircraft::new);
+        test_bed.command(|a| a.set_nose_steering_feedback(Angle::new::<degree>(90.)));
+        test_bed.run();
+
+        assert!(!test_bed.query(|a| a.steering_control.is_oversteered()));
+    }
+
     fn steering_actuator(context: &mut InitContext) -> SteeringActuator {
         SteeringActuator::new(
             context,
             "NOSE_WHEEL",
+            SteeringActuatorId::Nose,
             Angle::new::<degree>(75.),
             AngularVelocity::new::<radian_per_second>(0.35),
             Length::new::<meter>(0.05),
@@ -47,6 +47,15 @@ impl PumpCharacteristics {
     const A320_RAT_DISPLACEMENT_MAP_CUBIC_INCH: [f64; 9] =
         [0.5, 0.8, 1.15, 1.15, 1.15, 0.8, 0.3, 0.0, 0.0];
 
+    // Hand pump lever strokes are modelled as an equivalent constant pump speed
+    const A320_HAND_PUMP_EQUIVALENT_SPEED_RPM: f64 = 300.0;
+
+    const A320_HAND_PUMP_DISPLACEMENT_BREAKPTS_PSI: [f64; 9] = [
+        0.0, 500.0, 1000.0, 1500.0, 2500.0, 2900.0, 3000.0, 3050.0, 3500.0,
+    ];
+    const A320_HAND_PUMP_DISPLACEMENT_MAP_CUBIC_INCH: [f64; 9] =
+        [0.25, 0.25, 0.25, 0.25, 0.25, 0.1, 0.0, 0.0, 0.0];
+
     const A380_EDP_DISPLACEMENT_BREAKPTS_PSI: [f64; 9] = [
         0.0, 500.0, 1000.0, 2900.0, 4790.0, 5150.0, 5225.0, 5350.0, 5500.0,
     ];
@@ -125,6 +134,18 @@ impl PumpCharacteristics {
         )
     }
 
+    pub fn a320_hand_pump() -> Self {
+        PumpCharacteristics::new(
+            Self::A320_HAND_PUMP_DISPLACEMENT_BREAKPTS_PSI,
+            Self::A320_HAND_PUMP_DISPLACEMENT_MAP_CUBIC_INCH,
+            Self::AIR_PRESSURE_BREAKPTS_PSI,
+            Self::AIR_PRESSURE_CARAC_RATIO,
+            Some(AngularVelocity::new::<revolution_per_minute>(
+                Self::A320_HAND_PUMP_EQUIVALENT_SPEED_RPM,
+            )),
+        )
+    }
+
     pub fn a380_electric_pump() -> Self {
         PumpCharacteristics::new(
             Self::A380_EPUMP_DISPLACEMENT_BREAKPTS_PSI,
@@ -159,6 +180,23 @@ impl PumpCharacteristics {
         )
     }
 
+    /// Replaces the displacement map, keeping all other characteristics
+    pub fn with_displacement_map(
+        mut self,
+        pressure_map_breakpoints_psi: [f64; 9],
+        displacement_map_cubic_inch: [f64; 9],
+    ) -> Self {
+        self.pressure_map_breakpoints_psi = pressure_map_breakpoints_psi;
+        self.displacement_map_cubic_inch = displacement_map_cubic_inch;
+        self
+    }
+
+    /// Replaces the regulation speed, keeping all other characteristics
+    pub fn with_regulated_speed(mut self, regulated_speed: AngularVelocity) -> Self {
+        self.regulated_speed = Some(regulated_speed);
+        self
+    }
+
     pub fn current_displacement(&self, pressure: Pressure) -> Volume {
         Volume::new::<cubic_inch>(interpolation(
             &self.pressure_map_breakpoints_psi,
@@ -1,19 +1,94 @@
-use uom::si::{f64::*, length::meter};
+use uom::si::{
+    acceleration::meter_per_second_squared, f64::*, force::kilonewton, length::meter,
+    pressure::psi, velocity::knot, velocity::meter_per_second,
+};
 
 use crate::{
     shared::{
-        low_pass_filter::LowPassFilter, steering_angle_from_plane_yaw_rate, DelayedFalseLogicGate,
+        low_pass_filter::LowPassFilter, steering_angle_from_plane_yaw_rate, Clamp,
+        DelayedFalseLogicGate, InternationalStandardAtmosphere,
     },
     simulation::{
-        InitContext, Read, SimulationElement, SimulatorReader, UpdateContext, VariableIdentifier,
+        InitContext, Read, Reader, SimulationElement, SimulatorReader, SimulatorWriter,
+        UpdateContext, VariableIdentifier, Write, Writer,
     },
 };
 use std::time::Duration;
 
 use super::nose_steering::Pushback;
 
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum TugType {
+    Towbar = 0,
+    Towbarless = 1,
+}
+impl TugType {
+    fn max_tractive_force(&self) -> Force {
+        match self {
+            TugType::Towbar => Force::new::<kilonewton>(150.),
+            TugType::Towbarless => Force::new::<kilonewton>(300.),
+        }
+    }
+
+    fn max_speed(&self) -> Velocity {
+        match self {
+            TugType::Towbar => Velocity::new::<knot>(12.),
+            TugType::Towbarless => Velocity::new::<knot>(16.),
+        }
+    }
+}
+
+try_read_write_enum!(TugType);
+
+impl TryFrom<f64> for TugType {
+    type Error = u8;
+
+    fn try_from(value: f64) -> Result<Self, Self::Error> {
+        match value as u8 {
+            0 => Ok(TugType::Towbar),
+            1 => Ok(TugType::Towbarless),
+            i => Err(i),
+        }
+    }
+}
+
+/// Towing limits of the aircraft nose gear
+pub struct TowingLimits {
+    towbar_shear_pin_force: Force,
+    nose_gear_towing_force: Force,
+    towbar_steering_angle: Angle,
+}
+impl TowingLimits {
+    /// ## Parameters
+    /// `towbar_shear_pin_force` - tow force breaking the towbar traction/compression shear pin
+    /// `nose_gear_towing_force` - maximum horizontal load on the nose gear when towed
+    /// `towbar_steering_angle` - steering angle breaking the towbar torsion shear pin
+    pub fn new(
+        towbar_shear_pin_force: Force,
+        nose_gear_towing_force: Force,
+        towbar_steering_angle: Angle,
+    ) -> Self {
+        Self {
+            towbar_shear_pin_force,
+            nose_gear_towing_force,
+            towbar_steering_angle,
+        }
+    }
+}
+
+/// Tug pushing or towing the aircraft by the nose gear.
+///
+/// The tow force is estimated from aircraft acceleration, rolling resistance and the resistance of
+/// brakes set while being towed. A towbar breaks its shear pin when the tow force or the steering
+/// angle exceeds the aircraft limits, disconnecting the tug. A towbarless tug has no shear pin: the
+/// nose gear gets overloaded instead and the wheel follows the tug past its steering limits.
 pub struct PushbackTug {
     state_id: VariableIdentifier,
+    tug_type_id: VariableIdentifier,
+    shear_pin_broken_id: VariableIdentifier,
+    nose_gear_tow_load_id: VariableIdentifier,
+    nose_gear_overload_id: VariableIdentifier,
+    tug_max_speed_id: VariableIdentifier,
 
     steering_angle: LowPassFilter<Angle>,
 
@@ -25,6 +100,13 @@ pub struct PushbackTug {
     // 4 = might be finishing pushback, to confirm
     state: f64,
     nose_wheel_steering_pin_inserted: DelayedFalseLogicGate,
+
+    tug_type: TugType,
+    limits: TowingLimits,
+
+    tow_force: Force,
+    shear_pin_broken: bool,
+    nose_gear_overloaded: bool,
 }
 impl PushbackTug {
     pub const DURATION_AFTER_WHICH_NWS_PIN_IS_REMOVED_AFTER_PUSHBACK: Duration =
@@ -34,9 +116,23 @@ impl PushbackTug {
 
     const STEERING_ANGLE_FILTER_TIME_CONSTANT: Duration = Duration::from_millis(800);
 
-    pub fn new(context: &mut InitContext) -> Self {
+    const ROLLING_RESISTANCE_COEFFICIENT: f64 = 0.01;
+    const MIN_SPEED_FOR_RESISTANCE_METER_PER_SECOND: f64 = 0.05;
+
+    /// Brake pressure at which brakes hold the whole aircraft weight on main gears
+    const BRAKE_PRESSURE_FOR_FULL_HOLDING_PSI: f64 = 1000.;
+    const BRAKE_FRICTION_COEFFICIENT: f64 = 0.5;
+    const MAIN_GEAR_WEIGHT_RATIO: f64 = 0.9;
+
+    pub fn new(context: &mut InitContext, limits: TowingLimits) -> Self {
         Self {
             state_id: context.get_identifier("PUSHBACK STATE".to_owned()),
+            tug_type_id: context.get_identifier("PUSHBACK_TUG_TYPE".to_owned()),
+            shear_pin_broken_id: context
+                .get_identifier("PUSHBACK_TOWBAR_SHEAR_PIN_BROKEN".to_owned()),
+            nose_gear_tow_load_id: context.get_identifier("NOSE_GEAR_TOW_LOAD".to_owned()),
+            nose_gear_overload_id: context.get_identifier("NOSE_GEAR_TOWING_OVERLOAD".to_owned()),
+            tug_max_speed_id: context.get_identifier("PUSHBACK_TUG_MAX_SPEED".to_owned()),
 
             steering_angle: LowPassFilter::new(Self::STEERING_ANGLE_FILTER_TIME_CONSTANT),
 
@@ -44,11 +140,19 @@ impl PushbackTug {
             nose_wheel_steering_pin_inserted: DelayedFalseLogicGate::new(
                 Self::DURATION_AFTER_WHICH_NWS_PIN_IS_REMOVED_AFTER_PUSHBACK,
             ),
+
+            tug_type: TugType::Towbar,
+            limits,
+
+            tow_force: Force::default(),
+            shear_pin_broken: false,
+            nose_gear_overloaded: false,
         }
     }
 
     fn update_pushback_angle(&mut self, context: &UpdateContext) {
-        if self.is_pushing() {
+        // A broken towbar no longer turns the nose wheel
+        if self.is_pushing() && !self.shear_pin_broken {
             let new_angle = if context.local_velocity().to_ms_vector()[2].abs() < 0.05 {
                 self.steering_angle.output()
             } else {
@@ -58,16 +162,90 @@ impl PushbackTug {
         }
     }
 
-    pub fn update(&mut self, context: &UpdateContext) {
+    /// ## Parameters
+    /// `brake_pressure` - highest pressure applied to the main gear brakes
+    pub fn update(&mut self, context: &UpdateContext, brake_pressure: Pressure) {
         self.nose_wheel_steering_pin_inserted
             .update(context, self.is_pushing());
 
         self.update_pushback_angle(context);
+
+        if self.is_pushing() && !self.shear_pin_broken {
+            self.update_tow_force(context, brake_pressure);
+            self.update_limits();
+        } else {
+            self.tow_force = Force::default();
+        }
+
+        // A new towbar and shear pin come with the next tug
+        if !self.is_pushing() {
+            self.shear_pin_broken = false;
+        }
+    }
+
+    fn update_tow_force(&mut self, context: &UpdateContext, brake_pressure: Pressure) {
+        let weight = context.total_weight()
+            * Acceleration::new::<meter_per_second_squared>(
+                InternationalStandardAtmosphere::GRAVITY_ACCELERATION,
+            );
+
+        let long_velocity = context
+            .local_velocity()
+            .long_velocity()
+            .get::<meter_per_second>();
+        let resistance = if long_velocity.abs() > Self::MIN_SPEED_FOR_RESISTANCE_METER_PER_SECOND {
+            let brake_holding_ratio = (brake_pressure.get::<psi>()
+                / Self::BRAKE_PRESSURE_FOR_FULL_HOLDING_PSI)
+                .clamp(0., 1.);
+
+            weight
+                * (Self::ROLLING_RESISTANCE_COEFFICIENT
+                    + brake_holding_ratio
+                        * Self::BRAKE_FRICTION_COEFFICIENT
+                        * Self::MAIN_GEAR_WEIGHT_RATIO)
+                * long_velocity.signum()
+        } else {
+            Force::default()
+        };
+
+        let max_tractive_force = self.tug_type.max_tractive_force();
+        self.tow_force = (context.total_weight() * context.long_accel() + resistance)
+            .clamp(-max_tractive_force, max_tractive_force);
+    }
+
+    fn update_limits(&mut self) {
+        match self.tug_type {
+            TugType::Towbar => {
+                if self.tow_force.abs() > self.limits.towbar_shear_pin_force
+                    || self.steering_angle.output().abs() > self.limits.towbar_steering_angle
+                {
+                    self.shear_pin_broken = true;
+                }
+            }
+            TugType::Towbarless => {
+                if self.tow_force.abs() > self.limits.nose_gear_towing_force {
+                    self.nose_gear_overloaded = true;
+                }
+            }
+        }
     }
 
     fn is_pushing(&self) -> bool {
         (self.state - PushbackTug::STATE_NO_PUSHBACK).abs() > f64::EPSILON
     }
+
+    /// Horizontal load transmitted by the tug to the nose gear, relative to its towing limit
+    pub fn nose_gear_tow_load(&self) -> Ratio {
+        self.tow_force.abs() / self.limits.nose_gear_towing_force
+    }
+
+    pub fn is_shear_pin_broken(&self) -> bool {
+        self.shear_pin_broken
+    }
+
+    pub fn is_nose_gear_overloaded(&self) -> bool {
+        self.nose_gear_overloaded
+    }
 }
 impl Pushback for PushbackTug {
     fn is_nose_wheel_steering_pin_inserted(&self) -> bool {
@@ -81,5 +259,164 @@ impl Pushback for PushbackTug {
 impl SimulationElement for PushbackTug {
     fn read(&mut self, reader: &mut SimulatorReader) {
         self.state = reader.read(&self.state_id);
+        self.tug_type =
+            reader.read_discrete_or_fallback(&self.tug_type_id, "TugType", TugType::Towbar);
+
+        // Overload is read back so maintenance can reset it after inspection
+        self.nose_gear_overloaded = reader.read(&self.nose_gear_overload_id);
+    }
+
+    fn write(&self, writer: &mut SimulatorWriter) {
+        writer.write(&self.shear_pin_broken_id, self.shear_pin_broken);
+        writer.write(&self.nose_gear_tow_load_id, self.nose_gear_tow_load());
+        writer.write(&self.nose_gear_overload_id, self.nose_gear_overloaded);
+        writer.write(&self.tug_max_speed_id, self.tug_type.max_speed());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::simulation::test::{ReadByName, SimulationTestBed, TestBed, WriteByName};
+    use crate::simulation::{Aircraft, SimulationElementVisitor};
+    use uom::si::{angle::degree, ratio::ratio};
+
+    struct TestAircraft {
+        pushback_tug: PushbackTug,
+        brake_pressure: Pressure,
+    }
+    impl TestAircraft {
+        fn new(context: &mut InitContext) -> Self {
+            Self {
+                pushback_tug: PushbackTug::new(
+                    context,
+                    TowingLimits::new(
+                        Force::new::<kilonewton>(60.),
+                        Force::new::<kilonewton>(90.),
+                        Angle::new::<degree>(95.),
+                    ),
+                ),
+                brake_pressure: Pressure::default(),
+            }
+        }
+
+        fn set_brake_pressure(&mut self, pressure: Pressure) {
+            self.brake_pressure = pressure;
+        }
+    }
+    impl Aircraft for TestAircraft {
+        fn update_after_power_distribution(&mut self, context: &UpdateContext) {
+            self.pushback_tug.update(context, self.brake_pressure);
+        }
+    }
+    impl SimulationElement for TestAircraft {
+        fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
+            self.pushback_tug.accept(visitor);
+
+            visitor.visit(self);
+        }
+    }
+
+    fn test_bed_pushing_back(tug_type: TugType) -> SimulationTestBed<TestAircraft> {
+        let mut test_bed = SimulationTestBed::new(TestAircraft::new);
+        test_bed.write_by_name("TOTAL WEIGHT", 150000.);
+        test_bed.write_by_name("PUSHBACK_TUG_TYPE", tug_type as u8 as f64);
+        test_bed.write_by_name("PUSHBACK STATE", 0.);
+        test_bed.write_by_name("VELOCITY BODY Z", -3.);
+
+        test_bed
+    }
+
+    #[test]
+    fn no_tow_load_without_pushback() {
+        let mut test_bed = SimulationTestBed::new(TestAircraft::new);
+        test_bed.write_by_name("TOTAL WEIGHT", 150000.);
+        test_bed.write_by_name("PUSHBACK STATE", 3.);
+        test_bed.write_by_name("VELOCITY BODY Z", -3.);
+        test_bed.run();
+
+        assert_eq!(
+            test_bed.query(|a| a.pushback_tug.nose_gear_tow_load()),
+            Ratio::default()
+        );
+    }
+
+    #[test]
+    fn normal_pushback_keeps_towbar_connected() {
+        let mut test_bed = test_bed_pushing_back(TugType::Towbar);
+        test_bed.run();
+
+        let tow_load = test_bed.query(|a| a.pushback_tug.nose_gear_tow_load());
+        assert!(tow_load > Ratio::default());
+        assert!(tow_load < Ratio::new::<ratio>(0.2));
+
+        let shear_pin_broken: bool = test_bed.read_by_name("PUSHBACK_TOWBAR_SHEAR_PIN_BROKEN");
+        assert!(!shear_pin_broken);
+    }
+
+    #[test]
+    fn braking_while_pushed_breaks_towbar_shear_pin() {
+        let mut test_bed = test_bed_pushing_back(TugType::Towbar);
+        test_bed.command(|a| a.set_brake_pressure(Pressure::new::<psi>(3000.)));
+        test_bed.run();
+
+        let shear_pin_broken: bool = test_bed.read_by_name("PUSHBACK_TOWBAR_SHEAR_PIN_BROKEN");
+        assert!(shear_pin_broken);
+        assert!(!test_bed.query(|a| a.pushback_tug.is_nose_gear_overloaded()));
+
+        test_bed.command(|a| a.set_brake_pressure(Pressure::default()));
+        test_bed.run();
+
+        assert!(test_bed.query(|a| a.pushback_tug.is_shear_pin_broken()));
+        assert_eq!(
+            test_bed.query(|a| a.pushback_tug.nose_gear_tow_load()),
+            Ratio::default()
+        );
+    }
+
+    #[test]
+    fn shear_pin_is_replaced_with_next_tug() {
+        let mut test_bed = test_bed_pushing_back(TugType::Towbar);
+        test_bed.command(|a| a.set_brake_pressure(Pressure::new::<psi>(3000.)));
+        test_bed.run();
+        assert!(test_bed.query(|a| a.pushback_tug.is_shear_pin_broken()));
+
+        test_bed.write_by_name("PUSHBACK STATE", 3.);
+        test_bed.run();
+
+        assert!(!test_bed.query(|a| a.pushback_tug.is_shear_pin_broken()));
+    }
+
+    #[test]
+    fn braking_while_pushed_by_towbarless_tug_overloads_nose_gear() {
+        let mut test_bed = test_bed_pushing_back(TugType::Towbarless);
+        test_bed.command(|a| a.set_brake_pressure(Pressure::new::<psi>(3000.)));
+        test_bed.run();
+
+        let nose_gear_overloaded: bool = test_bed.read_by_name("NOSE_GEAR_TOWING_OVERLOAD");
+        assert!(nose_gear_overloaded);
+        assert!(!test_bed.query(|a| a.pushback_tug.is_shear_pin_broken()));
+
+        // Stays latched until reset by maintenance
+        test_bed.write_by_name("PUSHBACK STATE", 3.);
+        test_bed.run();
+        assert!(test_bed.query(|a| a.pushback_tug.is_nose_gear_overloaded()));
+
+        test_bed.write_by_name("NOSE_GEAR_TOWING_OVERLOAD", false);
+        test_bed.run();
+        assert!(!test_bed.query(|a| a.pushback_tug.is_nose_gear_overloaded()));
+    }
+
+    #[test]
+    fn towbarless_tug_is_faster_than_towbar_tug() {
+        let mut test_bed = test_bed_pushing_back(TugType::Towbar);
+        test_bed.run();
+        let towbar_max_speed: Velocity = test_bed.read_by_name("PUSHBACK_TUG_MAX_SPEED");
+
+        test_bed.write_by_name("PUSHBACK_TUG_TYPE", TugType::Towbarless as u8 as f64);
+        test_bed.run();
+        let towbarless_max_speed: Velocity = test_bed.read_by_name("PUSHBACK_TUG_MAX_SPEED");
+
+        assert!(towbarless_max_speed > towbar_max_speed);
     }
 }
@@ -2,11 +2,12 @@ use std::time::Duration;
 use uom::si::{f64::*, ratio::ratio, volume::gallon};
 
 use crate::{
+    engine::reverser::ReverserFailures,
     shared::{
         low_pass_filter::LowPassFilter, random_from_normal_distribution, ElectricalBusType,
         ElectricalBuses, ReverserPosition,
     },
-    simulation::{SimulationElement, SimulationElementVisitor, UpdateContext},
+    simulation::{InitContext, SimulationElement, SimulationElementVisitor, UpdateContext},
 };
 
 use super::{
@@ -52,8 +53,9 @@ impl ReverserActuator {
         context: &UpdateContext,
         pressure: Pressure,
         is_mechanically_locked: bool,
+        failures: &ReverserFailures,
     ) {
-        self.update_current_speed(context, pressure, is_mechanically_locked);
+        self.update_current_speed(context, pressure, is_mechanically_locked, failures);
 
         self.position += context.delta_as_secs_f64() * self.current_speed.output();
 
@@ -76,12 +78,14 @@ impl ReverserActuator {
         context: &UpdateContext,
         pressure: Pressure,
         is_mechanically_locked: bool,
+        failures: &ReverserFailures,
     ) {
-        if is_mechanically_locked {
+        let max_speed = self.max_speed_from_pressure(pressure);
+
+        if is_mechanically_locked || !failures.actuator_can_move(max_speed) {
             self.current_speed.reset(Ratio::default());
         } else {
-            self.current_speed
-                .update(context.delta(), self.max_speed_from_pressure(pressure));
+            self.current_speed.update(context.delta(), max_speed);
         }
     }
 
@@ -132,8 +136,8 @@ impl ElectricalLock {
         }
     }
 
-    fn update(&mut self, controller: &impl ReverserInterface, actuator_position: Ratio) {
-        let is_locking = !controller.should_unlock() || !self.is_powered;
+    fn update(&mut self, should_unlock: bool, actuator_position: Ratio) {
+        let is_locking = !should_unlock || !self.is_powered;
 
         self.is_locked = is_locking && actuator_position.get::<ratio>() < 0.01;
     }
@@ -288,9 +292,13 @@ pub struct ReverserAssembly {
     electrical_lock: ElectricalLock,
     hydraulic_manifold: ReverserHydraulicManifold,
     actuator: ReverserActuator,
+
+    failures: ReverserFailures,
 }
 impl ReverserAssembly {
     pub fn new(
+        context: &mut InitContext,
+        number: usize,
         nominal_hydraulic_pressure: Pressure,
         switch_high_threshold_pressure: Pressure,
         switch_low_threshold_pressure: Pressure,
@@ -307,6 +315,8 @@ impl ReverserAssembly {
                 switch_low_threshold_pressure,
             ),
             actuator: ReverserActuator::new(nominal_hydraulic_pressure),
+
+            failures: ReverserFailures::new(context, number),
         }
     }
 
@@ -316,16 +326,29 @@ impl ReverserAssembly {
         controller: &impl ReverserInterface,
         pressure: Pressure,
     ) {
-        self.electrical_lock
-            .update(controller, self.reverser_position());
+        self.failures.update(context, self.actuator.position());
+
+        // Maintenance lockout mechanically holds the lock engaged and deactivates the
+        // hydraulic control unit
+        self.electrical_lock.update(
+            controller.should_unlock() && !self.failures.maintenance_lockout_engaged(),
+            self.reverser_position(),
+        );
+
+        let supply_pressure = if self.failures.actuation_is_available() {
+            pressure
+        } else {
+            Pressure::default()
+        };
 
         self.hydraulic_manifold
-            .update(context, pressure, controller);
+            .update(context, supply_pressure, controller);
 
         self.actuator.update(
             context,
             self.hydraulic_manifold.actuator_pressure(),
             self.electrical_lock.is_locked(),
+            &self.failures,
         );
     }
 
@@ -339,17 +362,16 @@ impl ReverserFeedback for ReverserAssembly {
     }
 
     fn proximity_sensor_all_stowed(&self) -> bool {
-        self.reverser_position().get::<ratio>() < 0.01
+        self.failures.all_sleeves_stowed(self.actuator.position())
     }
 
     fn proximity_sensor_all_deployed(&self) -> bool {
-        self.reverser_position().get::<ratio>() > 0.95
+        self.failures.all_sleeves_deployed(self.actuator.position())
     }
 
     fn proximity_sensor_at_least_one_stowed(&self) -> bool {
-        // We do not model multiple doors for now, placeholder is a higher threshold for one door stowed only
-        self.reverser_position().get::<ratio>() < 0.2
-            && self.reverser_position().get::<ratio>() >= 0.01
+        self.failures
+            .at_least_one_sleeve_stowed(self.actuator.position())
     }
 
     fn pressure_switch_pressurised(&self) -> bool {
@@ -362,13 +384,14 @@ impl ReverserFeedback for ReverserAssembly {
 }
 impl ReverserPosition for ReverserAssembly {
     fn reverser_position(&self) -> Ratio {
-        self.actuator.position()
+        self.failures.reverser_position(self.actuator.position())
     }
 }
 impl SimulationElement for ReverserAssembly {
     fn accept<V: SimulationElementVisitor>(&mut self, visitor: &mut V) {
         self.electrical_lock.accept(visitor);
         self.hydraulic_manifold.accept(visitor);
+        self.failures.accept(visitor);
 
         visitor.visit(self);
     }
@@ -377,6 +400,7 @@ impl SimulationElement for ReverserAssembly {
 #[cfg(test)]
 mod tests {
     use more_asserts::*;
+    use ntest::assert_about_eq;
 
     use uom::si::electric_potential::volt;
 
@@ -385,8 +409,9 @@ mod tests {
     use crate::electrical::Electricity;
 
     use super::*;
+    use crate::failures::FailureType;
     use crate::shared::{update_iterator::FixedStepLoop, PotentialOrigin};
-    use crate::simulation::test::{SimulationTestBed, TestBed};
+    use crate::simulation::test::{SimulationTestBed, TestBed, WriteByName};
     use crate::simulation::{Aircraft, InitContext, SimulationElement};
 
     use std::time::Duration;
@@ -461,6 +486,8 @@ mod tests {
                 controller: TestReverserController::default(),
 
                 reverser: ReverserAssembly::new(
+                    context,
+                    1,
                     Pressure::new::<psi>(3000.),
                     Pressure::new::<psi>(2100.),
                     Pressure::new::<psi>(1750.),
@@ -749,4 +776,108 @@ mod tests {
         );
         assert!(test_bed.query(|a| a.reverser_is_locked()));
     }
+
+    fn command_deploy(test_bed: &mut SimulationTestBed<TestAircraft>, is_deploying: bool) {
+        test_bed.command(|a| a.set_hyd_pressure(Pressure::new::<psi>(3000.)));
+        test_bed.command(|a| a.set_ac_elec_power(true));
+        test_bed.command(|a| a.set_dc_elec_power(true));
+        test_bed.command(|a| a.set_isolation_valve(false));
+        test_bed.command(|a| a.set_deploy_reverser(is_deploying));
+        test_bed.command(|a| a.set_lock_reverser(!is_deploying));
+    }
+
+    #[test]
+    fn reverser_do_not_deploy_if_failed_to_deploy() {
+        let mut test_bed = SimulationTestBed::new(TestAircraft::new);
+
+        test_bed.fail(FailureType::ReverserFailToDeploy(1));
+        command_deploy(&mut test_bed, true);
+
+        test_bed.run_with_delta(Duration::from_millis(3000));
+
+        assert_eq!(test_bed.query(|a| a.reverser_position().get::<ratio>()), 0.);
+    }
+
+    #[test]
+    fn reverser_stays_deployed_if_failed_to_stow() {
+        let mut test_bed = SimulationTestBed::new(TestAircraft::new);
+
+        command_deploy(&mut test_bed, true);
+        test_bed.run_with_delta(Duration::from_millis(3000));
+
+        test_bed.fail(FailureType::ReverserFailToStow(1));
+        command_deploy(&mut test_bed, false);
+        test_bed.run_with_delta(Duration::from_millis(3000));
+
+        assert_ge!(
+            test_bed.query(|a| a.reverser_position().get::<ratio>()),
+            0.97
+        );
+        assert!(test_bed.query(|a| a.reverser.proximity_sensor_all_deployed()));
+    }
+
+    #[test]
+    fn reverser_with_jammed_sleeve_deploys_halfway() {
+        let mut test_bed = SimulationTestBed::new(TestAircraft::new);
+
+        test_bed.fail(FailureType::ReverserSleeveJammed(1));
+        command_deploy(&mut test_bed, true);
+        test_bed.run_with_delta(Duration::from_millis(3000));
+
+        assert_about_eq!(
+            test_bed.query(|a| a.reverser_position().get::<ratio>()),
+            0.5,
+            0.01
+        );
+        assert!(test_bed.query(|a| !a.reverser.proximity_sensor_all_deployed()));
+        assert!(test_bed.query(|a| a.reverser.proximity_sensor_at_least_one_stowed()));
+    }
+
+    #[test]
+    fn reverser_unlocked_failure_loses_stowed_indication() {
+        let mut test_bed = SimulationTestBed::new(TestAircraft::new);
+
+        test_bed.command(|a| a.set_ac_elec_power(true));
+        test_bed.command(|a| a.set_dc_elec_power(true));
+        test_bed.fail(FailureType::ReverserUnlocked(1));
+        test_bed.run_with_delta(Duration::from_millis(5000));
+
+        assert_ge!(
+            test_bed.query(|a| a.reverser_position().get::<ratio>()),
+            0.05
+        );
+        assert_le!(
+            test_bed.query(|a| a.reverser_position().get::<ratio>()),
+            0.1
+        );
+        assert!(test_bed.query(|a| !a.reverser.proximity_sensor_all_stowed()));
+        assert!(test_bed.query(|a| !a.reverser.tertiary_lock_is_locked()));
+    }
+
+    #[test]
+    fn reverser_do_not_deploy_without_actuation_supply() {
+        let mut test_bed = SimulationTestBed::new(TestAircraft::new);
+
+        test_bed.fail(FailureType::ReverserActuationSupplyLoss(1));
+        command_deploy(&mut test_bed, true);
+        test_bed.run_with_delta(Duration::from_millis(3000));
+
+        assert_le!(
+            test_bed.query(|a| a.reverser_manifold_pressure().get::<psi>()),
+            50.
+        );
+        assert_eq!(test_bed.query(|a| a.reverser_position().get::<ratio>()), 0.);
+    }
+
+    #[test]
+    fn reverser_do_not_deploy_with_maintenance_lockout() {
+        let mut test_bed = SimulationTestBed::new(TestAircraft::new);
+
+        test_bed.write_by_name("REVERSER_1_MAINTENANCE_LOCKOUT", true);
+        command_deploy(&mut test_bed, true);
+        test_bed.run_with_delta(Duration::from_millis(3000));
+
+        assert_eq!(test_bed.query(|a| a.reverser_position().get::<ratio>()), 0.);
+        assert!(test_bed.query(|a| a.reverser_is_locked()));
+    }
 }
@@ -2,12 +2,16 @@ use uom::si::{
     angle::{degree, radian},
     angular_velocity::{radian_per_second, revolution_per_minute},
     f64::*,
+    force::newton,
+    mass_density::kilogram_per_cubic_meter,
     pressure::psi,
     ratio::ratio,
+    velocity::meter_per_second,
     volume::{cubic_inch, gallon},
     volume_rate::gallon_per_minute,
 };
 
+use crate::failures::{Failure, FailureType};
 use crate::simulation::{
     InitContext, SimulationElement, SimulationElementVisitor, SimulatorWriter, UpdateContext,
     VariableIdentifier, Write,
@@ -15,7 +19,7 @@ use crate::simulation::{
 
 use crate::shared::{
     interpolation, low_pass_filter::LowPassFilter, ElectricalBusType, ElectricalBuses,
-    TrimmableHorizontalStabilizer,
+    HydraulicColor, TrimmableHorizontalStabilizer,
 };
 
 use super::linear_actuator::Actuator;
@@ -24,14 +28,24 @@ use std::time::Duration;
 
 struct TrimWheels {
     position_percent_id: VariableIdentifier,
+    manual_force_id: VariableIdentifier,
 
     position: Angle,
+    manual_force: Force,
     trim_actuator_over_trim_wheel_ratio: Ratio,
 
     min_angle: Angle,
     max_angle: Angle,
 }
 impl TrimWheels {
+    const FRICTION_FORCE_NEWTON: f64 = 20.;
+
+    // Force felt on the wheel per pascal of dynamic pressure when the THS is not hydraulically
+    // driven and the pilot has to back drive the screwjack against the aerodynamic load
+    const AERODYNAMIC_LOAD_FORCE_NEWTON_PER_PASCAL: f64 = 0.035;
+
+    const MAX_PILOT_FORCE_NEWTON: f64 = 250.;
+
     fn new(
         context: &mut InitContext,
         trim_actuator_over_trim_wheel_ratio: Ratio,
@@ -40,8 +54,10 @@ impl TrimWheels {
     ) -> Self {
         Self {
             position_percent_id: context.get_identifier("HYD_TRIM_WHEEL_PERCENT".to_owned()),
+            manual_force_id: context.get_identifier("HYD_TRIM_WHEEL_MANUAL_FORCE".to_owned()),
 
             position: Angle::default(),
+            manual_force: Force::default(),
             trim_actuator_over_trim_wheel_ratio,
             min_angle,
             max_angle: min_angle + total_range_angle,
@@ -52,6 +68,45 @@ impl TrimWheels {
         self.position = pta.position / self.trim_actuator_over_trim_wheel_ratio.get::<ratio>();
     }
 
+    /// Computes the force the pilot needs to apply on the wheel to move it manually.
+    /// While the THS hydraulic motors follow the wheel, only the mechanical friction is felt.
+    /// Otherwise the aerodynamic load on the stabilizer is fed back through the screwjack.
+    fn update_manual_force(
+        &mut self,
+        context: &UpdateContext,
+        manual_controller: &impl ManualPitchTrimController,
+        ths_hydraulics: &TrimmableHorizontalStabilizerActuator,
+    ) {
+        self.manual_force = if manual_controller.is_manually_moved() {
+            let aerodynamic_load_force = if ths_hydraulics.is_hydraulically_driven() {
+                0.
+            } else {
+                Self::AERODYNAMIC_LOAD_FORCE_NEWTON_PER_PASCAL
+                    * Self::dynamic_pressure_pascal(context)
+            };
+
+            Force::new::<newton>(Self::FRICTION_FORCE_NEWTON + aerodynamic_load_force)
+        } else {
+            Force::default()
+        };
+    }
+
+    fn dynamic_pressure_pascal(context: &UpdateContext) -> f64 {
+        0.5 * context
+            .ambient_air_density()
+            .get::<kilogram_per_cubic_meter>()
+            * context.true_airspeed().get::<meter_per_second>().powi(2)
+    }
+
+    /// Ratio of the manual input speed the pilot can achieve given the force required on the wheel
+    fn manual_speed_ratio(&self) -> Ratio {
+        if self.manual_force.get::<newton>() <= Self::MAX_PILOT_FORCE_NEWTON {
+            Ratio::new::<ratio>(1.)
+        } else {
+            Ratio::new::<ratio>(Self::MAX_PILOT_FORCE_NEWTON / self.manual_force.get::<newton>())
+        }
+    }
+
     fn position_normalized(&self) -> Ratio {
         ((self.position - self.min_angle) / (self.max_angle - self.min_angle))
             .min(Ratio::new::<ratio>(100.))
@@ -64,6 +119,7 @@ impl SimulationElement for TrimWheels {
             &self.position_percent_id,
             self.position_normalized().get::<ratio>() * 100.,
         );
+        writer.write(&self.manual_force_id, self.manual_force.get::<newton>());
     }
 }
 
@@ -141,6 +197,7 @@ struct ElectricDriveMotor {
     motor: DriveMotor,
 
     is_powered: bool,
+    failure: Failure,
 
     powered_by_bus_array: Vec<ElectricalBusType>,
     powered_by_bus: ElectricalTrimMotorElecBus,
@@ -155,6 +212,7 @@ impl ElectricDriveMotor {
         speed_regulation_coef_map: [f64; 7],
 
         powered_by_bus_array: Vec<ElectricalBusType>,
+        failure_type: FailureType,
     ) -> Self {
         // FIXME no runtime panics please! Use the type system or compile time checks
         // Only supports one main bus or one main plus one standby
@@ -163,6 +221,7 @@ impl ElectricDriveMotor {
         Self {
             motor: DriveMotor::new(max_speed, speed_error_breakpoint, speed_regulation_coef_map),
             is_powered: true,
+            failure: Failure::new(failure_type),
 
             powered_by_bus_array,
 
@@ -172,7 +231,8 @@ impl ElectricDriveMotor {
     }
 
     fn set_active_state(&mut self, is_active: bool) {
-        self.motor.set_active_state(is_active && self.is_powered);
+        self.motor
+            .set_active_state(is_active && self.is_powered && !self.failure.is_active());
     }
 
     fn update(
@@ -200,6 +260,11 @@ impl ElectricDriveMotor {
     }
 }
 impl SimulationElement for ElectricDriveMotor {
+    fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
+        self.failure.accept(visitor);
+        visitor.visit(self);
+    }
+
     fn receive_power(&mut self, buses: &impl ElectricalBuses) {
         let bus_selected_index: usize =
             (self.powered_by_bus as usize).min(self.powered_by_bus_array.len() - 1);
@@ -213,13 +278,14 @@ pub trait TrimmableHorizontalStabilizerMotorController {
     fn requested_position(&self) -> Angle;
 }
 
-#[derive(Clone, Copy)]
 struct HydraulicDriveMotor {
     max_speed: AngularVelocity,
     max_speed_hyd_pressure: Pressure,
     displacement: Volume,
 
     motor: DriveMotor,
+    failure: Failure,
+    pressure: Pressure,
 
     total_volume_to_actuator: Volume,
     total_volume_to_reservoir: Volume,
@@ -227,6 +293,9 @@ struct HydraulicDriveMotor {
 impl HydraulicDriveMotor {
     const FLOW_CONSTANT_RPM_CUBIC_INCH_TO_GPM: f64 = 231.;
 
+    // Under that pressure the motor can't hold the screwjack against the aerodynamic load
+    const MIN_PRESSURE_TO_DRIVE_PSI: f64 = 1450.;
+
     fn new(
         max_speed: AngularVelocity,
         max_speed_hyd_pressure: Pressure,
@@ -234,6 +303,8 @@ impl HydraulicDriveMotor {
 
         speed_error_breakpoint: [f64; 7],
         speed_regulation_coef_map: [f64; 7],
+
+        hydraulic_circuit: HydraulicColor,
     ) -> Self {
         Self {
             max_speed,
@@ -241,6 +312,8 @@ impl HydraulicDriveMotor {
             displacement,
 
             motor: DriveMotor::new(max_speed, speed_error_breakpoint, speed_regulation_coef_map),
+            failure: Failure::new(FailureType::ThsHydraulicMotor(hydraulic_circuit)),
+            pressure: Pressure::default(),
 
             total_volume_to_actuator: Volume::default(),
             total_volume_to_reservoir: Volume::default(),
@@ -255,7 +328,10 @@ impl HydraulicDriveMotor {
         position_requested: Angle,
         pressure: Pressure,
     ) {
-        self.motor.set_active_state(motor_active);
+        self.pressure = pressure;
+
+        self.motor
+            .set_active_state(motor_active && !self.failure.is_active());
 
         self.motor
             .set_max_speed(self.current_max_speed_from_hydraulic_pressure(pressure));
@@ -283,6 +359,11 @@ impl HydraulicDriveMotor {
         self.motor.speed()
     }
 
+    /// True if the motor is healthy and its hydraulic circuit is pressurised enough to drive the THS
+    fn can_drive(&self) -> bool {
+        !self.failure.is_active() && self.pressure.get::<psi>() > Self::MIN_PRESSURE_TO_DRIVE_PSI
+    }
+
     fn flow(&self) -> VolumeRate {
         VolumeRate::new::<gallon_per_minute>(
             self.speed().get::<revolution_per_minute>() * self.displacement.get::<cubic_inch>()
@@ -304,6 +385,12 @@ impl Actuator for HydraulicDriveMotor {
         self.total_volume_to_actuator = Volume::new::<gallon>(0.);
     }
 }
+impl SimulationElement for HydraulicDriveMotor {
+    fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
+        self.failure.accept(visitor);
+        visitor.visit(self);
+    }
+}
 
 #[derive(Clone, Copy)]
 struct ElectricMotorClutch {
@@ -348,6 +435,8 @@ struct PitchTrimActuator {
     electric_clutches: [ElectricMotorClutch; 3],
     manual_override_active: bool,
 
+    runaway_failure: Failure,
+
     position: Angle,
     speed: AngularVelocity,
 
@@ -382,23 +471,28 @@ impl PitchTrimActuator {
                         ElectricalBusType::DirectCurrent(2),
                         ElectricalBusType::DirectCurrentHot(2),
                     ],
+                    FailureType::ThsElectricMotor(1),
                 ),
                 ElectricDriveMotor::new(
                     max_elec_motor_speed,
                     Self::ELECTRIC_MOTOR_POSITION_ERROR_BREAKPOINT,
                     Self::ELECTRIC_MOTOR_SPEED_REGULATION_COEF_MAP,
                     vec![ElectricalBusType::DirectCurrentEssential],
+                    FailureType::ThsElectricMotor(2),
                 ),
                 ElectricDriveMotor::new(
                     max_elec_motor_speed,
                     Self::ELECTRIC_MOTOR_POSITION_ERROR_BREAKPOINT,
                     Self::ELECTRIC_MOTOR_SPEED_REGULATION_COEF_MAP,
                     vec![ElectricalBusType::DirectCurrent(2)],
+                    FailureType::ThsElectricMotor(3),
                 ),
             ],
             electric_clutches: [ElectricMotorClutch::default(); 3],
             manual_override_active: false,
 
+            runaway_failure: Failure::new(FailureType::ThsTrimRunaway),
+
             position: Angle::default(),
             speed: AngularVelocity::default(),
 
@@ -415,11 +509,16 @@ impl PitchTrimActuator {
         electric_controller: &impl PitchTrimActuatorController,
         manual_controller: &impl ManualPitchTrimController,
         ths_hydraulic_assembly: &TrimmableHorizontalStabilizerActuator,
+        manual_speed_ratio: Ratio,
     ) {
         self.update_clutches_state(electric_controller);
         self.update_motors(context, electric_controller, ths_hydraulic_assembly);
 
-        self.update_speed_and_override(manual_controller, ths_hydraulic_assembly);
+        self.update_speed_and_override(
+            manual_controller,
+            ths_hydraulic_assembly,
+            manual_speed_ratio,
+        );
 
         self.update_position(context);
     }
@@ -439,11 +538,12 @@ impl PitchTrimActuator {
         &mut self,
         manual_controller: &impl ManualPitchTrimController,
         ths_hydraulic_assembly: &TrimmableHorizontalStabilizerActuator,
+        manual_speed_ratio: Ratio,
     ) {
         let elec_drive_speed = self.elec_motor_drive_total_speed();
 
         if manual_controller.is_manually_moved() {
-            self.speed = manual_controller.moving_speed();
+            self.speed = manual_controller.moving_speed() * manual_speed_ratio.get::<ratio>();
             self.manual_override_active = elec_drive_speed.get::<revolution_per_minute>().abs()
                 > Self::MIN_ELEC_MOTOR_SPEED_FOR_MANUAL_OVERRIDE_DETECTION_RPM;
         } else {
@@ -451,6 +551,11 @@ impl PitchTrimActuator {
             self.manual_override_active = false
         }
 
+        // A jammed screwjack blocks the whole mechanical chain, trim wheel included
+        if ths_hydraulic_assembly.is_jammed() {
+            self.speed = AngularVelocity::default();
+        }
+
         if ths_hydraulic_assembly.is_at_max_down_spool_valve
             && self.speed.get::<radian_per_second>() < 0.
             || ths_hydraulic_assembly.is_at_max_up_spool_valve
@@ -489,13 +594,17 @@ impl PitchTrimActuator {
         for (motor_index, motor) in self.electric_motors.iter_mut().enumerate() {
             motor.set_active_state(controller.energised_motor()[motor_index]);
 
-            let trim_actuator_normalized_position_request = ths_hydraulic_assembly
-                .normalized_position_from_ths_deflection(controller.commanded_position());
+            // A runaway drives the energised motor to full nose up regardless of the command
+            let final_trim_actuator_position_request = if self.runaway_failure.is_active() {
+                self.max_actuator_angle
+            } else {
+                let trim_actuator_normalized_position_request = ths_hydraulic_assembly
+                    .normalized_position_from_ths_deflection(controller.commanded_position());
 
-            let final_trim_actuator_position_request = trim_actuator_normalized_position_request
-                .get::<ratio>()
-                * (self.max_actuator_angle - self.min_actuator_angle)
-                + self.min_actuator_angle;
+                trim_actuator_normalized_position_request.get::<ratio>()
+                    * (self.max_actuator_angle - self.min_actuator_angle)
+                    + self.min_actuator_angle
+            };
 
             motor.update(context, self.position, final_trim_actuator_position_request);
         }
@@ -515,6 +624,7 @@ impl PitchTrimActuator {
 impl SimulationElement for PitchTrimActuator {
     fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
         accept_iterable!(self.electric_motors, visitor);
+        self.runaway_failure.accept(visitor);
         visitor.visit(self);
     }
 
@@ -620,11 +730,15 @@ impl TrimmableHorizontalStabilizerAssembly {
         manual_controller: &impl ManualPitchTrimController,
         pressures: [Pressure; 2],
     ) {
+        self.trim_wheel
+            .update_manual_force(context, manual_controller, &self.ths_hydraulics);
+
         self.pitch_trim_actuator.update(
             context,
             electric_controller,
             manual_controller,
             &self.ths_hydraulics,
+            self.trim_wheel.manual_speed_ratio(),
         );
 
         self.trim_wheel.update(&self.pitch_trim_actuator);
@@ -678,6 +792,8 @@ pub struct TrimmableHorizontalStabilizerActuator {
     deflection_id: VariableIdentifier,
     hydraulic_motors: [HydraulicDriveMotor; 2],
 
+    jam_failure: Failure,
+
     speed: AngularVelocity,
     actual_deflection: Angle,
 
@@ -707,13 +823,19 @@ impl TrimmableHorizontalStabilizerActuator {
     ) -> Self {
         Self {
             deflection_id: context.get_identifier("HYD_FINAL_THS_DEFLECTION".to_owned()),
-            hydraulic_motors: [HydraulicDriveMotor::new(
-                motor_max_speed,
-                motor_max_speed_hyd_pressure,
-                motor_displacement,
-                Self::HYDRAULIC_MOTOR_POSITION_ERROR_BREAKPOINT,
-                Self::HYDRAULIC_MOTOR_SPEED_REGULATION_COEF_MAP,
-            ); 2],
+            // Motors are in green->yellow order
+            hydraulic_motors: [HydraulicColor::Green, HydraulicColor::Yellow].map(|circuit| {
+                HydraulicDriveMotor::new(
+                    motor_max_speed,
+                    motor_max_speed_hyd_pressure,
+                    motor_displacement,
+                    Self::HYDRAULIC_MOTOR_POSITION_ERROR_BREAKPOINT,
+                    Self::HYDRAULIC_MOTOR_SPEED_REGULATION_COEF_MAP,
+                    circuit,
+                )
+            }),
+
+            jam_failure: Failure::new(FailureType::ThsJam),
 
             speed: AngularVelocity::default(),
             actual_deflection: Angle::default(),
@@ -746,7 +868,7 @@ impl TrimmableHorizontalStabilizerActuator {
         {
             motor.update(
                 context,
-                controller.motor_should_activate(),
+                controller.motor_should_activate() && !self.jam_failure.is_active(),
                 self.actual_deflection,
                 controller.requested_position(),
                 pressure,
@@ -759,15 +881,29 @@ impl TrimmableHorizontalStabilizerActuator {
     }
 
     fn update_speed(&mut self) {
+        if self.jam_failure.is_active() {
+            self.speed = AngularVelocity::default();
+            return;
+        }
+
         let mut sum_of_speeds = AngularVelocity::default();
 
-        for motor in self.hydraulic_motors {
+        for motor in &self.hydraulic_motors {
             sum_of_speeds += motor.speed() * self.motor_to_ths_gearing_ratio;
         }
 
         self.speed = sum_of_speeds;
     }
 
+    /// True if the THS is free to move and at least one hydraulic motor has pressure to drive it
+    fn is_hydraulically_driven(&self) -> bool {
+        !self.is_jammed() && self.hydraulic_motors.iter().any(|m| m.can_drive())
+    }
+
+    fn is_jammed(&self) -> bool {
+        self.jam_failure.is_active()
+    }
+
     fn update_spool_valve_lock_position(&mut self, deflection_demand: Angle) {
         let deflection_error = deflection_demand - self.actual_deflection;
         self.is_at_max_up_spool_valve = deflection_error.get::<degree>()
@@ -804,6 +940,12 @@ impl TrimmableHorizontalStabilizerActuator {
     }
 }
 impl SimulationElement for TrimmableHorizontalStabilizerActuator {
+    fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
+        accept_iterable!(self.hydraulic_motors, visitor);
+        self.jam_failure.accept(visitor);
+        visitor.visit(self);
+    }
+
     fn write(&self, writer: &mut SimulatorWriter) {
         writer.write(&self.deflection_id, self.actual_deflection.get::<degree>());
     }
@@ -814,7 +956,10 @@ mod tests {
     use more_asserts::*;
 
     use uom::si::angle::degree;
-    use uom::si::{angular_velocity::degree_per_second, electric_potential::volt, ratio::percent};
+    use uom::si::{
+        angular_velocity::degree_per_second, electric_potential::volt, ratio::percent,
+        velocity::knot,
+    };
 
     use crate::electrical::test::TestElectricitySource;
     use crate::electrical::ElectricalBus;
@@ -1161,4 +1306,141 @@ mod tests {
         let deflection: Angle = test_bed.read_by_name("HYD_FINAL_THS_DEFLECTION");
         assert_gt!(deflection.get::<degree>(), 5.);
     }
+
+    #[test]
+    fn jammed_ths_does_not_move_with_elec_trim_demand() {
+        let mut test_bed = SimulationTestBed::new(TestAircraft::new);
+        test_bed.fail(FailureType::ThsJam);
+
+        test_bed.command(|a| a.set_elec_trim_demand(Angle::new::<degree>(13.), 0));
+        test_bed.run_with_delta(Duration::from_millis(10000));
+
+        let deflection: Angle = test_bed.read_by_name("HYD_FINAL_THS_DEFLECTION");
+        assert_lt!(deflection.get::<degree>().abs(), 0.01);
+    }
+
+    #[test]
+    fn jammed_ths_blocks_manual_trim_wheel() {
+        let mut test_bed = SimulationTestBed::new(TestAircraft::new);
+        test_bed.fail(FailureType::ThsJam);
+
+        let trim_wheel_init: f64 = test_bed.read_by_name("HYD_TRIM_WHEEL_PERCENT");
+
+        test_bed.command(|a| a.set_no_elec_input());
+        test_bed.command(|a| a.set_manual_trim_input(true));
+        test_bed.run_with_delta(Duration::from_millis(5000));
+
+        let deflection: Angle = test_bed.read_by_name("HYD_FINAL_THS_DEFLECTION");
+        assert_lt!(deflection.get::<degree>().abs(), 0.01);
+
+        let trim_wheel: f64 = test_bed.read_by_name("HYD_TRIM_WHEEL_PERCENT");
+        assert_lt!((trim_wheel - trim_wheel_init).abs(), 0.01);
+    }
+
+    #[test]
+    fn ths_moves_slower_with_one_hydraulic_motor_failed() {
+        let mut test_bed = SimulationTestBed::new(TestAircraft::new);
+        test_bed.command(|a| a.set_elec_trim_demand(Angle::new::<degree>(13.), 0));
+        test_bed.run_with_delta(Duration::from_millis(3000));
+        let deflection_two_motors: Angle = test_bed.read_by_name("HYD_FINAL_THS_DEFLECTION");
+
+        let mut test_bed = SimulationTestBed::new(TestAircraft::new);
+        test_bed.fail(FailureType::ThsHydraulicMotor(HydraulicColor::Green));
+        test_bed.command(|a| a.set_elec_trim_demand(Angle::new::<degree>(13.), 0));
+        test_bed.run_with_delta(Duration::from_millis(3000));
+        let deflection_one_motor: Angle = test_bed.read_by_name("HYD_FINAL_THS_DEFLECTION");
+
+        assert_gt!(deflection_one_motor.get::<degree>(), 1.);
+        assert_lt!(deflection_one_motor, deflection_two_motors);
+
+        test_bed.run_with_delta(Duration::from_millis(20000));
+        let deflection: Angle = test_bed.read_by_name("HYD_FINAL_THS_DEFLECTION");
+        assert_gt!(deflection.get::<degree>(), 12.9);
+        assert_lt!(deflection.get::<degree>(), 13.1);
+    }
+
+    #[test]
+    fn failed_elec_motor_is_stuck_and_trim_recovers_on_other_motor() {
+        let mut test_bed = SimulationTestBed::new(TestAircraft::new);
+        test_bed.fail(FailureType::ThsElectricMotor(1));
+
+        test_bed.command(|a| a.set_elec_trim_demand(Angle::new::<degree>(5.), 0));
+        test_bed.run_with_delta(Duration::from_millis(5000));
+
+        let deflection: Angle = test_bed.read_by_name("HYD_FINAL_THS_DEFLECTION");
+        assert_lt!(deflection.get::<degree>().abs(), 0.1);
+
+        test_bed.command(|a| a.set_elec_trim_demand(Angle::new::<degree>(5.), 1));
+        test_bed.run_with_delta(Duration::from_millis(10000));
+
+        let deflection: Angle = test_bed.read_by_name("HYD_FINAL_THS_DEFLECTION");
+        assert_gt!(deflection.get::<degree>(), 4.9);
+        assert_lt!(deflection.get::<degree>(), 5.1);
+    }
+
+    #[test]
+    fn trim_runaway_drives_ths_nose_up_until_manually_overridden() {
+        let mut test_bed = SimulationTestBed::new(TestAircraft::new);
+        test_bed.fail(FailureType::ThsTrimRunaway);
+
+        test_bed.command(|a| a.set_elec_trim_demand(Angle::new::<degree>(0.), 0));
+        test_bed.run_with_delta(Duration::from_millis(5000));
+
+        let deflection_runaway: Angle = test_bed.read_by_name("HYD_FINAL_THS_DEFLECTION");
+        assert_gt!(deflection_runaway.get::<degree>(), 2.);
+
+        test_bed.command(|a| a.set_manual_trim_input(false));
+        test_bed.run_with_delta(Duration::from_millis(2000));
+
+        let man_override: f64 = test_bed.read_by_name("HYD_THS_TRIM_MANUAL_OVERRIDE");
+        assert_gt!(man_override, 0.5);
+
+        let deflection: Angle = test_bed.read_by_name("HYD_FINAL_THS_DEFLECTION");
+        assert_lt!(deflection, deflection_runaway);
+    }
+
+    #[test]
+    fn manual_trim_force_is_friction_only_when_ths_hydraulically_driven() {
+        let mut test_bed = SimulationTestBed::new(TestAircraft::new);
+        test_bed.set_ambient_air_density(MassDensity::new::<kilogram_per_cubic_meter>(1.225));
+        test_bed.set_true_airspeed(Velocity::new::<knot>(300.));
+
+        test_bed.command(|a| a.set_no_elec_input());
+        test_bed.command(|a| a.set_manual_trim_input(true));
+        test_bed.run_with_delta(Duration::from_millis(1000));
+
+        let force: f64 = test_bed.read_by_name("HYD_TRIM_WHEEL_MANUAL_FORCE");
+        assert_lt!(force, 30.);
+    }
+
+    #[test]
+    fn manual_trim_without_hydraulics_is_harder_at_high_airspeed() {
+        let mut test_bed = SimulationTestBed::new(TestAircraft::new);
+        test_bed.command(|a| a.set_hyd_pressure([Pressure::default(), Pressure::default()]));
+        test_bed.set_ambient_air_density(MassDensity::new::<kilogram_per_cubic_meter>(1.225));
+        test_bed.set_true_airspeed(Velocity::new::<knot>(120.));
+
+        test_bed.command(|a| a.set_no_elec_input());
+        test_bed.command(|a| a.set_manual_trim_input(true));
+        test_bed.run_with_delta(Duration::from_millis(100));
+
+        let force_low_speed: f64 = test_bed.read_by_name("HYD_TRIM_WHEEL_MANUAL_FORCE");
+        let trim_wheel_low_speed: Ratio = test_bed.read_by_name("HYD_TRIM_WHEEL_PERCENT");
+
+        let mut test_bed = SimulationTestBed::new(TestAircraft::new);
+        test_bed.command(|a| a.set_hyd_pressure([Pressure::default(), Pressure::default()]));
+        test_bed.set_ambient_air_density(MassDensity::new::<kilogram_per_cubic_meter>(1.225));
+        test_bed.set_true_airspeed(Velocity::new::<knot>(350.));
+
+        test_bed.command(|a| a.set_no_elec_input());
+        test_bed.command(|a| a.set_manual_trim_input(true));
+        test_bed.run_with_delta(Duration::from_millis(100));
+
+        let force_high_speed: f64 = test_bed.read_by_name("HYD_TRIM_WHEEL_MANUAL_FORCE");
+        let trim_wheel_high_speed: Ratio = test_bed.read_by_name("HYD_TRIM_WHEEL_PERCENT");
+
+        assert_gt!(force_high_speed, 250.);
+        assert_gt!(force_high_speed, force_low_speed);
+        assert_lt!(trim_wheel_high_speed, trim_wheel_low_speed);
+    }
 }
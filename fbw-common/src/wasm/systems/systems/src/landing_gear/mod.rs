@@ -1,3 +1,5 @@
+pub mod tpis;
+
 use std::time::Duration;
 
 use crate::{
@@ -28,11 +30,12 @@ pub trait GearSystemSensors {
     fn is_wheel_id_down_and_locked(&self, wheel_id: GearWheel, lgciu_id: LgciuId) -> bool;
     fn is_door_id_up_and_locked(&self, wheel_id: GearWheel, lgciu_id: LgciuId) -> bool;
     fn is_door_id_down_and_locked(&self, wheel_id: GearWheel, lgciu_id: LgciuId) -> bool;
+    fn is_wheel_id_damaged(&self, wheel_id: GearWheel) -> bool;
+    fn is_door_id_damaged(&self, wheel_id: GearWheel) -> bool;
 }
 
 pub struct TiltingGear {
     tilt_animation_id: VariableIdentifier,
-    compression_id: VariableIdentifier,
     wheel_speed_id: VariableIdentifier,
 
     tilt_height_from_low_to_up: Length,
@@ -40,7 +43,6 @@ pub struct TiltingGear {
     aft_contact_point_offset_from_datum_ref_meters: Vector3<f64>,
     tilting_max_angle: Angle,
 
-    current_compression: Ratio,
     tilt_position: Ratio,
     wheel_speed: AngularVelocity,
     wheel_radius: Length,
@@ -77,8 +79,6 @@ impl TiltingGear {
         Self {
             tilt_animation_id: context
                 .get_identifier(format!("GEAR_{}_TILT_POSITION", contact_point_id)),
-            compression_id: context
-                .get_identifier(format!("GEAR ANIMATION POSITION:{}", contact_point_id)),
             wheel_speed_id: context.get_identifier(format!("WHEEL_RPM_{}", contact_point_id)),
 
             tilt_height_from_low_to_up,
@@ -86,7 +86,6 @@ impl TiltingGear {
             aft_contact_point_offset_from_datum_ref_meters,
             tilting_max_angle,
 
-            current_compression: Ratio::default(),
             tilt_position: Ratio::default(),
 
             wheel_speed: AngularVelocity::default(),
@@ -191,11 +190,15 @@ impl TiltingGear {
         };
     }
 
-    pub fn update(&mut self, context: &UpdateContext) {
+    /// Contact point offsets are given with strut fully extended.
+    /// Strut stroke lifts the bogie up towards the fuselage before the tilt mechanism has to absorb ground contact.
+    pub fn update(&mut self, context: &UpdateContext, strut_stroke: Length) {
         let fwd_current_tire_height =
-            height_over_ground(context, self.fwd_contact_point_offset_from_datum_ref_meters);
+            height_over_ground(context, self.fwd_contact_point_offset_from_datum_ref_meters)
+                + strut_stroke;
         let aft_current_tire_height =
-            height_over_ground(context, self.aft_contact_point_offset_from_datum_ref_meters);
+            height_over_ground(context, self.aft_contact_point_offset_from_datum_ref_meters)
+                + strut_stroke;
 
         self.update_physics(context, fwd_current_tire_height, aft_current_tire_height);
 
@@ -228,9 +231,183 @@ impl SimulationElement for TiltingGear {
             self.wheel_speed.get::<revolution_per_minute>(),
         );
     }
+}
 
-    fn read(&mut self, reader: &mut SimulatorReader) {
-        self.current_compression = reader.read(&self.compression_id);
+/// Oleo-pneumatic shock absorber of a landing gear strut.
+/// Nitrogen gas acts as the spring: the strut strokes until the gas force balances the strut load.
+/// Oil forced through the damping orifices limits how fast the strut can stroke.
+/// Strut load is derived from the simulator contact point compression, seen as a linear spring.
+pub struct ShockAbsorber {
+    stroke_id: VariableIdentifier,
+
+    max_stroke: Length,
+    piston_area: Area,
+    extended_gas_volume: Volume,
+    precharge_pressure: Pressure,
+    max_contact_load: Force,
+
+    load: Force,
+    stroke: Length,
+    is_initialised: bool,
+
+    under_serviced_failure: Failure,
+}
+impl ShockAbsorber {
+    const POLYTROPIC_INDEX: f64 = 1.3;
+
+    // Stroke speeds reached when the load unbalance equals the max contact load.
+    // Rebound orifices are smaller than compression ones
+    const COMPRESSION_SPEED_AT_MAX_UNBALANCE_M_S: f64 = 4.;
+    const EXTENSION_SPEED_AT_MAX_UNBALANCE_M_S: f64 = 1.5;
+
+    const UNDER_SERVICED_NITROGEN_CHARGE_RATIO: f64 = 0.35;
+    const UNDER_SERVICED_OIL_QUANTITY_RATIO: f64 = 0.7;
+
+    // Gas volume left at full stroke can't go below this ratio of the extended volume
+    const MIN_GAS_VOLUME_RATIO: f64 = 0.05;
+
+    // Weight on wheels proximity sensor target is reached after this ratio of the max stroke.
+    // Low enough for a static aircraft at light weight to still read compressed
+    const WEIGHT_ON_WHEELS_STROKE_THRESHOLD_RATIO: f64 = 0.02;
+
+    pub fn new(
+        context: &mut InitContext,
+        wheel_id: GearWheel,
+        max_stroke: Length,
+        piston_area: Area,
+        extended_gas_volume: Volume,
+        precharge_pressure: Pressure,
+        max_contact_load: Force,
+    ) -> Self {
+        Self {
+            stroke_id: context
+                .get_identifier(format!("GEAR_{}_STRUT_STROKE", Self::strut_name(wheel_id))),
+
+            max_stroke,
+            piston_area,
+            extended_gas_volume,
+            precharge_pressure,
+            max_contact_load,
+
+            load: Force::default(),
+            stroke: Length::default(),
+            is_initialised: false,
+
+            under_serviced_failure: Failure::new(FailureType::GearStrutUnderServiced(wheel_id)),
+        }
+    }
+
+    fn strut_name(wheel_id: GearWheel) -> &'static str {
+        match wheel_id {
+            GearWheel::NOSE => "CENTER",
+            GearWheel::LEFT => "LEFT",
+            GearWheel::RIGHT => "RIGHT",
+            GearWheel::WINGLEFT => "LEFT_WING",
+            GearWheel::WINGRIGHT => "RIGHT_WING",
+        }
+    }
+
+    pub fn update(&mut self, context: &UpdateContext, contact_compression: Ratio) {
+        self.load = self.max_contact_load * contact_compression.get::<ratio>().clamp(0., 1.);
+        let equilibrium_stroke = self.equilibrium_stroke(self.load);
+
+        // Strut starts settled on its equilibrium so init on ground is not seen as a touchdown
+        if !self.is_initialised {
+            self.stroke = equilibrium_stroke;
+            self.is_initialised = true;
+            return;
+        }
+
+        let load_unbalance =
+            (self.load - self.gas_force(self.stroke)).abs() / self.max_contact_load;
+
+        let speed_at_max_unbalance = if equilibrium_stroke > self.stroke {
+            Self::COMPRESSION_SPEED_AT_MAX_UNBALANCE_M_S
+        } else {
+            Self::EXTENSION_SPEED_AT_MAX_UNBALANCE_M_S
+        };
+
+        // Orifice damping force goes with the square of the stroke speed
+        let stroke_speed = speed_at_max_unbalance * load_unbalance.get::<ratio>().sqrt()
+            / self.oil_quantity().get::<ratio>().sqrt();
+        let max_stroke_variation = Length::new::<meter>(stroke_speed * context.delta_as_secs_f64());
+
+        self.stroke = if equilibrium_stroke > self.stroke {
+            (self.stroke + max_stroke_variation).min(equilibrium_stroke)
+        } else {
+            (self.stroke - max_stroke_variation).max(equilibrium_stroke)
+        };
+    }
+
+    fn nitrogen_charge(&self) -> Ratio {
+        if self.under_serviced_failure.is_active() {
+            Ratio::new::<ratio>(Self::UNDER_SERVICED_NITROGEN_CHARGE_RATIO)
+        } else {
+            Ratio::new::<ratio>(1.)
+        }
+    }
+
+    fn oil_quantity(&self) -> Ratio {
+        if self.under_serviced_failure.is_active() {
+            Ratio::new::<ratio>(Self::UNDER_SERVICED_OIL_QUANTITY_RATIO)
+        } else {
+            Ratio::new::<ratio>(1.)
+        }
+    }
+
+    fn preload(&self) -> Force {
+        self.precharge_pressure * self.nitrogen_charge() * self.piston_area
+    }
+
+    fn gas_force(&self, stroke: Length) -> Force {
+        let compressed_volume = (self.extended_gas_volume - self.piston_area * stroke)
+            .max(self.extended_gas_volume * Self::MIN_GAS_VOLUME_RATIO);
+
+        self.preload()
+            * (self.extended_gas_volume / compressed_volume)
+                .get::<ratio>()
+                .powf(Self::POLYTROPIC_INDEX)
+    }
+
+    fn equilibrium_stroke(&self, load: Force) -> Length {
+        if load <= self.preload() {
+            Length::default()
+        } else {
+            let stroke = self.extended_gas_volume / self.piston_area
+                * (1.
+                    - (self.preload() / load)
+                        .get::<ratio>()
+                        .powf(1. / Self::POLYTROPIC_INDEX));
+
+            stroke.min(self.max_stroke)
+        }
+    }
+
+    pub fn stroke(&self) -> Length {
+        self.stroke
+    }
+
+    pub fn compression(&self) -> Ratio {
+        self.stroke / self.max_stroke
+    }
+
+    pub fn is_bottomed(&self) -> bool {
+        self.stroke >= self.max_stroke
+    }
+
+    pub fn is_compressed(&self) -> bool {
+        self.compression() > Ratio::new::<ratio>(Self::WEIGHT_ON_WHEELS_STROKE_THRESHOLD_RATIO)
+    }
+}
+impl SimulationElement for ShockAbsorber {
+    fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
+        self.under_serviced_failure.accept(visitor);
+
+        visitor.visit(self);
+    }
+
+    fn write(&self, writer: &mut SimulatorWriter) {
+        writer.write(&self.stroke_id, self.stroke.get::<meter>());
     }
 }
 
@@ -238,7 +415,7 @@ impl SimulationElement for TiltingGear {
 /// Note that this type somewhat hides the gear's position.
 /// The real aircraft also can only check whether or not the gear is up and
 /// locked or down and locked. No in between state.
-/// It provides as well the state of all weight on wheel sensors
+/// It provides as well the state of all weight on wheel sensors, which sense the stroke of the shock absorbers
 pub struct LandingGear {
     center_compression_id: VariableIdentifier,
     left_compression_id: VariableIdentifier,
@@ -251,9 +428,13 @@ pub struct LandingGear {
     left_compression: Ratio,
     right_compression: Ratio,
 
-    has_auxiliary_wheels: bool,
     left_aux_compression: Ratio,
     right_aux_compression: Ratio,
+
+    nose_strut: ShockAbsorber,
+    left_strut: ShockAbsorber,
+    right_strut: ShockAbsorber,
+    auxiliary_struts: Option<[ShockAbsorber; 2]>,
 }
 impl LandingGear {
     pub const GEAR_CENTER_COMPRESSION: &'static str = "CONTACT POINT COMPRESSION";
@@ -262,10 +443,14 @@ impl LandingGear {
     pub const GEAR_LEFT_WING_COMPRESSION: &'static str = "CONTACT POINT COMPRESSION:3";
     pub const GEAR_RIGHT_WING_COMPRESSION: &'static str = "CONTACT POINT COMPRESSION:4";
 
-    // Compression is [0 .. 1], this var sets the threshold above which we are "compressed"
-    const COMPRESSION_THRESHOLD_FOR_WEIGHT_ON_WHEELS_RATIO: f64 = 0.01;
+    pub fn new(
+        context: &mut InitContext,
+        nose_strut: ShockAbsorber,
+        main_struts: [ShockAbsorber; 2],
+        auxiliary_struts: Option<[ShockAbsorber; 2]>,
+    ) -> Self {
+        let [left_strut, right_strut] = main_struts;
 
-    pub fn new(context: &mut InitContext, has_auxiliary_wheels: bool) -> Self {
         Self {
             center_compression_id: context.get_identifier(Self::GEAR_CENTER_COMPRESSION.to_owned()),
             left_compression_id: context.get_identifier(Self::GEAR_LEFT_COMPRESSION.to_owned()),
@@ -280,38 +465,67 @@ impl LandingGear {
             left_compression: Ratio::default(),
             right_compression: Ratio::default(),
 
-            has_auxiliary_wheels,
             left_aux_compression: Ratio::default(),
             right_aux_compression: Ratio::default(),
+
+            nose_strut,
+            left_strut,
+            right_strut,
+            auxiliary_struts,
         }
     }
 
-    fn is_wheel_id_compressed(&self, wheel_id: GearWheel) -> bool {
-        self.wheel_id_compression(wheel_id)
-            > Ratio::new::<ratio>(Self::COMPRESSION_THRESHOLD_FOR_WEIGHT_ON_WHEELS_RATIO)
+    pub fn update(&mut self, context: &UpdateContext) {
+        self.nose_strut.update(context, self.center_compression);
+        self.left_strut.update(context, self.left_compression);
+        self.right_strut.update(context, self.right_compression);
+
+        if let Some([left_aux_strut, right_aux_strut]) = &mut self.auxiliary_struts {
+            left_aux_strut.update(context, self.left_aux_compression);
+            right_aux_strut.update(context, self.right_aux_compression);
+        }
     }
 
-    fn wheel_id_compression(&self, wheel_id: GearWheel) -> Ratio {
-        match wheel_id {
-            GearWheel::NOSE => self.center_compression,
-            GearWheel::LEFT => self.left_compression,
-            GearWheel::RIGHT => self.right_compression,
-            GearWheel::WINGLEFT => self.left_aux_compression,
-            GearWheel::WINGRIGHT => self.right_aux_compression,
+    pub fn is_wheel_id_compressed(&self, wheel_id: GearWheel) -> bool {
+        self.strut(wheel_id).is_compressed()
+    }
+
+    pub fn strut_stroke(&self, wheel_id: GearWheel) -> Length {
+        self.strut(wheel_id).stroke()
+    }
+
+    // If no auxiliary gears, auxiliary are just a copy of main gears
+    fn strut(&self, wheel_id: GearWheel) -> &ShockAbsorber {
+        match (wheel_id, &self.auxiliary_struts) {
+            (GearWheel::NOSE, _) => &self.nose_strut,
+            (GearWheel::LEFT, _) | (GearWheel::WINGLEFT, None) => &self.left_strut,
+            (GearWheel::RIGHT, _) | (GearWheel::WINGRIGHT, None) => &self.right_strut,
+            (GearWheel::WINGLEFT, Some([left_aux_strut, _])) => left_aux_strut,
+            (GearWheel::WINGRIGHT, Some([_, right_aux_strut])) => right_aux_strut,
         }
     }
 }
 impl SimulationElement for LandingGear {
+    fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
+        self.nose_strut.accept(visitor);
+        self.left_strut.accept(visitor);
+        self.right_strut.accept(visitor);
+
+        if let Some(auxiliary_struts) = &mut self.auxiliary_struts {
+            for strut in auxiliary_struts {
+                strut.accept(visitor);
+            }
+        }
+
+        visitor.visit(self);
+    }
+
     fn read(&mut self, reader: &mut SimulatorReader) {
         self.center_compression = reader.read(&self.center_compression_id);
         self.left_compression = reader.read(&self.left_compression_id);
         self.right_compression = reader.read(&self.right_compression_id);
 
-        // If no auxiliary gears, auxiliary are just a copy of main gears
-        if !self.has_auxiliary_wheels {
-            self.left_aux_compression = self.left_compression;
-            self.right_aux_compression = self.right_compression;
-        } else {
+        if self.auxiliary_struts.is_some() {
             self.left_aux_compression = reader.read(&self.left_auxiliary_compression_id);
             self.right_aux_compression = reader.read(&self.right_auxiliary_compression_id);
         }
@@ -325,6 +539,49 @@ fn lgciu_number(lgciu_id: LgciuId) -> u8 {
     }
 }
 
+fn other_lgciu_id(lgciu_id: LgciuId) -> LgciuId {
+    match lgciu_id {
+        LgciuId::Lgciu1 => LgciuId::Lgciu2,
+        LgciuId::Lgciu2 => LgciuId::Lgciu1,
+    }
+}
+
+/// Monitors the up and down proximity sensors of one gear or door seen by one lgciu channel.
+/// Readings are consistent when exactly one locked position is detected, none being detected only while in transit.
+/// A sensor disagreeing with the other channel is isolated once its own channel stays inconsistent
+/// for longer than a transition.
+#[derive(Default)]
+struct ProximitySensorPairMonitor {
+    inconsistent_duration: Duration,
+    up_sensor_isolated: bool,
+    down_sensor_isolated: bool,
+}
+impl ProximitySensorPairMonitor {
+    const TRANSITION_TIMEOUT: Duration = Duration::from_secs(10);
+
+    fn update(
+        &mut self,
+        context: &UpdateContext,
+        (up_detected, down_detected): (bool, bool),
+        (other_channel_up_detected, other_channel_down_detected): (bool, bool),
+    ) {
+        if up_detected == down_detected {
+            self.inconsistent_duration += context.delta();
+        } else {
+            self.inconsistent_duration = Duration::default();
+        }
+
+        if self.inconsistent_duration > Self::TRANSITION_TIMEOUT {
+            self.up_sensor_isolated |= up_detected != other_channel_up_detected;
+            self.down_sensor_isolated |= down_detected != other_channel_down_detected;
+        }
+    }
+
+    fn any_sensor_isolated(&self) -> bool {
+        self.up_sensor_isolated || self.down_sensor_isolated
+    }
+}
+
 struct LgciuSensorInputs {
     lgciu_id: LgciuId,
 
@@ -351,11 +608,27 @@ struct LgciuSensorInputs {
     right_door_up_and_locked: bool,
     left_door_up_and_locked: bool,
 
+    nose_gear_damaged: bool,
+    right_gear_damaged: bool,
+    left_gear_damaged: bool,
+
+    nose_door_damaged: bool,
+    right_door_damaged: bool,
+    left_door_damaged: bool,
+
+    // Indexed by lgciu channel then by nose, left and right gear
+    gear_sensor_monitors: [[ProximitySensorPairMonitor; 3]; 2],
+    door_sensor_monitors: [[ProximitySensorPairMonitor; 3]; 2],
+    proximity_sensor_disagree_detected: bool,
+    proximity_sensor_disagree_duration: Duration,
+
     nose_gear_compressed_id: VariableIdentifier,
     left_gear_compressed_id: VariableIdentifier,
     right_gear_compressed_id: VariableIdentifier,
 }
 impl LgciuSensorInputs {
+    const PROXIMITY_SENSOR_DISAGREE_CONFIRMATION_TIME: Duration = Duration::from_secs(1);
+
     fn new(context: &mut InitContext, lgciu_id: LgciuId) -> Self {
         Self {
             lgciu_id,
@@ -380,6 +653,18 @@ impl LgciuSensorInputs {
             right_door_up_and_locked: false,
             left_door_up_and_locked: false,
 
+            nose_gear_damaged: false,
+            right_gear_damaged: false,
+            left_gear_damaged: false,
+            nose_door_damaged: false,
+            right_door_damaged: false,
+            left_door_damaged: false,
+
+            gear_sensor_monitors: Default::default(),
+            door_sensor_monitors: Default::default(),
+            proximity_sensor_disagree_detected: false,
+            proximity_sensor_disagree_duration: Duration::default(),
+
             nose_gear_compressed_id: context.get_identifier(format!(
                 "LGCIU_{}_NOSE_GEAR_COMPRESSED",
                 lgciu_number(lgciu_id)
@@ -395,10 +680,11 @@ impl LgciuSensorInputs {
         }
     }
 
-    pub fn update(
+    pub fn update<T: GearSystemSensors>(
         &mut self,
+        context: &UpdateContext,
         landing_gear: &LandingGear,
-        gear_system_sensors: &impl GearSystemSensors,
+        gear_system_sensors: &T,
         external_power_available: bool,
         is_powered: bool,
     ) {
@@ -411,32 +697,187 @@ impl LgciuSensorInputs {
         self.right_gear_sensor_compressed = landing_gear.is_wheel_id_compressed(GearWheel::RIGHT)
             || landing_gear.is_wheel_id_compressed(GearWheel::WINGRIGHT);
 
-        self.right_gear_up_and_locked =
-            gear_system_sensors.is_wheel_id_up_and_locked(GearWheel::RIGHT, self.lgciu_id);
-        self.left_gear_up_and_locked =
-            gear_system_sensors.is_wheel_id_up_and_locked(GearWheel::LEFT, self.lgciu_id);
-        self.nose_gear_up_and_locked =
-            gear_system_sensors.is_wheel_id_up_and_locked(GearWheel::NOSE, self.lgciu_id);
-
-        self.right_gear_down_and_locked =
-            gear_system_sensors.is_wheel_id_down_and_locked(GearWheel::RIGHT, self.lgciu_id);
-        self.left_gear_down_and_locked =
-            gear_system_sensors.is_wheel_id_down_and_locked(GearWheel::LEFT, self.lgciu_id);
-        self.nose_gear_down_and_locked =
-            gear_system_sensors.is_wheel_id_down_and_locked(GearWheel::NOSE, self.lgciu_id);
-
-        self.nose_door_fully_opened =
-            gear_system_sensors.is_door_id_down_and_locked(GearWheel::NOSE, self.lgciu_id);
-        self.right_door_fully_opened =
-            gear_system_sensors.is_door_id_down_and_locked(GearWheel::RIGHT, self.lgciu_id);
-        self.left_door_fully_opened =
-            gear_system_sensors.is_door_id_down_and_locked(GearWheel::LEFT, self.lgciu_id);
-        self.nose_door_up_and_locked =
-            gear_system_sensors.is_door_id_up_and_locked(GearWheel::NOSE, self.lgciu_id);
-        self.right_door_up_and_locked =
-            gear_system_sensors.is_door_id_up_and_locked(GearWheel::RIGHT, self.lgciu_id);
-        self.left_door_up_and_locked =
-            gear_system_sensors.is_door_id_up_and_locked(GearWheel::LEFT, self.lgciu_id);
+        // Isolation is cleared by the power up BITE
+        if !is_powered {
+            self.gear_sensor_monitors = Default::default();
+            self.door_sensor_monitors = Default::default();
+        }
+
+        let mut any_sensor_disagree = false;
+
+        for wheel_id in [GearWheel::NOSE, GearWheel::LEFT, GearWheel::RIGHT] {
+            let (gear_up_and_locked, gear_up_disagree, gear_down_and_locked, gear_down_disagree) =
+                Self::read_proximity_sensor_pair(
+                    context,
+                    &mut self.gear_sensor_monitors,
+                    self.lgciu_id,
+                    wheel_id,
+                    |lgciu_id| {
+                        (
+                            gear_system_sensors.is_wheel_id_up_and_locked(wheel_id, lgciu_id),
+                            gear_system_sensors.is_wheel_id_down_and_locked(wheel_id, lgciu_id),
+                        )
+                    },
+                );
+            let (door_up_and_locked, door_up_disagree, door_fully_opened, door_opened_disagree) =
+                Self::read_proximity_sensor_pair(
+                    context,
+                    &mut self.door_sensor_monitors,
+                    self.lgciu_id,
+                    wheel_id,
+                    |lgciu_id| {
+                        (
+                            gear_system_sensors.is_door_id_up_and_locked(wheel_id, lgciu_id),
+                            gear_system_sensors.is_door_id_down_and_locked(wheel_id, lgciu_id),
+                        )
+                    },
+                );
+
+            any_sensor_disagree |=
+                gear_up_disagree || gear_down_disagree || door_up_disagree || door_opened_disagree;
+
+            match wheel_id {
+                GearWheel::NOSE => {
+                    self.nose_gear_up_and_locked = gear_up_and_locked;
+                    self.nose_gear_down_and_locked = gear_down_and_locked;
+                    self.nose_door_up_and_locked = door_up_and_locked;
+                    self.nose_door_fully_opened = door_fully_opened;
+                }
+                GearWheel::LEFT => {
+                    self.left_gear_up_and_locked = gear_up_and_locked;
+                    self.left_gear_down_and_locked = gear_down_and_locked;
+                    self.left_door_up_and_locked = door_up_and_locked;
+                    self.left_door_fully_opened = door_fully_opened;
+                }
+                GearWheel::RIGHT => {
+                    self.right_gear_up_and_locked = gear_up_and_locked;
+                    self.right_gear_down_and_locked = gear_down_and_locked;
+                    self.right_door_up_and_locked = door_up_and_locked;
+                    self.right_door_fully_opened = door_fully_opened;
+                }
+                // TODO gear not implemented, wing bogey is copied from body gear state
+                GearWheel::WINGLEFT | GearWheel::WINGRIGHT => {}
+            }
+        }
+
+        if any_sensor_disagree {
+            self.proximity_sensor_disagree_duration += context.delta();
+        } else {
+            self.proximity_sensor_disagree_duration = Duration::default();
+        }
+        self.proximity_sensor_disagree_detected = self.proximity_sensor_disagree_duration
+            > Self::PROXIMITY_SENSOR_DISAGREE_CONFIRMATION_TIME;
+
+        self.nose_gear_damaged = gear_system_sensors.is_wheel_id_damaged(GearWheel::NOSE);
+        self.right_gear_damaged = gear_system_sensors.is_wheel_id_damaged(GearWheel::RIGHT);
+        self.left_gear_damaged = gear_system_sensors.is_wheel_id_damaged(GearWheel::LEFT);
+        self.nose_door_damaged = gear_system_sensors.is_door_id_damaged(GearWheel::NOSE);
+        self.right_door_damaged = gear_system_sensors.is_door_id_damaged(GearWheel::RIGHT);
+        self.left_door_damaged = gear_system_sensors.is_door_id_damaged(GearWheel::LEFT);
+    }
+
+    /// Reads the up and down proximity sensors of a gear or door on both channels and monitors their consistency.
+    /// A sensor isolated on own channel is replaced by the other channel value so one faulty sensor
+    /// does not prevent gear operation.
+    /// Returns up state, up disagree, down state and down disagree, disagree being only reported
+    /// between non isolated sensors.
+    fn read_proximity_sensor_pair(
+        context: &UpdateContext,
+        monitors: &mut [[ProximitySensorPairMonitor; 3]; 2],
+        lgciu_id: LgciuId,
+        wheel_id: GearWheel,
+        sensors_state: impl Fn(LgciuId) -> (bool, bool),
+    ) -> (bool, bool, bool, bool) {
+        let other_lgciu_id = other_lgciu_id(lgciu_id);
+        let wheel_index = Self::monitored_wheel_index(wheel_id);
+
+        let own_state = sensors_state(lgciu_id);
+        let other_state = sensors_state(other_lgciu_id);
+
+        monitors[lgciu_id as usize][wheel_index].update(context, own_state, other_state);
+        monitors[other_lgciu_id as usize][wheel_index].update(context, other_state, own_state);
+
+        let own_monitor = &monitors[lgciu_id as usize][wheel_index];
+        let other_monitor = &monitors[other_lgciu_id as usize][wheel_index];
+
+        let (up_state, up_disagree) = Self::voted_sensor_state(
+            own_state.0,
+            own_monitor.up_sensor_isolated,
+            other_state.0,
+            other_monitor.up_sensor_isolated,
+        );
+        let (down_state, down_disagree) = Self::voted_sensor_state(
+            own_state.1,
+            own_monitor.down_sensor_isolated,
+            other_state.1,
+            other_monitor.down_sensor_isolated,
+        );
+
+        (up_state, up_disagree, down_state, down_disagree)
+    }
+
+    fn voted_sensor_state(
+        own_state: bool,
+        own_isolated: bool,
+        other_state: bool,
+        other_isolated: bool,
+    ) -> (bool, bool) {
+        if own_isolated && !other_isolated {
+            (other_state, false)
+        } else {
+            (
+                own_state,
+                !own_isolated && !other_isolated && own_state != other_state,
+            )
+        }
+    }
+
+    fn monitored_wheel_index(wheel_id: GearWheel) -> usize {
+        match wheel_id {
+            GearWheel::NOSE => 0,
+            GearWheel::LEFT | GearWheel::WINGLEFT => 1,
+            GearWheel::RIGHT | GearWheel::WINGRIGHT => 2,
+        }
+    }
+
+    fn gear_sensor_monitor(
+        &self,
+        lgciu_id: LgciuId,
+        wheel_id: GearWheel,
+    ) -> &ProximitySensorPairMonitor {
+        &self.gear_sensor_monitors[lgciu_id as usize][Self::monitored_wheel_index(wheel_id)]
+    }
+
+    fn door_sensor_monitor(
+        &self,
+        lgciu_id: LgciuId,
+        wheel_id: GearWheel,
+    ) -> &ProximitySensorPairMonitor {
+        &self.door_sensor_monitors[lgciu_id as usize][Self::monitored_wheel_index(wheel_id)]
+    }
+
+    fn any_sensor_isolated(&self, lgciu_id: LgciuId) -> bool {
+        self.gear_sensor_monitors[lgciu_id as usize]
+            .iter()
+            .chain(self.door_sensor_monitors[lgciu_id as usize].iter())
+            .any(|monitor| monitor.any_sensor_isolated())
+    }
+
+    fn proximity_sensor_fault(&self) -> bool {
+        self.is_powered && self.any_sensor_isolated(self.lgciu_id)
+    }
+
+    fn proximity_sensor_disagree(&self) -> bool {
+        self.is_powered && self.proximity_sensor_disagree_detected
+    }
+
+    fn any_structural_damage(&self) -> bool {
+        self.nose_gear_damaged
+            || self.right_gear_damaged
+            || self.left_gear_damaged
+            || self.nose_door_damaged
+            || self.right_door_damaged
+            || self.left_door_damaged
     }
 
     fn unlock_state(&self, wheel_id: GearWheel, gear_lever_is_down: bool) -> bool {
@@ -603,7 +1044,9 @@ impl LandingGearControlCoordinator {
         let lgciu_should_switch_because_is_failed =
             match lgcius_status[self.active_lgciu_id as usize] {
                 LgciuStatus::Ok | LgciuStatus::FailedNoChangeOver => false,
-                LgciuStatus::FailedNotPowered | LgciuStatus::FailedAutoChangeOver => true,
+                LgciuStatus::SelfTest
+                | LgciuStatus::FailedNotPowered
+                | LgciuStatus::FailedAutoChangeOver => true,
             };
 
         if lgciu_should_switch_at_new_up_cycle
@@ -865,6 +1308,7 @@ impl SimulationElement for LandingGearHandleUnit {
 #[derive(PartialEq, Clone, Copy, Debug)]
 enum LgciuStatus {
     Ok,
+    SelfTest,
     FailedNotPowered,
     FailedNoChangeOver,
     FailedAutoChangeOver,
@@ -883,8 +1327,14 @@ pub struct LandingGearControlInterfaceUnit {
     discrete_word_3_id: VariableIdentifier,
     discrete_word_4_id: VariableIdentifier,
 
+    self_test_in_progress_id: VariableIdentifier,
+    proximity_sensor_fault_id: VariableIdentifier,
+    proximity_sensor_disagree_id: VariableIdentifier,
+    maintenance_word_id: VariableIdentifier,
+
     is_powered: bool,
     is_powered_previous_state: bool,
+    is_first_update: bool,
 
     powered_by: ElectricalBusType,
     external_power_available: bool,
@@ -896,6 +1346,7 @@ pub struct LandingGearControlInterfaceUnit {
     transition_duration: Duration,
     gear_lever_position_is_down_previous_state: bool,
     status: LgciuStatus,
+    self_test_remaining: Duration,
 
     power_supply_failure: Failure,
     internal_error_failure: Failure,
@@ -907,6 +1358,7 @@ pub struct LandingGearControlInterfaceUnit {
 }
 impl LandingGearControlInterfaceUnit {
     const MAX_TRANSITION_DURATION: Duration = Duration::from_secs(30);
+    const POWER_UP_SELF_TEST_DURATION: Duration = Duration::from_secs(3);
 
     pub fn new(
         context: &mut InitContext,
@@ -948,8 +1400,22 @@ impl LandingGearControlInterfaceUnit {
             discrete_word_4_id: context
                 .get_identifier(format!("LGCIU_{}_DISCRETE_WORD_4", lgciu_number(lgciu_id))),
 
+            self_test_in_progress_id: context
+                .get_identifier(format!("LGCIU_{}_BITE_IN_PROGRESS", lgciu_number(lgciu_id))),
+            proximity_sensor_fault_id: context.get_identifier(format!(
+                "LGCIU_{}_PROX_SENSOR_FAULT",
+                lgciu_number(lgciu_id)
+            )),
+            proximity_sensor_disagree_id: context.get_identifier(format!(
+                "LGCIU_{}_PROX_SENSOR_DISAGREE",
+                lgciu_number(lgciu_id)
+            )),
+            maintenance_word_id: context
+                .get_identifier(format!("LGCIU_{}_MAINTENANCE_WORD", lgciu_number(lgciu_id))),
+
             is_powered: false,
             is_powered_previous_state: false,
+            is_first_update: true,
 
             powered_by,
             external_power_available: false,
@@ -961,6 +1427,7 @@ impl LandingGearControlInterfaceUnit {
             transition_duration: Duration::default(),
             gear_lever_position_is_down_previous_state: true,
             status: LgciuStatus::Ok,
+            self_test_remaining: Duration::default(),
 
             power_supply_failure: Failure::new(FailureType::LgciuPowerSupply(lgciu_id)),
             internal_error_failure: Failure::new(FailureType::LgciuInternalError(lgciu_id)),
@@ -985,6 +1452,7 @@ impl LandingGearControlInterfaceUnit {
         self.external_power_available = external_power_available;
 
         self.sensor_inputs.update(
+            context,
             landing_gear,
             gear_system_sensors,
             external_power_available,
@@ -1002,6 +1470,8 @@ impl LandingGearControlInterfaceUnit {
 
             if !self.is_powered_previous_state {
                 self.actions_when_startup();
+            } else if self.self_test_remaining > Duration::ZERO {
+                self.update_self_test(context);
             }
 
             if is_master_computer {
@@ -1024,6 +1494,7 @@ impl LandingGearControlInterfaceUnit {
 
         self.is_active_computer_previous_state = is_master_computer;
         self.is_powered_previous_state = self.is_powered;
+        self.is_first_update = false;
     }
 
     fn update_monitoring(&mut self, context: &UpdateContext, gear_handle: &impl LandingGearHandle) {
@@ -1082,6 +1553,20 @@ impl LandingGearControlInterfaceUnit {
     fn actions_when_startup(&mut self) {
         self.reset_fault_timers();
         self.compute_init_gear_state();
+
+        // Power up BITE is skipped on simulation init so lgcius are readily available
+        if !self.is_first_update {
+            self.self_test_remaining = Self::POWER_UP_SELF_TEST_DURATION;
+        }
+    }
+
+    fn update_self_test(&mut self, context: &UpdateContext) {
+        self.self_test_remaining = self.self_test_remaining.saturating_sub(context.delta());
+
+        // Gear may have moved under the other lgciu control during the test
+        if self.self_test_remaining == Duration::ZERO {
+            self.compute_init_gear_state();
+        }
     }
 
     fn compute_init_gear_state(&mut self) {
@@ -1131,12 +1616,20 @@ impl LandingGearControlInterfaceUnit {
         self.transition_duration = Duration::default();
     }
 
+    fn self_test_in_progress(&self) -> bool {
+        self.is_powered && self.self_test_remaining > Duration::ZERO
+    }
+
     pub fn gear_system_state(&self) -> GearSystemState {
         self.gear_system_control.state()
     }
 
     fn status(&self) -> LgciuStatus {
-        self.status
+        if self.self_test_in_progress() {
+            LgciuStatus::SelfTest
+        } else {
+            self.status
+        }
     }
 
     pub fn discrete_word_1(&self) -> Arinc429Word<u32> {
@@ -1202,7 +1695,7 @@ impl LandingGearControlInterfaceUnit {
                     && self.sensor_inputs.right_gear_down_and_locked,
             );
             // Control fault (before version 4D, control fault + proximity sensor disagree)
-            word.set_bit(29, false);
+            word.set_bit(29, self.sensor_inputs.proximity_sensor_disagree());
 
             word
         }
@@ -1217,6 +1710,12 @@ impl LandingGearControlInterfaceUnit {
             word.set_bit(12, !self.sensor_inputs.right_gear_up_and_locked);
             word.set_bit(13, !self.sensor_inputs.nose_gear_up_and_locked);
             word.set_bit(14, !self.gear_handle_is_down());
+            word.set_bit(15, self.sensor_inputs.left_gear_damaged);
+            word.set_bit(16, self.sensor_inputs.right_gear_damaged);
+            word.set_bit(17, self.sensor_inputs.nose_gear_damaged);
+            word.set_bit(18, self.sensor_inputs.left_door_damaged);
+            word.set_bit(19, self.sensor_inputs.right_door_damaged);
+            word.set_bit(20, self.sensor_inputs.nose_door_damaged);
             word.set_bit(25, self.sensor_inputs.left_door_fully_opened);
             word.set_bit(26, self.sensor_inputs.right_door_fully_opened);
             word.set_bit(27, self.sensor_inputs.nose_door_fully_opened);
@@ -1250,7 +1749,44 @@ impl LandingGearControlInterfaceUnit {
             word.set_bit(26, true);
 
             // SYS fault (for version 4D and later)
-            word.set_bit(29, self.status() != LgciuStatus::Ok);
+            word.set_bit(
+                29,
+                !matches!(self.status(), LgciuStatus::Ok | LgciuStatus::SelfTest)
+                    || self.sensor_inputs.any_structural_damage()
+                    || self.sensor_inputs.proximity_sensor_fault(),
+            );
+
+            word
+        }
+    }
+
+    /// BITE output identifying the proximity sensors isolated on this lgciu channel
+    pub fn maintenance_word(&self) -> Arinc429Word<u32> {
+        if !self.is_powered {
+            Arinc429Word::new(0, SignStatus::FailureWarning)
+        } else {
+            let lgciu_id = self.sensor_inputs.lgciu_id;
+            let mut word = Arinc429Word::new(0, SignStatus::NormalOperation);
+
+            for (bit_offset, wheel_id) in [GearWheel::LEFT, GearWheel::RIGHT, GearWheel::NOSE]
+                .into_iter()
+                .enumerate()
+            {
+                let gear_monitor = self.sensor_inputs.gear_sensor_monitor(lgciu_id, wheel_id);
+                let door_monitor = self.sensor_inputs.door_sensor_monitor(lgciu_id, wheel_id);
+
+                word.set_bit(11 + bit_offset as u8, gear_monitor.up_sensor_isolated);
+                word.set_bit(14 + bit_offset as u8, gear_monitor.down_sensor_isolated);
+                word.set_bit(17 + bit_offset as u8, door_monitor.up_sensor_isolated);
+                word.set_bit(20 + bit_offset as u8, door_monitor.down_sensor_isolated);
+            }
+
+            // Cross channel monitoring also isolates the other lgciu sensors
+            word.set_bit(
+                28,
+                self.sensor_inputs
+                    .any_sensor_isolated(other_lgciu_id(lgciu_id)),
+            );
 
             word
         }
@@ -1311,6 +1847,17 @@ impl SimulationElement for LandingGearControlInterfaceUnit {
         writer.write(&self.discrete_word_2_id, self.discrete_word_2());
         writer.write(&self.discrete_word_3_id, self.discrete_word_3());
         writer.write(&self.discrete_word_4_id, self.discrete_word_4());
+        writer.write(&self.maintenance_word_id, self.maintenance_word());
+
+        writer.write(&self.self_test_in_progress_id, self.self_test_in_progress());
+        writer.write(
+            &self.proximity_sensor_fault_id,
+            self.sensor_inputs.proximity_sensor_fault(),
+        );
+        writer.write(
+            &self.proximity_sensor_disagree_id,
+            self.sensor_inputs.proximity_sensor_disagree(),
+        );
     }
 }
 
@@ -1396,9 +1943,11 @@ impl LgciuGearControl for LandingGearControlInterfaceUnit {
             || (self.gear_handle_is_down()
                 && self.gear_system_state() == GearSystemState::AllDownLocked);
 
-        match self.status {
+        match self.status() {
             LgciuStatus::Ok | LgciuStatus::FailedNoChangeOver => !stop_hyd_control,
-            LgciuStatus::FailedNotPowered | LgciuStatus::FailedAutoChangeOver => false,
+            LgciuStatus::SelfTest
+            | LgciuStatus::FailedNotPowered
+            | LgciuStatus::FailedAutoChangeOver => false,
         }
     }
 }
@@ -1559,11 +2108,20 @@ mod tests {
     use crate::electrical::{test::TestElectricitySource, ElectricalBus, Electricity};
     use crate::shared::PotentialOrigin;
 
-    use uom::si::{electric_potential::volt, pressure::psi};
+    use uom::si::{
+        area::square_meter,
+        electric_potential::volt,
+        force::newton,
+        pressure::{megapascal, psi},
+        volume::cubic_meter,
+    };
 
     struct TestGearSystem {
         door_position: u8,
         gear_position: u8,
+        damaged_gear: Option<GearWheel>,
+        failed_sensor: Option<(GearWheel, LgciuId)>,
+        stuck_sensor: Option<(GearWheel, LgciuId)>,
     }
     impl TestGearSystem {
         const UP_LOCK_TRESHOLD: u8 = 10;
@@ -1572,6 +2130,19 @@ mod tests {
             Self {
                 door_position: Self::UP_LOCK_TRESHOLD,
                 gear_position: 1,
+                damaged_gear: None,
+                failed_sensor: None,
+                stuck_sensor: None,
+            }
+        }
+
+        fn sensor_reading(&self, wheel_id: GearWheel, lgciu_id: LgciuId, state: bool) -> bool {
+            if self.failed_sensor == Some((wheel_id, lgciu_id)) {
+                false
+            } else if self.stuck_sensor == Some((wheel_id, lgciu_id)) {
+                true
+            } else {
+                state
             }
         }
 
@@ -1604,12 +2175,16 @@ mod tests {
         }
     }
     impl GearSystemSensors for TestGearSystem {
-        fn is_wheel_id_up_and_locked(&self, _: GearWheel, _: LgciuId) -> bool {
-            self.gear_position >= Self::UP_LOCK_TRESHOLD
+        fn is_wheel_id_up_and_locked(&self, wheel_id: GearWheel, lgciu_id: LgciuId) -> bool {
+            self.sensor_reading(
+                wheel_id,
+                lgciu_id,
+                self.gear_position >= Self::UP_LOCK_TRESHOLD,
+            )
         }
 
-        fn is_wheel_id_down_and_locked(&self, _: GearWheel, _: LgciuId) -> bool {
-            self.gear_position <= 1
+        fn is_wheel_id_down_and_locked(&self, wheel_id: GearWheel, lgciu_id: LgciuId) -> bool {
+            self.sensor_reading(wheel_id, lgciu_id, self.gear_position <= 1)
         }
 
         fn is_door_id_up_and_locked(&self, _: GearWheel, _: LgciuId) -> bool {
@@ -1619,6 +2194,14 @@ mod tests {
         fn is_door_id_down_and_locked(&self, _: GearWheel, _: LgciuId) -> bool {
             self.door_position <= 1
         }
+
+        fn is_wheel_id_damaged(&self, wheel_id: GearWheel) -> bool {
+            self.damaged_gear == Some(wheel_id)
+        }
+
+        fn is_door_id_damaged(&self, _: GearWheel) -> bool {
+            false
+        }
     }
 
     struct TestGearAircraft {
@@ -1633,7 +2216,7 @@ mod tests {
     impl TestGearAircraft {
         fn new(context: &mut InitContext) -> Self {
             Self {
-                landing_gear: LandingGear::new(context, false),
+                landing_gear: test_landing_gear(context),
                 lgcius: LandingGearControlInterfaceUnitSet::new(
                     context,
                     ElectricalBusType::DirectCurrentEssential,
@@ -1652,6 +2235,8 @@ mod tests {
         }
 
         fn update(&mut self, context: &UpdateContext) {
+            self.landing_gear.update(context);
+
             self.lgcius
                 .update(context, &self.landing_gear, &self.gear_system, false);
 
@@ -1674,6 +2259,18 @@ mod tests {
         fn set_no_pressure(&mut self) {
             self.pressure = Pressure::new::<psi>(0.);
         }
+
+        fn damage_gear(&mut self, wheel_id: GearWheel) {
+            self.gear_system.damaged_gear = Some(wheel_id);
+        }
+
+        fn fail_gear_sensor(&mut self, wheel_id: GearWheel, lgciu_id: LgciuId) {
+            self.gear_system.failed_sensor = Some((wheel_id, lgciu_id));
+        }
+
+        fn stuck_gear_sensor(&mut self, wheel_id: GearWheel, lgciu_id: LgciuId) {
+            self.gear_system.stuck_sensor = Some((wheel_id, lgciu_id));
+        }
     }
     impl Aircraft for TestGearAircraft {
         fn update_before_power_distribution(
@@ -1757,6 +2354,22 @@ mod tests {
         fn fail_hyd_pressure(&mut self) {
             self.command(|a| a.set_no_pressure());
         }
+
+        fn is_self_test_in_progress(&mut self, lgciu_number: u8) -> bool {
+            self.read_by_name(&format!("LGCIU_{}_BITE_IN_PROGRESS", lgciu_number))
+        }
+
+        fn is_proximity_sensor_fault(&mut self, lgciu_number: u8) -> bool {
+            self.read_by_name(&format!("LGCIU_{}_PROX_SENSOR_FAULT", lgciu_number))
+        }
+
+        fn is_proximity_sensor_disagree(&mut self, lgciu_number: u8) -> bool {
+            self.read_by_name(&format!("LGCIU_{}_PROX_SENSOR_DISAGREE", lgciu_number))
+        }
+
+        fn is_left_gear_downlocked(&mut self, lgciu_number: u8) -> bool {
+            self.read_by_name(&format!("LGCIU_{}_LEFT_GEAR_DOWNLOCKED", lgciu_number))
+        }
     }
     impl TestBed for LgciusTestBed {
         type Aircraft = TestGearAircraft;
@@ -1817,6 +2430,12 @@ mod tests {
         assert!(test_bed.contains_variable_with_name("LGCIU_2_DISCRETE_WORD_3"));
         assert!(test_bed.contains_variable_with_name("LGCIU_1_DISCRETE_WORD_4"));
         assert!(test_bed.contains_variable_with_name("LGCIU_2_DISCRETE_WORD_4"));
+        assert!(test_bed.contains_variable_with_name("LGCIU_1_BITE_IN_PROGRESS"));
+        assert!(test_bed.contains_variable_with_name("LGCIU_2_BITE_IN_PROGRESS"));
+        assert!(test_bed.contains_variable_with_name("LGCIU_1_PROX_SENSOR_FAULT"));
+        assert!(test_bed.contains_variable_with_name("LGCIU_2_PROX_SENSOR_FAULT"));
+        assert!(test_bed.contains_variable_with_name("LGCIU_1_PROX_SENSOR_DISAGREE"));
+        assert!(test_bed.contains_variable_with_name("LGCIU_2_PROX_SENSOR_DISAGREE"));
     }
 
     #[test]
@@ -2070,6 +2689,18 @@ mod tests {
 
         test_bed.unfail(FailureType::LgciuPowerSupply(LgciuId::Lgciu2));
 
+        // Lgciu 2 is not available until its power up BITE is completed
+        test_bed = test_bed.run_one_tick();
+        assert_eq!(
+            test_bed.query(|a| a.lgcius.lgciu2().status()),
+            LgciuStatus::SelfTest
+        );
+        test_bed.run_with_delta(Duration::from_secs(4));
+        assert_eq!(
+            test_bed.query(|a| a.lgcius.lgciu2().status()),
+            LgciuStatus::Ok
+        );
+
         test_bed = test_bed.set_gear_handle_down().run_one_tick();
         assert_eq!(
             test_bed.query(|a| a.lgcius.active_lgciu_id()),
@@ -2111,11 +2742,222 @@ mod tests {
         );
     }
 
+    #[test]
+    fn lgciu_flags_damaged_gear_in_discrete_words() {
+        let mut test_bed = test_bed_on_ground_with().on_the_ground().run_one_tick();
+
+        assert!(!test_bed.query(|a| a.lgcius.lgciu1().discrete_word_3().get_bit(15)));
+        assert!(!test_bed.query(|a| a.lgcius.lgciu1().discrete_word_4().get_bit(29)));
+
+        test_bed.command(|a| a.damage_gear(GearWheel::LEFT));
+        test_bed = test_bed.run_one_tick();
+
+        assert!(test_bed.query(|a| a.lgcius.lgciu1().discrete_word_3().get_bit(15)));
+        assert!(!test_bed.query(|a| a.lgcius.lgciu1().discrete_word_3().get_bit(16)));
+        assert!(!test_bed.query(|a| a.lgcius.lgciu1().discrete_word_3().get_bit(17)));
+        assert!(test_bed.query(|a| a.lgcius.lgciu2().discrete_word_3().get_bit(15)));
+        assert!(test_bed.query(|a| a.lgcius.lgciu1().discrete_word_4().get_bit(29)));
+    }
+
+    #[test]
+    fn lgciu_no_self_test_on_simulation_start() {
+        let mut test_bed = test_bed_on_ground_with().on_the_ground().run_one_tick();
+
+        assert_eq!(
+            test_bed.query(|a| a.lgcius.lgciu1().status()),
+            LgciuStatus::Ok
+        );
+        assert_eq!(
+            test_bed.query(|a| a.lgcius.lgciu2().status()),
+            LgciuStatus::Ok
+        );
+        assert!(!test_bed.is_self_test_in_progress(1));
+        assert!(!test_bed.is_self_test_in_progress(2));
+    }
+
+    #[test]
+    fn lgciu_runs_self_test_on_power_up_and_hands_over_control() {
+        let mut test_bed = test_bed_in_flight_with()
+            .in_flight()
+            .set_gear_handle_down()
+            .run_one_tick();
+
+        assert_eq!(
+            test_bed.query(|a| a.lgcius.active_lgciu_id()),
+            LgciuId::Lgciu1
+        );
+
+        test_bed.fail(FailureType::LgciuPowerSupply(LgciuId::Lgciu1));
+        test_bed = test_bed.run_one_tick().run_one_tick();
+        assert_eq!(
+            test_bed.query(|a| a.lgcius.active_lgciu_id()),
+            LgciuId::Lgciu2
+        );
+
+        test_bed.unfail(FailureType::LgciuPowerSupply(LgciuId::Lgciu1));
+        test_bed = test_bed.run_one_tick();
+        assert!(test_bed.is_self_test_in_progress(1));
+        assert!(!test_bed.query(|a| a.lgcius.lgciu1().control_active()));
+
+        test_bed.run_with_delta(Duration::from_millis(2500));
+        assert!(test_bed.is_self_test_in_progress(1));
+
+        test_bed.run_with_delta(Duration::from_millis(1000));
+        assert!(!test_bed.is_self_test_in_progress(1));
+        assert_eq!(
+            test_bed.query(|a| a.lgcius.lgciu1().status()),
+            LgciuStatus::Ok
+        );
+
+        // Next retraction cycle alternates back to the tested lgciu
+        test_bed = test_bed.set_gear_handle_up().run_one_tick();
+        assert_eq!(
+            test_bed.query(|a| a.lgcius.active_lgciu_id()),
+            LgciuId::Lgciu1
+        );
+    }
+
+    #[test]
+    fn lgciu_failed_proximity_sensor_is_isolated_and_gear_still_operates() {
+        let mut test_bed = test_bed_in_flight_with()
+            .in_flight()
+            .set_gear_handle_down()
+            .run_one_tick();
+
+        test_bed.command(|a| a.fail_gear_sensor(GearWheel::LEFT, LgciuId::Lgciu1));
+        test_bed = test_bed.run_one_tick();
+
+        // Channel 1 sees the gear in transit while channel 2 sees it locked down
+        assert!(!test_bed.is_proximity_sensor_fault(1));
+        assert!(!test_bed.is_left_gear_downlocked(1));
+
+        test_bed.run_with_delta(Duration::from_secs(11));
+
+        assert!(test_bed.is_proximity_sensor_fault(1));
+        assert!(!test_bed.is_proximity_sensor_fault(2));
+        assert!(!test_bed.is_proximity_sensor_disagree(1));
+        assert!(test_bed.is_left_gear_downlocked(1));
+        assert!(test_bed.query(|a| a.lgcius.lgciu1().discrete_word_4().get_bit(29)));
+
+        let maintenance_word_1 = test_bed.query(|a| a.lgcius.lgciu1().maintenance_word());
+        let maintenance_word_2 = test_bed.query(|a| a.lgcius.lgciu2().maintenance_word());
+        assert!(maintenance_word_1.get_bit(14));
+        assert!(!maintenance_word_1.get_bit(11));
+        assert!(!maintenance_word_1.get_bit(28));
+        assert!(!maintenance_word_2.get_bit(14));
+        assert!(maintenance_word_2.get_bit(28));
+
+        for _ in 0..2 {
+            test_bed = test_bed.set_gear_handle_up().run_one_tick();
+            for _ in 0..30 {
+                test_bed.run_without_delta();
+            }
+            assert_eq!(
+                test_bed.query(|a| a.lgcius.gear_system_state()),
+                GearSystemState::AllUpLocked
+            );
+
+            test_bed = test_bed.set_gear_handle_down().run_one_tick();
+            for _ in 0..30 {
+                test_bed.run_without_delta();
+            }
+            assert_eq!(
+                test_bed.query(|a| a.lgcius.gear_system_state()),
+                GearSystemState::AllDownLocked
+            );
+        }
+
+        assert_eq!(
+            test_bed.query(|a| a.lgcius.lgciu1().status()),
+            LgciuStatus::Ok
+        );
+        assert_eq!(
+            test_bed.query(|a| a.lgcius.lgciu2().status()),
+            LgciuStatus::Ok
+        );
+    }
+
+    #[test]
+    fn lgciu_flags_proximity_sensor_disagree_after_confirmation_time() {
+        let mut test_bed = test_bed_in_flight_with()
+            .in_flight()
+            .set_gear_handle_up()
+            .run_one_tick();
+
+        test_bed.command(|a| a.stuck_gear_sensor(GearWheel::NOSE, LgciuId::Lgciu2));
+        test_bed = test_bed.run_one_tick();
+
+        assert!(!test_bed.is_proximity_sensor_disagree(1));
+
+        test_bed.run_with_delta(Duration::from_millis(1500));
+
+        assert!(test_bed.is_proximity_sensor_disagree(1));
+        assert!(test_bed.is_proximity_sensor_disagree(2));
+        assert!(!test_bed.is_proximity_sensor_fault(1));
+        assert!(test_bed.query(|a| a.lgcius.lgciu1().discrete_word_2().get_bit(29)));
+    }
+
+    #[test]
+    fn lgciu_isolates_sensor_detecting_up_and_down_after_transition_timeout() {
+        let mut test_bed = test_bed_in_flight_with()
+            .in_flight()
+            .set_gear_handle_up()
+            .run_one_tick();
+
+        for _ in 0..30 {
+            test_bed.run_without_delta();
+        }
+        assert_eq!(
+            test_bed.query(|a| a.lgcius.gear_system_state()),
+            GearSystemState::AllUpLocked
+        );
+
+        test_bed.command(|a| a.stuck_gear_sensor(GearWheel::NOSE, LgciuId::Lgciu2));
+        test_bed = test_bed.run_one_tick();
+
+        test_bed.run_with_delta(Duration::from_secs(11));
+
+        assert!(!test_bed.is_proximity_sensor_fault(1));
+        assert!(test_bed.is_proximity_sensor_fault(2));
+        assert!(!test_bed.is_proximity_sensor_disagree(1));
+        assert!(!test_bed.is_proximity_sensor_disagree(2));
+
+        // Only the nose gear downlock sensor disagrees with the other channel
+        let maintenance_word = test_bed.query(|a| a.lgcius.lgciu2().maintenance_word());
+        assert!(maintenance_word.get_bit(16));
+        assert!(!maintenance_word.get_bit(13));
+        assert!(!test_bed.query(|a| a.lgcius.lgciu2().discrete_word_3().get_bit(13)));
+    }
+
+    #[test]
+    fn lgciu_isolated_sensor_is_cleared_by_power_up_bite() {
+        let mut test_bed = test_bed_in_flight_with()
+            .in_flight()
+            .set_gear_handle_up()
+            .run_one_tick();
+
+        for _ in 0..30 {
+            test_bed.run_without_delta();
+        }
+
+        test_bed.command(|a| a.stuck_gear_sensor(GearWheel::NOSE, LgciuId::Lgciu2));
+        test_bed = test_bed.run_one_tick();
+        test_bed.run_with_delta(Duration::from_secs(11));
+        assert!(test_bed.is_proximity_sensor_fault(2));
+
+        test_bed.fail(FailureType::LgciuPowerSupply(LgciuId::Lgciu2));
+        test_bed = test_bed.run_one_tick();
+        test_bed.unfail(FailureType::LgciuPowerSupply(LgciuId::Lgciu2));
+        test_bed = test_bed.run_one_tick();
+
+        assert!(!test_bed.is_proximity_sensor_fault(2));
+    }
+
     #[test]
     fn tilting_gear_does_not_tilt_when_no_pitch_on_ground() {
         let mut test_bed = SimulationTestBed::from(ElementCtorFn(test_tilting_gear_left))
             .with_update_before_power_distribution(|el, context, _| {
-                el.update(context);
+                el.update(context, Length::default());
             });
 
         test_bed.write_by_name("PLANE PITCH DEGREES", 0.);
@@ -2131,7 +2973,7 @@ mod tests {
     fn tilting_gear_tilts_when_up_pitch_on_ground() {
         let mut test_bed = SimulationTestBed::from(ElementCtorFn(test_tilting_gear_left))
             .with_update_before_power_distribution(|el, context, _| {
-                el.update(context);
+                el.update(context, Length::default());
             });
 
         test_bed.write_by_name("PLANE PITCH DEGREES", -5.);
@@ -2148,7 +2990,7 @@ mod tests {
     fn tilting_gear_tilts_at_max_angle_when_high_up_pitch_on_ground() {
         let mut test_bed = SimulationTestBed::from(ElementCtorFn(test_tilting_gear_left))
             .with_update_before_power_distribution(|el, context, _| {
-                el.update(context);
+                el.update(context, Length::default());
             });
 
         test_bed.write_by_name("PLANE PITCH DEGREES", -15.);
@@ -2164,7 +3006,7 @@ mod tests {
     fn tilting_gear_tilts_at_max_angle_when_not_touching_ground() {
         let mut test_bed = SimulationTestBed::from(ElementCtorFn(test_tilting_gear_left))
             .with_update_before_power_distribution(|el, context, _| {
-                el.update(context);
+                el.update(context, Length::default());
             });
 
         test_bed.write_by_name("PLANE PITCH DEGREES", -15.);
@@ -2181,7 +3023,7 @@ mod tests {
     fn tilting_gear_at_max_tilt_when_not_compressed_and_just_touching_ground() {
         let mut test_bed = SimulationTestBed::from(ElementCtorFn(test_tilting_gear_left))
             .with_update_before_power_distribution(|el, context, _| {
-                el.update(context);
+                el.update(context, Length::default());
             });
 
         test_bed.write_by_name("PLANE PITCH DEGREES", 0.);
@@ -2197,7 +3039,7 @@ mod tests {
     fn tilting_gear_start_tilting_when_touching_ground() {
         let mut test_bed = SimulationTestBed::from(ElementCtorFn(test_tilting_gear_left))
             .with_update_before_power_distribution(|el, context, _| {
-                el.update(context);
+                el.update(context, Length::default());
             });
 
         test_bed.write_by_name("PLANE PITCH DEGREES", 0.);
@@ -2214,7 +3056,7 @@ mod tests {
     fn tilting_gear_wheel_spins_when_touching_ground() {
         let mut test_bed = SimulationTestBed::from(ElementCtorFn(test_tilting_gear_left))
             .with_update_before_power_distribution(|el, context, _| {
-                el.update(context);
+                el.update(context, Length::default());
             });
 
         test_bed.write_by_name("PLANE PITCH DEGREES", 0.);
@@ -2235,7 +3077,7 @@ mod tests {
     fn tilting_gear_wheel_stops_when_airborne() {
         let mut test_bed = SimulationTestBed::from(ElementCtorFn(test_tilting_gear_left))
             .with_update_before_power_distribution(|el, context, _| {
-                el.update(context);
+                el.update(context, Length::default());
             });
 
         test_bed.write_by_name("PLANE PITCH DEGREES", 0.);
@@ -2262,6 +3104,124 @@ mod tests {
         assert_lt!(wheel_speed.get::<revolution_per_minute>(), 50.);
     }
 
+    #[test]
+    fn strut_init_settled_on_ground() {
+        let mut test_bed = landing_gear_test_bed_with_compression(Ratio::new::<ratio>(0.5));
+        test_bed.run_with_delta(Duration::from_millis(10));
+
+        let stroke = test_bed.query_element(|e| e.strut_stroke(GearWheel::LEFT));
+        assert_gt!(stroke.get::<meter>(), 0.2);
+        assert_lt!(stroke.get::<meter>(), 0.4);
+    }
+
+    #[test]
+    fn strut_stays_extended_below_nitrogen_preload() {
+        let mut test_bed = landing_gear_test_bed_with_compression(Ratio::new::<ratio>(0.05));
+        test_bed.run_with_delta(Duration::from_millis(500));
+
+        assert!(!test_bed.query_element(|e| e.is_wheel_id_compressed(GearWheel::LEFT)));
+        assert_eq!(
+            test_bed.query_element(|e| e.strut_stroke(GearWheel::LEFT)),
+            Length::default()
+        );
+    }
+
+    #[test]
+    fn strut_extends_after_lift_off() {
+        let mut test_bed = landing_gear_test_bed_with_compression(Ratio::new::<ratio>(0.5));
+        test_bed.run_with_delta(Duration::from_millis(10));
+
+        test_bed.write_by_name(LandingGear::GEAR_LEFT_COMPRESSION, Ratio::default());
+        test_bed.run_with_delta(Duration::from_millis(50));
+
+        // Weight on wheels is still sensed until the strut has extended
+        assert!(test_bed.query_element(|e| e.is_wheel_id_compressed(GearWheel::LEFT)));
+        assert_gt!(
+            test_bed.query_element(|e| e.strut_stroke(GearWheel::LEFT)),
+            Length::default()
+        );
+
+        test_bed.run_multiple_frames(Duration::from_secs(1));
+
+        assert!(!test_bed.query_element(|e| e.is_wheel_id_compressed(GearWheel::LEFT)));
+        assert_eq!(
+            test_bed.query_element(|e| e.strut_stroke(GearWheel::LEFT)),
+            Length::default()
+        );
+    }
+
+    #[test]
+    fn under_serviced_strut_strokes_further_and_can_bottom() {
+        let mut test_bed = landing_gear_test_bed_with_compression(Ratio::new::<ratio>(0.5));
+        test_bed.run_with_delta(Duration::from_millis(10));
+
+        let nominal_stroke = test_bed.query_element(|e| e.strut_stroke(GearWheel::LEFT));
+
+        test_bed.fail(FailureType::GearStrutUnderServiced(GearWheel::LEFT));
+        test_bed.run_multiple_frames(Duration::from_secs(1));
+
+        let under_serviced_stroke = test_bed.query_element(|e| e.strut_stroke(GearWheel::LEFT));
+        assert_gt!(under_serviced_stroke, nominal_stroke);
+
+        test_bed.write_by_name(LandingGear::GEAR_LEFT_COMPRESSION, Ratio::new::<ratio>(1.));
+        test_bed.run_multiple_frames(Duration::from_secs(1));
+
+        assert!(test_bed.query_element(|e| e.strut(GearWheel::LEFT).is_bottomed()));
+    }
+
+    #[test]
+    fn under_serviced_strut_delays_lift_off_detection() {
+        fn time_to_lift_off_detection(under_serviced: bool) -> Duration {
+            let mut test_bed = landing_gear_test_bed_with_compression(Ratio::new::<ratio>(0.5));
+            if under_serviced {
+                test_bed.fail(FailureType::GearStrutUnderServiced(GearWheel::LEFT));
+            }
+            test_bed.run_with_delta(Duration::from_millis(10));
+            assert!(test_bed.query_element(|e| e.is_wheel_id_compressed(GearWheel::LEFT)));
+
+            // Wing lift unloads the strut over 2 seconds
+            let mut time = Duration::default();
+            while test_bed.query_element(|e| e.is_wheel_id_compressed(GearWheel::LEFT)) {
+                assert!(time < Duration::from_secs(3));
+
+                time += Duration::from_millis(10);
+                let compression = (0.5 - 0.25 * time.as_secs_f64()).max(0.);
+                test_bed.write_by_name(
+                    LandingGear::GEAR_LEFT_COMPRESSION,
+                    Ratio::new::<ratio>(compression),
+                );
+                test_bed.run_with_delta(Duration::from_millis(10));
+            }
+
+            time
+        }
+
+        let nominal_time = time_to_lift_off_detection(false);
+        let under_serviced_time = time_to_lift_off_detection(true);
+
+        assert_gt!(nominal_time, Duration::from_millis(500));
+        assert_gt!(
+            under_serviced_time,
+            nominal_time + Duration::from_millis(500)
+        );
+    }
+
+    #[test]
+    fn tilting_gear_strut_stroke_absorbs_ground_contact_before_tilt() {
+        let mut test_bed = SimulationTestBed::from(ElementCtorFn(test_tilting_gear_left))
+            .with_update_before_power_distribution(|el, context, _| {
+                el.update(context, Length::new::<meter>(0.1));
+            });
+
+        test_bed.write_by_name("PLANE PITCH DEGREES", 0.);
+        test_bed.write_by_name("PLANE ALT ABOVE GROUND", Length::new::<meter>(1.9));
+
+        test_bed.run();
+
+        let tilt_position = Ratio::new::<ratio>(test_bed.read_by_name("GEAR_1_TILT_POSITION"));
+        assert_ge!(tilt_position.get::<ratio>(), 0.99);
+    }
+
     fn test_tilting_gear_left(context: &mut InitContext) -> TiltingGear {
         TiltingGear::new(
             context,
@@ -2279,8 +3239,10 @@ mod tests {
         center: Ratio,
         right: Ratio,
     ) -> SimulationTestBed<TestAircraft<LandingGear>> {
-        let mut test_bed =
-            SimulationTestBed::from(ElementCtorFn(|context| LandingGear::new(context, false)));
+        let mut test_bed = SimulationTestBed::from(ElementCtorFn(test_landing_gear))
+            .with_update_before_power_distribution(|el, context, _| {
+                el.update(context);
+            });
         test_bed.write_by_name(LandingGear::GEAR_LEFT_COMPRESSION, left);
         test_bed.write_by_name(LandingGear::GEAR_CENTER_COMPRESSION, center);
         test_bed.write_by_name(LandingGear::GEAR_RIGHT_COMPRESSION, right);
@@ -2289,4 +3251,38 @@ mod tests {
 
         test_bed
     }
+
+    fn test_strut(context: &mut InitContext, wheel_id: GearWheel) -> ShockAbsorber {
+        ShockAbsorber::new(
+            context,
+            wheel_id,
+            Length::new::<meter>(0.47),
+            Area::new::<square_meter>(0.03),
+            Volume::new::<cubic_meter>(0.0207),
+            Pressure::new::<megapascal>(5.1),
+            Force::new::<newton>(600000.),
+        )
+    }
+
+    fn test_landing_gear(context: &mut InitContext) -> LandingGear {
+        let nose_strut = test_strut(context, GearWheel::NOSE);
+        let main_struts = [
+            test_strut(context, GearWheel::LEFT),
+            test_strut(context, GearWheel::RIGHT),
+        ];
+
+        LandingGear::new(context, nose_strut, main_struts, None)
+    }
+
+    fn landing_gear_test_bed_with_compression(
+        compression: Ratio,
+    ) -> SimulationTestBed<TestAircraft<LandingGear>> {
+        let mut test_bed = SimulationTestBed::from(ElementCtorFn(test_landing_gear))
+            .with_update_before_power_distribution(|el, context, _| {
+                el.update(context);
+            });
+        test_bed.write_by_name(LandingGear::GEAR_LEFT_COMPRESSION, compression);
+
+        test_bed
+    }
 }
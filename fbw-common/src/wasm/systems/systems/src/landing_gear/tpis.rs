@@ -0,0 +1,378 @@
+use crate::{
+    failures::{Failure, FailureType},
+    shared::{
+        arinc429::{Arinc429Word, SignStatus},
+        ElectricalBusType, ElectricalBuses, TyrePressure,
+    },
+    simulation::{
+        InitContext, SimulationElement, SimulationElementVisitor, SimulatorWriter, UpdateContext,
+        VariableIdentifier, Write,
+    },
+};
+use uom::si::{
+    f64::*,
+    pressure::psi,
+    ratio::ratio,
+    thermodynamic_temperature::{degree_celsius, kelvin},
+};
+
+/// Tyre pressure indicating system computer.
+/// The sensor of each wheel measures tyre gas pressure and temperature. The computer corrects the
+/// pressure to the inflation reference temperature so a hot tyre can be compared with its cold
+/// inflation pressure. As the measured pressure is a gauge pressure, the correction is applied to
+/// the absolute gas pressure. It then raises low pressure and pressure difference alerts.
+/// Tyres are given by axle pairs with their cold inflation pressure: the tyre at an even position
+/// is compared with the one after it.
+pub struct TyrePressureIndicatingSystem<const N: usize> {
+    channels: [TyrePressureChannel; N],
+
+    powered_by: ElectricalBusType,
+    is_powered: bool,
+    failure: Failure,
+}
+impl<const N: usize> TyrePressureIndicatingSystem<N> {
+    /// Temperature the displayed pressures are corrected to
+    const REFERENCE_TEMPERATURE_DEGREE_CELSIUS: f64 = 15.;
+    /// Compensated pressure below which a tyre is flagged as under-inflated
+    const LOW_PRESSURE_RATIO: f64 = 0.9;
+    /// Pressure difference between the tyres of an axle above which both are flagged
+    const PRESSURE_DIFFERENCE_RATIO: f64 = 0.1;
+
+    pub fn new(
+        context: &mut InitContext,
+        tyres: [(usize, Pressure); N],
+        powered_by: ElectricalBusType,
+    ) -> Self {
+        debug_assert!(N.is_multiple_of(2), "Tyres are monitored by axle pairs");
+
+        Self {
+            channels: tyres.map(|(index, nominal_pressure)| {
+                TyrePressureChannel::new(context, index, nominal_pressure)
+            }),
+
+            powered_by,
+            is_powered: false,
+            failure: Failure::new(FailureType::TyrePressureIndicatingSystem),
+        }
+    }
+
+    /// Tyres must be given in the same order as the system was built with
+    pub fn update<'a>(
+        &mut self,
+        context: &UpdateContext,
+        tyres: impl IntoIterator<Item = &'a dyn TyrePressure>,
+    ) {
+        let ambient_pressure = context.ambient_pressure();
+        let reference_temperature = ThermodynamicTemperature::new::<degree_celsius>(
+            Self::REFERENCE_TEMPERATURE_DEGREE_CELSIUS,
+        )
+        .get::<kelvin>();
+
+        for (channel, tyre) in self.channels.iter_mut().zip(tyres) {
+            channel.compensated_pressure = (tyre.tyre_pressure() + ambient_pressure)
+                * reference_temperature
+                / tyre.tyre_temperature().get::<kelvin>()
+                - ambient_pressure;
+            channel.low_pressure = (channel.compensated_pressure / channel.nominal_pressure)
+                .get::<ratio>()
+                < Self::LOW_PRESSURE_RATIO;
+        }
+
+        for axle in self.channels.chunks_exact_mut(2) {
+            let pressure_difference =
+                ((axle[0].compensated_pressure - axle[1].compensated_pressure).abs()
+                    / axle[0].nominal_pressure)
+                    .get::<ratio>()
+                    > Self::PRESSURE_DIFFERENCE_RATIO;
+            axle[0].pressure_difference = pressure_difference;
+            axle[1].pressure_difference = pressure_difference;
+        }
+    }
+
+    fn is_operative(&self) -> bool {
+        self.is_powered && !self.failure.is_active()
+    }
+
+    /// Temperature compensated pressure of the tyre at the given position
+    pub fn pressure_word(&self, position: usize) -> Arinc429Word<f64> {
+        if self.is_operative() {
+            Arinc429Word::new(
+                self.channels[position].compensated_pressure.get::<psi>(),
+                SignStatus::NormalOperation,
+            )
+        } else {
+            Arinc429Word::new(0., SignStatus::FailureWarning)
+        }
+    }
+
+    /// Alerts of the tyre at the given position
+    /// Bit 11: low pressure
+    /// Bit 12: pressure difference with the other tyre of the axle
+    pub fn discrete_word(&self, position: usize) -> Arinc429Word<u32> {
+        if self.is_operative() {
+            let channel = &self.channels[position];
+            let mut word = Arinc429Word::new(0, SignStatus::NormalOperation);
+            word.set_bit(11, channel.low_pressure);
+            word.set_bit(12, channel.pressure_difference);
+
+            word
+        } else {
+            Arinc429Word::new(0, SignStatus::FailureWarning)
+        }
+    }
+}
+impl<const N: usize> SimulationElement for TyrePressureIndicatingSystem<N> {
+    fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
+        self.failure.accept(visitor);
+
+        visitor.visit(self);
+    }
+
+    fn receive_power(&mut self, buses: &impl ElectricalBuses) {
+        self.is_powered = buses.is_powered(self.powered_by);
+    }
+
+    fn write(&self, writer: &mut SimulatorWriter) {
+        for (position, channel) in self.channels.iter().enumerate() {
+            writer.write(&channel.pressure_id, self.pressure_word(position));
+            writer.write(&channel.discrete_word_id, self.discrete_word(position));
+        }
+    }
+}
+
+struct TyrePressureChannel {
+    pressure_id: VariableIdentifier,
+    discrete_word_id: VariableIdentifier,
+
+    nominal_pressure: Pressure,
+    compensated_pressure: Pressure,
+    low_pressure: bool,
+    pressure_difference: bool,
+}
+impl TyrePressureChannel {
+    fn new(context: &mut InitContext, index: usize, nominal_pressure: Pressure) -> Self {
+        Self {
+            pressure_id: context.get_identifier(format!("TPIS_TYRE_{index}_PRESSURE")),
+            discrete_word_id: context.get_identifier(format!("TPIS_TYRE_{index}_DISCRETE_WORD")),
+
+            nominal_pressure,
+            compensated_pressure: Pressure::default(),
+            low_pressure: false,
+            pressure_difference: false,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        electrical::{test::TestElectricitySource, ElectricalBus, Electricity},
+        shared::PotentialOrigin,
+        simulation::{
+            test::{ReadByName, SimulationTestBed, TestBed},
+            Aircraft, UpdateContext,
+        },
+    };
+    use std::time::Duration;
+    use uom::si::electric_potential::volt;
+
+    struct TestTyre {
+        pressure: Pressure,
+        temperature: ThermodynamicTemperature,
+    }
+    impl TestTyre {
+        fn new() -> Self {
+            Self {
+                pressure: Pressure::new::<psi>(200.),
+                temperature: ThermodynamicTemperature::new::<degree_celsius>(15.),
+            }
+        }
+    }
+    impl TyrePressure for TestTyre {
+        fn tyre_pressure(&self) -> Pressure {
+            self.pressure
+        }
+
+        fn tyre_temperature(&self) -> ThermodynamicTemperature {
+            self.temperature
+        }
+    }
+
+    struct TestAircraft {
+        electricity_source: TestElectricitySource,
+        dc_1_bus: ElectricalBus,
+        is_dc_1_powered: bool,
+
+        tyres: [TestTyre; 4],
+        tpis: TyrePressureIndicatingSystem<4>,
+    }
+    impl TestAircraft {
+        fn new(context: &mut InitContext) -> Self {
+            Self {
+                electricity_source: TestElectricitySource::powered(
+                    context,
+                    PotentialOrigin::EngineGenerator(1),
+                ),
+                dc_1_bus: ElectricalBus::new(context, ElectricalBusType::DirectCurrent(1)),
+                is_dc_1_powered: true,
+
+                tyres: [(); 4].map(|_| TestTyre::new()),
+                tpis: TyrePressureIndicatingSystem::new(
+                    context,
+                    [1, 2, 3, 4].map(|index| (index, Pressure::new::<psi>(200.))),
+                    ElectricalBusType::DirectCurrent(1),
+                ),
+            }
+        }
+
+        fn set_tyre(
+            &mut self,
+            position: usize,
+            pressure_psi: f64,
+            temperature_degree_celsius: f64,
+        ) {
+            self.tyres[position].pressure = Pressure::new::<psi>(pressure_psi);
+            self.tyres[position].temperature =
+                ThermodynamicTemperature::new::<degree_celsius>(temperature_degree_celsius);
+        }
+
+        fn set_dc_1_power(&mut self, is_powered: bool) {
+            self.is_dc_1_powered = is_powered;
+        }
+    }
+    impl Aircraft for TestAircraft {
+        fn update_before_power_distribution(
+            &mut self,
+            _: &UpdateContext,
+            electricity: &mut Electricity,
+        ) {
+            self.electricity_source
+                .power_with_potential(ElectricPotential::new::<volt>(28.));
+            electricity.supplied_by(&self.electricity_source);
+
+            if self.is_dc_1_powered {
+                electricity.flow(&self.electricity_source, &self.dc_1_bus);
+            }
+        }
+
+        fn update_after_power_distribution(&mut self, context: &UpdateContext) {
+            self.tpis.update(
+                context,
+                self.tyres.iter().map(|tyre| tyre as &dyn TyrePressure),
+            );
+        }
+    }
+    impl SimulationElement for TestAircraft {
+        fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
+            self.tpis.accept(visitor);
+
+            visitor.visit(self);
+        }
+    }
+
+    const AMBIENT_PRESSURE_PSI: f64 = 14.7;
+
+    fn test_bed() -> SimulationTestBed<TestAircraft> {
+        let mut test_bed = SimulationTestBed::new(TestAircraft::new);
+        test_bed.set_ambient_pressure(Pressure::new::<psi>(AMBIENT_PRESSURE_PSI));
+
+        test_bed
+    }
+
+    /// Gauge pressure of a tyre inflated at 15°C once its gas is brought to another temperature
+    fn gauge_pressure_at(cold_pressure_psi: f64, temperature_degree_celsius: f64) -> f64 {
+        (cold_pressure_psi + AMBIENT_PRESSURE_PSI) * (temperature_degree_celsius + 273.15) / 288.15
+            - AMBIENT_PRESSURE_PSI
+    }
+
+    fn pressure_word(
+        test_bed: &mut SimulationTestBed<TestAircraft>,
+        index: usize,
+    ) -> Arinc429Word<f64> {
+        test_bed.read_arinc429_by_name(&format!("TPIS_TYRE_{index}_PRESSURE"))
+    }
+
+    fn discrete_word(
+        test_bed: &mut SimulationTestBed<TestAircraft>,
+        index: usize,
+    ) -> Arinc429Word<u32> {
+        test_bed.read_arinc429_by_name(&format!("TPIS_TYRE_{index}_DISCRETE_WORD"))
+    }
+
+    #[test]
+    fn correctly_inflated_tyres_have_no_alert() {
+        let mut test_bed = test_bed();
+        test_bed.run_with_delta(Duration::from_secs(1));
+
+        for index in 1..=4 {
+            let pressure = pressure_word(&mut test_bed, index);
+            assert!(pressure.is_normal_operation());
+            assert!((pressure.value() - 200.).abs() < 0.1);
+
+            let discrete = discrete_word(&mut test_bed, index);
+            assert!(!discrete.get_bit(11));
+            assert!(!discrete.get_bit(12));
+        }
+    }
+
+    #[test]
+    fn hot_tyre_pressure_is_compensated_to_reference_temperature() {
+        let mut test_bed = test_bed();
+        // Same gas load heated to 150°C
+        test_bed.command(|a| a.set_tyre(0, gauge_pressure_at(200., 150.), 150.));
+        test_bed.run_with_delta(Duration::from_secs(1));
+
+        assert!((pressure_word(&mut test_bed, 1).value() - 200.).abs() < 0.1);
+        assert!(!discrete_word(&mut test_bed, 1).get_bit(12));
+    }
+
+    #[test]
+    fn under_inflated_tyre_raises_low_pressure_and_axle_difference() {
+        let mut test_bed = test_bed();
+        test_bed.command(|a| a.set_tyre(2, 170., 15.));
+        test_bed.run_with_delta(Duration::from_secs(1));
+
+        let discrete = discrete_word(&mut test_bed, 3);
+        assert!(discrete.get_bit(11));
+        assert!(discrete.get_bit(12));
+
+        let partner = discrete_word(&mut test_bed, 4);
+        assert!(!partner.get_bit(11));
+        assert!(partner.get_bit(12));
+
+        let other_axle = discrete_word(&mut test_bed, 1);
+        assert!(!other_axle.get_bit(11));
+        assert!(!other_axle.get_bit(12));
+    }
+
+    #[test]
+    fn cold_tyre_under_nominal_pressure_is_not_flagged_low() {
+        let mut test_bed = test_bed();
+        // Cold soak at -30°C reduces measured pressure without any gas loss
+        for position in 0..4 {
+            test_bed.command(|a| a.set_tyre(position, gauge_pressure_at(200., -30.), -30.));
+        }
+        test_bed.run_with_delta(Duration::from_secs(1));
+
+        assert!(pressure_word(&mut test_bed, 1).value() > 199.);
+        assert!(!discrete_word(&mut test_bed, 1).get_bit(11));
+    }
+
+    #[test]
+    fn unpowered_or_failed_tpis_outputs_failure_warning() {
+        let mut test_bed = test_bed();
+        test_bed.command(|a| a.set_dc_1_power(false));
+        test_bed.run_with_delta(Duration::from_secs(1));
+
+        assert!(pressure_word(&mut test_bed, 1).is_failure_warning());
+        assert!(discrete_word(&mut test_bed, 1).is_failure_warning());
+
+        test_bed.command(|a| a.set_dc_1_power(true));
+        test_bed.fail(FailureType::TyrePressureIndicatingSystem);
+        test_bed.run_with_delta(Duration::from_secs(1));
+
+        assert!(pressure_word(&mut test_bed, 1).is_failure_warning());
+        assert!(discrete_word(&mut test_bed, 1).is_failure_warning());
+    }
+}
@@ -0,0 +1,467 @@
+use std::time::Duration;
+
+use crate::{
+    failures::{Failure, FailureType},
+    pneumatic::BleedDuctLeak,
+    shared::{
+        logic_nodes::HysteresisNode, BleedDuctZone, ElectricalBusType, ElectricalBuses,
+        FireDetectionLoopID,
+    },
+    simulation::{
+        InitContext, SimulationElement, SimulationElementVisitor, SimulatorWriter, UpdateContext,
+        VariableIdentifier, Write,
+    },
+};
+
+use uom::si::{
+    f64::*,
+    mass_rate::kilogram_per_second,
+    thermodynamic_temperature::{degree_celsius, kelvin},
+};
+
+/// Overheat detection for one bleed duct zone (pylon, wing or APU).
+///
+/// Hot air escaping from a leaking duct heats up the compartment around it. Sensing loops run
+/// along the duct and trip once the compartment exceeds their alarm temperature. They only reset
+/// once the compartment has cooled down well below it, which takes a while after the leaking
+/// duct is isolated. As for fire detection, a zone with two loops needs both of them to detect
+/// the overheat, unless one of them has failed.
+pub struct BleedLeakDetection {
+    zone: BleedDuctZone,
+    leak_detected_id: VariableIdentifier,
+    loop_fault_id: VariableIdentifier,
+
+    zone_temperature: ThermodynamicTemperature,
+    zone_temperature_initialized: bool,
+    leak_detected: bool,
+
+    loops: Vec<BleedLeakDetectionLoop>,
+}
+impl BleedLeakDetection {
+    const ZONE_HEATING_TIME_CONSTANT: Duration = Duration::from_secs(15);
+    // Without hot air blowing into it, the compartment only cools down through natural ventilation
+    const ZONE_COOLING_TIME_CONSTANT: Duration = Duration::from_secs(60);
+
+    // Leak flow at which about two thirds of the compartment temperature rise is reached
+    const REFERENCE_LEAK_FLOW_KG_PER_S: f64 = 0.02;
+
+    pub fn new(
+        context: &mut InitContext,
+        zone: BleedDuctZone,
+        alarm_temperature: ThermodynamicTemperature,
+        loops: &[(FireDetectionLoopID, ElectricalBusType)],
+    ) -> Self {
+        Self {
+            zone,
+            leak_detected_id: context.get_identifier(format!("PNEU_{}_LEAK_DETECTED", zone)),
+            loop_fault_id: context
+                .get_identifier(format!("PNEU_{}_LEAK_DETECTION_LOOP_FAULT", zone)),
+
+            zone_temperature: ThermodynamicTemperature::new::<degree_celsius>(15.),
+            zone_temperature_initialized: false,
+            leak_detected: false,
+
+            loops: loops
+                .iter()
+                .map(|&(loop_id, powered_by)| {
+                    BleedLeakDetectionLoop::new(loop_id, zone, alarm_temperature, powered_by)
+                })
+                .collect(),
+        }
+    }
+
+    pub fn update(&mut self, context: &UpdateContext, leak: &BleedDuctLeak) {
+        self.update_zone_temperature(context, leak);
+
+        let zone_temperature = self.zone_temperature;
+        self.loops
+            .iter_mut()
+            .for_each(|l| l.update(zone_temperature));
+
+        self.leak_detected = self.loops.iter().any(|l| l.leak_detected_in_loop())
+            && self
+                .loops
+                .iter()
+                .all(|l| l.leak_detected_in_loop() || l.loop_has_failed());
+
+        self.loops.iter_mut().for_each(|l| l.update_was_powered());
+    }
+
+    fn update_zone_temperature(&mut self, context: &UpdateContext, leak: &BleedDuctLeak) {
+        if !self.zone_temperature_initialized {
+            self.zone_temperature = context.ambient_temperature();
+            self.zone_temperature_initialized = true;
+        }
+
+        let ambient_temperature = context.ambient_temperature().get::<kelvin>();
+
+        // The bigger the leak, the closer the compartment gets to the temperature of the escaping air
+        let leak_share = 1.
+            - (-leak.fluid_flow().get::<kilogram_per_second>().max(0.)
+                / Self::REFERENCE_LEAK_FLOW_KG_PER_S)
+                .exp();
+        let target_temperature = ambient_temperature
+            + leak_share * (leak.leak_temperature().get::<kelvin>() - ambient_temperature);
+
+        let current_temperature = self.zone_temperature.get::<kelvin>();
+        let time_constant = if target_temperature > current_temperature {
+            Self::ZONE_HEATING_TIME_CONSTANT
+        } else {
+            Self::ZONE_COOLING_TIME_CONSTANT
+        };
+        self.zone_temperature = ThermodynamicTemperature::new::<kelvin>(
+            current_temperature
+                + (target_temperature - current_temperature)
+                    * (1. - (-context.delta_as_secs_f64() / time_constant.as_secs_f64()).exp()),
+        );
+    }
+
+    pub fn zone(&self) -> BleedDuctZone {
+        self.zone
+    }
+
+    pub fn leak_detected(&self) -> bool {
+        self.leak_detected
+    }
+
+    pub fn has_loop_fault(&self) -> bool {
+        self.loops.iter().any(|l| l.loop_has_failed())
+    }
+
+    pub fn zone_temperature(&self) -> ThermodynamicTemperature {
+        self.zone_temperature
+    }
+}
+impl SimulationElement for BleedLeakDetection {
+    fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
+        accept_iterable!(self.loops, visitor);
+
+        visitor.visit(self);
+    }
+
+    fn write(&self, writer: &mut SimulatorWriter) {
+        writer.write(&self.leak_detected_id, self.leak_detected());
+        writer.write(&self.loop_fault_id, self.has_loop_fault());
+    }
+}
+
+struct BleedLeakDetectionLoop {
+    overheat: HysteresisNode<ThermodynamicTemperature>,
+    is_overheat_sensed: bool,
+    powered_by: ElectricalBusType,
+    is_powered: bool,
+    was_powered_before: bool,
+
+    failure: Failure,
+}
+impl BleedLeakDetectionLoop {
+    const RESET_TEMPERATURE_MARGIN_C: f64 = 30.;
+
+    fn new(
+        loop_id: FireDetectionLoopID,
+        zone: BleedDuctZone,
+        alarm_temperature: ThermodynamicTemperature,
+        powered_by: ElectricalBusType,
+    ) -> Self {
+        Self {
+            overheat: HysteresisNode::new(
+                ThermodynamicTemperature::new::<kelvin>(
+                    alarm_temperature.get::<kelvin>() - Self::RESET_TEMPERATURE_MARGIN_C,
+                ),
+                alarm_temperature,
+            ),
+            is_overheat_sensed: false,
+            powered_by,
+            is_powered: false,
+            was_powered_before: false,
+
+            failure: Failure::new(FailureType::BleedLeakDetectionLoop(loop_id, zone)),
+        }
+    }
+
+    fn update(&mut self, zone_temperature: ThermodynamicTemperature) {
+        self.is_overheat_sensed = self.overheat.update(zone_temperature);
+    }
+
+    fn leak_detected_in_loop(&self) -> bool {
+        !self.failure.is_active() && self.is_powered && self.is_overheat_sensed
+    }
+
+    fn loop_has_failed(&self) -> bool {
+        self.failure.is_active() || (!self.is_powered && self.was_powered_before)
+    }
+
+    /// This is to avoid a loop fault on initial load, before the loop has ever been powered
+    fn update_was_powered(&mut self) {
+        self.was_powered_before |= self.is_powered
+    }
+}
+impl SimulationElement for BleedLeakDetectionLoop {
+    fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
+        self.failure.accept(visitor);
+
+        visitor.visit(self);
+    }
+
+    fn receive_power(&mut self, buses: &impl ElectricalBuses) {
+        self.is_powered = buses.is_powered(self.powered_by);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::{
+        electrical::{test::TestElectricitySource, ElectricalBus, Electricity},
+        pneumatic::{CompressionChamber, TargetPressureTemperatureSignal},
+        shared::{ControllerSignal, PotentialOrigin},
+        simulation::{
+            test::{ReadByName, SimulationTestBed, TestBed},
+            Aircraft,
+        },
+    };
+
+    use uom::si::{electric_potential::volt, pressure::psi, volume::cubic_meter};
+
+    struct HotBleedAirSource;
+    impl ControllerSignal<TargetPressureTemperatureSignal> for HotBleedAirSource {
+        fn signal(&self) -> Option<TargetPressureTemperatureSignal> {
+            Some(TargetPressureTemperatureSignal::new(
+                Pressure::new::<psi>(45.),
+                ThermodynamicTemperature::new::<degree_celsius>(250.),
+            ))
+        }
+    }
+
+    struct TestAircraft {
+        duct: CompressionChamber,
+        leak: BleedDuctLeak,
+        wing_leak_detection: BleedLeakDetection,
+
+        powered_source: TestElectricitySource,
+        dc_1_bus: ElectricalBus,
+        dc_2_bus: ElectricalBus,
+        is_dc_2_powered: bool,
+    }
+    impl TestAircraft {
+        fn new(context: &mut InitContext) -> Self {
+            Self {
+                duct: CompressionChamber::new(Volume::new::<cubic_meter>(0.5)),
+                leak: BleedDuctLeak::new(BleedDuctZone::Wing(1), 1.),
+                wing_leak_detection: BleedLeakDetection::new(
+                    context,
+                    BleedDuctZone::Wing(1),
+                    ThermodynamicTemperature::new::<degree_celsius>(124.),
+                    &[
+                        (FireDetectionLoopID::A, ElectricalBusType::DirectCurrent(1)),
+                        (FireDetectionLoopID::B, ElectricalBusType::DirectCurrent(2)),
+                    ],
+                ),
+
+                powered_source: TestElectricitySource::powered(
+                    context,
+                    PotentialOrigin::EngineGenerator(1),
+                ),
+                dc_1_bus: ElectricalBus::new(context, ElectricalBusType::DirectCurrent(1)),
+                dc_2_bus: ElectricalBus::new(context, ElectricalBusType::DirectCurrent(2)),
+                is_dc_2_powered: true,
+            }
+        }
+
+        fn unpower_dc_2_bus(&mut self) {
+            self.is_dc_2_powered = false;
+        }
+    }
+    impl Aircraft for TestAircraft {
+        fn update_before_power_distribution(
+            &mut self,
+            _: &UpdateContext,
+            electricity: &mut Electricity,
+        ) {
+            self.powered_source
+                .power_with_potential(ElectricPotential::new::<volt>(28.));
+            electricity.supplied_by(&self.powered_source);
+            electricity.flow(&self.powered_source, &self.dc_1_bus);
+
+            if self.is_dc_2_powered {
+                electricity.flow(&self.powered_source, &self.dc_2_bus);
+            }
+        }
+
+        fn update_after_power_distribution(&mut self, context: &UpdateContext) {
+            self.duct.update(&HotBleedAirSource);
+            self.leak.update_move_fluid(context, &mut self.duct);
+            self.wing_leak_detection.update(context, &self.leak);
+        }
+    }
+    impl SimulationElement for TestAircraft {
+        fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
+            self.leak.accept(visitor);
+            self.wing_leak_detection.accept(visitor);
+
+            visitor.visit(self);
+        }
+    }
+
+    struct BleedLeakDetectionTestBed {
+        test_bed: SimulationTestBed<TestAircraft>,
+    }
+    impl BleedLeakDetectionTestBed {
+        fn new() -> Self {
+            Self {
+                test_bed: SimulationTestBed::new(TestAircraft::new),
+            }
+        }
+
+        fn and_run(mut self) -> Self {
+            for _ in 0..120 {
+                self.run_with_delta(Duration::from_millis(500));
+            }
+            self
+        }
+
+        fn with_wing_duct_leak(mut self) -> Self {
+            self.fail(FailureType::BleedDuctLeak(BleedDuctZone::Wing(1)));
+            self
+        }
+
+        fn without_wing_duct_leak(mut self) -> Self {
+            self.unfail(FailureType::BleedDuctLeak(BleedDuctZone::Wing(1)));
+            self
+        }
+
+        fn with_failed_loop(mut self, loop_id: FireDetectionLoopID) -> Self {
+            self.fail(FailureType::BleedLeakDetectionLoop(
+                loop_id,
+                BleedDuctZone::Wing(1),
+            ));
+            self
+        }
+
+        fn ambient_temperature_of(mut self, temperature: ThermodynamicTemperature) -> Self {
+            self.set_ambient_temperature(temperature);
+            self
+        }
+
+        fn unpower_dc_2_bus(mut self) -> Self {
+            self.command(|a| a.unpower_dc_2_bus());
+            self
+        }
+
+        fn zone_temperature(&self) -> ThermodynamicTemperature {
+            self.query(|a| a.wing_leak_detection.zone_temperature())
+        }
+
+        fn leak_detected(&mut self) -> bool {
+            self.read_by_name("PNEU_WING_1_LEAK_DETECTED")
+        }
+
+        fn has_loop_fault(&mut self) -> bool {
+            self.read_by_name("PNEU_WING_1_LEAK_DETECTION_LOOP_FAULT")
+        }
+    }
+    impl TestBed for BleedLeakDetectionTestBed {
+        type Aircraft = TestAircraft;
+
+        fn test_bed(&self) -> &SimulationTestBed<TestAircraft> {
+            &self.test_bed
+        }
+
+        fn test_bed_mut(&mut self) -> &mut SimulationTestBed<TestAircraft> {
+            &mut self.test_bed
+        }
+    }
+
+    fn test_bed() -> BleedLeakDetectionTestBed {
+        BleedLeakDetectionTestBed::new()
+    }
+
+    #[test]
+    fn no_leak_detected_without_duct_leak() {
+        let mut test_bed = test_bed().and_run();
+
+        assert!(!test_bed.leak_detected());
+        assert!(!test_bed.has_loop_fault());
+        assert!(test_bed.zone_temperature() < ThermodynamicTemperature::new::<degree_celsius>(50.));
+    }
+
+    #[test]
+    fn zone_starts_at_ambient_temperature() {
+        let mut test_bed =
+            test_bed().ambient_temperature_of(ThermodynamicTemperature::new::<degree_celsius>(45.));
+        test_bed.run_with_delta(Duration::from_millis(500));
+
+        assert!((test_bed.zone_temperature().get::<degree_celsius>() - 45.).abs() < 0.1);
+    }
+
+    #[test]
+    fn duct_leak_heats_zone_and_is_detected() {
+        let mut test_bed = test_bed().with_wing_duct_leak().and_run();
+
+        assert!(
+            test_bed.zone_temperature() > ThermodynamicTemperature::new::<degree_celsius>(124.)
+        );
+        assert!(test_bed.leak_detected());
+    }
+
+    #[test]
+    fn detected_leak_only_resets_once_zone_has_cooled_down() {
+        let mut test_bed = test_bed()
+            .with_wing_duct_leak()
+            .and_run()
+            .without_wing_duct_leak();
+        test_bed.run_with_delta(Duration::from_secs(45));
+
+        assert!(
+            test_bed.zone_temperature() < ThermodynamicTemperature::new::<degree_celsius>(124.)
+        );
+        assert!(test_bed.leak_detected());
+
+        test_bed = test_bed.and_run();
+
+        assert!(!test_bed.leak_detected());
+    }
+
+    #[test]
+    fn leak_is_detected_with_one_failed_loop() {
+        let mut test_bed = test_bed()
+            .with_failed_loop(FireDetectionLoopID::B)
+            .with_wing_duct_leak()
+            .and_run();
+
+        assert!(test_bed.leak_detected());
+        assert!(test_bed.has_loop_fault());
+    }
+
+    #[test]
+    fn leak_is_not_detected_with_both_loops_failed() {
+        let mut test_bed = test_bed()
+            .with_failed_loop(FireDetectionLoopID::A)
+            .with_failed_loop(FireDetectionLoopID::B)
+            .with_wing_duct_leak()
+            .and_run();
+
+        assert!(!test_bed.leak_detected());
+        assert!(test_bed.has_loop_fault());
+    }
+
+    #[test]
+    fn loss_of_power_on_one_loop_is_a_loop_fault() {
+        let mut test_bed = test_bed().and_run().unpower_dc_2_bus().and_run();
+
+        assert!(test_bed.has_loop_fault());
+        assert!(!test_bed.leak_detected());
+    }
+
+    #[test]
+    fn leak_is_detected_with_one_unpowered_loop() {
+        let mut test_bed = test_bed()
+            .and_run()
+            .unpower_dc_2_bus()
+            .with_wing_duct_leak()
+            .and_run();
+
+        assert!(test_bed.leak_detected());
+    }
+}
@@ -2,8 +2,8 @@ use crate::{
     failures::{Failure, FailureType},
     pneumatic::valve::*,
     shared::{
-        ControllerSignal, ElectricalBusType, ElectricalBuses, EngineCorrectedN1, EngineCorrectedN2,
-        HydraulicColor, PneumaticValve,
+        BleedDuctZone, ControllerSignal, ElectricalBusType, ElectricalBuses, EngineCorrectedN1,
+        EngineCorrectedN2, HydraulicColor, PneumaticValve,
     },
     simulation::{
         InitContext, Read, Reader, SimulationElement, SimulationElementVisitor, SimulatorReader,
@@ -21,6 +21,7 @@ use uom::si::{
     volume::cubic_meter,
 };
 
+pub mod leak_detection;
 pub mod valve;
 
 pub trait PneumaticValveSignal {
@@ -719,6 +720,66 @@ impl SimulationElement for PressurisedReservoirWithExhaustValve<VariableVolumeCo
     }
 }
 
+/// A leak in a bleed air duct. While the failure of its zone is active, air escapes from the
+/// duct it is attached to into the surrounding compartment.
+pub struct BleedDuctLeak {
+    zone: BleedDuctZone,
+    exhaust: PneumaticExhaust,
+    duct_temperature: ThermodynamicTemperature,
+
+    failure: Failure,
+}
+impl BleedDuctLeak {
+    pub fn new(zone: BleedDuctZone, leak_speed: f64) -> Self {
+        Self {
+            zone,
+            exhaust: PneumaticExhaust::new(leak_speed, leak_speed, Pressure::default()),
+            duct_temperature: ThermodynamicTemperature::new::<degree_celsius>(15.),
+            failure: Failure::new(FailureType::BleedDuctLeak(zone)),
+        }
+    }
+
+    pub fn update_move_fluid(
+        &mut self,
+        context: &UpdateContext,
+        duct: &mut impl PneumaticContainer,
+    ) {
+        self.duct_temperature = duct.temperature();
+
+        if self.is_leaking() {
+            self.exhaust.update_move_fluid(context, duct);
+        }
+    }
+
+    pub fn zone(&self) -> BleedDuctZone {
+        self.zone
+    }
+
+    pub fn is_leaking(&self) -> bool {
+        self.failure.is_active()
+    }
+
+    pub fn fluid_flow(&self) -> MassRate {
+        if self.is_leaking() {
+            self.exhaust.fluid_flow()
+        } else {
+            MassRate::default()
+        }
+    }
+
+    /// Temperature of the air escaping through the leak.
+    pub fn leak_temperature(&self) -> ThermodynamicTemperature {
+        self.duct_temperature
+    }
+}
+impl SimulationElement for BleedDuctLeak {
+    fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
+        self.failure.accept(visitor);
+
+        visitor.visit(self);
+    }
+}
+
 struct PneumaticContainerWithConnector<T: PneumaticContainer> {
     container: T,
     connector: PurelyPneumaticValve,
@@ -975,6 +1036,7 @@ mod tests {
         shared::{ControllerSignal, InternationalStandardAtmosphere, MachNumber},
         simulation::{test::TestVariableRegistry, UpdateContext},
     };
+    use rustc_hash::FxHashSet;
     use std::time::Duration;
 
     use uom::si::{
@@ -1400,6 +1462,37 @@ mod tests {
         );
     }
 
+    #[test]
+    fn bleed_duct_does_not_leak_without_failure() {
+        let mut duct = quick_container(1., 50., 150.);
+        let mut leak = BleedDuctLeak::new(BleedDuctZone::Pylon(1), 0.5);
+
+        let context = context(Duration::from_secs(1), Length::new::<foot>(0.));
+
+        leak.update_move_fluid(&context, &mut duct);
+
+        assert_about_eq!(duct.pressure().get::<psi>(), 50.);
+        assert_eq!(leak.fluid_flow(), MassRate::default());
+    }
+
+    #[test]
+    fn failed_bleed_duct_leaks_to_ambient() {
+        let mut duct = quick_container(1., 50., 150.);
+        let mut leak = BleedDuctLeak::new(BleedDuctZone::Wing(2), 0.5);
+        leak.failure
+            .receive_failure(&FxHashSet::from_iter([FailureType::BleedDuctLeak(
+                BleedDuctZone::Wing(2),
+            )]));
+
+        let context = context(Duration::from_secs(1), Length::new::<foot>(0.));
+
+        leak.update_move_fluid(&context, &mut duct);
+
+        assert!(leak.is_leaking());
+        assert_lt!(duct.pressure().get::<psi>(), 50.);
+        assert_gt!(leak.fluid_flow(), MassRate::default());
+    }
+
     #[test]
     fn container_with_valve_behaves_like_open_valve() {
         let mut source = quick_container(1., 20., 15.);
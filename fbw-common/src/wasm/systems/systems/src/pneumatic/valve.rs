@@ -111,11 +111,13 @@ impl<const N: usize> PneumaticValveCharacteristics<N> {
 
 /// A valve with a solenoid. If the solenoid is energized, the valve is allowed to open
 /// If the solenoid is de-energized, the valve is closed.
+/// A mechanically stuck valve keeps its stuck position regardless of the solenoid state.
 pub struct SolenoidValve<const N: usize> {
     connector: PneumaticContainerConnector,
     characteristics: PneumaticValveCharacteristics<N>,
     solenoid: Solenoid,
     open_amount: Ratio,
+    stuck_open_amount: Option<Ratio>,
 }
 impl<const N: usize> SolenoidValve<N> {
     pub fn new(
@@ -127,9 +129,14 @@ impl<const N: usize> SolenoidValve<N> {
             characteristics,
             solenoid: Solenoid::new(powered_by),
             open_amount: Ratio::default(),
+            stuck_open_amount: None,
         }
     }
 
+    pub fn set_stuck_open_amount(&mut self, stuck_open_amount: Option<Ratio>) {
+        self.stuck_open_amount = stuck_open_amount;
+    }
+
     pub fn update_solenoid(&mut self, controller: &impl ControllerSignal<SolenoidSignal>) {
         self.solenoid.update(controller);
     }
@@ -140,7 +147,9 @@ impl<const N: usize> SolenoidValve<N> {
         upstream: &mut impl PneumaticContainer,
         downstream: &mut impl PneumaticContainer,
     ) {
-        let target_open_amount = if !self.solenoid.is_energized() {
+        let target_open_amount = if let Some(stuck_open_amount) = self.stuck_open_amount {
+            stuck_open_amount.get::<ratio>()
+        } else if !self.solenoid.is_energized() {
             0.
         } else {
             self.characteristics.get_open_amount(
@@ -152,11 +161,15 @@ impl<const N: usize> SolenoidValve<N> {
         let current_open_amount = self.open_amount.get::<ratio>();
         let open_amount_change = context.delta_as_secs_f64() * self.characteristics.valve_speed;
 
-        self.open_amount = Ratio::new::<ratio>(if target_open_amount > current_open_amount {
-            target_open_amount.min(current_open_amount + open_amount_change)
+        self.open_amount = if self.stuck_open_amount.is_some() {
+            Ratio::new::<ratio>(target_open_amount)
         } else {
-            target_open_amount.max(current_open_amount - open_amount_change)
-        });
+            Ratio::new::<ratio>(if target_open_amount > current_open_amount {
+                target_open_amount.min(current_open_amount + open_amount_change)
+            } else {
+                target_open_amount.max(current_open_amount - open_amount_change)
+            })
+        };
 
         self.connector
             .with_transfer_speed_factor(self.open_amount)
@@ -189,6 +202,7 @@ pub struct ElectroPneumaticValve {
     connector: PneumaticContainerConnector,
     is_powered: bool,
     powered_by: ElectricalBusType,
+    stuck_open_amount: Option<Ratio>,
 }
 impl ElectroPneumaticValve {
     const SPRING_CHARACTERISTIC: f64 = 1.;
@@ -199,16 +213,24 @@ impl ElectroPneumaticValve {
             connector: PneumaticContainerConnector::new(),
             is_powered: false,
             powered_by,
+            stuck_open_amount: None,
         }
     }
 
+    /// A stuck valve ignores both its controller and the pressure forces acting on it.
+    pub fn set_stuck_open_amount(&mut self, stuck_open_amount: Option<Ratio>) {
+        self.stuck_open_amount = stuck_open_amount;
+    }
+
     pub fn update_move_fluid(
         &mut self,
         context: &UpdateContext,
         container_one: &mut impl PneumaticContainer,
         container_two: &mut impl PneumaticContainer,
     ) {
-        if !self.is_powered {
+        if let Some(stuck_open_amount) = self.stuck_open_amount {
+            self.open_amount = stuck_open_amount;
+        } else if !self.is_powered {
             self.set_open_amount_from_pressure_difference(
                 container_one.pressure() - container_two.pressure(),
             )
@@ -250,7 +272,7 @@ impl ControllablePneumaticValve for ElectroPneumaticValve {
         &mut self,
         controller: &U,
     ) {
-        if self.is_powered {
+        if self.is_powered && self.stuck_open_amount.is_none() {
             if let Some(signal) = controller.signal() {
                 self.open_amount = signal.target_open_amount();
             }
@@ -345,6 +367,7 @@ pub trait FullyClosed {
 pub struct DefaultValve {
     open_amount: Ratio,
     connector: PneumaticContainerConnector,
+    stuck_open_amount: Option<Ratio>,
 }
 impl PneumaticValve for DefaultValve {
     fn is_open(&self) -> bool {
@@ -356,6 +379,7 @@ impl DefaultValve {
         Self {
             open_amount,
             connector: PneumaticContainerConnector::new(),
+            stuck_open_amount: None,
         }
     }
 
@@ -371,6 +395,10 @@ impl DefaultValve {
         self.open_amount
     }
 
+    pub fn set_stuck_open_amount(&mut self, stuck_open_amount: Option<Ratio>) {
+        self.stuck_open_amount = stuck_open_amount;
+    }
+
     pub fn update_move_fluid(
         &mut self,
         context: &UpdateContext,
@@ -408,7 +436,9 @@ impl ControllablePneumaticValve for DefaultValve {
         &mut self,
         controller: &U,
     ) {
-        if let Some(signal) = controller.signal() {
+        if let Some(stuck_open_amount) = self.stuck_open_amount {
+            self.open_amount = stuck_open_amount;
+        } else if let Some(signal) = controller.signal() {
             self.open_amount = signal.target_open_amount();
         }
     }
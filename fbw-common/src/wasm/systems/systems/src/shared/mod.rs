@@ -145,6 +145,11 @@ pub trait LandingGearHandle {
     fn gear_handle_baulk_locked(&self) -> bool;
 }
 
+pub trait TyrePressure {
+    fn tyre_pressure(&self) -> Pressure;
+    fn tyre_temperature(&self) -> ThermodynamicTemperature;
+}
+
 pub trait TrimmableHorizontalStabilizer {
     fn trim_angle(&self) -> Angle;
 }
@@ -204,6 +209,19 @@ pub enum GearActuatorId {
     GearDoorRight,
 }
 
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Hash)]
+pub enum SteeringActuatorId {
+    Nose,
+    BodyLeft,
+    BodyRight,
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Hash)]
+pub enum CargoDoorId {
+    Forward,
+    Aft,
+}
+
 pub trait EngineCorrectedN1 {
     fn corrected_n1(&self) -> Ratio;
 }
@@ -216,6 +234,10 @@ pub trait EngineUncorrectedN2 {
     fn uncorrected_n2(&self) -> Ratio;
 }
 
+pub trait EngineExhaustGasTemperature {
+    fn exhaust_gas_temperature(&self) -> ThermodynamicTemperature;
+}
+
 pub trait CabinAltitude {
     fn altitude(&self) -> Length;
 }
@@ -270,6 +292,7 @@ pub trait AdirsDiscreteOutputs {
     fn low_speed_warning_4(&self, adiru_number: usize) -> bool;
 }
 
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Hash)]
 pub enum GearWheel {
     NOSE = 0,
     LEFT = 1,
@@ -824,7 +847,7 @@ pub struct InternationalStandardAtmosphere;
 impl InternationalStandardAtmosphere {
     const TEMPERATURE_LAPSE_RATE: f64 = 0.0065;
     const GAS_CONSTANT_DRY_AIR: f64 = 287.04;
-    const GRAVITY_ACCELERATION: f64 = 9.807;
+    pub const GRAVITY_ACCELERATION: f64 = 9.807;
     const GROUND_PRESSURE_PASCAL: f64 = 101325.;
     const GROUND_TEMPERATURE_KELVIN: f64 = 288.15;
 
@@ -1128,6 +1151,23 @@ impl Display for FireDetectionZone {
     }
 }
 
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash)]
+pub enum BleedDuctZone {
+    Pylon(usize),
+    Wing(usize),
+    Apu,
+}
+
+impl Display for BleedDuctZone {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            BleedDuctZone::Pylon(number) => write!(f, "PYLON_{}", number),
+            BleedDuctZone::Wing(number) => write!(f, "WING_{}", number),
+            BleedDuctZone::Apu => write!(f, "APU"),
+        }
+    }
+}
+
 #[derive(Clone, Copy, Eq, PartialEq, Hash)]
 pub enum FireDetectionLoopID {
     A,
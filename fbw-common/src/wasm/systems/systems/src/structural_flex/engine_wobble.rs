@@ -1,8 +1,9 @@
 use std::time::Duration;
 
 use crate::{
+    engine::EngineVibration,
     physics::{GravityEffect, WobblePhysics},
-    shared::update_iterator::MaxStepLoop,
+    shared::{random_from_range, update_iterator::MaxStepLoop},
     simulation::{
         InitContext, Read, SimulationElement, SimulationElementVisitor, SimulatorReader,
         SimulatorWriter, UpdateContext, VariableIdentifier, Write,
@@ -15,6 +16,7 @@ use uom::si::{acceleration::meter_per_second_squared, f64::*};
 
 /// Solves a basic mass connected to a static point through a spring damper system
 /// Mass center of gravity position reacting to external accelerations is then used to model engine wobbling movement
+/// Engine vibration sensed by the vibration monitoring unit excites the mass laterally so engine shaking matches it
 pub struct EngineFlexPhysics {
     x_position_id: VariableIdentifier,
 
@@ -33,6 +35,8 @@ pub struct EngineFlexPhysics {
     animation_position: f64,
 }
 impl EngineFlexPhysics {
+    const SHAKE_ACCELERATION_PER_VIBRATION_UNIT_M_S2: f64 = 0.8;
+
     pub fn new(context: &mut InitContext, engine_number: usize) -> Self {
         Self {
             x_position_id: context
@@ -64,11 +68,20 @@ impl EngineFlexPhysics {
         }
     }
 
-    pub fn update(&mut self, context: &UpdateContext, wing_pylon_acceleration: Acceleration) {
+    pub fn update(
+        &mut self,
+        context: &UpdateContext,
+        wing_pylon_acceleration: Acceleration,
+        engine_vibration: f64,
+    ) {
+        let unbalance_shake_acceleration = random_from_range(-1., 1.)
+            * engine_vibration
+            * Self::SHAKE_ACCELERATION_PER_VIBRATION_UNIT_M_S2;
+
         self.wobble_physics.update(
             context,
             Vector3::new(
-                0.,
+                unbalance_shake_acceleration,
                 wing_pylon_acceleration.get::<meter_per_second_squared>(),
                 0.,
             ),
@@ -140,14 +153,26 @@ impl<const N: usize> EnginesFlexiblePhysics<N> {
         }
     }
 
-    pub fn update(&mut self, context: &UpdateContext, pylons_accelerations: [Acceleration; N]) {
+    pub fn update(
+        &mut self,
+        context: &UpdateContext,
+        pylons_accelerations: [Acceleration; N],
+        engines: [&impl EngineVibration; N],
+    ) {
         self.engines_flex_updater.update(context);
 
         for cur_time_step in self.engines_flex_updater {
-            for (engine_flex, pylons_acceleration) in
-                &mut self.engines_flex.iter_mut().zip(pylons_accelerations)
+            for ((engine_flex, pylons_acceleration), engine) in self
+                .engines_flex
+                .iter_mut()
+                .zip(pylons_accelerations)
+                .zip(engines)
             {
-                engine_flex.update(&context.with_delta(cur_time_step), pylons_acceleration);
+                engine_flex.update(
+                    &context.with_delta(cur_time_step),
+                    pylons_acceleration,
+                    engine.vibration(),
+                );
             }
         }
     }
@@ -169,22 +194,47 @@ mod tests {
         Aircraft, InitContext, SimulationElement, SimulationElementVisitor, UpdateContext,
     };
 
+    use more_asserts::assert_gt;
     use ntest::assert_about_eq;
     use std::time::Duration;
 
+    #[derive(Default)]
+    struct TestEngine {
+        vibration: f64,
+    }
+    impl EngineVibration for TestEngine {
+        fn vibration(&self) -> f64 {
+            self.vibration
+        }
+    }
+
     struct EngineFlexTestAircraft {
         engines_flex: EnginesFlexiblePhysics<4>,
+        engines: [TestEngine; 4],
     }
     impl EngineFlexTestAircraft {
         fn new(context: &mut InitContext) -> Self {
             Self {
                 engines_flex: EnginesFlexiblePhysics::new(context),
+                engines: Default::default(),
             }
         }
 
         fn update(&mut self, context: &UpdateContext) {
-            self.engines_flex
-                .update(context, [Acceleration::default(); 4]);
+            self.engines_flex.update(
+                context,
+                [Acceleration::default(); 4],
+                [
+                    &self.engines[0],
+                    &self.engines[1],
+                    &self.engines[2],
+                    &self.engines[3],
+                ],
+            );
+        }
+
+        fn set_engine_vibration(&mut self, engine_number: usize, vibration: f64) {
+            self.engines[engine_number - 1].vibration = vibration;
         }
     }
     impl Aircraft for EngineFlexTestAircraft {
@@ -228,6 +278,26 @@ mod tests {
         assert_about_eq!(engine_4_position, 0.5);
     }
 
+    #[test]
+    fn check_only_vibrating_engine_shakes() {
+        let mut test_bed = SimulationTestBed::new(EngineFlexTestAircraft::new);
+
+        test_bed.command(|a| a.set_engine_vibration(1, 10.));
+
+        let mut engine_1_max_deviation: f64 = 0.;
+        for _ in 0..50 {
+            test_bed.run_with_delta(Duration::from_secs_f64(0.1));
+
+            let engine_1_position: f64 = test_bed.read_by_name("ENGINE_1_WOBBLE_X_POSITION");
+            engine_1_max_deviation = engine_1_max_deviation.max((engine_1_position - 0.5).abs());
+
+            let engine_2_position: f64 = test_bed.read_by_name("ENGINE_2_WOBBLE_X_POSITION");
+            assert_about_eq!(engine_2_position, 0.5);
+        }
+
+        assert_gt!(engine_1_max_deviation, 0.01);
+    }
+
     // Following test is ignored because it's hard to set static boundaries to desired results
     // Tuning is better done visually using dev mode to edit physical properties
     #[test]